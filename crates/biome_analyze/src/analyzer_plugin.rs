@@ -11,6 +11,10 @@ pub type AnalyzerPluginVec = Vec<Arc<Box<dyn AnalyzerPlugin>>>;
 
 /// Definition of an analyzer plugin.
 pub trait AnalyzerPlugin: Debug + Send + Sync {
+    /// The name of the plugin, used to report the time spent in it when
+    /// profiling.
+    fn name(&self) -> &str;
+
     fn evaluate(&self, root: AnyParse, path: Arc<Utf8PathBuf>) -> Vec<RuleDiagnostic>;
 
     fn supports_css(&self) -> bool;
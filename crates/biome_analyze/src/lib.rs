@@ -9,6 +9,7 @@ use std::ops;
 use std::ops::Sub;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 mod analyzer_plugin;
 mod categories;
@@ -16,6 +17,7 @@ pub mod context;
 mod diagnostics;
 mod matcher;
 pub mod options;
+pub mod profiling;
 mod query;
 mod registry;
 mod rule;
@@ -39,6 +41,7 @@ pub use crate::categories::{
 pub use crate::diagnostics::{AnalyzerDiagnostic, AnalyzerSuppressionDiagnostic, RuleError};
 pub use crate::matcher::{InspectMatcher, MatchQueryParams, QueryMatcher, RuleKey, SignalEntry};
 pub use crate::options::{AnalyzerConfiguration, AnalyzerOptions, AnalyzerRules};
+use crate::profiling::Timer;
 pub use crate::query::{AddVisitor, QueryKey, QueryMatch, Queryable};
 pub use crate::registry::{
     LanguageRoot, MetadataRegistry, Phase, Phases, RegistryRuleMetadata, RegistryVisitor,
@@ -156,6 +159,8 @@ where
 
         let mut line_index = 0;
         let mut suppressions = Suppressions::new(self.metadata);
+        let start = profiling::is_enabled().then(Instant::now);
+        let mut semantic_model_duration = Duration::ZERO;
 
         for (index, (phase, mut visitors)) in phases.into_iter().enumerate() {
             let runner = PhaseRunner {
@@ -173,6 +178,7 @@ where
                 options: ctx.options,
                 suppressions: &mut suppressions,
                 categories,
+                semantic_model_duration: &mut semantic_model_duration,
             };
 
             // The first phase being run will inspect the tokens and parse the
@@ -192,16 +198,31 @@ where
             // phase runner as it needs mutable access to the service bag (the
             // runner borrows the services for the entire phase)
             for visitor in visitors {
+                let builds_semantic_model = visitor.builds_semantic_model();
+                let timer = Timer::start();
                 visitor.finish(VisitorFinishContext {
                     root: &ctx.root,
                     services: &mut ctx.services,
                 });
+                if let Some(duration) = timer.elapsed().filter(|_| builds_semantic_model) {
+                    semantic_model_duration += duration;
+                }
             }
         }
 
+        if let Some(start) = start.filter(|_| !semantic_model_duration.is_zero()) {
+            profiling::record_semantic_model(
+                &ctx.options.file_path,
+                start,
+                semantic_model_duration,
+            );
+        }
+
         for plugin in plugins {
             let root: AnyParse = ctx.root.syntax().as_send().expect("not a root node").into();
+            let timer = Timer::start();
             let diagnostics = plugin.evaluate(root, ctx.options.file_path.clone());
+            timer.record_plugin(plugin.name(), &ctx.options.file_path);
             for diagnostic in diagnostics {
                 let name = diagnostic
                     .subcategory
@@ -353,6 +374,9 @@ struct PhaseRunner<'analyzer, 'phase, L: Language, Matcher, Break, Diag> {
     suppressions: &'phase mut Suppressions<'analyzer>,
     /// The current categories
     categories: RuleCategories,
+    /// Time spent by the visitors building the semantic model, when
+    /// profiling is enabled
+    semantic_model_duration: &'phase mut Duration,
 }
 
 impl<L, Matcher, Break, Diag> PhaseRunner<'_, '_, L, Matcher, Break, Diag>
@@ -392,7 +416,13 @@ where
                     options: self.options,
                 };
 
+                let timer = Timer::start();
                 visitor.visit(&event, ctx);
+                if let Some(duration) = timer.elapsed() {
+                    if visitor.builds_semantic_model() {
+                        *self.semantic_model_duration += duration;
+                    }
+                }
             }
         }
 
@@ -417,7 +447,13 @@ where
                     options: self.options,
                 };
 
+                let timer = Timer::start();
                 visitor.visit(&event, ctx);
+                if let Some(duration) = timer.elapsed() {
+                    if visitor.builds_semantic_model() {
+                        *self.semantic_model_duration += duration;
+                    }
+                }
             }
 
             // Flush all pending query signals
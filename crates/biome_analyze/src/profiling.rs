@@ -0,0 +1,325 @@
+//! Opt-in profiling of the time spent processing files.
+//!
+//! The profile is global to the process, and it's recorded only between the
+//! calls to [enable] and [take]. The hooks check an atomic flag before reading
+//! the clock, so they cost close to nothing when profiling is disabled.
+//!
+//! The analyzer records the time spent by each rule, by each plugin and to
+//! build the semantic model, while the workspace records the other phases of
+//! the files and the scanning of the project.
+
+use crate::RuleKey;
+use camino::{Utf8Path, Utf8PathBuf};
+use rustc_hash::FxHashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{LazyLock, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+static PROFILE: LazyLock<Mutex<Profile>> = LazyLock::new(|| Mutex::new(Profile::new()));
+
+static NEXT_THREAD_ID: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    /// Stable identifier of the current thread, used by the events of the
+    /// profile
+    static THREAD_ID: u64 = NEXT_THREAD_ID.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Starts recording a new profile, discarding the events recorded so far.
+pub fn enable() {
+    *lock() = Profile::new();
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Returns whether the profile is being recorded.
+#[inline]
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Stops recording the profile, and returns the profile recorded so far.
+pub fn take() -> Profile {
+    ENABLED.store(false, Ordering::Relaxed);
+    std::mem::replace(&mut *lock(), Profile::new())
+}
+
+fn lock() -> MutexGuard<'static, Profile> {
+    PROFILE.lock().unwrap_or_else(|error| error.into_inner())
+}
+
+/// The phases of the processing of a file
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum FilePhase {
+    Parse,
+    SemanticModel,
+    /// The analysis of the file, which includes building its semantic model
+    /// and running the rules and plugins
+    Analysis,
+    Format,
+}
+
+impl FilePhase {
+    pub const ALL: [Self; 4] = [
+        Self::Parse,
+        Self::SemanticModel,
+        Self::Analysis,
+        Self::Format,
+    ];
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Parse => "parse",
+            Self::SemanticModel => "semantic model",
+            Self::Analysis => "analysis",
+            Self::Format => "format",
+        }
+    }
+}
+
+/// The time spent in some work, and how many times it was done
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Timing {
+    pub duration: Duration,
+    pub count: u32,
+}
+
+impl Timing {
+    pub fn add(&mut self, duration: Duration) {
+        self.duration += duration;
+        self.count += 1;
+    }
+
+    pub fn merge(&mut self, other: Self) {
+        self.duration += other.duration;
+        self.count += other.count;
+    }
+}
+
+/// The time spent in each phase of a file
+#[derive(Clone, Debug, Default)]
+pub struct FileProfile {
+    phases: [Timing; FilePhase::ALL.len()],
+}
+
+impl FileProfile {
+    pub fn phase(&self, phase: FilePhase) -> Timing {
+        self.phases[phase as usize]
+    }
+
+    /// The time spent in all the phases of the file, without counting twice
+    /// the semantic model, which is part of the analysis
+    pub fn duration(&self) -> Duration {
+        FilePhase::ALL
+            .into_iter()
+            .filter(|phase| *phase != FilePhase::SemanticModel)
+            .map(|phase| self.phase(phase).duration)
+            .sum()
+    }
+}
+
+/// What a [ProfileEvent] measures
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProfileEventKind {
+    Scan,
+    Phase(FilePhase),
+    Plugin,
+}
+
+/// A span of work recorded with its start, to export the profile as a trace
+#[derive(Clone, Debug)]
+pub struct ProfileEvent {
+    pub kind: ProfileEventKind,
+    /// The name of the plugin, for the events of the plugins
+    pub name: Option<String>,
+    /// The file being processed, if any
+    pub path: Option<Utf8PathBuf>,
+    /// The start of the work, since the start of the profile
+    pub start: Duration,
+    pub duration: Duration,
+    /// The thread that did the work
+    pub thread: u64,
+}
+
+/// The time spent processing files, recorded when profiling is enabled
+#[derive(Debug)]
+pub struct Profile {
+    start: Instant,
+    rules: FxHashMap<RuleKey, Timing>,
+    plugins: FxHashMap<String, Timing>,
+    files: FxHashMap<Utf8PathBuf, FileProfile>,
+    scan: Timing,
+    module_graph: Timing,
+    events: Vec<ProfileEvent>,
+}
+
+impl Profile {
+    fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            rules: FxHashMap::default(),
+            plugins: FxHashMap::default(),
+            files: FxHashMap::default(),
+            scan: Timing::default(),
+            module_graph: Timing::default(),
+            events: Vec::new(),
+        }
+    }
+
+    /// The time spent by each rule, matching the queries of the files
+    pub fn rules(&self) -> &FxHashMap<RuleKey, Timing> {
+        &self.rules
+    }
+
+    /// The time spent by each plugin, by name
+    pub fn plugins(&self) -> &FxHashMap<String, Timing> {
+        &self.plugins
+    }
+
+    /// The time spent processing each file
+    pub fn files(&self) -> &FxHashMap<Utf8PathBuf, FileProfile> {
+        &self.files
+    }
+
+    /// The time spent in the given phase by all the files
+    pub fn phase(&self, phase: FilePhase) -> Timing {
+        let mut timing = Timing::default();
+        for file in self.files.values() {
+            timing.merge(file.phase(phase));
+        }
+        timing
+    }
+
+    /// The time spent scanning the project
+    pub fn scan(&self) -> Timing {
+        self.scan
+    }
+
+    /// The time spent updating the module graph, while scanning the project
+    /// or when files change
+    pub fn module_graph(&self) -> Timing {
+        self.module_graph
+    }
+
+    /// The spans of work recorded, in the order they finished
+    pub fn events(&self) -> &[ProfileEvent] {
+        &self.events
+    }
+
+    fn push_event(
+        &mut self,
+        kind: ProfileEventKind,
+        name: Option<&str>,
+        path: Option<&Utf8Path>,
+        start: Instant,
+        duration: Duration,
+    ) {
+        self.events.push(ProfileEvent {
+            kind,
+            name: name.map(ToString::to_string),
+            path: path.map(Utf8Path::to_path_buf),
+            start: start.saturating_duration_since(self.start),
+            duration,
+            thread: THREAD_ID.with(|id| *id),
+        });
+    }
+}
+
+/// Measures a span of work when profiling is enabled.
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct Timer(Option<Instant>);
+
+impl Timer {
+    /// Starts measuring, if profiling is enabled.
+    #[inline]
+    pub fn start() -> Self {
+        Self(is_enabled().then(Instant::now))
+    }
+
+    /// Returns the time elapsed since the timer started, if profiling is
+    /// enabled.
+    #[inline]
+    pub fn elapsed(self) -> Option<Duration> {
+        self.0.map(|start| start.elapsed())
+    }
+
+    /// Records the time spent in the given phase of a file.
+    pub fn record_phase(self, path: &Utf8Path, phase: FilePhase) {
+        if let Some(start) = self.0 {
+            let duration = start.elapsed();
+            let mut profile = lock();
+            profile.files.entry(path.to_path_buf()).or_default().phases[phase as usize]
+                .add(duration);
+            profile.push_event(
+                ProfileEventKind::Phase(phase),
+                None,
+                Some(path),
+                start,
+                duration,
+            );
+        }
+    }
+
+    /// Records the time spent by a plugin to evaluate a file.
+    pub fn record_plugin(self, name: &str, path: &Utf8Path) {
+        if let Some(start) = self.0 {
+            let duration = start.elapsed();
+            let mut profile = lock();
+            profile
+                .plugins
+                .entry(name.to_string())
+                .or_default()
+                .add(duration);
+            profile.push_event(
+                ProfileEventKind::Plugin,
+                Some(name),
+                Some(path),
+                start,
+                duration,
+            );
+        }
+    }
+
+    /// Records the time spent scanning the project.
+    pub fn record_scan(self) {
+        if let Some(start) = self.0 {
+            let duration = start.elapsed();
+            let mut profile = lock();
+            profile.scan.add(duration);
+            profile.push_event(ProfileEventKind::Scan, None, None, start, duration);
+        }
+    }
+
+    /// Records the time spent updating the module graph.
+    pub fn record_module_graph(self) {
+        if let Some(duration) = self.elapsed() {
+            lock().module_graph.add(duration);
+        }
+    }
+}
+
+/// Records the time spent by the rules, accumulated by the registry while
+/// analyzing a file.
+pub(crate) fn record_rules(rules: impl IntoIterator<Item = (RuleKey, Timing)>) {
+    let mut profile = lock();
+    for (rule, timing) in rules {
+        profile.rules.entry(rule).or_default().merge(timing);
+    }
+}
+
+/// Records the time spent building the semantic model of a file, which is
+/// accumulated while the visitors walk the syntax tree.
+pub(crate) fn record_semantic_model(path: &Utf8Path, start: Instant, duration: Duration) {
+    let mut profile = lock();
+    profile.files.entry(path.to_path_buf()).or_default().phases[FilePhase::SemanticModel as usize]
+        .add(duration);
+    profile.push_event(
+        ProfileEventKind::Phase(FilePhase::SemanticModel),
+        None,
+        Some(path),
+        start,
+        duration,
+    );
+}
@@ -3,6 +3,7 @@ use crate::{
     RuleKey, RuleMetadata, ServiceBag, SignalEntry, Visitor,
     context::RuleContext,
     matcher::{GroupKey, MatchQueryParams},
+    profiling::{self, Timer, Timing},
     query::{QueryKey, Queryable},
     signals::RuleSignal,
 };
@@ -119,6 +120,22 @@ impl<L: Language + Default> RuleRegistry<L> {
     }
 }
 
+impl<L: Language> Drop for RuleRegistry<L> {
+    fn drop(&mut self) {
+        // The time spent by the rules is accumulated in their state while
+        // the file is analyzed, and recorded at once when the analysis ends
+        if profiling::is_enabled() {
+            profiling::record_rules(
+                self.phase_rules
+                    .iter()
+                    .flat_map(|phase| &phase.rule_states)
+                    .filter(|state| state.timing.count > 0)
+                    .map(|state| (state.key, state.timing)),
+            );
+        }
+    }
+}
+
 /// Holds a collection of rules for each phase.
 #[derive(Default)]
 struct PhaseRules<L: Language> {
@@ -218,7 +235,7 @@ impl<L: Language + Default + 'static> RegistryVisitor<L> for RuleRegistryBuilder
             }
         }
 
-        phase.rule_states.push(RuleState::default());
+        phase.rule_states.push(RuleState::new(RuleKey::rule::<R>()));
 
         <R::Query as Queryable>::build_visitor(&mut self.visitors, self.root);
     }
@@ -285,8 +302,12 @@ impl<L: Language + 'static> QueryMatcher<L> for RuleRegistry<L> {
         // Run all the rules registered to this QueryMatch
         for rule in rules {
             let state = &mut phase.rule_states[rule.state_index];
+            let timer = Timer::start();
             // TODO: #3394 track error in the signal queue
             let _ = (rule.run)(&mut params, state);
+            if let Some(duration) = timer.elapsed() {
+                state.timing.add(duration);
+            }
         }
     }
 }
@@ -359,9 +380,23 @@ pub struct RegistryRule<L: Language> {
 }
 
 /// Internal state for a given rule
-#[derive(Default)]
 struct RuleState<L: Language> {
+    key: RuleKey,
     suppressions: RuleSuppressions<L>,
+    /// Time spent running the rule, when profiling is enabled
+    timing: Timing,
+}
+
+impl<L: Language> RuleState<L> {
+    fn new(key: RuleKey) -> Self {
+        Self {
+            key,
+            suppressions: RuleSuppressions {
+                inner: FxHashSet::default(),
+            },
+            timing: Timing::default(),
+        }
+    }
 }
 
 /// Set of nodes this rule has suppressed from matching its query
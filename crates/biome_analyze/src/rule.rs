@@ -16,6 +16,7 @@ use biome_rowan::{AstNode, BatchMutation, BatchMutationExt, Language, TextRange}
 use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::fmt::Debug;
+use std::str::FromStr;
 
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
@@ -67,6 +68,19 @@ pub enum FixKind {
     Unsafe,
 }
 
+impl FromStr for FixKind {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Self::None),
+            "safe" => Ok(Self::Safe),
+            "unsafe" => Ok(Self::Unsafe),
+            _ => Err("Invalid fix kind, valid values are: none, safe, unsafe"),
+        }
+    }
+}
+
 impl Display for FixKind {
     fn fmt(&self, fmt: &mut biome_console::fmt::Formatter) -> std::io::Result<()> {
         match self {
@@ -151,6 +165,8 @@ pub enum RuleSource {
     DenoLint(&'static str),
     /// Rules from [Eslint Plugin Vitest](https://github.com/vitest-dev/eslint-plugin-vitest)
     EslintVitest(&'static str),
+    /// Rules from [yamllint](https://github.com/adrienverge/yamllint)
+    Yamllint(&'static str),
 }
 
 impl PartialEq for RuleSource {
@@ -191,6 +207,7 @@ impl std::fmt::Display for RuleSource {
             Self::EslintRegexp(_) => write!(f, "eslint-plugin-regexp"),
             Self::DenoLint(_) => write!(f, "deno-lint"),
             Self::EslintVitest(_) => write!(f, "@vitest/eslint-plugin"),
+            Self::Yamllint(_) => write!(f, "yamllint"),
         }
     }
 }
@@ -264,7 +281,8 @@ impl RuleSource {
             | Self::EslintRegexp(rule_name)
             | Self::Stylelint(rule_name)
             | Self::DenoLint(rule_name)
-            | Self::EslintVitest(rule_name) => rule_name,
+            | Self::EslintVitest(rule_name)
+            | Self::Yamllint(rule_name) => rule_name,
         }
     }
 
@@ -298,6 +316,7 @@ impl RuleSource {
             Self::EslintRegexp(rule_name) => format!("regexp/{rule_name}"),
             Self::DenoLint(rule_name) => format!("deno-lint/{rule_name}"),
             Self::EslintVitest(rule_name) => format!("vitest/{rule_name}"),
+            Self::Yamllint(rule_name) => format!("yamllint/{rule_name}"),
         }
     }
 
@@ -332,6 +351,7 @@ impl RuleSource {
             Self::EslintRegexp(rule_name) => format!("https://ota-meshi.github.io/eslint-plugin-regexp/rules/{rule_name}.html"),
             Self::DenoLint(rule_name) => format!("https://lint.deno.land/rules/{rule_name}"),
             Self::EslintVitest(rule_name) => format!("https://github.com/vitest-dev/eslint-plugin-vitest/blob/main/docs/rule/{rule_name}.md"),
+            Self::Yamllint(rule_name) => format!("https://yamllint.readthedocs.io/en/stable/rules.html#module-yamllint.rules.{}", rule_name.replace('-', "_")),
         }
     }
 
@@ -346,7 +366,10 @@ impl RuleSource {
 
     /// All ESLint plugins, exception for the TypeScript one
     pub const fn is_eslint_plugin(&self) -> bool {
-        !matches!(self, Self::Clippy(_) | Self::Eslint(_) | Self::Stylelint(_))
+        !matches!(
+            self,
+            Self::Clippy(_) | Self::Eslint(_) | Self::Stylelint(_) | Self::Yamllint(_)
+        )
     }
 
     pub const fn is_stylelint(&self) -> bool {
@@ -410,14 +433,24 @@ pub enum RuleDomain {
 
 impl Display for RuleDomain {
     fn fmt(&self, fmt: &mut Formatter) -> std::io::Result<()> {
-        // use lower case naming, it needs to match the name of the configuration
-        match self {
-            Self::React => fmt.write_str("react"),
-            Self::Test => fmt.write_str("test"),
-            Self::Solid => fmt.write_str("solid"),
-            Self::Next => fmt.write_str("next"),
-            Self::Vue => fmt.write_str("vue"),
-            Self::Project => fmt.write_str("project"),
+        fmt.write_str(self.as_str())
+    }
+}
+
+impl FromStr for RuleDomain {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "react" => Ok(Self::React),
+            "test" => Ok(Self::Test),
+            "solid" => Ok(Self::Solid),
+            "next" => Ok(Self::Next),
+            "vue" => Ok(Self::Vue),
+            "project" => Ok(Self::Project),
+            _ => {
+                Err("Invalid rule domain, valid values are: react, test, solid, next, vue, project")
+            }
         }
     }
 }
@@ -436,6 +469,18 @@ impl PartialOrd for RuleDomain {
 }
 
 impl RuleDomain {
+    /// Returns the name of the domain, as used in the configuration.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::React => "react",
+            Self::Test => "test",
+            Self::Solid => "solid",
+            Self::Next => "next",
+            Self::Vue => "vue",
+            Self::Project => "project",
+        }
+    }
+
     /// If the project has one of these dependencies, the domain will be automatically enabled, unless it's explicitly disabled by the configuration.
     ///
     /// If the array is empty, it means that the rules that belong to a certain domain won't enable themselves automatically.
@@ -54,6 +54,12 @@ pub trait Visitor {
     fn finish(self: Box<Self>, ctx: VisitorFinishContext<Self::Language>) {
         let _ = ctx;
     }
+
+    /// Whether this visitor builds the semantic model of the file, so that
+    /// the time spent in it is profiled apart from the rules.
+    fn builds_semantic_model(&self) -> bool {
+        false
+    }
 }
 
 /// A node visitor is a special kind of visitor that does not have a persistent
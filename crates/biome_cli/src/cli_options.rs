@@ -56,7 +56,9 @@ pub struct CliOptions {
     /// Allows to change how diagnostics and summary are reported.
     #[bpaf(
         long("reporter"),
-        argument("json|json-pretty|github|junit|summary|gitlab"),
+        argument(
+            "json|json-pretty|github|junit|summary|gitlab|sarif|patch|checkstyle|rdjson|rdjsonl"
+        ),
         fallback(CliReporter::default())
     )]
     pub reporter: CliReporter,
@@ -149,6 +151,16 @@ pub enum CliReporter {
     Summary,
     /// Reports linter diagnostics using the [GitLab Code Quality report](https://docs.gitlab.com/ee/ci/testing/code_quality.html#implement-a-custom-tool).
     GitLab,
+    /// Reports diagnostics using the [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) format.
+    Sarif,
+    /// Prints a unified diff of the changes that `--write` would apply, which can be applied with `git apply`.
+    Patch,
+    /// Reports diagnostics using the [Checkstyle](https://checkstyle.org) XML format.
+    Checkstyle,
+    /// Reports diagnostics using the [Reviewdog Diagnostic Format](https://github.com/reviewdog/reviewdog/tree/master/proto/rdf), in a single JSON object.
+    Rdjson,
+    /// Reports diagnostics using the [Reviewdog Diagnostic Format](https://github.com/reviewdog/reviewdog/tree/master/proto/rdf), one JSON object per line.
+    Rdjsonl,
 }
 
 impl CliReporter {
@@ -168,6 +180,11 @@ impl FromStr for CliReporter {
             "github" => Ok(Self::GitHub),
             "junit" => Ok(Self::Junit),
             "gitlab" => Ok(Self::GitLab),
+            "sarif" => Ok(Self::Sarif),
+            "patch" => Ok(Self::Patch),
+            "checkstyle" => Ok(Self::Checkstyle),
+            "rdjson" => Ok(Self::Rdjson),
+            "rdjsonl" => Ok(Self::Rdjsonl),
             _ => Err(format!(
                 "value {s:?} is not valid for the --reporter argument"
             )),
@@ -185,6 +202,11 @@ impl Display for CliReporter {
             Self::GitHub => f.write_str("github"),
             Self::Junit => f.write_str("junit"),
             Self::GitLab => f.write_str("gitlab"),
+            Self::Sarif => f.write_str("sarif"),
+            Self::Patch => f.write_str("patch"),
+            Self::Checkstyle => f.write_str("checkstyle"),
+            Self::Rdjson => f.write_str("rdjson"),
+            Self::Rdjsonl => f.write_str("rdjsonl"),
         }
     }
 }
@@ -1,7 +1,7 @@
 use super::{FixFileModeOptions, LoadEditorConfig, determine_fix_file_mode};
 use crate::cli_options::CliOptions;
 use crate::commands::{CommandRunner, get_files_to_process_with_cli_options};
-use crate::{CliDiagnostic, Execution, TraversalMode};
+use crate::{CacheOptions, CliDiagnostic, Execution, ProfileOptions, TraversalMode};
 use biome_configuration::analyzer::LinterEnabled;
 use biome_configuration::analyzer::assist::{AssistConfiguration, AssistEnabled};
 use biome_configuration::formatter::FormatterEnabled;
@@ -23,6 +23,9 @@ pub(crate) struct CheckCommandPayload {
     pub(crate) linter_enabled: Option<LinterEnabled>,
     pub(crate) assist_enabled: Option<AssistEnabled>,
     pub(crate) enforce_assist: bool,
+    pub(crate) cache: Option<CacheOptions>,
+    pub(crate) watch: bool,
+    pub(crate) profile: Option<ProfileOptions>,
     pub(crate) staged: bool,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
@@ -138,6 +141,12 @@ impl CommandRunner for CheckCommandPayload {
             enforce_assist: self.enforce_assist,
             skip_parse_errors: cli_options.skip_parse_errors,
         })
-        .set_report(cli_options))
+        .set_report(cli_options)
+        .with_cache(self.cache.clone())
+        .with_profile(self.profile.clone()))
+    }
+
+    fn should_watch(&self) -> bool {
+        self.watch
     }
 }
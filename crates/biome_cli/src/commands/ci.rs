@@ -1,7 +1,7 @@
 use crate::changed::get_changed_files;
 use crate::cli_options::CliOptions;
 use crate::commands::{CommandRunner, LoadEditorConfig};
-use crate::{CliDiagnostic, Execution};
+use crate::{CacheOptions, CliDiagnostic, Execution};
 use biome_configuration::analyzer::LinterEnabled;
 use biome_configuration::analyzer::assist::{AssistConfiguration, AssistEnabled};
 use biome_configuration::formatter::FormatterEnabled;
@@ -18,6 +18,7 @@ pub(crate) struct CiCommandPayload {
     pub(crate) linter_enabled: Option<LinterEnabled>,
     pub(crate) assist_enabled: Option<AssistEnabled>,
     pub(crate) enforce_assist: bool,
+    pub(crate) cache: Option<CacheOptions>,
     pub(crate) paths: Vec<OsString>,
     pub(crate) configuration: Option<Configuration>,
     pub(crate) changed: bool,
@@ -119,7 +120,8 @@ impl CommandRunner for CiCommandPayload {
             self.enforce_assist,
             cli_options.skip_parse_errors,
         )
-        .set_report(cli_options))
+        .set_report(cli_options)
+        .with_cache(self.cache.clone()))
     }
 
     fn check_incompatible_arguments(&self) -> Result<(), CliDiagnostic> {
@@ -0,0 +1,543 @@
+//! The `config` command, which shows how the configuration applies to the
+//! files of the project.
+
+use crate::cli_options::CliOptions;
+use crate::commands::{
+    ConfigSubCommand, print_diagnostics_from_workspace_result, validate_configuration_diagnostics,
+};
+use crate::{CliDiagnostic, CliSession};
+use biome_analyze::RuleFilter;
+use biome_configuration::{Configuration, ConfigurationPathHint, ConfigurationPayload, Rules};
+use biome_console::fmt::{Display, Formatter};
+use biome_console::{ConsoleExt, markup};
+use biome_deserialize::Merge;
+use biome_fs::BiomePath;
+use biome_resolver::FsWithResolverProxy;
+use biome_service::WorkspaceError;
+use biome_service::configuration::{
+    ConfigurationExt, LoadedConfiguration, load_configuration, load_editorconfig, read_config,
+};
+use biome_service::workspace::{
+    DocumentFileSource, GetFileConfigurationParams, GetFileConfigurationResult, OpenProjectParams,
+    OpenProjectResult, ScanKind, ScanProjectFolderParams, UpdateSettingsParams,
+};
+use camino::Utf8Path;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::io;
+
+pub(crate) fn config(
+    session: CliSession,
+    cli_options: &CliOptions,
+    sub_command: ConfigSubCommand,
+) -> Result<(), CliDiagnostic> {
+    match sub_command {
+        ConfigSubCommand::Print { path } => print(session, cli_options, &path),
+    }
+}
+
+/// Handler of the `config print` command.
+///
+/// The workspace is configured like the other commands do, and the settings
+/// that apply to the file are the ones that the workspace returns for it.
+fn print(
+    session: CliSession,
+    cli_options: &CliOptions,
+    path: &Utf8Path,
+) -> Result<(), CliDiagnostic> {
+    let CliSession { app, .. } = session;
+    let workspace = &*app.workspace;
+    let fs = workspace.fs();
+    let working_directory = fs.working_directory().unwrap_or_default();
+    let path = working_directory.join(path);
+
+    let configuration_path = cli_options.as_configuration_path_hint();
+    let is_configuration_from_user = configuration_path.is_from_user();
+    let loaded_configuration = load_configuration(fs, configuration_path.clone())?;
+    validate_configuration_diagnostics(&loaded_configuration, app.console, cli_options.verbose)?;
+
+    let LoadedConfiguration {
+        configuration,
+        directory_path,
+        ..
+    } = loaded_configuration;
+    let editorconfig = if configuration.use_editorconfig() {
+        let (editorconfig, _) =
+            load_editorconfig(fs, working_directory.clone(), directory_path.clone())?;
+        editorconfig
+    } else {
+        None
+    };
+    let configuration = match &editorconfig {
+        Some(editorconfig) => {
+            let mut editorconfig = editorconfig.clone();
+            editorconfig.merge_with(configuration);
+            editorconfig
+        }
+        None => configuration,
+    };
+
+    let project_path = BiomePath::from(working_directory.clone());
+    let OpenProjectResult { project_key, .. } = workspace.open_project(OpenProjectParams {
+        path: project_path.clone(),
+        open_uninitialized: true,
+        only_rules: None,
+        skip_rules: None,
+    })?;
+    // When the user provides the path to the configuration, its folder might
+    // be outside the project, so the folder of the project is used instead
+    let root_directory = if is_configuration_from_user {
+        Some(project_path.clone())
+    } else {
+        directory_path.map(BiomePath::from)
+    };
+    let result = workspace.update_settings(UpdateSettingsParams {
+        project_key,
+        workspace_directory: root_directory.clone(),
+        configuration,
+    })?;
+    print_diagnostics_from_workspace_result(
+        result.diagnostics.as_slice(),
+        app.console,
+        cli_options.verbose,
+    )?;
+    // The nested configuration files are loaded by the scanner
+    let result = workspace.scan_project_folder(ScanProjectFolderParams {
+        project_key,
+        path: Some(project_path),
+        watch: false,
+        force: false,
+        scan_kind: ScanKind::KnownFiles,
+    })?;
+    print_diagnostics_from_workspace_result(
+        result.diagnostics.as_slice(),
+        app.console,
+        cli_options.verbose,
+    )?;
+
+    let file_configuration = workspace.get_file_configuration(GetFileConfigurationParams {
+        project_key,
+        path: BiomePath::from(path.clone()),
+    })?;
+
+    let mut root_layers = load_file_layers(fs, configuration_path, true, &working_directory)?;
+    if let Some(editorconfig) = editorconfig {
+        root_layers.insert(
+            0,
+            Layer {
+                source: Source::EditorConfig,
+                configuration: editorconfig,
+            },
+        );
+    }
+    let layers = match &file_configuration.directory {
+        Some(directory) if Some(directory) != root_directory.as_ref() => {
+            let nested_layers = load_file_layers(
+                fs,
+                ConfigurationPathHint::FromWorkspace(directory.to_path_buf()),
+                false,
+                &working_directory,
+            )?;
+            // The workspace merges the nested configuration into the root
+            // configuration, if it extends it
+            if nested_layers
+                .last()
+                .is_some_and(|layer| layer.configuration.extends_root())
+            {
+                root_layers.extend(nested_layers);
+                root_layers
+            } else {
+                nested_layers
+            }
+        }
+        _ => root_layers,
+    };
+
+    let resolved = ResolvedConfiguration::new(file_configuration, &layers);
+    app.console.log(markup! {
+        {PrintResolvedConfiguration {
+            path: display_path(&path, &working_directory),
+            language: language_of(&path),
+            sources: &resolved.sources,
+            settings: &resolved.settings,
+        }}
+    });
+
+    Ok(())
+}
+
+/// Where a setting comes from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Source {
+    /// The `.editorconfig` file.
+    EditorConfig,
+    /// A configuration file, or a file extended by the configuration file
+    /// `extended_by`.
+    File {
+        path: String,
+        extended_by: Option<String>,
+    },
+    /// The override at `index` in the `overrides` of the configuration file
+    /// `path`.
+    Override { path: String, index: usize },
+    /// The rule is enabled because it's recommended.
+    Recommended,
+}
+
+impl Display for Source {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        match self {
+            Self::EditorConfig => fmt.write_str(".editorconfig"),
+            Self::File { path, .. } => fmt.write_str(path),
+            Self::Override { path, index } => write!(fmt, "{path}, overrides[{index}]"),
+            Self::Recommended => fmt.write_str("recommended"),
+        }
+    }
+}
+
+/// A configuration that contributes to the settings of a file.
+struct Layer {
+    source: Source,
+    configuration: Configuration,
+}
+
+/// The settings that apply to a file, with the source of each of them.
+struct ResolvedConfiguration {
+    /// The configuration files, and the overrides, that apply to the file,
+    /// in order of precedence.
+    sources: Vec<Source>,
+    /// The value and the source of each setting, by path.
+    settings: BTreeMap<String, (Value, Source)>,
+}
+
+impl ResolvedConfiguration {
+    /// Assigns the settings that the workspace applies to a file to their
+    /// source.
+    ///
+    /// `layers` are the configurations that were merged into the
+    /// configuration of the file, in the order in which they apply. The
+    /// overrides of the configuration of the file are the ones of the layers,
+    /// in the same order.
+    fn new(file_configuration: GetFileConfigurationResult, layers: &[Layer]) -> Self {
+        let GetFileConfigurationResult {
+            configuration,
+            overrides: matching_overrides,
+            ..
+        } = file_configuration;
+
+        let mut sources = Vec::new();
+        let mut setting_sources = BTreeMap::new();
+        let mut override_sources = Vec::new();
+        for layer in layers {
+            sources.push(layer.source.clone());
+            record_sources(
+                &to_value(&layer.configuration),
+                &layer.source,
+                &mut setting_sources,
+            );
+
+            let file = match &layer.source {
+                Source::File { path, .. } => path.clone(),
+                Source::EditorConfig => ".editorconfig".to_string(),
+                Source::Override { .. } | Source::Recommended => continue,
+            };
+            let count = layer
+                .configuration
+                .overrides
+                .as_ref()
+                .map_or(0, |overrides| overrides.0.len());
+            override_sources.extend((0..count).map(|index| Source::Override {
+                path: file.clone(),
+                index,
+            }));
+        }
+
+        let mut value = to_value(&configuration);
+        let overrides = configuration.overrides.unwrap_or_default();
+        for index in matching_overrides {
+            let (Some(pattern), Some(source)) =
+                (overrides.0.get(index), override_sources.get(index))
+            else {
+                continue;
+            };
+
+            let mut pattern = to_value(pattern);
+            if let Value::Object(pattern) = &mut pattern {
+                pattern.remove("includes");
+            }
+            record_sources(&pattern, source, &mut setting_sources);
+            merge_values(&mut value, pattern, "");
+            sources.push(source.clone());
+        }
+
+        let mut settings = BTreeMap::new();
+        let mut values = BTreeMap::new();
+        flatten(&value, "", &mut values);
+        for (key, value) in values {
+            if let Some(source) = setting_sources.remove(&key) {
+                settings.insert(key, (value, source));
+            }
+        }
+
+        // The rules that aren't configured, but are enabled by default
+        let rules = value
+            .pointer("/linter/rules")
+            .and_then(|rules| serde_json::from_value::<Rules>(rules.clone()).ok())
+            .unwrap_or_default();
+        for rule in rules.as_enabled_rules() {
+            let RuleFilter::Rule(group, rule) = rule else {
+                continue;
+            };
+            let group_key = format!("linter.rules.{group}");
+            let key = format!("{group_key}.{rule}");
+            if settings.contains_key(&key) {
+                continue;
+            }
+            // A rule can be enabled by the severity of its group
+            let setting = settings
+                .get(&group_key)
+                .cloned()
+                .unwrap_or_else(|| (Value::String("on".to_string()), Source::Recommended));
+            settings.insert(key, setting);
+        }
+
+        Self { sources, settings }
+    }
+}
+
+/// Loads the configuration file found from `configuration_path`, and the
+/// files that it extends, in the order in which they apply.
+///
+/// If the configuration file extends the root configuration, the layers of
+/// the root configuration apply first, like [load_configuration] does.
+fn load_file_layers(
+    fs: &dyn FsWithResolverProxy,
+    configuration_path: ConfigurationPathHint,
+    seek_root: bool,
+    working_directory: &Utf8Path,
+) -> Result<Vec<Layer>, WorkspaceError> {
+    let Some(payload) = read_config(fs, configuration_path, seek_root)? else {
+        return Ok(Vec::new());
+    };
+    let directory = payload
+        .configuration_file_path
+        .parent()
+        .map(Utf8Path::to_path_buf);
+    let layers = load_layers(fs, payload, working_directory)?;
+    let extends_root = layers
+        .last()
+        .is_some_and(|layer| layer.configuration.extends_root());
+    match directory {
+        Some(directory) if seek_root && extends_root => {
+            let root = read_config(fs, ConfigurationPathHint::FromWorkspace(directory), true)?;
+            let mut root_layers = match root {
+                Some(payload) => load_layers(fs, payload, working_directory)?,
+                None => Vec::new(),
+            };
+            root_layers.extend(layers);
+            Ok(root_layers)
+        }
+        _ => Ok(layers),
+    }
+}
+
+/// Loads the configuration file of `payload`, and the files that it extends,
+/// in the order in which they apply.
+///
+/// The diagnostics of the files are ignored, the workspace reports them.
+fn load_layers(
+    fs: &dyn FsWithResolverProxy,
+    payload: ConfigurationPayload,
+    working_directory: &Utf8Path,
+) -> Result<Vec<Layer>, WorkspaceError> {
+    let ConfigurationPayload {
+        deserialized,
+        configuration_file_path,
+        external_resolution_base_path,
+    } = payload;
+    let Some(mut configuration) = deserialized.into_deserialized() else {
+        return Ok(Vec::new());
+    };
+
+    let file = display_path(&configuration_file_path, working_directory);
+    let mut layers = Vec::new();
+    let extended = configuration.deserialize_extends(
+        fs,
+        configuration_file_path
+            .parent()
+            .expect("file path should have a parent"),
+        &external_resolution_base_path,
+    )?;
+    for (extended_file_path, deserialized) in extended {
+        if let Some(extended_configuration) = deserialized.into_deserialized() {
+            layers.push(Layer {
+                source: Source::File {
+                    path: display_path(&extended_file_path, working_directory),
+                    extended_by: Some(file.clone()),
+                },
+                configuration: extended_configuration,
+            });
+        }
+    }
+    layers.push(Layer {
+        source: Source::File {
+            path: file,
+            extended_by: None,
+        },
+        configuration,
+    });
+
+    Ok(layers)
+}
+
+fn to_value(value: &impl serde::Serialize) -> Value {
+    serde_json::to_value(value).unwrap_or_default()
+}
+
+/// Returns whether `key` is the path of a setting that is printed as a whole,
+/// instead of being split into the settings that it contains: the
+/// configuration of a rule, with its severity and its options, is printed on
+/// a single line.
+fn is_setting(key: &str) -> bool {
+    let segments: Vec<_> = key.split('.').collect();
+    matches!(
+        segments.as_slice(),
+        ["linter", "rules", _, _] | ["assist", "actions", _, _]
+    )
+}
+
+fn child_key(key: &str, name: &str) -> String {
+    if key.is_empty() {
+        name.to_string()
+    } else {
+        format!("{key}.{name}")
+    }
+}
+
+/// Collects the settings of `value`, by path.
+fn flatten(value: &Value, key: &str, settings: &mut BTreeMap<String, Value>) {
+    match value {
+        Value::Object(object) if !is_setting(key) => {
+            for (name, value) in object {
+                flatten(value, &child_key(key, name), settings);
+            }
+        }
+        Value::Null => {}
+        _ => {
+            settings.insert(key.to_string(), value.clone());
+        }
+    }
+}
+
+/// Records `source` as the source of the settings of `value`.
+fn record_sources(value: &Value, source: &Source, sources: &mut BTreeMap<String, Source>) {
+    let mut settings = BTreeMap::new();
+    flatten(value, "", &mut settings);
+    for key in settings.into_keys() {
+        sources.insert(key, source.clone());
+    }
+}
+
+/// Applies the settings of an override to `target`.
+fn merge_values(target: &mut Value, value: Value, key: &str) {
+    match (target, value) {
+        (Value::Object(target), Value::Object(object)) if !is_setting(key) => {
+            for (name, value) in object {
+                let child_key = child_key(key, &name);
+                merge_values(target.entry(name).or_insert(Value::Null), value, &child_key);
+            }
+        }
+        (target, value) => *target = value,
+    }
+}
+
+fn display_path(path: &Utf8Path, working_directory: &Utf8Path) -> String {
+    path.strip_prefix(working_directory)
+        .unwrap_or(path)
+        .to_string()
+}
+
+/// Returns the name of the section of the configuration that is specific to
+/// the language of `path`.
+fn language_of(path: &Utf8Path) -> Option<&'static str> {
+    match DocumentFileSource::from_path(path) {
+        DocumentFileSource::Js(_) => Some("javascript"),
+        DocumentFileSource::Json(_) => Some("json"),
+        DocumentFileSource::Css(_) => Some("css"),
+        DocumentFileSource::Graphql(_) => Some("graphql"),
+        DocumentFileSource::Html(_) => Some("html"),
+        DocumentFileSource::Grit(_) => Some("grit"),
+        DocumentFileSource::Markdown(_) => Some("markdown"),
+        DocumentFileSource::Yaml(_) => Some("yaml"),
+        DocumentFileSource::Ignore | DocumentFileSource::Unknown => None,
+    }
+}
+
+struct PrintResolvedConfiguration<'a> {
+    path: String,
+    language: Option<&'static str>,
+    sources: &'a [Source],
+    settings: &'a BTreeMap<String, (Value, Source)>,
+}
+
+impl Display for PrintResolvedConfiguration<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        fmt.write_markup(markup! {
+            "Configuration of "<Emphasis>{self.path}</Emphasis>"\n\n"
+            <Emphasis>"Configuration files and overrides"</Emphasis>"\n"
+        })?;
+        if self.sources.is_empty() {
+            fmt.write_markup(markup! {
+                "  "<Dim>"None, the default configuration applies."</Dim>"\n"
+            })?;
+        }
+        for source in self.sources {
+            match source {
+                Source::File {
+                    extended_by: Some(extended_by),
+                    ..
+                } => fmt.write_markup(markup! {
+                    "  "{source}<Dim>" (extended by "{extended_by}")"</Dim>"\n"
+                })?,
+                _ => fmt.write_markup(markup! {
+                    "  "{source}"\n"
+                })?,
+            }
+        }
+
+        for (title, section) in [
+            ("Formatter", "formatter"),
+            ("Linter", "linter"),
+            ("Assist", "assist"),
+        ] {
+            fmt.write_markup(markup! {
+                "\n"<Emphasis>{title}</Emphasis>"\n"
+            })?;
+            let language_section = self
+                .language
+                .map(|language| format!("{language}.{section}."));
+            let settings = self.settings.iter().filter(|(key, _)| {
+                key.strip_prefix(section)
+                    .is_some_and(|key| key.starts_with('.'))
+                    || language_section
+                        .as_ref()
+                        .is_some_and(|language_section| key.starts_with(language_section))
+            });
+            let mut is_empty = true;
+            for (key, (value, source)) in settings {
+                is_empty = false;
+                let value = value.to_string();
+                fmt.write_markup(markup! {
+                    "  "{key}": "{value}<Dim>" ("{source}")"</Dim>"\n"
+                })?;
+            }
+            if is_empty {
+                fmt.write_markup(markup! {
+                    "  "<Dim>"The default settings apply."</Dim>"\n"
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+}
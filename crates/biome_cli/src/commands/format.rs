@@ -1,12 +1,14 @@
 use crate::cli_options::CliOptions;
 use crate::commands::{CommandRunner, LoadEditorConfig, get_files_to_process_with_cli_options};
-use crate::{CliDiagnostic, Execution, TraversalMode};
+use crate::{CacheOptions, CliDiagnostic, Execution, TraversalMode};
 use biome_configuration::css::CssFormatterConfiguration;
 use biome_configuration::graphql::GraphqlFormatterConfiguration;
 use biome_configuration::html::HtmlFormatterConfiguration;
 use biome_configuration::javascript::JsFormatterConfiguration;
 use biome_configuration::json::JsonFormatterConfiguration;
+use biome_configuration::markdown::MarkdownFormatterConfiguration;
 use biome_configuration::vcs::VcsConfiguration;
+use biome_configuration::yaml::YamlFormatterConfiguration;
 use biome_configuration::{Configuration, FilesConfiguration, FormatterConfiguration};
 use biome_console::Console;
 use biome_deserialize::Merge;
@@ -21,12 +23,16 @@ pub(crate) struct FormatCommandPayload {
     pub(crate) css_formatter: Option<CssFormatterConfiguration>,
     pub(crate) graphql_formatter: Option<GraphqlFormatterConfiguration>,
     pub(crate) html_formatter: Option<HtmlFormatterConfiguration>,
+    pub(crate) markdown_formatter: Option<MarkdownFormatterConfiguration>,
+    pub(crate) yaml_formatter: Option<YamlFormatterConfiguration>,
     pub(crate) formatter_configuration: Option<FormatterConfiguration>,
     pub(crate) vcs_configuration: Option<VcsConfiguration>,
     pub(crate) files_configuration: Option<FilesConfiguration>,
     pub(crate) stdin_file_path: Option<String>,
     pub(crate) write: bool,
     pub(crate) fix: bool,
+    pub(crate) cache: Option<CacheOptions>,
+    pub(crate) watch: bool,
     pub(crate) paths: Vec<OsString>,
     pub(crate) staged: bool,
     pub(crate) changed: bool,
@@ -85,6 +91,16 @@ impl CommandRunner for FormatCommandPayload {
             let html = configuration.html.get_or_insert_with(Default::default);
             html.formatter.merge_with(self.html_formatter.clone());
         }
+        if self.markdown_formatter.is_some() {
+            let markdown = configuration.markdown.get_or_insert_with(Default::default);
+            markdown
+                .formatter
+                .merge_with(self.markdown_formatter.clone());
+        }
+        if self.yaml_formatter.is_some() {
+            let yaml = configuration.yaml.get_or_insert_with(Default::default);
+            yaml.formatter.merge_with(self.yaml_formatter.clone());
+        }
 
         if self.javascript_formatter.is_some() {
             let javascript = configuration
@@ -144,6 +160,11 @@ impl CommandRunner for FormatCommandPayload {
             stdin: self.get_stdin(console)?,
             vcs_targeted: (self.staged, self.changed).into(),
         })
-        .set_report(cli_options))
+        .set_report(cli_options)
+        .with_cache(self.cache.clone()))
+    }
+
+    fn should_watch(&self) -> bool {
+        self.watch
     }
 }
@@ -1,13 +1,14 @@
 use super::{FixFileModeOptions, determine_fix_file_mode};
 use crate::cli_options::CliOptions;
 use crate::commands::{CommandRunner, get_files_to_process_with_cli_options};
-use crate::{CliDiagnostic, Execution, TraversalMode};
+use crate::{BaselineMode, CacheOptions, CliDiagnostic, Execution, ProfileOptions, TraversalMode};
 use biome_configuration::analyzer::RuleSelector;
 use biome_configuration::css::CssLinterConfiguration;
 use biome_configuration::graphql::GraphqlLinterConfiguration;
 use biome_configuration::javascript::JsLinterConfiguration;
 use biome_configuration::json::JsonLinterConfiguration;
 use biome_configuration::vcs::VcsConfiguration;
+use biome_configuration::yaml::YamlLinterConfiguration;
 use biome_configuration::{Configuration, FilesConfiguration, LinterConfiguration};
 use biome_console::Console;
 use biome_deserialize::Merge;
@@ -22,6 +23,11 @@ pub(crate) struct LintCommandPayload {
     pub(crate) unsafe_: bool,
     pub(crate) suppress: bool,
     pub(crate) suppression_reason: Option<String>,
+    pub(crate) baseline: Option<String>,
+    pub(crate) write_baseline: Option<String>,
+    pub(crate) cache: Option<CacheOptions>,
+    pub(crate) watch: bool,
+    pub(crate) profile: Option<ProfileOptions>,
     pub(crate) linter_configuration: Option<LinterConfiguration>,
     pub(crate) vcs_configuration: Option<VcsConfiguration>,
     pub(crate) files_configuration: Option<FilesConfiguration>,
@@ -36,6 +42,7 @@ pub(crate) struct LintCommandPayload {
     pub(crate) json_linter: Option<JsonLinterConfiguration>,
     pub(crate) css_linter: Option<CssLinterConfiguration>,
     pub(crate) graphql_linter: Option<GraphqlLinterConfiguration>,
+    pub(crate) yaml_linter: Option<YamlLinterConfiguration>,
 }
 
 impl CommandRunner for LintCommandPayload {
@@ -92,6 +99,10 @@ impl CommandRunner for LintCommandPayload {
             let json = fs_configuration.json.get_or_insert_with(Default::default);
             json.linter.merge_with(self.json_linter.clone());
         }
+        if self.yaml_linter.is_some() {
+            let yaml = fs_configuration.yaml.get_or_insert_with(Default::default);
+            yaml.linter.merge_with(self.yaml_linter.clone());
+        }
 
         Ok(fs_configuration)
     }
@@ -134,6 +145,17 @@ impl CommandRunner for LintCommandPayload {
             suppress: self.suppress,
             suppression_reason: self.suppression_reason.clone(),
         })?;
+        let baseline = match (&self.baseline, &self.write_baseline) {
+            (Some(_), Some(_)) => {
+                return Err(CliDiagnostic::incompatible_arguments(
+                    "--baseline",
+                    "--write-baseline",
+                ));
+            }
+            (Some(path), None) => Some(BaselineMode::Filter { path: path.into() }),
+            (None, Some(path)) => Some(BaselineMode::Write { path: path.into() }),
+            (None, None) => None,
+        };
         Ok(Execution::new(TraversalMode::Lint {
             fix_file_mode,
             stdin: self.get_stdin(console)?,
@@ -143,7 +165,14 @@ impl CommandRunner for LintCommandPayload {
             suppress: self.suppress,
             suppression_reason: self.suppression_reason.clone(),
             skip_parse_errors: cli_options.skip_parse_errors,
+            baseline,
         })
-        .set_report(cli_options))
+        .set_report(cli_options)
+        .with_cache(self.cache.clone())
+        .with_profile(self.profile.clone()))
+    }
+
+    fn should_watch(&self) -> bool {
+        self.watch
     }
 }
@@ -1,21 +1,30 @@
 use crate::changed::{get_changed_files, get_staged_files};
 use crate::cli_options::{CliOptions, CliReporter, ColorsArg, cli_options};
+use crate::commands::rules::RulesLanguage;
 use crate::commands::scan_kind::get_forced_scan_kind;
-use crate::execute::Stdin;
+use crate::execute::{ProfileFormat, Stdin};
 use crate::logging::LoggingKind;
 use crate::{
     CliDiagnostic, CliSession, Execution, LoggingLevel, TraversalMode, VERSION, execute_mode,
     setup_cli_subscriber,
 };
+use biome_analyze::{FixKind, RuleDomain};
 use biome_configuration::analyzer::assist::AssistEnabled;
-use biome_configuration::analyzer::{LinterEnabled, RuleSelector};
+use biome_configuration::analyzer::{LinterEnabled, RuleGroup, RuleSelector};
 use biome_configuration::css::{CssFormatterConfiguration, CssLinterConfiguration};
 use biome_configuration::formatter::FormatterEnabled;
 use biome_configuration::graphql::{GraphqlFormatterConfiguration, GraphqlLinterConfiguration};
 use biome_configuration::html::{HtmlFormatterConfiguration, html_formatter_configuration};
 use biome_configuration::javascript::{JsFormatterConfiguration, JsLinterConfiguration};
 use biome_configuration::json::{JsonFormatterConfiguration, JsonLinterConfiguration};
+use biome_configuration::markdown::{
+    MarkdownFormatterConfiguration, markdown_formatter_configuration,
+};
 use biome_configuration::vcs::VcsConfiguration;
+use biome_configuration::yaml::{
+    YamlFormatterConfiguration, YamlLinterConfiguration, yaml_formatter_configuration,
+    yaml_linter_configuration,
+};
 use biome_configuration::{BiomeDiagnostic, Configuration};
 use biome_configuration::{
     FilesConfiguration, FormatterConfiguration, LinterConfiguration, configuration,
@@ -47,6 +56,7 @@ use tracing::info;
 pub(crate) mod check;
 pub(crate) mod ci;
 pub(crate) mod clean;
+pub(crate) mod config;
 pub(crate) mod daemon;
 pub(crate) mod explain;
 pub(crate) mod format;
@@ -54,9 +64,11 @@ pub(crate) mod init;
 pub(crate) mod lint;
 pub(crate) mod migrate;
 pub(crate) mod rage;
+pub(crate) mod rules;
 mod scan_kind;
 pub(crate) mod search;
 pub(crate) mod version;
+mod watch;
 
 #[derive(Debug, Clone, Bpaf)]
 #[bpaf(options, version(VERSION))]
@@ -144,6 +156,41 @@ pub enum BiomeCommand {
         #[bpaf(long("enforce-assist"), argument("true|false"), fallback(true))]
         enforce_assist: bool,
 
+        /// Stores the results of the command in a cache file, so that the next runs with `--cache`
+        /// skip the files that didn't change, and report their diagnostics from the cache.
+        #[bpaf(long("cache"), switch)]
+        cache: bool,
+
+        /// The path of the cache file, which implies `--cache`. Defaults to `.biomecache` in the
+        /// working directory.
+        #[bpaf(long("cache-location"), argument("PATH"))]
+        cache_location: Option<String>,
+
+        /// Keeps running after the first check, and checks again the files that change, together
+        /// with the files that import them.
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+
+        /// Records the time spent by each rule, plugin and phase of the files, and by the scanning
+        /// of the project, then prints the slowest ones.
+        #[bpaf(long("profile"), switch)]
+        profile: bool,
+
+        /// Writes the whole profile to the given file, which implies `--profile`.
+        ///
+        /// Example: `biome check --profile-output=profile.json`
+        #[bpaf(long("profile-output"), argument("PATH"))]
+        profile_output: Option<Utf8PathBuf>,
+
+        /// The format of the file written by `--profile-output`. `chrome-trace` files can be
+        /// opened in `chrome://tracing` or Perfetto.
+        #[bpaf(
+            long("profile-format"),
+            argument("json|chrome-trace"),
+            fallback(ProfileFormat::default())
+        )]
+        profile_format: ProfileFormat,
+
         #[bpaf(external(configuration), hide_usage, optional)]
         configuration: Option<Configuration>,
         #[bpaf(external, hide_usage)]
@@ -201,6 +248,56 @@ pub enum BiomeCommand {
         #[bpaf(long("reason"), argument("STRING"))]
         suppression_reason: Option<String>,
 
+        /// Hides the diagnostics recorded in the given baseline file, and reports the entries of
+        /// the baseline that don't match any diagnostic anymore.
+        ///
+        /// Example: `biome lint --baseline=biome-baseline.json`
+        #[bpaf(long("baseline"), argument("PATH"))]
+        baseline: Option<String>,
+
+        /// Records the current diagnostics in the given baseline file, so that the next runs with
+        /// `--baseline` only report the new ones. The diagnostics recorded in the file aren't
+        /// reported. The entries of the files that aren't linted are kept.
+        ///
+        /// Example: `biome lint --write-baseline=biome-baseline.json`
+        #[bpaf(long("write-baseline"), argument("PATH"))]
+        write_baseline: Option<String>,
+
+        /// Stores the results of the command in a cache file, so that the next runs with `--cache`
+        /// skip the files that didn't change, and report their diagnostics from the cache.
+        #[bpaf(long("cache"), switch)]
+        cache: bool,
+
+        /// The path of the cache file, which implies `--cache`. Defaults to `.biomecache` in the
+        /// working directory.
+        #[bpaf(long("cache-location"), argument("PATH"))]
+        cache_location: Option<String>,
+
+        /// Keeps running after the first lint, and lints again the files that change, together
+        /// with the files that import them.
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+
+        /// Records the time spent by each rule, plugin and phase of the files, and by the scanning
+        /// of the project, then prints the slowest ones.
+        #[bpaf(long("profile"), switch)]
+        profile: bool,
+
+        /// Writes the whole profile to the given file, which implies `--profile`.
+        ///
+        /// Example: `biome lint --profile-output=profile.json`
+        #[bpaf(long("profile-output"), argument("PATH"))]
+        profile_output: Option<Utf8PathBuf>,
+
+        /// The format of the file written by `--profile-output`. `chrome-trace` files can be
+        /// opened in `chrome://tracing` or Perfetto.
+        #[bpaf(
+            long("profile-format"),
+            argument("json|chrome-trace"),
+            fallback(ProfileFormat::default())
+        )]
+        profile_format: ProfileFormat,
+
         #[bpaf(external(linter_configuration), hide_usage, optional)]
         linter_configuration: Option<LinterConfiguration>,
 
@@ -222,6 +319,9 @@ pub enum BiomeCommand {
         #[bpaf(external(graphql_linter_configuration), optional, hide_usage, hide)]
         graphql_linter: Option<GraphqlLinterConfiguration>,
 
+        #[bpaf(external(yaml_linter_configuration), optional, hide_usage, hide)]
+        yaml_linter: Option<YamlLinterConfiguration>,
+
         #[bpaf(external, hide_usage)]
         cli_options: CliOptions,
 
@@ -287,6 +387,12 @@ pub enum BiomeCommand {
         #[bpaf(external(html_formatter_configuration), optional, hide_usage, hide)]
         html_formatter: Option<HtmlFormatterConfiguration>,
 
+        #[bpaf(external(markdown_formatter_configuration), optional, hide_usage, hide)]
+        markdown_formatter: Option<MarkdownFormatterConfiguration>,
+
+        #[bpaf(external(yaml_formatter_configuration), optional, hide_usage, hide)]
+        yaml_formatter: Option<YamlFormatterConfiguration>,
+
         #[bpaf(external(vcs_configuration), optional, hide_usage)]
         vcs_configuration: Option<VcsConfiguration>,
 
@@ -314,6 +420,10 @@ pub enum BiomeCommand {
         #[bpaf(long("fix"), switch, hide_usage)]
         fix: bool,
 
+        /// Keeps running after the first run, and formats again the files that change.
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+
         /// When set to true, only the files that have been staged (the ones prepared to be committed)
         /// will be linted.
         #[bpaf(long("staged"), switch)]
@@ -353,6 +463,16 @@ pub enum BiomeCommand {
         #[bpaf(long("enforce-assist"), argument("true|false"), fallback(true))]
         enforce_assist: bool,
 
+        /// Stores the results of the command in a cache file, so that the next runs with `--cache`
+        /// skip the files that didn't change, and report their diagnostics from the cache.
+        #[bpaf(long("cache"), switch)]
+        cache: bool,
+
+        /// The path of the cache file, which implies `--cache`. Defaults to `.biomecache` in the
+        /// working directory.
+        #[bpaf(long("cache-location"), argument("PATH"))]
+        cache_location: Option<String>,
+
         #[bpaf(external(configuration), hide_usage, optional)]
         configuration: Option<Configuration>,
         #[bpaf(external, hide_usage)]
@@ -435,6 +555,22 @@ pub enum BiomeCommand {
         sub_command: Option<MigrateSubCommand>,
     },
 
+    /// Shows how the configuration applies to the files of the project.
+    ///
+    /// ### Example
+    ///
+    /// ```shell
+    /// biome config print src/index.ts
+    /// ```
+    #[bpaf(command)]
+    Config {
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+
+        #[bpaf(external(config_sub_command))]
+        sub_command: ConfigSubCommand,
+    },
+
     /// EXPERIMENTAL: Searches for Grit patterns across a project.
     ///
     /// Note: GritQL escapes code snippets using backticks, but most shells
@@ -445,12 +581,18 @@ pub enum BiomeCommand {
     ///
     /// ```shell
     /// biome search '`console.log($message)`' # find all `console.log` invocations
+    /// biome search '`console.log($message)` => `console.info($message)`' --write # rewrite them
     /// ```
     #[bpaf(command)]
     Search {
         #[bpaf(external, hide_usage)]
         cli_options: CliOptions,
 
+        /// Writes the rewrites of the pattern to the files. Without this
+        /// option, the changes are printed as diffs.
+        #[bpaf(long("write"), switch)]
+        write: bool,
+
         #[bpaf(external(files_configuration), optional, hide_usage)]
         files_configuration: Option<FilesConfiguration>,
 
@@ -483,7 +625,9 @@ pub enum BiomeCommand {
 
         /// The GritQL pattern to search for.
         ///
-        /// Note that the search command (currently) does not support rewrites.
+        /// The rewrites of the pattern, such as `pattern => replacement`, are
+        /// previewed as diffs, and applied with `--write`. The rewritten code
+        /// is formatted.
         #[bpaf(positional("PATTERN"))]
         pattern: String,
 
@@ -492,6 +636,22 @@ pub enum BiomeCommand {
         paths: Vec<OsString>,
     },
 
+    /// Shows information about the lint rules.
+    ///
+    /// ### Example
+    ///
+    /// ```shell
+    /// biome rules list --domain=react --fixable
+    /// ```
+    #[bpaf(command)]
+    Rules {
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+
+        #[bpaf(external(rules_sub_command))]
+        sub_command: RulesSubCommand,
+    },
+
     /// Shows documentation of various aspects of the CLI.
     ///
     /// ### Examples
@@ -571,6 +731,60 @@ impl MigrateSubCommand {
     }
 }
 
+#[derive(Debug, Bpaf, Clone)]
+pub enum ConfigSubCommand {
+    /// Prints the formatter, linter and assist settings that apply to a file, after resolving
+    /// `extends`, nested configuration files, `overrides` and `.editorconfig`. Each value is
+    /// printed along with the configuration file, or the override, that sets it.
+    #[bpaf(command)]
+    Print {
+        /// The file to print the settings of.
+        #[bpaf(positional("PATH"))]
+        path: Utf8PathBuf,
+    },
+}
+
+#[derive(Debug, Bpaf, Clone)]
+pub enum RulesSubCommand {
+    /// Lists the lint rules, and whether the base configuration enables them.
+    ///
+    /// Only the configuration file of the project is taken into account: the rules enabled
+    /// by nested configuration files, by `overrides`, or by the dependencies of the project
+    /// aren't reported as enabled.
+    #[bpaf(command)]
+    List {
+        /// Only lists the rules of a language: `js`, `jsx`, `ts`, `json`, `css`, `graphql` or
+        /// `yaml`.
+        /// The rules of JSX and TypeScript files are listed with `js`.
+        #[bpaf(long("language"), argument("LANGUAGE"), optional)]
+        language: Option<RulesLanguage>,
+
+        /// Only lists the rules of a group, for example `suspicious`.
+        #[bpaf(long("group"), argument("GROUP"), optional)]
+        group: Option<RuleGroup>,
+
+        /// Only lists the rules of a domain, for example `react`.
+        #[bpaf(long("domain"), argument("DOMAIN"), optional)]
+        domain: Option<RuleDomain>,
+
+        /// Only lists the recommended rules.
+        #[bpaf(long("recommended"), switch)]
+        recommended: bool,
+
+        /// Only lists the rules that provide a fix.
+        #[bpaf(long("fixable"), switch)]
+        fixable: bool,
+
+        /// Only lists the rules that provide a fix of this kind: `safe`, `unsafe` or `none`.
+        #[bpaf(long("fix-kind"), argument("KIND"), optional)]
+        fix_kind: Option<FixKind>,
+
+        /// Prints the rules as JSON.
+        #[bpaf(long("json"), switch)]
+        json: bool,
+    },
+}
+
 impl BiomeCommand {
     const fn cli_options(&self) -> Option<&CliOptions> {
         match self {
@@ -581,6 +795,8 @@ impl BiomeCommand {
             | Self::Ci { cli_options, .. }
             | Self::Format { cli_options, .. }
             | Self::Migrate { cli_options, .. }
+            | Self::Config { cli_options, .. }
+            | Self::Rules { cli_options, .. }
             | Self::Search { cli_options, .. } => Some(cli_options),
             Self::LspProxy { .. }
             | Self::Start { .. }
@@ -620,6 +836,15 @@ impl BiomeCommand {
         }
     }
 
+    pub const fn should_watch(&self) -> bool {
+        match self {
+            Self::Check { watch, .. } | Self::Lint { watch, .. } | Self::Format { watch, .. } => {
+                *watch
+            }
+            _ => false,
+        }
+    }
+
     pub const fn should_use_server(&self) -> bool {
         match self.cli_options() {
             Some(cli_options) => cli_options.use_server,
@@ -814,23 +1039,44 @@ pub(crate) trait CommandRunner: Sized {
     const COMMAND_NAME: &'static str;
 
     /// The main command to use.
-    fn run(&mut self, session: CliSession, cli_options: &CliOptions) -> Result<(), CliDiagnostic> {
+    fn run(
+        &mut self,
+        mut session: CliSession,
+        cli_options: &CliOptions,
+    ) -> Result<(), CliDiagnostic> {
         setup_cli_subscriber(
             cli_options.log_file.as_deref(),
             cli_options.log_level,
             cli_options.log_kind,
         );
+        self.check_incompatible_arguments()?;
+        if self.should_watch() {
+            if cli_options.use_server {
+                return Err(CliDiagnostic::incompatible_arguments("watch", "use-server"));
+            }
+            if self.get_stdin_file_path().is_some() {
+                return Err(CliDiagnostic::incompatible_arguments(
+                    "watch",
+                    "stdin-file-path",
+                ));
+            }
+        }
+        // The watcher is only available when the CLI owns the workspace
+        let watcher = session.watcher.take().filter(|_| self.should_watch());
         let console = &mut *session.app.console;
         let workspace = &*session.app.workspace;
         let fs = workspace.fs();
-        self.check_incompatible_arguments()?;
+        let configured_workspace = self.configure_workspace(fs, console, workspace, cli_options)?;
+        if let Some(changes) = watcher {
+            return watch::run(self, session, cli_options, configured_workspace, changes);
+        }
         let ConfiguredWorkspace {
             execution,
             paths,
             duration,
             configuration_files,
             project_key,
-        } = self.configure_workspace(fs, console, workspace, cli_options)?;
+        } = configured_workspace;
         execute_mode(
             execution,
             session,
@@ -878,7 +1124,23 @@ pub(crate) trait CommandRunner: Sized {
             .map(BiomePath::from)
             .unwrap_or_default();
 
-        let execution = self.get_execution(cli_options, console, workspace)?;
+        let mut execution = self.get_execution(cli_options, console, workspace)?;
+        if let Some(profile) = execution.as_profile() {
+            // The profile is recorded by the workspace, which runs in another
+            // process when using the daemon
+            if cli_options.use_server {
+                return Err(CliDiagnostic::incompatible_arguments(
+                    "profile",
+                    "use-server",
+                ));
+            }
+            profile.enable();
+        }
+        // The configuration is moved to the workspace, but the cache needs it
+        // to compute its hash once the nested configuration files are known
+        let cache_configuration = execution
+            .as_cache()
+            .map(|_| (configuration.clone(), configuration_dir_path.clone()));
 
         let params = if let TraversalMode::Lint { only, skip, .. } = execution.traversal_mode() {
             OpenProjectParams {
@@ -912,6 +1174,12 @@ pub(crate) trait CommandRunner: Sized {
                 open_project_result.scan_kind
             }
         });
+        // The watcher only watches the folders that are scanned
+        let scan_kind = if self.should_watch() && scan_kind == ScanKind::NoScanner {
+            ScanKind::KnownFiles
+        } else {
+            scan_kind
+        };
 
         let result = workspace.update_settings(UpdateSettingsParams {
             project_key: open_project_result.project_key,
@@ -935,7 +1203,7 @@ pub(crate) trait CommandRunner: Sized {
         let result = workspace.scan_project_folder(ScanProjectFolderParams {
             project_key: open_project_result.project_key,
             path: Some(project_path.clone()),
-            watch: cli_options.use_server,
+            watch: cli_options.use_server || self.should_watch(),
             force: false, // TODO: Maybe we'll want a CLI flag for this.
             scan_kind,
         })?;
@@ -948,6 +1216,17 @@ pub(crate) trait CommandRunner: Sized {
             )?;
         }
 
+        if let (Some(cache), Some((configuration, configuration_dir_path))) =
+            (execution.as_cache_mut(), cache_configuration)
+        {
+            cache.hash_configuration(
+                fs,
+                &configuration,
+                configuration_dir_path.as_deref(),
+                &result.configuration_files,
+            );
+        }
+
         Ok(ConfiguredWorkspace {
             execution,
             paths,
@@ -1024,6 +1303,12 @@ pub(crate) trait CommandRunner: Sized {
     fn should_validate_configuration_diagnostics(&self) -> bool {
         true
     }
+
+    /// Whether the command should keep running and process again the files
+    /// that change.
+    fn should_watch(&self) -> bool {
+        false
+    }
 }
 
 pub(crate) struct ConfiguredWorkspace {
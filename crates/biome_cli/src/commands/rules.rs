@@ -0,0 +1,360 @@
+//! The `rules` command, which lists the lint rules of Biome.
+
+use crate::cli_options::CliOptions;
+use crate::commands::{RulesSubCommand, validate_configuration_diagnostics};
+use crate::{CliDiagnostic, CliSession};
+use biome_analyze::{FixKind, RuleDomain, RuleFilter, RuleMetadata};
+use biome_configuration::Configuration;
+use biome_configuration::analyzer::{RuleDomainValue, RuleGroup};
+use biome_console::fmt::{Display, Formatter};
+use biome_console::{ConsoleExt, markup};
+use biome_diagnostics::Severity;
+use biome_service::configuration::load_configuration;
+use biome_service::documentation::{ExplainRule, rules_metadata};
+use rustc_hash::FxHashSet;
+use serde::Serialize;
+use std::io;
+use std::str::FromStr;
+
+/// The languages that the rules can be filtered by.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RulesLanguage {
+    /// JavaScript, including JSX and TypeScript
+    Js,
+    Jsx,
+    Ts,
+    Json,
+    Css,
+    Graphql,
+    Yaml,
+}
+
+impl RulesLanguage {
+    /// Returns whether the rules written for `language` apply to this
+    /// language.
+    fn matches(self, language: &str) -> bool {
+        match self {
+            Self::Js => matches!(language, "js" | "jsx" | "ts"),
+            Self::Jsx => language == "jsx",
+            Self::Ts => language == "ts",
+            Self::Json => language == "json",
+            Self::Css => language == "css",
+            Self::Graphql => language == "graphql",
+            Self::Yaml => language == "yaml",
+        }
+    }
+}
+
+impl FromStr for RulesLanguage {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "js" => Ok(Self::Js),
+            "jsx" => Ok(Self::Jsx),
+            "ts" => Ok(Self::Ts),
+            "json" => Ok(Self::Json),
+            "css" => Ok(Self::Css),
+            "graphql" => Ok(Self::Graphql),
+            "yaml" => Ok(Self::Yaml),
+            _ => Err("Invalid language, valid values are: js, jsx, ts, json, css, graphql, yaml"),
+        }
+    }
+}
+
+pub(crate) fn rules(
+    session: CliSession,
+    cli_options: &CliOptions,
+    sub_command: RulesSubCommand,
+) -> Result<(), CliDiagnostic> {
+    match sub_command {
+        RulesSubCommand::List {
+            language,
+            group,
+            domain,
+            recommended,
+            fixable,
+            fix_kind,
+            json,
+        } => list(
+            session,
+            cli_options,
+            RulesFilter {
+                language,
+                group,
+                domain,
+                recommended,
+                fixable,
+                fix_kind,
+            },
+            json,
+        ),
+    }
+}
+
+/// The filters of the `rules list` command.
+struct RulesFilter {
+    language: Option<RulesLanguage>,
+    group: Option<RuleGroup>,
+    domain: Option<RuleDomain>,
+    recommended: bool,
+    fixable: bool,
+    fix_kind: Option<FixKind>,
+}
+
+impl RulesFilter {
+    fn matches(&self, rule: &ExplainRule) -> bool {
+        let metadata = rule.metadata();
+        self.language
+            .is_none_or(|language| language.matches(metadata.language))
+            && self
+                .group
+                .is_none_or(|group| group.as_str() == rule.group())
+            && self
+                .domain
+                .is_none_or(|domain| metadata.domains.contains(&domain))
+            && (!self.recommended || metadata.recommended)
+            && (!self.fixable || metadata.fix_kind != FixKind::None)
+            && self
+                .fix_kind
+                .is_none_or(|fix_kind| metadata.fix_kind == fix_kind)
+    }
+}
+
+/// Handler of the `rules list` command.
+fn list(
+    session: CliSession,
+    cli_options: &CliOptions,
+    filter: RulesFilter,
+    json: bool,
+) -> Result<(), CliDiagnostic> {
+    let CliSession { app, .. } = session;
+    let loaded_configuration =
+        load_configuration(app.workspace.fs(), cli_options.as_configuration_path_hint())?;
+    validate_configuration_diagnostics(&loaded_configuration, app.console, cli_options.verbose)?;
+    let enabled_rules = EnabledRules::new(&loaded_configuration.configuration);
+
+    let mut rules: Vec<_> = rules_metadata()
+        .into_values()
+        .filter(|rule| rule.category() == "lint" && filter.matches(rule))
+        .map(|rule| {
+            let is_enabled = enabled_rules.is_enabled(&rule);
+            (rule, is_enabled)
+        })
+        .collect();
+    rules.sort_by_key(|(rule, _)| (rule.group(), rule.metadata().name));
+
+    if json {
+        let rules = JsonRules {
+            rules: rules
+                .iter()
+                .map(|(rule, is_enabled)| JsonRule::new(rule, *is_enabled))
+                .collect(),
+        };
+        app.console.log(markup! {{rules}});
+    } else {
+        app.console.log(markup! {{ListRules(&rules)}});
+    }
+
+    Ok(())
+}
+
+/// Computes the rules enabled by the base configuration of the project, which
+/// doesn't include `overrides` and the domains enabled by the dependencies of
+/// the project.
+struct EnabledRules<'a> {
+    is_linter_enabled: bool,
+    rules: FxHashSet<RuleFilter<'static>>,
+    configuration: &'a Configuration,
+}
+
+impl<'a> EnabledRules<'a> {
+    fn new(configuration: &'a Configuration) -> Self {
+        Self {
+            is_linter_enabled: configuration
+                .linter
+                .as_ref()
+                .is_none_or(|linter| linter.is_enabled()),
+            rules: configuration.get_linter_rules().as_enabled_rules(),
+            configuration,
+        }
+    }
+
+    fn is_enabled(&self, rule: &ExplainRule) -> bool {
+        if !self.is_linter_enabled {
+            return false;
+        }
+
+        let metadata = rule.metadata();
+        let mut is_enabled = self
+            .rules
+            .contains(&RuleFilter::Rule(rule.group(), metadata.name));
+
+        // Nursery rules are enabled only from their group
+        if rule.group() == "nursery" {
+            return is_enabled;
+        }
+        let Some(domains) = self.configuration.get_linter_domains() else {
+            return is_enabled;
+        };
+        for domain in metadata.domains {
+            match domains.get(domain) {
+                Some(RuleDomainValue::All) => is_enabled = true,
+                Some(RuleDomainValue::Recommended) if metadata.recommended => is_enabled = true,
+                Some(RuleDomainValue::None) => return false,
+                Some(RuleDomainValue::Recommended) | None => {}
+            }
+        }
+        is_enabled
+    }
+}
+
+struct ListRules<'a>(&'a [(ExplainRule, bool)]);
+
+impl Display for ListRules<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let names: Vec<_> = self
+            .0
+            .iter()
+            .map(|(rule, _)| format!("{}/{}", rule.group(), rule.metadata().name))
+            .collect();
+        let width = names.iter().map(String::len).max().unwrap_or_default();
+
+        for ((rule, is_enabled), name) in self.0.iter().zip(names) {
+            let metadata = rule.metadata();
+            let name = format!("{name:<width$}");
+            let language = format!("{:<7}", metadata.language);
+            if *is_enabled {
+                fmt.write_markup(markup! { <Success>"enabled "</Success> })?;
+            } else {
+                fmt.write_markup(markup! { <Dim>"disabled"</Dim> })?;
+            }
+            fmt.write_markup(markup! {
+                "  "<Emphasis>{name}</Emphasis>"  "{language}
+                <Dim>{RuleTags(metadata)}</Dim>"\n"
+            })?;
+        }
+
+        let count = self.0.len();
+        let enabled = self.0.iter().filter(|(_, is_enabled)| *is_enabled).count();
+        fmt.write_markup(markup! {
+            "\n"{count}" rules, "{enabled}" enabled in the base configuration."
+        })
+    }
+}
+
+/// Prints whether the rule is recommended, its fix and its domains.
+struct RuleTags<'a>(&'a RuleMetadata);
+
+impl Display for RuleTags<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let metadata = self.0;
+        let mut tags = Vec::new();
+        if metadata.recommended {
+            tags.push("recommended".to_string());
+        }
+        match metadata.fix_kind {
+            FixKind::None => {}
+            FixKind::Safe => tags.push("safe fix".to_string()),
+            FixKind::Unsafe => tags.push("unsafe fix".to_string()),
+        }
+        if !metadata.domains.is_empty() {
+            let domains: Vec<_> = metadata
+                .domains
+                .iter()
+                .map(|domain| domain.as_str())
+                .collect();
+            tags.push(format!("domains: {}", domains.join(", ")));
+        }
+        if metadata.deprecated.is_some() {
+            tags.push("deprecated".to_string());
+        }
+        fmt.write_str(&tags.join(", "))
+    }
+}
+
+#[derive(Serialize)]
+struct JsonRules {
+    rules: Vec<JsonRule>,
+}
+
+impl Display for JsonRules {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let content = serde_json::to_string(&self)?;
+        fmt.write_str(content.as_str())
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonRule {
+    name: &'static str,
+    group: &'static str,
+    /// The category of the diagnostics of the rule, for example
+    /// `lint/suspicious/noDebugger`
+    category: String,
+    language: &'static str,
+    version: &'static str,
+    recommended: bool,
+    severity: Severity,
+    fix_kind: &'static str,
+    domains: Vec<&'static str>,
+    sources: Vec<JsonRuleSource>,
+    deprecated: Option<&'static str>,
+    /// Whether the configuration file of the project enables the rule, without
+    /// taking `overrides` and the dependencies of the project into account
+    enabled_in_base_configuration: bool,
+}
+
+impl JsonRule {
+    fn new(rule: &ExplainRule, enabled_in_base_configuration: bool) -> Self {
+        let metadata = rule.metadata();
+        Self {
+            name: metadata.name,
+            group: rule.group(),
+            category: format!("{}/{}/{}", rule.category(), rule.group(), metadata.name),
+            language: metadata.language,
+            version: metadata.version,
+            recommended: metadata.recommended,
+            severity: metadata.severity,
+            fix_kind: match metadata.fix_kind {
+                FixKind::None => "none",
+                FixKind::Safe => "safe",
+                FixKind::Unsafe => "unsafe",
+            },
+            domains: metadata
+                .domains
+                .iter()
+                .map(|domain| domain.as_str())
+                .collect(),
+            sources: metadata
+                .sources
+                .iter()
+                .map(|source| JsonRuleSource {
+                    source: source.source.to_string(),
+                    rule: source.source.to_namespaced_rule_name(),
+                    url: source.source.to_rule_url(),
+                    kind: if source.kind.is_inspired() {
+                        "inspired"
+                    } else {
+                        "same"
+                    },
+                })
+                .collect(),
+            deprecated: metadata.deprecated,
+            enabled_in_base_configuration,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct JsonRuleSource {
+    /// The name of the tool, for example `ESLint`
+    source: String,
+    /// The name of the rule in the tool
+    rule: String,
+    url: String,
+    /// Whether the rule implements the same logic of the source, or is only
+    /// inspired by it
+    kind: &'static str,
+}
@@ -54,6 +54,7 @@ mod tests {
             suppress: false,
             suppression_reason: None,
             skip_parse_errors: false,
+            baseline: None,
         });
 
         assert_eq!(
@@ -13,6 +13,7 @@ use biome_service::{Workspace, WorkspaceError};
 use std::ffi::OsString;
 
 pub(crate) struct SearchCommandPayload {
+    pub(crate) write: bool,
     pub(crate) files_configuration: Option<FilesConfiguration>,
     pub(crate) paths: Vec<OsString>,
     pub(crate) pattern: String,
@@ -54,7 +55,7 @@ impl CommandRunner for SearchCommandPayload {
     }
 
     fn should_write(&self) -> bool {
-        false
+        self.write
     }
 
     fn get_execution(
@@ -71,6 +72,7 @@ impl CommandRunner for SearchCommandPayload {
             .pattern_id;
         Ok(Execution::new(TraversalMode::Search {
             pattern,
+            write: self.should_write(),
             language: self.language.clone(),
             stdin: self.get_stdin(console)?,
         })
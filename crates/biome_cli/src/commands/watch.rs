@@ -0,0 +1,140 @@
+//! The watch mode of the commands `check`, `lint` and `format`.
+//!
+//! The files are processed again each time the
+//! [workspace watcher](biome_service::WorkspaceWatcher) reports a change. The
+//! workspace, and its module graph, are kept between the runs, and the
+//! results of the previous run are kept in the cache: only the files that
+//! changed, and the files that import them, are processed again, while the
+//! results of the others are replayed, so that the whole report can be
+//! printed again.
+
+use super::{CommandRunner, ConfiguredWorkspace};
+use crate::cli_options::CliOptions;
+use crate::{CliDiagnostic, CliSession, execute_mode};
+use biome_console::{Console, ConsoleExt, markup};
+use biome_diagnostics::{Diagnostic, PrintDiagnostic};
+use biome_fs::BiomePath;
+use biome_service::App;
+use biome_service::workspace::DocumentFileSource;
+use camino::Utf8PathBuf;
+use crossbeam::channel::Receiver;
+use std::time::Duration;
+
+/// How long to wait for other changes after a change is reported. Editors
+/// and tools often write several files at once, or the same file several
+/// times, and they should cause a single run.
+const DEBOUNCE_DURATION: Duration = Duration::from_millis(100);
+
+/// Runs the command, then runs it again after each change, until the watcher
+/// stops.
+pub(crate) fn run(
+    command: &mut impl CommandRunner,
+    session: CliSession,
+    cli_options: &CliOptions,
+    mut configured_workspace: ConfiguredWorkspace,
+    changes: Receiver<Vec<Utf8PathBuf>>,
+) -> Result<(), CliDiagnostic> {
+    let CliSession {
+        app: App { workspace, console },
+        ..
+    } = session;
+    let workspace = &*workspace;
+    let fs = workspace.fs();
+
+    let mut should_run = true;
+    loop {
+        if should_run {
+            console.clear();
+            let ConfiguredWorkspace {
+                execution,
+                paths,
+                duration,
+                configuration_files,
+                project_key,
+            } = &configured_workspace;
+            let result = execute_mode(
+                execution.clone(),
+                CliSession::new(workspace, console)?,
+                cli_options,
+                paths.clone(),
+                *duration,
+                configuration_files.clone(),
+                *project_key,
+            );
+            if let Err(error) = result {
+                print_error(console, &error, cli_options.verbose);
+            }
+            // The scanner only runs once
+            configured_workspace.duration = None;
+        }
+
+        console.log(markup! {
+            <Dim>"Waiting for changes..."</Dim>
+        });
+
+        let Some(changed_paths) = wait_for_changes(&changes) else {
+            return Ok(());
+        };
+        let cache_file_path = configured_workspace
+            .execution
+            .as_cache()
+            .and_then(|cache| cache.file_path(fs));
+        let changed_paths: Vec<_> = changed_paths
+            .into_iter()
+            .filter(|path| cache_file_path.as_ref() != Some(path))
+            .map(BiomePath::from)
+            .filter(can_affect_results)
+            .collect();
+        should_run = !changed_paths.is_empty();
+
+        if changed_paths
+            .iter()
+            .any(|path| path.is_config() || path.is_ignore())
+        {
+            match command.configure_workspace(fs, console, workspace, cli_options) {
+                Ok(configured) => {
+                    configured_workspace = ConfiguredWorkspace {
+                        duration: None,
+                        ..configured
+                    };
+                }
+                Err(error) => {
+                    // The files are processed again once the configuration
+                    // is fixed
+                    print_error(console, &error, cli_options.verbose);
+                    should_run = false;
+                }
+            }
+        }
+    }
+}
+
+/// Returns whether a change to `path` can affect the results of the command:
+/// the path is a file that Biome can handle, a configuration or ignore file,
+/// or a folder.
+fn can_affect_results(path: &BiomePath) -> bool {
+    path.is_config()
+        || path.is_ignore()
+        || path.extension().is_none()
+        || DocumentFileSource::from_path(path) != DocumentFileSource::Unknown
+}
+
+/// Waits for the next change, and for the changes that follow it closely.
+///
+/// Returns [None] if the watcher stopped.
+fn wait_for_changes(changes: &Receiver<Vec<Utf8PathBuf>>) -> Option<Vec<Utf8PathBuf>> {
+    let mut changed_paths = changes.recv().ok()?;
+    while let Ok(paths) = changes.recv_timeout(DEBOUNCE_DURATION) {
+        changed_paths.extend(paths);
+    }
+
+    Some(changed_paths)
+}
+
+fn print_error(console: &mut dyn Console, error: &CliDiagnostic, verbose: bool) {
+    if error.tags().is_verbose() && verbose {
+        console.error(markup! {{PrintDiagnostic::verbose(error)}})
+    } else {
+        console.error(markup! {{PrintDiagnostic::simple(error)}})
+    }
+}
@@ -60,6 +60,8 @@ pub enum CliDiagnostic {
     Report(ReportDiagnostic),
     /// Emitted when there's an error emitted when using stdin mode
     Stdin(StdinDiagnostic),
+    /// Emitted when the baseline file can't be read
+    InvalidBaseline(InvalidBaseline),
 }
 
 #[derive(Debug, Diagnostic)]
@@ -206,6 +208,21 @@ pub struct IoDiagnostic {
     source: Option<Error>,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "baseline/parse",
+    severity = Error,
+    message(
+        description = "The baseline file can't be read: {reason}",
+        message("The baseline file can't be read: "{{&self.reason}})
+    )
+)]
+pub struct InvalidBaseline {
+    #[location(resource)]
+    path: String,
+    reason: String,
+}
+
 #[derive(Debug, Diagnostic)]
 #[diagnostic(
     category = "internalError/io",
@@ -444,6 +461,14 @@ impl CliDiagnostic {
         })
     }
 
+    /// Emitted when the baseline file isn't valid
+    pub fn invalid_baseline(path: impl Into<String>, reason: impl ToString) -> Self {
+        Self::InvalidBaseline(InvalidBaseline {
+            path: path.into(),
+            reason: reason.to_string(),
+        })
+    }
+
     /// Return by the help command when it is called with a subcommand it doesn't know
     pub fn new_unknown_help(command: impl Into<String>) -> Self {
         Self::UnknownCommandHelp(UnknownCommandHelp {
@@ -0,0 +1,335 @@
+//! Baseline files record the diagnostics that a project accepts for now, so
+//! that only the new ones are reported.
+//!
+//! Diagnostics are identified by their category, the file that emits them and
+//! a hash of the code around them. Line numbers aren't part of the
+//! fingerprint, so a diagnostic still matches the baseline when the code above
+//! it changes.
+
+use super::hasher::StableHasher;
+use crate::CliDiagnostic;
+use crate::diagnostics::ReportDiagnostic;
+use biome_console::fmt::Display;
+use biome_console::markup;
+use biome_diagnostics::{Advices, Diagnostic, Error, LogCategory, SerdeJsonError, Visit};
+use biome_fs::{FileSystem, OpenOptions};
+use biome_rowan::TextRange;
+use camino::{Utf8Path, Utf8PathBuf};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Mutex;
+
+/// How the baseline file is used by the `lint` command
+#[derive(Debug, Clone)]
+pub enum BaselineMode {
+    /// The diagnostics that match the baseline are hidden, and the entries of
+    /// the baseline that don't match any diagnostic are reported as stale.
+    Filter { path: Utf8PathBuf },
+    /// The baseline is overwritten with the diagnostics of the current run.
+    /// The entries of the files that weren't linted are kept.
+    Write { path: Utf8PathBuf },
+}
+
+/// The version of the format of the baseline files
+const BASELINE_VERSION: u32 = 1;
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct BaselineFile {
+    version: u32,
+    diagnostics: Vec<BaselineEntry>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct BaselineEntry {
+    /// The path of the file, relative to the working directory
+    path: String,
+    /// The category of the diagnostic, such as `lint/suspicious/noDebugger`
+    category: String,
+    /// A hash of the code around the diagnostic
+    fingerprint: String,
+    /// The number of diagnostics that have the same fingerprint in the file
+    count: u32,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+struct Fingerprint {
+    path: String,
+    category: String,
+    hash: String,
+}
+
+pub(crate) struct Baseline {
+    path: Utf8PathBuf,
+    write: bool,
+    /// In filter mode, the number of diagnostics that are still accepted for
+    /// each fingerprint. In write mode, the number of diagnostics recorded so
+    /// far.
+    entries: Mutex<BTreeMap<Fingerprint, u32>>,
+    /// In write mode, the entries of the existing baseline, which are kept
+    /// for the files that aren't linted by the current run.
+    previous_entries: BTreeMap<Fingerprint, u32>,
+}
+
+impl Baseline {
+    /// Reads the baseline file, relative paths are resolved against the
+    /// working directory.
+    ///
+    /// In write mode, a missing or invalid baseline file is treated as empty,
+    /// since it's going to be overwritten.
+    pub(crate) fn load(fs: &dyn FileSystem, mode: &BaselineMode) -> Result<Self, CliDiagnostic> {
+        let (path, write) = match mode {
+            BaselineMode::Filter { path } => (path, false),
+            BaselineMode::Write { path } => (path, true),
+        };
+        let path = match fs.working_directory() {
+            Some(working_directory) => working_directory.join(path),
+            None => path.clone(),
+        };
+
+        if write {
+            let previous_entries = if fs.path_exists(&path) {
+                read_entries(fs, &path).unwrap_or_default()
+            } else {
+                BTreeMap::new()
+            };
+
+            return Ok(Self {
+                path,
+                write,
+                entries: Mutex::default(),
+                previous_entries,
+            });
+        }
+
+        let entries = read_entries(fs, &path)?;
+        Ok(Self {
+            path,
+            write,
+            entries: Mutex::new(entries),
+            previous_entries: BTreeMap::new(),
+        })
+    }
+
+    /// Returns whether the diagnostic is accepted by the baseline, in which
+    /// case it must not be reported.
+    ///
+    /// In write mode, every diagnostic that has a category is added to the
+    /// baseline, and is accepted.
+    pub(crate) fn accepts(&self, path: &str, content: &str, diagnostic: &Error) -> bool {
+        let Some(category) = diagnostic.category() else {
+            return false;
+        };
+
+        let fingerprint = Fingerprint {
+            path: path.replace('\\', "/"),
+            category: category.name().to_string(),
+            hash: format!(
+                "{:016x}",
+                fingerprint_code(content, diagnostic.location().span)
+            ),
+        };
+
+        let mut entries = self.entries.lock().unwrap();
+        if self.write {
+            *entries.entry(fingerprint).or_default() += 1;
+            return true;
+        }
+
+        match entries.get_mut(&fingerprint) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Writes the baseline file in write mode. In filter mode, it returns a
+    /// diagnostic listing the entries that didn't match any diagnostic.
+    ///
+    /// Only the entries of the files that were processed, or that don't exist
+    /// anymore, are affected by the current run: the other files weren't
+    /// linted, so their entries are kept when writing and aren't stale when
+    /// filtering.
+    pub(crate) fn finish<'a>(
+        &self,
+        fs: &dyn FileSystem,
+        processed_paths: impl IntoIterator<Item = &'a str>,
+    ) -> Result<Option<Error>, CliDiagnostic> {
+        let mut entries = std::mem::take(&mut *self.entries.lock().unwrap());
+
+        let processed_paths: BTreeSet<_> = processed_paths.into_iter().collect();
+        let working_directory = fs.working_directory();
+        let is_linted = |fingerprint: &Fingerprint| {
+            let path = Utf8Path::new(&fingerprint.path);
+            let exists = match working_directory.as_ref() {
+                Some(working_directory) => fs.path_exists(&working_directory.join(path)),
+                None => fs.path_exists(path),
+            };
+            !exists || processed_paths.contains(fingerprint.path.as_str())
+        };
+
+        if self.write {
+            for (fingerprint, count) in &self.previous_entries {
+                if !is_linted(fingerprint) {
+                    entries.insert(fingerprint.clone(), *count);
+                }
+            }
+
+            let file = BaselineFile {
+                version: BASELINE_VERSION,
+                diagnostics: entries
+                    .into_iter()
+                    .map(|(fingerprint, count)| BaselineEntry {
+                        path: fingerprint.path,
+                        category: fingerprint.category,
+                        fingerprint: fingerprint.hash,
+                        count,
+                    })
+                    .collect(),
+            };
+            let mut content = serde_json::to_string_pretty(&file).map_err(|error| {
+                CliDiagnostic::Report(ReportDiagnostic::Serialization(SerdeJsonError::from(error)))
+            })?;
+            content.push('\n');
+
+            let mut baseline_file = fs.open_with_options(
+                &self.path,
+                OpenOptions::default()
+                    .write(true)
+                    .create(true)
+                    .truncate(true),
+            )?;
+            baseline_file.set_content(content.as_bytes())?;
+
+            return Ok(None);
+        }
+
+        let stale_entries: Vec<_> = entries
+            .into_iter()
+            .filter(|(fingerprint, count)| *count > 0 && is_linted(fingerprint))
+            .map(|(fingerprint, count)| {
+                if count > 1 {
+                    format!("{} ({}) x{count}", fingerprint.path, fingerprint.category)
+                } else {
+                    format!("{} ({})", fingerprint.path, fingerprint.category)
+                }
+            })
+            .collect();
+
+        if stale_entries.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Error::from(StaleBaselineDiagnostic {
+            path: self.path.to_string(),
+            advice: StaleEntriesAdvice {
+                entries: stale_entries,
+            },
+        })))
+    }
+}
+
+/// Reads the entries of the baseline file at `path`.
+fn read_entries(
+    fs: &dyn FileSystem,
+    path: &Utf8Path,
+) -> Result<BTreeMap<Fingerprint, u32>, CliDiagnostic> {
+    let content = fs
+        .read_file_from_path(path)
+        .map_err(|error| CliDiagnostic::workspace_error(error.into()))?;
+    let file: BaselineFile = serde_json::from_str(&content)
+        .map_err(|error| CliDiagnostic::invalid_baseline(path.as_str(), error))?;
+    if file.version != BASELINE_VERSION {
+        return Err(CliDiagnostic::invalid_baseline(
+            path.as_str(),
+            format!(
+                "unsupported version {}, expected {BASELINE_VERSION}",
+                file.version
+            ),
+        ));
+    }
+
+    let mut entries = BTreeMap::new();
+    for entry in file.diagnostics {
+        let fingerprint = Fingerprint {
+            path: entry.path,
+            category: entry.category,
+            hash: entry.fingerprint,
+        };
+        *entries.entry(fingerprint).or_default() += entry.count;
+    }
+    Ok(entries)
+}
+
+/// Hashes the code around the range of a diagnostic: the lines that contain
+/// the range, without their indentation, and the code of the range itself.
+fn fingerprint_code(content: &str, range: Option<TextRange>) -> u64 {
+    let code = range.and_then(|range| {
+        let start = usize::from(range.start());
+        let end = usize::from(range.end());
+        let line_start = content
+            .get(..start)?
+            .rfind('\n')
+            .map_or(0, |index| index + 1);
+        let line_end = content
+            .get(end..)?
+            .find('\n')
+            .map_or(content.len(), |index| end + index);
+
+        Some((content.get(line_start..line_end)?, content.get(start..end)?))
+    });
+
+    let mut hasher = StableHasher::default();
+    if let Some((lines, code)) = code {
+        for line in lines.lines() {
+            hasher.write(line.trim());
+        }
+        hasher.write(code);
+    }
+    hasher.finish()
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "baseline/stale",
+    severity = Warning,
+    message(
+        description = "Some entries of the baseline don't match any diagnostic.",
+        message("Some entries of the baseline don't match any diagnostic.")
+    )
+)]
+struct StaleBaselineDiagnostic {
+    #[location(resource)]
+    path: String,
+    #[advice]
+    advice: StaleEntriesAdvice,
+}
+
+#[derive(Debug)]
+struct StaleEntriesAdvice {
+    entries: Vec<String>,
+}
+
+impl Advices for StaleEntriesAdvice {
+    fn record(&self, visitor: &mut dyn Visit) -> std::io::Result<()> {
+        visitor.record_log(
+            LogCategory::Info,
+            &markup! { "These diagnostics were fixed:" },
+        )?;
+        let list: Vec<_> = self
+            .entries
+            .iter()
+            .map(|entry| entry as &dyn Display)
+            .collect();
+        visitor.record_list(&list)?;
+        visitor.record_log(
+            LogCategory::Info,
+            &markup! {
+                "Run the command with "<Emphasis>"--write-baseline"</Emphasis>" to remove them from the baseline."
+            },
+        )
+    }
+}
@@ -0,0 +1,484 @@
+//! The cache stores the results of the files processed by `check`, `lint` and
+//! `ci` when `--cache` is passed, so that the next runs replay them instead of
+//! processing again the files that didn't change.
+//!
+//! The result of a file is reused when its key didn't change. The key is a
+//! hash of the content of the file and of the modules it depends on,
+//! according to the module graph: the rules of the `project` domain inspect
+//! the imported modules, so their diagnostics must be updated when these
+//! modules change. The version of Biome, the configuration, the plugins and
+//! the options of the command are hashed separately: when one of them
+//! changes, the whole cache is discarded.
+//!
+//! In watch mode, the results are also kept in memory between the runs when
+//! `--cache` isn't passed, so that only the files that changed, and the files
+//! that import them, are processed again.
+
+use super::Execution;
+use super::hasher::StableHasher;
+use super::process_file::{DiffKind, FileResult, FileStatus, Message};
+use super::traverse::TraversalOptions;
+use crate::CliDiagnostic;
+use crate::diagnostics::ReportDiagnostic;
+use biome_configuration::Configuration;
+use biome_configuration::plugins::PluginConfiguration;
+use biome_configuration::{ConfigurationPathHint, plugins::Plugins};
+use biome_diagnostics::serde::Diagnostic;
+use biome_diagnostics::{Error, SerdeJsonError};
+use biome_fs::{BiomePath, FileSystem, OpenOptions};
+use biome_resolver::FsWithResolverProxy;
+use biome_service::configuration::load_configuration;
+use biome_service::workspace::GetModuleDependenciesParams;
+use camino::{Utf8Path, Utf8PathBuf};
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+/// The location of the cache file when `--cache-location` isn't passed,
+/// relative to the working directory
+const DEFAULT_CACHE_LOCATION: &str = ".biomecache";
+
+/// The version of the format of the cache files
+const CACHE_VERSION: u32 = 1;
+
+/// Options of the cache, enabled with `--cache` or `--watch`
+#[derive(Debug, Clone)]
+pub struct CacheOptions {
+    storage: CacheStorage,
+    /// The hash of the configuration files and of the plugins they load
+    configuration: u64,
+}
+
+/// Where the results of the files are stored between runs
+#[derive(Debug, Clone)]
+enum CacheStorage {
+    /// The cache file. Its path is relative to the working directory in
+    /// [CacheOptions], and absolute in [FileCache].
+    File(Utf8PathBuf),
+    /// The results are only kept in memory, for the next runs of the watch
+    /// mode
+    Memory(Arc<Mutex<CacheFile>>),
+}
+
+impl CacheOptions {
+    /// Returns the options of the cache when `--cache` or `--cache-location`
+    /// is passed, or when the command runs in watch mode.
+    pub(crate) fn from_arguments(
+        cache: bool,
+        location: Option<String>,
+        watch: bool,
+    ) -> Option<Self> {
+        let storage = if cache || location.is_some() {
+            CacheStorage::File(Utf8PathBuf::from(
+                location.as_deref().unwrap_or(DEFAULT_CACHE_LOCATION),
+            ))
+        } else if watch {
+            CacheStorage::Memory(Arc::default())
+        } else {
+            return None;
+        };
+
+        Some(Self {
+            storage,
+            configuration: 0,
+        })
+    }
+
+    /// Returns the absolute path of the cache file, if the results are
+    /// written to a file.
+    pub(crate) fn file_path(&self, fs: &dyn FileSystem) -> Option<Utf8PathBuf> {
+        match &self.storage {
+            CacheStorage::File(location) => Some(match fs.working_directory() {
+                Some(working_directory) => working_directory.join(location),
+                None => location.clone(),
+            }),
+            CacheStorage::Memory(_) => None,
+        }
+    }
+
+    /// Hashes the configuration of the project, the nested configuration
+    /// files and the plugins they load.
+    pub(crate) fn hash_configuration(
+        &mut self,
+        fs: &dyn FsWithResolverProxy,
+        configuration: &Configuration,
+        configuration_directory: Option<&Utf8Path>,
+        nested_configuration_files: &[BiomePath],
+    ) {
+        let mut hasher = StableHasher::default();
+        hash_configuration(&mut hasher, fs, configuration, configuration_directory);
+
+        for path in nested_configuration_files {
+            match load_configuration(fs, ConfigurationPathHint::FromUser(path.to_path_buf())) {
+                Ok(loaded_configuration) => hash_configuration(
+                    &mut hasher,
+                    fs,
+                    &loaded_configuration.configuration,
+                    loaded_configuration.directory_path.as_deref(),
+                ),
+                Err(_) => hasher.write(&fs.read_file_from_path(path).unwrap_or_default()),
+            }
+        }
+
+        self.configuration = hasher.finish();
+    }
+}
+
+fn hash_configuration(
+    hasher: &mut StableHasher,
+    fs: &dyn FileSystem,
+    configuration: &Configuration,
+    configuration_directory: Option<&Utf8Path>,
+) {
+    hasher.write(&serde_json::to_string(configuration).unwrap_or_default());
+
+    let overrides_plugins = configuration
+        .overrides
+        .iter()
+        .flat_map(|overrides| overrides.0.iter())
+        .filter_map(|pattern| pattern.plugins.as_ref());
+    for plugins in configuration.plugins.iter().chain(overrides_plugins) {
+        hash_plugins(hasher, fs, plugins, configuration_directory);
+    }
+}
+
+fn hash_plugins(
+    hasher: &mut StableHasher,
+    fs: &dyn FileSystem,
+    plugins: &Plugins,
+    configuration_directory: Option<&Utf8Path>,
+) {
+    for plugin in plugins.iter() {
+        match plugin {
+            PluginConfiguration::Path(path) => {
+                let path = match configuration_directory {
+                    Some(directory) => directory.join(path),
+                    None => Utf8PathBuf::from(path),
+                };
+                hasher.write(&fs.read_file_from_path(&path).unwrap_or_default());
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheFile {
+    version: u32,
+    /// A hash of the version of Biome, the configuration and the options of
+    /// the command
+    settings: String,
+    /// The results of the files, by path relative to the working directory
+    files: BTreeMap<String, CacheEntry>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheEntry {
+    /// A hash of the content of the file and of its dependencies
+    key: String,
+    /// The messages sent while processing the file
+    messages: Vec<CachedMessage>,
+    /// The message returned as the status of the file, it's [None] when the
+    /// file is unchanged
+    status: Option<CachedMessage>,
+}
+
+/// A serializable [Message]. The path and the content of the file aren't
+/// stored: they're known when the message is replayed.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum CachedMessage {
+    #[serde(rename_all = "camelCase")]
+    SkippedFixes {
+        skipped_suggested_fixes: u32,
+    },
+    Failure,
+    Error(Diagnostic),
+    #[serde(rename_all = "camelCase")]
+    Diagnostics {
+        diagnostics: Vec<Diagnostic>,
+        skipped_diagnostics: u32,
+    },
+    #[serde(rename_all = "camelCase")]
+    Diff {
+        new: String,
+        diff_kind: DiffKind,
+    },
+}
+
+impl From<&Message> for CachedMessage {
+    fn from(message: &Message) -> Self {
+        match message {
+            Message::SkippedFixes {
+                skipped_suggested_fixes,
+            } => Self::SkippedFixes {
+                skipped_suggested_fixes: *skipped_suggested_fixes,
+            },
+            Message::Failure => Self::Failure,
+            Message::Error(error) => Self::Error(Diagnostic::from_ref(error)),
+            Message::Diagnostics {
+                diagnostics,
+                skipped_diagnostics,
+                ..
+            } => Self::Diagnostics {
+                diagnostics: diagnostics.iter().map(Diagnostic::from_ref).collect(),
+                skipped_diagnostics: *skipped_diagnostics,
+            },
+            Message::Diff { new, diff_kind, .. } => Self::Diff {
+                new: new.clone(),
+                diff_kind: *diff_kind,
+            },
+        }
+    }
+}
+
+impl CachedMessage {
+    fn to_message(&self, file: &CachedFile) -> Message {
+        match self {
+            Self::SkippedFixes {
+                skipped_suggested_fixes,
+            } => Message::SkippedFixes {
+                skipped_suggested_fixes: *skipped_suggested_fixes,
+            },
+            Self::Failure => Message::Failure,
+            Self::Error(diagnostic) => Message::Error(Error::from(diagnostic.clone())),
+            Self::Diagnostics {
+                diagnostics,
+                skipped_diagnostics,
+            } => Message::Diagnostics {
+                file_path: file.biome_path.to_string(),
+                content: file.content.clone(),
+                diagnostics: diagnostics.iter().cloned().map(Error::from).collect(),
+                skipped_diagnostics: *skipped_diagnostics,
+            },
+            Self::Diff { new, diff_kind } => Message::Diff {
+                file_name: file.biome_path.to_string(),
+                old: file.content.clone(),
+                new: new.clone(),
+                diff_kind: *diff_kind,
+            },
+        }
+    }
+}
+
+/// A file that is about to be processed, and its key in the cache
+pub(crate) struct CachedFile {
+    biome_path: BiomePath,
+    /// The path of the file, relative to the working directory
+    path: String,
+    content: String,
+    key: String,
+}
+
+pub(crate) struct FileCache {
+    storage: CacheStorage,
+    working_directory: Option<Utf8PathBuf>,
+    settings: String,
+    /// The results of the previous run
+    previous: BTreeMap<String, CacheEntry>,
+    /// The results of the files processed during this run. The files whose
+    /// result can't be cached are mapped to [None].
+    entries: Mutex<BTreeMap<String, Option<CacheEntry>>>,
+    /// The hashes of the content of the dependencies, which are usually
+    /// shared by many files
+    dependency_hashes: Mutex<FxHashMap<Utf8PathBuf, u64>>,
+}
+
+impl FileCache {
+    /// Reads the results of the previous run. A cache file that can't be
+    /// read, or results that were stored by another version of Biome or with
+    /// other settings, are ignored.
+    pub(crate) fn load(fs: &dyn FileSystem, execution: &Execution, options: &CacheOptions) -> Self {
+        let working_directory = fs.working_directory();
+        let storage = match options.file_path(fs) {
+            Some(path) => CacheStorage::File(path),
+            None => options.storage.clone(),
+        };
+
+        let mut hasher = StableHasher::default();
+        hasher.write(crate::VERSION);
+        hasher.write_u64(options.configuration);
+        hasher.write(&format!("{:?}", execution.traversal_mode()));
+        // The patch reporter computes the changes of `--write` without writing
+        // them, so its results must not be replayed by a run that writes
+        hasher.write(if execution.is_patch() { "patch" } else { "" });
+        let settings = format!("{:016x}", hasher.finish());
+
+        let previous = match &storage {
+            CacheStorage::File(path) => fs
+                .read_file_from_path(path)
+                .ok()
+                .and_then(|content| serde_json::from_str::<CacheFile>(&content).ok()),
+            CacheStorage::Memory(memory) => Some(std::mem::take(&mut *memory.lock().unwrap())),
+        };
+        let previous = previous
+            .filter(|file| file.version == CACHE_VERSION && file.settings == settings)
+            .map(|file| file.files)
+            .unwrap_or_default();
+
+        Self {
+            storage,
+            working_directory,
+            settings,
+            previous,
+            entries: Mutex::default(),
+            dependency_hashes: Mutex::default(),
+        }
+    }
+
+    /// Returns whether `path` is the cache file, which must not be processed.
+    pub(crate) fn is_cache_file(&self, path: &Utf8Path) -> bool {
+        matches!(&self.storage, CacheStorage::File(file_path) if file_path == path)
+    }
+
+    /// Reads the file and computes its key. It returns [None] if the file
+    /// can't be read, in which case it's processed without the cache.
+    pub(crate) fn read(
+        &self,
+        ctx: &TraversalOptions,
+        biome_path: &BiomePath,
+    ) -> Option<CachedFile> {
+        let content = ctx.fs.read_file_from_path(biome_path).ok()?;
+        let dependencies = ctx
+            .workspace
+            .get_module_dependencies(GetModuleDependenciesParams {
+                project_key: ctx.project_key,
+                path: biome_path.clone(),
+            })
+            .ok()?
+            .dependencies;
+
+        let mut hasher = StableHasher::default();
+        hasher.write(&content);
+        for dependency in dependencies {
+            hasher.write(&self.to_relative_path(&dependency));
+            hasher.write_u64(self.dependency_hash(ctx.fs, &dependency));
+        }
+
+        Some(CachedFile {
+            biome_path: biome_path.clone(),
+            path: self.to_relative_path(biome_path),
+            content,
+            key: format!("{:016x}", hasher.finish()),
+        })
+    }
+
+    /// Sends the messages of the file stored in the cache, and returns its
+    /// status. It returns [None] if the cache doesn't have a result for this
+    /// version of the file.
+    pub(crate) fn replay(&self, ctx: &TraversalOptions, file: &CachedFile) -> Option<FileStatus> {
+        let entry = self
+            .previous
+            .get(&file.path)
+            .filter(|entry| entry.key == file.key)?;
+
+        for message in &entry.messages {
+            ctx.push_message(message.to_message(file));
+        }
+
+        Some(match &entry.status {
+            Some(message) => FileStatus::Message(message.to_message(file)),
+            None => FileStatus::Unchanged,
+        })
+    }
+
+    /// Stores the result of a file and the messages that were sent while
+    /// processing it.
+    ///
+    /// Only the results of the files that weren't modified are stored: the
+    /// results of the others are computed again during the next run.
+    pub(crate) fn store(
+        &self,
+        file: CachedFile,
+        result: &FileResult,
+        messages: Vec<CachedMessage>,
+    ) {
+        let status = match result {
+            Ok(FileStatus::Unchanged) => Some(None),
+            Ok(FileStatus::Message(message)) => Some(Some(CachedMessage::from(message))),
+            _ => None,
+        };
+        let entry = status.map(|status| CacheEntry {
+            key: file.key,
+            messages,
+            status,
+        });
+
+        self.entries.lock().unwrap().insert(file.path, entry);
+    }
+
+    /// Stores the results for the next run, in the cache file or in memory.
+    /// The results of the files that weren't processed during this run are
+    /// kept, unless the files were deleted.
+    pub(crate) fn persist(self, fs: &dyn FileSystem) -> Result<(), CliDiagnostic> {
+        let mut files = self.previous;
+        for (path, entry) in self.entries.into_inner().unwrap() {
+            match entry {
+                Some(entry) => files.insert(path, entry),
+                None => files.remove(&path),
+            };
+        }
+        files.retain(|path, _| match self.working_directory.as_ref() {
+            Some(working_directory) => fs.path_exists(&working_directory.join(path)),
+            None => fs.path_exists(Utf8Path::new(path)),
+        });
+
+        let file = CacheFile {
+            version: CACHE_VERSION,
+            settings: self.settings,
+            files,
+        };
+        let path = match self.storage {
+            CacheStorage::File(path) => path,
+            CacheStorage::Memory(memory) => {
+                *memory.lock().unwrap() = file;
+                return Ok(());
+            }
+        };
+        let content = serde_json::to_string(&file).map_err(|error| {
+            CliDiagnostic::Report(ReportDiagnostic::Serialization(SerdeJsonError::from(error)))
+        })?;
+
+        let mut cache_file = fs.open_with_options(
+            &path,
+            OpenOptions::default()
+                .write(true)
+                .create(true)
+                .truncate(true),
+        )?;
+        cache_file.set_content(content.as_bytes())?;
+
+        Ok(())
+    }
+
+    fn dependency_hash(&self, fs: &dyn FileSystem, path: &Utf8Path) -> u64 {
+        if let Some(hash) = self.dependency_hashes.lock().unwrap().get(path) {
+            return *hash;
+        }
+
+        let mut hasher = StableHasher::default();
+        match fs.read_file_from_path(path) {
+            Ok(content) => hasher.write(&content),
+            // A dependency that can't be read has a different hash than an
+            // empty one
+            Err(_) => hasher.write_u64(u64::MAX),
+        }
+        let hash = hasher.finish();
+        self.dependency_hashes
+            .lock()
+            .unwrap()
+            .insert(path.to_path_buf(), hash);
+        hash
+    }
+
+    fn to_relative_path(&self, path: &Utf8Path) -> String {
+        self.working_directory
+            .as_ref()
+            .and_then(|working_directory| path.strip_prefix(working_directory).ok())
+            .unwrap_or(path)
+            .as_str()
+            .replace('\\', "/")
+    }
+}
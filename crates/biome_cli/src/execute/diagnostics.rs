@@ -82,6 +82,25 @@ pub(crate) struct SkippedDiagnostic;
 #[diagnostic(category = "search", severity = Information)]
 pub(crate) struct SearchDiagnostic;
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "search",
+    severity = Error,
+    message = "This code is rewritten in conflicting ways, so the file isn't rewritten."
+)]
+pub(crate) struct RewriteConflictDiagnostic;
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "search",
+    severity = Information,
+    message = "The pattern would rewrite the following content:"
+)]
+pub(crate) struct RewriteDiffDiagnostic {
+    #[advice]
+    pub(crate) diff: ContentDiffAdvice,
+}
+
 /// Extension trait for turning [Display]-able error types into [TraversalError]
 pub(crate) trait ResultExt {
     type Result;
@@ -0,0 +1,34 @@
+/// A FNV-1a hasher. Unlike the hashers of the standard library, its output is
+/// the same across platforms and releases, so it can be used for the hashes
+/// that are written to disk, such as the ones of the baseline and the cache.
+pub(crate) struct StableHasher(u64);
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl StableHasher {
+    pub(crate) fn write(&mut self, text: &str) {
+        // The separator ensures that `["ab", "c"]` and `["a", "bc"]` don't
+        // have the same hash
+        self.write_bytes(text.as_bytes());
+        self.write_bytes(&[0]);
+    }
+
+    pub(crate) fn write_u64(&mut self, value: u64) {
+        self.write_bytes(&value.to_le_bytes());
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    pub(crate) fn finish(&self) -> u64 {
+        self.0
+    }
+}
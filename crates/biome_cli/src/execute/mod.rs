@@ -1,6 +1,10 @@
+mod baseline;
+mod cache;
 mod diagnostics;
+mod hasher;
 mod migrate;
 mod process_file;
+mod profile;
 mod std_in;
 pub(crate) mod traverse;
 
@@ -9,15 +13,20 @@ use crate::commands::MigrateSubCommand;
 use crate::diagnostics::ReportDiagnostic;
 use crate::execute::migrate::MigratePayload;
 use crate::execute::traverse::{TraverseResult, traverse};
+use crate::reporter::checkstyle::{CheckstyleReporter, CheckstyleReporterVisitor};
 use crate::reporter::github::{GithubReporter, GithubReporterVisitor};
 use crate::reporter::gitlab::{GitLabReporter, GitLabReporterVisitor};
 use crate::reporter::json::{JsonReporter, JsonReporterVisitor};
 use crate::reporter::junit::{JunitReporter, JunitReporterVisitor};
+use crate::reporter::patch::{PatchReporter, PatchReporterVisitor};
+use crate::reporter::rdjson::{RdjsonReporter, RdjsonReporterVisitor};
+use crate::reporter::sarif::{SarifReporter, SarifReporterVisitor};
 use crate::reporter::summary::{SummaryReporter, SummaryReporterVisitor};
 use crate::reporter::terminal::{ConsoleReporter, ConsoleReporterVisitor};
 use crate::{
     CliDiagnostic, CliSession, DiagnosticsPayload, Reporter, TEMPORARY_INTERNAL_REPORTER_FILE,
 };
+use biome_analyze::profiling;
 use biome_configuration::analyzer::RuleSelector;
 use biome_console::{ConsoleExt, markup};
 use biome_diagnostics::{Category, category};
@@ -36,6 +45,11 @@ use std::fmt::{Display, Formatter};
 use std::time::Duration;
 use tracing::{info, instrument};
 
+pub use baseline::BaselineMode;
+pub use cache::CacheOptions;
+pub(crate) use profile::relative_path;
+pub use profile::{ProfileFormat, ProfileOptions};
+
 /// Useful information during the traversal of files and virtual content
 #[derive(Debug, Clone)]
 pub struct Execution {
@@ -47,6 +61,12 @@ pub struct Execution {
 
     /// The maximum number of diagnostics that can be printed in console
     max_diagnostics: u32,
+
+    /// The options of the cache of the results of the files, enabled with `--cache`
+    cache: Option<CacheOptions>,
+
+    /// The options of the profile of the files, enabled with `--profile`
+    profile: Option<ProfileOptions>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -140,6 +160,9 @@ pub enum TraversalMode {
 
         /// It skips parse errors
         skip_parse_errors: bool,
+
+        /// How the baseline file is used, with `--baseline` or `--write-baseline`
+        baseline: Option<BaselineMode>,
     },
     /// This mode is enabled when running the command `biome ci`
     CI {
@@ -176,10 +199,11 @@ pub enum TraversalMode {
     /// This mode is enabled when running the command `biome search`
     Search {
         /// The GritQL pattern to search for.
-        ///
-        /// Note that the search command does not support rewrites.
         pattern: PatternId,
 
+        /// Write the rewrites of the pattern to disk
+        write: bool,
+
         /// The language to query for.
         ///
         /// Grit queries are specific to the grammar of the language they
@@ -247,6 +271,16 @@ pub enum ReportMode {
     Junit,
     /// Reports information in the [GitLab Code Quality](https://docs.gitlab.com/ee/ci/testing/code_quality.html#implement-a-custom-tool) format.
     GitLab,
+    /// Reports information in the [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) format.
+    Sarif,
+    /// Prints a unified diff of the changes that `--write` would apply,
+    /// without writing them to disk
+    Patch,
+    /// Reports information in the [Checkstyle](https://checkstyle.org) XML format.
+    Checkstyle,
+    /// Reports information in the [Reviewdog Diagnostic Format](https://github.com/reviewdog/reviewdog/tree/master/proto/rdf),
+    /// either in a single JSON object or one JSON object per line
+    Rdjson { lines: bool },
 }
 
 impl Default for ReportMode {
@@ -269,6 +303,11 @@ impl From<CliReporter> for ReportMode {
             CliReporter::GitHub => Self::GitHub,
             CliReporter::Junit => Self::Junit,
             CliReporter::GitLab => Self::GitLab {},
+            CliReporter::Sarif => Self::Sarif,
+            CliReporter::Patch => Self::Patch,
+            CliReporter::Checkstyle => Self::Checkstyle,
+            CliReporter::Rdjson => Self::Rdjson { lines: false },
+            CliReporter::Rdjsonl => Self::Rdjson { lines: true },
         }
     }
 }
@@ -279,6 +318,8 @@ impl Execution {
             report_mode: ReportMode::default(),
             traversal_mode: mode,
             max_diagnostics: 20,
+            cache: None,
+            profile: None,
         }
     }
 
@@ -305,15 +346,61 @@ impl Execution {
                 skip_parse_errors,
             },
             max_diagnostics: 20,
+            cache: None,
+            profile: None,
         }
     }
 
     /// It sets the reporting mode by reading the [CliOptions]
     pub(crate) fn set_report(mut self, cli_options: &CliOptions) -> Self {
         self.report_mode = cli_options.reporter.clone().into();
+        if self.is_patch() {
+            // The patch contains the changes of `--write`, which are computed
+            // but never written to disk
+            match &mut self.traversal_mode {
+                TraversalMode::Check { fix_file_mode, .. }
+                | TraversalMode::Lint { fix_file_mode, .. } => {
+                    fix_file_mode.get_or_insert(FixFileMode::SafeFixes);
+                }
+                TraversalMode::Format { write, .. } | TraversalMode::Search { write, .. } => {
+                    *write = true
+                }
+                TraversalMode::CI { .. } | TraversalMode::Migrate { .. } => {}
+            }
+        }
+        self
+    }
+
+    /// Whether the changes to the files are reported as a patch, instead of
+    /// being written to disk
+    pub(crate) const fn is_patch(&self) -> bool {
+        matches!(self.report_mode, ReportMode::Patch)
+    }
+
+    /// It enables the cache of the results of the files
+    pub(crate) fn with_cache(mut self, cache: Option<CacheOptions>) -> Self {
+        self.cache = cache;
         self
     }
 
+    pub(crate) fn as_cache(&self) -> Option<&CacheOptions> {
+        self.cache.as_ref()
+    }
+
+    pub(crate) fn as_cache_mut(&mut self) -> Option<&mut CacheOptions> {
+        self.cache.as_mut()
+    }
+
+    /// It enables the profile of the files
+    pub(crate) fn with_profile(mut self, profile: Option<ProfileOptions>) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    pub(crate) fn as_profile(&self) -> Option<&ProfileOptions> {
+        self.profile.as_ref()
+    }
+
     pub(crate) fn traversal_mode(&self) -> &TraversalMode {
         &self.traversal_mode
     }
@@ -411,11 +498,16 @@ impl Execution {
 
     /// Whether the traversal mode requires write access to files
     pub(crate) const fn requires_write_access(&self) -> bool {
+        if self.is_patch() {
+            return false;
+        }
         match self.traversal_mode {
             TraversalMode::Check { fix_file_mode, .. }
             | TraversalMode::Lint { fix_file_mode, .. } => fix_file_mode.is_some(),
-            TraversalMode::CI { .. } | TraversalMode::Search { .. } => false,
-            TraversalMode::Format { write, .. } | TraversalMode::Migrate { write, .. } => write,
+            TraversalMode::CI { .. } => false,
+            TraversalMode::Format { write, .. }
+            | TraversalMode::Migrate { write, .. }
+            | TraversalMode::Search { write, .. } => write,
         }
     }
 
@@ -447,7 +539,7 @@ impl Execution {
             TraversalMode::CI { .. } => false,
             TraversalMode::Format { write, .. } => write,
             TraversalMode::Migrate { write, .. } => write,
-            TraversalMode::Search { .. } => false,
+            TraversalMode::Search { write, .. } => write,
         }
     }
 
@@ -461,6 +553,8 @@ impl Execution {
             },
             report_mode: ReportMode::default(),
             max_diagnostics: 0,
+            cache: None,
+            profile: None,
         }
     }
 
@@ -484,7 +578,7 @@ impl Execution {
     #[instrument(level = "debug", skip(self), fields(result))]
     pub(crate) fn should_write(&self) -> bool {
         let result = match self.traversal_mode {
-            TraversalMode::Format { write, .. } => write,
+            TraversalMode::Format { write, .. } | TraversalMode::Search { write, .. } => write,
 
             _ => self.is_safe_fixes_enabled() || self.is_safe_and_unsafe_fixes_enabled(),
         };
@@ -492,6 +586,14 @@ impl Execution {
         result
     }
 
+    /// Returns how the baseline file is used, if any
+    pub(crate) fn as_baseline(&self) -> Option<&BaselineMode> {
+        match &self.traversal_mode {
+            TraversalMode::Lint { baseline, .. } => baseline.as_ref(),
+            _ => None,
+        }
+    }
+
     #[instrument(level = "debug", skip(self), fields(result))]
     pub(crate) fn should_skip_parse_errors(&self) -> bool {
         let result = match self.traversal_mode {
@@ -581,6 +683,7 @@ pub fn execute_mode(
         mut summary,
         evaluated_paths,
         mut diagnostics,
+        patches,
     } = traverse(
         &execution,
         &mut session,
@@ -603,6 +706,7 @@ pub fn execute_mode(
     });
     // We join the duration of the scanning with the duration of the traverse.
     summary.scanner_duration = scanner_duration;
+    let profile = execution.as_profile().map(|_| profiling::take());
     let console = session.app.console;
     let workspace = &*session.app.workspace;
     let fs = workspace.fs();
@@ -626,6 +730,7 @@ pub fn execute_mode(
                     verbose: cli_options.verbose,
                     working_directory: fs.working_directory().clone(),
                     evaluated_paths,
+                    profile: profile.as_ref(),
                 };
                 reporter.write(&mut SummaryReporterVisitor(console))?;
             } else {
@@ -636,6 +741,7 @@ pub fn execute_mode(
                     evaluated_paths,
                     verbose: cli_options.verbose,
                     working_directory: fs.working_directory().clone(),
+                    profile: profile.as_ref(),
                 };
                 reporter.write(&mut ConsoleReporterVisitor(console))?;
             }
@@ -702,6 +808,17 @@ pub fn execute_mode(
                 session.app.workspace.fs().working_directory(),
             ))?;
         }
+        ReportMode::Sarif => {
+            let reporter = SarifReporter {
+                diagnostics: diagnostics_payload,
+                execution: execution.clone(),
+                verbose: cli_options.verbose,
+            };
+            reporter.write(&mut SarifReporterVisitor::new(
+                console,
+                session.app.workspace.fs().working_directory(),
+            ))?;
+        }
         ReportMode::Junit => {
             let reporter = JunitReporter {
                 summary,
@@ -711,6 +828,42 @@ pub fn execute_mode(
             };
             reporter.write(&mut JunitReporterVisitor::new(console))?;
         }
+        ReportMode::Patch => {
+            let reporter = PatchReporter {
+                diagnostics_payload,
+                patches,
+                execution: execution.clone(),
+                verbose: cli_options.verbose,
+            };
+            reporter.write(&mut PatchReporterVisitor(console))?;
+        }
+        ReportMode::Checkstyle => {
+            let reporter = CheckstyleReporter {
+                diagnostics: diagnostics_payload,
+                execution: execution.clone(),
+                verbose: cli_options.verbose,
+            };
+            reporter.write(&mut CheckstyleReporterVisitor::new(
+                console,
+                session.app.workspace.fs().working_directory(),
+            ))?;
+        }
+        ReportMode::Rdjson { lines } => {
+            let reporter = RdjsonReporter {
+                diagnostics: diagnostics_payload,
+                execution: execution.clone(),
+                verbose: cli_options.verbose,
+            };
+            reporter.write(&mut RdjsonReporterVisitor::new(
+                console,
+                session.app.workspace.fs().working_directory(),
+                lines,
+            ))?;
+        }
+    }
+
+    if let (Some(options), Some(profile)) = (execution.as_profile(), profile.as_ref()) {
+        options.export(fs, profile, fs.working_directory().as_deref())?;
     }
 
     // Processing emitted error diagnostics, exit with a non-zero code
@@ -724,13 +877,15 @@ pub fn execute_mode(
         ))
     } else if errors > 0 || should_exit_on_warnings {
         let category = execution.as_diagnostic_category();
+        // The fixes of the patch aren't applied
+        let applies_fixes = execution.is_safe_fixes_enabled() && !execution.is_patch();
         if should_exit_on_warnings {
-            if execution.is_safe_fixes_enabled() {
+            if applies_fixes {
                 Err(CliDiagnostic::apply_warnings(category))
             } else {
                 Err(CliDiagnostic::check_warnings(category))
             }
-        } else if execution.is_safe_fixes_enabled() {
+        } else if applies_fixes {
             Err(CliDiagnostic::apply_error(category))
         } else {
             Err(CliDiagnostic::check_error(category))
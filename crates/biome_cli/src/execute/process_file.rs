@@ -5,6 +5,7 @@ mod search;
 pub(crate) mod workspace_file;
 
 use crate::execute::TraversalMode;
+use crate::execute::cache::CachedMessage;
 use crate::execute::diagnostics::{ResultExt, UnhandledDiagnostic};
 use crate::execute::traverse::TraversalOptions;
 use biome_analyze::RuleCategoriesBuilder;
@@ -17,6 +18,8 @@ use check::check_file;
 use format::format;
 use lint_and_assist::lint_and_assist;
 use search::search;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
 use std::marker::PhantomData;
 use std::ops::Deref;
 
@@ -71,9 +74,14 @@ impl Message {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub(crate) enum DiffKind {
     Format,
+    /// The changes that `--write` would apply, reported as a patch
+    Patch,
+    /// The changes that the rewrite of a search pattern would apply
+    Rewrite,
 }
 
 impl<D> From<D> for Message
@@ -101,6 +109,9 @@ pub(crate) type FileResult = Result<FileStatus, Message>;
 /// compiler constraints set by the lifetimes of the [TraversalOptions]
 pub(crate) struct SharedTraversalOptions<'ctx, 'app> {
     inner: &'app TraversalOptions<'ctx, 'app>,
+    /// The messages sent while processing the file, recorded when its result
+    /// is stored in the cache
+    recorded_messages: Option<RefCell<Vec<CachedMessage>>>,
     _p: PhantomData<&'app ()>,
 }
 
@@ -109,7 +120,35 @@ impl<'ctx, 'app> SharedTraversalOptions<'ctx, 'app> {
         Self {
             _p: PhantomData,
             inner: t,
+            recorded_messages: None,
+        }
+    }
+
+    fn with_recorded_messages(mut self) -> Self {
+        self.recorded_messages = Some(RefCell::default());
+        self
+    }
+
+    fn take_recorded_messages(&self) -> Vec<CachedMessage> {
+        self.recorded_messages
+            .as_ref()
+            .map(RefCell::take)
+            .unwrap_or_default()
+    }
+
+    /// Send a message to the display thread
+    pub(crate) fn push_message(&self, msg: impl Into<Message>) {
+        let msg = msg.into();
+        if let Some(recorded_messages) = &self.recorded_messages {
+            recorded_messages
+                .borrow_mut()
+                .push(CachedMessage::from(&msg));
         }
+        self.inner.push_message(msg);
+    }
+
+    pub(crate) fn push_diagnostic(&self, error: Error) {
+        self.push_message(error);
     }
 }
 
@@ -206,9 +245,18 @@ pub(crate) fn process_file(ctx: &TraversalOptions, biome_path: &BiomePath) -> Fi
         };
     }
 
-    let shared_context = &SharedTraversalOptions::new(ctx);
+    let cached_file = ctx.cache.and_then(|cache| cache.read(ctx, biome_path));
+    let shared_context = &match (ctx.cache, &cached_file) {
+        (Some(cache), Some(cached_file)) => {
+            if let Some(status) = cache.replay(ctx, cached_file) {
+                return Ok(status);
+            }
+            SharedTraversalOptions::new(ctx).with_recorded_messages()
+        }
+        _ => SharedTraversalOptions::new(ctx),
+    };
 
-    match ctx.execution.traversal_mode {
+    let result = match ctx.execution.traversal_mode {
         TraversalMode::Lint {
             ref suppression_reason,
             suppress,
@@ -238,5 +286,15 @@ pub(crate) fn process_file(ctx: &TraversalOptions, biome_path: &BiomePath) -> Fi
             // the unsupported case should be handled already at this point
             search(shared_context, biome_path.clone(), pattern)
         }
+    };
+
+    if let (Some(cache), Some(cached_file)) = (ctx.cache, cached_file) {
+        cache.store(
+            cached_file,
+            &result,
+            shared_context.take_recorded_messages(),
+        );
     }
+
+    result
 }
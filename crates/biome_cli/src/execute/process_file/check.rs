@@ -4,7 +4,7 @@ use crate::execute::process_file::workspace_file::WorkspaceFile;
 use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraversalOptions};
 use biome_analyze::RuleCategoriesBuilder;
 use biome_diagnostics::DiagnosticExt;
-use biome_fs::{BiomePath, TraversalContext};
+use biome_fs::BiomePath;
 use biome_service::diagnostics::FileTooLarge;
 use biome_service::workspace::FileFeaturesResult;
 
@@ -88,11 +88,12 @@ pub(crate) fn check_file<'ctx>(
         }
     }
 
-    if has_failures {
+    let result = if has_failures {
         Ok(FileStatus::Message(Message::Failure))
     } else if changed {
         Ok(FileStatus::Changed)
     } else {
         Ok(FileStatus::Unchanged)
-    }
+    };
+    workspace_file.send_patch(ctx, result)
 }
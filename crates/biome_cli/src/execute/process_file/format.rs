@@ -5,7 +5,7 @@ use crate::execute::process_file::{
 };
 use biome_analyze::RuleCategoriesBuilder;
 use biome_diagnostics::{Diagnostic, DiagnosticExt, Error, Severity, category};
-use biome_fs::{BiomePath, TraversalContext};
+use biome_fs::BiomePath;
 use biome_service::diagnostics::FileTooLarge;
 use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
 use tracing::{debug, instrument};
@@ -25,7 +25,8 @@ pub(crate) fn format<'ctx>(
         );
         Ok(FileStatus::Ignored)
     } else {
-        format_with_guard(ctx, &mut workspace_file)
+        let result = format_with_guard(ctx, &mut workspace_file);
+        workspace_file.send_patch(ctx, result)
     }
 }
 
@@ -4,7 +4,7 @@ use crate::execute::process_file::workspace_file::WorkspaceFile;
 use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraversalOptions};
 use biome_analyze::RuleCategories;
 use biome_diagnostics::{Diagnostic, DiagnosticExt, Error, Severity, category};
-use biome_fs::{BiomePath, TraversalContext};
+use biome_fs::BiomePath;
 use biome_rowan::TextSize;
 use biome_service::diagnostics::FileTooLarge;
 use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
@@ -29,13 +29,14 @@ pub(crate) fn lint_and_assist<'ctx>(
         );
         Ok(FileStatus::Ignored)
     } else {
-        analyze_with_guard(
+        let result = analyze_with_guard(
             ctx,
             &mut workspace_file,
             suppress,
             suppression_reason,
             categories,
-        )
+        );
+        workspace_file.send_patch(ctx, result)
     }
 }
 
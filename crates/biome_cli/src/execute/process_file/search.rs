@@ -1,13 +1,17 @@
 use crate::execute::TraversalMode;
-use crate::execute::diagnostics::{ResultExt, SearchDiagnostic};
+use crate::execute::diagnostics::{ResultExt, RewriteConflictDiagnostic, SearchDiagnostic};
 use crate::execute::process_file::workspace_file::WorkspaceFile;
-use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraversalOptions};
+use crate::execute::process_file::{
+    DiffKind, FileResult, FileStatus, Message, SharedTraversalOptions,
+};
 use biome_diagnostics::{DiagnosticExt, category};
-use biome_fs::{BiomePath, TraversalContext};
+use biome_fs::BiomePath;
 use biome_grit_patterns::{GritTargetLanguage, JsTargetLanguage};
+use biome_service::WorkspaceError;
 use biome_service::diagnostics::FileTooLarge;
 use biome_service::file_handlers::DocumentFileSource;
-use biome_service::workspace::PatternId;
+use biome_service::workspace::{PatternId, SearchRewrite};
+use std::ops::Range;
 
 pub(crate) fn search<'ctx>(
     ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
@@ -24,19 +28,20 @@ pub(crate) fn search<'ctx>(
         );
         Ok(FileStatus::Ignored)
     } else {
-        search_with_guard(ctx, &mut workspace_file, pattern)
+        let result = search_with_guard(ctx, &mut workspace_file, pattern);
+        workspace_file.send_patch(ctx, result)
     }
 }
 
 pub(crate) fn search_with_guard<'ctx>(
-    _ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
+    ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
     workspace_file: &mut WorkspaceFile,
     pattern: &PatternId,
 ) -> FileResult {
     let _ = tracing::info_span!("Search ", path =? workspace_file.path).entered();
 
     let file_source = DocumentFileSource::from_path(workspace_file.path.as_path());
-    let pattern_language = match &_ctx.execution.traversal_mode {
+    let pattern_language = match &ctx.execution.traversal_mode {
         TraversalMode::Search {
             language: Some(pattern_language),
             ..
@@ -63,17 +68,84 @@ pub(crate) fn search_with_guard<'ctx>(
     let matches_len = result.matches.len();
 
     let search_results = Message::Diagnostics {
-        file_path: file_name,
-        content: input,
+        file_path: file_name.clone(),
+        content: input.clone(),
         diagnostics: result
             .matches
             .into_iter()
             .map(|mat| SearchDiagnostic.with_file_span(mat))
+            .chain(
+                result
+                    .conflicts
+                    .into_iter()
+                    .map(|range| RewriteConflictDiagnostic.with_file_span(range)),
+            )
             .collect(),
         skipped_diagnostics: 0,
     };
 
-    Ok(FileStatus::SearchResult(matches_len, search_results))
+    let Some(rewrite) = result.rewrite else {
+        return Ok(FileStatus::SearchResult(matches_len, search_results));
+    };
+
+    let output = format_rewrite(workspace_file, rewrite)?;
+    if ctx.execution.should_write() {
+        workspace_file.update_file(output)?;
+        ctx.push_message(search_results);
+        ctx.increment_matches(matches_len);
+        Ok(FileStatus::Changed)
+    } else {
+        ctx.push_message(Message::Diff {
+            file_name,
+            old: input,
+            new: output,
+            diff_kind: DiffKind::Rewrite,
+        });
+        Ok(FileStatus::SearchResult(matches_len, search_results))
+    }
+}
+
+/// Applies the rewrite to the workspace file and formats the rewritten code,
+/// returning the new content of the file.
+///
+/// The ranges are formatted from the last one, so that the formatting of a
+/// range doesn't shift the ranges that precede it. The ranges that can't be
+/// formatted, for example because the formatter is disabled, are kept as
+/// they were rewritten.
+fn format_rewrite(
+    workspace_file: &mut WorkspaceFile,
+    rewrite: SearchRewrite,
+) -> Result<String, WorkspaceError> {
+    let SearchRewrite {
+        mut content,
+        ranges,
+    } = rewrite;
+    workspace_file.change_content(content.clone())?;
+
+    // The start of the last formatted range, because the formatter may
+    // extend a range to the enclosing nodes
+    let mut formatted_start = content.len();
+    for range in ranges.into_iter().rev() {
+        if usize::from(range.end()) > formatted_start {
+            continue;
+        }
+        let Ok(printed) = workspace_file.guard().format_range(range) else {
+            continue;
+        };
+        match printed.range() {
+            Some(printed_range) => {
+                formatted_start = printed_range.start().into();
+                content.replace_range(Range::<usize>::from(printed_range), printed.as_code());
+            }
+            None => {
+                formatted_start = 0;
+                content = printed.into_code();
+            }
+        }
+        workspace_file.change_content(content.clone())?;
+    }
+
+    Ok(content)
 }
 
 fn is_file_compatible_with_pattern(
@@ -1,5 +1,7 @@
 use crate::execute::diagnostics::{ResultExt, ResultIoExt};
-use crate::execute::process_file::SharedTraversalOptions;
+use crate::execute::process_file::{
+    DiffKind, FileResult, FileStatus, Message, SharedTraversalOptions,
+};
 use biome_diagnostics::{Error, category};
 use biome_fs::{BiomePath, File, OpenOptions};
 use biome_service::workspace::{FileContent, FileGuard, OpenFileParams};
@@ -10,6 +12,12 @@ pub(crate) struct WorkspaceFile<'ctx, 'app> {
     guard: FileGuard<'app, dyn Workspace + 'ctx>,
     file: Box<dyn File>,
     pub(crate) path: BiomePath,
+    /// The content of the file on disk, when the changes are reported as a
+    /// patch instead of being written
+    original: Option<String>,
+    /// The version of the document in the workspace, which is bumped by each
+    /// change
+    version: i32,
 }
 
 impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
@@ -31,6 +39,7 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
         file.read_to_string(&mut input)
             .with_file_path(path.to_string())?;
 
+        let original = ctx.execution.is_patch().then(|| input.clone());
         let guard = FileGuard::open(
             ctx.workspace,
             OpenFileParams {
@@ -43,7 +52,14 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
         )
         .with_file_path_and_code(path.to_string(), category!("internalError/fs"))?;
 
-        Ok(Self { file, guard, path })
+        let version = file.file_version();
+        Ok(Self {
+            file,
+            guard,
+            path,
+            original,
+            version,
+        })
     }
 
     pub(crate) fn guard(&self) -> &FileGuard<'app, dyn Workspace + 'ctx> {
@@ -58,15 +74,50 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
         self.path.extension()
     }
 
-    /// It updates the workspace file with `new_content`
+    /// It updates the workspace file with `new_content`, and writes it to
+    /// disk unless the changes are reported as a patch
     pub(crate) fn update_file(&mut self, new_content: impl Into<String>) -> Result<(), Error> {
         let new_content = new_content.into();
 
-        self.file
-            .set_content(new_content.as_bytes())
-            .with_file_path(self.path.to_string())?;
-        self.guard
-            .change_file(self.file.file_version(), new_content)?;
+        if self.original.is_none() {
+            self.file
+                .set_content(new_content.as_bytes())
+                .with_file_path(self.path.to_string())?;
+        }
+        self.change_content(new_content)?;
         Ok(())
     }
+
+    /// It updates the workspace file with `new_content`, without writing it
+    /// to disk
+    pub(crate) fn change_content(&mut self, new_content: String) -> Result<(), WorkspaceError> {
+        self.version += 1;
+        self.guard.change_file(self.version, new_content)
+    }
+
+    /// When the changes are reported as a patch, it sends the changes made to
+    /// the file, which is then considered unchanged because nothing was
+    /// written to disk.
+    pub(crate) fn send_patch(
+        &self,
+        ctx: &SharedTraversalOptions,
+        result: FileResult,
+    ) -> FileResult {
+        let Some(original) = &self.original else {
+            return result;
+        };
+        let new = self.input()?;
+        if new != *original {
+            ctx.push_message(Message::Diff {
+                file_name: self.path.to_string(),
+                old: original.clone(),
+                new,
+                diff_kind: DiffKind::Patch,
+            });
+        }
+        match result {
+            Ok(FileStatus::Changed) => Ok(FileStatus::Unchanged),
+            result => result,
+        }
+    }
 }
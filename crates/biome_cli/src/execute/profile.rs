@@ -0,0 +1,303 @@
+//! The profile records the time spent processing the files by `check` and
+//! `lint` when `--profile` is passed: the time spent by each rule, by each
+//! plugin, in each phase of the files and scanning the project. The slowest
+//! ones are printed by the terminal reporters, and the whole profile can be
+//! exported in JSON or in the Chrome trace format with `--profile-output`.
+
+use crate::CliDiagnostic;
+use crate::diagnostics::ReportDiagnostic;
+use biome_analyze::profiling::{self, FilePhase, FileProfile, Profile, ProfileEventKind, Timing};
+use biome_diagnostics::SerdeJsonError;
+use biome_fs::{FileSystem, OpenOptions};
+use camino::{Utf8Path, Utf8PathBuf};
+use serde::Serialize;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// The format of the file written by `--profile-output`
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ProfileFormat {
+    #[default]
+    Json,
+    /// The trace event format of Chrome, which can be opened in
+    /// `chrome://tracing` or in Perfetto
+    ChromeTrace,
+}
+
+impl FromStr for ProfileFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "chrome-trace" => Ok(Self::ChromeTrace),
+            _ => Err(format!(
+                "value {s:?} is not valid for the --profile-format argument"
+            )),
+        }
+    }
+}
+
+/// Options of the profile, enabled with `--profile` or `--profile-output`
+#[derive(Debug, Clone)]
+pub struct ProfileOptions {
+    output: Option<Utf8PathBuf>,
+    format: ProfileFormat,
+}
+
+impl ProfileOptions {
+    /// Returns the options of the profile when `--profile` or
+    /// `--profile-output` is passed.
+    pub(crate) fn from_arguments(
+        profile: bool,
+        output: Option<Utf8PathBuf>,
+        format: ProfileFormat,
+    ) -> Option<Self> {
+        (profile || output.is_some()).then_some(Self { output, format })
+    }
+
+    /// Starts recording the profile.
+    pub(crate) fn enable(&self) {
+        profiling::enable();
+    }
+
+    /// Writes the profile to the file of `--profile-output`, if any.
+    pub(crate) fn export(
+        &self,
+        fs: &dyn FileSystem,
+        profile: &Profile,
+        working_directory: Option<&Utf8Path>,
+    ) -> Result<(), CliDiagnostic> {
+        let Some(output) = self.output.as_ref() else {
+            return Ok(());
+        };
+        let content = match self.format {
+            ProfileFormat::Json => {
+                serde_json::to_string(&JsonProfile::new(profile, working_directory))
+            }
+            ProfileFormat::ChromeTrace => {
+                serde_json::to_string(&ChromeTrace::new(profile, working_directory))
+            }
+        }
+        .map_err(|error| {
+            CliDiagnostic::Report(ReportDiagnostic::Serialization(SerdeJsonError::from(error)))
+        })?;
+
+        let path = match working_directory {
+            Some(working_directory) => working_directory.join(output),
+            None => output.clone(),
+        };
+        let mut file = fs.open_with_options(
+            &path,
+            OpenOptions::default()
+                .write(true)
+                .create(true)
+                .truncate(true),
+        )?;
+        file.set_content(content.as_bytes())?;
+
+        Ok(())
+    }
+}
+
+/// Returns the path of a file relative to the working directory, when it's
+/// inside it.
+pub(crate) fn relative_path<'a>(
+    path: &'a Utf8Path,
+    working_directory: Option<&Utf8Path>,
+) -> &'a Utf8Path {
+    working_directory
+        .and_then(|working_directory| path.strip_prefix(working_directory).ok())
+        .unwrap_or(path)
+}
+
+/// The profile exported in JSON. The durations are in microseconds, and the
+/// lists are sorted from the slowest entry.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonProfile {
+    scan: JsonTiming,
+    module_graph: JsonTiming,
+    phases: JsonPhases,
+    rules: Vec<JsonNamedTiming>,
+    plugins: Vec<JsonNamedTiming>,
+    files: Vec<JsonFile>,
+}
+
+impl JsonProfile {
+    fn new(profile: &Profile, working_directory: Option<&Utf8Path>) -> Self {
+        let mut rules: Vec<_> = profile
+            .rules()
+            .iter()
+            .map(|(rule, timing)| JsonNamedTiming::new(rule.to_string(), *timing))
+            .collect();
+        rules.sort_by(|a, b| b.timing.duration.cmp(&a.timing.duration));
+
+        let mut plugins: Vec<_> = profile
+            .plugins()
+            .iter()
+            .map(|(name, timing)| JsonNamedTiming::new(name.clone(), *timing))
+            .collect();
+        plugins.sort_by(|a, b| b.timing.duration.cmp(&a.timing.duration));
+
+        let mut files: Vec<_> = profile.files().iter().collect();
+        files.sort_by(|(_, a), (_, b)| b.duration().cmp(&a.duration()));
+
+        Self {
+            scan: profile.scan().into(),
+            module_graph: profile.module_graph().into(),
+            phases: JsonPhases::new(|phase| profile.phase(phase)),
+            rules,
+            plugins,
+            files: files
+                .into_iter()
+                .map(|(path, file)| JsonFile::new(path, file, working_directory))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct JsonTiming {
+    duration: u128,
+    count: u32,
+}
+
+impl From<Timing> for JsonTiming {
+    fn from(timing: Timing) -> Self {
+        Self {
+            duration: timing.duration.as_micros(),
+            count: timing.count,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct JsonNamedTiming {
+    name: String,
+    #[serde(flatten)]
+    timing: JsonTiming,
+}
+
+impl JsonNamedTiming {
+    fn new(name: String, timing: Timing) -> Self {
+        Self {
+            name,
+            timing: timing.into(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonPhases {
+    parse: JsonTiming,
+    semantic_model: JsonTiming,
+    analysis: JsonTiming,
+    format: JsonTiming,
+}
+
+impl JsonPhases {
+    fn new(timing: impl Fn(FilePhase) -> Timing) -> Self {
+        Self {
+            parse: timing(FilePhase::Parse).into(),
+            semantic_model: timing(FilePhase::SemanticModel).into(),
+            analysis: timing(FilePhase::Analysis).into(),
+            format: timing(FilePhase::Format).into(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct JsonFile {
+    path: String,
+    /// The time spent in all the phases of the file
+    duration: u128,
+    phases: JsonPhases,
+}
+
+impl JsonFile {
+    fn new(path: &Utf8Path, file: &FileProfile, working_directory: Option<&Utf8Path>) -> Self {
+        Self {
+            path: relative_path(path, working_directory).to_string(),
+            duration: file.duration().as_micros(),
+            phases: JsonPhases::new(|phase| file.phase(phase)),
+        }
+    }
+}
+
+/// The profile exported in the [trace event format] of Chrome.
+///
+/// [trace event format]: https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChromeTrace {
+    trace_events: Vec<ChromeTraceEvent>,
+    display_time_unit: &'static str,
+}
+
+impl ChromeTrace {
+    fn new(profile: &Profile, working_directory: Option<&Utf8Path>) -> Self {
+        let trace_events = profile
+            .events()
+            .iter()
+            .map(|event| {
+                let (name, category) = match event.kind {
+                    ProfileEventKind::Scan => ("scan".to_string(), "scanner"),
+                    ProfileEventKind::Phase(phase) => (phase.as_str().to_string(), "file"),
+                    ProfileEventKind::Plugin => (
+                        event
+                            .name
+                            .clone()
+                            .unwrap_or_else(|| "anonymous".to_string()),
+                        "plugin",
+                    ),
+                };
+                ChromeTraceEvent {
+                    name,
+                    cat: category,
+                    ph: "X",
+                    ts: as_micros(event.start),
+                    dur: as_micros(event.duration),
+                    pid: 1,
+                    tid: event.thread,
+                    args: ChromeTraceArgs {
+                        path: event
+                            .path
+                            .as_deref()
+                            .map(|path| relative_path(path, working_directory).to_string()),
+                    },
+                }
+            })
+            .collect();
+
+        Self {
+            trace_events,
+            display_time_unit: "ms",
+        }
+    }
+}
+
+/// A complete event of the trace, which has a start and a duration
+#[derive(Serialize)]
+struct ChromeTraceEvent {
+    name: String,
+    cat: &'static str,
+    ph: &'static str,
+    ts: f64,
+    dur: f64,
+    pid: u32,
+    tid: u64,
+    args: ChromeTraceArgs,
+}
+
+#[derive(Serialize)]
+struct ChromeTraceArgs {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+}
+
+/// The trace event format expects timestamps in microseconds
+fn as_micros(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1_000_000.0
+}
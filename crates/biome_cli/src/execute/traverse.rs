@@ -1,10 +1,13 @@
+use super::baseline::Baseline;
+use super::cache::FileCache;
 use super::process_file::{DiffKind, FileStatus, Message, process_file};
 use super::{Execution, TraversalMode};
 use crate::cli_options::CliOptions;
 use crate::execute::diagnostics::{
     CIFormatDiffDiagnostic, ContentDiffAdvice, FormatDiffDiagnostic, PanicDiagnostic,
+    RewriteDiffDiagnostic,
 };
-use crate::reporter::TraversalSummary;
+use crate::reporter::{FilePatch, TraversalSummary};
 use crate::{CliDiagnostic, CliSession};
 use biome_diagnostics::DiagnosticTags;
 use biome_diagnostics::{DiagnosticExt, Error, Resource, Severity, category};
@@ -33,6 +36,8 @@ pub(crate) struct TraverseResult {
     pub(crate) summary: TraversalSummary,
     pub(crate) evaluated_paths: BTreeSet<BiomePath>,
     pub(crate) diagnostics: Vec<Error>,
+    /// The changes to the files, when they are reported as a patch
+    pub(crate) patches: Vec<FilePatch>,
 }
 
 pub(crate) fn traverse(
@@ -81,13 +86,23 @@ pub(crate) fn traverse(
 
     let max_diagnostics = execution.get_max_diagnostics();
 
+    let baseline = execution
+        .as_baseline()
+        .map(|mode| Baseline::load(fs, mode))
+        .transpose()?;
+
+    let cache = execution
+        .as_cache()
+        .map(|options| FileCache::load(fs, execution, options));
+
     let working_directory = fs.working_directory();
     let printer = DiagnosticsPrinter::new(execution, working_directory.as_deref())
         .with_verbose(cli_options.verbose)
         .with_diagnostic_level(cli_options.diagnostic_level)
-        .with_max_diagnostics(max_diagnostics);
+        .with_max_diagnostics(max_diagnostics)
+        .with_baseline(baseline.as_ref());
 
-    let (duration, evaluated_paths, diagnostics) = thread::scope(|s| {
+    let (duration, evaluated_paths, (mut diagnostics, patches)) = thread::scope(|s| {
         let handler = thread::Builder::new()
             .name(String::from("biome::console"))
             .spawn_scoped(s, || printer.run(receiver, recv_files))
@@ -110,12 +125,13 @@ pub(crate) fn traverse(
                 skipped: &skipped,
                 messages: sender,
                 evaluated_paths: RwLock::default(),
+                cache: cache.as_ref(),
             },
         );
         // wait for the main thread to finish
-        let diagnostics = handler.join().unwrap();
+        let printed = handler.join().unwrap();
 
-        (elapsed, evaluated_paths, diagnostics)
+        (elapsed, evaluated_paths, printed)
     });
 
     // Make sure patterns are always cleaned up at the end of traversal.
@@ -125,8 +141,22 @@ pub(crate) fn traverse(
         });
     }
 
+    if let Some(cache) = cache {
+        cache.persist(fs)?;
+    }
+
     let errors = printer.errors();
-    let warnings = printer.warnings();
+    let mut warnings = printer.warnings();
+    if let Some(baseline) = &baseline {
+        let processed_paths: Vec<_> = evaluated_paths
+            .iter()
+            .map(|path| printer.to_relative_file_path(path.as_str()))
+            .collect();
+        if let Some(diagnostic) = baseline.finish(fs, processed_paths.iter().map(String::as_str))? {
+            warnings += 1;
+            diagnostics.push(diagnostic);
+        }
+    }
     let changed = changed.load(Ordering::Relaxed);
     let unchanged = unchanged.load(Ordering::Relaxed);
     let matches = matches.load(Ordering::Relaxed);
@@ -148,6 +178,7 @@ pub(crate) fn traverse(
         },
         evaluated_paths,
         diagnostics,
+        patches,
     })
 }
 
@@ -205,6 +236,9 @@ struct DiagnosticsPrinter<'ctx> {
 
     /// The current working directory, borrowed from [FileSystem]
     working_directory: Option<&'ctx Utf8Path>,
+
+    /// The baseline of accepted diagnostics, used with `--baseline` or `--write-baseline`
+    baseline: Option<&'ctx Baseline>,
 }
 
 impl<'ctx> DiagnosticsPrinter<'ctx> {
@@ -221,6 +255,7 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
             printed_diagnostics: AtomicU32::new(0),
             total_skipped_suggested_fixes: AtomicU32::new(0),
             working_directory,
+            baseline: None,
         }
     }
 
@@ -239,6 +274,11 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
         self
     }
 
+    fn with_baseline(mut self, baseline: Option<&'ctx Baseline>) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
     fn errors(&self) -> u32 {
         self.errors.load(Ordering::Relaxed)
     }
@@ -287,10 +327,17 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
         should_print
     }
 
-    fn run(&self, receiver: Receiver<Message>, interner: Receiver<Utf8PathBuf>) -> Vec<Error> {
+    /// Receives the messages of the traversal, and returns the diagnostics to
+    /// print and the changes to report as a patch
+    fn run(
+        &self,
+        receiver: Receiver<Message>,
+        interner: Receiver<Utf8PathBuf>,
+    ) -> (Vec<Error>, Vec<FilePatch>) {
         let mut paths: FxHashSet<String> = FxHashSet::default();
 
         let mut diagnostics_to_print = vec![];
+        let mut patches = vec![];
 
         while let Ok(msg) = receiver.recv() {
             match msg {
@@ -363,6 +410,12 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                         if self.should_skip_diagnostic(severity, diag.tags()) {
                             continue;
                         }
+                        if self
+                            .baseline
+                            .is_some_and(|baseline| baseline.accepts(&file_path, &content, &diag))
+                        {
+                            continue;
+                        }
                         if severity == Severity::Error {
                             self.errors.fetch_add(1, Ordering::Relaxed);
                         }
@@ -387,6 +440,36 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                     diff_kind,
                 } => {
                     let file_path = self.to_relative_file_path(&file_name);
+                    if matches!(diff_kind, DiffKind::Rewrite) {
+                        // The preview of a rewrite doesn't fail the search
+                        if !self
+                            .should_skip_diagnostic(Severity::Information, DiagnosticTags::empty())
+                            && self.should_print()
+                        {
+                            diagnostics_to_print.push(
+                                RewriteDiffDiagnostic {
+                                    diff: ContentDiffAdvice {
+                                        old: old.clone(),
+                                        new,
+                                    },
+                                }
+                                .with_file_source_code(old)
+                                .with_file_path(file_path),
+                            );
+                        }
+                        continue;
+                    }
+                    if matches!(diff_kind, DiffKind::Patch) || self.execution.is_patch() {
+                        // The files that would be changed fail the run, like
+                        // the checks without `--write`
+                        self.errors.fetch_add(1, Ordering::Relaxed);
+                        patches.push(FilePatch {
+                            path: file_path.replace(std::path::MAIN_SEPARATOR, "/"),
+                            old,
+                            new,
+                        });
+                        continue;
+                    }
                     // A diff is an error in CI mode and in format check mode
                     let is_error = self.execution.is_ci() || !self.execution.is_format_write();
                     if is_error {
@@ -434,12 +517,13 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                                     diagnostics_to_print.push(diag);
                                 }
                             }
+                            DiffKind::Patch | DiffKind::Rewrite => {}
                         }
                     }
                 }
             }
         }
-        diagnostics_to_print
+        (diagnostics_to_print, patches)
     }
 
     fn to_relative_file_path(&self, path: &str) -> String {
@@ -476,6 +560,8 @@ pub(crate) struct TraversalOptions<'ctx, 'app> {
     pub(crate) messages: Sender<Message>,
     /// List of paths that should be processed
     pub(crate) evaluated_paths: RwLock<BTreeSet<BiomePath>>,
+    /// The cache of the results of the files, used with `--cache`
+    pub(crate) cache: Option<&'ctx FileCache>,
 }
 
 impl TraversalOptions<'_, '_> {
@@ -556,6 +642,13 @@ impl TraversalContext for TraversalOptions<'_, '_> {
             return false;
         }
 
+        if self
+            .cache
+            .is_some_and(|cache| cache.is_cache_file(biome_path))
+        {
+            return false;
+        }
+
         let path = biome_path.as_path();
         if self.fs.path_is_dir(path) || self.fs.path_is_symlink(path) {
             // handle:
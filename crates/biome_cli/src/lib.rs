@@ -10,7 +10,9 @@
 
 use biome_console::{ColorMode, Console};
 use biome_service::{App, Workspace, WorkspaceRef};
+use camino::Utf8PathBuf;
 use commands::search::SearchCommandPayload;
+use crossbeam::channel::Receiver;
 use std::env;
 
 mod changed;
@@ -33,9 +35,12 @@ use crate::commands::migrate::MigrateCommandPayload;
 pub use crate::commands::{BiomeCommand, biome_command};
 pub use crate::logging::{LoggingLevel, setup_cli_subscriber};
 pub use diagnostics::CliDiagnostic;
-pub use execute::{Execution, TraversalMode, VcsTargeted, execute_mode};
+pub use execute::{
+    BaselineMode, CacheOptions, Execution, ProfileFormat, ProfileOptions, TraversalMode,
+    VcsTargeted, execute_mode,
+};
 pub use panic::setup_panic_handler;
-pub use reporter::{DiagnosticsPayload, Reporter, ReporterVisitor, TraversalSummary};
+pub use reporter::{DiagnosticsPayload, FilePatch, Reporter, ReporterVisitor, TraversalSummary};
 pub use service::{SocketTransport, open_transport};
 
 pub(crate) const VERSION: &str = match option_env!("BIOME_VERSION") {
@@ -51,6 +56,9 @@ pub const TEMPORARY_INTERNAL_REPORTER_FILE: &str = "__BIOME_INTERNAL_FILE__.json
 pub struct CliSession<'app> {
     /// Instance of [App] used by this run of the CLI
     pub app: App<'app>,
+    /// Receives the paths that changed on disk, when the commands run in
+    /// watch mode
+    pub watcher: Option<Receiver<Vec<Utf8PathBuf>>>,
 }
 
 impl<'app> CliSession<'app> {
@@ -60,9 +68,18 @@ impl<'app> CliSession<'app> {
     ) -> Result<Self, CliDiagnostic> {
         Ok(Self {
             app: App::new(console, WorkspaceRef::Borrowed(workspace)),
+            watcher: None,
         })
     }
 
+    /// Sets the receiver of the changes reported by the
+    /// [watcher](biome_service::WorkspaceWatcher) of the workspace, which is
+    /// required by the watch mode.
+    pub fn with_watcher(mut self, watcher: Receiver<Vec<Utf8PathBuf>>) -> Self {
+        self.watcher = Some(watcher);
+        self
+    }
+
     /// Main function to run Biome CLI
     pub fn run(self, command: BiomeCommand) -> Result<(), CliDiagnostic> {
         match command {
@@ -88,6 +105,12 @@ impl<'app> CliSession<'app> {
                 formatter_enabled,
                 assist_enabled,
                 enforce_assist,
+                cache,
+                cache_location,
+                watch,
+                profile,
+                profile_output,
+                profile_format,
                 staged,
                 changed,
                 since,
@@ -105,6 +128,13 @@ impl<'app> CliSession<'app> {
                     formatter_enabled,
                     assist_enabled,
                     enforce_assist,
+                    cache: CacheOptions::from_arguments(cache, cache_location, watch),
+                    watch,
+                    profile: ProfileOptions::from_arguments(
+                        profile,
+                        profile_output,
+                        profile_format,
+                    ),
                     staged,
                     changed,
                     since,
@@ -114,6 +144,14 @@ impl<'app> CliSession<'app> {
                 write,
                 suppress,
                 suppression_reason,
+                baseline,
+                write_baseline,
+                cache,
+                cache_location,
+                watch,
+                profile,
+                profile_output,
+                profile_format,
                 fix,
                 unsafe_,
                 cli_options,
@@ -131,6 +169,7 @@ impl<'app> CliSession<'app> {
                 javascript_linter,
                 json_linter,
                 graphql_linter,
+                yaml_linter,
             } => run_command(
                 self,
                 &cli_options,
@@ -138,6 +177,15 @@ impl<'app> CliSession<'app> {
                     write,
                     suppress,
                     suppression_reason,
+                    baseline,
+                    write_baseline,
+                    cache: CacheOptions::from_arguments(cache, cache_location, watch),
+                    watch,
+                    profile: ProfileOptions::from_arguments(
+                        profile,
+                        profile_output,
+                        profile_format,
+                    ),
                     fix,
                     unsafe_,
                     linter_configuration,
@@ -154,6 +202,7 @@ impl<'app> CliSession<'app> {
                     javascript_linter,
                     json_linter,
                     graphql_linter,
+                    yaml_linter,
                 },
             ),
             BiomeCommand::Ci {
@@ -161,6 +210,8 @@ impl<'app> CliSession<'app> {
                 formatter_enabled,
                 assist_enabled,
                 enforce_assist,
+                cache,
+                cache_location,
                 configuration,
                 paths,
                 cli_options,
@@ -175,6 +226,7 @@ impl<'app> CliSession<'app> {
                     formatter_enabled,
                     assist_enabled,
                     enforce_assist,
+                    cache: CacheOptions::from_arguments(cache, cache_location, false),
                     configuration,
                     paths,
                     changed,
@@ -187,6 +239,7 @@ impl<'app> CliSession<'app> {
                 stdin_file_path,
                 write,
                 fix,
+                watch,
                 cli_options,
                 paths,
                 vcs_configuration,
@@ -195,6 +248,8 @@ impl<'app> CliSession<'app> {
                 css_formatter,
                 graphql_formatter,
                 html_formatter,
+                markdown_formatter,
+                yaml_formatter,
                 staged,
                 changed,
                 since,
@@ -207,6 +262,8 @@ impl<'app> CliSession<'app> {
                     stdin_file_path,
                     write,
                     fix,
+                    cache: CacheOptions::from_arguments(false, None, watch),
+                    watch,
                     paths,
                     vcs_configuration,
                     files_configuration,
@@ -214,6 +271,8 @@ impl<'app> CliSession<'app> {
                     css_formatter,
                     graphql_formatter,
                     html_formatter,
+                    markdown_formatter,
+                    yaml_formatter,
                     staged,
                     changed,
                     since,
@@ -242,8 +301,17 @@ impl<'app> CliSession<'app> {
                     configuration_file_path: None,
                 },
             ),
+            BiomeCommand::Config {
+                cli_options,
+                sub_command,
+            } => commands::config::config(self, &cli_options, sub_command),
+            BiomeCommand::Rules {
+                cli_options,
+                sub_command,
+            } => commands::rules::rules(self, &cli_options, sub_command),
             BiomeCommand::Search {
                 cli_options,
+                write,
                 files_configuration,
                 paths,
                 pattern,
@@ -254,6 +322,7 @@ impl<'app> CliSession<'app> {
                 self,
                 &cli_options,
                 SearchCommandPayload {
+                    write,
                     files_configuration,
                     paths,
                     pattern,
@@ -11,9 +11,12 @@ use biome_cli::{
 use biome_console::{ConsoleExt, EnvConsole, markup};
 use biome_diagnostics::{Diagnostic, PrintDiagnostic, set_bottom_frame};
 use biome_fs::OsFileSystem;
-use biome_service::workspace;
+use biome_service::workspace::{self, ServiceDataNotification};
+use biome_service::{WorkspaceServer, WorkspaceWatcher};
 use std::process::{ExitCode, Termination};
+use std::thread;
 use tokio::runtime::Runtime;
+use tokio::sync::watch;
 
 #[cfg(target_os = "windows")]
 #[global_allocator]
@@ -65,6 +68,8 @@ fn run_workspace(console: &mut EnvConsole, command: BiomeCommand) -> Result<(),
             Some(transport) => workspace::client(transport, fs)?,
             None => return Err(CliDiagnostic::server_not_running()),
         }
+    } else if command.should_watch() {
+        return run_watched_workspace(console, command, fs);
     } else {
         let threads = command.get_threads();
         workspace::server(fs, threads)
@@ -73,3 +78,31 @@ fn run_workspace(console: &mut EnvConsole, command: BiomeCommand) -> Result<(),
     let session = CliSession::new(&*workspace, console)?;
     session.run(command)
 }
+
+/// Runs the command with a workspace that is kept up-to-date by a watcher,
+/// which reports the changes to the watch mode of the command.
+fn run_watched_workspace(
+    console: &mut EnvConsole,
+    command: BiomeCommand,
+    fs: Box<OsFileSystem>,
+) -> Result<(), CliDiagnostic> {
+    let (mut watcher, instruction_channel) = WorkspaceWatcher::new()?;
+    let changes = watcher.subscribe_to_changes();
+    let (service_data_tx, _) = watch::channel(ServiceDataNotification::Updated);
+    let workspace = WorkspaceServer::new(
+        fs,
+        instruction_channel.sender.clone(),
+        service_data_tx,
+        command.get_threads(),
+    );
+
+    thread::scope(|scope| {
+        scope.spawn(|| watcher.run(&workspace));
+
+        let result = CliSession::new(&workspace, console)
+            .and_then(|session| session.with_watcher(changes).run(command));
+        // Stops the watcher
+        drop(instruction_channel);
+        result
+    })
+}
@@ -0,0 +1,225 @@
+use crate::reporter::diagnostic_path;
+use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary};
+use biome_console::fmt::{Display, Formatter};
+use biome_console::{Console, ConsoleExt, markup};
+use biome_diagnostics::display::SourceFile;
+use biome_diagnostics::{Error, PrintDescription, Severity};
+use camino::{Utf8Path, Utf8PathBuf};
+use std::collections::BTreeMap;
+use std::io;
+
+pub(crate) struct CheckstyleReporter {
+    pub(crate) execution: Execution,
+    pub(crate) diagnostics: DiagnosticsPayload,
+    pub(crate) verbose: bool,
+}
+
+impl Reporter for CheckstyleReporter {
+    fn write(self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
+        visitor.report_diagnostics(&self.execution, self.diagnostics, self.verbose)?;
+        Ok(())
+    }
+}
+
+pub(crate) struct CheckstyleReporterVisitor<'a> {
+    console: &'a mut dyn Console,
+    repository_root: Option<Utf8PathBuf>,
+}
+
+impl<'a> CheckstyleReporterVisitor<'a> {
+    pub(crate) fn new(console: &'a mut dyn Console, repository_root: Option<Utf8PathBuf>) -> Self {
+        Self {
+            console,
+            repository_root,
+        }
+    }
+}
+
+impl ReporterVisitor for CheckstyleReporterVisitor<'_> {
+    fn report_summary(
+        &mut self,
+        _: &Execution,
+        _: TraversalSummary,
+        _verbose: bool,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn report_diagnostics(
+        &mut self,
+        _execution: &Execution,
+        payload: DiagnosticsPayload,
+        verbose: bool,
+    ) -> io::Result<()> {
+        let diagnostics = CheckstyleDiagnostics {
+            payload,
+            path: self.repository_root.as_deref(),
+            verbose,
+        };
+        self.console.log(markup!({ diagnostics }));
+        Ok(())
+    }
+}
+
+struct CheckstyleDiagnostics<'a> {
+    payload: DiagnosticsPayload,
+    verbose: bool,
+    path: Option<&'a Utf8Path>,
+}
+
+impl Display for CheckstyleDiagnostics<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let Self {
+            verbose,
+            payload,
+            path,
+        } = self;
+
+        // Checkstyle groups the errors by file
+        let mut files: BTreeMap<String, Vec<CheckstyleError>> = BTreeMap::new();
+        for diagnostic in payload
+            .diagnostics
+            .iter()
+            .filter(|d| d.severity() >= payload.diagnostic_level)
+            .filter(|d| {
+                if d.tags().is_verbose() {
+                    *verbose
+                } else {
+                    true
+                }
+            })
+        {
+            // The diagnostics that aren't tied to a file are reported in a
+            // file without a name, so that they aren't lost
+            let file = diagnostic_path(diagnostic, *path).unwrap_or_default();
+            files
+                .entry(file)
+                .or_default()
+                .push(CheckstyleError::from_diagnostic(diagnostic));
+        }
+
+        fmt.write_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n")?;
+        fmt.write_str("<checkstyle version=\"4.3\">\n")?;
+        for (file, errors) in files {
+            fmt.write_str(&format!("  <file name=\"{}\">\n", escape(&file)))?;
+            for error in errors {
+                fmt.write_str("    <error")?;
+                if let Some((line, column)) = error.position {
+                    fmt.write_str(&format!(" line=\"{line}\" column=\"{column}\""))?;
+                }
+                fmt.write_str(&format!(
+                    " severity=\"{}\" message=\"{}\"",
+                    error.severity,
+                    escape(&error.message)
+                ))?;
+                if let Some(source) = error.source {
+                    fmt.write_str(&format!(" source=\"{}\"", escape(source)))?;
+                }
+                fmt.write_str(" />\n")?;
+            }
+            fmt.write_str("  </file>\n")?;
+        }
+        fmt.write_str("</checkstyle>")?;
+        Ok(())
+    }
+}
+
+/// An error of the [Checkstyle](https://checkstyle.org) XML report, which is
+/// understood by Jenkins and most CI tools.
+struct CheckstyleError {
+    /// The one-based line and column where the error starts
+    position: Option<(usize, usize)>,
+    severity: &'static str,
+    message: String,
+    source: Option<&'static str>,
+}
+
+impl CheckstyleError {
+    fn from_diagnostic(diagnostic: &Error) -> Self {
+        let location = diagnostic.location();
+        let position = location
+            .span
+            .zip(location.source_code)
+            .and_then(|(span, source_code)| {
+                SourceFile::new(source_code).location(span.start()).ok()
+            })
+            .map(|start| (start.line_number.get(), start.column_number.get()));
+
+        Self {
+            position,
+            severity: match diagnostic.severity() {
+                Severity::Hint | Severity::Information => "info",
+                Severity::Warning => "warning",
+                Severity::Error | Severity::Fatal => "error",
+            },
+            message: PrintDescription(diagnostic).to_string(),
+            source: diagnostic.category().map(|category| category.name()),
+        }
+    }
+}
+
+/// Escapes the characters that can't appear in the attributes of an XML
+/// element, and replaces the ones that XML 1.0 doesn't allow at all.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for char in text.chars() {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            '\r' => escaped.push_str("&#13;"),
+            '\t' => escaped.push_str("&#9;"),
+            // Not even a character reference can represent these characters
+            '\0'..='\u{1f}' | '\u{fffe}' | '\u{ffff}' => escaped.push(char::REPLACEMENT_CHARACTER),
+            _ => escaped.push(char),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod test {
+    use super::CheckstyleDiagnostics;
+    use crate::DiagnosticsPayload;
+    use crate::cli_options::MaxDiagnostics;
+    use biome_console::fmt::{Formatter, Termcolor};
+    use biome_console::markup;
+    use biome_diagnostics::termcolor::NoColor;
+    use biome_diagnostics::{Diagnostic, Error, Severity};
+
+    #[derive(Debug, Diagnostic)]
+    #[diagnostic(category = "internalError/io", message = "Something went wrong.")]
+    struct DiagnosticWithoutPath;
+
+    #[test]
+    fn reports_diagnostics_without_path() {
+        let diagnostics = CheckstyleDiagnostics {
+            payload: DiagnosticsPayload {
+                diagnostics: vec![Error::from(DiagnosticWithoutPath)],
+                diagnostic_level: Severity::Hint,
+                max_diagnostics: MaxDiagnostics::default(),
+            },
+            verbose: false,
+            path: None,
+        };
+
+        let mut buffer = Vec::new();
+        let mut write = Termcolor(NoColor::new(&mut buffer));
+        Formatter::new(&mut write)
+            .write_markup(markup!({ diagnostics }))
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(buffer).unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<checkstyle version="4.3">
+  <file name="">
+    <error severity="error" message="Something went wrong." source="internalError/io" />
+  </file>
+</checkstyle>"#
+        );
+    }
+}
@@ -1,19 +1,30 @@
+pub(crate) mod checkstyle;
 pub(crate) mod github;
 pub(crate) mod gitlab;
 pub(crate) mod json;
 pub(crate) mod junit;
+pub(crate) mod patch;
+pub(crate) mod rdjson;
+pub(crate) mod sarif;
 pub(crate) mod summary;
 pub(crate) mod terminal;
 
 use crate::cli_options::MaxDiagnostics;
 use crate::execute::Execution;
+use biome_analyze::profiling::Profile;
+use biome_console::fmt::{Display, Formatter, Termcolor};
 use biome_diagnostics::advice::ListAdvice;
-use biome_diagnostics::{Diagnostic, Error, Severity};
+use biome_diagnostics::termcolor::NoColor;
+use biome_diagnostics::{Diagnostic, Error, LogCategory, Resource, Severity, Visit};
 use biome_fs::BiomePath;
-use camino::Utf8PathBuf;
+use biome_rowan::{TextRange, TextSize};
+use biome_text_edit::{CompressedOp, DiffOp, TextEdit};
+use camino::{Utf8Path, Utf8PathBuf};
+use path_absolutize::Absolutize;
 use serde::Serialize;
 use std::collections::BTreeSet;
 use std::io;
+use std::path::Path;
 use std::time::Duration;
 
 pub struct DiagnosticsPayload {
@@ -74,6 +85,29 @@ pub trait ReporterVisitor {
         _payload: DiagnosticsPayload,
         _verbose: bool,
     ) -> io::Result<()>;
+
+    /// Writes the changes to the files as a unified diff.
+    fn report_patches(&mut self, _patches: &[FilePatch]) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Writes the slowest entries of the profile recorded with `--profile`.
+    fn report_profile(
+        &mut self,
+        _profile: &Profile,
+        _working_directory: Option<&Utf8Path>,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The changes to a file that would be written to disk with `--write`
+#[derive(Debug)]
+pub struct FilePatch {
+    /// The path of the file, relative to the working directory
+    pub path: String,
+    pub old: String,
+    pub new: String,
 }
 
 #[derive(Debug, Diagnostic)]
@@ -97,3 +131,94 @@ pub(crate) struct EvaluatedPathsDiagnostic {
     #[advice]
     advice: ListAdvice<String>,
 }
+
+/// Returns the path of the file of a diagnostic, relative to the repository
+/// root when possible, with forward slashes even on Windows.
+pub(crate) fn diagnostic_path(
+    diagnostic: &Error,
+    repository_root: Option<&Utf8Path>,
+) -> Option<String> {
+    let Some(Resource::File(file)) = diagnostic.location().resource else {
+        return None;
+    };
+
+    let path = repository_root
+        .zip(Path::new(file).absolutize().ok())
+        .and_then(|(root, resolved)| {
+            let relativized = resolved.strip_prefix(root).ok()?;
+            Utf8Path::from_path(relativized).map(Utf8Path::to_string)
+        })
+        .unwrap_or_else(|| file.to_string());
+
+    Some(path.replace('\\', "/"))
+}
+
+/// Collects the code suggestions of a diagnostic, along with the message
+/// logged right before each of them.
+#[derive(Default)]
+pub(crate) struct FixesVisitor {
+    last_message: Option<String>,
+    pub(crate) fixes: Vec<(String, TextEdit)>,
+}
+
+impl Visit for FixesVisitor {
+    fn record_log(&mut self, _category: LogCategory, text: &dyn Display) -> io::Result<()> {
+        let mut message = Termcolor(NoColor::new(Vec::new()));
+        text.fmt(&mut Formatter::new(&mut message))?;
+        self.last_message = String::from_utf8(message.0.into_inner()).ok();
+        Ok(())
+    }
+
+    fn record_diff(&mut self, diff: &TextEdit) -> io::Result<()> {
+        let description = self.last_message.take().unwrap_or_default();
+        self.fixes.push((description, diff.clone()));
+        Ok(())
+    }
+}
+
+/// Returns the replacements of the original source code made by the diff of a
+/// code action, as the deleted ranges and the inserted text.
+///
+/// The diff covers the whole file, so the replacements are found by tracking
+/// the offset in the original source code across the operations of the diff.
+pub(crate) fn text_edit_replacements(
+    edit: &TextEdit,
+    source: &str,
+) -> Option<Vec<(TextRange, String)>> {
+    let mut replacements = Vec::new();
+    let mut offset = TextSize::from(0);
+    let mut pending: Option<(TextRange, String)> = None;
+
+    for op in edit {
+        match op {
+            CompressedOp::DiffOp(DiffOp::Equal { range }) => {
+                replacements.extend(pending.take());
+                offset += range.len();
+            }
+            CompressedOp::DiffOp(DiffOp::Insert { range }) => {
+                let (_, inserted) =
+                    pending.get_or_insert_with(|| (TextRange::empty(offset), String::new()));
+                inserted.push_str(edit.get_text(*range));
+            }
+            CompressedOp::DiffOp(DiffOp::Delete { range }) => {
+                let (deleted, _) =
+                    pending.get_or_insert_with(|| (TextRange::empty(offset), String::new()));
+                *deleted = deleted.cover(TextRange::at(offset, range.len()));
+                offset += range.len();
+            }
+            CompressedOp::EqualLines { line_count } => {
+                replacements.extend(pending.take());
+                let remaining = source.get(usize::from(offset)..)?;
+                for line in remaining
+                    .split_inclusive('\n')
+                    .take(line_count.get() as usize + 1)
+                {
+                    offset += TextSize::of(line);
+                }
+            }
+        }
+    }
+    replacements.extend(pending);
+
+    Some(replacements)
+}
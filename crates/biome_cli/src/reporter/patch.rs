@@ -0,0 +1,82 @@
+use crate::{
+    DiagnosticsPayload, Execution, FilePatch, Reporter, ReporterVisitor, TraversalSummary,
+};
+use biome_console::{Console, ConsoleExt, LogLevel, markup};
+use biome_diagnostics::PrintDiagnostic;
+use similar::TextDiff;
+use std::io;
+
+pub(crate) struct PatchReporter {
+    pub(crate) diagnostics_payload: DiagnosticsPayload,
+    pub(crate) patches: Vec<FilePatch>,
+    pub(crate) execution: Execution,
+    pub(crate) verbose: bool,
+}
+
+impl Reporter for PatchReporter {
+    fn write(mut self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
+        visitor.report_diagnostics(&self.execution, self.diagnostics_payload, self.verbose)?;
+        self.patches.sort_by(|a, b| a.path.cmp(&b.path));
+        visitor.report_patches(&self.patches)?;
+        Ok(())
+    }
+}
+
+/// Prints the patch to the standard output, so that it can be redirected to a
+/// file and applied with `git apply`. The diagnostics are printed to the
+/// standard error.
+pub(crate) struct PatchReporterVisitor<'a>(pub(crate) &'a mut dyn Console);
+
+impl ReporterVisitor for PatchReporterVisitor<'_> {
+    fn report_summary(
+        &mut self,
+        _execution: &Execution,
+        _summary: TraversalSummary,
+        _verbose: bool,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn report_diagnostics(
+        &mut self,
+        _execution: &Execution,
+        diagnostics_payload: DiagnosticsPayload,
+        verbose: bool,
+    ) -> io::Result<()> {
+        for diagnostic in &diagnostics_payload.diagnostics {
+            if diagnostic.severity() >= diagnostics_payload.diagnostic_level {
+                if diagnostic.tags().is_verbose() && verbose {
+                    self.0
+                        .error(markup! {{PrintDiagnostic::verbose(diagnostic)}});
+                } else {
+                    self.0
+                        .error(markup! {{PrintDiagnostic::simple(diagnostic)}});
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn report_patches(&mut self, patches: &[FilePatch]) -> io::Result<()> {
+        // The patch is written as is: sanitizing it would replace characters
+        // such as a BOM, and `git apply` would then reject it
+        for patch in patches {
+            self.0.print_raw(LogLevel::Log, &unified_diff(patch));
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the changes of the file as a unified diff, with the `a/` and `b/`
+/// prefixes used by Git.
+fn unified_diff(patch: &FilePatch) -> String {
+    let diff = TextDiff::from_lines(patch.old.as_str(), patch.new.as_str());
+    let old_header = format!("a/{}", patch.path);
+    let new_header = format!("b/{}", patch.path);
+    diff.unified_diff()
+        .context_radius(3)
+        .header(&old_header, &new_header)
+        .to_string()
+}
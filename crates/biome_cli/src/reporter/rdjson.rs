@@ -0,0 +1,320 @@
+use crate::reporter::{FixesVisitor, diagnostic_path, text_edit_replacements};
+use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary};
+use biome_console::fmt::{Display, Formatter};
+use biome_console::{Console, ConsoleExt, markup};
+use biome_diagnostics::display::SourceFile;
+use biome_diagnostics::{Error, PrintDescription, Severity};
+use biome_rowan::{TextRange, TextSize};
+use camino::{Utf8Path, Utf8PathBuf};
+use serde::Serialize;
+use std::io;
+
+pub(crate) struct RdjsonReporter {
+    pub(crate) execution: Execution,
+    pub(crate) diagnostics: DiagnosticsPayload,
+    pub(crate) verbose: bool,
+}
+
+impl Reporter for RdjsonReporter {
+    fn write(self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
+        visitor.report_diagnostics(&self.execution, self.diagnostics, self.verbose)?;
+        Ok(())
+    }
+}
+
+pub(crate) struct RdjsonReporterVisitor<'a> {
+    console: &'a mut dyn Console,
+    repository_root: Option<Utf8PathBuf>,
+    /// Whether the diagnostics are printed one per line, in the `rdjsonl`
+    /// format, instead of in a single `rdjson` object
+    lines: bool,
+}
+
+impl<'a> RdjsonReporterVisitor<'a> {
+    pub(crate) fn new(
+        console: &'a mut dyn Console,
+        repository_root: Option<Utf8PathBuf>,
+        lines: bool,
+    ) -> Self {
+        Self {
+            console,
+            repository_root,
+            lines,
+        }
+    }
+}
+
+impl ReporterVisitor for RdjsonReporterVisitor<'_> {
+    fn report_summary(
+        &mut self,
+        _: &Execution,
+        _: TraversalSummary,
+        _verbose: bool,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn report_diagnostics(
+        &mut self,
+        _execution: &Execution,
+        payload: DiagnosticsPayload,
+        verbose: bool,
+    ) -> io::Result<()> {
+        let diagnostics = RdjsonDiagnostics {
+            payload,
+            path: self.repository_root.as_deref(),
+            verbose,
+            lines: self.lines,
+        };
+        self.console.log(markup!({ diagnostics }));
+        Ok(())
+    }
+}
+
+struct RdjsonDiagnostics<'a> {
+    payload: DiagnosticsPayload,
+    verbose: bool,
+    path: Option<&'a Utf8Path>,
+    lines: bool,
+}
+
+impl Display for RdjsonDiagnostics<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let Self {
+            verbose,
+            payload,
+            path,
+            lines,
+        } = self;
+
+        let diagnostics: Vec<_> = payload
+            .diagnostics
+            .iter()
+            .filter(|d| d.severity() >= payload.diagnostic_level)
+            .filter(|d| {
+                if d.tags().is_verbose() {
+                    *verbose
+                } else {
+                    true
+                }
+            })
+            .map(|diagnostic| {
+                // The diagnostics that aren't tied to a file are reported
+                // with an empty path, so that they aren't lost
+                let path = diagnostic_path(diagnostic, *path).unwrap_or_default();
+                RdjsonDiagnostic::from_diagnostic(diagnostic, path)
+            })
+            .collect();
+
+        if *lines {
+            let mut first = true;
+            for diagnostic in &diagnostics {
+                if !first {
+                    fmt.write_str("\n")?;
+                }
+                first = false;
+                fmt.write_str(&serde_json::to_string(diagnostic)?)?;
+            }
+        } else {
+            let result = RdjsonResult {
+                source: RdjsonSource::BIOME,
+                diagnostics,
+            };
+            fmt.write_str(&serde_json::to_string_pretty(&result)?)?;
+        }
+        Ok(())
+    }
+}
+
+/// The root object of the [Reviewdog Diagnostic Format](https://github.com/reviewdog/reviewdog/tree/master/proto/rdf).
+#[derive(Serialize)]
+struct RdjsonResult {
+    source: RdjsonSource,
+    diagnostics: Vec<RdjsonDiagnostic>,
+}
+
+#[derive(Serialize)]
+struct RdjsonSource {
+    name: &'static str,
+    url: &'static str,
+}
+
+impl RdjsonSource {
+    const BIOME: Self = Self {
+        name: "Biome",
+        url: "https://biomejs.dev",
+    };
+}
+
+#[derive(Serialize)]
+struct RdjsonDiagnostic {
+    message: String,
+    location: RdjsonLocation,
+    severity: &'static str,
+    /// The source is repeated in each diagnostic, because the `rdjsonl`
+    /// format doesn't have a root object
+    source: RdjsonSource,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<RdjsonCode>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    suggestions: Vec<RdjsonSuggestion>,
+}
+
+impl RdjsonDiagnostic {
+    fn from_diagnostic(diagnostic: &Error, path: String) -> Self {
+        let location = diagnostic.location();
+        let source_code = location.source_code;
+        let source_file = source_code.map(SourceFile::new);
+
+        let range = location
+            .span
+            .zip(source_code.zip(source_file.as_ref()))
+            .and_then(|(span, (source_code, source_file))| {
+                RdjsonRange::from_range(source_code.text, source_file, span)
+            });
+
+        // The suggestions are those of the first code action, because
+        // reviewdog applies all the suggestions of a diagnostic together
+        let suggestions = match source_code.zip(source_file.as_ref()) {
+            Some((source_code, source_file)) => {
+                let mut visitor = FixesVisitor::default();
+                // The suggestions are best effort, they're omitted if the
+                // advices can't be visited
+                let fixes = match diagnostic.advices(&mut visitor) {
+                    Ok(()) => visitor.fixes,
+                    Err(_) => Vec::new(),
+                };
+                fixes
+                    .first()
+                    .and_then(|(_, edit)| text_edit_replacements(edit, source_code.text))
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|(deleted, text)| {
+                        Some(RdjsonSuggestion {
+                            range: RdjsonRange::from_range(source_code.text, source_file, deleted)?,
+                            text,
+                        })
+                    })
+                    .collect()
+            }
+            None => Vec::new(),
+        };
+
+        Self {
+            message: PrintDescription(diagnostic).to_string(),
+            location: RdjsonLocation { path, range },
+            severity: match diagnostic.severity() {
+                Severity::Hint | Severity::Information => "INFO",
+                Severity::Warning => "WARNING",
+                Severity::Error | Severity::Fatal => "ERROR",
+            },
+            source: RdjsonSource::BIOME,
+            code: diagnostic.category().map(|category| RdjsonCode {
+                value: category.name(),
+                url: category.link(),
+            }),
+            suggestions,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct RdjsonLocation {
+    path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    range: Option<RdjsonRange>,
+}
+
+#[derive(Serialize)]
+struct RdjsonRange {
+    start: RdjsonPosition,
+    end: RdjsonPosition,
+}
+
+impl RdjsonRange {
+    fn from_range(source: &str, source_file: &SourceFile, range: TextRange) -> Option<Self> {
+        Some(Self {
+            start: RdjsonPosition::from_offset(source, source_file, range.start())?,
+            end: RdjsonPosition::from_offset(source, source_file, range.end())?,
+        })
+    }
+}
+
+/// A position in a file. Lines are one-based, and columns are one-based and
+/// count the UTF-8 bytes of the line.
+#[derive(Serialize)]
+struct RdjsonPosition {
+    line: usize,
+    column: usize,
+}
+
+impl RdjsonPosition {
+    fn from_offset(source: &str, source_file: &SourceFile, offset: TextSize) -> Option<Self> {
+        let location = source_file.location(offset).ok()?;
+        let offset = usize::from(offset);
+        let line_start = source
+            .get(..offset)?
+            .rfind('\n')
+            .map_or(0, |index| index + 1);
+
+        Some(Self {
+            line: location.line_number.get(),
+            column: offset - line_start + 1,
+        })
+    }
+}
+
+#[derive(Serialize)]
+struct RdjsonCode {
+    value: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<&'static str>,
+}
+
+/// A replacement of a range of the file, which reviewdog posts as a
+/// suggested change.
+#[derive(Serialize)]
+struct RdjsonSuggestion {
+    range: RdjsonRange,
+    text: String,
+}
+
+#[cfg(test)]
+mod test {
+    use super::RdjsonDiagnostics;
+    use crate::DiagnosticsPayload;
+    use crate::cli_options::MaxDiagnostics;
+    use biome_console::fmt::{Formatter, Termcolor};
+    use biome_console::markup;
+    use biome_diagnostics::termcolor::NoColor;
+    use biome_diagnostics::{Diagnostic, Error, Severity};
+
+    #[derive(Debug, Diagnostic)]
+    #[diagnostic(category = "internalError/io", message = "Something went wrong.")]
+    struct DiagnosticWithoutPath;
+
+    #[test]
+    fn reports_diagnostics_without_path() {
+        let diagnostics = RdjsonDiagnostics {
+            payload: DiagnosticsPayload {
+                diagnostics: vec![Error::from(DiagnosticWithoutPath)],
+                diagnostic_level: Severity::Hint,
+                max_diagnostics: MaxDiagnostics::default(),
+            },
+            verbose: false,
+            path: None,
+            lines: true,
+        };
+
+        let mut buffer = Vec::new();
+        let mut write = Termcolor(NoColor::new(&mut buffer));
+        Formatter::new(&mut write)
+            .write_markup(markup!({ diagnostics }))
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(buffer).unwrap(),
+            r#"{"message":"Something went wrong.","location":{"path":""},"severity":"ERROR","source":{"name":"Biome","url":"https://biomejs.dev"},"code":{"value":"internalError/io"}}"#
+        );
+    }
+}
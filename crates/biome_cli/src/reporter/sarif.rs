@@ -0,0 +1,367 @@
+use crate::reporter::{FixesVisitor, diagnostic_path, text_edit_replacements};
+use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary, VERSION};
+use biome_console::fmt::{Display, Formatter};
+use biome_console::{Console, ConsoleExt, markup};
+use biome_diagnostics::display::SourceFile;
+use biome_diagnostics::{Error, PrintDescription, Severity};
+use biome_rowan::TextRange;
+use biome_text_edit::TextEdit;
+use camino::{Utf8Path, Utf8PathBuf};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::io;
+
+pub(crate) struct SarifReporter {
+    pub(crate) execution: Execution,
+    pub(crate) diagnostics: DiagnosticsPayload,
+    pub(crate) verbose: bool,
+}
+
+impl Reporter for SarifReporter {
+    fn write(self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
+        visitor.report_diagnostics(&self.execution, self.diagnostics, self.verbose)?;
+        Ok(())
+    }
+}
+
+pub(crate) struct SarifReporterVisitor<'a> {
+    console: &'a mut dyn Console,
+    repository_root: Option<Utf8PathBuf>,
+}
+
+impl<'a> SarifReporterVisitor<'a> {
+    pub(crate) fn new(console: &'a mut dyn Console, repository_root: Option<Utf8PathBuf>) -> Self {
+        Self {
+            console,
+            repository_root,
+        }
+    }
+}
+
+impl ReporterVisitor for SarifReporterVisitor<'_> {
+    fn report_summary(
+        &mut self,
+        _: &Execution,
+        _: TraversalSummary,
+        _verbose: bool,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn report_diagnostics(
+        &mut self,
+        _execution: &Execution,
+        payload: DiagnosticsPayload,
+        verbose: bool,
+    ) -> io::Result<()> {
+        let diagnostics = SarifDiagnostics {
+            payload,
+            path: self.repository_root.as_deref(),
+            verbose,
+        };
+        self.console.log(markup!({ diagnostics }));
+        Ok(())
+    }
+}
+
+struct SarifDiagnostics<'a> {
+    payload: DiagnosticsPayload,
+    verbose: bool,
+    path: Option<&'a Utf8Path>,
+}
+
+impl Display for SarifDiagnostics<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let Self {
+            verbose, payload, ..
+        } = self;
+
+        let mut rules = BTreeMap::new();
+        let mut results = Vec::new();
+        for diagnostic in payload
+            .diagnostics
+            .iter()
+            .filter(|d| d.severity() >= payload.diagnostic_level)
+            .filter(|d| {
+                if d.tags().is_verbose() {
+                    *verbose
+                } else {
+                    true
+                }
+            })
+        {
+            if let Some(category) = diagnostic.category() {
+                rules.entry(category.name()).or_insert_with(|| SarifRule {
+                    id: category.name(),
+                    help_uri: category.link(),
+                });
+            }
+
+            results.push(SarifResult::from_diagnostic(
+                diagnostic,
+                diagnostic_path(diagnostic, self.path),
+            ));
+        }
+
+        // Results reference their rule by index, so we assign them once all
+        // the rules are known
+        let rules: Vec<_> = rules.into_values().collect();
+        for result in &mut results {
+            result.rule_index = result
+                .rule_id
+                .and_then(|rule_id| rules.iter().position(|rule| rule.id == rule_id));
+        }
+
+        let log = SarifLog {
+            schema: "https://json.schemastore.org/sarif-2.1.0.json",
+            version: "2.1.0",
+            runs: vec![SarifRun {
+                tool: SarifTool {
+                    driver: SarifDriver {
+                        name: "Biome",
+                        information_uri: "https://biomejs.dev",
+                        version: VERSION,
+                        rules,
+                    },
+                },
+                column_kind: "unicodeCodePoints",
+                results,
+            }],
+        };
+        let serialized = serde_json::to_string_pretty(&log)?;
+        fmt.write_str(serialized.as_str())?;
+        Ok(())
+    }
+}
+
+/// The root object of a SARIF file.
+/// See https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Serialize)]
+struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<SarifRun>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRun {
+    tool: SarifTool,
+    /// How the columns of the regions are counted. Biome counts characters.
+    column_kind: &'static str,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    name: &'static str,
+    information_uri: &'static str,
+    version: &'static str,
+    rules: Vec<SarifRule>,
+}
+
+/// A rule, identified by the category of its diagnostics.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRule {
+    id: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    level: &'static str,
+    message: SarifMessage,
+    locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<SarifFix>,
+}
+
+impl SarifResult {
+    fn from_diagnostic(diagnostic: &Error, uri: Option<String>) -> Self {
+        let location = diagnostic.location();
+        let source_code = location.source_code;
+        let source_file = source_code.map(SourceFile::new);
+
+        let region = location
+            .span
+            .zip(source_file.as_ref())
+            .and_then(|(span, source_file)| SarifRegion::from_range(source_file, span));
+
+        let fixes = match (&uri, source_code, &source_file) {
+            (Some(uri), Some(source_code), Some(source_file)) => {
+                let mut visitor = FixesVisitor::default();
+                // The fixes are best effort, they're omitted if the advices
+                // can't be visited
+                match diagnostic.advices(&mut visitor) {
+                    Ok(()) => visitor
+                        .fixes
+                        .into_iter()
+                        .filter_map(|(description, edit)| {
+                            SarifFix::from_text_edit(
+                                description,
+                                &edit,
+                                uri,
+                                source_code.text,
+                                source_file,
+                            )
+                        })
+                        .collect(),
+                    Err(_) => Vec::new(),
+                }
+            }
+            _ => Vec::new(),
+        };
+
+        let locations = match uri {
+            Some(uri) => vec![SarifLocation {
+                physical_location: SarifPhysicalLocation {
+                    artifact_location: SarifArtifactLocation { uri: uri.clone() },
+                    region,
+                },
+            }],
+            None => Vec::new(),
+        };
+
+        Self {
+            rule_id: diagnostic.category().map(|category| category.name()),
+            rule_index: None,
+            level: match diagnostic.severity() {
+                Severity::Hint => "note",
+                Severity::Information => "note",
+                Severity::Warning => "warning",
+                Severity::Error => "error",
+                Severity::Fatal => "error",
+            },
+            message: SarifMessage {
+                text: PrintDescription(diagnostic).to_string(),
+            },
+            locations,
+            fixes,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<SarifRegion>,
+}
+
+#[derive(Serialize)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+/// A region of a file. Lines and columns are one-based, and the end column
+/// is exclusive.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+impl SarifRegion {
+    fn from_range(source_file: &SourceFile, range: TextRange) -> Option<Self> {
+        let start = source_file.location(range.start()).ok()?;
+        let end = source_file.location(range.end()).ok()?;
+
+        Some(Self {
+            start_line: start.line_number.get(),
+            start_column: start.column_number.get(),
+            end_line: end.line_number.get(),
+            end_column: end.column_number.get(),
+        })
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifFix {
+    description: SarifMessage,
+    artifact_changes: Vec<SarifArtifactChange>,
+}
+
+impl SarifFix {
+    /// Converts the diff of a code action into the replacements of a fix.
+    fn from_text_edit(
+        description: String,
+        edit: &TextEdit,
+        uri: &str,
+        source: &str,
+        source_file: &SourceFile,
+    ) -> Option<Self> {
+        let replacements = text_edit_replacements(edit, source)?;
+        if replacements.is_empty() {
+            return None;
+        }
+
+        let replacements = replacements
+            .into_iter()
+            .map(|(deleted, inserted)| {
+                Some(SarifReplacement {
+                    deleted_region: SarifRegion::from_range(source_file, deleted)?,
+                    inserted_content: SarifInsertedContent { text: inserted },
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Self {
+            description: SarifMessage { text: description },
+            artifact_changes: vec![SarifArtifactChange {
+                artifact_location: SarifArtifactLocation {
+                    uri: uri.to_string(),
+                },
+                replacements,
+            }],
+        })
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactChange {
+    artifact_location: SarifArtifactLocation,
+    replacements: Vec<SarifReplacement>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifReplacement {
+    deleted_region: SarifRegion,
+    inserted_content: SarifInsertedContent,
+}
+
+#[derive(Serialize)]
+struct SarifInsertedContent {
+    text: String,
+}
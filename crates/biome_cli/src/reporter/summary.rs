@@ -1,6 +1,8 @@
+use crate::execute::relative_path;
 use crate::reporter::terminal::ConsoleTraversalSummary;
 use crate::reporter::{EvaluatedPathsDiagnostic, FixedPathsDiagnostic};
 use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary};
+use biome_analyze::profiling::{FilePhase, Profile};
 use biome_console::fmt::{Display, Formatter};
 use biome_console::{Console, ConsoleExt, markup};
 use biome_diagnostics::advice::ListAdvice;
@@ -9,26 +11,32 @@ use biome_diagnostics::{
     Visit, category,
 };
 use biome_fs::BiomePath;
-use camino::Utf8PathBuf;
+use camino::{Utf8Path, Utf8PathBuf};
 use std::cmp::Ordering;
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Debug;
 use std::io;
+use std::time::Duration;
 
-pub(crate) struct SummaryReporter {
+pub(crate) struct SummaryReporter<'a> {
     pub(crate) summary: TraversalSummary,
     pub(crate) diagnostics_payload: DiagnosticsPayload,
     pub(crate) execution: Execution,
     pub(crate) evaluated_paths: BTreeSet<BiomePath>,
     pub(crate) working_directory: Option<Utf8PathBuf>,
     pub(crate) verbose: bool,
+    /// The profile recorded with `--profile`
+    pub(crate) profile: Option<&'a Profile>,
 }
 
-impl Reporter for SummaryReporter {
+impl Reporter for SummaryReporter<'_> {
     fn write(self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
         visitor.report_diagnostics(&self.execution, self.diagnostics_payload, self.verbose)?;
         if self.verbose {
-            visitor.report_handled_paths(self.evaluated_paths, self.working_directory)?;
+            visitor.report_handled_paths(self.evaluated_paths, self.working_directory.clone())?;
+        }
+        if let Some(profile) = self.profile {
+            visitor.report_profile(profile, self.working_directory.as_deref())?;
         }
         visitor.report_summary(&self.execution, self.summary, self.verbose)?;
         Ok(())
@@ -184,6 +192,18 @@ impl ReporterVisitor for SummaryReporterVisitor<'_> {
 
         Ok(())
     }
+
+    fn report_profile(
+        &mut self,
+        profile: &Profile,
+        working_directory: Option<&Utf8Path>,
+    ) -> io::Result<()> {
+        self.0.log(markup! {
+            {ConsoleProfile(profile, working_directory)}
+        });
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Default)]
@@ -412,3 +432,92 @@ impl Display for DiagnosticsBySeverity {
         Ok(())
     }
 }
+
+/// The number of entries printed for each list of the profile
+const PROFILE_ENTRIES: usize = 10;
+
+/// Prints the time spent in each phase, and the slowest rules, plugins and
+/// files of a profile.
+pub(crate) struct ConsoleProfile<'a>(pub(crate) &'a Profile, pub(crate) Option<&'a Utf8Path>);
+
+impl Display for ConsoleProfile<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let Self(profile, working_directory) = *self;
+
+        let phases = [
+            ("scan", profile.scan()),
+            ("module graph", profile.module_graph()),
+        ]
+        .into_iter()
+        .chain(
+            FilePhase::ALL
+                .into_iter()
+                .map(|phase| (phase.as_str(), profile.phase(phase))),
+        )
+        .filter(|(_, timing)| timing.count > 0)
+        .map(|(name, timing)| (name.to_string(), timing.duration))
+        .collect();
+
+        let rules = profile
+            .rules()
+            .iter()
+            .map(|(rule, timing)| (rule.to_string(), timing.duration))
+            .collect();
+
+        let plugins = profile
+            .plugins()
+            .iter()
+            .map(|(name, timing)| (name.clone(), timing.duration))
+            .collect();
+
+        let files = profile
+            .files()
+            .iter()
+            .map(|(path, file)| {
+                (
+                    relative_path(path, working_directory).to_string(),
+                    file.duration(),
+                )
+            })
+            .collect();
+
+        fmt.write_markup(markup! {
+            <Emphasis>"Profile"</Emphasis>"\n"
+            {ProfileSection("Time spent by phase", phases, false)}
+            {ProfileSection("Slowest rules", rules, true)}
+            {ProfileSection("Slowest plugins", plugins, true)}
+            {ProfileSection("Slowest files", files, true)}
+        })
+    }
+}
+
+/// A list of entries of the profile, with their duration
+struct ProfileSection<'a>(&'a str, Vec<(String, Duration)>, bool);
+
+impl Display for ProfileSection<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let Self(title, entries, sort) = self;
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        let mut entries: Vec<_> = entries.iter().collect();
+        if *sort {
+            entries.sort_by(|(name_a, a), (name_b, b)| b.cmp(a).then(name_a.cmp(name_b)));
+            entries.truncate(PROFILE_ENTRIES);
+        }
+        let width = entries
+            .iter()
+            .map(|(name, _)| name.len())
+            .max()
+            .unwrap_or_default();
+
+        fmt.write_markup(markup! { "\n  "<Info>{title}</Info>"\n" })?;
+        for (name, duration) in entries {
+            let name = format!("{name:<width$}");
+            fmt.write_markup(markup! { "    "{name}"  "{duration}"\n" })?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,33 +1,40 @@
 use crate::Reporter;
 use crate::execute::{Execution, TraversalMode};
+use crate::reporter::summary::ConsoleProfile;
 use crate::reporter::{
     DiagnosticsPayload, EvaluatedPathsDiagnostic, FixedPathsDiagnostic, ReporterVisitor,
     TraversalSummary,
 };
+use biome_analyze::profiling::Profile;
 use biome_console::fmt::Formatter;
 use biome_console::{Console, ConsoleExt, fmt, markup};
-use biome_diagnostics::PrintDiagnostic;
 use biome_diagnostics::advice::ListAdvice;
+use biome_diagnostics::{Error, PrintDescription, PrintDiagnostic, category};
 use biome_fs::BiomePath;
-use camino::Utf8PathBuf;
+use camino::{Utf8Path, Utf8PathBuf};
 use std::collections::BTreeSet;
 use std::io;
 use std::time::Duration;
 
-pub(crate) struct ConsoleReporter {
+pub(crate) struct ConsoleReporter<'a> {
     pub(crate) summary: TraversalSummary,
     pub(crate) diagnostics_payload: DiagnosticsPayload,
     pub(crate) execution: Execution,
     pub(crate) evaluated_paths: BTreeSet<BiomePath>,
     pub(crate) working_directory: Option<Utf8PathBuf>,
     pub(crate) verbose: bool,
+    /// The profile recorded with `--profile`
+    pub(crate) profile: Option<&'a Profile>,
 }
 
-impl Reporter for ConsoleReporter {
+impl Reporter for ConsoleReporter<'_> {
     fn write(self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
         visitor.report_diagnostics(&self.execution, self.diagnostics_payload, self.verbose)?;
         if self.verbose {
-            visitor.report_handled_paths(self.evaluated_paths, self.working_directory)?;
+            visitor.report_handled_paths(self.evaluated_paths, self.working_directory.clone())?;
+        }
+        if let Some(profile) = self.profile {
+            visitor.report_profile(profile, self.working_directory.as_deref())?;
         }
         visitor.report_summary(&self.execution, self.summary, self.verbose)?;
         Ok(())
@@ -37,6 +44,18 @@ impl Reporter for ConsoleReporter {
 pub(crate) struct ConsoleReporterVisitor<'a>(pub(crate) &'a mut dyn Console);
 
 impl ReporterVisitor for ConsoleReporterVisitor<'_> {
+    fn report_profile(
+        &mut self,
+        profile: &Profile,
+        working_directory: Option<&Utf8Path>,
+    ) -> io::Result<()> {
+        self.0.log(markup! {
+            {ConsoleProfile(profile, working_directory)}
+        });
+
+        Ok(())
+    }
+
     fn report_summary(
         &mut self,
         execution: &Execution,
@@ -123,7 +142,7 @@ impl ReporterVisitor for ConsoleReporterVisitor<'_> {
         verbose: bool,
     ) -> io::Result<()> {
         for diagnostic in &diagnostics_payload.diagnostics {
-            if execution.is_search() {
+            if execution.is_search() && is_search_match(diagnostic) {
                 self.0.log(markup! {{PrintDiagnostic::search(diagnostic)}});
                 continue;
             }
@@ -143,6 +162,14 @@ impl ReporterVisitor for ConsoleReporterVisitor<'_> {
     }
 }
 
+/// The matches of a search pattern don't have a message, they're printed as
+/// the matched code. The other diagnostics of a search, such as the rewrites
+/// and their conflicts, are printed like any other diagnostic.
+fn is_search_match(diagnostic: &Error) -> bool {
+    diagnostic.category() == Some(category!("search"))
+        && PrintDescription(diagnostic).to_string().is_empty()
+}
+
 struct Files(usize);
 
 impl fmt::Display for Files {
@@ -161,8 +188,14 @@ struct SummaryDetail<'a>(pub(crate) &'a TraversalMode, usize);
 impl fmt::Display for SummaryDetail<'_> {
     fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
         let Self(mode, files) = self;
-        if let TraversalMode::Search { .. } = mode {
-            return Ok(());
+        if let TraversalMode::Search { write, .. } = mode {
+            return if *write && *files > 0 {
+                fmt.write_markup(markup! {
+                    " Rewrote "{Files(*files)}"."
+                })
+            } else {
+                Ok(())
+            };
         }
 
         if *files > 0 {
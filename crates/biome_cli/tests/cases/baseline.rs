@@ -0,0 +1,240 @@
+use crate::snap_test::SnapshotPayload;
+use crate::{assert_cli_snapshot, run_cli};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use bpaf::Args;
+use camino::Utf8Path;
+
+const BEFORE: &str = r#"function f() {
+    debugger;
+    debugger;
+}
+"#;
+
+#[test]
+fn write_baseline_records_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), BEFORE.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--write-baseline=biome-baseline.json",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "write_baseline_records_diagnostics",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn baseline_reports_only_new_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), BEFORE.as_bytes());
+
+    let (mut fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--write-baseline=biome-baseline.json",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    // The existing diagnostics moved down, and a new one is added
+    fs.insert(
+        file_path.into(),
+        r#"const a = 1;
+
+function f() {
+        debugger;
+    debugger;
+    a == 2;
+}
+"#
+        .as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--baseline=biome-baseline.json", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "baseline_reports_only_new_diagnostics",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn baseline_reports_stale_entries() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), BEFORE.as_bytes());
+
+    let (mut fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--write-baseline=biome-baseline.json",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    fs.insert(
+        file_path.into(),
+        r#"function f() {
+    debugger;
+}
+"#
+        .as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--baseline=biome-baseline.json", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "baseline_reports_stale_entries",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn write_baseline_keeps_entries_of_files_not_linted() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path1 = Utf8Path::new("a.js");
+    fs.insert(file_path1.into(), BEFORE.as_bytes());
+    let file_path2 = Utf8Path::new("b.js");
+    fs.insert(file_path2.into(), BEFORE.as_bytes());
+
+    let (mut fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--write-baseline=biome-baseline.json",
+                file_path1.as_str(),
+                file_path2.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    fs.insert(
+        file_path1.into(),
+        r#"function f() {
+    debugger;
+}
+"#
+        .as_bytes(),
+    );
+
+    // Only `a.js` is linted, so the entries of `b.js` must be kept
+    let mut console = BufferConsole::default();
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--write-baseline=biome-baseline.json",
+                file_path1.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "write_baseline_keeps_entries_of_files_not_linted",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn err_when_both_baseline_and_write_baseline_are_passed() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), BEFORE.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--baseline=biome-baseline.json",
+                "--write-baseline=biome-baseline.json",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "err_when_both_baseline_and_write_baseline_are_passed",
+        fs,
+        console,
+        result,
+    ));
+}
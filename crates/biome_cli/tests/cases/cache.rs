@@ -0,0 +1,188 @@
+use crate::snap_test::SnapshotPayload;
+use crate::{assert_cli_snapshot, run_cli, run_cli_with_server_workspace};
+use biome_console::BufferConsole;
+use biome_fs::{FileSystem, MemoryFileSystem};
+use bpaf::Args;
+use camino::Utf8Path;
+use serde_json::Value;
+
+const CACHE_FILE: &str = ".biomecache";
+
+fn read_cache(fs: &MemoryFileSystem) -> Value {
+    let content = fs
+        .read_file_from_path(Utf8Path::new(CACHE_FILE))
+        .expect("the cache file must exist");
+    serde_json::from_str(&content).expect("the cache file must be valid JSON")
+}
+
+#[test]
+fn cache_replays_results_of_unchanged_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let (mut fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["check", "--cache", file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    // The result stored in the cache is replayed instead of the one of the
+    // file, so altering it shows that the file isn't processed again
+    let mut cache = read_cache(&fs);
+    let entry = &mut cache["files"][file_path.as_str()];
+    assert!(
+        entry.is_object(),
+        "the cache must have an entry for the file"
+    );
+    entry["messages"] = Value::Array(Vec::new());
+    entry["status"] = Value::Null;
+    fs.insert(CACHE_FILE.into(), cache.to_string().as_bytes());
+
+    let mut console = BufferConsole::default();
+    let (mut fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["check", "--cache", file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    fs.remove(Utf8Path::new(CACHE_FILE));
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "cache_replays_results_of_unchanged_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn cache_processes_changed_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), "let a = 1;\n".as_bytes());
+
+    let (mut fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["check", "--cache", file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    fs.insert(file_path.into(), "debugger;\nlet a  =  1;\n".as_bytes());
+
+    let mut console = BufferConsole::default();
+    let (mut fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["check", "--cache", file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    fs.remove(Utf8Path::new(CACHE_FILE));
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "cache_processes_changed_files",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn cache_processes_files_whose_dependencies_changed() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{
+    "linter": {
+        "rules": {
+            "nursery": {
+                "noImportCycles": "error"
+            }
+        }
+    }
+}
+"#
+        .as_bytes(),
+    );
+    let a_path = Utf8Path::new("src/a.js");
+    fs.insert(
+        a_path.into(),
+        "import { b } from \"./b.js\";\n\nexport const a = b;\n".as_bytes(),
+    );
+    let b_path = Utf8Path::new("src/b.js");
+    fs.insert(b_path.into(), "export const b = 1;\n".as_bytes());
+
+    let (mut fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(["lint", "--cache", a_path.as_str(), b_path.as_str()].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    // `a.js` doesn't change, but it's now part of an import cycle
+    fs.insert(
+        b_path.into(),
+        "import { a } from \"./a.js\";\n\nexport const b = a;\n".as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let (mut fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(["lint", "--cache", a_path.as_str(), b_path.as_str()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    fs.remove(Utf8Path::new(CACHE_FILE));
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "cache_processes_files_whose_dependencies_changed",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn cache_doesnt_replay_patch_results_when_writing() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), "let a  =  1;\n".as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["check", "--reporter=patch", "--cache", file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    // The patch doesn't write the changes, so `--write` must apply them
+    let mut console = BufferConsole::default();
+    let (mut fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["check", "--write", "--cache", file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    fs.remove(Utf8Path::new(CACHE_FILE));
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "cache_doesnt_replay_patch_results_when_writing",
+        fs,
+        console,
+        result,
+    ));
+}
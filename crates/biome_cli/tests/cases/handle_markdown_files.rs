@@ -0,0 +1,96 @@
+use crate::run_cli;
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot, assert_file_contents};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use bpaf::Args;
+use camino::Utf8Path;
+
+const MARKDOWN: &str = r#"# Title
+
+```js
+const message  =  "hello"
+const template = `
+  
+`
+```
+
+> ```json
+> {"a":1,"b":[1,2,3]}
+> ```
+
+- ```ts
+  let value :string = `
+      
+  `
+  ```
+"#;
+
+#[test]
+fn format_embedded_code_with_language_settings() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{
+    "markdown": { "formatter": { "enabled": true } },
+    "javascript": { "formatter": { "quoteStyle": "single", "semicolons": "asNeeded" } },
+    "json": { "formatter": { "expand": "always" } }
+}"#
+        .as_bytes(),
+    );
+    let markdown_file = Utf8Path::new("file.md");
+    fs.insert(markdown_file.into(), MARKDOWN.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", markdown_file.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_embedded_code_with_language_settings",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn keep_embedded_code_when_its_formatter_is_disabled() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{
+    "markdown": { "formatter": { "enabled": true } },
+    "javascript": { "formatter": { "enabled": false } },
+    "json": { "formatter": { "enabled": false } }
+}"#
+        .as_bytes(),
+    );
+    let markdown_file = Utf8Path::new("file.md");
+    fs.insert(markdown_file.into(), MARKDOWN.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--write", markdown_file.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, markdown_file, MARKDOWN);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "keep_embedded_code_when_its_formatter_is_disabled",
+        fs,
+        console,
+        result,
+    ));
+}
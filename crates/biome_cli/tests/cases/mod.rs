@@ -2,7 +2,9 @@
 //! case that affects many commands
 
 mod assist;
+mod baseline;
 mod biome_json_support;
+mod cache;
 mod config_extends;
 mod config_path;
 mod cts_files;
@@ -11,6 +13,7 @@ mod editorconfig;
 mod graphql;
 mod handle_astro_files;
 mod handle_css_files;
+mod handle_markdown_files;
 mod handle_svelte_files;
 mod handle_vue_files;
 mod included_files;
@@ -22,13 +25,19 @@ mod overrides_formatter;
 mod overrides_linter;
 mod overrides_max_file_size;
 mod overrides_organize_imports;
+mod profile;
 mod protected_files;
+mod reporter_checkstyle;
 mod reporter_github;
 mod reporter_gitlab;
 mod reporter_junit;
+mod reporter_patch;
+mod reporter_rdjson;
+mod reporter_sarif;
 mod reporter_summary;
 mod reporter_terminal;
 mod rules_via_dependencies;
 mod suppressions;
 mod unknown_files;
 mod vcs_ignored_files;
+mod watch;
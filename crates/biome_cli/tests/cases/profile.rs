@@ -0,0 +1,203 @@
+use crate::snap_test::SnapshotPayload;
+use crate::{assert_cli_snapshot, run_cli, run_cli_with_server_workspace};
+use biome_console::BufferConsole;
+use biome_fs::{FileSystem, MemoryFileSystem};
+use bpaf::Args;
+use camino::Utf8Path;
+use serde_json::Value;
+use std::sync::Mutex;
+
+/// The profile is global to the process, so the tests recording it can't run
+/// at the same time. The plugin of these tests has a name that no other test
+/// uses, so the tests running in the meantime can't change its count.
+static PROFILE: Mutex<()> = Mutex::new(());
+
+fn read_profile(fs: &MemoryFileSystem, path: &str) -> Value {
+    let content = fs
+        .read_file_from_path(Utf8Path::new(path))
+        .expect("the profile file must exist");
+    serde_json::from_str(&content).expect("the profile file must be valid JSON")
+}
+
+fn find<'a>(list: &'a Value, key: &str, value: &str) -> Option<&'a Value> {
+    list.as_array()
+        .expect("must be an array")
+        .iter()
+        .find(|entry| entry[key] == value)
+}
+
+#[test]
+fn profile_prints_slowest_rules_and_files() {
+    let _guard = PROFILE.lock().unwrap_or_else(|error| error.into_inner());
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let (_, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--profile",
+                "--reporter=summary",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let profile = console
+        .out_buffer
+        .iter()
+        .map(|message| format!("{:?}", message.content))
+        .find(|content| content.contains("Profile"))
+        .expect("the profile must be printed");
+    for expected in [
+        "Time spent by phase",
+        "parse",
+        "analysis",
+        "Slowest rules",
+        "suspicious/noDebugger",
+        "Slowest files",
+        "file.js",
+    ] {
+        assert!(
+            profile.contains(expected),
+            "the profile must contain {expected:?}: {profile}"
+        );
+    }
+}
+
+#[test]
+fn profile_output_json() {
+    let _guard = PROFILE.lock().unwrap_or_else(|error| error.into_inner());
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["check", "--profile-output=profile.json", file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let profile = read_profile(&fs, "profile.json");
+    let rule = find(&profile["rules"], "name", "suspicious/noDebugger")
+        .expect("the profile must contain the rules that ran");
+    assert!(rule["count"].as_u64().unwrap() > 0);
+
+    let file = find(&profile["files"], "path", file_path.as_str())
+        .expect("the profile must contain the file");
+    for phase in ["parse", "analysis", "format"] {
+        assert!(
+            file["phases"][phase]["count"].as_u64().unwrap() > 0,
+            "the {phase} of the file must be recorded: {file}"
+        );
+    }
+    assert!(profile["phases"]["parse"]["count"].as_u64().unwrap() > 0);
+    assert!(profile["scan"]["duration"].is_u64());
+}
+
+#[test]
+fn profile_output_chrome_trace() {
+    let _guard = PROFILE.lock().unwrap_or_else(|error| error.into_inner());
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "lint",
+                "--profile-output=trace.json",
+                "--profile-format=chrome-trace",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let trace = read_profile(&fs, "trace.json");
+    let events = trace["traceEvents"]
+        .as_array()
+        .expect("the trace must have events");
+    let parse = events
+        .iter()
+        .find(|event| event["name"] == "parse" && event["args"]["path"] == file_path.as_str())
+        .expect("the trace must contain the parsing of the file");
+    assert_eq!(parse["ph"], "X");
+    assert_eq!(parse["cat"], "file");
+    assert!(parse["ts"].is_number());
+    assert!(parse["dur"].is_number());
+}
+
+#[test]
+fn profile_records_plugins() {
+    let _guard = PROFILE.lock().unwrap_or_else(|error| error.into_inner());
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        "biome.json".into(),
+        br#"{ "plugins": ["profiledZIndex.grit"] }"#,
+    );
+    fs.insert(
+        "profiledZIndex.grit".into(),
+        br#"language css
+
+`z-index: $zIndexValue;` where {
+    register_diagnostic(span=$zIndexValue, message="z-index values should be set using the design library.", severity="error")
+}
+"#,
+    );
+    let file_path = Utf8Path::new("style.css");
+    fs.insert(file_path.into(), ".a {\n  z-index: 1;\n}\n".as_bytes());
+
+    let (fs, result) = run_cli_with_server_workspace(
+        fs,
+        &mut console,
+        Args::from(["lint", "--profile-output=profile.json", file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let profile = read_profile(&fs, "profile.json");
+    let plugin = find(&profile["plugins"], "name", "profiledZIndex")
+        .expect("the profile must contain the plugins that ran");
+    assert_eq!(plugin["count"], 1);
+}
+
+#[test]
+fn profile_incompatible_with_use_server() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["lint", "--profile", "--use-server", file_path.as_str()].as_slice()),
+    );
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "profile_incompatible_with_use_server",
+        fs,
+        console,
+        result,
+    ));
+}
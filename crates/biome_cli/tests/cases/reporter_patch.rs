@@ -0,0 +1,174 @@
+use crate::run_cli;
+use crate::snap_test::{SnapshotPayload, assert_cli_snapshot, assert_file_contents};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use bpaf::Args;
+use camino::Utf8Path;
+
+const MAIN: &str = r#"const value = 1
+if (!!value) {
+  console.log( "value" )
+}
+"#;
+
+const FORMATTED: &str = r#"const value = 1;
+if (value) {
+	console.log("value");
+}
+"#;
+
+#[test]
+fn reports_patch_check_command() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("main.js");
+    fs.insert(file_path.into(), MAIN.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["check", "--reporter=patch", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    // The patch is only printed
+    assert_file_contents(&fs, file_path, MAIN);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_patch_check_command",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_patch_format_command() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path1 = Utf8Path::new("main.js");
+    fs.insert(file_path1.into(), MAIN.as_bytes());
+
+    let file_path2 = Utf8Path::new("formatted.js");
+    fs.insert(file_path2.into(), FORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--reporter=patch",
+                file_path1.as_str(),
+                file_path2.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path1, MAIN);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_patch_format_command",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_patch_ci_command() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("main.js");
+    fs.insert(file_path.into(), MAIN.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["ci", "--reporter=patch", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_patch_ci_command",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_empty_patch_when_nothing_changes() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("formatted.js");
+    fs.insert(file_path.into(), FORMATTED.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["check", "--reporter=patch", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_empty_patch_when_nothing_changes",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn reports_patch_without_sanitizing_it() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("main.js");
+    fs.insert(
+        file_path.into(),
+        "\u{feff}const value =  \"a\u{200b}b\"\n".as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["format", "--reporter=patch", file_path.as_str()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let patch = console
+        .out_buffer
+        .iter()
+        .flat_map(|message| &message.content.0)
+        .map(|node| node.content.as_str())
+        .find(|content| content.starts_with("--- a/main.js"))
+        .expect("the patch must be printed");
+    assert!(
+        patch.contains("+\u{feff}const value = \"a\u{200b}b\";\n"),
+        "the patch must contain the characters of the file as is:\n{patch}"
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "reports_patch_without_sanitizing_it",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,255 @@
+use crate::snap_test::SnapshotPayload;
+use crate::{assert_cli_snapshot, run_cli, run_cli_with_dyn_fs};
+use biome_console::BufferConsole;
+use biome_fs::{MemoryFileSystem, TemporaryFs};
+use bpaf::Args;
+use camino::Utf8Path;
+
+#[test]
+fn config_print_help() {
+    let fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["config", "print", "--help"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "config_print_help",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn config_print_group_severity() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{
+    "linter": {
+        "rules": {
+            "recommended": false,
+            "security": "warn"
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["config", "print", "file.js"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "config_print_group_severity",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn config_print_extends_and_overrides() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Utf8Path::new("shared.json").into(),
+        r#"{
+    "formatter": { "indentStyle": "space", "indentWidth": 4 },
+    "javascript": { "formatter": { "quoteStyle": "single" } },
+    "linter": {
+        "rules": {
+            "recommended": false,
+            "suspicious": { "noDebugger": "error" }
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{
+    "extends": ["shared.json"],
+    "formatter": { "indentWidth": 2 },
+    "linter": {
+        "rules": {
+            "style": {
+                "useNamingConvention": {
+                    "level": "warn",
+                    "options": { "strictCase": false }
+                }
+            }
+        }
+    },
+    "overrides": [
+        {
+            "includes": ["test/**"],
+            "linter": { "rules": { "suspicious": { "noDebugger": "off" } } }
+        },
+        {
+            "includes": ["src/**"],
+            "formatter": { "lineWidth": 120 },
+            "linter": { "rules": { "style": { "useConst": "info" } } }
+        }
+    ]
+}"#
+        .as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["config", "print", "src/index.js"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "config_print_extends_and_overrides",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn config_print_nested_configuration() {
+    let mut console = BufferConsole::default();
+    let mut fs = TemporaryFs::new("config_print_nested_configuration");
+
+    fs.create_file(
+        "biome.json",
+        r#"{
+    "formatter": { "lineWidth": 100 },
+    "linter": { "rules": { "recommended": false, "suspicious": { "noDebugger": "error" } } }
+}"#,
+    );
+    fs.create_file(
+        "packages/lib/biome.json",
+        r#"{
+    "root": false,
+    "extends": "//",
+    "linter": { "rules": { "suspicious": { "noDebugger": "warn" } } }
+}"#,
+    );
+
+    let result = run_cli_with_dyn_fs(
+        Box::new(fs.create_os()),
+        &mut console,
+        Args::from(["config", "print", "packages/lib/index.ts"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "config_print_nested_configuration",
+        fs.create_mem(),
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn config_print_nested_configuration_overrides() {
+    let mut console = BufferConsole::default();
+    let mut fs = TemporaryFs::new("config_print_nested_configuration_overrides");
+
+    fs.create_file(
+        "biome.json",
+        r#"{
+    "linter": { "rules": { "recommended": false } },
+    "overrides": [
+        { "includes": ["**/*.ts"], "formatter": { "lineWidth": 80 } }
+    ]
+}"#,
+    );
+    fs.create_file(
+        "packages/lib/biome.json",
+        r#"{
+    "root": false,
+    "extends": "//",
+    "overrides": [
+        {
+            "includes": ["index.ts"],
+            "linter": { "rules": { "suspicious": { "noDebugger": "warn" } } }
+        }
+    ]
+}"#,
+    );
+
+    let result = run_cli_with_dyn_fs(
+        Box::new(fs.create_os()),
+        &mut console,
+        Args::from(["config", "print", "packages/lib/index.ts"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "config_print_nested_configuration_overrides",
+        fs.create_mem(),
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn config_print_editorconfig() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Utf8Path::new(".editorconfig").into(),
+        r#"
+[*]
+indent_style = tab
+
+[*.json]
+indent_style = space
+indent_size = 2
+"#
+        .as_bytes(),
+    );
+    fs.insert(
+        Utf8Path::new("biome.json").into(),
+        r#"{
+    "formatter": { "useEditorconfig": true, "lineWidth": 100 },
+    "linter": { "rules": { "recommended": false } }
+}"#
+        .as_bytes(),
+    );
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(["config", "print", "package.json"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "config_print_editorconfig",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -1,5 +1,6 @@
 mod check;
 mod ci;
+mod config;
 mod explain;
 mod format;
 mod init;
@@ -9,5 +10,6 @@ mod migrate;
 mod migrate_eslint;
 mod migrate_prettier;
 mod rage;
+mod rules;
 mod search;
 mod version;
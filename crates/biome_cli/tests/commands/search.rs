@@ -5,7 +5,7 @@ use camino::Utf8Path;
 
 use crate::{
     run_cli,
-    snap_test::{SnapshotPayload, assert_cli_snapshot},
+    snap_test::{SnapshotPayload, assert_cli_snapshot, assert_file_contents},
 };
 
 // Feel free to add content at the end of this dummy file. It shouldn't affect
@@ -169,3 +169,156 @@ fn search_css_pattern_skips_js_files() {
         result,
     ));
 }
+
+const JS_REWRITE_CONTENT: &str = r#"console.log(  "hello"  );
+function greet() {
+    console.log("world")
+}
+"#;
+
+#[test]
+fn search_rewrite_prints_diff() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), JS_REWRITE_CONTENT.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "search",
+                "`console.log($message)` => `console.info($message)`",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, JS_REWRITE_CONTENT);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_rewrite_prints_diff",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_rewrite_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), JS_REWRITE_CONTENT.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "search",
+                "--write",
+                "`console.log($message)` => `console.info($message)`",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        r#"console.info("hello");
+function greet() {
+    console.info("world");
+}
+"#,
+    );
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_rewrite_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_rewrite_reports_conflicts() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), JS_FILE_CONTENT.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "search",
+                "--write",
+                "`'foo'` as $string where { $string => `'bar'`, $string => `'baz'` }",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, JS_FILE_CONTENT);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_rewrite_reports_conflicts",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn search_rewrite_reports_patch() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Utf8Path::new("file.js");
+    fs.insert(file_path.into(), JS_REWRITE_CONTENT.as_bytes());
+
+    let (fs, result) = run_cli(
+        fs,
+        &mut console,
+        Args::from(
+            [
+                "search",
+                "--reporter=patch",
+                "`console.log($message)` => `console.info($message)`",
+                file_path.as_str(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, JS_REWRITE_CONTENT);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "search_rewrite_reports_patch",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -377,7 +377,7 @@ pub(crate) fn run_cli_with_dyn_fs(
     let workspace = workspace::client(transport, fs).unwrap();
     let app = App::new(console, WorkspaceRef::Owned(workspace));
 
-    let mut session = CliSession { app };
+    let mut session = CliSession { app, watcher: None };
     let command = biome_command().run_inner(args);
     match command {
         Ok(command) => session.run(command),
@@ -407,7 +407,7 @@ pub(crate) fn run_cli_with_server_workspace(
     let workspace = workspace::server(Box::new(fs), None);
     let app = App::new(console, WorkspaceRef::Owned(workspace));
 
-    let mut session = CliSession { app };
+    let mut session = CliSession { app, watcher: None };
     let command = biome_command().run_inner(args);
     let result = match command {
         Ok(command) => session.run(command),
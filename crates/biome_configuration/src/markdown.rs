@@ -0,0 +1,70 @@
+use crate::bool::Bool;
+use biome_deserialize_macros::{Deserializable, Merge};
+use biome_formatter::{IndentStyle, IndentWidth, LineEnding, LineWidth};
+use biome_markdown_formatter::context::ProseWrap;
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+/// Options applied to Markdown files
+#[derive(
+    Bpaf, Clone, Default, Debug, Deserializable, Deserialize, Eq, Merge, PartialEq, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct MarkdownConfiguration {
+    /// Markdown formatter options
+    #[bpaf(external(markdown_formatter_configuration), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formatter: Option<MarkdownFormatterConfiguration>,
+}
+
+pub type MarkdownFormatterEnabled = Bool<false>; // Keep it disabled by default while experimental.
+
+/// Options that changes how the Markdown formatter behaves
+#[derive(
+    Bpaf, Clone, Debug, Default, Deserializable, Deserialize, Eq, Merge, PartialEq, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct MarkdownFormatterConfiguration {
+    /// Control the formatter for Markdown files.
+    #[bpaf(long("markdown-formatter-enabled"), argument("true|false"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<MarkdownFormatterEnabled>,
+
+    /// The indent style applied to the code blocks of Markdown files.
+    #[bpaf(long("markdown-formatter-indent-style"), argument("tab|space"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indent_style: Option<IndentStyle>,
+
+    /// The size of the indentation applied to the code blocks of Markdown files. Default to 2.
+    #[bpaf(long("markdown-formatter-indent-width"), argument("NUMBER"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indent_width: Option<IndentWidth>,
+
+    /// The type of line ending applied to Markdown files.
+    #[bpaf(long("markdown-formatter-line-ending"), argument("lf|crlf|cr"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_ending: Option<LineEnding>,
+
+    /// What's the max width of a line applied to Markdown files. Defaults to 80.
+    #[bpaf(long("markdown-formatter-line-width"), argument("NUMBER"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_width: Option<LineWidth>,
+
+    /// How the prose of Markdown files is wrapped: `always` wraps it at the line width, `never`
+    /// joins each paragraph on a single line, and `preserve` keeps the line breaks as they are.
+    /// Defaults to preserve.
+    #[bpaf(
+        long("markdown-formatter-prose-wrap"),
+        argument("always|never|preserve")
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prose_wrap: Option<ProseWrap>,
+}
+
+impl MarkdownFormatterConfiguration {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.unwrap_or_default().into()
+    }
+}
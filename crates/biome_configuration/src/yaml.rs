@@ -0,0 +1,92 @@
+use crate::bool::Bool;
+use biome_deserialize_macros::{Deserializable, Merge};
+use biome_formatter::{BracketSpacing, IndentWidth, LineEnding, LineWidth, QuoteStyle};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+/// Options applied to YAML files
+#[derive(
+    Bpaf, Clone, Default, Debug, Deserializable, Deserialize, Eq, Merge, PartialEq, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct YamlConfiguration {
+    /// YAML formatter options
+    #[bpaf(external(yaml_formatter_configuration), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formatter: Option<YamlFormatterConfiguration>,
+
+    /// YAML linter options
+    #[bpaf(external(yaml_linter_configuration), optional)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linter: Option<YamlLinterConfiguration>,
+}
+
+pub type YamlFormatterEnabled = Bool<false>; // Keep it disabled by default while experimental.
+
+/// Options that changes how the YAML formatter behaves
+#[derive(
+    Bpaf, Clone, Debug, Default, Deserializable, Deserialize, Eq, Merge, PartialEq, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct YamlFormatterConfiguration {
+    /// Control the formatter for YAML files.
+    #[bpaf(long("yaml-formatter-enabled"), argument("true|false"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<YamlFormatterEnabled>,
+
+    /// The size of the indentation applied to YAML files. Default to 2. YAML files are always
+    /// indented with spaces.
+    #[bpaf(long("yaml-formatter-indent-width"), argument("NUMBER"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indent_width: Option<IndentWidth>,
+
+    /// The type of line ending applied to YAML files.
+    #[bpaf(long("yaml-formatter-line-ending"), argument("lf|crlf|cr"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_ending: Option<LineEnding>,
+
+    /// What's the max width of a line applied to YAML files. Defaults to 80.
+    #[bpaf(long("yaml-formatter-line-width"), argument("NUMBER"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_width: Option<LineWidth>,
+
+    /// The type of quotes used in YAML files. Defaults to double. The quotes of a scalar are
+    /// changed only when its value doesn't need any new escape.
+    #[bpaf(long("yaml-formatter-quote-style"), argument("double|single"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_style: Option<QuoteStyle>,
+
+    /// Whether to insert spaces around the braces of flow mappings. Defaults to true.
+    #[bpaf(long("yaml-formatter-bracket-spacing"), argument("true|false"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bracket_spacing: Option<BracketSpacing>,
+}
+
+impl YamlFormatterConfiguration {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.unwrap_or_default().into()
+    }
+}
+
+pub type YamlLinterEnabled = Bool<true>;
+
+/// Options that change how the YAML linter behaves.
+#[derive(
+    Bpaf, Clone, Debug, Default, Deserializable, Deserialize, Eq, Merge, PartialEq, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct YamlLinterConfiguration {
+    /// Control the linter for YAML files.
+    #[bpaf(long("yaml-linter-enabled"), argument("true|false"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<YamlLinterEnabled>,
+}
+
+impl YamlLinterConfiguration {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.unwrap_or_default().into()
+    }
+}
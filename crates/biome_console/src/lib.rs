@@ -11,7 +11,7 @@ mod markup;
 mod utils;
 mod write;
 
-pub use self::markup::{Markup, MarkupBuf, MarkupElement, MarkupNode};
+pub use self::markup::{Markup, MarkupBuf, MarkupElement, MarkupNode, MarkupNodeBuf};
 pub use biome_markup::markup;
 pub use utils::*;
 
@@ -37,8 +37,15 @@ pub trait Console: Send + Sync + RefUnwindSafe {
     /// Prints a message (formatted using [markup!]) to the console.
     fn print(&mut self, level: LogLevel, args: Markup);
 
+    /// Writes the content to the console as is, without sanitizing it nor
+    /// adding a new line at the end.
+    fn print_raw(&mut self, level: LogLevel, content: &str);
+
     /// It reads from a source, and if this source contains something, it's converted into a [String]
     fn read(&mut self) -> Option<String>;
+
+    /// Clears the screen, if the console writes to a terminal.
+    fn clear(&mut self) {}
 }
 
 /// Extension trait for [Console] providing convenience printing methods
@@ -166,6 +173,15 @@ impl Console for EnvConsole {
         write!(out, "").unwrap();
     }
 
+    fn print_raw(&mut self, level: LogLevel, content: &str) {
+        let mut out = match level {
+            LogLevel::Error => self.err.lock(),
+            LogLevel::Log => self.out.lock(),
+        };
+
+        out.write_all(content.as_bytes()).unwrap();
+    }
+
     fn read(&mut self) -> Option<String> {
         // Here we check if stdin is redirected. If not, we bail.
         //
@@ -180,6 +196,17 @@ impl Console for EnvConsole {
         // Skipping the error for now
         if result.is_ok() { Some(buffer) } else { None }
     }
+
+    fn clear(&mut self) {
+        if !io::stdout().is_terminal() {
+            return;
+        }
+        let mut out = self.out.lock();
+        // Clears the screen and the scrollback, then moves the cursor to the
+        // top left corner
+        write!(out, "\x1b[2J\x1b[3J\x1b[H").unwrap();
+        out.flush().unwrap();
+    }
 }
 
 /// Implementation of [Console] storing all printed messages to a memory buffer
@@ -218,6 +245,16 @@ impl Console for BufferConsole {
             content: args.to_owned(),
         });
     }
+
+    fn print_raw(&mut self, level: LogLevel, content: &str) {
+        self.out_buffer.push(Message {
+            level,
+            content: MarkupBuf(vec![MarkupNodeBuf {
+                elements: Vec::new(),
+                content: content.to_string(),
+            }]),
+        });
+    }
     fn read(&mut self) -> Option<String> {
         if self.in_buffer.is_empty() {
             None
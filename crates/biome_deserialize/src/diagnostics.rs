@@ -197,6 +197,25 @@ impl DeserializationDiagnostic {
         self
     }
 
+    /// Returns the range of the value that caused the diagnostic, if any
+    pub fn range(&self) -> Option<TextRange> {
+        self.range
+    }
+
+    /// Returns the keys or values that are accepted in place of an unknown
+    /// key or value
+    ///
+    /// This is empty for the diagnostics that aren't about unknown keys or
+    /// values.
+    pub fn expected_names(&self) -> Vec<String> {
+        self.deserialization_advice
+            .notes
+            .iter()
+            .flat_map(|(_, list)| list)
+            .map(|item| item.0.iter().map(|node| node.content.as_str()).collect())
+            .collect()
+    }
+
     /// Adds a note to the diagnostic
     pub fn with_note(mut self, message: impl Display) -> Self {
         self.deserialization_advice
@@ -36,6 +36,12 @@ impl Diagnostic {
         Self::new_impl(diag.as_diagnostic())
     }
 
+    /// Creates a serializable diagnostic from a borrowed diagnostic, such as
+    /// an [Error](crate::Error) that is still needed afterwards.
+    pub fn from_ref<D: AsDiagnostic>(diag: &D) -> Self {
+        Self::new_impl(diag.as_diagnostic())
+    }
+
     fn new_impl<D: super::Diagnostic + ?Sized>(diag: &D) -> Self {
         let category = diag.category();
 
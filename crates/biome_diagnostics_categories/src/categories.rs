@@ -146,6 +146,7 @@ define_categories! {
     "lint/correctness/useValidTypeof": "https://biomejs.dev/linter/rules/use-valid-typeof",
     "lint/correctness/useYield": "https://biomejs.dev/linter/rules/use-yield",
     "lint/nursery/colorNoInvalidHex": "https://biomejs.dev/linter/rules/color-no-invalid-hex",
+    "lint/nursery/noAmbiguousBooleans": "https://biomejs.dev/linter/rules/no-ambiguous-booleans",
     "lint/nursery/noAwaitInLoop": "https://biomejs.dev/linter/rules/no-await-in-loop",
     "lint/nursery/noBitwiseOperators": "https://biomejs.dev/linter/rules/no-bitwise-operators",
     "lint/nursery/noColorInvalidHex": "https://biomejs.dev/linter/rules/no-color-invalid-hex",
@@ -154,6 +155,7 @@ define_categories! {
     "lint/nursery/noDestructuredProps": "https://biomejs.dev/linter/rules/no-destructured-props",
     "lint/nursery/noDoneCallback": "https://biomejs.dev/linter/rules/no-done-callback",
     "lint/nursery/noDuplicateAtImportRules": "https://biomejs.dev/linter/rules/no-duplicate-at-import-rules",
+    "lint/nursery/noDuplicateKeys": "https://biomejs.dev/linter/rules/no-duplicate-keys",
     "lint/nursery/noExcessiveLinesPerFunction": "https://biomejs.dev/linter/rules/no-excessive-lines-per-function",
     "lint/nursery/noFloatingPromises": "https://biomejs.dev/linter/rules/no-floating-promises",
     "lint/nursery/noGlobalDirnameFilename": "https://biomejs.dev/linter/rules/no-global-dirname-filename",
@@ -177,6 +179,7 @@ define_categories! {
     "lint/nursery/noShorthandPropertyOverrides": "https://biomejs.dev/linter/rules/no-shorthand-property-overrides",
     "lint/nursery/noTsIgnore": "https://biomejs.dev/linter/rules/no-ts-ignore",
     "lint/nursery/noUnassignedVariables": "https://biomejs.dev/linter/rules/no-unassigned-variables",
+    "lint/nursery/noUndeclaredAliases": "https://biomejs.dev/linter/rules/no-undeclared-aliases",
     "lint/nursery/noUndeclaredDependencies": "https://biomejs.dev/linter/rules/no-undeclared-dependencies",
     "lint/nursery/noUnknownAtRule": "https://biomejs.dev/linter/rules/no-unknown-at-rule",
     "lint/nursery/noUnknownFunction": "https://biomejs.dev/linter/rules/no-unknown-function",
@@ -193,6 +196,7 @@ define_categories! {
     "lint/nursery/noUselessUndefined": "https://biomejs.dev/linter/rules/no-useless-undefined",
     "lint/nursery/useAdjacentGetterSetter": "https://biomejs.dev/linter/rules/use-adjacent-getter-setter",
     "lint/nursery/useBiomeSuppressionComment": "https://biomejs.dev/linter/rules/use-biome-suppression-comment",
+    "lint/nursery/useConsistentIndentation": "https://biomejs.dev/linter/rules/use-consistent-indentation",
     "lint/nursery/useConsistentObjectDefinition": "https://biomejs.dev/linter/rules/use-consistent-object-definition",
     "lint/nursery/useConsistentResponse": "https://biomejs.dev/linter/rules/use-consistent-response",
     "lint/nursery/useExhaustiveSwitchCases": "https://biomejs.dev/linter/rules/use-exhaustive-switch-cases",
@@ -430,6 +434,10 @@ define_categories! {
     "suppressions/unknownAction",
     "suppressions/unused",
     "suppressions/incorrect",
+
+    // Baseline files
+    "baseline/parse",
+    "baseline/stale",
     // Used in tests and examples
     "args/fileNotFound",
     "flags/invalid",
@@ -1,6 +1,7 @@
 use crate::{
-    grit_context::GritQueryContext, grit_target_language::GritTargetLanguage,
-    grit_target_node::GritTargetNode, source_location_ext::SourceFileExt, util::TextRangeGritExt,
+    grit_context::GritQueryContext, grit_linearization::linearize_binding,
+    grit_target_language::GritTargetLanguage, grit_target_node::GritTargetNode,
+    source_location_ext::SourceFileExt, util::TextRangeGritExt,
 };
 use biome_diagnostics::{SourceCode, display::SourceFile};
 use biome_rowan::TextRange;
@@ -157,14 +158,38 @@ impl<'a> Binding<'a, GritQueryContext> for GritBinding<'a> {
 
     fn linearized_text(
         &self,
-        _language: &GritTargetLanguage,
-        _effects: &[Effect<'a, GritQueryContext>],
-        _files: &FileRegistry<'a, GritQueryContext>,
-        _memo: &mut HashMap<grit_util::CodeRange, Option<String>>,
+        language: &GritTargetLanguage,
+        effects: &[Effect<'a, GritQueryContext>],
+        files: &FileRegistry<'a, GritQueryContext>,
+        memo: &mut HashMap<CodeRange, Option<String>>,
         _distributed_indent: Option<usize>,
-        _logs: &mut AnalysisLogs,
+        logs: &mut AnalysisLogs,
     ) -> GritResult<Cow<'a, str>> {
-        Err(GritPatternError::new("Not implemented")) // TODO: Implement rewriting
+        match self {
+            Self::Node(node) => linearize_binding(
+                language,
+                effects,
+                files,
+                memo,
+                node.source(),
+                node.code_range(),
+                logs,
+            )
+            .map(|(text, _)| text),
+            Self::Range(range, source) => linearize_binding(
+                language,
+                effects,
+                files,
+                memo,
+                source,
+                range.to_code_range(source),
+                logs,
+            )
+            .map(|(text, _)| text),
+            Self::File(..) | Self::Empty(..) | Self::Constant(..) => {
+                Ok(self.text(language)?.into_owned().into())
+            }
+        }
     }
 
     fn text(&self, _language: &GritTargetLanguage) -> GritResult<Cow<'a, str>> {
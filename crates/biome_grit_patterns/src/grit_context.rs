@@ -2,25 +2,29 @@ use crate::grit_binding::GritBinding;
 use crate::grit_built_in_functions::BuiltIns;
 use crate::grit_code_snippet::GritCodeSnippet;
 use crate::grit_file::GritFile;
+use crate::grit_linearization::{find_conflicting_rewrites, linearize_binding};
 use crate::grit_node_patterns::{GritLeafNodePattern, GritNodePattern};
+use crate::grit_query::RewriteConflict;
 use crate::grit_resolved_pattern::GritResolvedPattern;
 use crate::grit_target_language::GritTargetLanguage;
 use crate::grit_target_node::GritTargetNode;
 use crate::grit_tree::GritTargetTree;
 use biome_analyze::RuleDiagnostic;
 use biome_parser::AnyParse;
-use camino::Utf8PathBuf;
+use camino::{Utf8Path, Utf8PathBuf};
+use grit_pattern_matcher::binding::Binding;
 use grit_pattern_matcher::constants::{GLOBAL_VARS_SCOPE_INDEX, NEW_FILES_INDEX};
 use grit_pattern_matcher::context::{ExecContext, QueryContext};
+use grit_pattern_matcher::effects::Effect;
 use grit_pattern_matcher::file_owners::{FileOwner, FileOwners};
 use grit_pattern_matcher::pattern::{
-    CallBuiltIn, File, FilePtr, GritFunctionDefinition, Matcher, Pattern, PatternDefinition,
-    PredicateDefinition, ResolvedPattern, State,
+    CallBuiltIn, File, FilePtr, FileRegistry, GritFunctionDefinition, Matcher, Pattern,
+    PatternDefinition, PredicateDefinition, ResolvedPattern, State,
 };
 use grit_util::error::GritPatternError;
-use grit_util::{AnalysisLogs, FileOrigin, InputRanges, MatchRanges, error::GritResult};
+use grit_util::{AnalysisLogs, CodeRange, FileOrigin, InputRanges, MatchRanges, error::GritResult};
 use path_absolutize::Absolutize;
-use std::collections::BTreeSet;
+use std::collections::{BTreeSet, HashMap};
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
@@ -55,6 +59,9 @@ pub struct GritExecContext<'a> {
     pub predicates: &'a [PredicateDefinition<GritQueryContext>],
 
     pub diagnostics: Mutex<Vec<RuleDiagnostic>>,
+
+    /// Conflicting rewrites, which prevent their files from being rewritten.
+    pub conflicts: Mutex<Vec<RewriteConflict>>,
 }
 
 impl GritExecContext<'_> {
@@ -76,6 +83,14 @@ impl GritExecContext<'_> {
     pub fn into_diagnostics(self) -> Vec<RuleDiagnostic> {
         self.diagnostics.into_inner().unwrap()
     }
+
+    pub fn add_conflict(&self, conflict: RewriteConflict) {
+        self.conflicts.lock().unwrap().push(conflict);
+    }
+
+    pub fn take_conflicts(&self) -> Vec<RewriteConflict> {
+        std::mem::take(&mut self.conflicts.lock().unwrap())
+    }
 }
 
 impl<'a> ExecContext<'a, GritQueryContext> for GritExecContext<'a> {
@@ -178,13 +193,21 @@ impl<'a> ExecContext<'a, GritQueryContext> for GritExecContext<'a> {
         };
         for file_ptr in files {
             let file = state.files.get_file_owner(file_ptr);
-            let mut match_log = file.matches.borrow_mut();
-
-            if match_log.input_matches.is_none() {
-                match_log.input_matches = Some(input_ranges.clone());
+            {
+                let mut match_log = file.matches.borrow_mut();
+                if match_log.input_matches.is_none() {
+                    match_log.input_matches = Some(input_ranges.clone());
+                }
             }
 
-            // TODO: Implement effect application
+            if let Some(rewritten_file) =
+                apply_effects(file, &state.effects, &state.files, self, logs)?
+            {
+                self.files.push(rewritten_file);
+                state
+                    .files
+                    .push_revision(&file_ptr, self.files.last().unwrap());
+            }
         }
 
         let new_files_binding = &mut state.bindings[GLOBAL_VARS_SCOPE_INDEX as usize]
@@ -272,6 +295,57 @@ impl<'a> ExecContext<'a, GritQueryContext> for GritExecContext<'a> {
     }
 }
 
+/// Applies the effects of a step to the given file.
+///
+/// Returns a new revision of the file, or `None` if the effects don't change
+/// it.
+fn apply_effects<'a>(
+    file: &'a FileOwner<GritTargetTree>,
+    effects: &[Effect<'a, GritQueryContext>],
+    files: &FileRegistry<'a, GritQueryContext>,
+    context: &GritExecContext<'a>,
+    logs: &mut AnalysisLogs,
+) -> GritResult<Option<FileOwner<GritTargetTree>>> {
+    let language = context.language();
+    let effects: Vec<_> = effects
+        .iter()
+        .filter(|effect| !effect.binding.is_suppressed(language, context.name()))
+        .cloned()
+        .collect();
+    if effects.is_empty() {
+        return Ok(None);
+    }
+
+    if let Some((range, conflicting_range)) = find_conflicting_rewrites(&effects, language) {
+        context.add_conflict(RewriteConflict {
+            source_file: file.name.clone(),
+            range,
+            conflicting_range,
+        });
+        return Ok(None);
+    }
+
+    let source = file.tree.text();
+    let range = CodeRange::new(0, source.len() as u32, source);
+    let mut memo = HashMap::new();
+    let (new_source, ranges) =
+        linearize_binding(language, &effects, files, &mut memo, source, range, logs)?;
+    if new_source == source {
+        return Ok(None);
+    }
+
+    let path = Utf8Path::from_path(&file.name).unwrap_or(Utf8Path::new(""));
+    let parse = language.get_parser().parse_with_path(&new_source, path);
+    file_owner_from_matches(
+        file.name.clone(),
+        &parse,
+        Some(MatchRanges::new(ranges)),
+        FileOrigin::Mutated,
+        language,
+        logs,
+    )
+}
+
 fn file_owner_from_matches(
     name: impl Into<PathBuf>,
     parse: &AnyParse,
@@ -0,0 +1,153 @@
+use crate::grit_context::GritQueryContext;
+use crate::grit_resolved_pattern::GritResolvedPattern;
+use crate::grit_target_language::GritTargetLanguage;
+use grit_pattern_matcher::binding::Binding;
+use grit_pattern_matcher::effects::Effect;
+use grit_pattern_matcher::pattern::{FileRegistry, ResolvedPattern};
+use grit_util::error::GritResult;
+use grit_util::{AnalysisLogs, ByteRange, CodeRange, EffectKind};
+use std::borrow::Cow;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+/// Returns the text of the `range` of `source` after applying the effects
+/// whose bindings are inside that range, together with the ranges of the
+/// inserted text in the returned text.
+///
+/// The replacement of an effect is itself linearized, so the effects that
+/// target the bindings it refers to are applied too. While the replacement
+/// of a binding is linearized, the `memo` maps the range of that binding to
+/// `None`, so that a rewrite that refers to the binding it replaces, such as
+/// `$x => foo($x)`, uses the original text of the binding instead of
+/// recursing forever.
+pub(crate) fn linearize_binding<'a>(
+    language: &GritTargetLanguage,
+    effects: &[Effect<'a, GritQueryContext>],
+    files: &FileRegistry<'a, GritQueryContext>,
+    memo: &mut HashMap<CodeRange, Option<String>>,
+    source: &'a str,
+    range: CodeRange,
+    logs: &mut AnalysisLogs,
+) -> GritResult<(Cow<'a, str>, Vec<ByteRange>)> {
+    let mut replacements = Vec::new();
+    for effect in effects {
+        let Some(binding_range) = effect.binding.code_range(language) else {
+            effect
+                .binding
+                .log_empty_field_rewrite_error(language, logs)?;
+            continue;
+        };
+
+        let is_inside_range = effect
+            .binding
+            .source()
+            .is_some_and(|binding_source| range.applies_to(binding_source))
+            && range.start <= binding_range.start
+            && binding_range.end <= range.end;
+        if !is_inside_range || memo.get(&binding_range).is_some_and(Option::is_none) {
+            continue;
+        }
+
+        memo.insert(binding_range.clone(), None);
+        let text = effect
+            .pattern
+            .linearized_text(language, effects, files, memo, false, logs);
+        memo.remove(&binding_range);
+
+        let (start, end) = match effect.kind {
+            EffectKind::Rewrite => (binding_range.start, binding_range.end),
+            EffectKind::Insert => (binding_range.end, binding_range.end),
+        };
+        replacements.push(Replacement {
+            start: start as usize,
+            end: end as usize,
+            kind: effect.kind.clone(),
+            text: text?.into_owned(),
+        });
+    }
+
+    let range_start = range.start as usize;
+    let range_end = range.end as usize;
+    if replacements.is_empty() {
+        return Ok((Cow::Borrowed(&source[range_start..range_end]), Vec::new()));
+    }
+
+    // Insertions go before the rewrites that start at the same position,
+    // because they're appended to the binding that ends there. Rewrites that
+    // start at the same position are sorted from the outermost.
+    replacements.sort_by_key(|replacement| {
+        (
+            replacement.start,
+            matches!(replacement.kind, EffectKind::Rewrite),
+            Reverse(replacement.end),
+        )
+    });
+
+    let mut text = String::with_capacity(range_end - range_start);
+    let mut ranges = Vec::with_capacity(replacements.len());
+    let mut cursor = range_start;
+    for replacement in replacements {
+        // The replacements inside a previous rewrite are skipped, because
+        // the text of the rewrite already contains them if it refers to
+        // their bindings. Conflicting rewrites are discarded before the
+        // effects are applied.
+        if replacement.start < cursor {
+            continue;
+        }
+
+        text.push_str(&source[cursor..replacement.start]);
+        let start = text.len();
+        text.push_str(&replacement.text);
+        ranges.push(ByteRange::new(start, text.len()));
+        cursor = replacement.end;
+    }
+    text.push_str(&source[cursor..range_end]);
+
+    Ok((Cow::Owned(text), ranges))
+}
+
+/// Returns the ranges of the first two rewrites that conflict, if any.
+///
+/// Two rewrites conflict when they overlap without one of them containing the
+/// other, or when they replace the same binding with different patterns.
+pub(crate) fn find_conflicting_rewrites(
+    effects: &[Effect<GritQueryContext>],
+    language: &GritTargetLanguage,
+) -> Option<(ByteRange, ByteRange)> {
+    let mut rewrites: Vec<_> = effects
+        .iter()
+        .filter(|effect| matches!(effect.kind, EffectKind::Rewrite))
+        .filter_map(|effect| Some((effect.binding.range(language)?, &effect.pattern)))
+        .collect();
+    rewrites.sort_by_key(|(range, _)| (range.start, Reverse(range.end)));
+
+    // The rewrites that contain the current one
+    let mut enclosing: Vec<(ByteRange, &GritResolvedPattern)> = Vec::new();
+    for (range, pattern) in rewrites {
+        while enclosing
+            .last()
+            .is_some_and(|(enclosing_range, _)| enclosing_range.end <= range.start)
+        {
+            enclosing.pop();
+        }
+
+        if let Some((enclosing_range, enclosing_pattern)) = enclosing.last() {
+            let is_same_range =
+                enclosing_range.start == range.start && enclosing_range.end == range.end;
+            if range.end > enclosing_range.end || (is_same_range && *enclosing_pattern != pattern) {
+                return Some((*enclosing_range, range));
+            }
+        }
+
+        enclosing.push((range, pattern));
+    }
+
+    None
+}
+
+struct Replacement {
+    start: usize,
+    end: usize,
+    kind: EffectKind,
+    text: String,
+}
@@ -78,6 +78,7 @@ impl GritQuery {
             patterns: &self.definitions.patterns,
             predicates: &self.definitions.predicates,
             diagnostics: Mutex::new(Vec::new()),
+            conflicts: Mutex::new(Vec::new()),
         };
 
         let var_registry = VarRegistry::from_locations(&self.variable_locations);
@@ -103,6 +104,7 @@ impl GritQuery {
 
         Ok(GritQueryResult {
             effects,
+            conflicts: context.take_conflicts(),
             diagnostics: context.into_diagnostics(),
             logs,
         })
@@ -209,6 +211,7 @@ impl GritQuery {
 #[derive(Debug)]
 pub struct GritQueryResult {
     pub effects: Vec<GritQueryEffect>,
+    pub conflicts: Vec<RewriteConflict>,
     pub diagnostics: Vec<RuleDiagnostic>,
     pub logs: AnalysisLogs,
 }
@@ -333,6 +336,17 @@ impl Rewrite {
     }
 }
 
+/// Two rewrites that target overlapping code, without one of them containing
+/// the other, or that rewrite the same code differently.
+///
+/// The file isn't rewritten when its rewrites conflict.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewriteConflict {
+    pub source_file: PathBuf,
+    pub range: ByteRange,
+    pub conflicting_range: ByteRange,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct CreateFile {
     pub rewritten: OutputFile,
@@ -15,7 +15,7 @@ use grit_pattern_matcher::pattern::{
     ResolvedSnippet, State, to_unsigned,
 };
 use grit_util::error::{GritPatternError, GritResult};
-use grit_util::{AnalysisLogs, Ast, CodeRange, Range};
+use grit_util::{AnalysisLogs, Ast, CodeRange, EffectKind, Range};
 use std::borrow::Cow;
 use std::collections::{BTreeMap, HashMap};
 
@@ -344,11 +344,66 @@ impl<'a> ResolvedPattern<'a, GritQueryContext> for GritResolvedPattern<'a> {
 
     fn extend(
         &mut self,
-        _with: Self,
-        _effects: &mut Vec<Effect<'a, GritQueryContext>>,
-        _language: &<GritQueryContext as QueryContext>::Language<'a>,
+        mut with: Self,
+        effects: &mut Vec<Effect<'a, GritQueryContext>>,
+        language: &<GritQueryContext as QueryContext>::Language<'a>,
     ) -> GritResult<()> {
-        Err(GritPatternError::new("Not implemented")) // TODO: Implement rewriting
+        match self {
+            Self::Binding(bindings) => {
+                for binding in bindings.iter() {
+                    let is_first = !effects.iter().any(|effect| effect.binding == *binding);
+                    with.normalize_insert(binding, is_first, language)?;
+                    effects.push(Effect {
+                        binding: binding.clone(),
+                        pattern: with.clone(),
+                        kind: EffectKind::Insert,
+                    });
+                }
+                Ok(())
+            }
+            Self::Snippets(snippets) => {
+                snippets.extend(with.to_snippets()?);
+                Ok(())
+            }
+            Self::List(list) => {
+                list.push(with);
+                Ok(())
+            }
+            Self::Map(map) => {
+                let Self::Map(with) = with else {
+                    return Err(GritPatternError::new(
+                        "can only extend a map with another map",
+                    ));
+                };
+                map.extend(with);
+                Ok(())
+            }
+            Self::Constant(Constant::Integer(integer)) => match with {
+                Self::Constant(Constant::Integer(with)) => {
+                    *integer += with;
+                    Ok(())
+                }
+                _ => Err(GritPatternError::new(
+                    "can only extend an integer with another integer",
+                )),
+            },
+            Self::Constant(Constant::Float(float)) => match with {
+                Self::Constant(Constant::Float(with)) => {
+                    *float += with;
+                    Ok(())
+                }
+                Self::Constant(Constant::Integer(with)) => {
+                    *float += with as f64;
+                    Ok(())
+                }
+                _ => Err(GritPatternError::new(
+                    "can only extend a float with another number",
+                )),
+            },
+            Self::Constant(_) | Self::File(_) | Self::Files(_) => {
+                Err(GritPatternError::new("cannot extend a constant or a file"))
+            }
+        }
     }
 
     fn float(
@@ -527,14 +582,43 @@ impl<'a> ResolvedPattern<'a, GritQueryContext> for GritResolvedPattern<'a> {
 
     fn linearized_text(
         &self,
-        _language: &GritTargetLanguage,
-        _effects: &[Effect<'a, GritQueryContext>],
-        _files: &FileRegistry<'a, GritQueryContext>,
-        _memo: &mut HashMap<CodeRange, Option<String>>,
+        language: &GritTargetLanguage,
+        effects: &[Effect<'a, GritQueryContext>],
+        files: &FileRegistry<'a, GritQueryContext>,
+        memo: &mut HashMap<CodeRange, Option<String>>,
         _should_pad_snippet: bool,
-        _logs: &mut AnalysisLogs,
+        logs: &mut AnalysisLogs,
     ) -> GritResult<Cow<'a, str>> {
-        Err(GritPatternError::new("Not implemented")) // TODO: Implement rewriting
+        match self {
+            Self::Binding(bindings) => bindings
+                .last()
+                .ok_or_else(|| {
+                    GritPatternError::new(
+                        "cannot linearize text of resolved_pattern with no binding",
+                    )
+                })?
+                .linearized_text(language, effects, files, memo, None, logs),
+            // We don't distribute the indentation over the snippets, the
+            // rewritten code is formatted afterwards.
+            Self::Snippets(snippets) => Ok(snippets
+                .iter()
+                .try_fold(String::new(), |mut text, snippet| {
+                    text.push_str(
+                        &snippet.linearized_text(language, effects, files, memo, None, logs)?,
+                    );
+                    Ok::<String, GritPatternError>(text)
+                })?
+                .into()),
+            Self::List(list) => Ok(list
+                .iter()
+                .map(|pattern| pattern.linearized_text(language, effects, files, memo, false, logs))
+                .collect::<GritResult<Vec<_>>>()?
+                .join(",")
+                .into()),
+            Self::Map(_) | Self::File(_) | Self::Files(_) | Self::Constant(_) => {
+                self.text(files, language)
+            }
+        }
     }
 
     fn matches_undefined(&self) -> bool {
@@ -560,11 +644,22 @@ impl<'a> ResolvedPattern<'a, GritQueryContext> for GritResolvedPattern<'a> {
 
     fn normalize_insert(
         &mut self,
-        _binding: &GritBinding,
-        _is_first: bool,
-        _language: &GritTargetLanguage,
+        binding: &GritBinding,
+        is_first: bool,
+        language: &GritTargetLanguage,
     ) -> GritResult<()> {
-        Err(GritPatternError::new("Not implemented")) // TODO: Implement insertion padding
+        let Self::Snippets(snippets) = self else {
+            return Ok(());
+        };
+        let Some(ResolvedSnippet::Text(text)) = snippets.first() else {
+            return Ok(());
+        };
+        if let Some(padding) = binding.get_insertion_padding(text, is_first, language) {
+            if padding.chars().next() != text.chars().next() {
+                snippets.insert(0, ResolvedSnippet::Text(padding.into()));
+            }
+        }
+        Ok(())
     }
 
     fn position(&self, language: &GritTargetLanguage) -> Option<Range> {
@@ -329,7 +329,7 @@ impl GritAstNode for GritTargetNode<'_> {
     }
 
     fn code_range(&self) -> CodeRange {
-        self.text_trimmed_range().to_code_range(self.text())
+        self.text_trimmed_range().to_code_range(self.source())
     }
 
     #[expect(refining_impl_trait)]
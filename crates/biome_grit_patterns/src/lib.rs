@@ -11,6 +11,7 @@ mod grit_css_parser;
 mod grit_definitions;
 mod grit_file;
 mod grit_js_parser;
+mod grit_linearization;
 mod grit_node;
 mod grit_node_patterns;
 mod grit_query;
@@ -30,7 +31,7 @@ pub use grit_built_in_functions::BuiltInFunction;
 pub use grit_context::{GritExecContext, GritQueryContext, GritTargetFile};
 pub use grit_pattern_matcher::pattern::{Pattern as GritPattern, State as GritQueryState};
 pub use grit_query::{
-    CreateFile, GritQuery, GritQueryEffect, GritQueryResult, Message, OutputFile,
+    CreateFile, GritQuery, GritQueryEffect, GritQueryResult, Message, OutputFile, RewriteConflict,
 };
 pub use grit_resolved_pattern::GritResolvedPattern;
 pub use grit_target_language::{GritTargetLanguage, JsTargetLanguage};
@@ -69,7 +69,7 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
         .unwrap_or_else(|err| panic!("cannot execute query from {query_path:?}: {err:?}"));
     let snapshot_result = SnapshotResult::from_query_effects(result.effects);
 
-    let snapshot = if result.logs.is_empty() {
+    let mut snapshot = if result.logs.is_empty() {
         format!("{snapshot_result:#?}")
     } else {
         let logs = result
@@ -87,6 +87,10 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
         format!("{snapshot_result:#?}\n\n## Logs\n\n{logs}")
     };
 
+    if !result.conflicts.is_empty() {
+        snapshot.push_str(&format!("\n\n## Conflicts\n\n{:#?}", result.conflicts));
+    }
+
     insta::with_settings!({
         prepend_module_to_snapshot => false,
         snapshot_path => query_path.parent().unwrap(),
@@ -0,0 +1,2 @@
+foo(1);
+qux(2);
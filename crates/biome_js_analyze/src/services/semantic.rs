@@ -126,6 +126,10 @@ impl Visitor for SemanticModelBuilderVisitor {
         let model = self.builder.build();
         ctx.services.insert_service(model);
     }
+
+    fn builds_semantic_model(&self) -> bool {
+        true
+    }
 }
 
 pub struct SemanticModelVisitor;
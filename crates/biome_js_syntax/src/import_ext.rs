@@ -189,8 +189,8 @@ impl AnyJsNamedImportSpecifier {
     /// ```
     pub fn imported_name(&self) -> Option<JsSyntaxToken> {
         match self {
-            specifier @ (Self::JsNamedImportSpecifier(_)
-            | Self::JsShorthandNamedImportSpecifier(_)) => specifier
+            Self::JsNamedImportSpecifier(specifier) => specifier.name().ok()?.value().ok(),
+            specifier @ Self::JsShorthandNamedImportSpecifier(_) => specifier
                 .local_name()?
                 .as_js_identifier_binding()?
                 .name_token()
@@ -0,0 +1,73 @@
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use anyhow::Context;
+use biome_lsp_converters::{from_proto, to_proto};
+use biome_service::WorkspaceError;
+use biome_service::workspace::{CompletionItemKind, CompletionsParams};
+use tower_lsp_server::lsp_types as lsp;
+
+/// Handler for `textDocument/completion` LSP requests.
+///
+/// Completions are provided for the keys and the values of the Biome
+/// configuration files.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn completion(
+    session: &Session,
+    params: lsp::CompletionParams,
+) -> Result<Option<lsp::CompletionResponse>, LspError> {
+    let url = params.text_document_position.text_document.uri;
+    let position = params.text_document_position.position;
+    let path = session.file_path(&url)?;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+
+    let position_encoding = session.position_encoding();
+    let offset = from_proto::offset(&doc.line_index, position, position_encoding)
+        .with_context(|| format!("failed to access position {position:?} in {}", url.as_str()))?;
+
+    let result = match session.workspace.completions(CompletionsParams {
+        project_key: doc.project_key,
+        path,
+        offset,
+    }) {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+
+    if result.items.is_empty() {
+        return Ok(None);
+    }
+
+    let items = result
+        .items
+        .into_iter()
+        .map(|item| {
+            let range = to_proto::range(&doc.line_index, item.range, position_encoding)?;
+            Ok(lsp::CompletionItem {
+                kind: Some(match item.kind {
+                    CompletionItemKind::Property => lsp::CompletionItemKind::PROPERTY,
+                    CompletionItemKind::Value => lsp::CompletionItemKind::VALUE,
+                }),
+                documentation: item.documentation.map(|value| {
+                    lsp::Documentation::MarkupContent(lsp::MarkupContent {
+                        kind: lsp::MarkupKind::Markdown,
+                        value,
+                    })
+                }),
+                // The completion replaces the whole string, quotes included,
+                // so the client must filter on the quoted label
+                filter_text: Some(item.insert_text.clone()),
+                text_edit: Some(lsp::CompletionTextEdit::Edit(lsp::TextEdit {
+                    range,
+                    new_text: item.insert_text,
+                })),
+                label: item.label,
+                ..Default::default()
+            })
+        })
+        .collect::<Result<_, LspError>>()?;
+
+    Ok(Some(lsp::CompletionResponse::Array(items)))
+}
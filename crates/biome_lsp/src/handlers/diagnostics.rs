@@ -0,0 +1,113 @@
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use crate::utils;
+use biome_line_index::LineIndex;
+use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
+use biome_service::workspace::{GetFileContentParams, PullProjectDiagnosticsParams};
+use tower_lsp_server::UriExt;
+use tower_lsp_server::lsp_types::{
+    self as lsp, FullDocumentDiagnosticReport, RelatedFullDocumentDiagnosticReport, Uri,
+    WorkspaceDiagnosticReport, WorkspaceDocumentDiagnosticReport,
+    WorkspaceFullDocumentDiagnosticReport,
+};
+use tracing::error;
+
+/// Handler for `textDocument/diagnostic` LSP requests.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn document_diagnostic(
+    session: &Session,
+    params: lsp::DocumentDiagnosticParams,
+) -> Result<Option<RelatedFullDocumentDiagnosticReport>, LspError> {
+    let url = params.text_document.uri;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+
+    let items = session.compute_diagnostics(&url, &doc)?;
+
+    Ok(Some(RelatedFullDocumentDiagnosticReport {
+        related_documents: None,
+        full_document_diagnostic_report: FullDocumentDiagnosticReport {
+            result_id: None,
+            items,
+        },
+    }))
+}
+
+/// Handler for `workspace/diagnostic` LSP requests.
+///
+/// Reports the diagnostics of the rules that need the project scan, such as
+/// import cycles, for the files that aren't opened by the client. Opened
+/// files are reported by `textDocument/diagnostic` requests instead.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn workspace_diagnostic(
+    session: &Session,
+    params: lsp::WorkspaceDiagnosticParams,
+) -> Result<Option<WorkspaceDiagnosticReport>, LspError> {
+    if !session.configuration_status().is_loaded() {
+        return Ok(None);
+    }
+
+    let position_encoding = session.position_encoding();
+
+    let mut items = Vec::new();
+    for project_key in session.project_keys() {
+        let result = session
+            .workspace
+            .pull_project_diagnostics(PullProjectDiagnosticsParams { project_key })?;
+
+        for file in result.files {
+            let Some(uri) = Uri::from_file_path(file.path.as_path()) else {
+                continue;
+            };
+            if session.document(&uri).is_some() {
+                continue;
+            }
+
+            let content = session.workspace.get_file_content(GetFileContentParams {
+                project_key,
+                path: file.path.clone(),
+            })?;
+            let line_index = LineIndex::new(&content);
+            let offset = match file.path.extension() {
+                Some("vue") => VueFileHandler::start(content.as_str()),
+                Some("astro") => AstroFileHandler::start(content.as_str()),
+                Some("svelte") => SvelteFileHandler::start(content.as_str()),
+                _ => None,
+            };
+
+            let diagnostics = file
+                .diagnostics
+                .into_iter()
+                .filter_map(|diagnostic| {
+                    match utils::diagnostic_to_lsp(
+                        diagnostic,
+                        &uri,
+                        &line_index,
+                        position_encoding,
+                        offset,
+                    ) {
+                        Ok(diagnostic) => Some(diagnostic),
+                        Err(err) => {
+                            error!("failed to convert diagnostic to LSP: {err:?}");
+                            None
+                        }
+                    }
+                })
+                .collect();
+
+            items.push(WorkspaceDocumentDiagnosticReport::Full(
+                WorkspaceFullDocumentDiagnosticReport {
+                    uri,
+                    version: None,
+                    full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                        result_id: None,
+                        items: diagnostics,
+                    },
+                },
+            ));
+        }
+    }
+
+    Ok(Some(WorkspaceDiagnosticReport { items }))
+}
@@ -0,0 +1,150 @@
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use anyhow::Context;
+use biome_analyze::RuleCategoriesBuilder;
+use biome_diagnostics::{Category, Diagnostic as _};
+use biome_lsp_converters::{from_proto, to_proto};
+use biome_rowan::{TextRange, TextSize};
+use biome_service::WorkspaceError;
+use biome_service::documentation::{ExplainRule, rules_metadata};
+use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
+use biome_service::workspace::{
+    FeaturesBuilder, GetFileContentParams, HoverParams, PullDiagnosticsParams,
+    SupportsFeatureParams,
+};
+use std::collections::BTreeMap;
+use std::sync::LazyLock;
+use tower_lsp_server::lsp_types::{self as lsp, HoverContents, MarkupContent, MarkupKind};
+
+/// Handler for `textDocument/hover` LSP requests.
+///
+/// The result combines the information returned by the workspace, such as the
+/// inferred type of the hovered node, with the documentation of the lint rules
+/// that emitted a diagnostic at the hovered position.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn hover(
+    session: &Session,
+    params: lsp::HoverParams,
+) -> Result<Option<lsp::Hover>, LspError> {
+    let url = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+    let path = session.file_path(&url)?;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+
+    let position_encoding = session.position_encoding();
+    let offset = from_proto::offset(&doc.line_index, position, position_encoding)
+        .with_context(|| format!("failed to access position {position:?} in {}", url.as_str()))?;
+
+    let mut range = None;
+    let mut contents = Vec::new();
+
+    match session.workspace.hover(HoverParams {
+        project_key: doc.project_key,
+        path: path.clone(),
+        offset,
+    }) {
+        Ok(result) => {
+            range = result.range;
+            contents.extend(result.contents);
+        }
+        Err(WorkspaceError::SourceFileNotSupported(_)) => {}
+        Err(error) => return Err(error.into()),
+    }
+
+    let file_features = session.workspace.file_features(SupportsFeatureParams {
+        project_key: doc.project_key,
+        path: path.clone(),
+        features: FeaturesBuilder::new().with_linter().build(),
+    })?;
+
+    if file_features.supports_lint() && session.configuration_status().is_loaded() {
+        let content = session.workspace.get_file_content(GetFileContentParams {
+            project_key: doc.project_key,
+            path: path.clone(),
+        })?;
+        let embedded_offset = match path.extension() {
+            Some("vue") => VueFileHandler::start(content.as_str()),
+            Some("astro") => AstroFileHandler::start(content.as_str()),
+            Some("svelte") => SvelteFileHandler::start(content.as_str()),
+            _ => None,
+        }
+        .map_or(TextSize::default(), TextSize::from);
+
+        // Positions before the embedded script can't be covered by a lint diagnostic
+        if let Some(script_offset) = offset.checked_sub(embedded_offset) {
+            // Only lint the nodes around the hovered position. The range spans the
+            // characters on both sides, so diagnostics ending or starting at the
+            // position are still computed.
+            let lint_range = TextRange::new(
+                script_offset
+                    .checked_sub(TextSize::from(1))
+                    .unwrap_or_default(),
+                script_offset + TextSize::from(1),
+            );
+            let result = session.workspace.pull_diagnostics(PullDiagnosticsParams {
+                project_key: doc.project_key,
+                path,
+                categories: RuleCategoriesBuilder::default().with_lint().build(),
+                only: Vec::new(),
+                skip: Vec::new(),
+                enabled_rules: Vec::new(),
+                pull_code_actions: false,
+                range: Some(lint_range),
+            })?;
+
+            for diagnostic in result.diagnostics {
+                let Some(span) = diagnostic.location().span else {
+                    continue;
+                };
+                let span =
+                    TextRange::new(span.start() + embedded_offset, span.end() + embedded_offset);
+                if !span.contains_inclusive(offset) {
+                    continue;
+                }
+
+                if let Some(documentation) = diagnostic.category().and_then(rule_documentation) {
+                    range.get_or_insert(span);
+                    contents.push(documentation);
+                }
+            }
+        }
+    }
+
+    if contents.is_empty() {
+        return Ok(None);
+    }
+
+    let range = range
+        .map(|range| to_proto::range(&doc.line_index, range, position_encoding))
+        .transpose()?;
+
+    Ok(Some(lsp::Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: contents.join("\n\n---\n\n"),
+        }),
+        range,
+    }))
+}
+
+/// The metadata of all the rules, computed on the first hover.
+static RULES_METADATA: LazyLock<BTreeMap<&'static str, ExplainRule>> =
+    LazyLock::new(rules_metadata);
+
+/// Returns the Markdown documentation of the rule that emitted a diagnostic
+/// with the given `category`, if any.
+fn rule_documentation(category: &'static Category) -> Option<String> {
+    let (_, rule_name) = category.name().rsplit_once('/')?;
+    let rule = RULES_METADATA.get(rule_name)?;
+
+    // The first paragraph of the documentation is the summary of the rule.
+    let summary = rule.metadata().docs.trim().split("\n\n").next()?;
+    let mut documentation = format!("**{}**\n\n{summary}", category.name());
+    if let Some(link) = category.link() {
+        documentation.push_str(&format!("\n\n[See the rule documentation]({link})"));
+    }
+
+    Some(documentation)
+}
@@ -0,0 +1,62 @@
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use anyhow::Context;
+use biome_lsp_converters::{from_proto, to_proto};
+use biome_service::WorkspaceError;
+use biome_service::workspace::{InlayHintKind, InlayHintsParams};
+use tower_lsp_server::lsp_types as lsp;
+
+/// Handler for `textDocument/inlayHint` LSP requests.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn inlay_hint(
+    session: &Session,
+    params: lsp::InlayHintParams,
+) -> Result<Option<Vec<lsp::InlayHint>>, LspError> {
+    let url = params.text_document.uri;
+    let path = session.file_path(&url)?;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+
+    let position_encoding = session.position_encoding();
+    let range = from_proto::text_range(&doc.line_index, params.range, position_encoding)
+        .with_context(|| {
+            format!(
+                "failed to access range {:?} in {}",
+                params.range,
+                url.as_str()
+            )
+        })?;
+
+    let result = match session.workspace.inlay_hints(InlayHintsParams {
+        project_key: doc.project_key,
+        path,
+        range: Some(range),
+    }) {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+
+    let mut inlay_hints = Vec::with_capacity(result.hints.len());
+    for hint in result.hints {
+        let position = to_proto::position(&doc.line_index, hint.offset, position_encoding)?;
+        let (kind, padding_right) = match hint.kind {
+            InlayHintKind::Type => (lsp::InlayHintKind::TYPE, None),
+            InlayHintKind::Parameter => (lsp::InlayHintKind::PARAMETER, Some(true)),
+        };
+
+        inlay_hints.push(lsp::InlayHint {
+            position,
+            label: lsp::InlayHintLabel::String(hint.label),
+            kind: Some(kind),
+            text_edits: None,
+            tooltip: None,
+            padding_left: None,
+            padding_right,
+            data: None,
+        });
+    }
+
+    Ok(Some(inlay_hints))
+}
@@ -0,0 +1,137 @@
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use anyhow::Context;
+use biome_fs::BiomePath;
+use biome_line_index::LineIndex;
+use biome_lsp_converters::{from_proto, to_proto};
+use biome_service::WorkspaceError;
+use biome_service::projects::ProjectKey;
+use biome_service::workspace::{
+    FindReferencesParams, GetFileContentParams, GotoDefinitionParams, SymbolLocation,
+};
+use tower_lsp_server::UriExt;
+use tower_lsp_server::lsp_types::{self as lsp, GotoDefinitionResponse, Uri};
+
+/// Handler for `textDocument/definition` LSP requests.
+///
+/// Imported symbols are resolved through the module graph, so the definition
+/// may be located in a different file.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn goto_definition(
+    session: &Session,
+    params: lsp::GotoDefinitionParams,
+) -> Result<Option<GotoDefinitionResponse>, LspError> {
+    let url = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+    let path = session.file_path(&url)?;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+
+    let offset = from_proto::offset(&doc.line_index, position, session.position_encoding())
+        .with_context(|| format!("failed to access position {position:?} in {}", url.as_str()))?;
+
+    let result = match session.workspace.goto_definition(GotoDefinitionParams {
+        project_key: doc.project_key,
+        path,
+        offset,
+    }) {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+
+    let locations = to_lsp_locations(session, doc.project_key, result.definitions)?;
+    if locations.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(GotoDefinitionResponse::Array(locations)))
+}
+
+/// Handler for `textDocument/references` LSP requests.
+///
+/// References are collected across all the modules of the project that import
+/// the symbol.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn references(
+    session: &Session,
+    params: lsp::ReferenceParams,
+) -> Result<Option<Vec<lsp::Location>>, LspError> {
+    let url = params.text_document_position.text_document.uri;
+    let position = params.text_document_position.position;
+    let path = session.file_path(&url)?;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+
+    let offset = from_proto::offset(&doc.line_index, position, session.position_encoding())
+        .with_context(|| format!("failed to access position {position:?} in {}", url.as_str()))?;
+
+    let result = match session.workspace.find_references(FindReferencesParams {
+        project_key: doc.project_key,
+        path,
+        offset,
+        include_declaration: params.context.include_declaration,
+    }) {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+
+    let locations = to_lsp_locations(session, doc.project_key, result.references)?;
+
+    Ok(Some(locations))
+}
+
+/// Converts the given symbol locations to LSP locations.
+///
+/// Locations may point to files that aren't opened by the client, in which
+/// case their content is retrieved from the workspace.
+pub(crate) fn to_lsp_locations(
+    session: &Session,
+    project_key: ProjectKey,
+    locations: Vec<SymbolLocation>,
+) -> Result<Vec<lsp::Location>, LspError> {
+    let position_encoding = session.position_encoding();
+
+    let mut result = Vec::with_capacity(locations.len());
+    let mut current_file: Option<(Uri, LineIndex)> = None;
+    for location in locations {
+        let Some(uri) = Uri::from_file_path(location.path.as_path()) else {
+            continue;
+        };
+
+        let line_index = match current_file {
+            Some((ref current_uri, ref line_index)) if current_uri == &uri => line_index,
+            _ => {
+                let line_index = line_index(session, project_key, &uri, location.path)?;
+                &current_file.insert((uri.clone(), line_index)).1
+            }
+        };
+
+        let range = to_proto::range(line_index, location.range, position_encoding)?;
+        result.push(lsp::Location { uri, range });
+    }
+
+    Ok(result)
+}
+
+/// Returns the line index of the file at `path`, which may not be opened by
+/// the client.
+pub(crate) fn line_index(
+    session: &Session,
+    project_key: ProjectKey,
+    uri: &Uri,
+    path: BiomePath,
+) -> Result<LineIndex, LspError> {
+    match session.document(uri) {
+        Some(doc) => Ok(doc.line_index),
+        None => {
+            let content = session
+                .workspace
+                .get_file_content(GetFileContentParams { project_key, path })?;
+            Ok(LineIndex::new(&content))
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use anyhow::Context;
+use biome_lsp_converters::{from_proto, to_proto};
+use biome_service::WorkspaceError;
+use biome_service::workspace::{
+    FoldingRangeKind, FoldingRangesParams, LinkedEditingRangesParams, SelectionRangeParams,
+};
+use tower_lsp_server::lsp_types as lsp;
+
+/// Handler for `textDocument/foldingRange` LSP requests.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn folding_range(
+    session: &Session,
+    params: lsp::FoldingRangeParams,
+) -> Result<Option<Vec<lsp::FoldingRange>>, LspError> {
+    let url = params.text_document.uri;
+    let path = session.file_path(&url)?;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+
+    let result = match session.workspace.folding_ranges(FoldingRangesParams {
+        project_key: doc.project_key,
+        path,
+    }) {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+
+    let position_encoding = session.position_encoding();
+    let mut folding_ranges = Vec::with_capacity(result.ranges.len());
+    for folding_range in result.ranges {
+        let range = to_proto::range(&doc.line_index, folding_range.range, position_encoding)?;
+        // Only the lines after the first one are folded
+        if range.end.line <= range.start.line {
+            continue;
+        }
+
+        folding_ranges.push(lsp::FoldingRange {
+            start_line: range.start.line,
+            start_character: None,
+            end_line: range.end.line,
+            end_character: None,
+            kind: folding_range.kind.map(|kind| match kind {
+                FoldingRangeKind::Comment => lsp::FoldingRangeKind::Comment,
+                FoldingRangeKind::Region => lsp::FoldingRangeKind::Region,
+            }),
+            collapsed_text: None,
+        });
+    }
+
+    Ok(Some(folding_ranges))
+}
+
+/// Handler for `textDocument/selectionRange` LSP requests.
+///
+/// Each position is expanded to the ranges of the enclosing syntax nodes.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn selection_range(
+    session: &Session,
+    params: lsp::SelectionRangeParams,
+) -> Result<Option<Vec<lsp::SelectionRange>>, LspError> {
+    let url = params.text_document.uri;
+    let path = session.file_path(&url)?;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+
+    let position_encoding = session.position_encoding();
+    let mut selection_ranges = Vec::with_capacity(params.positions.len());
+    for position in params.positions {
+        let offset = from_proto::offset(&doc.line_index, position, position_encoding)
+            .with_context(|| {
+                format!("failed to access position {position:?} in {}", url.as_str())
+            })?;
+
+        let result = match session.workspace.selection_range(SelectionRangeParams {
+            project_key: doc.project_key,
+            path: path.clone(),
+            offset,
+        }) {
+            Ok(result) => result,
+            Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+            Err(error) => return Err(error.into()),
+        };
+
+        let selection_range = result
+            .ranges
+            .into_iter()
+            .rev()
+            .try_fold(None, |parent, range| {
+                Ok::<_, LspError>(Some(lsp::SelectionRange {
+                    range: to_proto::range(&doc.line_index, range, position_encoding)?,
+                    parent: parent.map(Box::new),
+                }))
+            })?
+            // The protocol expects a range for every position, even if
+            // there's no syntax node around it.
+            .unwrap_or(lsp::SelectionRange {
+                range: lsp::Range::new(position, position),
+                parent: None,
+            });
+
+        selection_ranges.push(selection_range);
+    }
+
+    Ok(Some(selection_ranges))
+}
+
+/// Handler for `textDocument/linkedEditingRange` LSP requests.
+///
+/// The names of the opening and closing tags of JSX and HTML elements are
+/// edited together.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn linked_editing_range(
+    session: &Session,
+    params: lsp::LinkedEditingRangeParams,
+) -> Result<Option<lsp::LinkedEditingRanges>, LspError> {
+    let url = params.text_document_position_params.text_document.uri;
+    let position = params.text_document_position_params.position;
+    let path = session.file_path(&url)?;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+
+    let position_encoding = session.position_encoding();
+    let offset = from_proto::offset(&doc.line_index, position, position_encoding)
+        .with_context(|| format!("failed to access position {position:?} in {}", url.as_str()))?;
+
+    let result = match session
+        .workspace
+        .linked_editing_ranges(LinkedEditingRangesParams {
+            project_key: doc.project_key,
+            path,
+            offset,
+        }) {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+
+    if result.ranges.is_empty() {
+        return Ok(None);
+    }
+
+    let ranges = result
+        .ranges
+        .into_iter()
+        .map(|range| to_proto::range(&doc.line_index, range, position_encoding))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Some(lsp::LinkedEditingRanges {
+        ranges,
+        word_pattern: None,
+    }))
+}
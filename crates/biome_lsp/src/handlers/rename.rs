@@ -0,0 +1,111 @@
+#![expect(clippy::mutable_key_type)]
+use crate::diagnostics::LspError;
+use crate::handlers::navigation::line_index;
+use crate::session::Session;
+use crate::utils::text_edit;
+use anyhow::Context;
+use biome_lsp_converters::{from_proto, to_proto};
+use biome_service::WorkspaceError;
+use biome_service::workspace::{PrepareRenameParams, RenameParams};
+use std::collections::HashMap;
+use tower_lsp_server::UriExt;
+use tower_lsp_server::lsp_types::{self as lsp, PrepareRenameResponse, Uri};
+
+/// Handler for `textDocument/prepareRename` LSP requests.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn prepare_rename(
+    session: &Session,
+    params: lsp::TextDocumentPositionParams,
+) -> Result<Option<PrepareRenameResponse>, LspError> {
+    let url = params.text_document.uri;
+    let position = params.position;
+    let path = session.file_path(&url)?;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+
+    let position_encoding = session.position_encoding();
+    let offset = from_proto::offset(&doc.line_index, position, position_encoding)
+        .with_context(|| format!("failed to access position {position:?} in {}", url.as_str()))?;
+
+    let result = match session.workspace.prepare_rename(PrepareRenameParams {
+        project_key: doc.project_key,
+        path,
+        symbol_at: offset,
+    }) {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+
+    let Some(range) = result.range else {
+        return Ok(None);
+    };
+
+    let range = to_proto::range(&doc.line_index, range, position_encoding)?;
+    Ok(Some(PrepareRenameResponse::Range(range)))
+}
+
+/// Handler for `textDocument/rename` LSP requests.
+///
+/// Renaming an exported symbol updates the modules that import it as well, so
+/// the returned edit may span several files.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn rename(
+    session: &Session,
+    params: lsp::RenameParams,
+) -> Result<Option<lsp::WorkspaceEdit>, LspError> {
+    let url = params.text_document_position.text_document.uri;
+    let position = params.text_document_position.position;
+    let path = session.file_path(&url)?;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+
+    let position_encoding = session.position_encoding();
+    let offset = from_proto::offset(&doc.line_index, position, position_encoding)
+        .with_context(|| format!("failed to access position {position:?} in {}", url.as_str()))?;
+
+    let result = match session.workspace.rename(RenameParams {
+        project_key: doc.project_key,
+        path,
+        symbol_at: offset,
+        new_name: params.new_name,
+    }) {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+
+    let mut changes = HashMap::new();
+    let edits = text_edit(
+        &doc.line_index,
+        result.indels,
+        position_encoding,
+        Some(result.range.start().into()),
+    )?;
+    if !edits.is_empty() {
+        changes.insert(url, edits);
+    }
+
+    for file in result.other_files {
+        let Some(uri) = Uri::from_file_path(file.path.as_path()) else {
+            continue;
+        };
+
+        let line_index = line_index(session, doc.project_key, &uri, file.path)?;
+        let edits = text_edit(
+            &line_index,
+            file.indels,
+            position_encoding,
+            Some(file.range.start().into()),
+        )?;
+        changes.insert(uri, edits);
+    }
+
+    Ok(Some(lsp::WorkspaceEdit {
+        changes: Some(changes),
+        document_changes: None,
+        change_annotations: None,
+    }))
+}
@@ -0,0 +1,250 @@
+use crate::diagnostics::LspError;
+use crate::session::Session;
+use anyhow::Context;
+use biome_line_index::LineIndex;
+use biome_lsp_converters::{PositionEncoding, from_proto, to_proto};
+use biome_service::WorkspaceError;
+use biome_service::workspace::{
+    SemanticToken, SemanticTokenKind, SemanticTokenModifier, SemanticTokensParams,
+};
+use tower_lsp_server::lsp_types::{
+    self as lsp, SemanticTokenModifier as LspModifier, SemanticTokenType as LspType,
+    SemanticTokensLegend, SemanticTokensRangeResult, SemanticTokensResult, Uri,
+};
+
+/// The token kinds, in the order of the legend advertised to the client.
+const TOKEN_KINDS: [SemanticTokenKind; 12] = [
+    SemanticTokenKind::Class,
+    SemanticTokenKind::Directive,
+    SemanticTokenKind::Enum,
+    SemanticTokenKind::EnumMember,
+    SemanticTokenKind::Fragment,
+    SemanticTokenKind::Function,
+    SemanticTokenKind::Interface,
+    SemanticTokenKind::Namespace,
+    SemanticTokenKind::Parameter,
+    SemanticTokenKind::Type,
+    SemanticTokenKind::TypeParameter,
+    SemanticTokenKind::Variable,
+];
+
+/// The token modifiers, in the order of the legend advertised to the client.
+const TOKEN_MODIFIERS: [SemanticTokenModifier; 6] = [
+    SemanticTokenModifier::Declaration,
+    SemanticTokenModifier::Global,
+    SemanticTokenModifier::Imported,
+    SemanticTokenModifier::Readonly,
+    SemanticTokenModifier::Shadowing,
+    SemanticTokenModifier::Unresolved,
+];
+
+/// Returns the legend of the semantic tokens sent by the server.
+///
+/// Kinds and modifiers that don't have a standard LSP counterpart are
+/// advertised with custom names, which clients can style explicitly.
+pub(crate) fn legend() -> SemanticTokensLegend {
+    SemanticTokensLegend {
+        token_types: TOKEN_KINDS.map(to_lsp_token_type).to_vec(),
+        token_modifiers: TOKEN_MODIFIERS.map(to_lsp_token_modifier).to_vec(),
+    }
+}
+
+fn to_lsp_token_type(kind: SemanticTokenKind) -> LspType {
+    match kind {
+        SemanticTokenKind::Class => LspType::CLASS,
+        SemanticTokenKind::Directive => LspType::DECORATOR,
+        SemanticTokenKind::Enum => LspType::ENUM,
+        SemanticTokenKind::EnumMember => LspType::ENUM_MEMBER,
+        SemanticTokenKind::Fragment => LspType::new("fragment"),
+        SemanticTokenKind::Function => LspType::FUNCTION,
+        SemanticTokenKind::Interface => LspType::INTERFACE,
+        SemanticTokenKind::Namespace => LspType::NAMESPACE,
+        SemanticTokenKind::Parameter => LspType::PARAMETER,
+        SemanticTokenKind::Type => LspType::TYPE,
+        SemanticTokenKind::TypeParameter => LspType::TYPE_PARAMETER,
+        SemanticTokenKind::Variable => LspType::VARIABLE,
+    }
+}
+
+fn to_lsp_token_modifier(modifier: SemanticTokenModifier) -> LspModifier {
+    match modifier {
+        SemanticTokenModifier::Declaration => LspModifier::DECLARATION,
+        SemanticTokenModifier::Global => LspModifier::new("global"),
+        SemanticTokenModifier::Imported => LspModifier::new("imported"),
+        SemanticTokenModifier::Readonly => LspModifier::READONLY,
+        SemanticTokenModifier::Shadowing => LspModifier::new("shadowing"),
+        SemanticTokenModifier::Unresolved => LspModifier::new("unresolved"),
+    }
+}
+
+/// Handler for `textDocument/semanticTokens/full` LSP requests.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn semantic_tokens_full(
+    session: &Session,
+    params: lsp::SemanticTokensParams,
+) -> Result<Option<SemanticTokensResult>, LspError> {
+    let data = semantic_tokens(session, &params.text_document.uri, None)?;
+
+    Ok(data.map(|data| {
+        SemanticTokensResult::Tokens(lsp::SemanticTokens {
+            result_id: None,
+            data,
+        })
+    }))
+}
+
+/// Handler for `textDocument/semanticTokens/range` LSP requests.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn semantic_tokens_range(
+    session: &Session,
+    params: lsp::SemanticTokensRangeParams,
+) -> Result<Option<SemanticTokensRangeResult>, LspError> {
+    let data = semantic_tokens(session, &params.text_document.uri, Some(params.range))?;
+
+    Ok(data.map(|data| {
+        SemanticTokensRangeResult::Tokens(lsp::SemanticTokens {
+            result_id: None,
+            data,
+        })
+    }))
+}
+
+fn semantic_tokens(
+    session: &Session,
+    url: &Uri,
+    range: Option<lsp::Range>,
+) -> Result<Option<Vec<lsp::SemanticToken>>, LspError> {
+    let path = session.file_path(url)?;
+    let Some(doc) = session.document(url) else {
+        return Ok(None);
+    };
+
+    let position_encoding = session.position_encoding();
+    let range = range
+        .map(|range| from_proto::text_range(&doc.line_index, range, position_encoding))
+        .transpose()
+        .with_context(|| format!("failed to access range {range:?} in {}", url.as_str()))?;
+
+    let result = match session.workspace.semantic_tokens(SemanticTokensParams {
+        project_key: doc.project_key,
+        path,
+        range,
+    }) {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+
+    let data = encode_tokens(&doc.line_index, result.tokens, position_encoding)?;
+
+    Ok(Some(data))
+}
+
+/// Encodes the tokens using the relative positions required by the protocol,
+/// where each token is positioned relatively to the previous one.
+fn encode_tokens(
+    line_index: &LineIndex,
+    tokens: Vec<SemanticToken>,
+    position_encoding: PositionEncoding,
+) -> Result<Vec<lsp::SemanticToken>, LspError> {
+    let mut data = Vec::with_capacity(tokens.len());
+    let mut previous = lsp::Position::default();
+    for token in tokens {
+        let lsp::Range { start, end } =
+            to_proto::range(line_index, token.range, position_encoding)?;
+        // Tokens can't span multiple lines.
+        if start.line != end.line || start.character == end.character {
+            continue;
+        }
+
+        let token_type = TOKEN_KINDS
+            .iter()
+            .position(|kind| *kind == token.kind)
+            .unwrap_or_default() as u32;
+        let token_modifiers_bitset = token
+            .modifiers
+            .iter()
+            .filter_map(|modifier| TOKEN_MODIFIERS.iter().position(|item| item == modifier))
+            .fold(0, |bitset, index| bitset | (1 << index));
+
+        let delta_line = start.line - previous.line;
+        let delta_start = if delta_line == 0 {
+            start.character - previous.character
+        } else {
+            start.character
+        };
+        data.push(lsp::SemanticToken {
+            delta_line,
+            delta_start,
+            length: end.character - start.character,
+            token_type,
+            token_modifiers_bitset,
+        });
+        previous = start;
+    }
+
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_rowan::{TextRange, TextSize};
+
+    #[test]
+    fn encodes_tokens_relatively() {
+        let line_index = LineIndex::new("let a = b;\nlet c = a;\n");
+        let token = |start: u32, end: u32, kind, modifiers| SemanticToken {
+            range: TextRange::new(TextSize::from(start), TextSize::from(end)),
+            kind,
+            modifiers,
+        };
+        let tokens = vec![
+            token(
+                4,
+                5,
+                SemanticTokenKind::Variable,
+                vec![SemanticTokenModifier::Declaration],
+            ),
+            token(
+                8,
+                9,
+                SemanticTokenKind::Variable,
+                vec![SemanticTokenModifier::Unresolved],
+            ),
+            token(
+                15,
+                16,
+                SemanticTokenKind::Variable,
+                vec![
+                    SemanticTokenModifier::Declaration,
+                    SemanticTokenModifier::Readonly,
+                ],
+            ),
+            token(19, 20, SemanticTokenKind::Variable, Vec::new()),
+        ];
+
+        let data = encode_tokens(&line_index, tokens, PositionEncoding::Utf8).unwrap();
+        let encoded: Vec<_> = data
+            .iter()
+            .map(|token| {
+                (
+                    token.delta_line,
+                    token.delta_start,
+                    token.length,
+                    token.token_type,
+                    token.token_modifiers_bitset,
+                )
+            })
+            .collect();
+        assert_eq!(
+            encoded,
+            [
+                (0, 4, 1, 11, 0b1),
+                (0, 4, 1, 11, 0b10_0000),
+                (1, 4, 1, 11, 0b1001),
+                (0, 4, 1, 11, 0),
+            ]
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use crate::diagnostics::LspError;
+use crate::handlers::navigation::line_index;
+use crate::session::Session;
+use biome_line_index::LineIndex;
+use biome_lsp_converters::{PositionEncoding, to_proto};
+use biome_service::WorkspaceError;
+use biome_service::workspace::{
+    DocumentSymbol, DocumentSymbolsParams, SymbolKind, WorkspaceSymbolsParams,
+};
+use tower_lsp_server::UriExt;
+use tower_lsp_server::lsp_types::{self as lsp, DocumentSymbolResponse, OneOf, Uri};
+
+/// The maximum number of symbols returned by a `workspace/symbol` request,
+/// across all the projects of the session.
+const MAX_WORKSPACE_SYMBOLS: usize = 256;
+
+/// Handler for `textDocument/documentSymbol` LSP requests.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn document_symbol(
+    session: &Session,
+    params: lsp::DocumentSymbolParams,
+) -> Result<Option<DocumentSymbolResponse>, LspError> {
+    let url = params.text_document.uri;
+    let path = session.file_path(&url)?;
+    let Some(doc) = session.document(&url) else {
+        return Ok(None);
+    };
+
+    let result = match session.workspace.document_symbols(DocumentSymbolsParams {
+        project_key: doc.project_key,
+        path,
+    }) {
+        Ok(result) => result,
+        Err(WorkspaceError::SourceFileNotSupported(_)) => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+
+    let position_encoding = session.position_encoding();
+    let symbols = result
+        .symbols
+        .into_iter()
+        .map(|symbol| to_lsp_document_symbol(&doc.line_index, symbol, position_encoding))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Some(DocumentSymbolResponse::Nested(symbols)))
+}
+
+/// Handler for `workspace/symbol` LSP requests.
+///
+/// Symbols are searched in all the projects opened by the session, including
+/// the files that were indexed by the scanner. At most [MAX_WORKSPACE_SYMBOLS]
+/// symbols are returned, so the search stops as soon as enough symbols match.
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub(crate) fn workspace_symbol(
+    session: &Session,
+    params: lsp::WorkspaceSymbolParams,
+) -> Result<Option<Vec<lsp::WorkspaceSymbol>>, LspError> {
+    let position_encoding = session.position_encoding();
+
+    let mut symbols = Vec::new();
+    for project_key in session.project_keys() {
+        if symbols.len() >= MAX_WORKSPACE_SYMBOLS {
+            break;
+        }
+
+        let result = session
+            .workspace
+            .workspace_symbols(WorkspaceSymbolsParams {
+                project_key,
+                query: params.query.clone(),
+                limit: MAX_WORKSPACE_SYMBOLS - symbols.len(),
+            })?;
+
+        let mut current_file: Option<(Uri, LineIndex)> = None;
+        for symbol in result.symbols {
+            let Some(uri) = Uri::from_file_path(symbol.path.as_path()) else {
+                continue;
+            };
+
+            let line_index = match current_file {
+                Some((ref current_uri, ref line_index)) if current_uri == &uri => line_index,
+                _ => {
+                    let line_index = line_index(session, project_key, &uri, symbol.path)?;
+                    &current_file.insert((uri.clone(), line_index)).1
+                }
+            };
+
+            let range = to_proto::range(line_index, symbol.range, position_encoding)?;
+            symbols.push(lsp::WorkspaceSymbol {
+                name: symbol.name,
+                kind: to_lsp_symbol_kind(symbol.kind),
+                tags: None,
+                container_name: symbol.container_name,
+                location: OneOf::Left(lsp::Location { uri, range }),
+                data: None,
+            });
+        }
+    }
+
+    Ok(Some(symbols))
+}
+
+fn to_lsp_document_symbol(
+    line_index: &LineIndex,
+    symbol: DocumentSymbol,
+    position_encoding: PositionEncoding,
+) -> Result<lsp::DocumentSymbol, LspError> {
+    let children = symbol
+        .children
+        .into_iter()
+        .map(|child| to_lsp_document_symbol(line_index, child, position_encoding))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // The `deprecated` field is deprecated in favour of `tags`, but it still
+    // needs to be specified
+    #[expect(deprecated)]
+    Ok(lsp::DocumentSymbol {
+        name: symbol.name,
+        detail: None,
+        kind: to_lsp_symbol_kind(symbol.kind),
+        tags: None,
+        deprecated: None,
+        range: to_proto::range(line_index, symbol.range, position_encoding)?,
+        selection_range: to_proto::range(line_index, symbol.selection_range, position_encoding)?,
+        children: (!children.is_empty()).then_some(children),
+    })
+}
+
+fn to_lsp_symbol_kind(kind: SymbolKind) -> lsp::SymbolKind {
+    match kind {
+        SymbolKind::Array => lsp::SymbolKind::ARRAY,
+        SymbolKind::Boolean => lsp::SymbolKind::BOOLEAN,
+        SymbolKind::Class => lsp::SymbolKind::CLASS,
+        SymbolKind::Constant => lsp::SymbolKind::CONSTANT,
+        SymbolKind::Constructor => lsp::SymbolKind::CONSTRUCTOR,
+        SymbolKind::Enum => lsp::SymbolKind::ENUM,
+        SymbolKind::EnumMember => lsp::SymbolKind::ENUM_MEMBER,
+        SymbolKind::Field => lsp::SymbolKind::FIELD,
+        SymbolKind::Function => lsp::SymbolKind::FUNCTION,
+        SymbolKind::Interface => lsp::SymbolKind::INTERFACE,
+        SymbolKind::Method => lsp::SymbolKind::METHOD,
+        SymbolKind::Module => lsp::SymbolKind::MODULE,
+        SymbolKind::Namespace => lsp::SymbolKind::NAMESPACE,
+        SymbolKind::Null => lsp::SymbolKind::NULL,
+        SymbolKind::Number => lsp::SymbolKind::NUMBER,
+        SymbolKind::Object => lsp::SymbolKind::OBJECT,
+        SymbolKind::Property => lsp::SymbolKind::PROPERTY,
+        SymbolKind::String => lsp::SymbolKind::STRING,
+        SymbolKind::Struct => lsp::SymbolKind::STRUCT,
+        SymbolKind::TypeParameter => lsp::SymbolKind::TYPE_PARAMETER,
+        SymbolKind::Variable => lsp::SymbolKind::VARIABLE,
+    }
+}
@@ -490,6 +490,172 @@ impl LanguageServer for LSPServer {
 
         self.map_op_error(result).await
     }
+
+    async fn hover(&self, params: HoverParams) -> LspResult<Option<Hover>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::hover::hover(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> LspResult<Option<GotoDefinitionResponse>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::navigation::goto_definition(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn references(&self, params: ReferenceParams) -> LspResult<Option<Vec<Location>>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::navigation::references(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> LspResult<Option<PrepareRenameResponse>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::rename::prepare_rename(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn rename(&self, params: RenameParams) -> LspResult<Option<WorkspaceEdit>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::rename::rename(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> LspResult<Option<DocumentSymbolResponse>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::symbols::document_symbol(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn symbol(
+        &self,
+        params: WorkspaceSymbolParams,
+    ) -> LspResult<Option<OneOf<Vec<SymbolInformation>, Vec<WorkspaceSymbol>>>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::symbols::workspace_symbol(&self.session, params)
+        });
+
+        let symbols = self.map_op_error(result).await?;
+        Ok(symbols.map(OneOf::Right))
+    }
+
+    async fn semantic_tokens_full(
+        &self,
+        params: SemanticTokensParams,
+    ) -> LspResult<Option<SemanticTokensResult>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::semantic_tokens::semantic_tokens_full(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn semantic_tokens_range(
+        &self,
+        params: SemanticTokensRangeParams,
+    ) -> LspResult<Option<SemanticTokensRangeResult>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::semantic_tokens::semantic_tokens_range(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn diagnostic(
+        &self,
+        params: DocumentDiagnosticParams,
+    ) -> LspResult<DocumentDiagnosticReportResult> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::diagnostics::document_diagnostic(&self.session, params)
+        });
+
+        let report = self.map_op_error(result).await?.unwrap_or_default();
+        Ok(DocumentDiagnosticReportResult::Report(
+            DocumentDiagnosticReport::Full(report),
+        ))
+    }
+
+    async fn workspace_diagnostic(
+        &self,
+        params: WorkspaceDiagnosticParams,
+    ) -> LspResult<WorkspaceDiagnosticReportResult> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::diagnostics::workspace_diagnostic(&self.session, params)
+        });
+
+        let report = self.map_op_error(result).await?.unwrap_or_default();
+        Ok(WorkspaceDiagnosticReportResult::Report(report))
+    }
+
+    async fn folding_range(
+        &self,
+        params: FoldingRangeParams,
+    ) -> LspResult<Option<Vec<FoldingRange>>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::ranges::folding_range(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn selection_range(
+        &self,
+        params: SelectionRangeParams,
+    ) -> LspResult<Option<Vec<SelectionRange>>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::ranges::selection_range(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn linked_editing_range(
+        &self,
+        params: LinkedEditingRangeParams,
+    ) -> LspResult<Option<LinkedEditingRanges>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::ranges::linked_editing_range(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn completion(&self, params: CompletionParams) -> LspResult<Option<CompletionResponse>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::completions::completion(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
+
+    async fn inlay_hint(&self, params: InlayHintParams) -> LspResult<Option<Vec<InlayHint>>> {
+        let result = biome_diagnostics::panic::catch_unwind(move || {
+            handlers::inlay_hints::inlay_hint(&self.session, params)
+        });
+
+        self.map_op_error(result).await
+    }
 }
 
 impl Drop for LSPServer {
@@ -670,6 +836,8 @@ impl ServerFactory {
         workspace_method!(builder, change_file);
         workspace_method!(builder, check_file_size);
         workspace_method!(builder, get_file_content);
+        workspace_method!(builder, get_module_dependencies);
+        workspace_method!(builder, get_file_configuration);
         workspace_method!(builder, close_file);
         workspace_method!(builder, pull_diagnostics);
         workspace_method!(builder, pull_actions);
@@ -678,6 +846,19 @@ impl ServerFactory {
         workspace_method!(builder, format_on_type);
         workspace_method!(builder, fix_file);
         workspace_method!(builder, rename);
+        workspace_method!(builder, hover);
+        workspace_method!(builder, goto_definition);
+        workspace_method!(builder, find_references);
+        workspace_method!(builder, prepare_rename);
+        workspace_method!(builder, document_symbols);
+        workspace_method!(builder, workspace_symbols);
+        workspace_method!(builder, semantic_tokens);
+        workspace_method!(builder, pull_project_diagnostics);
+        workspace_method!(builder, folding_ranges);
+        workspace_method!(builder, selection_range);
+        workspace_method!(builder, inlay_hints);
+        workspace_method!(builder, completions);
+        workspace_method!(builder, linked_editing_ranges);
         workspace_method!(builder, parse_pattern);
         workspace_method!(builder, search_pattern);
         workspace_method!(builder, drop_pattern);
@@ -3305,6 +3305,7 @@ export function bar() {
                 skip: Vec::new(),
                 enabled_rules: vec![RuleSelector::Rule("nursery", "noImportCycles")],
                 pull_code_actions: false,
+                range: None,
             },
         )
         .await?
@@ -3345,6 +3346,7 @@ export function bar() {
                 skip: Vec::new(),
                 enabled_rules: vec![RuleSelector::Rule("nursery", "noImportCycles")],
                 pull_code_actions: false,
+                range: None,
             },
         )
         .await?
@@ -3377,6 +3379,7 @@ export function bar() {
                 skip: Vec::new(),
                 enabled_rules: vec![RuleSelector::Rule("nursery", "noImportCycles")],
                 pull_code_actions: false,
+                range: None,
             },
         )
         .await?
@@ -3413,6 +3416,7 @@ export function bar() {
                 skip: Vec::new(),
                 enabled_rules: vec![RuleSelector::Rule("nursery", "noImportCycles")],
                 pull_code_actions: false,
+                range: None,
             },
         )
         .await?
@@ -3524,6 +3528,7 @@ export function bar() {
                 skip: Vec::new(),
                 enabled_rules: vec![RuleSelector::Rule("nursery", "noImportCycles")],
                 pull_code_actions: false,
+                range: None,
             },
         )
         .await?
@@ -3569,6 +3574,7 @@ export function bar() {
                 skip: Vec::new(),
                 enabled_rules: vec![RuleSelector::Rule("nursery", "noImportCycles")],
                 pull_code_actions: false,
+                range: None,
             },
         )
         .await?
@@ -3610,6 +3616,7 @@ export function bar() {
                 skip: Vec::new(),
                 enabled_rules: vec![RuleSelector::Rule("nursery", "noImportCycles")],
                 pull_code_actions: false,
+                range: None,
             },
         )
         .await?
@@ -3628,6 +3635,1137 @@ export function bar() {
     Ok(())
 }
 
+#[tokio::test]
+async fn hover_shows_inferred_type_and_rule_documentation() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, mut receiver) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document("export const a = \"foo\";\ndebugger;\n")
+        .await?;
+    wait_for_notification(&mut receiver, |n| n.is_publish_diagnostics()).await;
+
+    let hover_at = |line, character| lsp::HoverParams {
+        text_document_position_params: lsp::TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier {
+                uri: uri!("document.js"),
+            },
+            position: Position::new(line, character),
+        },
+        work_done_progress_params: WorkDoneProgressParams {
+            work_done_token: None,
+        },
+    };
+
+    let res: lsp::Hover = server
+        .request("textDocument/hover", "hover_type", hover_at(0, 13))
+        .await?
+        .context("hover returned None")?;
+
+    assert_eq!(
+        res,
+        lsp::Hover {
+            contents: lsp::HoverContents::Markup(lsp::MarkupContent {
+                kind: lsp::MarkupKind::Markdown,
+                value: String::from("`a`: `value: foo`"),
+            }),
+            range: Some(Range::new(Position::new(0, 13), Position::new(0, 14))),
+        }
+    );
+
+    let res: lsp::Hover = server
+        .request("textDocument/hover", "hover_rule", hover_at(1, 2))
+        .await?
+        .context("hover returned None")?;
+
+    let lsp::HoverContents::Markup(contents) = res.contents else {
+        bail!("expected Markdown contents, got {:?}", res.contents);
+    };
+    assert!(
+        contents
+            .value
+            .starts_with("**lint/suspicious/noDebugger**\n\nDisallow the use of `debugger`"),
+        "unexpected hover contents: {}",
+        contents.value
+    );
+    assert!(
+        contents
+            .value
+            .ends_with("(https://biomejs.dev/linter/rules/no-debugger)")
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn goto_definition_and_references_across_modules() -> Result<()> {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        to_utf8_file_path_buf(uri!("biome.json")),
+        r#"{
+  "linter": {
+    "rules": {
+      "nursery": {
+        "noImportCycles": "error"
+      }
+    }
+  }
+}
+"#,
+    );
+    fs.insert(
+        to_utf8_file_path_buf(uri!("bar.ts")),
+        "export function bar() {}\n",
+    );
+    fs.insert(
+        to_utf8_file_path_buf(uri!("foo.ts")),
+        "import { bar } from \"./bar.ts\";\n\nbar();\n",
+    );
+
+    let factory = ServerFactory::new_with_fs(Box::new(fs));
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    server
+        .open_named_document(
+            "import { bar } from \"./bar.ts\";\n\nbar();\n",
+            uri!("foo.ts"),
+            "typescript",
+        )
+        .await?;
+
+    let text_document_position = lsp::TextDocumentPositionParams {
+        text_document: TextDocumentIdentifier {
+            uri: uri!("foo.ts"),
+        },
+        position: Position::new(2, 1),
+    };
+
+    let res: lsp::GotoDefinitionResponse = server
+        .request(
+            "textDocument/definition",
+            "goto_definition",
+            lsp::GotoDefinitionParams {
+                text_document_position_params: text_document_position.clone(),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("goto_definition returned None")?;
+
+    assert_eq!(
+        res,
+        lsp::GotoDefinitionResponse::Array(vec![lsp::Location {
+            uri: uri!("bar.ts"),
+            range: Range::new(Position::new(0, 16), Position::new(0, 19)),
+        }])
+    );
+
+    let res: Vec<lsp::Location> = server
+        .request(
+            "textDocument/references",
+            "references",
+            lsp::ReferenceParams {
+                text_document_position,
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: lsp::PartialResultParams::default(),
+                context: lsp::ReferenceContext {
+                    include_declaration: true,
+                },
+            },
+        )
+        .await?
+        .context("references returned None")?;
+
+    assert_eq!(
+        res,
+        vec![
+            lsp::Location {
+                uri: uri!("bar.ts"),
+                range: Range::new(Position::new(0, 16), Position::new(0, 19)),
+            },
+            lsp::Location {
+                uri: uri!("foo.ts"),
+                range: Range::new(Position::new(0, 9), Position::new(0, 12)),
+            },
+            lsp::Location {
+                uri: uri!("foo.ts"),
+                range: Range::new(Position::new(2, 0), Position::new(2, 3)),
+            },
+        ]
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rename_updates_importing_modules() -> Result<()> {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        to_utf8_file_path_buf(uri!("biome.json")),
+        r#"{
+  "linter": {
+    "rules": {
+      "nursery": {
+        "noImportCycles": "error"
+      }
+    }
+  }
+}
+"#,
+    );
+    fs.insert(
+        to_utf8_file_path_buf(uri!("bar.ts")),
+        "export function bar() {}\n",
+    );
+    fs.insert(
+        to_utf8_file_path_buf(uri!("baz.ts")),
+        "import { bar as qux } from \"./bar.ts\";\n\nqux();\n",
+    );
+    fs.insert(
+        to_utf8_file_path_buf(uri!("foo.ts")),
+        "import { bar } from \"./bar.ts\";\n\nbar();\n",
+    );
+
+    let factory = ServerFactory::new_with_fs(Box::new(fs));
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    server
+        .open_named_document(
+            "import { bar } from \"./bar.ts\";\n\nbar();\n",
+            uri!("foo.ts"),
+            "typescript",
+        )
+        .await?;
+
+    let text_document_position = lsp::TextDocumentPositionParams {
+        text_document: TextDocumentIdentifier {
+            uri: uri!("foo.ts"),
+        },
+        position: Position::new(2, 1),
+    };
+
+    let res: lsp::PrepareRenameResponse = server
+        .request(
+            "textDocument/prepareRename",
+            "prepare_rename",
+            text_document_position.clone(),
+        )
+        .await?
+        .context("prepare_rename returned None")?;
+
+    assert_eq!(
+        res,
+        lsp::PrepareRenameResponse::Range(Range::new(Position::new(2, 0), Position::new(2, 3)))
+    );
+
+    let res: lsp::WorkspaceEdit = server
+        .request(
+            "textDocument/rename",
+            "rename",
+            lsp::RenameParams {
+                text_document_position,
+                new_name: "renamed".to_string(),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+            },
+        )
+        .await?
+        .context("rename returned None")?;
+
+    let edit = |line: u32, start: u32, end: u32| lsp::TextEdit {
+        range: Range::new(Position::new(line, start), Position::new(line, end)),
+        new_text: "renamed".to_string(),
+    };
+    assert_eq!(
+        res.changes,
+        Some(HashMap::from([
+            (uri!("bar.ts"), vec![edit(0, 16, 19)]),
+            (uri!("baz.ts"), vec![edit(0, 9, 12)]),
+            (uri!("foo.ts"), vec![edit(0, 9, 12), edit(2, 0, 3)]),
+        ]))
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn document_and_workspace_symbols() -> Result<()> {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        to_utf8_file_path_buf(uri!("biome.json")),
+        r#"{
+  "linter": {
+    "rules": {
+      "nursery": {
+        "noImportCycles": "error"
+      }
+    }
+  }
+}
+"#,
+    );
+    fs.insert(
+        to_utf8_file_path_buf(uri!("utils.ts")),
+        "export function formatDate() {}\n",
+    );
+
+    let factory = ServerFactory::new_with_fs(Box::new(fs));
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    server
+        .open_named_document(
+            ".card {\n  @media (width > 600px) {\n    .title {}\n  }\n}\n",
+            uri!("styles.css"),
+            "css",
+        )
+        .await?;
+
+    let res: lsp::DocumentSymbolResponse = server
+        .request(
+            "textDocument/documentSymbol",
+            "document_symbol",
+            lsp::DocumentSymbolParams {
+                text_document: TextDocumentIdentifier {
+                    uri: uri!("styles.css"),
+                },
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("document_symbol returned None")?;
+
+    let lsp::DocumentSymbolResponse::Nested(symbols) = res else {
+        panic!("expected nested document symbols");
+    };
+    let names = |symbols: &[lsp::DocumentSymbol]| {
+        symbols
+            .iter()
+            .map(|symbol| symbol.name.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(names(&symbols), [".card"]);
+    let media = symbols[0].children.as_deref().unwrap_or_default();
+    assert_eq!(names(media), ["@media (width > 600px)"]);
+    assert_eq!(
+        names(media[0].children.as_deref().unwrap_or_default()),
+        [".title"]
+    );
+
+    // Workspace symbols with a full location also deserialize as symbol
+    // information, so we read them as workspace symbols explicitly.
+    let symbols: Vec<lsp::WorkspaceSymbol> = server
+        .request(
+            "workspace/symbol",
+            "workspace_symbol",
+            lsp::WorkspaceSymbolParams {
+                query: "fmtdate".to_string(),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("workspace_symbol returned None")?;
+
+    assert_eq!(symbols.len(), 1);
+    assert_eq!(symbols[0].name, "formatDate");
+    assert_eq!(symbols[0].kind, lsp::SymbolKind::FUNCTION);
+    assert_eq!(
+        symbols[0].location,
+        OneOf::Left(lsp::Location {
+            uri: uri!("utils.ts"),
+            range: Range::new(Position::new(0, 16), Position::new(0, 26)),
+        })
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn workspace_symbols_are_capped_across_projects() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize_projects().await?;
+    server.initialized().await?;
+
+    let content: String = (0..200).map(|i| format!("const a{i} = {i};\n")).collect();
+    server
+        .open_named_document(&content, uri!("test_one/file.js"), "javascript")
+        .await?;
+    server
+        .open_named_document(&content, uri!("test_two/file.js"), "javascript")
+        .await?;
+
+    let symbols: Vec<lsp::WorkspaceSymbol> = server
+        .request(
+            "workspace/symbol",
+            "workspace_symbol",
+            lsp::WorkspaceSymbolParams {
+                query: "a".to_string(),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("workspace_symbol returned None")?;
+
+    assert_eq!(symbols.len(), 256);
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn semantic_tokens_from_semantic_model() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document(
+            "import { a } from \"a\";\nconst b = 1;\nfunction f(b) {\n  return a + b + c + console;\n}\n",
+        )
+        .await?;
+
+    let res: lsp::SemanticTokensResult = server
+        .request(
+            "textDocument/semanticTokens/full",
+            "semantic_tokens",
+            lsp::SemanticTokensParams {
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+                text_document: TextDocumentIdentifier {
+                    uri: uri!("document.js"),
+                },
+            },
+        )
+        .await?
+        .context("semantic_tokens returned None")?;
+
+    let lsp::SemanticTokensResult::Tokens(tokens) = res else {
+        panic!("expected semantic tokens");
+    };
+
+    let legend = handlers::semantic_tokens::legend();
+    let mut line = 0;
+    let mut character = 0;
+    let tokens: Vec<_> = tokens
+        .data
+        .iter()
+        .map(|token| {
+            if token.delta_line > 0 {
+                character = 0;
+            }
+            line += token.delta_line;
+            character += token.delta_start;
+            let modifiers: Vec<_> = legend
+                .token_modifiers
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| token.token_modifiers_bitset & (1 << index) != 0)
+                .map(|(_, modifier)| modifier.as_str())
+                .collect();
+            (
+                line,
+                character,
+                legend.token_types[token.token_type as usize].as_str(),
+                modifiers.join(","),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        tokens,
+        [
+            (
+                0,
+                9,
+                "variable",
+                "declaration,imported,readonly".to_string()
+            ),
+            (1, 6, "variable", "declaration,readonly".to_string()),
+            (2, 9, "function", "declaration".to_string()),
+            (2, 11, "parameter", "declaration,shadowing".to_string()),
+            (3, 9, "variable", "imported,readonly".to_string()),
+            (3, 13, "parameter", "shadowing".to_string()),
+            (3, 17, "variable", "unresolved".to_string()),
+            (3, 21, "variable", "global".to_string()),
+        ]
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pull_document_and_workspace_diagnostics() -> Result<()> {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        to_utf8_file_path_buf(uri!("biome.json")),
+        r#"{
+  "linter": {
+    "rules": {
+      "nursery": {
+        "noImportCycles": "error"
+      }
+    }
+  }
+}
+"#,
+    );
+    fs.insert(
+        to_utf8_file_path_buf(uri!("foo.ts")),
+        "import { bar } from \"./bar.ts\";\n\nexport function foo() {\n    bar();\n}\n",
+    );
+    fs.insert(
+        to_utf8_file_path_buf(uri!("bar.ts")),
+        "import { foo } from \"./foo.ts\";\n\nexport function bar() {\n    foo();\n}\n",
+    );
+    fs.insert(
+        to_utf8_file_path_buf(uri!("baz.ts")),
+        "export function baz() {\n    debugger;\n}\n",
+    );
+
+    let factory = ServerFactory::new_with_fs(Box::new(fs));
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    server
+        .open_named_document(
+            "import { bar } from \"./bar.ts\";\n\nexport function foo() {\n    bar();\n}\n",
+            uri!("foo.ts"),
+            "typescript",
+        )
+        .await?;
+
+    let code = |diagnostic: &lsp::Diagnostic| match &diagnostic.code {
+        Some(NumberOrString::String(code)) => code.clone(),
+        _ => String::new(),
+    };
+
+    let report: lsp::DocumentDiagnosticReportResult = server
+        .request(
+            "textDocument/diagnostic",
+            "document_diagnostic",
+            lsp::DocumentDiagnosticParams {
+                text_document: TextDocumentIdentifier {
+                    uri: uri!("foo.ts"),
+                },
+                identifier: None,
+                previous_result_id: None,
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("document_diagnostic returned None")?;
+
+    let lsp::DocumentDiagnosticReportResult::Report(lsp::DocumentDiagnosticReport::Full(report)) =
+        report
+    else {
+        panic!("expected a full document report");
+    };
+    let codes: Vec<_> = report
+        .full_document_diagnostic_report
+        .items
+        .iter()
+        .map(code)
+        .collect();
+    assert_eq!(codes, ["lint/nursery/noImportCycles"]);
+
+    let report: lsp::WorkspaceDiagnosticReportResult = server
+        .request(
+            "workspace/diagnostic",
+            "workspace_diagnostic",
+            lsp::WorkspaceDiagnosticParams {
+                identifier: None,
+                previous_result_ids: Vec::new(),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("workspace_diagnostic returned None")?;
+
+    let lsp::WorkspaceDiagnosticReportResult::Report(report) = report else {
+        panic!("expected a full workspace report");
+    };
+    // The opened document isn't reported, and only the rules that need the
+    // project scan run on the other files.
+    let files: Vec<_> = report
+        .items
+        .iter()
+        .map(|item| match item {
+            lsp::WorkspaceDocumentDiagnosticReport::Full(report) => (
+                report.uri.clone(),
+                report
+                    .full_document_diagnostic_report
+                    .items
+                    .iter()
+                    .map(code)
+                    .collect::<Vec<_>>(),
+            ),
+            lsp::WorkspaceDocumentDiagnosticReport::Unchanged(_) => {
+                panic!("expected full document reports")
+            }
+        })
+        .collect();
+    assert_eq!(
+        files,
+        [
+            (uri!("biome.json"), Vec::new()),
+            (
+                uri!("bar.ts"),
+                vec!["lint/nursery/noImportCycles".to_string()]
+            ),
+            (uri!("baz.ts"), Vec::new()),
+        ]
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn folding_and_selection_ranges() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_document(
+            "// #region setup\nconst a = {\n  b: 1,\n  c: [\n    2,\n  ],\n};\n// #endregion\n\n/**\n * Docs\n */\nfunction f() {\n  return a;\n}\n",
+        )
+        .await?;
+
+    let folding_ranges: Vec<lsp::FoldingRange> = server
+        .request(
+            "textDocument/foldingRange",
+            "folding_range",
+            lsp::FoldingRangeParams {
+                text_document: TextDocumentIdentifier {
+                    uri: uri!("document.js"),
+                },
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("folding_range returned None")?;
+
+    let folding_ranges: Vec<_> = folding_ranges
+        .into_iter()
+        .map(|range| (range.start_line, range.end_line, range.kind))
+        .collect();
+    assert_eq!(
+        folding_ranges,
+        [
+            (0, 7, Some(lsp::FoldingRangeKind::Region)),
+            (1, 5, None),
+            (3, 4, None),
+            (9, 11, Some(lsp::FoldingRangeKind::Comment)),
+            (12, 13, None),
+        ]
+    );
+
+    let selection_ranges: Vec<lsp::SelectionRange> = server
+        .request(
+            "textDocument/selectionRange",
+            "selection_range",
+            lsp::SelectionRangeParams {
+                text_document: TextDocumentIdentifier {
+                    uri: uri!("document.js"),
+                },
+                positions: vec![Position::new(2, 2)],
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+            },
+        )
+        .await?
+        .context("selection_range returned None")?;
+
+    assert_eq!(selection_ranges.len(), 1);
+    let mut ranges = Vec::new();
+    let mut selection_range = selection_ranges.first();
+    while let Some(current) = selection_range {
+        ranges.push(current.range);
+        selection_range = current.parent.as_deref();
+    }
+    // The member name, the member, the member list, and the object
+    assert_eq!(
+        ranges[..4],
+        [
+            Range::new(Position::new(2, 2), Position::new(2, 3)),
+            Range::new(Position::new(2, 2), Position::new(2, 6)),
+            Range::new(Position::new(2, 2), Position::new(5, 4)),
+            Range::new(Position::new(1, 10), Position::new(6, 1)),
+        ]
+    );
+    // The statements of the module
+    assert_eq!(
+        ranges.last(),
+        Some(&Range::new(Position::new(1, 0), Position::new(14, 1)))
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn inlay_hints_for_types_and_parameters() -> Result<()> {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        to_utf8_file_path_buf(uri!("biome.json")),
+        r#"{
+  "linter": {
+    "rules": {
+      "nursery": {
+        "noImportCycles": "error"
+      }
+    }
+  }
+}
+"#,
+    );
+    fs.insert(
+        to_utf8_file_path_buf(uri!("greet.ts")),
+        "export function greet(name: string, excited: boolean): string {\n  return excited ? `${name}!` : name;\n}\n",
+    );
+
+    let factory = ServerFactory::new_with_fs(Box::new(fs));
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.load_configuration().await?;
+
+    server
+        .open_named_document(
+            "import { greet } from \"./greet.ts\";\n\nfunction describe(value: number) {\n  if (value < 0) {\n    return undefined;\n  }\n  return \"positive\";\n}\n\nconst name = \"world\";\nconst isWorld = async () => name === \"world\";\nconst message = greet(name, true);\nconst excited = true;\ngreet(message, excited);\n",
+            uri!("main.ts"),
+            "typescript",
+        )
+        .await?;
+
+    let hints: Vec<lsp::InlayHint> = server
+        .request(
+            "textDocument/inlayHint",
+            "inlay_hint",
+            lsp::InlayHintParams {
+                text_document: TextDocumentIdentifier {
+                    uri: uri!("main.ts"),
+                },
+                range: Range::new(Position::new(0, 0), Position::new(14, 0)),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+            },
+        )
+        .await?
+        .context("inlay_hint returned None")?;
+
+    let hints: Vec<_> = hints
+        .into_iter()
+        .map(|hint| {
+            let lsp::InlayHintLabel::String(label) = hint.label else {
+                unreachable!("hints have string labels");
+            };
+            (hint.position, label, hint.kind)
+        })
+        .collect();
+    assert_eq!(
+        hints,
+        [
+            (
+                Position::new(2, 32),
+                ": undefined | \"positive\"".to_string(),
+                Some(lsp::InlayHintKind::TYPE)
+            ),
+            (
+                Position::new(10, 24),
+                ": Promise<boolean>".to_string(),
+                Some(lsp::InlayHintKind::TYPE)
+            ),
+            (
+                Position::new(11, 13),
+                ": string".to_string(),
+                Some(lsp::InlayHintKind::TYPE)
+            ),
+            (
+                Position::new(11, 28),
+                "excited:".to_string(),
+                Some(lsp::InlayHintKind::PARAMETER)
+            ),
+            (
+                Position::new(13, 6),
+                "name:".to_string(),
+                Some(lsp::InlayHintKind::PARAMETER)
+            ),
+        ]
+    );
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn inlay_hints_for_cyclic_type_aliases() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_named_document(
+            "type A = B;\ntype B = A;\ndeclare const a: A;\nconst b = a;\nfunction f(value: A) {\n  return value;\n}\n",
+            uri!("cycle.ts"),
+            "typescript",
+        )
+        .await?;
+
+    let hints: Vec<lsp::InlayHint> = server
+        .request(
+            "textDocument/inlayHint",
+            "inlay_hint",
+            lsp::InlayHintParams {
+                text_document: TextDocumentIdentifier {
+                    uri: uri!("cycle.ts"),
+                },
+                range: Range::new(Position::new(0, 0), Position::new(7, 0)),
+                work_done_progress_params: WorkDoneProgressParams::default(),
+            },
+        )
+        .await?
+        .context("inlay_hint returned None")?;
+
+    assert!(hints.is_empty(), "unexpected inlay hints: {hints:?}");
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn linked_editing_ranges_for_tags() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_named_document(
+            "const a = (\n  <Foo.Bar>\n    <div>text</div>\n  </Foo.Bar>\n);\n",
+            uri!("component.jsx"),
+            "javascriptreact",
+        )
+        .await?;
+    server
+        .open_named_document(
+            "<section>\n  <p>text</p>\n</section>\n",
+            uri!("index.html"),
+            "html",
+        )
+        .await?;
+
+    let linked_editing_range = |uri: Uri, position: Position| lsp::LinkedEditingRangeParams {
+        text_document_position_params: lsp::TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri },
+            position,
+        },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+    };
+
+    let res: lsp::LinkedEditingRanges = server
+        .request(
+            "textDocument/linkedEditingRange",
+            "linked_editing_range",
+            linked_editing_range(uri!("component.jsx"), Position::new(1, 8)),
+        )
+        .await?
+        .context("linked_editing_range returned None")?;
+
+    assert_eq!(
+        res.ranges,
+        [
+            Range::new(Position::new(1, 3), Position::new(1, 10)),
+            Range::new(Position::new(3, 4), Position::new(3, 11)),
+        ]
+    );
+
+    let res: lsp::LinkedEditingRanges = server
+        .request(
+            "textDocument/linkedEditingRange",
+            "linked_editing_range",
+            linked_editing_range(uri!("index.html"), Position::new(1, 12)),
+        )
+        .await?
+        .context("linked_editing_range returned None")?;
+
+    assert_eq!(
+        res.ranges,
+        [
+            Range::new(Position::new(1, 3), Position::new(1, 4)),
+            Range::new(Position::new(1, 11), Position::new(1, 12)),
+        ]
+    );
+
+    // The text of an element isn't linked to its tags
+    let res: Option<lsp::LinkedEditingRanges> = server
+        .request(
+            "textDocument/linkedEditingRange",
+            "linked_editing_range",
+            linked_editing_range(uri!("index.html"), Position::new(1, 6)),
+        )
+        .await?
+        .context("linked_editing_range returned no response")?;
+
+    assert_eq!(res, None);
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn completions_and_hover_for_configuration() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server
+        .open_named_document(
+            r#"{
+  "formatter": { "indentStyle": "" },
+  "linter": {
+    "rules": {
+      "style": { "useConst": "error",  },
+      "suspicious": {  }
+    }
+  }
+}
+"#,
+            uri!("biome.json"),
+            "json",
+        )
+        .await?;
+
+    let completion_at = |line, character| lsp::CompletionParams {
+        text_document_position: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier {
+                uri: uri!("biome.json"),
+            },
+            position: Position::new(line, character),
+        },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+        context: None,
+    };
+    let labels = |response: lsp::CompletionResponse| match response {
+        lsp::CompletionResponse::Array(items) => items,
+        lsp::CompletionResponse::List(list) => list.items,
+    };
+
+    // Enum values
+    let res: lsp::CompletionResponse = server
+        .request(
+            "textDocument/completion",
+            "completion_value",
+            completion_at(1, 33),
+        )
+        .await?
+        .context("completion returned None")?;
+    let items = labels(res);
+    let values: Vec<_> = items.iter().map(|item| item.label.as_str()).collect();
+    assert_eq!(values, ["tab", "space"]);
+    assert_eq!(items[0].kind, Some(lsp::CompletionItemKind::VALUE));
+    assert_eq!(
+        items[0].text_edit,
+        Some(lsp::CompletionTextEdit::Edit(lsp::TextEdit {
+            range: Range::new(Position::new(1, 32), Position::new(1, 34)),
+            new_text: String::from("\"tab\""),
+        }))
+    );
+
+    // Rule names, except the ones already configured
+    let res: lsp::CompletionResponse = server
+        .request(
+            "textDocument/completion",
+            "completion_rule",
+            completion_at(4, 38),
+        )
+        .await?
+        .context("completion returned None")?;
+    let items = labels(res);
+    assert!(items.iter().all(|item| item.label != "useConst"));
+    let rule = items
+        .iter()
+        .find(|item| item.label == "useBlockStatements")
+        .context("missing rule completion")?;
+    assert_eq!(rule.kind, Some(lsp::CompletionItemKind::PROPERTY));
+    assert!(rule.documentation.is_some());
+
+    // Groups
+    let res: lsp::CompletionResponse = server
+        .request(
+            "textDocument/completion",
+            "completion_group",
+            completion_at(3, 14),
+        )
+        .await?
+        .context("completion returned None")?;
+    let labels: Vec<_> = labels(res).into_iter().map(|item| item.label).collect();
+    assert!(labels.contains(&String::from("correctness")));
+    assert!(!labels.contains(&String::from("style")));
+
+    // Rule documentation
+    let res: lsp::Hover = server
+        .request(
+            "textDocument/hover",
+            "hover_rule",
+            lsp::HoverParams {
+                text_document_position_params: TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier {
+                        uri: uri!("biome.json"),
+                    },
+                    position: Position::new(4, 20),
+                },
+                work_done_progress_params: WorkDoneProgressParams::default(),
+            },
+        )
+        .await?
+        .context("hover returned None")?;
+    let lsp::HoverContents::Markup(contents) = res.contents else {
+        bail!("expected Markdown contents, got {:?}", res.contents);
+    };
+    assert!(
+        contents.value.starts_with("## useConst\n"),
+        "unexpected hover contents: {}",
+        contents.value
+    );
+    assert!(!contents.value.contains("expect_diagnostic"));
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
 // #region MONOREPO TESTS
 
 #[tokio::test]
@@ -307,6 +307,11 @@ impl Session {
             .map(|(_project_path, project_key)| *project_key)
     }
 
+    /// Returns the keys of all the projects opened by the session.
+    pub(crate) fn project_keys(&self) -> Vec<ProjectKey> {
+        self.projects.pin().values().copied().collect()
+    }
+
     /// Registers an open project with its root path and scans the folder.
     #[tracing::instrument(level = "debug", skip(self))]
     pub(crate) async fn insert_and_scan_project(
@@ -382,8 +387,6 @@ impl Session {
         url: Uri,
         doc: Document,
     ) -> Result<(), LspError> {
-        let biome_path = self.file_path(&url)?;
-
         if !self.notified_broken_configuration() {
             if self.configuration_status().is_editorconfig_error() {
                 self.set_notified_broken_configuration();
@@ -401,6 +404,30 @@ impl Session {
             }
         }
 
+        // Clients that support pull diagnostics request them on their own
+        if self.supports_pull_diagnostics() {
+            return Ok(());
+        }
+
+        let diagnostics = self.compute_diagnostics(&url, &doc)?;
+
+        tracing::Span::current().record("diagnostic_count", diagnostics.len());
+
+        self.client
+            .publish_diagnostics(url, diagnostics, Some(doc.version))
+            .await;
+
+        Ok(())
+    }
+
+    /// Computes the diagnostics of the file matching the provided url,
+    /// converted to the LSP format.
+    pub(crate) fn compute_diagnostics(
+        &self,
+        url: &Uri,
+        doc: &Document,
+    ) -> Result<Vec<Diagnostic>, LspError> {
+        let biome_path = self.file_path(url)?;
         let file_features = self.workspace.file_features(SupportsFeatureParams {
             project_key: doc.project_key,
             features: FeaturesBuilder::new().with_linter().with_assist().build(),
@@ -408,76 +435,73 @@ impl Session {
         })?;
 
         if !file_features.supports_lint() && !file_features.supports_assist() {
-            self.client
-                .publish_diagnostics(url, vec![], Some(doc.version))
-                .await;
-            return Ok(());
+            return Ok(Vec::new());
         }
 
-        let diagnostics: Vec<Diagnostic> = {
-            let mut categories = RuleCategoriesBuilder::default().with_syntax();
-            if self.configuration_status().is_loaded() {
-                if file_features.supports_lint() {
-                    categories = categories.with_lint();
-                }
-                if file_features.supports_assist() {
-                    categories = categories.with_assist();
-                }
+        let mut categories = RuleCategoriesBuilder::default().with_syntax();
+        if self.configuration_status().is_loaded() {
+            if file_features.supports_lint() {
+                categories = categories.with_lint();
             }
-            let result = self.workspace.pull_diagnostics(PullDiagnosticsParams {
-                project_key: doc.project_key,
-                path: biome_path.clone(),
-                categories: categories.build(),
-                only: Vec::new(),
-                skip: Vec::new(),
-                enabled_rules: Vec::new(),
-                pull_code_actions: false,
-            })?;
-
-            let content = self.workspace.get_file_content(GetFileContentParams {
-                project_key: doc.project_key,
-                path: biome_path.clone(),
-            })?;
-            let offset = match biome_path.extension() {
-                Some("vue") => VueFileHandler::start(content.as_str()),
-                Some("astro") => AstroFileHandler::start(content.as_str()),
-                Some("svelte") => SvelteFileHandler::start(content.as_str()),
-                _ => None,
-            };
+            if file_features.supports_assist() {
+                categories = categories.with_assist();
+            }
+        }
+        let result = self.workspace.pull_diagnostics(PullDiagnosticsParams {
+            project_key: doc.project_key,
+            path: biome_path.clone(),
+            categories: categories.build(),
+            only: Vec::new(),
+            skip: Vec::new(),
+            enabled_rules: Vec::new(),
+            pull_code_actions: false,
+            range: None,
+        })?;
 
-            result
-                .diagnostics
-                .into_iter()
-                .filter_map(|d| {
-                    match utils::diagnostic_to_lsp(
-                        d,
-                        &url,
-                        &doc.line_index,
-                        self.position_encoding(),
-                        offset,
-                    ) {
-                        Ok(diag) => Some(diag),
-                        Err(err) => {
-                            error!("failed to convert diagnostic to LSP: {err:?}");
-                            None
-                        }
-                    }
-                })
-                .collect()
+        let content = self.workspace.get_file_content(GetFileContentParams {
+            project_key: doc.project_key,
+            path: biome_path.clone(),
+        })?;
+        let offset = match biome_path.extension() {
+            Some("vue") => VueFileHandler::start(content.as_str()),
+            Some("astro") => AstroFileHandler::start(content.as_str()),
+            Some("svelte") => SvelteFileHandler::start(content.as_str()),
+            _ => None,
         };
 
-        tracing::Span::current().record("diagnostic_count", diagnostics.len());
-
-        self.client
-            .publish_diagnostics(url, diagnostics, Some(doc.version))
-            .await;
+        let diagnostics = result
+            .diagnostics
+            .into_iter()
+            .filter_map(|d| {
+                match utils::diagnostic_to_lsp(
+                    d,
+                    url,
+                    &doc.line_index,
+                    self.position_encoding(),
+                    offset,
+                ) {
+                    Ok(diag) => Some(diag),
+                    Err(err) => {
+                        error!("failed to convert diagnostic to LSP: {err:?}");
+                        None
+                    }
+                }
+            })
+            .collect();
 
-        Ok(())
+        Ok(diagnostics)
     }
 
     /// Updates diagnostics for every [`Document`] in this [`Session`]
     #[tracing::instrument(level = "debug", skip(self))]
     pub(crate) async fn update_all_diagnostics(&self) {
+        if self.supports_pull_diagnostics() {
+            if let Err(error) = self.client.workspace_diagnostic_refresh().await {
+                error!("Failed to refresh the diagnostics: {error}");
+            }
+            return;
+        }
+
         let mut futures: FuturesUnordered<_> = self
             .documents
             .pin()
@@ -507,6 +531,14 @@ impl Session {
         result
     }
 
+    /// True if the client supports pulling diagnostics with "textDocument/diagnostic" requests
+    pub(crate) fn supports_pull_diagnostics(&self) -> bool {
+        self.initialize_params
+            .get()
+            .and_then(|c| c.client_capabilities.text_document.as_ref())
+            .is_some_and(|c| c.diagnostic.is_some())
+    }
+
     #[instrument(level = "info", skip(self))]
     pub(crate) fn can_register_did_change_watched_files(&self) -> bool {
         let result = self
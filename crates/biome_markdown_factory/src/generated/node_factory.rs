@@ -6,26 +6,49 @@ use biome_markdown_syntax::{
     MarkdownSyntaxToken as SyntaxToken, *,
 };
 use biome_rowan::AstNode;
-pub fn md_bullet(
-    bullet_token: SyntaxToken,
-    space_token: SyntaxToken,
-    content: MdInlineItemList,
-) -> MdBullet {
-    MdBullet::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_BULLET,
-        [
-            Some(SyntaxElement::Token(bullet_token)),
-            Some(SyntaxElement::Token(space_token)),
-            Some(SyntaxElement::Node(content.into_syntax())),
-        ],
+pub fn md_autolink(value_token: SyntaxToken) -> MdAutolink {
+    MdAutolink::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_AUTOLINK,
+        [Some(SyntaxElement::Token(value_token))],
     ))
 }
-pub fn md_bullet_list_item(md_bullet_list: MdBulletList) -> MdBulletListItem {
-    MdBulletListItem::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_BULLET_LIST_ITEM,
-        [Some(SyntaxElement::Node(md_bullet_list.into_syntax()))],
+pub fn md_bullet_list(items: MdBulletListItemList) -> MdBulletList {
+    MdBulletList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_BULLET_LIST,
+        [Some(SyntaxElement::Node(items.into_syntax()))],
     ))
 }
+pub fn md_bullet_list_item(
+    bullet_token: SyntaxToken,
+    content: MdBlockList,
+) -> MdBulletListItemBuilder {
+    MdBulletListItemBuilder {
+        bullet_token,
+        content,
+        task_token: None,
+    }
+}
+pub struct MdBulletListItemBuilder {
+    bullet_token: SyntaxToken,
+    content: MdBlockList,
+    task_token: Option<SyntaxToken>,
+}
+impl MdBulletListItemBuilder {
+    pub fn with_task_token(mut self, task_token: SyntaxToken) -> Self {
+        self.task_token = Some(task_token);
+        self
+    }
+    pub fn build(self) -> MdBulletListItem {
+        MdBulletListItem::unwrap_cast(SyntaxNode::new_detached(
+            MarkdownSyntaxKind::MD_BULLET_LIST_ITEM,
+            [
+                Some(SyntaxElement::Token(self.bullet_token)),
+                self.task_token.map(|token| SyntaxElement::Token(token)),
+                Some(SyntaxElement::Node(self.content.into_syntax())),
+            ],
+        ))
+    }
+}
 pub fn md_document(value: MdBlockList, eof_token: SyntaxToken) -> MdDocumentBuilder {
     MdDocumentBuilder {
         value,
@@ -56,23 +79,42 @@ impl MdDocumentBuilder {
 }
 pub fn md_fenced_code_block(
     l_fence_token: SyntaxToken,
-    code_list: MdCodeNameList,
-    l_hard_line: MdHardLine,
-    content: MdTextual,
-    r_hard_line: MdHardLine,
-    r_fence_token: SyntaxToken,
-) -> MdFencedCodeBlock {
-    MdFencedCodeBlock::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_FENCED_CODE_BLOCK,
-        [
-            Some(SyntaxElement::Token(l_fence_token)),
-            Some(SyntaxElement::Node(code_list.into_syntax())),
-            Some(SyntaxElement::Node(l_hard_line.into_syntax())),
-            Some(SyntaxElement::Node(content.into_syntax())),
-            Some(SyntaxElement::Node(r_hard_line.into_syntax())),
-            Some(SyntaxElement::Token(r_fence_token)),
-        ],
-    ))
+    content: MdInlineItemList,
+) -> MdFencedCodeBlockBuilder {
+    MdFencedCodeBlockBuilder {
+        l_fence_token,
+        content,
+        info: None,
+        r_fence_token: None,
+    }
+}
+pub struct MdFencedCodeBlockBuilder {
+    l_fence_token: SyntaxToken,
+    content: MdInlineItemList,
+    info: Option<MdTextual>,
+    r_fence_token: Option<SyntaxToken>,
+}
+impl MdFencedCodeBlockBuilder {
+    pub fn with_info(mut self, info: MdTextual) -> Self {
+        self.info = Some(info);
+        self
+    }
+    pub fn with_r_fence_token(mut self, r_fence_token: SyntaxToken) -> Self {
+        self.r_fence_token = Some(r_fence_token);
+        self
+    }
+    pub fn build(self) -> MdFencedCodeBlock {
+        MdFencedCodeBlock::unwrap_cast(SyntaxNode::new_detached(
+            MarkdownSyntaxKind::MD_FENCED_CODE_BLOCK,
+            [
+                Some(SyntaxElement::Token(self.l_fence_token)),
+                self.info
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Node(self.content.into_syntax())),
+                self.r_fence_token.map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
 }
 pub fn md_hard_line(value_token: SyntaxToken) -> MdHardLine {
     MdHardLine::unwrap_cast(SyntaxNode::new_detached(
@@ -86,60 +128,26 @@ pub fn md_hash(hash_token: SyntaxToken) -> MdHash {
         [Some(SyntaxElement::Token(hash_token))],
     ))
 }
-pub fn md_header(before: MdHashList, after: MdHashList) -> MdHeaderBuilder {
-    MdHeaderBuilder {
-        before,
-        after,
-        content: None,
-    }
-}
-pub struct MdHeaderBuilder {
-    before: MdHashList,
-    after: MdHashList,
-    content: Option<MdParagraph>,
-}
-impl MdHeaderBuilder {
-    pub fn with_content(mut self, content: MdParagraph) -> Self {
-        self.content = Some(content);
-        self
-    }
-    pub fn build(self) -> MdHeader {
-        MdHeader::unwrap_cast(SyntaxNode::new_detached(
-            MarkdownSyntaxKind::MD_HEADER,
-            [
-                Some(SyntaxElement::Node(self.before.into_syntax())),
-                self.content
-                    .map(|token| SyntaxElement::Node(token.into_syntax())),
-                Some(SyntaxElement::Node(self.after.into_syntax())),
-            ],
-        ))
-    }
+pub fn md_header(before: MdHashList, content: MdInlineItemList, after: MdHashList) -> MdHeader {
+    MdHeader::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_HEADER,
+        [
+            Some(SyntaxElement::Node(before.into_syntax())),
+            Some(SyntaxElement::Node(content.into_syntax())),
+            Some(SyntaxElement::Node(after.into_syntax())),
+        ],
+    ))
 }
-pub fn md_html_block(md_textual: MdTextual) -> MdHtmlBlock {
+pub fn md_html_block(content: MdInlineItemList) -> MdHtmlBlock {
     MdHtmlBlock::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_HTML_BLOCK,
-        [Some(SyntaxElement::Node(md_textual.into_syntax()))],
-    ))
-}
-pub fn md_indent(value_token: SyntaxToken) -> MdIndent {
-    MdIndent::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_INDENT,
-        [Some(SyntaxElement::Token(value_token))],
+        [Some(SyntaxElement::Node(content.into_syntax()))],
     ))
 }
-pub fn md_indent_code_block(lines: MdIndentedCodeLineList) -> MdIndentCodeBlock {
+pub fn md_indent_code_block(content: MdInlineItemList) -> MdIndentCodeBlock {
     MdIndentCodeBlock::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_INDENT_CODE_BLOCK,
-        [Some(SyntaxElement::Node(lines.into_syntax()))],
-    ))
-}
-pub fn md_indented_code_line(indentation: MdIndent, content: MdTextual) -> MdIndentedCodeLine {
-    MdIndentedCodeLine::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_INDENTED_CODE_LINE,
-        [
-            Some(SyntaxElement::Node(indentation.into_syntax())),
-            Some(SyntaxElement::Node(content.into_syntax())),
-        ],
+        [Some(SyntaxElement::Node(content.into_syntax()))],
     ))
 }
 pub fn md_inline_code(
@@ -170,92 +178,68 @@ pub fn md_inline_emphasis(
         ],
     ))
 }
+pub fn md_inline_html(value_token: SyntaxToken) -> MdInlineHtml {
+    MdInlineHtml::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_INLINE_HTML,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
 pub fn md_inline_image(
-    l_brack_token: SyntaxToken,
     excl_token: SyntaxToken,
-    alt: MdInlineImageAlt,
-    source: MdInlineImageSource,
+    l_brack_token: SyntaxToken,
+    alt: MdInlineItemList,
     r_brack_token: SyntaxToken,
+    l_paren_token: SyntaxToken,
+    r_paren_token: SyntaxToken,
 ) -> MdInlineImageBuilder {
     MdInlineImageBuilder {
-        l_brack_token,
         excl_token,
+        l_brack_token,
         alt,
-        source,
         r_brack_token,
-        link: None,
+        l_paren_token,
+        r_paren_token,
+        destination: None,
+        title: None,
     }
 }
 pub struct MdInlineImageBuilder {
-    l_brack_token: SyntaxToken,
     excl_token: SyntaxToken,
-    alt: MdInlineImageAlt,
-    source: MdInlineImageSource,
+    l_brack_token: SyntaxToken,
+    alt: MdInlineItemList,
     r_brack_token: SyntaxToken,
-    link: Option<MdInlineImageLink>,
+    l_paren_token: SyntaxToken,
+    r_paren_token: SyntaxToken,
+    destination: Option<MdLinkDestination>,
+    title: Option<MdLinkTitle>,
 }
 impl MdInlineImageBuilder {
-    pub fn with_link(mut self, link: MdInlineImageLink) -> Self {
-        self.link = Some(link);
+    pub fn with_destination(mut self, destination: MdLinkDestination) -> Self {
+        self.destination = Some(destination);
+        self
+    }
+    pub fn with_title(mut self, title: MdLinkTitle) -> Self {
+        self.title = Some(title);
         self
     }
     pub fn build(self) -> MdInlineImage {
         MdInlineImage::unwrap_cast(SyntaxNode::new_detached(
             MarkdownSyntaxKind::MD_INLINE_IMAGE,
             [
-                Some(SyntaxElement::Token(self.l_brack_token)),
                 Some(SyntaxElement::Token(self.excl_token)),
+                Some(SyntaxElement::Token(self.l_brack_token)),
                 Some(SyntaxElement::Node(self.alt.into_syntax())),
-                Some(SyntaxElement::Node(self.source.into_syntax())),
                 Some(SyntaxElement::Token(self.r_brack_token)),
-                self.link
+                Some(SyntaxElement::Token(self.l_paren_token)),
+                self.destination
                     .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.title
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Token(self.r_paren_token)),
             ],
         ))
     }
 }
-pub fn md_inline_image_alt(
-    l_brack_token: SyntaxToken,
-    content: MdInlineItemList,
-    r_brack_token: SyntaxToken,
-) -> MdInlineImageAlt {
-    MdInlineImageAlt::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_INLINE_IMAGE_ALT,
-        [
-            Some(SyntaxElement::Token(l_brack_token)),
-            Some(SyntaxElement::Node(content.into_syntax())),
-            Some(SyntaxElement::Token(r_brack_token)),
-        ],
-    ))
-}
-pub fn md_inline_image_link(
-    l_paren_token: SyntaxToken,
-    content: MdInlineItemList,
-    r_paren_token: SyntaxToken,
-) -> MdInlineImageLink {
-    MdInlineImageLink::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_INLINE_IMAGE_LINK,
-        [
-            Some(SyntaxElement::Token(l_paren_token)),
-            Some(SyntaxElement::Node(content.into_syntax())),
-            Some(SyntaxElement::Token(r_paren_token)),
-        ],
-    ))
-}
-pub fn md_inline_image_source(
-    l_paren_token: SyntaxToken,
-    content: MdInlineItemList,
-    r_paren_token: SyntaxToken,
-) -> MdInlineImageSource {
-    MdInlineImageSource::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_INLINE_IMAGE_SOURCE,
-        [
-            Some(SyntaxElement::Token(l_paren_token)),
-            Some(SyntaxElement::Node(content.into_syntax())),
-            Some(SyntaxElement::Token(r_paren_token)),
-        ],
-    ))
-}
 pub fn md_inline_italic(
     l_fence_token: SyntaxToken,
     content: MdInlineItemList,
@@ -275,83 +259,406 @@ pub fn md_inline_link(
     text: MdInlineItemList,
     r_brack_token: SyntaxToken,
     l_paren_token: SyntaxToken,
-    source: MdInlineItemList,
     r_paren_token: SyntaxToken,
-) -> MdInlineLink {
-    MdInlineLink::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_INLINE_LINK,
+) -> MdInlineLinkBuilder {
+    MdInlineLinkBuilder {
+        l_brack_token,
+        text,
+        r_brack_token,
+        l_paren_token,
+        r_paren_token,
+        destination: None,
+        title: None,
+    }
+}
+pub struct MdInlineLinkBuilder {
+    l_brack_token: SyntaxToken,
+    text: MdInlineItemList,
+    r_brack_token: SyntaxToken,
+    l_paren_token: SyntaxToken,
+    r_paren_token: SyntaxToken,
+    destination: Option<MdLinkDestination>,
+    title: Option<MdLinkTitle>,
+}
+impl MdInlineLinkBuilder {
+    pub fn with_destination(mut self, destination: MdLinkDestination) -> Self {
+        self.destination = Some(destination);
+        self
+    }
+    pub fn with_title(mut self, title: MdLinkTitle) -> Self {
+        self.title = Some(title);
+        self
+    }
+    pub fn build(self) -> MdInlineLink {
+        MdInlineLink::unwrap_cast(SyntaxNode::new_detached(
+            MarkdownSyntaxKind::MD_INLINE_LINK,
+            [
+                Some(SyntaxElement::Token(self.l_brack_token)),
+                Some(SyntaxElement::Node(self.text.into_syntax())),
+                Some(SyntaxElement::Token(self.r_brack_token)),
+                Some(SyntaxElement::Token(self.l_paren_token)),
+                self.destination
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.title
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Token(self.r_paren_token)),
+            ],
+        ))
+    }
+}
+pub fn md_inline_strikethrough(
+    l_fence_token: SyntaxToken,
+    content: MdInlineItemList,
+    r_fence_token: SyntaxToken,
+) -> MdInlineStrikethrough {
+    MdInlineStrikethrough::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_INLINE_STRIKETHROUGH,
         [
-            Some(SyntaxElement::Token(l_brack_token)),
-            Some(SyntaxElement::Node(text.into_syntax())),
-            Some(SyntaxElement::Token(r_brack_token)),
-            Some(SyntaxElement::Token(l_paren_token)),
-            Some(SyntaxElement::Node(source.into_syntax())),
-            Some(SyntaxElement::Token(r_paren_token)),
+            Some(SyntaxElement::Token(l_fence_token)),
+            Some(SyntaxElement::Node(content.into_syntax())),
+            Some(SyntaxElement::Token(r_fence_token)),
         ],
     ))
 }
-pub fn md_link_block(label: MdTextual, url: MdTextual) -> MdLinkBlockBuilder {
-    MdLinkBlockBuilder {
+pub fn md_link_destination(value_token: SyntaxToken) -> MdLinkDestination {
+    MdLinkDestination::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_LINK_DESTINATION,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn md_link_reference_definition(
+    l_brack_token: SyntaxToken,
+    label: MdTextual,
+    r_brack_token: SyntaxToken,
+    colon_token: SyntaxToken,
+    destination: MdLinkDestination,
+) -> MdLinkReferenceDefinitionBuilder {
+    MdLinkReferenceDefinitionBuilder {
+        l_brack_token,
         label,
-        url,
+        r_brack_token,
+        colon_token,
+        destination,
         title: None,
     }
 }
-pub struct MdLinkBlockBuilder {
+pub struct MdLinkReferenceDefinitionBuilder {
+    l_brack_token: SyntaxToken,
     label: MdTextual,
-    url: MdTextual,
-    title: Option<MdTextual>,
+    r_brack_token: SyntaxToken,
+    colon_token: SyntaxToken,
+    destination: MdLinkDestination,
+    title: Option<MdLinkTitle>,
 }
-impl MdLinkBlockBuilder {
-    pub fn with_title(mut self, title: MdTextual) -> Self {
+impl MdLinkReferenceDefinitionBuilder {
+    pub fn with_title(mut self, title: MdLinkTitle) -> Self {
         self.title = Some(title);
         self
     }
-    pub fn build(self) -> MdLinkBlock {
-        MdLinkBlock::unwrap_cast(SyntaxNode::new_detached(
-            MarkdownSyntaxKind::MD_LINK_BLOCK,
+    pub fn build(self) -> MdLinkReferenceDefinition {
+        MdLinkReferenceDefinition::unwrap_cast(SyntaxNode::new_detached(
+            MarkdownSyntaxKind::MD_LINK_REFERENCE_DEFINITION,
             [
+                Some(SyntaxElement::Token(self.l_brack_token)),
                 Some(SyntaxElement::Node(self.label.into_syntax())),
-                Some(SyntaxElement::Node(self.url.into_syntax())),
+                Some(SyntaxElement::Token(self.r_brack_token)),
+                Some(SyntaxElement::Token(self.colon_token)),
+                Some(SyntaxElement::Node(self.destination.into_syntax())),
                 self.title
                     .map(|token| SyntaxElement::Node(token.into_syntax())),
             ],
         ))
     }
 }
-pub fn md_order_list_item(md_bullet_list: MdBulletList) -> MdOrderListItem {
-    MdOrderListItem::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_ORDER_LIST_ITEM,
-        [Some(SyntaxElement::Node(md_bullet_list.into_syntax()))],
+pub fn md_link_title(value_token: SyntaxToken) -> MdLinkTitle {
+    MdLinkTitle::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_LINK_TITLE,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn md_ordered_list(items: MdOrderedListItemList) -> MdOrderedList {
+    MdOrderedList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_ORDERED_LIST,
+        [Some(SyntaxElement::Node(items.into_syntax()))],
     ))
 }
-pub fn md_paragraph(list: MdInlineItemList, hard_line: MdHardLine) -> MdParagraph {
+pub fn md_ordered_list_item(
+    marker_token: SyntaxToken,
+    content: MdBlockList,
+) -> MdOrderedListItemBuilder {
+    MdOrderedListItemBuilder {
+        marker_token,
+        content,
+        task_token: None,
+    }
+}
+pub struct MdOrderedListItemBuilder {
+    marker_token: SyntaxToken,
+    content: MdBlockList,
+    task_token: Option<SyntaxToken>,
+}
+impl MdOrderedListItemBuilder {
+    pub fn with_task_token(mut self, task_token: SyntaxToken) -> Self {
+        self.task_token = Some(task_token);
+        self
+    }
+    pub fn build(self) -> MdOrderedListItem {
+        MdOrderedListItem::unwrap_cast(SyntaxNode::new_detached(
+            MarkdownSyntaxKind::MD_ORDERED_LIST_ITEM,
+            [
+                Some(SyntaxElement::Token(self.marker_token)),
+                self.task_token.map(|token| SyntaxElement::Token(token)),
+                Some(SyntaxElement::Node(self.content.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn md_paragraph(content: MdInlineItemList) -> MdParagraph {
     MdParagraph::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_PARAGRAPH,
-        [
-            Some(SyntaxElement::Node(list.into_syntax())),
-            Some(SyntaxElement::Node(hard_line.into_syntax())),
-        ],
+        [Some(SyntaxElement::Node(content.into_syntax()))],
     ))
 }
-pub fn md_quote(any_md_block: AnyMdBlock) -> MdQuote {
+pub fn md_quote(marker_token: SyntaxToken, content: MdBlockList) -> MdQuote {
     MdQuote::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_QUOTE,
-        [Some(SyntaxElement::Node(any_md_block.into_syntax()))],
+        [
+            Some(SyntaxElement::Token(marker_token)),
+            Some(SyntaxElement::Node(content.into_syntax())),
+        ],
     ))
 }
-pub fn md_setext_header(md_paragraph: MdParagraph) -> MdSetextHeader {
+pub fn md_reference_image(
+    excl_token: SyntaxToken,
+    l_brack_token: SyntaxToken,
+    alt: MdInlineItemList,
+    r_brack_token: SyntaxToken,
+) -> MdReferenceImageBuilder {
+    MdReferenceImageBuilder {
+        excl_token,
+        l_brack_token,
+        alt,
+        r_brack_token,
+        label: None,
+    }
+}
+pub struct MdReferenceImageBuilder {
+    excl_token: SyntaxToken,
+    l_brack_token: SyntaxToken,
+    alt: MdInlineItemList,
+    r_brack_token: SyntaxToken,
+    label: Option<MdReferenceLinkLabel>,
+}
+impl MdReferenceImageBuilder {
+    pub fn with_label(mut self, label: MdReferenceLinkLabel) -> Self {
+        self.label = Some(label);
+        self
+    }
+    pub fn build(self) -> MdReferenceImage {
+        MdReferenceImage::unwrap_cast(SyntaxNode::new_detached(
+            MarkdownSyntaxKind::MD_REFERENCE_IMAGE,
+            [
+                Some(SyntaxElement::Token(self.excl_token)),
+                Some(SyntaxElement::Token(self.l_brack_token)),
+                Some(SyntaxElement::Node(self.alt.into_syntax())),
+                Some(SyntaxElement::Token(self.r_brack_token)),
+                self.label
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn md_reference_link(
+    l_brack_token: SyntaxToken,
+    text: MdInlineItemList,
+    r_brack_token: SyntaxToken,
+) -> MdReferenceLinkBuilder {
+    MdReferenceLinkBuilder {
+        l_brack_token,
+        text,
+        r_brack_token,
+        label: None,
+    }
+}
+pub struct MdReferenceLinkBuilder {
+    l_brack_token: SyntaxToken,
+    text: MdInlineItemList,
+    r_brack_token: SyntaxToken,
+    label: Option<MdReferenceLinkLabel>,
+}
+impl MdReferenceLinkBuilder {
+    pub fn with_label(mut self, label: MdReferenceLinkLabel) -> Self {
+        self.label = Some(label);
+        self
+    }
+    pub fn build(self) -> MdReferenceLink {
+        MdReferenceLink::unwrap_cast(SyntaxNode::new_detached(
+            MarkdownSyntaxKind::MD_REFERENCE_LINK,
+            [
+                Some(SyntaxElement::Token(self.l_brack_token)),
+                Some(SyntaxElement::Node(self.text.into_syntax())),
+                Some(SyntaxElement::Token(self.r_brack_token)),
+                self.label
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn md_reference_link_label(
+    l_brack_token: SyntaxToken,
+    r_brack_token: SyntaxToken,
+) -> MdReferenceLinkLabelBuilder {
+    MdReferenceLinkLabelBuilder {
+        l_brack_token,
+        r_brack_token,
+        label: None,
+    }
+}
+pub struct MdReferenceLinkLabelBuilder {
+    l_brack_token: SyntaxToken,
+    r_brack_token: SyntaxToken,
+    label: Option<MdTextual>,
+}
+impl MdReferenceLinkLabelBuilder {
+    pub fn with_label(mut self, label: MdTextual) -> Self {
+        self.label = Some(label);
+        self
+    }
+    pub fn build(self) -> MdReferenceLinkLabel {
+        MdReferenceLinkLabel::unwrap_cast(SyntaxNode::new_detached(
+            MarkdownSyntaxKind::MD_REFERENCE_LINK_LABEL,
+            [
+                Some(SyntaxElement::Token(self.l_brack_token)),
+                self.label
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Token(self.r_brack_token)),
+            ],
+        ))
+    }
+}
+pub fn md_setext_header(content: MdInlineItemList, underline_token: SyntaxToken) -> MdSetextHeader {
     MdSetextHeader::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_SETEXT_HEADER,
-        [Some(SyntaxElement::Node(md_paragraph.into_syntax()))],
+        [
+            Some(SyntaxElement::Node(content.into_syntax())),
+            Some(SyntaxElement::Token(underline_token)),
+        ],
     ))
 }
-pub fn md_soft_break(value_token: SyntaxToken) -> MdSoftBreak {
-    MdSoftBreak::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_SOFT_BREAK,
-        [Some(SyntaxElement::Token(value_token))],
+pub fn md_table(
+    header: MdTableRow,
+    delimiter: MdTableDelimiterRow,
+    rows: MdTableRowList,
+) -> MdTable {
+    MdTable::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_TABLE,
+        [
+            Some(SyntaxElement::Node(header.into_syntax())),
+            Some(SyntaxElement::Node(delimiter.into_syntax())),
+            Some(SyntaxElement::Node(rows.into_syntax())),
+        ],
     ))
 }
+pub fn md_table_cell(content: MdInlineItemList) -> MdTableCellBuilder {
+    MdTableCellBuilder {
+        content,
+        l_pipe_token: None,
+    }
+}
+pub struct MdTableCellBuilder {
+    content: MdInlineItemList,
+    l_pipe_token: Option<SyntaxToken>,
+}
+impl MdTableCellBuilder {
+    pub fn with_l_pipe_token(mut self, l_pipe_token: SyntaxToken) -> Self {
+        self.l_pipe_token = Some(l_pipe_token);
+        self
+    }
+    pub fn build(self) -> MdTableCell {
+        MdTableCell::unwrap_cast(SyntaxNode::new_detached(
+            MarkdownSyntaxKind::MD_TABLE_CELL,
+            [
+                self.l_pipe_token.map(|token| SyntaxElement::Token(token)),
+                Some(SyntaxElement::Node(self.content.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn md_table_delimiter_cell(value_token: SyntaxToken) -> MdTableDelimiterCellBuilder {
+    MdTableDelimiterCellBuilder {
+        value_token,
+        l_pipe_token: None,
+    }
+}
+pub struct MdTableDelimiterCellBuilder {
+    value_token: SyntaxToken,
+    l_pipe_token: Option<SyntaxToken>,
+}
+impl MdTableDelimiterCellBuilder {
+    pub fn with_l_pipe_token(mut self, l_pipe_token: SyntaxToken) -> Self {
+        self.l_pipe_token = Some(l_pipe_token);
+        self
+    }
+    pub fn build(self) -> MdTableDelimiterCell {
+        MdTableDelimiterCell::unwrap_cast(SyntaxNode::new_detached(
+            MarkdownSyntaxKind::MD_TABLE_DELIMITER_CELL,
+            [
+                self.l_pipe_token.map(|token| SyntaxElement::Token(token)),
+                Some(SyntaxElement::Token(self.value_token)),
+            ],
+        ))
+    }
+}
+pub fn md_table_delimiter_row(cells: MdTableDelimiterCellList) -> MdTableDelimiterRowBuilder {
+    MdTableDelimiterRowBuilder {
+        cells,
+        r_pipe_token: None,
+    }
+}
+pub struct MdTableDelimiterRowBuilder {
+    cells: MdTableDelimiterCellList,
+    r_pipe_token: Option<SyntaxToken>,
+}
+impl MdTableDelimiterRowBuilder {
+    pub fn with_r_pipe_token(mut self, r_pipe_token: SyntaxToken) -> Self {
+        self.r_pipe_token = Some(r_pipe_token);
+        self
+    }
+    pub fn build(self) -> MdTableDelimiterRow {
+        MdTableDelimiterRow::unwrap_cast(SyntaxNode::new_detached(
+            MarkdownSyntaxKind::MD_TABLE_DELIMITER_ROW,
+            [
+                Some(SyntaxElement::Node(self.cells.into_syntax())),
+                self.r_pipe_token.map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn md_table_row(cells: MdTableCellList) -> MdTableRowBuilder {
+    MdTableRowBuilder {
+        cells,
+        r_pipe_token: None,
+    }
+}
+pub struct MdTableRowBuilder {
+    cells: MdTableCellList,
+    r_pipe_token: Option<SyntaxToken>,
+}
+impl MdTableRowBuilder {
+    pub fn with_r_pipe_token(mut self, r_pipe_token: SyntaxToken) -> Self {
+        self.r_pipe_token = Some(r_pipe_token);
+        self
+    }
+    pub fn build(self) -> MdTableRow {
+        MdTableRow::unwrap_cast(SyntaxNode::new_detached(
+            MarkdownSyntaxKind::MD_TABLE_ROW,
+            [
+                Some(SyntaxElement::Node(self.cells.into_syntax())),
+                self.r_pipe_token.map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
 pub fn md_textual(value_token: SyntaxToken) -> MdTextual {
     MdTextual::unwrap_cast(SyntaxNode::new_detached(
         MarkdownSyntaxKind::MD_TEXTUAL,
@@ -376,82 +683,85 @@ where
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
-pub fn md_bullet_list<I>(items: I) -> MdBulletList
+pub fn md_bullet_list_item_list<I>(items: I) -> MdBulletListItemList
 where
-    I: IntoIterator<Item = MdBullet>,
+    I: IntoIterator<Item = MdBulletListItem>,
     I::IntoIter: ExactSizeIterator,
 {
-    MdBulletList::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_BULLET_LIST,
+    MdBulletListItemList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_BULLET_LIST_ITEM_LIST,
         items
             .into_iter()
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
-pub fn md_code_name_list<I, S>(items: I, separators: S) -> MdCodeNameList
+pub fn md_hash_list<I>(items: I) -> MdHashList
 where
-    I: IntoIterator<Item = MdTextual>,
+    I: IntoIterator<Item = MdHash>,
     I::IntoIter: ExactSizeIterator,
-    S: IntoIterator<Item = MarkdownSyntaxToken>,
-    S::IntoIter: ExactSizeIterator,
 {
-    let mut items = items.into_iter();
-    let mut separators = separators.into_iter();
-    let length = items.len() + separators.len();
-    MdCodeNameList::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_CODE_NAME_LIST,
-        (0..length).map(|index| {
-            if index % 2 == 0 {
-                Some(items.next()?.into_syntax().into())
-            } else {
-                Some(separators.next()?.into())
-            }
-        }),
+    MdHashList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_HASH_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
     ))
 }
-pub fn md_hash_list<I>(items: I) -> MdHashList
+pub fn md_inline_item_list<I>(items: I) -> MdInlineItemList
 where
-    I: IntoIterator<Item = MdHash>,
+    I: IntoIterator<Item = AnyMdInline>,
     I::IntoIter: ExactSizeIterator,
 {
-    MdHashList::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_HASH_LIST,
+    MdInlineItemList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_INLINE_ITEM_LIST,
         items
             .into_iter()
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
-pub fn md_indented_code_line_list<I>(items: I) -> MdIndentedCodeLineList
+pub fn md_ordered_list_item_list<I>(items: I) -> MdOrderedListItemList
 where
-    I: IntoIterator<Item = MdIndentedCodeLine>,
+    I: IntoIterator<Item = MdOrderedListItem>,
     I::IntoIter: ExactSizeIterator,
 {
-    MdIndentedCodeLineList::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_INDENTED_CODE_LINE_LIST,
+    MdOrderedListItemList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_ORDERED_LIST_ITEM_LIST,
         items
             .into_iter()
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
-pub fn md_inline_item_list<I>(items: I) -> MdInlineItemList
+pub fn md_table_cell_list<I>(items: I) -> MdTableCellList
 where
-    I: IntoIterator<Item = AnyMdInline>,
+    I: IntoIterator<Item = MdTableCell>,
     I::IntoIter: ExactSizeIterator,
 {
-    MdInlineItemList::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_INLINE_ITEM_LIST,
+    MdTableCellList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_TABLE_CELL_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn md_table_delimiter_cell_list<I>(items: I) -> MdTableDelimiterCellList
+where
+    I: IntoIterator<Item = MdTableDelimiterCell>,
+    I::IntoIter: ExactSizeIterator,
+{
+    MdTableDelimiterCellList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_TABLE_DELIMITER_CELL_LIST,
         items
             .into_iter()
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
-pub fn md_order_list<I>(items: I) -> MdOrderList
+pub fn md_table_row_list<I>(items: I) -> MdTableRowList
 where
-    I: IntoIterator<Item = AnyCodeBlock>,
+    I: IntoIterator<Item = MdTableRow>,
     I::IntoIter: ExactSizeIterator,
 {
-    MdOrderList::unwrap_cast(SyntaxNode::new_detached(
-        MarkdownSyntaxKind::MD_ORDER_LIST,
+    MdTableRowList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MD_TABLE_ROW_LIST,
         items
             .into_iter()
             .map(|item| Some(item.into_syntax().into())),
@@ -15,26 +15,31 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
     ) -> RawSyntaxNode<Self::Kind> {
         match kind {
             MD_BOGUS => RawSyntaxNode::new(kind, children.into_iter().map(Some)),
-            MD_BULLET => {
+            MD_AUTOLINK => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if matches!(element.kind(), T ! [-] | T ! [*]) {
+                    if element.kind() == MD_AUTOLINK_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
-                if let Some(element) = &current_element {
-                    if element.kind() == MD_TEXTUAL_LITERAL {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        MD_AUTOLINK.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
                 }
-                slots.next_slot();
+                slots.into_node(MD_AUTOLINK, children)
+            }
+            MD_BULLET_LIST => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdInlineItemList::can_cast(element.kind()) {
+                    if MdBulletListItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -42,18 +47,32 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_BULLET.to_bogus(),
+                        MD_BULLET_LIST.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_BULLET, children)
+                slots.into_node(MD_BULLET_LIST, children)
             }
             MD_BULLET_LIST_ITEM => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdBulletList::can_cast(element.kind()) {
+                    if matches!(element.kind(), T ! [-] | T ! [*] | T ! [+]) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == MD_TASK_MARKER_LITERAL {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdBlockList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -102,24 +121,10 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
             }
             MD_FENCED_CODE_BLOCK => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<6usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!["```"] {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if MdCodeNameList::can_cast(element.kind()) {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
-                if let Some(element) = &current_element {
-                    if MdHardLine::can_cast(element.kind()) {
+                    if element.kind() == MD_CODE_FENCE_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -133,14 +138,14 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdHardLine::can_cast(element.kind()) {
+                    if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!["```"] {
+                    if element.kind() == MD_CODE_FENCE_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -201,7 +206,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdParagraph::can_cast(element.kind()) {
+                    if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -227,7 +232,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -241,12 +246,12 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.into_node(MD_HTML_BLOCK, children)
             }
-            MD_INDENT => {
+            MD_INDENT_CODE_BLOCK => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == MD_INDENT_CHUNK_LITERAL {
+                    if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -254,18 +259,32 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INDENT.to_bogus(),
+                        MD_INDENT_CODE_BLOCK.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INDENT, children)
+                slots.into_node(MD_INDENT_CODE_BLOCK, children)
             }
-            MD_INDENT_CODE_BLOCK => {
+            MD_INLINE_CODE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdIndentedCodeLineList::can_cast(element.kind()) {
+                    if element.kind() == MD_BACKTICK_RUN_LITERAL {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdInlineItemList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == MD_BACKTICK_RUN_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -273,25 +292,32 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INDENT_CODE_BLOCK.to_bogus(),
+                        MD_INLINE_CODE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INDENT_CODE_BLOCK, children)
+                slots.into_node(MD_INLINE_CODE, children)
             }
-            MD_INDENTED_CODE_LINE => {
+            MD_INLINE_EMPHASIS => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdIndent::can_cast(element.kind()) {
+                    if matches!(element.kind(), T ! [**] | T!["__"]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if MdInlineItemList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if matches!(element.kind(), T ! [**] | T!["__"]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -299,18 +325,44 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INDENTED_CODE_LINE.to_bogus(),
+                        MD_INLINE_EMPHASIS.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INDENTED_CODE_LINE, children)
+                slots.into_node(MD_INLINE_EMPHASIS, children)
             }
-            MD_INLINE_CODE => {
+            MD_INLINE_HTML => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == MD_HTML_LITERAL {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        MD_INLINE_HTML.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(MD_INLINE_HTML, children)
+            }
+            MD_INLINE_IMAGE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<8usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['`'] {
+                    if element.kind() == T![!] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['['] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -324,7 +376,35 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['`'] {
+                    if element.kind() == T![']'] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['('] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdLinkDestination::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdLinkTitle::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![')'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -332,18 +412,18 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_CODE.to_bogus(),
+                        MD_INLINE_IMAGE.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_CODE, children)
+                slots.into_node(MD_INLINE_IMAGE, children)
             }
-            MD_INLINE_EMPHASIS => {
+            MD_INLINE_ITALIC => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if matches!(element.kind(), T ! [**] | T!["__"]) {
+                    if matches!(element.kind(), T ! [*] | T!["_"]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -357,7 +437,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if matches!(element.kind(), T ! [**] | T!["__"]) {
+                    if matches!(element.kind(), T ! [*] | T!["_"]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -365,15 +445,15 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_EMPHASIS.to_bogus(),
+                        MD_INLINE_ITALIC.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_EMPHASIS, children)
+                slots.into_node(MD_INLINE_ITALIC, children)
             }
-            MD_INLINE_IMAGE => {
+            MD_INLINE_LINK => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<6usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<7usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
                     if element.kind() == T!['['] {
@@ -383,35 +463,42 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![!] {
+                    if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdInlineImageAlt::can_cast(element.kind()) {
+                    if element.kind() == T![']'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdInlineImageSource::can_cast(element.kind()) {
+                    if element.kind() == T!['('] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![']'] {
+                    if MdLinkDestination::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdLinkTitle::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdInlineImageLink::can_cast(element.kind()) {
+                    if element.kind() == T![')'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -419,18 +506,18 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_IMAGE.to_bogus(),
+                        MD_INLINE_LINK.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_IMAGE, children)
+                slots.into_node(MD_INLINE_LINK, children)
             }
-            MD_INLINE_IMAGE_ALT => {
+            MD_INLINE_STRIKETHROUGH => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['['] {
+                    if matches!(element.kind(), T ! [~] | T ! [~~]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -444,7 +531,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![']'] {
+                    if matches!(element.kind(), T ! [~] | T ! [~~]) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -452,32 +539,110 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_IMAGE_ALT.to_bogus(),
+                        MD_INLINE_STRIKETHROUGH.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_IMAGE_ALT, children)
+                slots.into_node(MD_INLINE_STRIKETHROUGH, children)
             }
-            MD_INLINE_IMAGE_LINK => {
+            MD_LINK_DESTINATION => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['('] {
+                    if element.kind() == MD_TEXTUAL_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        MD_LINK_DESTINATION.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(MD_LINK_DESTINATION, children)
+            }
+            MD_LINK_REFERENCE_DEFINITION => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<6usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdInlineItemList::can_cast(element.kind()) {
+                    if element.kind() == T!['['] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![')'] {
+                    if MdTextual::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![']'] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [:] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdLinkDestination::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdLinkTitle::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        MD_LINK_REFERENCE_DEFINITION.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(MD_LINK_REFERENCE_DEFINITION, children)
+            }
+            MD_LINK_TITLE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == MD_TEXTUAL_LITERAL {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        MD_LINK_TITLE.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(MD_LINK_TITLE, children)
+            }
+            MD_ORDERED_LIST => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if MdOrderedListItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -485,32 +650,32 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_IMAGE_LINK.to_bogus(),
+                        MD_ORDERED_LIST.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_IMAGE_LINK, children)
+                slots.into_node(MD_ORDERED_LIST, children)
             }
-            MD_INLINE_IMAGE_SOURCE => {
+            MD_ORDERED_LIST_ITEM => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['('] {
+                    if element.kind() == MD_ORDERED_LIST_MARKER_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdInlineItemList::can_cast(element.kind()) {
+                    if element.kind() == MD_TASK_MARKER_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![')'] {
+                    if MdBlockList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -518,49 +683,65 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_IMAGE_SOURCE.to_bogus(),
+                        MD_ORDERED_LIST_ITEM.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_IMAGE_SOURCE, children)
+                slots.into_node(MD_ORDERED_LIST_ITEM, children)
             }
-            MD_INLINE_ITALIC => {
+            MD_PARAGRAPH => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if matches!(element.kind(), T ! [*] | T!["_"]) {
+                    if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        MD_PARAGRAPH.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(MD_PARAGRAPH, children)
+            }
+            MD_QUOTE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdInlineItemList::can_cast(element.kind()) {
+                    if element.kind() == T ! [>] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if matches!(element.kind(), T ! [*] | T!["_"]) {
+                    if MdBlockList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if current_element.is_some() {
-                    return RawSyntaxNode::new(
-                        MD_INLINE_ITALIC.to_bogus(),
-                        children.into_iter().map(Some),
-                    );
+                    return RawSyntaxNode::new(MD_QUOTE.to_bogus(), children.into_iter().map(Some));
                 }
-                slots.into_node(MD_INLINE_ITALIC, children)
+                slots.into_node(MD_QUOTE, children)
             }
-            MD_INLINE_LINK => {
+            MD_REFERENCE_IMAGE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<6usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<5usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T![!] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
                 if let Some(element) = &current_element {
                     if element.kind() == T!['['] {
                         slots.mark_present();
@@ -583,7 +764,26 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T!['('] {
+                    if MdReferenceLinkLabel::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(
+                        MD_REFERENCE_IMAGE.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
+                }
+                slots.into_node(MD_REFERENCE_IMAGE, children)
+            }
+            MD_REFERENCE_LINK => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<4usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if element.kind() == T!['['] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -597,7 +797,14 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if element.kind() == T![')'] {
+                    if element.kind() == T![']'] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdReferenceLinkLabel::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -605,18 +812,18 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_INLINE_LINK.to_bogus(),
+                        MD_REFERENCE_LINK.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_INLINE_LINK, children)
+                slots.into_node(MD_REFERENCE_LINK, children)
             }
-            MD_LINK_BLOCK => {
+            MD_REFERENCE_LINK_LABEL => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if element.kind() == T!['['] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -630,7 +837,7 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdTextual::can_cast(element.kind()) {
+                    if element.kind() == T![']'] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -638,18 +845,25 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_LINK_BLOCK.to_bogus(),
+                        MD_REFERENCE_LINK_LABEL.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_LINK_BLOCK, children)
+                slots.into_node(MD_REFERENCE_LINK_LABEL, children)
             }
-            MD_ORDER_LIST_ITEM => {
+            MD_SETEXT_HEADER => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdBulletList::can_cast(element.kind()) {
+                    if MdInlineItemList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == MD_SETEXT_UNDERLINE_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -657,25 +871,55 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_ORDER_LIST_ITEM.to_bogus(),
+                        MD_SETEXT_HEADER.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_ORDER_LIST_ITEM, children)
+                slots.into_node(MD_SETEXT_HEADER, children)
             }
-            MD_PARAGRAPH => {
+            MD_TABLE => {
+                let mut elements = (&children).into_iter();
+                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut current_element = elements.next();
+                if let Some(element) = &current_element {
+                    if MdTableRow::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdTableDelimiterRow::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if MdTableRowList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if current_element.is_some() {
+                    return RawSyntaxNode::new(MD_TABLE.to_bogus(), children.into_iter().map(Some));
+                }
+                slots.into_node(MD_TABLE, children)
+            }
+            MD_TABLE_CELL => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdInlineItemList::can_cast(element.kind()) {
+                    if element.kind() == T ! [|] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if let Some(element) = &current_element {
-                    if MdHardLine::can_cast(element.kind()) {
+                    if MdInlineItemList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -683,34 +927,51 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_PARAGRAPH.to_bogus(),
+                        MD_TABLE_CELL.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_PARAGRAPH, children)
+                slots.into_node(MD_TABLE_CELL, children)
             }
-            MD_QUOTE => {
+            MD_TABLE_DELIMITER_CELL => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if AnyMdBlock::can_cast(element.kind()) {
+                    if element.kind() == T ! [|] {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == MD_TABLE_DELIMITER_LITERAL {
                         slots.mark_present();
                         current_element = elements.next();
                     }
                 }
                 slots.next_slot();
                 if current_element.is_some() {
-                    return RawSyntaxNode::new(MD_QUOTE.to_bogus(), children.into_iter().map(Some));
+                    return RawSyntaxNode::new(
+                        MD_TABLE_DELIMITER_CELL.to_bogus(),
+                        children.into_iter().map(Some),
+                    );
                 }
-                slots.into_node(MD_QUOTE, children)
+                slots.into_node(MD_TABLE_DELIMITER_CELL, children)
             }
-            MD_SETEXT_HEADER => {
+            MD_TABLE_DELIMITER_ROW => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if MdParagraph::can_cast(element.kind()) {
+                    if MdTableDelimiterCellList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [|] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -718,18 +979,25 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_SETEXT_HEADER.to_bogus(),
+                        MD_TABLE_DELIMITER_ROW.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_SETEXT_HEADER, children)
+                slots.into_node(MD_TABLE_DELIMITER_ROW, children)
             }
-            MD_SOFT_BREAK => {
+            MD_TABLE_ROW => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if element.kind() == MD_SOFT_BREAK_LITERAL {
+                    if MdTableCellList::can_cast(element.kind()) {
+                        slots.mark_present();
+                        current_element = elements.next();
+                    }
+                }
+                slots.next_slot();
+                if let Some(element) = &current_element {
+                    if element.kind() == T ! [|] {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -737,11 +1005,11 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
-                        MD_SOFT_BREAK.to_bogus(),
+                        MD_TABLE_ROW.to_bogus(),
                         children.into_iter().map(Some),
                     );
                 }
-                slots.into_node(MD_SOFT_BREAK, children)
+                slots.into_node(MD_TABLE_ROW, children)
             }
             MD_TEXTUAL => {
                 let mut elements = (&children).into_iter();
@@ -782,22 +1050,23 @@ impl SyntaxFactory for MarkdownSyntaxFactory {
                 slots.into_node(MD_THEMATIC_BREAK_BLOCK, children)
             }
             MD_BLOCK_LIST => Self::make_node_list_syntax(kind, children, AnyMdBlock::can_cast),
-            MD_BULLET_LIST => Self::make_node_list_syntax(kind, children, MdBullet::can_cast),
-            MD_CODE_NAME_LIST => Self::make_separated_list_syntax(
-                kind,
-                children,
-                MdTextual::can_cast,
-                T ! [,],
-                false,
-            ),
-            MD_HASH_LIST => Self::make_node_list_syntax(kind, children, MdHash::can_cast),
-            MD_INDENTED_CODE_LINE_LIST => {
-                Self::make_node_list_syntax(kind, children, MdIndentedCodeLine::can_cast)
+            MD_BULLET_LIST_ITEM_LIST => {
+                Self::make_node_list_syntax(kind, children, MdBulletListItem::can_cast)
             }
+            MD_HASH_LIST => Self::make_node_list_syntax(kind, children, MdHash::can_cast),
             MD_INLINE_ITEM_LIST => {
                 Self::make_node_list_syntax(kind, children, AnyMdInline::can_cast)
             }
-            MD_ORDER_LIST => Self::make_node_list_syntax(kind, children, AnyCodeBlock::can_cast),
+            MD_ORDERED_LIST_ITEM_LIST => {
+                Self::make_node_list_syntax(kind, children, MdOrderedListItem::can_cast)
+            }
+            MD_TABLE_CELL_LIST => {
+                Self::make_node_list_syntax(kind, children, MdTableCell::can_cast)
+            }
+            MD_TABLE_DELIMITER_CELL_LIST => {
+                Self::make_node_list_syntax(kind, children, MdTableDelimiterCell::can_cast)
+            }
+            MD_TABLE_ROW_LIST => Self::make_node_list_syntax(kind, children, MdTableRow::can_cast),
             _ => unreachable!("Is {:?} a token?", kind),
         }
     }
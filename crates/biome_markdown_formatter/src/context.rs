@@ -0,0 +1,276 @@
+use crate::comments::{FormatMarkdownLeadingComment, MarkdownCommentStyle, MarkdownComments};
+use biome_deserialize_macros::{Deserializable, Merge};
+use biome_formatter::printer::PrinterOptions;
+use biome_formatter::{
+    CstFormatContext, FormatContext, FormatOptions, IndentStyle, IndentWidth, LineEnding,
+    LineWidth, TransformSourceMap,
+};
+use biome_markdown_syntax::{MarkdownFileSource, MarkdownLanguage};
+use std::fmt;
+use std::rc::Rc;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct MarkdownFormatContext {
+    options: MarkdownFormatOptions,
+    /// The comments of the nodes and tokens in the document.
+    comments: Rc<MarkdownComments>,
+    source_map: Option<TransformSourceMap>,
+    /// The containers of the block being formatted, from the outermost to the innermost.
+    /// Every line of the block starts with their markers.
+    containers: Vec<Container>,
+    /// Formats the code of the fenced code blocks, if any.
+    embedded_formatter: Option<Rc<dyn EmbeddedFormatter>>,
+}
+
+impl MarkdownFormatContext {
+    pub fn new(options: MarkdownFormatOptions, comments: MarkdownComments) -> Self {
+        Self {
+            options,
+            comments: Rc::new(comments),
+            source_map: None,
+            containers: Vec::new(),
+            embedded_formatter: None,
+        }
+    }
+
+    pub fn with_source_map(mut self, source_map: Option<TransformSourceMap>) -> Self {
+        self.source_map = source_map;
+        self
+    }
+
+    pub fn with_embedded_formatter(
+        mut self,
+        embedded_formatter: Option<Rc<dyn EmbeddedFormatter>>,
+    ) -> Self {
+        self.embedded_formatter = embedded_formatter;
+        self
+    }
+
+    pub(crate) fn embedded_formatter(&self) -> Option<&dyn EmbeddedFormatter> {
+        self.embedded_formatter.as_deref()
+    }
+
+    pub(crate) fn push_container(&mut self, container: Container) {
+        self.containers.push(container);
+    }
+
+    pub(crate) fn pop_container(&mut self) {
+        self.containers.pop();
+    }
+
+    pub(crate) fn containers(&self) -> &[Container] {
+        &self.containers
+    }
+}
+
+impl FormatContext for MarkdownFormatContext {
+    type Options = MarkdownFormatOptions;
+
+    fn options(&self) -> &Self::Options {
+        &self.options
+    }
+
+    fn source_map(&self) -> Option<&TransformSourceMap> {
+        None
+    }
+}
+
+impl CstFormatContext for MarkdownFormatContext {
+    type Language = MarkdownLanguage;
+    type Style = MarkdownCommentStyle;
+    type CommentRule = FormatMarkdownLeadingComment;
+
+    fn comments(&self) -> &MarkdownComments {
+        &self.comments
+    }
+}
+
+/// Formats the code of the fenced code blocks, which is written in other languages.
+///
+/// The Markdown formatter doesn't know these languages, so the code is formatted by the caller,
+/// with the options of its language.
+pub trait EmbeddedFormatter: fmt::Debug {
+    /// Formats `code`, whose language is named by the info string `info`, so that its lines fit
+    /// in the line width once they are prefixed by `prefix_width` columns of container markers.
+    ///
+    /// Returns `None` to keep the code as it is, for instance when its language isn't supported
+    /// or when it has syntax errors.
+    fn format(&self, info: &str, code: &str, prefix_width: usize) -> Option<String>;
+}
+
+/// A block that contains other blocks, whose marker starts every line of its content
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum Container {
+    /// A block quote, whose lines start with `> `
+    Quote,
+    /// A list item, whose lines are indented by the width of its marker
+    ListItem { width: usize },
+}
+
+impl Container {
+    /// Returns the width of the marker of the container
+    pub(crate) const fn width(&self) -> usize {
+        match self {
+            Self::Quote => 2,
+            Self::ListItem { width } => *width,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MarkdownFormatOptions {
+    indent_style: IndentStyle,
+    indent_width: IndentWidth,
+    line_ending: LineEnding,
+    line_width: LineWidth,
+    prose_wrap: ProseWrap,
+    _file_source: MarkdownFileSource,
+}
+
+impl MarkdownFormatOptions {
+    pub fn new(file_source: MarkdownFileSource) -> Self {
+        Self {
+            _file_source: file_source,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_indent_style(mut self, indent_style: IndentStyle) -> Self {
+        self.indent_style = indent_style;
+        self
+    }
+
+    pub fn with_indent_width(mut self, indent_width: IndentWidth) -> Self {
+        self.indent_width = indent_width;
+        self
+    }
+
+    pub fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    pub fn with_line_width(mut self, line_width: LineWidth) -> Self {
+        self.line_width = line_width;
+        self
+    }
+
+    pub fn with_prose_wrap(mut self, prose_wrap: ProseWrap) -> Self {
+        self.prose_wrap = prose_wrap;
+        self
+    }
+
+    pub fn set_indent_style(&mut self, indent_style: IndentStyle) {
+        self.indent_style = indent_style;
+    }
+
+    pub fn set_indent_width(&mut self, indent_width: IndentWidth) {
+        self.indent_width = indent_width;
+    }
+
+    pub fn set_line_ending(&mut self, line_ending: LineEnding) {
+        self.line_ending = line_ending;
+    }
+
+    pub fn set_line_width(&mut self, line_width: LineWidth) {
+        self.line_width = line_width;
+    }
+
+    pub fn set_prose_wrap(&mut self, prose_wrap: ProseWrap) {
+        self.prose_wrap = prose_wrap;
+    }
+
+    pub fn prose_wrap(&self) -> ProseWrap {
+        self.prose_wrap
+    }
+}
+
+impl FormatOptions for MarkdownFormatOptions {
+    fn indent_style(&self) -> IndentStyle {
+        self.indent_style
+    }
+
+    fn indent_width(&self) -> IndentWidth {
+        self.indent_width
+    }
+
+    fn line_width(&self) -> LineWidth {
+        self.line_width
+    }
+
+    fn line_ending(&self) -> LineEnding {
+        self.line_ending
+    }
+
+    fn as_print_options(&self) -> PrinterOptions {
+        PrinterOptions::from(self)
+    }
+}
+
+impl fmt::Display for MarkdownFormatOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Indent style: {}", self.indent_style)?;
+        writeln!(f, "Indent width: {}", self.indent_width.value())?;
+        writeln!(f, "Line ending: {}", self.line_ending)?;
+        writeln!(f, "Line width: {}", self.line_width.value())?;
+        writeln!(f, "Prose wrap: {}", self.prose_wrap)
+    }
+}
+
+/// Controls how the text of paragraphs is wrapped
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserializable, Merge)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ProseWrap {
+    /// The text is wrapped when it exceeds the line width
+    Always,
+    /// Every paragraph is printed on a single line
+    Never,
+    /// The line breaks of the text are kept as they are
+    #[default]
+    Preserve,
+}
+
+impl fmt::Display for ProseWrap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Always => std::write!(f, "always"),
+            Self::Never => std::write!(f, "never"),
+            Self::Preserve => std::write!(f, "preserve"),
+        }
+    }
+}
+
+impl ProseWrap {
+    pub const fn is_always(&self) -> bool {
+        matches!(self, Self::Always)
+    }
+
+    pub const fn is_never(&self) -> bool {
+        matches!(self, Self::Never)
+    }
+
+    pub const fn is_preserve(&self) -> bool {
+        matches!(self, Self::Preserve)
+    }
+}
+
+impl FromStr for ProseWrap {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "always" => Ok(Self::Always),
+            "never" => Ok(Self::Never),
+            "preserve" => Ok(Self::Preserve),
+            _ => Err(
+                "Value not supported for prose-wrap. Supported values are 'always', 'never' and 'preserve'.",
+            ),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdAutolink, MdAutolinkFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdAutolink;
+impl FormatNodeRule<MdAutolink> for FormatMdAutolink {
+    fn fmt_fields(&self, node: &MdAutolink, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdAutolinkFields { value_token } = node.as_fields();
+
+        write!(f, [value_token.format()])
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdBulletList, MdBulletListFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdBulletList;
+impl FormatNodeRule<MdBulletList> for FormatMdBulletList {
+    fn fmt_fields(&self, node: &MdBulletList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdBulletListFields { items } = node.as_fields();
+
+        write!(f, [items.format()])
+    }
+}
@@ -0,0 +1,45 @@
+use crate::prelude::*;
+use crate::utils::format_replaced_token;
+use crate::utils::list::format_list_item_content;
+use biome_formatter::{FormatRuleWithOptions, write};
+use biome_markdown_syntax::{MdBulletListItem, MdBulletListItemFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdBulletListItem {
+    /// The bullet shared by all the items of the list
+    bullet: &'static str,
+}
+
+impl FormatRuleWithOptions<MdBulletListItem> for FormatMdBulletListItem {
+    type Options = &'static str;
+
+    fn with_options(mut self, options: Self::Options) -> Self {
+        self.bullet = options;
+        self
+    }
+}
+
+impl FormatNodeRule<MdBulletListItem> for FormatMdBulletListItem {
+    fn fmt_fields(&self, node: &MdBulletListItem, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdBulletListItemFields {
+            bullet,
+            task_token,
+            content,
+        } = node.as_fields();
+
+        let bullet = bullet?;
+        let marker = if self.bullet.is_empty() {
+            "-"
+        } else {
+            self.bullet
+        };
+
+        write!(
+            f,
+            [
+                format_replaced_token(&bullet, &text(marker)),
+                format_list_item_content(task_token.as_ref(), &content, marker.len())
+            ]
+        )
+    }
+}
@@ -0,0 +1,26 @@
+use crate::prelude::*;
+use crate::utils::format_removed_token;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdDocument, MdDocumentFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdDocument;
+impl FormatNodeRule<MdDocument> for FormatMdDocument {
+    fn fmt_fields(&self, node: &MdDocument, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdDocumentFields {
+            bom_token,
+            value,
+            eof_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                bom_token.format(),
+                value.format(),
+                format_removed_token(&eof_token?),
+                hard_line_break()
+            ]
+        )
+    }
+}
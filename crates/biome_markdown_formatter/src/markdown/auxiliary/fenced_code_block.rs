@@ -0,0 +1,198 @@
+use crate::context::Container;
+use crate::prelude::*;
+use crate::utils::{format_line_break, format_replaced_token, mark_node_formatted};
+use biome_formatter::write;
+use biome_markdown_syntax::{
+    MarkdownSyntaxToken, MdFencedCodeBlock, MdFencedCodeBlockFields, MdInlineItemList,
+};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdFencedCodeBlock;
+impl FormatNodeRule<MdFencedCodeBlock> for FormatMdFencedCodeBlock {
+    fn fmt_fields(&self, node: &MdFencedCodeBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdFencedCodeBlockFields {
+            l_fence_token,
+            info,
+            content,
+            r_fence_token,
+        } = node.as_fields();
+
+        let l_fence_token = l_fence_token?;
+        let info_text = info
+            .as_ref()
+            .and_then(|info| info.value_token().ok())
+            .map(|token| token.text_trimmed().to_string())
+            .unwrap_or_default();
+
+        let lines: Vec<_> = content
+            .iter()
+            .filter_map(|line| line.syntax().first_token())
+            .collect();
+
+        // The info string of a backtick fence can't contain backticks
+        let fence_char = if info_text.contains('`') { '~' } else { '`' };
+        // The fence must be longer than any fence-like sequence of the code
+        let longest_run = lines
+            .iter()
+            .map(|line| {
+                line.text_trimmed()
+                    .trim_start()
+                    .chars()
+                    .take_while(|char| *char == fence_char)
+                    .count()
+            })
+            .max()
+            .unwrap_or(0);
+        let fence = fence_char.to_string().repeat(3.max(longest_run + 1));
+        let fence_start = l_fence_token.text_trimmed_range().start();
+
+        write!(
+            f,
+            [
+                format_replaced_token(&l_fence_token, &dynamic_text(&fence, fence_start)),
+                info.format()
+            ]
+        )?;
+
+        let containers = f.context().containers().to_vec();
+        let fence_trivia = l_fence_token.leading_trivia();
+        let fence_indent = fence_trivia.text().rsplit('\n').next().map_or(0, |prefix| {
+            indent_width(strip_container_markers(prefix, &containers))
+        });
+        let code = code_text(&content, r_fence_token.as_ref(), &containers, fence_indent);
+        let formatted = f.context().embedded_formatter().and_then(|formatter| {
+            let prefix_width = containers.iter().map(|container| container.width()).sum();
+            formatter.format(&info_text, &code, prefix_width)
+        });
+
+        mark_node_formatted(content.syntax(), f);
+
+        let mut empty_lines = 0;
+        for line in formatted.as_deref().unwrap_or(&code).lines() {
+            // The lines with only whitespace are kept, they can be part of a string
+            if line.is_empty() {
+                empty_lines += 1;
+                continue;
+            }
+            write!(
+                f,
+                [
+                    format_line_break(empty_lines),
+                    dynamic_text(line, fence_start)
+                ]
+            )?;
+            empty_lines = 0;
+        }
+
+        match r_fence_token {
+            Some(r_fence_token) => {
+                // The formatted code doesn't end with empty lines
+                let empty_lines = if formatted.is_some() { 0 } else { empty_lines };
+                write!(
+                    f,
+                    [
+                        format_line_break(empty_lines),
+                        format_replaced_token(
+                            &r_fence_token,
+                            &dynamic_text(&fence, r_fence_token.text_trimmed_range().start())
+                        )
+                    ]
+                )
+            }
+            // A block that isn't closed is closed explicitly
+            None => write!(f, [format_line_break(0), dynamic_text(&fence, fence_start)]),
+        }
+    }
+}
+
+/// Returns the code of a fenced code block, with a line break at the end of each line.
+///
+/// The lines that are empty or that contain only whitespace are part of the trivia of the
+/// following line, or of the closing fence. They are read from there, without the markers of the
+/// containers and the indentation of the fence.
+fn code_text(
+    content: &MdInlineItemList,
+    r_fence_token: Option<&MarkdownSyntaxToken>,
+    containers: &[Container],
+    fence_indent: usize,
+) -> String {
+    let mut code = String::new();
+    let tokens = content
+        .iter()
+        .filter_map(|line| line.syntax().first_token())
+        .map(Some)
+        .chain([r_fence_token.cloned()])
+        .flatten();
+
+    for token in tokens {
+        let trivia = token.leading_trivia();
+        let mut lines = trivia.text().split('\n');
+        // The first line ends the previous line, and the last one starts the line of the token
+        lines.next();
+        lines.next_back();
+        for line in lines {
+            let line = strip_container_markers(line.trim_end_matches('\r'), containers);
+            code.push_str(strip_indent(line, fence_indent));
+            code.push('\n');
+        }
+
+        if r_fence_token.is_none_or(|r_fence_token| *r_fence_token != token) {
+            code.push_str(token.text_trimmed());
+            code.push('\n');
+        }
+    }
+
+    code
+}
+
+/// Removes the markers of `containers` from the start of `line`.
+fn strip_container_markers<'a>(mut line: &'a str, containers: &[Container]) -> &'a str {
+    for container in containers {
+        line = match container {
+            Container::Quote => {
+                let marker = strip_indent(line, 3);
+                match marker.strip_prefix('>') {
+                    Some(rest) => rest.strip_prefix(' ').unwrap_or(rest),
+                    None => line,
+                }
+            }
+            Container::ListItem { width } => strip_indent(line, *width),
+        };
+    }
+
+    line
+}
+
+/// Removes up to `width` columns of indentation from the start of `line`.
+fn strip_indent(line: &str, width: usize) -> &str {
+    let mut start = 0;
+    for (index, column) in indent_columns(line) {
+        if column > width {
+            break;
+        }
+        start = index + 1;
+    }
+
+    &line[start..]
+}
+
+/// Returns the width of the indentation at the start of `line`.
+fn indent_width(line: &str) -> usize {
+    indent_columns(line).last().map_or(0, |(_, column)| column)
+}
+
+/// Returns the offset of each space or tab of the indentation of `line`, with the column that
+/// follows it.
+fn indent_columns(line: &str) -> impl Iterator<Item = (usize, usize)> + '_ {
+    line.bytes()
+        .enumerate()
+        .map_while(|(index, byte)| matches!(byte, b' ' | b'\t').then_some((index, byte)))
+        .scan(0, |column, (index, byte)| {
+            *column = if byte == b'\t' {
+                (*column / 4 + 1) * 4
+            } else {
+                *column + 1
+            };
+            Some((index, *column))
+        })
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdHardLine, MdHardLineFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdHardLine;
+impl FormatNodeRule<MdHardLine> for FormatMdHardLine {
+    fn fmt_fields(&self, node: &MdHardLine, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdHardLineFields { value_token } = node.as_fields();
+
+        write!(f, [value_token.format()])
+    }
+}
@@ -0,0 +1,34 @@
+use crate::prelude::*;
+use crate::utils::inline::InlineLayout;
+use crate::utils::mark_node_formatted;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdHeader, MdHeaderFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdHeader;
+impl FormatNodeRule<MdHeader> for FormatMdHeader {
+    fn fmt_fields(&self, node: &MdHeader, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdHeaderFields {
+            before,
+            content,
+            after,
+        } = node.as_fields();
+
+        write!(f, [before.format()])?;
+
+        if !content.is_empty() {
+            write!(
+                f,
+                [
+                    space(),
+                    content.format().with_options(InlineLayout::SingleLine)
+                ]
+            )?;
+        }
+
+        // The optional closing sequence is removed
+        mark_node_formatted(after.syntax(), f);
+
+        Ok(())
+    }
+}
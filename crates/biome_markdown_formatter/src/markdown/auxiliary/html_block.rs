@@ -0,0 +1,26 @@
+use crate::prelude::*;
+use crate::utils::{format_line_break, leading_line_breaks};
+use biome_formatter::write;
+use biome_markdown_syntax::{MdHtmlBlock, MdHtmlBlockFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdHtmlBlock;
+impl FormatNodeRule<MdHtmlBlock> for FormatMdHtmlBlock {
+    fn fmt_fields(&self, node: &MdHtmlBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdHtmlBlockFields { content } = node.as_fields();
+
+        // The lines of HTML blocks are kept as they are
+        for (index, line) in content.iter().enumerate() {
+            if index > 0 {
+                let empty_lines = line
+                    .syntax()
+                    .first_token()
+                    .map_or(0, |token| leading_line_breaks(&token).saturating_sub(1));
+                write!(f, [format_line_break(empty_lines)])?;
+            }
+            write!(f, [line.format()])?;
+        }
+
+        Ok(())
+    }
+}
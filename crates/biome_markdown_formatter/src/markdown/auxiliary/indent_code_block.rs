@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use crate::utils::{format_line_break, leading_line_breaks};
+use biome_formatter::write;
+use biome_markdown_syntax::{MdIndentCodeBlock, MdIndentCodeBlockFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdIndentCodeBlock;
+impl FormatNodeRule<MdIndentCodeBlock> for FormatMdIndentCodeBlock {
+    fn fmt_fields(&self, node: &MdIndentCodeBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdIndentCodeBlockFields { content } = node.as_fields();
+
+        for (index, line) in content.iter().enumerate() {
+            if index > 0 {
+                let empty_lines = line
+                    .syntax()
+                    .first_token()
+                    .map_or(0, |token| leading_line_breaks(&token).saturating_sub(1));
+                write!(f, [format_line_break(empty_lines)])?;
+            }
+            write!(f, [text("    "), line.format()])?;
+        }
+
+        Ok(())
+    }
+}
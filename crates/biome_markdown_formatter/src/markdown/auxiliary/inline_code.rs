@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use crate::utils::inline::{InlineLayout, format_inline_content};
+use biome_formatter::write;
+use biome_markdown_syntax::MdInlineCode;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineCode;
+impl FormatNodeRule<MdInlineCode> for FormatMdInlineCode {
+    fn fmt_fields(&self, node: &MdInlineCode, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        write!(
+            f,
+            [format_inline_content(
+                node.syntax(),
+                InlineLayout::SingleLine
+            )]
+        )
+    }
+}
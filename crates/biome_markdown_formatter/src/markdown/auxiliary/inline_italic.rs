@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use crate::utils::inline::{InlineLayout, format_inline_content};
+use biome_formatter::write;
+use biome_markdown_syntax::MdInlineItalic;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineItalic;
+impl FormatNodeRule<MdInlineItalic> for FormatMdInlineItalic {
+    fn fmt_fields(&self, node: &MdInlineItalic, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        write!(
+            f,
+            [format_inline_content(
+                node.syntax(),
+                InlineLayout::SingleLine
+            )]
+        )
+    }
+}
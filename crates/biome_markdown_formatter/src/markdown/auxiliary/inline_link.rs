@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use crate::utils::inline::{InlineLayout, format_inline_content};
+use biome_formatter::write;
+use biome_markdown_syntax::MdInlineLink;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineLink;
+impl FormatNodeRule<MdInlineLink> for FormatMdInlineLink {
+    fn fmt_fields(&self, node: &MdInlineLink, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        write!(
+            f,
+            [format_inline_content(
+                node.syntax(),
+                InlineLayout::SingleLine
+            )]
+        )
+    }
+}
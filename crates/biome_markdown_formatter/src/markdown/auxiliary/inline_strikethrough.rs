@@ -0,0 +1,22 @@
+use crate::prelude::*;
+use crate::utils::inline::{InlineLayout, format_inline_content};
+use biome_formatter::write;
+use biome_markdown_syntax::MdInlineStrikethrough;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineStrikethrough;
+impl FormatNodeRule<MdInlineStrikethrough> for FormatMdInlineStrikethrough {
+    fn fmt_fields(
+        &self,
+        node: &MdInlineStrikethrough,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        write!(
+            f,
+            [format_inline_content(
+                node.syntax(),
+                InlineLayout::SingleLine
+            )]
+        )
+    }
+}
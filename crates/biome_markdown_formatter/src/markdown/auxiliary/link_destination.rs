@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdLinkDestination, MdLinkDestinationFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdLinkDestination;
+impl FormatNodeRule<MdLinkDestination> for FormatMdLinkDestination {
+    fn fmt_fields(&self, node: &MdLinkDestination, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdLinkDestinationFields { value_token } = node.as_fields();
+
+        write!(f, [value_token.format()])
+    }
+}
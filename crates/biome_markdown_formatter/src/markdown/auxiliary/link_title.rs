@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdLinkTitle, MdLinkTitleFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdLinkTitle;
+impl FormatNodeRule<MdLinkTitle> for FormatMdLinkTitle {
+    fn fmt_fields(&self, node: &MdLinkTitle, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdLinkTitleFields { value_token } = node.as_fields();
+
+        write!(f, [value_token.format()])
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdOrderedList, MdOrderedListFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdOrderedList;
+impl FormatNodeRule<MdOrderedList> for FormatMdOrderedList {
+    fn fmt_fields(&self, node: &MdOrderedList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdOrderedListFields { items } = node.as_fields();
+
+        write!(f, [items.format()])
+    }
+}
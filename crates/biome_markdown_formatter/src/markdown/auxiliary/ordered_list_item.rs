@@ -0,0 +1,48 @@
+use crate::prelude::*;
+use crate::utils::format_replaced_token;
+use crate::utils::list::format_list_item_content;
+use biome_formatter::{FormatRuleWithOptions, write};
+use biome_markdown_syntax::{MdOrderedListItem, MdOrderedListItemFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdOrderedListItem {
+    /// The number and delimiter of the item, computed from its position in the list
+    marker: String,
+}
+
+impl FormatRuleWithOptions<MdOrderedListItem> for FormatMdOrderedListItem {
+    type Options = String;
+
+    fn with_options(mut self, options: Self::Options) -> Self {
+        self.marker = options;
+        self
+    }
+}
+
+impl FormatNodeRule<MdOrderedListItem> for FormatMdOrderedListItem {
+    fn fmt_fields(&self, node: &MdOrderedListItem, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdOrderedListItemFields {
+            marker_token,
+            task_token,
+            content,
+        } = node.as_fields();
+
+        let marker_token = marker_token?;
+        let marker = if self.marker.is_empty() {
+            marker_token.text_trimmed()
+        } else {
+            self.marker.as_str()
+        };
+
+        write!(
+            f,
+            [
+                format_replaced_token(
+                    &marker_token,
+                    &dynamic_text(marker, marker_token.text_trimmed_range().start())
+                ),
+                format_list_item_content(task_token.as_ref(), &content, marker.len())
+            ]
+        )
+    }
+}
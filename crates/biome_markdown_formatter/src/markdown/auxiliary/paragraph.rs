@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdParagraph, MdParagraphFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdParagraph;
+impl FormatNodeRule<MdParagraph> for FormatMdParagraph {
+    fn fmt_fields(&self, node: &MdParagraph, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdParagraphFields { content } = node.as_fields();
+
+        write!(f, [content.format()])
+    }
+}
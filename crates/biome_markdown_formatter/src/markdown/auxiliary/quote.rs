@@ -0,0 +1,29 @@
+use crate::context::Container;
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdQuote, MdQuoteFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdQuote;
+impl FormatNodeRule<MdQuote> for FormatMdQuote {
+    fn fmt_fields(&self, node: &MdQuote, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdQuoteFields {
+            marker_token,
+            content,
+        } = node.as_fields();
+
+        write!(f, [marker_token.format()])?;
+
+        if content.is_empty() {
+            return Ok(());
+        }
+
+        write!(f, [space()])?;
+
+        f.context_mut().push_container(Container::Quote);
+        let result = write!(f, [content.format()]);
+        f.context_mut().pop_container();
+
+        result
+    }
+}
@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use crate::utils::inline::{InlineLayout, format_inline_content};
+use biome_formatter::write;
+use biome_markdown_syntax::MdReferenceImage;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdReferenceImage;
+impl FormatNodeRule<MdReferenceImage> for FormatMdReferenceImage {
+    fn fmt_fields(&self, node: &MdReferenceImage, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        write!(
+            f,
+            [format_inline_content(
+                node.syntax(),
+                InlineLayout::SingleLine
+            )]
+        )
+    }
+}
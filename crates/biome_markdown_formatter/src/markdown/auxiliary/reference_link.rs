@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use crate::utils::inline::{InlineLayout, format_inline_content};
+use biome_formatter::write;
+use biome_markdown_syntax::MdReferenceLink;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdReferenceLink;
+impl FormatNodeRule<MdReferenceLink> for FormatMdReferenceLink {
+    fn fmt_fields(&self, node: &MdReferenceLink, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        write!(
+            f,
+            [format_inline_content(
+                node.syntax(),
+                InlineLayout::SingleLine
+            )]
+        )
+    }
+}
@@ -0,0 +1,33 @@
+use crate::prelude::*;
+use crate::utils::format_replaced_token;
+use crate::utils::inline::InlineLayout;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdSetextHeader, MdSetextHeaderFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdSetextHeader;
+impl FormatNodeRule<MdSetextHeader> for FormatMdSetextHeader {
+    fn fmt_fields(&self, node: &MdSetextHeader, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdSetextHeaderFields {
+            content,
+            underline_token,
+        } = node.as_fields();
+
+        // Setext headings are printed as ATX headings, which can't span multiple lines
+        let underline_token = underline_token?;
+        let hashes = if underline_token.text_trimmed().starts_with('=') {
+            "#"
+        } else {
+            "##"
+        };
+
+        write!(
+            f,
+            [
+                format_replaced_token(&underline_token, &text(hashes)),
+                space(),
+                content.format().with_options(InlineLayout::SingleLine)
+            ]
+        )
+    }
+}
@@ -0,0 +1,41 @@
+use crate::prelude::*;
+use crate::utils::format_line_break;
+use crate::utils::table::table_columns;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdTable, MdTableFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdTable;
+impl FormatNodeRule<MdTable> for FormatMdTable {
+    fn fmt_fields(&self, node: &MdTable, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdTableFields {
+            header,
+            delimiter,
+            rows,
+        } = node.as_fields();
+
+        // The cells of each column are padded to the width of the widest one
+        let columns = table_columns(node);
+
+        write!(
+            f,
+            [
+                header?.format().with_options(columns.clone()),
+                format_line_break(0),
+                delimiter?.format().with_options(columns.clone())
+            ]
+        )?;
+
+        for row in rows {
+            write!(
+                f,
+                [
+                    format_line_break(0),
+                    row.format().with_options(columns.clone())
+                ]
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use crate::prelude::*;
+use crate::utils::format_spaces;
+use crate::utils::inline::{InlineLayout, inline_text};
+use crate::utils::table::TableColumn;
+use biome_formatter::{FormatRuleWithOptions, write};
+use biome_markdown_syntax::{MdTableCell, MdTableCellFields};
+use unicode_width::UnicodeWidthStr;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdTableCell {
+    column: TableColumn,
+}
+
+impl FormatRuleWithOptions<MdTableCell> for FormatMdTableCell {
+    type Options = TableColumn;
+
+    fn with_options(mut self, options: Self::Options) -> Self {
+        self.column = options;
+        self
+    }
+}
+
+impl FormatNodeRule<MdTableCell> for FormatMdTableCell {
+    fn fmt_fields(&self, node: &MdTableCell, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdTableCellFields {
+            l_pipe_token,
+            content,
+        } = node.as_fields();
+
+        let width = inline_text(content.syntax()).width();
+        let (before, after) = self.column.padding(width);
+
+        match l_pipe_token {
+            Some(l_pipe_token) => write!(f, [l_pipe_token.format()])?,
+            None => write!(f, [text("|")])?,
+        }
+
+        write!(
+            f,
+            [
+                space(),
+                format_spaces(before),
+                content.format().with_options(InlineLayout::SingleLine),
+                format_spaces(after),
+                space()
+            ]
+        )
+    }
+}
@@ -0,0 +1,52 @@
+use crate::prelude::*;
+use crate::utils::format_replaced_token;
+use crate::utils::table::TableColumn;
+use biome_formatter::{FormatRuleWithOptions, write};
+use biome_markdown_syntax::{MdTableDelimiterCell, MdTableDelimiterCellFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdTableDelimiterCell {
+    column: TableColumn,
+}
+
+impl FormatRuleWithOptions<MdTableDelimiterCell> for FormatMdTableDelimiterCell {
+    type Options = TableColumn;
+
+    fn with_options(mut self, options: Self::Options) -> Self {
+        self.column = options;
+        self
+    }
+}
+
+impl FormatNodeRule<MdTableDelimiterCell> for FormatMdTableDelimiterCell {
+    fn fmt_fields(
+        &self,
+        node: &MdTableDelimiterCell,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        let MdTableDelimiterCellFields {
+            l_pipe_token,
+            value_token,
+        } = node.as_fields();
+
+        let value_token = value_token?;
+        let delimiter = self.column.delimiter();
+
+        match l_pipe_token {
+            Some(l_pipe_token) => write!(f, [l_pipe_token.format()])?,
+            None => write!(f, [text("|")])?,
+        }
+
+        write!(
+            f,
+            [
+                space(),
+                format_replaced_token(
+                    &value_token,
+                    &dynamic_text(&delimiter, value_token.text_trimmed_range().start())
+                ),
+                space()
+            ]
+        )
+    }
+}
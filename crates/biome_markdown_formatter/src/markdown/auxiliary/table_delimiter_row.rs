@@ -0,0 +1,56 @@
+use crate::prelude::*;
+use crate::utils::table::TableColumn;
+use biome_formatter::{FormatRuleWithOptions, write};
+use biome_markdown_syntax::{MdTableDelimiterRow, MdTableDelimiterRowFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdTableDelimiterRow {
+    columns: Vec<TableColumn>,
+}
+
+impl FormatRuleWithOptions<MdTableDelimiterRow> for FormatMdTableDelimiterRow {
+    type Options = Vec<TableColumn>;
+
+    fn with_options(mut self, options: Self::Options) -> Self {
+        self.columns = options;
+        self
+    }
+}
+
+impl FormatNodeRule<MdTableDelimiterRow> for FormatMdTableDelimiterRow {
+    fn fmt_fields(
+        &self,
+        node: &MdTableDelimiterRow,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        let MdTableDelimiterRowFields {
+            cells,
+            r_pipe_token,
+        } = node.as_fields();
+
+        for (index, cell) in cells.iter().enumerate() {
+            let column = self.columns.get(index).copied().unwrap_or_default();
+            write!(f, [cell.format().with_options(column)])?;
+        }
+
+        for column in self.columns.iter().skip(cells.len()) {
+            write!(
+                f,
+                [
+                    text("|"),
+                    space(),
+                    dynamic_text(
+                        &column.delimiter(),
+                        node.syntax().text_trimmed_range().end()
+                    ),
+                    space()
+                ]
+            )?;
+        }
+
+        match r_pipe_token {
+            Some(r_pipe_token) => write!(f, [r_pipe_token.format()]),
+            None => write!(f, [text("|")]),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::prelude::*;
+use crate::utils::format_spaces;
+use crate::utils::table::TableColumn;
+use biome_formatter::{FormatRuleWithOptions, write};
+use biome_markdown_syntax::{MdTableRow, MdTableRowFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdTableRow {
+    columns: Vec<TableColumn>,
+}
+
+impl FormatRuleWithOptions<MdTableRow> for FormatMdTableRow {
+    type Options = Vec<TableColumn>;
+
+    fn with_options(mut self, options: Self::Options) -> Self {
+        self.columns = options;
+        self
+    }
+}
+
+impl FormatNodeRule<MdTableRow> for FormatMdTableRow {
+    fn fmt_fields(&self, node: &MdTableRow, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdTableRowFields {
+            cells,
+            r_pipe_token,
+        } = node.as_fields();
+
+        for (index, cell) in cells.iter().enumerate() {
+            let column = self.columns.get(index).copied().unwrap_or_default();
+            write!(f, [cell.format().with_options(column)])?;
+        }
+
+        // Rows with fewer cells than the table are completed with empty cells
+        for column in self.columns.iter().skip(cells.len()) {
+            write!(f, [text("|"), format_spaces(column.width + 2)])?;
+        }
+
+        match r_pipe_token {
+            Some(r_pipe_token) => write!(f, [r_pipe_token.format()]),
+            None => write!(f, [text("|")]),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MdTextual, MdTextualFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdTextual;
+impl FormatNodeRule<MdTextual> for FormatMdTextual {
+    fn fmt_fields(&self, node: &MdTextual, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdTextualFields { value_token } = node.as_fields();
+
+        write!(f, [value_token.format()])
+    }
+}
@@ -0,0 +1,33 @@
+use crate::prelude::*;
+use crate::utils::format_replaced_token;
+use crate::utils::list::is_in_tight_list;
+use biome_formatter::write;
+use biome_markdown_syntax::{
+    MarkdownSyntaxKind, MdBlockList, MdThematicBreakBlock, MdThematicBreakBlockFields,
+};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdThematicBreakBlock;
+impl FormatNodeRule<MdThematicBreakBlock> for FormatMdThematicBreakBlock {
+    fn fmt_fields(
+        &self,
+        node: &MdThematicBreakBlock,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        let MdThematicBreakBlockFields { value_token } = node.as_fields();
+
+        // `---` right after a paragraph would turn it into a setext heading
+        let after_paragraph = node
+            .syntax()
+            .prev_sibling()
+            .is_some_and(|sibling| sibling.kind() == MarkdownSyntaxKind::MD_PARAGRAPH)
+            && node
+                .syntax()
+                .parent()
+                .and_then(MdBlockList::cast)
+                .is_some_and(|blocks| is_in_tight_list(&blocks));
+        let value = if after_paragraph { "***" } else { "---" };
+
+        write!(f, [format_replaced_token(&value_token?, &text(value))])
+    }
+}
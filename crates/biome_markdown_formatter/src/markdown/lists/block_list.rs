@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use crate::utils::format_line_break;
+use crate::utils::list::is_in_tight_list;
+use biome_formatter::write;
+use biome_markdown_syntax::MdBlockList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdBlockList;
+impl FormatRule<MdBlockList> for FormatMdBlockList {
+    type Context = MarkdownFormatContext;
+    fn fmt(&self, node: &MdBlockList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        // The blocks of the items of a tight list are only separated by a line break
+        let empty_lines = if is_in_tight_list(node) { 0 } else { 1 };
+
+        for (index, block) in node.iter().enumerate() {
+            if index > 0 {
+                write!(f, [format_line_break(empty_lines)])?;
+            }
+            write!(f, [block.format()])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use crate::prelude::*;
+use crate::utils::format_line_break;
+use crate::utils::list::{adjacent_lists_before, is_tight_list};
+use biome_formatter::write;
+use biome_markdown_syntax::MdBulletListItemList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdBulletListItemList;
+impl FormatRule<MdBulletListItemList> for FormatMdBulletListItemList {
+    type Context = MarkdownFormatContext;
+    fn fmt(&self, node: &MdBulletListItemList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let Some(list) = node.syntax().parent() else {
+            return Ok(());
+        };
+
+        // Adjacent lists alternate between `-` and `*`, so that they aren't merged
+        let bullet = if adjacent_lists_before(&list) % 2 == 0 {
+            "-"
+        } else {
+            "*"
+        };
+        let empty_lines = if is_tight_list(&list) { 0 } else { 1 };
+
+        for (index, item) in node.iter().enumerate() {
+            if index > 0 {
+                write!(f, [format_line_break(empty_lines)])?;
+            }
+            write!(f, [item.format().with_options(bullet)])?;
+        }
+
+        Ok(())
+    }
+}
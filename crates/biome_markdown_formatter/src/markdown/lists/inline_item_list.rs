@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use crate::utils::inline::{InlineLayout, format_inline_content};
+use biome_formatter::{FormatRuleWithOptions, write};
+use biome_markdown_syntax::MdInlineItemList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdInlineItemList {
+    layout: InlineLayout,
+}
+
+impl FormatRuleWithOptions<MdInlineItemList> for FormatMdInlineItemList {
+    type Options = InlineLayout;
+
+    fn with_options(mut self, options: Self::Options) -> Self {
+        self.layout = options;
+        self
+    }
+}
+
+impl FormatRule<MdInlineItemList> for FormatMdInlineItemList {
+    type Context = MarkdownFormatContext;
+    fn fmt(&self, node: &MdInlineItemList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        write!(f, [format_inline_content(node.syntax(), self.layout)])
+    }
+}
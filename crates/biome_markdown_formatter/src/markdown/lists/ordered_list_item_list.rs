@@ -0,0 +1,53 @@
+use crate::prelude::*;
+use crate::utils::format_line_break;
+use crate::utils::list::{adjacent_lists_before, is_tight_list};
+use biome_formatter::write;
+use biome_markdown_syntax::MdOrderedListItemList;
+use biome_rowan::AstNodeList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdOrderedListItemList;
+impl FormatRule<MdOrderedListItemList> for FormatMdOrderedListItemList {
+    type Context = MarkdownFormatContext;
+    fn fmt(&self, node: &MdOrderedListItemList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let Some(list) = node.syntax().parent() else {
+            return Ok(());
+        };
+
+        // Adjacent lists alternate between `.` and `)`, so that they aren't merged
+        let delimiter = if adjacent_lists_before(&list) % 2 == 0 {
+            '.'
+        } else {
+            ')'
+        };
+        let empty_lines = if is_tight_list(&list) { 0 } else { 1 };
+
+        // The list keeps its start number. Its items are numbered incrementally, unless the
+        // first two items have the same number, in which case all of them share it.
+        let mut numbers = node.iter().map(|item| {
+            item.marker_token()
+                .ok()
+                .and_then(|marker| marker_number(marker.text_trimmed()))
+        });
+        let start = numbers.next().flatten().unwrap_or(1);
+        let increment = match numbers.next().flatten() {
+            Some(second) if second == start => 0,
+            _ => 1,
+        };
+
+        for (index, item) in node.iter().enumerate() {
+            if index > 0 {
+                write!(f, [format_line_break(empty_lines)])?;
+            }
+            let number = start + index as u64 * increment;
+            let marker = std::format!("{number}{delimiter}");
+            write!(f, [item.format().with_options(marker)])?;
+        }
+
+        Ok(())
+    }
+}
+
+fn marker_number(marker: &str) -> Option<u64> {
+    marker.trim_end_matches(['.', ')']).parse().ok()
+}
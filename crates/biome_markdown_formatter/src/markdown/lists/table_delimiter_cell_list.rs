@@ -0,0 +1,10 @@
+use crate::prelude::*;
+use biome_markdown_syntax::MdTableDelimiterCellList;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdTableDelimiterCellList;
+impl FormatRule<MdTableDelimiterCellList> for FormatMdTableDelimiterCellList {
+    type Context = MarkdownFormatContext;
+    fn fmt(&self, node: &MdTableDelimiterCellList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        f.join().entries(node.iter().formatted()).finish()
+    }
+}
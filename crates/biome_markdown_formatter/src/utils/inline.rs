@@ -0,0 +1,466 @@
+use crate::context::ProseWrap;
+use crate::prelude::*;
+use crate::utils::{
+    format_container_markers, format_line_break, format_removed_token, format_replaced_token,
+};
+use biome_formatter::{CstFormatContext, write};
+use biome_markdown_syntax::{
+    MarkdownSyntaxKind, MarkdownSyntaxNode, MarkdownSyntaxToken, MdInlineCode, MdInlineItalic,
+};
+use biome_rowan::{AstNode, Direction, NodeOrToken, SyntaxTriviaPiece, WalkEvent};
+
+/// How the words of an inline content are laid out
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub(crate) enum InlineLayout {
+    /// The content of a paragraph, which is wrapped according to the `proseWrap` option
+    #[default]
+    Paragraph,
+    /// The content of a heading or of a table cell, which is printed on a single line
+    SingleLine,
+}
+
+/// A part of an inline content that is printed without whitespace around it
+#[derive(Debug, Clone)]
+enum InlinePiece {
+    /// A token printed as it is
+    Token(MarkdownSyntaxToken),
+    /// A token printed as another text
+    Replaced(MarkdownSyntaxToken, &'static str),
+    /// A hard line break, always followed by a line break
+    HardLine(MarkdownSyntaxToken),
+    /// A code span, whose content is printed as is, except for its line breaks that are
+    /// printed as spaces
+    Code {
+        open: MarkdownSyntaxToken,
+        tokens: Vec<MarkdownSyntaxToken>,
+        content: String,
+        close: MarkdownSyntaxToken,
+    },
+}
+
+impl InlinePiece {
+    fn first_token(&self) -> &MarkdownSyntaxToken {
+        match self {
+            Self::Token(token) | Self::Replaced(token, _) | Self::HardLine(token) => token,
+            Self::Code { open, .. } => open,
+        }
+    }
+
+    fn last_token(&self) -> &MarkdownSyntaxToken {
+        match self {
+            Self::Token(token) | Self::Replaced(token, _) | Self::HardLine(token) => token,
+            Self::Code { close, .. } => close,
+        }
+    }
+
+    /// Appends the text of the piece, as it's printed on a single line
+    fn push_text(&self, text: &mut String) {
+        match self {
+            Self::Token(token) => text.push_str(token.text_trimmed()),
+            Self::Replaced(_, replacement) => text.push_str(replacement),
+            Self::HardLine(_) => {}
+            Self::Code {
+                open,
+                content,
+                close,
+                ..
+            } => {
+                text.push_str(open.text_trimmed());
+                text.push_str(content);
+                text.push_str(close.text_trimmed());
+            }
+        }
+    }
+}
+
+/// What separates a word from the previous one in the source
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum WordSeparator {
+    Space,
+    LineBreak,
+}
+
+/// A run of inline pieces without whitespace between them
+#[derive(Debug, Clone)]
+struct InlineWord {
+    separator: WordSeparator,
+    pieces: Vec<InlinePiece>,
+}
+
+impl InlineWord {
+    fn ends_with_hard_line(&self) -> bool {
+        matches!(self.pieces.last(), Some(InlinePiece::HardLine(_)))
+    }
+
+    fn text(&self) -> String {
+        let mut text = String::new();
+        for piece in &self.pieces {
+            piece.push_text(&mut text);
+        }
+        text
+    }
+
+    /// Returns `true` if the word would start a block, and change the structure of the document,
+    /// when it's moved at the start of a line.
+    fn could_start_block(&self) -> bool {
+        let text = self.text();
+        let Some(first) = text.chars().next() else {
+            return false;
+        };
+
+        match first {
+            '#' => text.len() <= 6 && text.chars().all(|char| char == '#'),
+            '>' | '<' => true,
+            '+' => text.len() == 1,
+            '-' | '*' | '_' | '=' => text.chars().all(|char| char == first),
+            '`' => text.starts_with("```"),
+            '~' => text.starts_with("~~~"),
+            '0'..='9' => {
+                let digits = text.chars().take_while(char::is_ascii_digit).count();
+                digits <= 9 && matches!(&text[digits..], "." | ")")
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Format<MarkdownFormatContext> for InlineWord {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        for piece in &self.pieces {
+            match piece {
+                InlinePiece::Token(token) => write!(f, [token.format()])?,
+                InlinePiece::Replaced(token, replacement) => {
+                    write!(f, [format_replaced_token(token, &text(replacement))])?
+                }
+                InlinePiece::HardLine(token) => {
+                    let hard_line = if token.text_trimmed().starts_with('\\') {
+                        "\\"
+                    } else {
+                        "  "
+                    };
+                    write!(f, [format_replaced_token(token, &text(hard_line))])?
+                }
+                InlinePiece::Code {
+                    open,
+                    tokens,
+                    content,
+                    close,
+                } => {
+                    write!(f, [open.format()])?;
+                    for token in tokens {
+                        write!(f, [format_removed_token(token)])?;
+                    }
+                    write!(
+                        f,
+                        [
+                            dynamic_text(content, open.text_trimmed_range().end()),
+                            close.format()
+                        ]
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats an inline content, made of the inline nodes of `node`, with the given `layout`.
+pub(crate) fn format_inline_content(
+    node: &MarkdownSyntaxNode,
+    layout: InlineLayout,
+) -> FormatInlineContent<'_> {
+    FormatInlineContent { node, layout }
+}
+
+pub(crate) struct FormatInlineContent<'a> {
+    node: &'a MarkdownSyntaxNode,
+    layout: InlineLayout,
+}
+
+impl Format<MarkdownFormatContext> for FormatInlineContent<'_> {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        // The inline nodes aren't formatted by their own rules because words span across them
+        for node in self.node.descendants() {
+            f.context().comments().mark_suppression_checked(&node);
+        }
+
+        let mut words = inline_words(self.node);
+        let prose_wrap = match self.layout {
+            InlineLayout::Paragraph => f.options().prose_wrap(),
+            InlineLayout::SingleLine => {
+                for word in &mut words {
+                    word.separator = WordSeparator::Space;
+                    if let Some(InlinePiece::HardLine(token)) = word.pieces.last() {
+                        write!(f, [format_removed_token(token)])?;
+                        word.pieces.pop();
+                    }
+                }
+                words.retain(|word| !word.pieces.is_empty());
+                ProseWrap::Never
+            }
+        };
+
+        match prose_wrap {
+            ProseWrap::Always => format_filled_words(&words, f),
+            ProseWrap::Never | ProseWrap::Preserve => {
+                for (index, word) in words.iter().enumerate() {
+                    if index > 0 {
+                        let line_break = words[index - 1].ends_with_hard_line()
+                            || (prose_wrap.is_preserve()
+                                && word.separator == WordSeparator::LineBreak);
+                        if line_break {
+                            write!(f, [format_line_break(0)])?;
+                        } else {
+                            write!(f, [space()])?;
+                        }
+                    }
+                    write!(f, [word])?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Fills the lines with as many words as possible. Words that could start a block are kept on the
+/// line of the previous word.
+fn format_filled_words(words: &[InlineWord], f: &mut MarkdownFormatter) -> FormatResult<()> {
+    let mut lines: Vec<&[InlineWord]> = Vec::new();
+    let mut start = 0;
+    for (index, word) in words.iter().enumerate().skip(1) {
+        if words[index - 1].ends_with_hard_line() || !word.could_start_block() {
+            lines.push(&words[start..index]);
+            start = index;
+        }
+    }
+    if start < words.len() {
+        lines.push(&words[start..]);
+    }
+
+    let soft_line_break = format_with(|f| {
+        write!(
+            f,
+            [
+                soft_line_break_or_space(),
+                if_group_breaks(&format_container_markers(false))
+            ]
+        )
+    });
+    let hard_line_break = format_line_break(0);
+
+    let mut fill = f.fill();
+    let mut previous: Option<&InlineWord> = None;
+    for line in lines {
+        let separator: &dyn Format<MarkdownFormatContext> =
+            if previous.is_some_and(InlineWord::ends_with_hard_line) {
+                &hard_line_break
+            } else {
+                &soft_line_break
+            };
+        fill.entry(
+            separator,
+            &format_with(|f| {
+                for (index, word) in line.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, [space()])?;
+                    }
+                    write!(f, [word])?;
+                }
+                Ok(())
+            }),
+        );
+        previous = line.last();
+    }
+
+    fill.finish()
+}
+
+/// Returns the text of an inline content as it's printed on a single line
+pub(crate) fn inline_text(node: &MarkdownSyntaxNode) -> String {
+    let mut text = String::new();
+    for word in inline_words(node) {
+        if !text.is_empty() {
+            text.push(' ');
+        }
+        text.push_str(&word.text());
+    }
+    text
+}
+
+/// Splits the inline content of `node` into words.
+fn inline_words(node: &MarkdownSyntaxNode) -> Vec<InlineWord> {
+    let mut words: Vec<InlineWord> = Vec::new();
+
+    for piece in inline_pieces(node) {
+        let separator = words
+            .last()
+            .and_then(|word| word.pieces.last())
+            .map(|previous| separator_between(previous.last_token(), piece.first_token()));
+
+        match (separator, words.last_mut()) {
+            (Some(None), Some(word)) => word.pieces.push(piece),
+            (separator, _) => words.push(InlineWord {
+                separator: separator.flatten().unwrap_or(WordSeparator::Space),
+                pieces: vec![piece],
+            }),
+        }
+    }
+
+    words
+}
+
+/// Returns the whitespace between two consecutive tokens, or `None` if they're adjacent.
+fn separator_between(
+    previous: &MarkdownSyntaxToken,
+    next: &MarkdownSyntaxToken,
+) -> Option<WordSeparator> {
+    let mut separator = None;
+    for piece in previous
+        .trailing_trivia()
+        .pieces()
+        .chain(next.leading_trivia().pieces())
+    {
+        if piece.is_newline() {
+            return Some(WordSeparator::LineBreak);
+        }
+        separator = Some(WordSeparator::Space);
+    }
+    separator
+}
+
+fn inline_pieces(node: &MarkdownSyntaxNode) -> Vec<InlinePiece> {
+    let mut pieces = Vec::new();
+    let mut preorder = node.preorder_with_tokens(Direction::Next);
+
+    while let Some(event) = preorder.next() {
+        let WalkEvent::Enter(element) = event else {
+            continue;
+        };
+
+        match element {
+            NodeOrToken::Node(node) => {
+                if let Some(code) = MdInlineCode::cast_ref(&node) {
+                    if let Some(piece) = code_piece(&code) {
+                        pieces.push(piece);
+                        preorder.skip_subtree();
+                    }
+                }
+            }
+            NodeOrToken::Token(token) => {
+                let parent = token.parent();
+                let piece = match parent.as_ref().map(|parent| parent.kind()) {
+                    Some(MarkdownSyntaxKind::MD_INLINE_ITALIC) => {
+                        let italic = parent.and_then(MdInlineItalic::cast);
+                        InlinePiece::Replaced(token, italic.as_ref().map_or("_", italic_fence))
+                    }
+                    Some(MarkdownSyntaxKind::MD_INLINE_EMPHASIS) => {
+                        InlinePiece::Replaced(token, "**")
+                    }
+                    Some(MarkdownSyntaxKind::MD_HARD_LINE) => InlinePiece::HardLine(token),
+                    _ => InlinePiece::Token(token),
+                };
+                pieces.push(piece);
+            }
+        }
+    }
+
+    pieces
+}
+
+fn code_piece(code: &MdInlineCode) -> Option<InlinePiece> {
+    let open = code.l_tick_token().ok()?;
+    let close = code.r_tick_token().ok()?;
+    let tokens: Vec<_> = code
+        .content()
+        .syntax()
+        .descendants_tokens(biome_rowan::Direction::Next)
+        .collect();
+
+    let mut content = String::new();
+    let mut after_line_break = false;
+    let push_trivia = |trivia: &mut dyn Iterator<Item = SyntaxTriviaPiece<_>>,
+                       content: &mut String,
+                       after_line_break: &mut bool| {
+        for piece in trivia {
+            if piece.is_newline() {
+                content.push(' ');
+                *after_line_break = true;
+            } else if !*after_line_break {
+                // The indentation and the container markers of the following lines are dropped
+                content.push_str(piece.text());
+            }
+        }
+    };
+
+    push_trivia(
+        &mut open.trailing_trivia().pieces(),
+        &mut content,
+        &mut after_line_break,
+    );
+    for token in &tokens {
+        push_trivia(
+            &mut token.leading_trivia().pieces(),
+            &mut content,
+            &mut after_line_break,
+        );
+        content.push_str(token.text_trimmed());
+        after_line_break = false;
+        push_trivia(
+            &mut token.trailing_trivia().pieces(),
+            &mut content,
+            &mut after_line_break,
+        );
+    }
+    push_trivia(
+        &mut close.leading_trivia().pieces(),
+        &mut content,
+        &mut after_line_break,
+    );
+
+    Some(InlinePiece::Code {
+        open,
+        tokens,
+        content,
+        close,
+    })
+}
+
+/// Returns the fence of an italic content: `_`, unless the content is inside a word or starts or
+/// ends with an emphasis, where `_` wouldn't be parsed as expected.
+fn italic_fence(italic: &MdInlineItalic) -> &'static str {
+    let is_alphanumeric = |char: Option<char>| char.is_some_and(char::is_alphanumeric);
+
+    let before = italic
+        .l_fence()
+        .ok()
+        .filter(|fence| fence.leading_trivia().pieces().next().is_none())
+        .and_then(|fence| fence.prev_token())
+        .filter(|token| token.trailing_trivia().pieces().next().is_none())
+        .and_then(|token| token.text_trimmed().chars().next_back());
+    let after = italic
+        .r_fence()
+        .ok()
+        .filter(|fence| fence.trailing_trivia().pieces().next().is_none())
+        .and_then(|fence| fence.next_token())
+        .filter(|token| token.leading_trivia().pieces().next().is_none())
+        .and_then(|token| token.text_trimmed().chars().next());
+
+    let content = italic.content();
+    let is_emphasis_fence = |char: Option<char>| matches!(char, Some('*' | '_'));
+    let starts_with_fence = content
+        .syntax()
+        .first_token()
+        .is_some_and(|token| is_emphasis_fence(token.text_trimmed().chars().next()));
+    let ends_with_fence = content
+        .syntax()
+        .last_token()
+        .is_some_and(|token| is_emphasis_fence(token.text_trimmed().chars().next_back()));
+
+    if is_alphanumeric(before) || is_alphanumeric(after) || starts_with_fence || ends_with_fence {
+        "*"
+    } else {
+        "_"
+    }
+}
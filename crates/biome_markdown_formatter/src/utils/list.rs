@@ -0,0 +1,109 @@
+use crate::context::Container;
+use crate::prelude::*;
+use crate::utils::{format_replaced_token, leading_line_breaks};
+use biome_formatter::write;
+use biome_markdown_syntax::{
+    MarkdownSyntaxKind, MarkdownSyntaxNode, MarkdownSyntaxToken, MdBlockList,
+};
+use biome_rowan::{AstNode, AstNodeList};
+
+/// Returns `true` if the list is tight: neither its items, nor the blocks of its items, are
+/// separated by empty lines.
+///
+/// See <https://spec.commonmark.org/0.31.2/#tight>
+pub(crate) fn is_tight_list(list: &MarkdownSyntaxNode) -> bool {
+    let items = list.children().flat_map(|items| items.children());
+
+    for (index, item) in items.enumerate() {
+        let Some(marker) = item.first_token() else {
+            continue;
+        };
+        if index > 0 && leading_line_breaks(&marker) > 1 {
+            return false;
+        }
+
+        let blocks = item.children().find_map(MdBlockList::cast);
+        let separated_blocks =
+            blocks
+                .iter()
+                .flat_map(|blocks| blocks.iter().skip(1))
+                .any(|block| {
+                    block
+                        .syntax()
+                        .first_token()
+                        .is_some_and(|token| leading_line_breaks(&token) > 1)
+                });
+        if separated_blocks {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Returns `true` if the blocks of `list` are the content of an item of a tight list.
+pub(crate) fn is_in_tight_list(list: &MdBlockList) -> bool {
+    list.syntax()
+        .parent()
+        .filter(|item| {
+            matches!(
+                item.kind(),
+                MarkdownSyntaxKind::MD_BULLET_LIST_ITEM | MarkdownSyntaxKind::MD_ORDERED_LIST_ITEM
+            )
+        })
+        .and_then(|item| item.parent()?.parent())
+        .is_some_and(|list| is_tight_list(&list))
+}
+
+/// Returns the number of lists of the same kind that directly precede `list`.
+///
+/// Two adjacent lists must use different markers, otherwise they would be parsed as a single list.
+pub(crate) fn adjacent_lists_before(list: &MarkdownSyntaxNode) -> usize {
+    std::iter::successors(list.prev_sibling(), MarkdownSyntaxNode::prev_sibling)
+        .take_while(|sibling| sibling.kind() == list.kind())
+        .count()
+}
+
+/// Formats the task marker and the blocks of a list item, after its marker of `marker_width`
+/// columns.
+pub(crate) fn format_list_item_content<'a>(
+    task: Option<&'a MarkdownSyntaxToken>,
+    content: &'a MdBlockList,
+    marker_width: usize,
+) -> FormatListItemContent<'a> {
+    FormatListItemContent {
+        task,
+        content,
+        marker_width,
+    }
+}
+
+pub(crate) struct FormatListItemContent<'a> {
+    task: Option<&'a MarkdownSyntaxToken>,
+    content: &'a MdBlockList,
+    marker_width: usize,
+}
+
+impl Format<MarkdownFormatContext> for FormatListItemContent<'_> {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        if let Some(task) = self.task {
+            let checked = task.text_trimmed().contains(['x', 'X']);
+            let marker = if checked { "[x]" } else { "[ ]" };
+            write!(f, [space(), format_replaced_token(task, &text(marker))])?;
+        }
+
+        if self.content.is_empty() {
+            return Ok(());
+        }
+
+        write!(f, [space()])?;
+
+        f.context_mut().push_container(Container::ListItem {
+            width: self.marker_width + 1,
+        });
+        let result = write!(f, [self.content.format()]);
+        f.context_mut().pop_container();
+
+        result
+    }
+}
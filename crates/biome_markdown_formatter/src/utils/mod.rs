@@ -0,0 +1,193 @@
+pub(crate) mod inline;
+pub(crate) mod list;
+pub(crate) mod table;
+
+use crate::context::Container;
+use crate::prelude::*;
+use biome_formatter::{CstFormatContext, write};
+use biome_markdown_syntax::{MarkdownSyntaxNode, MarkdownSyntaxToken, TextSize};
+
+/// Used to indent the content of list items and to pad the cells of tables
+const SPACES: &str = "                                ";
+
+/// Writes `count` spaces.
+pub(crate) const fn format_spaces(count: usize) -> FormatSpaces {
+    FormatSpaces { count }
+}
+
+pub(crate) struct FormatSpaces {
+    count: usize,
+}
+
+impl Format<MarkdownFormatContext> for FormatSpaces {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let mut remaining = self.count;
+        while remaining > 0 {
+            let count = remaining.min(SPACES.len());
+            write!(f, [text(&SPACES[..count])])?;
+            remaining -= count;
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes the markers of the containers that start every line of the block being formatted.
+///
+/// An empty line only keeps the markers up to the innermost block quote, without trailing
+/// whitespace.
+pub(crate) const fn format_container_markers(empty_line: bool) -> FormatContainerMarkers {
+    FormatContainerMarkers { empty_line }
+}
+
+pub(crate) struct FormatContainerMarkers {
+    empty_line: bool,
+}
+
+impl Format<MarkdownFormatContext> for FormatContainerMarkers {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let containers = f.context().containers().to_vec();
+        let count = if self.empty_line {
+            containers
+                .iter()
+                .rposition(|container| *container == Container::Quote)
+                .map_or(0, |index| index + 1)
+        } else {
+            containers.len()
+        };
+
+        for (index, container) in containers[..count].iter().enumerate() {
+            match container {
+                Container::Quote if self.empty_line && index + 1 == count => {
+                    write!(f, [text(">")])?
+                }
+                Container::Quote => write!(f, [text("> ")])?,
+                Container::ListItem { width } => write!(f, [format_spaces(*width)])?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Starts a new line, preceded by `empty_lines` empty lines, inside the containers of the block
+/// being formatted.
+pub(crate) const fn format_line_break(empty_lines: usize) -> FormatLineBreak {
+    FormatLineBreak { empty_lines }
+}
+
+pub(crate) struct FormatLineBreak {
+    empty_lines: usize,
+}
+
+impl Format<MarkdownFormatContext> for FormatLineBreak {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        write!(f, [hard_line_break()])?;
+
+        // The printer collapses consecutive line breaks, but code blocks must keep their empty
+        // lines, so they are written as text instead.
+        for _ in 0..self.empty_lines {
+            write!(f, [format_container_markers(true), text("\n")])?;
+        }
+
+        write!(f, [format_container_markers(false)])
+    }
+}
+
+/// Writes a text that may span several lines, like inline HTML, inside the containers of the
+/// block being formatted. The markers of the containers are stripped from the original lines.
+pub(crate) fn format_multiline_text(text: &str, start: TextSize) -> FormatMultilineText<'_> {
+    FormatMultilineText { text, start }
+}
+
+pub(crate) struct FormatMultilineText<'a> {
+    text: &'a str,
+    start: TextSize,
+}
+
+impl Format<MarkdownFormatContext> for FormatMultilineText<'_> {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let quotes = f
+            .context()
+            .containers()
+            .iter()
+            .filter(|container| **container == Container::Quote)
+            .count();
+
+        let mut offset = self.start;
+        for (index, line) in self.text.split('\n').enumerate() {
+            let mut content = line;
+            if index > 0 {
+                write!(f, [format_line_break(0)])?;
+
+                for _ in 0..quotes {
+                    content = content.trim_start_matches([' ', '\t']);
+                    content = content.strip_prefix('>').unwrap_or(content);
+                }
+                content = content.trim_start_matches([' ', '\t']);
+            }
+
+            let content_start = offset + TextSize::from((line.len() - content.len()) as u32);
+            write!(f, [dynamic_text(content, content_start)])?;
+            offset += TextSize::from(line.len() as u32 + 1);
+        }
+
+        Ok(())
+    }
+}
+
+/// Marks `token` as formatted and writes `content` in its place.
+pub(crate) fn format_replaced_token<'a>(
+    token: &'a MarkdownSyntaxToken,
+    content: &'a dyn Format<MarkdownFormatContext>,
+) -> FormatReplacedToken<'a> {
+    FormatReplacedToken { token, content }
+}
+
+pub(crate) struct FormatReplacedToken<'a> {
+    token: &'a MarkdownSyntaxToken,
+    content: &'a dyn Format<MarkdownFormatContext>,
+}
+
+impl Format<MarkdownFormatContext> for FormatReplacedToken<'_> {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        f.state_mut().track_token(self.token);
+        self.content.fmt(f)
+    }
+}
+
+/// Marks `token` as formatted without writing it, nor its trivia.
+pub(crate) const fn format_removed_token(token: &MarkdownSyntaxToken) -> FormatRemovedToken<'_> {
+    FormatRemovedToken { token }
+}
+
+pub(crate) struct FormatRemovedToken<'a> {
+    token: &'a MarkdownSyntaxToken,
+}
+
+impl Format<MarkdownFormatContext> for FormatRemovedToken<'_> {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        f.state_mut().track_token(self.token);
+        Ok(())
+    }
+}
+
+/// Marks `node` and all its descendants as formatted, for nodes whose content is written by the
+/// formatter of an ancestor.
+pub(crate) fn mark_node_formatted(node: &MarkdownSyntaxNode, f: &mut MarkdownFormatter) {
+    for descendant in node.descendants() {
+        f.context().comments().mark_suppression_checked(&descendant);
+    }
+    for token in node.descendants_tokens(biome_rowan::Direction::Next) {
+        f.state_mut().track_token(&token);
+    }
+}
+
+/// Returns the number of line breaks in the leading trivia of `token`.
+pub(crate) fn leading_line_breaks(token: &MarkdownSyntaxToken) -> usize {
+    token
+        .leading_trivia()
+        .pieces()
+        .filter(|piece| piece.is_newline())
+        .count()
+}
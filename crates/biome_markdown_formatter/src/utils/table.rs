@@ -0,0 +1,105 @@
+use crate::utils::inline::inline_text;
+use biome_markdown_syntax::{MdTable, MdTableRow};
+use biome_rowan::{AstNode, AstNodeList};
+use unicode_width::UnicodeWidthStr;
+
+/// The alignment of the cells of a table column, defined by its delimiter cell
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub(crate) enum ColumnAlignment {
+    /// `---`
+    #[default]
+    None,
+    /// `:---`
+    Left,
+    /// `:---:`
+    Center,
+    /// `---:`
+    Right,
+}
+
+impl ColumnAlignment {
+    fn from_delimiter(delimiter: &str) -> Self {
+        match (delimiter.starts_with(':'), delimiter.ends_with(':')) {
+            (true, true) if delimiter.len() > 1 => Self::Center,
+            (true, _) => Self::Left,
+            (false, true) => Self::Right,
+            (false, false) => Self::None,
+        }
+    }
+}
+
+/// The layout of a table column, shared by all its cells
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub(crate) struct TableColumn {
+    /// The width of the widest cell of the column
+    pub(crate) width: usize,
+    pub(crate) alignment: ColumnAlignment,
+}
+
+impl TableColumn {
+    /// The minimum width of a column, so that its delimiter cell has at least three dashes
+    const MIN_WIDTH: usize = 3;
+
+    /// Returns the delimiter cell of the column
+    pub(crate) fn delimiter(&self) -> String {
+        let (start, end) = match self.alignment {
+            ColumnAlignment::None => ("", ""),
+            ColumnAlignment::Left => (":", ""),
+            ColumnAlignment::Center => (":", ":"),
+            ColumnAlignment::Right => ("", ":"),
+        };
+        let dashes = self.width - start.len() - end.len();
+        format!("{start}{}{end}", "-".repeat(dashes))
+    }
+
+    /// Returns the number of spaces to print before and after a cell content of `width`
+    pub(crate) fn padding(&self, width: usize) -> (usize, usize) {
+        let padding = self.width.saturating_sub(width);
+        match self.alignment {
+            ColumnAlignment::None | ColumnAlignment::Left => (0, padding),
+            ColumnAlignment::Center => (padding / 2, padding - padding / 2),
+            ColumnAlignment::Right => (padding, 0),
+        }
+    }
+}
+
+/// Returns the layout of the columns of `table`, so that the cells of a column are aligned.
+pub(crate) fn table_columns(table: &MdTable) -> Vec<TableColumn> {
+    let mut columns: Vec<TableColumn> = Vec::new();
+
+    if let Ok(delimiter) = table.delimiter() {
+        for cell in delimiter.cells() {
+            let alignment = cell
+                .value_token()
+                .map(|value| ColumnAlignment::from_delimiter(value.text_trimmed()))
+                .unwrap_or_default();
+            columns.push(TableColumn {
+                width: TableColumn::MIN_WIDTH,
+                alignment,
+            });
+        }
+    }
+
+    let rows = table.header().into_iter().chain(table.rows());
+    for row in rows {
+        for (index, width) in cell_widths(&row).into_iter().enumerate() {
+            if index >= columns.len() {
+                columns.push(TableColumn {
+                    width: TableColumn::MIN_WIDTH,
+                    alignment: ColumnAlignment::None,
+                });
+            }
+            columns[index].width = columns[index].width.max(width);
+        }
+    }
+
+    columns
+}
+
+/// Returns the width of the content of the cells of `row`, as they're printed
+pub(crate) fn cell_widths(row: &MdTableRow) -> Vec<usize> {
+    row.cells()
+        .iter()
+        .map(|cell| inline_text(cell.content().syntax()).width())
+        .collect()
+}
@@ -0,0 +1,45 @@
+use biome_formatter::LineWidth;
+use biome_formatter_test::check_reformat::CheckReformat;
+use biome_markdown_formatter::context::{MarkdownFormatOptions, ProseWrap};
+use biome_markdown_formatter::{MarkdownFormatLanguage, format_node};
+use biome_markdown_parser::parse_markdown;
+
+mod language {
+    include!("language.rs");
+}
+
+#[ignore]
+#[test]
+// use this test check if your snippet prints as you wish, without using a snapshot
+fn quick_test() {
+    let src = r#"
+Title
+=====
+
+* item with *emphasis*
+* [ ] task
+"#;
+    let parse = parse_markdown(src);
+    println!("{parse:#?}");
+
+    let options = MarkdownFormatOptions::default()
+        .with_line_width(LineWidth::try_from(80).unwrap())
+        .with_prose_wrap(ProseWrap::Preserve);
+    let doc = format_node(options.clone(), &parse.syntax()).unwrap();
+    let result = doc.print().unwrap();
+
+    let root = &parse.syntax();
+    let language = language::MarkdownTestFormatLanguage::default();
+
+    println!("{}", doc.into_document());
+    eprintln!("{}", result.as_code());
+
+    CheckReformat::new(
+        root,
+        result.as_code(),
+        "quick_test",
+        &language,
+        MarkdownFormatLanguage::new(options),
+    )
+    .check_reformat();
+}
@@ -1,8 +1,12 @@
-//! An extremely fast, lookup table based, JSON lexer which yields SyntaxKind tokens used by the rome-json parser.
+//! An extremely fast, lossless lexer which yields SyntaxKind tokens used by the Markdown parser.
 
 #[rustfmt::skip]
 mod tests;
 
+use crate::scanner::{
+    is_escape, is_line_ending, scan_autolink, scan_extended_autolink, scan_inline_html,
+    scan_link_destination, scan_link_title,
+};
 use biome_markdown_syntax::MarkdownSyntaxKind;
 use biome_markdown_syntax::MarkdownSyntaxKind::*;
 use biome_parser::diagnostic::ParseDiagnostic;
@@ -10,12 +14,19 @@ use biome_parser::lexer::{
     LexContext, Lexer, LexerCheckpoint, LexerWithCheckpoint, ReLexer, TokenFlags,
 };
 use biome_rowan::{SyntaxKind, TextSize};
-use biome_unicode_table::{Dispatch::*, lookup_byte};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
 pub enum MarkdownLexContext {
+    /// Lexes the text of blocks. The characters that can start an inline construct, such as
+    /// `*`, `[` or `<`, are lexed as separate textual tokens, so that the parser can re-lex
+    /// them once it knows which construct they start.
     #[default]
     Regular,
+    /// Like [MarkdownLexContext::Regular], but also lexes the
+    /// [hard line breaks](https://spec.commonmark.org/0.31.2/#hard-line-breaks) of paragraphs.
+    Inline,
+    /// Lexes the content of a code span, where backslash escapes aren't recognised.
+    CodeSpan,
 }
 
 impl LexContext for MarkdownLexContext {
@@ -28,12 +39,47 @@ impl LexContext for MarkdownLexContext {
 /// Context in which the [MarkdownLexContext]'s current should be re-lexed.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum MarkdownReLexContext {
-    #[expect(dead_code)]
-    Regular,
-    // UnicodeRange,
+    /// A thematic break, up to the end of the line
+    ThematicBreak,
+    /// A single `#` of an ATX heading
+    Hash,
+    /// The underline of a setext heading
+    SetextUnderline,
+    /// The backticks or tildes of a code fence
+    CodeFence,
+    /// The info string of a fenced code block, up to the end of the line
+    FenceInfo,
+    /// A line of code or of an HTML block, up to the end of the line
+    CodeLine,
+    /// A bullet or ordered list marker
+    ListMarker,
+    /// A task list marker, such as `[ ]` or `[x]`
+    TaskMarker,
+    /// A cell of the delimiter row of a table, such as `:---`
+    TableDelimiter,
+    /// A single punctuation character, such as `[` or `|`
+    Punctuation,
+    /// The delimiter of an italic or strikethrough inline: `*`, `_` or `~`
+    Emphasis,
+    /// The delimiter of an emphasis or strikethrough inline: `**`, `__` or `~~`
+    StrongEmphasis,
+    /// The backticks that open or close a code span
+    BacktickRun,
+    /// An autolink enclosed in angle brackets
+    Autolink,
+    /// An extended autolink of GitHub Flavored Markdown, such as `www.biomejs.dev`
+    ExtendedAutolink,
+    /// Raw HTML
+    InlineHtml,
+    /// The destination of a link
+    LinkDestination,
+    /// The title of a link
+    LinkTitle,
+    /// The text of a link label, up to its closing bracket
+    LinkLabel,
 }
 
-/// An extremely fast, lookup table based, lossless Markdown lexer
+/// An extremely fast, lossless Markdown lexer
 #[derive(Debug)]
 pub(crate) struct MarkdownLexer<'src> {
     /// Source text
@@ -90,12 +136,12 @@ impl<'src> Lexer<'src> for MarkdownLexer<'src> {
         self.diagnostics.push(diagnostic);
     }
 
-    fn next_token(&mut self, _context: Self::LexContext) -> Self::Kind {
+    fn next_token(&mut self, context: Self::LexContext) -> Self::Kind {
         self.current_start = self.text_position();
         self.current_flags = TokenFlags::empty();
 
         let kind = match self.current_byte() {
-            Some(current) => self.consume_token(current),
+            Some(current) => self.consume_token(current, context),
             None => EOF,
         };
 
@@ -176,12 +222,21 @@ impl<'src> MarkdownLexer<'src> {
         }
     }
 
-    pub(crate) fn consume_token(&mut self, current: u8) -> MarkdownSyntaxKind {
-        let dispatched = lookup_byte(current);
-        match dispatched {
-            WHS => self.consume_newline_or_whitespace(),
-            MUL | MIN | IDT => self.consume_thematic_break_literal(),
-            _ => self.consume_textual(),
+    fn consume_token(&mut self, current: u8, context: MarkdownLexContext) -> MarkdownSyntaxKind {
+        match current {
+            b'\n' | b'\r' => self.consume_newline(),
+            b' ' => self.consume_whitespace(context),
+            b'\t' => self.consume_tab(),
+            b'`' => self.consume_backtick_run(MD_TEXTUAL_LITERAL),
+            0xef if self.position == 0 && self.source.starts_with('\u{feff}') => {
+                self.unicode_bom_length = '\u{feff}'.len_utf8();
+                self.advance(self.unicode_bom_length);
+                UNICODE_BOM
+            }
+            _ if context == MarkdownLexContext::CodeSpan => self.consume_code_span_text(),
+            b'\\' => self.consume_backslash(context),
+            _ if is_inline_start(current) => self.consume_byte(MD_TEXTUAL_LITERAL),
+            _ => self.consume_text(),
         }
     }
 
@@ -189,12 +244,6 @@ impl<'src> MarkdownLexer<'src> {
         TextSize::try_from(self.position).expect("Input to be smaller than 4 GB")
     }
 
-    /// Bumps the current byte and creates a lexed token of the passed in kind
-    #[expect(dead_code)]
-    fn eat_byte(&mut self, tok: MarkdownSyntaxKind) -> MarkdownSyntaxKind {
-        self.advance(1);
-        tok
-    }
     /// Returns the byte at position `self.position + offset` or `None` if it is out of bounds.
     #[inline]
     fn byte_at(&self, offset: usize) -> Option<u8> {
@@ -210,19 +259,6 @@ impl<'src> MarkdownLexer<'src> {
         self.byte_at(1)
     }
 
-    /// Consume one newline or all whitespace until a non-whitespace or a newline is found.
-    ///
-    /// ## Safety
-    /// Must be called at a valid UT8 char boundary
-    fn consume_newline_or_whitespace(&mut self) -> MarkdownSyntaxKind {
-        match self.current_byte() {
-            Some(b'\n' | b'\r') => self.consume_newline(),
-            Some(b' ') => self.consume_whitespace(),
-            Some(b'\t') => self.consume_tab(),
-            _ => self.consume_textual(),
-        }
-    }
-
     /// Consume just one newline/line break.
     ///
     /// ## Safety
@@ -249,15 +285,31 @@ impl<'src> MarkdownLexer<'src> {
 
     /// Consumes all whitespace until a non-whitespace or a newline is found.
     ///
+    /// In the [MarkdownLexContext::Inline] context, two or more spaces at the end of a line
+    /// that has some content are a hard line break.
+    ///
     /// ## Safety
     /// Must be called at a valid UT8 char boundary
-    fn consume_whitespace(&mut self) -> MarkdownSyntaxKind {
+    fn consume_whitespace(&mut self, context: MarkdownLexContext) -> MarkdownSyntaxKind {
         self.assert_at_char_boundary();
+        let start = self.position;
         while let Some(b' ') = self.current_byte() {
             self.advance(1);
         }
 
-        WHITESPACE
+        let is_hard_line = context == MarkdownLexContext::Inline
+            && self.position - start >= 2
+            && self.current_byte().is_some_and(is_line_ending)
+            && self.source[..start]
+                .rsplit(['\n', '\r'])
+                .next()
+                .is_some_and(|line| !line.trim().is_empty());
+
+        if is_hard_line {
+            MD_HARD_LINE_LITERAL
+        } else {
+            WHITESPACE
+        }
     }
 
     fn consume_tab(&mut self) -> MarkdownSyntaxKind {
@@ -269,31 +321,180 @@ impl<'src> MarkdownLexer<'src> {
         TAB
     }
 
-    fn consume_thematic_break_literal(&mut self) -> MarkdownSyntaxKind {
+    /// Consumes a backslash. A backslash followed by an ASCII punctuation character escapes it,
+    /// and a backslash at the end of a line is a hard line break in the
+    /// [MarkdownLexContext::Inline] context.
+    fn consume_backslash(&mut self, context: MarkdownLexContext) -> MarkdownSyntaxKind {
+        match self.peek_byte() {
+            Some(next) if next.is_ascii_punctuation() => {
+                self.advance(2);
+                MD_TEXTUAL_LITERAL
+            }
+            Some(next) if is_line_ending(next) && context == MarkdownLexContext::Inline => {
+                self.consume_byte(MD_HARD_LINE_LITERAL)
+            }
+            _ => self.consume_byte(MD_TEXTUAL_LITERAL),
+        }
+    }
+
+    fn consume_backtick_run(&mut self, kind: MarkdownSyntaxKind) -> MarkdownSyntaxKind {
+        while let Some(b'`') = self.current_byte() {
+            self.advance(1);
+        }
+        kind
+    }
+
+    /// Consumes a run of text up to the next whitespace or character that can start an inline.
+    fn consume_text(&mut self) -> MarkdownSyntaxKind {
         self.assert_at_char_boundary();
 
-        let start_char = match self.current_byte() {
-            Some(b'-') => b'-',
-            Some(b'*') => b'*',
-            Some(b'_') => b'_',
-            _ => return self.consume_textual(),
-        };
+        // Only ASCII bytes end the text, so the position remains at a char boundary
+        while let Some(byte) = self.current_byte() {
+            if is_text_end(byte) || byte == b'\\' || byte == b'`' || is_inline_start(byte) {
+                break;
+            }
+            self.advance(1);
+        }
 
-        let mut count = 0;
-        loop {
-            self.consume_whitespace();
-            if matches!(self.current_byte(), Some(ch) if ch == start_char) {
-                self.advance(1);
-                count += 1;
-            } else {
+        MD_TEXTUAL_LITERAL
+    }
+
+    /// Consumes a run of text of a code span, up to the next whitespace or backtick.
+    fn consume_code_span_text(&mut self) -> MarkdownSyntaxKind {
+        self.assert_at_char_boundary();
+
+        while let Some(byte) = self.current_byte() {
+            if is_text_end(byte) || byte == b'`' {
                 break;
             }
+            self.advance(1);
         }
-        // until next newline or eof
-        if matches!(self.current_byte(), Some(b'\n' | b'\r') | None) && count >= 3 {
-            return MD_THEMATIC_BREAK_LITERAL;
+
+        MD_TEXTUAL_LITERAL
+    }
+
+    /// Re-lexes the token at the current position in the given context. If the text doesn't
+    /// start with the construct of the context, the token is lexed as in the regular context.
+    fn re_lex_token(&mut self, context: MarkdownReLexContext) -> MarkdownSyntaxKind {
+        let text = &self.source[self.position..];
+        let bytes = text.as_bytes();
+        let first = bytes[0];
+        let run_length = |predicate: &dyn Fn(u8) -> bool| {
+            bytes
+                .iter()
+                .position(|byte| !predicate(*byte))
+                .unwrap_or(bytes.len())
+        };
+
+        let token = match context {
+            MarkdownReLexContext::ThematicBreak => {
+                Some((MD_THEMATIC_BREAK_LITERAL, trimmed_line_length(text)))
+            }
+            MarkdownReLexContext::Hash => (first == b'#').then_some((HASH, 1)),
+            MarkdownReLexContext::SetextUnderline if matches!(first, b'=' | b'-') => Some((
+                MD_SETEXT_UNDERLINE_LITERAL,
+                run_length(&|byte| byte == first),
+            )),
+            MarkdownReLexContext::CodeFence if matches!(first, b'`' | b'~') => {
+                Some((MD_CODE_FENCE_LITERAL, run_length(&|byte| byte == first)))
+            }
+            MarkdownReLexContext::FenceInfo => {
+                Some((MD_TEXTUAL_LITERAL, trimmed_line_length(text)))
+            }
+            MarkdownReLexContext::CodeLine => Some((MD_TEXTUAL_LITERAL, line_length(text))),
+            MarkdownReLexContext::ListMarker => match first {
+                b'-' => Some((MINUS, 1)),
+                b'*' => Some((STAR, 1)),
+                b'+' => Some((PLUS, 1)),
+                _ => {
+                    let digits = run_length(&|byte| byte.is_ascii_digit());
+                    ((1..=9).contains(&digits) && matches!(bytes.get(digits), Some(b'.' | b')')))
+                        .then_some((MD_ORDERED_LIST_MARKER_LITERAL, digits + 1))
+                }
+            },
+            MarkdownReLexContext::TaskMarker => {
+                let is_task_marker = first == b'['
+                    && matches!(bytes.get(1), Some(b' ' | b'x' | b'X'))
+                    && bytes.get(2) == Some(&b']');
+                is_task_marker.then_some((MD_TASK_MARKER_LITERAL, 3))
+            }
+            MarkdownReLexContext::TableDelimiter => {
+                let mut length = usize::from(first == b':');
+                let hyphens = bytes[length..]
+                    .iter()
+                    .position(|byte| *byte != b'-')
+                    .unwrap_or(bytes.len() - length);
+                length += hyphens;
+                if bytes.get(length) == Some(&b':') {
+                    length += 1;
+                }
+                (hyphens > 0).then_some((MD_TABLE_DELIMITER_LITERAL, length))
+            }
+            MarkdownReLexContext::Punctuation => {
+                let kind = match first {
+                    b'<' => Some(L_ANGLE),
+                    b'>' => Some(R_ANGLE),
+                    b'(' => Some(L_PAREN),
+                    b')' => Some(R_PAREN),
+                    b'[' => Some(L_BRACK),
+                    b']' => Some(R_BRACK),
+                    b'!' => Some(BANG),
+                    b'|' => Some(PIPE),
+                    b':' => Some(COLON),
+                    _ => None,
+                };
+                kind.map(|kind| (kind, 1))
+            }
+            MarkdownReLexContext::Emphasis => match first {
+                b'*' => Some((STAR, 1)),
+                b'_' => Some((UNDERSCORE, 1)),
+                b'~' => Some((TILDE, 1)),
+                _ => None,
+            },
+            MarkdownReLexContext::StrongEmphasis => match bytes.get(..2) {
+                Some(b"**") => Some((DOUBLE_STAR, 2)),
+                Some(b"__") => Some((DOUBLE_UNDERSCORE, 2)),
+                Some(b"~~") => Some((DOUBLE_TILDE, 2)),
+                _ => None,
+            },
+            MarkdownReLexContext::BacktickRun if first == b'`' => {
+                Some((MD_BACKTICK_RUN_LITERAL, run_length(&|byte| byte == b'`')))
+            }
+            MarkdownReLexContext::Autolink => {
+                scan_autolink(text).map(|length| (MD_AUTOLINK_LITERAL, length))
+            }
+            MarkdownReLexContext::ExtendedAutolink => {
+                scan_extended_autolink(text).map(|length| (MD_AUTOLINK_LITERAL, length))
+            }
+            MarkdownReLexContext::InlineHtml => {
+                scan_inline_html(text).map(|length| (MD_HTML_LITERAL, length))
+            }
+            MarkdownReLexContext::LinkDestination => {
+                scan_link_destination(text).map(|length| (MD_TEXTUAL_LITERAL, length))
+            }
+            MarkdownReLexContext::LinkTitle => {
+                scan_link_title(text).map(|length| (MD_TEXTUAL_LITERAL, length))
+            }
+            MarkdownReLexContext::LinkLabel => {
+                let mut length = 0;
+                while let Some(&byte) = bytes.get(length) {
+                    if byte == b']' || byte == b'[' {
+                        break;
+                    }
+                    length += if is_escape(&bytes[length..]) { 2 } else { 1 };
+                }
+                Some((MD_TEXTUAL_LITERAL, length))
+            }
+            _ => None,
+        };
+
+        match token {
+            Some((kind, length)) if length > 0 => {
+                self.advance(length);
+                kind
+            }
+            _ => self.consume_token(first, MarkdownLexContext::Regular),
         }
-        ERROR_TOKEN
     }
 
     /// Get the UTF8 char which starts at the current byte
@@ -350,44 +551,50 @@ impl<'src> MarkdownLexer<'src> {
         self.position >= self.source.len()
     }
 
-    #[inline]
-    fn consume_textual(&mut self) -> MarkdownSyntaxKind {
-        self.assert_at_char_boundary();
-
-        let char = self.current_char_unchecked();
-        self.advance(char.len_utf8());
-
-        MD_TEXTUAL_LITERAL
-    }
-
     /// Bumps the current byte and creates a lexed token of the passed in kind
-    #[expect(dead_code)]
     fn consume_byte(&mut self, tok: MarkdownSyntaxKind) -> MarkdownSyntaxKind {
         self.advance(1);
         tok
     }
 }
 
+/// Returns `true` if `byte` can start an inline construct, or be the delimiter of one.
+/// These characters are lexed as separate tokens.
+const fn is_inline_start(byte: u8) -> bool {
+    matches!(
+        byte,
+        b'*' | b'_' | b'~' | b'[' | b']' | b'!' | b'(' | b')' | b'<' | b'>' | b'|'
+    )
+}
+
+/// Returns `true` if `byte` ends a run of text: a space, a tab or a line ending.
+const fn is_text_end(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t') || is_line_ending(byte)
+}
+
+/// Returns the length of the text up to the end of the line.
+fn line_length(text: &str) -> usize {
+    text.find(['\n', '\r']).unwrap_or(text.len())
+}
+
+/// Returns the length of the text up to the end of the line, without the trailing whitespace.
+fn trimmed_line_length(text: &str) -> usize {
+    text[..line_length(text)]
+        .trim_end_matches([' ', '\t'])
+        .len()
+}
+
 impl<'src> ReLexer<'src> for MarkdownLexer<'src> {
     fn re_lex(&mut self, context: Self::ReLexContext) -> Self::Kind {
-        let old_position = self.position;
         self.position = u32::from(self.current_start) as usize;
 
-        let re_lexed_kind = match self.current_byte() {
-            Some(current) => match context {
-                MarkdownReLexContext::Regular => self.consume_token(current),
-                // MarkdownReLexContext::UnicodeRange => self.consume_unicode_range_token(current),
-            },
-            None => EOF,
-        };
-
-        if self.current() == re_lexed_kind {
-            // Didn't re-lex anything. Return existing token again
-            self.position = old_position;
+        let re_lexed_kind = if self.is_eof() {
+            EOF
         } else {
-            self.current_kind = re_lexed_kind;
-        }
+            self.re_lex_token(context)
+        };
 
+        self.current_kind = re_lexed_kind;
         re_lexed_kind
     }
 }
@@ -2,9 +2,9 @@
 #![expect(unused_mut, unused_variables)]
 
 use super::{MarkdownLexer, TextSize};
-use crate::lexer::MarkdownLexContext;
+use crate::lexer::{MarkdownLexContext, MarkdownReLexContext};
 use biome_markdown_syntax::MarkdownSyntaxKind::*;
-use biome_parser::lexer::Lexer;
+use biome_parser::lexer::{Lexer, ReLexer};
 use quickcheck_macros::quickcheck;
 use std::sync::mpsc::channel;
 use std::thread;
@@ -141,24 +141,47 @@ fn whitespace() {
 }
 
 #[test]
-fn thematic_break_literal() {
+fn inline_start() {
     assert_lex! {
-        r#"---
-***
-___
-* * *
-* * * *
-_ _ _ _  _ "#,
-        MD_THEMATIC_BREAK_LITERAL:3,
-        NEWLINE:1,
-        MD_THEMATIC_BREAK_LITERAL:3,
-        NEWLINE:1,
-        MD_THEMATIC_BREAK_LITERAL:3,
-        NEWLINE:1,
-        MD_THEMATIC_BREAK_LITERAL:5,
-        NEWLINE:1,
-        MD_THEMATIC_BREAK_LITERAL:7,
-        NEWLINE:1,
-        MD_THEMATIC_BREAK_LITERAL:11,
+        "*a_[b]",
+        MD_TEXTUAL_LITERAL:1,
+        MD_TEXTUAL_LITERAL:1,
+        MD_TEXTUAL_LITERAL:1,
+        MD_TEXTUAL_LITERAL:1,
+        MD_TEXTUAL_LITERAL:1,
+        MD_TEXTUAL_LITERAL:1,
+    }
+}
+
+#[test]
+fn backtick_run() {
+    assert_lex! {
+        "``a`",
+        MD_TEXTUAL_LITERAL:2,
+        MD_TEXTUAL_LITERAL:1,
+        MD_TEXTUAL_LITERAL:1,
+    }
+}
+
+#[test]
+fn escape() {
+    assert_lex! {
+        r"\*a\",
+        MD_TEXTUAL_LITERAL:2,
+        MD_TEXTUAL_LITERAL:1,
+        MD_TEXTUAL_LITERAL:1,
     }
 }
+
+#[test]
+fn thematic_break_literal() {
+    let mut lexer = MarkdownLexer::from_str("* * *  \n");
+    lexer.next_token(MarkdownLexContext::default());
+
+    assert_eq!(
+        lexer.re_lex(MarkdownReLexContext::ThematicBreak),
+        MD_THEMATIC_BREAK_LITERAL
+    );
+    assert_eq!(lexer.current_range().len(), TextSize::from(5));
+    assert_eq!(lexer.next_token(MarkdownLexContext::default()), WHITESPACE);
+}
@@ -1,15 +1,35 @@
-use biome_markdown_syntax::MarkdownSyntaxKind;
+use biome_markdown_syntax::{MarkdownSyntaxKind, TextSize};
 use biome_parser::ParserContext;
+use biome_parser::diagnostic::merge_diagnostics;
 use biome_parser::event::Event;
 use biome_parser::prelude::*;
 use biome_parser::token_source::Trivia;
-use biome_parser::{ParserContextCheckpoint, diagnostic::merge_diagnostics};
+use rustc_hash::FxHashSet;
 
-use crate::token_source::{MarkdownTokenSource, MarkdownTokenSourceCheckpoint};
+use crate::lexer::MarkdownReLexContext;
+use crate::syntax::line::Container;
+use crate::token_source::MarkdownTokenSource;
 
 pub(crate) struct MarkdownParser<'source> {
     context: ParserContext<MarkdownSyntaxKind>,
     source: MarkdownTokenSource<'source>,
+    state: MarkdownParserState,
+}
+
+/// The block structure that the parser is in.
+#[derive(Debug, Default)]
+pub(crate) struct MarkdownParserState {
+    /// The open container blocks, from the outermost one
+    pub(crate) containers: Vec<Container>,
+
+    /// The start of the last line whose container markers have been consumed
+    pub(crate) matched_line: Option<usize>,
+
+    /// The column at which the content of the containers starts on the current line
+    pub(crate) content_column: usize,
+
+    /// The normalized labels of the link reference definitions of the document
+    pub(crate) link_labels: FxHashSet<String>,
 }
 
 impl<'source> MarkdownParser<'source> {
@@ -17,25 +37,36 @@ impl<'source> MarkdownParser<'source> {
         Self {
             context: ParserContext::default(),
             source: MarkdownTokenSource::from_str(source),
+            state: MarkdownParserState::default(),
         }
     }
 
-    pub fn checkpoint(&self) -> MarkdownParserCheckpoint {
-        MarkdownParserCheckpoint {
-            context: self.context.checkpoint(),
-            source: self.source.checkpoint(),
-        }
+    pub fn state(&self) -> &MarkdownParserState {
+        &self.state
     }
 
-    pub fn before_whitespace_count(&self) -> usize {
-        self.source.before_whitespace_count()
+    pub fn state_mut(&mut self) -> &mut MarkdownParserState {
+        &mut self.state
     }
 
-    pub fn rewind(&mut self, checkpoint: MarkdownParserCheckpoint) {
-        let MarkdownParserCheckpoint { context, source } = checkpoint;
+    /// Returns the whole source text
+    pub fn source_text(&self) -> &'source str {
+        self.source.source_text()
+    }
+
+    /// Re-lexes the current token in the given context and returns its new kind
+    pub fn re_lex(&mut self, context: MarkdownReLexContext) -> MarkdownSyntaxKind {
+        self.source.re_lex(context)
+    }
 
-        self.context.rewind(context);
-        self.source.rewind(source);
+    /// Re-lexes the current token in the given context from `start`, which precedes it on the
+    /// same line, and returns its new kind
+    pub fn re_lex_from(
+        &mut self,
+        start: TextSize,
+        context: MarkdownReLexContext,
+    ) -> MarkdownSyntaxKind {
+        self.source.re_lex_from(start, context)
     }
 
     pub fn finish(
@@ -74,8 +105,3 @@ impl<'source> Parser for MarkdownParser<'source> {
         &mut self.source
     }
 }
-
-pub struct MarkdownParserCheckpoint {
-    pub(super) context: ParserContextCheckpoint,
-    pub(super) source: MarkdownTokenSourceCheckpoint,
-}
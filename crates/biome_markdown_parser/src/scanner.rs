@@ -0,0 +1,478 @@
+//! Functions that recognise Markdown constructs at the start of a text.
+//!
+//! They're shared by the lexer, which uses them to re-lex the current token, and by the
+//! parser, which uses them to find the structure of the inlines of a block before parsing them.
+//! Each function returns the length in bytes of the construct, or `None` if the text doesn't
+//! start with it.
+
+use biome_string_case::StrOnlyExtension;
+
+/// Returns `true` if `byte` is a space, a tab or a line ending.
+pub(crate) const fn is_whitespace(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t' | b'\n' | b'\r' | b'\x0b' | b'\x0c')
+}
+
+/// Returns `true` if `byte` is a [line ending](https://spec.commonmark.org/0.31.2/#line-ending).
+pub(crate) const fn is_line_ending(byte: u8) -> bool {
+    matches!(byte, b'\n' | b'\r')
+}
+
+/// Returns `true` if the text starts with an escaped ASCII punctuation character.
+pub(crate) fn is_escape(bytes: &[u8]) -> bool {
+    bytes.first() == Some(&b'\\') && bytes.get(1).is_some_and(u8::is_ascii_punctuation)
+}
+
+/// Scans an [autolink](https://spec.commonmark.org/0.31.2/#autolinks), such as
+/// `<https://biomejs.dev>` or `<foo@example.com>`.
+pub(crate) fn scan_autolink(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    if bytes.first() != Some(&b'<') {
+        return None;
+    }
+
+    scan_uri_autolink(bytes).or_else(|| scan_email_autolink(bytes))
+}
+
+fn scan_uri_autolink(bytes: &[u8]) -> Option<usize> {
+    let mut index = 1;
+    if !bytes.get(index)?.is_ascii_alphabetic() {
+        return None;
+    }
+    index += 1;
+
+    while bytes
+        .get(index)
+        .is_some_and(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'+' | b'.' | b'-'))
+    {
+        index += 1;
+    }
+
+    if !(3..=33).contains(&index) || bytes.get(index) != Some(&b':') {
+        return None;
+    }
+    index += 1;
+
+    loop {
+        match *bytes.get(index)? {
+            b'>' => return Some(index + 1),
+            b'<' => return None,
+            byte if byte <= b' ' || byte == 0x7f => return None,
+            _ => index += 1,
+        }
+    }
+}
+
+fn scan_email_autolink(bytes: &[u8]) -> Option<usize> {
+    let mut index = 1;
+    while bytes
+        .get(index)
+        .is_some_and(|byte| byte.is_ascii_alphanumeric() || b".!#$%&'*+/=?^_`{|}~-".contains(byte))
+    {
+        index += 1;
+    }
+
+    if index == 1 || bytes.get(index) != Some(&b'@') {
+        return None;
+    }
+    index += 1;
+
+    loop {
+        let label_start = index;
+        while bytes
+            .get(index)
+            .is_some_and(|byte| byte.is_ascii_alphanumeric() || *byte == b'-')
+        {
+            index += 1;
+        }
+
+        let label = &bytes[label_start..index];
+        if label.is_empty()
+            || label.len() > 63
+            || label.first() == Some(&b'-')
+            || label.last() == Some(&b'-')
+        {
+            return None;
+        }
+
+        match bytes.get(index)? {
+            b'.' => index += 1,
+            b'>' => return Some(index + 1),
+            _ => return None,
+        }
+    }
+}
+
+/// Scans an [extended autolink](https://github.github.com/gfm/#autolinks-extension-) of GitHub
+/// Flavored Markdown, such as `www.biomejs.dev` or `https://biomejs.dev/guides`.
+pub(crate) fn scan_extended_autolink(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let starts_with = |prefix: &str| {
+        bytes
+            .get(..prefix.len())
+            .is_some_and(|start| start.eq_ignore_ascii_case(prefix.as_bytes()))
+    };
+
+    let domain_start = if starts_with("www.") {
+        0
+    } else if starts_with("http://") {
+        7
+    } else if starts_with("https://") {
+        8
+    } else {
+        return None;
+    };
+
+    let mut index = domain_start;
+    while bytes
+        .get(index)
+        .is_some_and(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.'))
+    {
+        index += 1;
+    }
+
+    let domain = text[domain_start..index].trim_end_matches('.');
+    let segments: Vec<_> = domain.split('.').collect();
+    if segments.len() < 2
+        || segments.iter().any(|segment| segment.is_empty())
+        || segments
+            .iter()
+            .rev()
+            .take(2)
+            .any(|segment| segment.contains('_'))
+    {
+        return None;
+    }
+
+    // A `|` ends the link too, because it separates the cells of a table
+    while let Some(&byte) = bytes.get(index) {
+        if is_whitespace(byte) || matches!(byte, b'<' | b'|') {
+            break;
+        }
+        index += 1;
+    }
+
+    // Trailing punctuation isn't part of the link
+    loop {
+        match bytes[..index].last() {
+            Some(b'?' | b'!' | b'.' | b',' | b':' | b'*' | b'_' | b'~' | b'\'' | b'"') => {
+                index -= 1
+            }
+            Some(b')') => {
+                let balance = bytes[..index]
+                    .iter()
+                    .fold(0isize, |balance, byte| match byte {
+                        b'(' => balance + 1,
+                        b')' => balance - 1,
+                        _ => balance,
+                    });
+                if balance < 0 {
+                    index -= 1;
+                } else {
+                    break;
+                }
+            }
+            Some(b';') => {
+                // An entity reference, such as `&amp;`
+                let entity_start = bytes[..index - 1]
+                    .iter()
+                    .rposition(|byte| !byte.is_ascii_alphanumeric());
+                match entity_start {
+                    Some(start) if bytes[start] == b'&' && start + 1 < index - 1 => index = start,
+                    _ => break,
+                }
+            }
+            _ => break,
+        }
+    }
+
+    (index > domain_start).then_some(index)
+}
+
+/// Scans [raw HTML](https://spec.commonmark.org/0.31.2/#raw-html): an open or closing tag, a
+/// comment, a processing instruction, a declaration or a CDATA section.
+pub(crate) fn scan_inline_html(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    if bytes.first() != Some(&b'<') {
+        return None;
+    }
+
+    match *bytes.get(1)? {
+        b'/' => {
+            let mut index = scan_tag_name(bytes, 2)?;
+            index = skip_html_whitespace(bytes, index);
+            (bytes.get(index) == Some(&b'>')).then_some(index + 1)
+        }
+        b'!' => {
+            if let Some(comment) = text.strip_prefix("<!--") {
+                if comment.starts_with('>') {
+                    Some(5)
+                } else if comment.starts_with("->") {
+                    Some(6)
+                } else {
+                    comment.find("-->").map(|end| end + 7)
+                }
+            } else if let Some(data) = text.strip_prefix("<![CDATA[") {
+                data.find("]]>").map(|end| end + 12)
+            } else if bytes.get(2).is_some_and(u8::is_ascii_alphabetic) {
+                text.find('>').map(|end| end + 1)
+            } else {
+                None
+            }
+        }
+        b'?' => text[2..].find("?>").map(|end| end + 4),
+        _ => scan_open_tag(bytes),
+    }
+}
+
+fn scan_open_tag(bytes: &[u8]) -> Option<usize> {
+    let mut index = scan_tag_name(bytes, 1)?;
+
+    loop {
+        let after_whitespace = skip_html_whitespace(bytes, index);
+        match bytes.get(after_whitespace)? {
+            b'>' => return Some(after_whitespace + 1),
+            b'/' => {
+                return (bytes.get(after_whitespace + 1) == Some(&b'>'))
+                    .then_some(after_whitespace + 2);
+            }
+            _ if after_whitespace == index => return None,
+            _ => index = scan_attribute(bytes, after_whitespace)?,
+        }
+    }
+}
+
+fn scan_tag_name(bytes: &[u8], start: usize) -> Option<usize> {
+    if !bytes.get(start)?.is_ascii_alphabetic() {
+        return None;
+    }
+
+    let mut index = start + 1;
+    while bytes
+        .get(index)
+        .is_some_and(|byte| byte.is_ascii_alphanumeric() || *byte == b'-')
+    {
+        index += 1;
+    }
+    Some(index)
+}
+
+fn scan_attribute(bytes: &[u8], start: usize) -> Option<usize> {
+    let first = *bytes.get(start)?;
+    if !(first.is_ascii_alphabetic() || matches!(first, b'_' | b':')) {
+        return None;
+    }
+
+    let mut index = start + 1;
+    while bytes.get(index).is_some_and(|byte| {
+        byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'.' | b':' | b'-')
+    }) {
+        index += 1;
+    }
+
+    let before_value = skip_html_whitespace(bytes, index);
+    if bytes.get(before_value) != Some(&b'=') {
+        return Some(index);
+    }
+
+    let value_start = skip_html_whitespace(bytes, before_value + 1);
+    match *bytes.get(value_start)? {
+        quote @ (b'"' | b'\'') => {
+            let length = bytes[value_start + 1..]
+                .iter()
+                .position(|byte| *byte == quote)?;
+            Some(value_start + length + 2)
+        }
+        _ => {
+            let mut index = value_start;
+            while bytes.get(index).is_some_and(|byte| {
+                !is_whitespace(*byte) && !matches!(byte, b'"' | b'\'' | b'=' | b'<' | b'>' | b'`')
+            }) {
+                index += 1;
+            }
+            (index > value_start).then_some(index)
+        }
+    }
+}
+
+fn skip_html_whitespace(bytes: &[u8], mut index: usize) -> usize {
+    while bytes.get(index).copied().is_some_and(is_whitespace) {
+        index += 1;
+    }
+    index
+}
+
+/// Scans a [link destination](https://spec.commonmark.org/0.31.2/#link-destination), either
+/// enclosed in angle brackets or made of non-whitespace characters with balanced parentheses.
+pub(crate) fn scan_link_destination(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+
+    if bytes.first() == Some(&b'<') {
+        let mut index = 1;
+        loop {
+            match *bytes.get(index)? {
+                b'>' => return Some(index + 1),
+                b'<' | b'\n' | b'\r' => return None,
+                _ if is_escape(&bytes[index..]) => index += 2,
+                _ => index += 1,
+            }
+        }
+    }
+
+    let mut index = 0;
+    let mut depth = 0usize;
+    while let Some(&byte) = bytes.get(index) {
+        match byte {
+            _ if is_escape(&bytes[index..]) => {
+                index += 2;
+                continue;
+            }
+            b'(' => {
+                depth += 1;
+                if depth > 32 {
+                    return None;
+                }
+            }
+            b')' if depth == 0 => break,
+            b')' => depth -= 1,
+            _ if byte <= b' ' || byte == 0x7f => break,
+            _ => {}
+        }
+        index += 1;
+    }
+
+    (index > 0 && depth == 0).then_some(index)
+}
+
+/// Scans a [link title](https://spec.commonmark.org/0.31.2/#link-title), enclosed in double
+/// quotes, single quotes or parentheses. A title can span multiple lines, but not blank ones.
+pub(crate) fn scan_link_title(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let closing = match bytes.first()? {
+        b'"' => b'"',
+        b'\'' => b'\'',
+        b'(' => b')',
+        _ => return None,
+    };
+
+    let mut index = 1;
+    loop {
+        match *bytes.get(index)? {
+            _ if is_escape(&bytes[index..]) => index += 2,
+            byte if byte == closing => return Some(index + 1),
+            b'(' if closing == b')' => return None,
+            byte if is_line_ending(byte) => {
+                index += 1;
+                if byte == b'\r' && bytes.get(index) == Some(&b'\n') {
+                    index += 1;
+                }
+                let rest = &bytes[index..];
+                let line_length = rest
+                    .iter()
+                    .position(|byte| is_line_ending(*byte))
+                    .unwrap_or(rest.len());
+                if rest[..line_length].iter().all(|byte| is_whitespace(*byte)) {
+                    return None;
+                }
+            }
+            _ => index += 1,
+        }
+    }
+}
+
+/// Scans a [link label](https://spec.commonmark.org/0.31.2/#link-label), including its brackets.
+pub(crate) fn scan_link_label(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    if bytes.first() != Some(&b'[') {
+        return None;
+    }
+
+    let mut index = 1;
+    let mut has_content = false;
+    loop {
+        if index > 1000 {
+            return None;
+        }
+
+        match *bytes.get(index)? {
+            b'[' => return None,
+            b']' => break,
+            _ if is_escape(&bytes[index..]) => {
+                has_content = true;
+                index += 2;
+            }
+            byte => {
+                has_content |= !is_whitespace(byte);
+                index += 1;
+            }
+        }
+    }
+
+    has_content.then_some(index + 1)
+}
+
+/// Normalizes a link label so that it can be matched against the labels of the link reference
+/// definitions: the label is case folded and its whitespace is collapsed.
+pub(crate) fn normalize_link_label(label: &str) -> String {
+    let collapsed = label.split_whitespace().collect::<Vec<_>>().join(" ");
+    // Lowercasing twice folds characters such as `ẞ` that are uppercased to multiple characters
+    collapsed
+        .to_lowercase_cow()
+        .to_uppercase()
+        .to_lowercase_cow()
+        .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn autolinks() {
+        assert_eq!(scan_autolink("<https://biomejs.dev> foo"), Some(21));
+        assert_eq!(scan_autolink("<foo@example.com>"), Some(17));
+        assert_eq!(scan_autolink("<https://biomejs.dev foo>"), None);
+        assert_eq!(scan_autolink("<m:abc>"), None);
+    }
+
+    #[test]
+    fn extended_autolinks() {
+        assert_eq!(scan_extended_autolink("www.commonmark.org/help."), Some(23));
+        assert_eq!(
+            scan_extended_autolink("https://example.com/a_(b))"),
+            Some(25)
+        );
+        assert_eq!(scan_extended_autolink("www.example.com/&hl;"), Some(16));
+        assert_eq!(scan_extended_autolink("www.example_site.com"), None);
+        assert_eq!(scan_extended_autolink("https://localhost"), None);
+    }
+
+    #[test]
+    fn inline_html() {
+        assert_eq!(scan_inline_html("<a href=\"foo\">bar"), Some(14));
+        assert_eq!(scan_inline_html("<img src=x />"), Some(13));
+        assert_eq!(scan_inline_html("</a >"), Some(5));
+        assert_eq!(scan_inline_html("<!-- comment -->"), Some(16));
+        assert_eq!(scan_inline_html("<33>"), None);
+        assert_eq!(scan_inline_html("<a href='bar'title=title>"), None);
+    }
+
+    #[test]
+    fn link_destinations_and_titles() {
+        assert_eq!(scan_link_destination("foo(and(bar)))"), Some(13));
+        assert_eq!(scan_link_destination("<my url> \"title\""), Some(8));
+        assert_eq!(scan_link_destination("<foo\nbar>"), None);
+        assert_eq!(scan_link_title("\"title \\\" here\")"), Some(15));
+        assert_eq!(scan_link_title("'multi\nline'"), Some(12));
+        assert_eq!(scan_link_title("'blank\n\nline'"), None);
+    }
+
+    #[test]
+    fn link_labels() {
+        assert_eq!(scan_link_label("[foo]: /url"), Some(5));
+        assert_eq!(scan_link_label("[foo\\]]"), Some(7));
+        assert_eq!(scan_link_label("[ ]"), None);
+        assert_eq!(scan_link_label("[foo[bar]]"), None);
+        assert_eq!(normalize_link_label(" Foo \n BAR "), "foo bar");
+        assert_eq!(normalize_link_label("ẞ"), normalize_link_label("SS"));
+    }
+}
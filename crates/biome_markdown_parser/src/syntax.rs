@@ -1,74 +1,195 @@
+pub mod code_block;
+pub mod header;
+pub mod html_block;
+pub mod inline;
+pub mod line;
+pub mod link_reference_definition;
+pub mod list;
+pub mod paragraph;
+pub mod quote;
+pub mod table;
 pub mod thematic_break_block;
 
 use biome_markdown_syntax::{T, kind::MarkdownSyntaxKind::*};
-use biome_parser::{
-    Parser,
-    prelude::ParsedSyntax::{self, *},
+use biome_parser::token_source::BumpWithContext;
+use biome_parser::{Parser, prelude::ParsedSyntax};
+use code_block::{
+    at_fenced_code_block, at_indent_code_block, parse_fenced_code_block, parse_indent_code_block,
 };
+use header::{at_header, parse_header};
+use html_block::{at_html_block, parse_html_block};
+use line::{Container, Line, LinePrefix, column_at, match_containers};
+use link_reference_definition::{
+    at_link_reference_definition, collect_link_labels, parse_link_reference_definition,
+};
+use list::{at_list, parse_list};
+use paragraph::parse_paragraph;
+use quote::{at_quote, parse_quote};
+use table::{at_table, parse_table};
 use thematic_break_block::{at_thematic_break_block, parse_thematic_break_block};
 
+use biome_rowan::TextSize;
+
 use crate::MarkdownParser;
+use crate::lexer::MarkdownLexContext;
 
 pub(crate) fn parse_document(p: &mut MarkdownParser) {
+    p.state_mut().link_labels = collect_link_labels(p.source_text());
+
     let m = p.start();
-    let _ = parse_block_list(p);
+    p.eat(UNICODE_BOM);
+    parse_block_list(p);
+    p.expect(T![EOF]);
     m.complete(p, MD_DOCUMENT);
 }
 
-pub(crate) fn parse_block_list(p: &mut MarkdownParser) -> ParsedSyntax {
+/// Parses the blocks of the innermost open container, or of the document, up to the first line
+/// that doesn't continue the container.
+pub(crate) fn parse_block_list(p: &mut MarkdownParser) {
+    let depth = p.state().containers.len();
     let m = p.start();
 
     while !p.at(T![EOF]) {
-        parse_any_block(p);
+        if let Some(prefix) = line_prefix(p) {
+            if prefix.matched < depth {
+                break;
+            }
+
+            consume_line_prefix(p, &prefix);
+            continue;
+        }
+
+        let _ = parse_any_block(p);
+
+        if let Some(Container::ListItem { is_empty, .. }) = p.state_mut().containers.last_mut() {
+            *is_empty = false;
+        }
     }
-    Present(m.complete(p, MD_BLOCK_LIST))
+
+    m.complete(p, MD_BLOCK_LIST);
 }
 
-pub(crate) fn parse_any_block(p: &mut MarkdownParser) {
+/// Parses the block that starts at the current token.
+pub(crate) fn parse_any_block(p: &mut MarkdownParser) -> ParsedSyntax {
     if at_indent_code_block(p) {
-        parse_indent_code_block(p);
+        parse_indent_code_block(p)
     } else if at_thematic_break_block(p) {
-        let break_block = try_parse(p, |p| {
-            let break_block = parse_thematic_break_block(p);
-            if break_block.is_absent() {
-                return Err(());
-            }
-            Ok(break_block)
-        });
-        if break_block.is_err() {
-            parse_paragraph(p);
-        }
+        parse_thematic_break_block(p)
+    } else if at_header(p) {
+        parse_header(p)
+    } else if at_fenced_code_block(p) {
+        parse_fenced_code_block(p)
+    } else if at_html_block(p) {
+        parse_html_block(p)
+    } else if at_quote(p) {
+        parse_quote(p)
+    } else if at_list(p) {
+        parse_list(p)
+    } else if at_link_reference_definition(p) {
+        parse_link_reference_definition(p)
+    } else if at_table(p) {
+        parse_table(p)
+    } else {
+        parse_paragraph(p)
     }
 }
 
-pub(crate) fn at_indent_code_block(p: &mut MarkdownParser) -> bool {
-    p.before_whitespace_count() > 4
+/// Returns the offset of the current token
+pub(crate) fn cur_offset(p: &MarkdownParser) -> usize {
+    usize::from(p.cur_range().start())
+}
+
+/// Converts an offset in the source text to a [TextSize]
+pub(crate) fn text_size(offset: usize) -> TextSize {
+    TextSize::try_from(offset).expect("Input to be smaller than 4 GB")
 }
 
-pub(crate) fn parse_indent_code_block(_p: &mut MarkdownParser) {
-    todo!()
+/// Returns the text from the current token to the end of its line
+pub(crate) fn cur_line_text<'source>(p: &MarkdownParser<'source>) -> &'source str {
+    let source = p.source_text();
+    let offset = cur_offset(p);
+    &source[offset..Line::at(source, offset).end]
 }
 
-pub(crate) fn parse_paragraph(_p: &mut MarkdownParser) {
-    todo!()
+/// Returns the indentation of the current token, relative to the content of the open containers
+pub(crate) fn cur_indent(p: &MarkdownParser) -> usize {
+    let source = p.source_text();
+    let offset = cur_offset(p);
+    column_at(source, Line::at(source, offset).start, offset)
+        .saturating_sub(p.state().content_column)
 }
 
-/// Attempt to parse some input with the given parsing function. If parsing
-/// succeeds, `Ok` is returned with the result of the parse and the state is
-/// preserved. If parsing fails, this function rewinds the parser back to
-/// where it was before attempting the parse and the `Err` value is returned.
-#[must_use = "The result of try_parse contains information about whether the parse succeeded and should not be ignored"]
-pub(crate) fn try_parse<T, E>(
+/// Matches the line of the current token against the open containers. Returns `None` if the
+/// container markers of the line have already been consumed, because the current token isn't
+/// the first one of its line.
+pub(crate) fn line_prefix(p: &MarkdownParser) -> Option<LinePrefix> {
+    let source = p.source_text();
+    let line = Line::at(source, cur_offset(p));
+    if p.state().matched_line == Some(line.start) {
+        return None;
+    }
+
+    let containers = &p.state().containers;
+    let mut prefix = match_containers(source, line, containers);
+    if prefix.follows_blank_line {
+        // A blank line closes the block quotes, because it doesn't start with `>`, and the
+        // list items that don't contain any block
+        let open = containers
+            .iter()
+            .take_while(|container| {
+                matches!(
+                    container,
+                    Container::ListItem {
+                        is_empty: false,
+                        ..
+                    }
+                )
+            })
+            .count();
+        prefix.matched = prefix.matched.min(open);
+        prefix.quote_markers.clear();
+    }
+
+    Some(prefix)
+}
+
+/// Consumes the `>` markers of the block quotes continued by the line of `prefix` as trivia.
+pub(crate) fn consume_line_prefix(p: &mut MarkdownParser, prefix: &LinePrefix) {
+    for &marker in &prefix.quote_markers {
+        if cur_offset(p) != marker {
+            break;
+        }
+        p.source_mut()
+            .skip_as_trivia_with_context(MarkdownLexContext::Regular);
+    }
+
+    let state = p.state_mut();
+    state.matched_line = Some(prefix.line.start);
+    state.content_column = prefix.content_column;
+}
+
+/// Moves to the next line that continues the open containers and that satisfies `predicate`,
+/// consuming its container markers. The lines that are blank after their markers are skipped.
+///
+/// Returns the prefix of the line, or `None` if the document ends or if the next line closes a
+/// container or doesn't satisfy `predicate`.
+pub(crate) fn next_continuation_line(
     p: &mut MarkdownParser,
-    func: impl FnOnce(&mut MarkdownParser) -> Result<T, E>,
-) -> Result<T, E> {
-    let checkpoint = p.checkpoint();
+    predicate: impl Fn(&LinePrefix) -> bool,
+) -> Option<LinePrefix> {
+    let depth = p.state().containers.len();
 
-    let res = func(p);
+    while !p.at(T![EOF]) {
+        let prefix = line_prefix(p)?;
+        if prefix.matched < depth || !predicate(&prefix) {
+            return None;
+        }
 
-    if res.is_err() {
-        p.rewind(checkpoint);
+        consume_line_prefix(p, &prefix);
+        if !prefix.is_blank {
+            return Some(prefix);
+        }
     }
 
-    res
+    None
 }
@@ -0,0 +1,121 @@
+use crate::lexer::MarkdownReLexContext;
+use crate::parser::MarkdownParser;
+use crate::syntax::line::{Line, column_at};
+use crate::syntax::{cur_indent, cur_line_text, cur_offset, next_continuation_line, text_size};
+use biome_markdown_syntax::{T, TextSize, kind::MarkdownSyntaxKind::*};
+use biome_parser::{
+    Parser,
+    prelude::ParsedSyntax::{self, *},
+};
+
+/// Returns `true` if the current line is indented by four or more columns, which starts an
+/// [indented code block](https://spec.commonmark.org/0.31.2/#indented-code-blocks).
+pub(crate) fn at_indent_code_block(p: &mut MarkdownParser) -> bool {
+    cur_indent(p) >= 4
+}
+
+pub(crate) fn parse_indent_code_block(p: &mut MarkdownParser) -> ParsedSyntax {
+    if !at_indent_code_block(p) {
+        return Absent;
+    }
+    let m = p.start();
+    let content = p.start();
+
+    let column = p.state().content_column + 4;
+    parse_code_line(p, column);
+    while next_continuation_line(p, |prefix| prefix.is_blank || prefix.indent >= 4).is_some() {
+        parse_code_line(p, column);
+    }
+
+    content.complete(p, MD_INLINE_ITEM_LIST);
+    Present(m.complete(p, MD_INDENT_CODE_BLOCK))
+}
+
+/// Parses the rest of the current line as a single textual node. Its indentation from `column`
+/// is part of the content, so it's included in the node rather than in the trivia.
+pub(crate) fn parse_code_line(p: &mut MarkdownParser, column: usize) {
+    let m = p.start();
+    p.re_lex_from(code_line_start(p, column), MarkdownReLexContext::CodeLine);
+    p.bump_remap(MD_TEXTUAL_LITERAL);
+    m.complete(p, MD_TEXTUAL);
+}
+
+/// Returns the offset of the first space or tab of the indentation of the current token that is
+/// at or after `column`, or the offset of the current token if it's before `column`.
+fn code_line_start(p: &MarkdownParser, column: usize) -> TextSize {
+    let source = p.source_text();
+    let offset = cur_offset(p);
+    let line_start = Line::at(source, offset).start;
+
+    let mut start = offset;
+    while start > line_start
+        && matches!(source.as_bytes()[start - 1], b' ' | b'\t')
+        && column_at(source, line_start, start - 1) >= column
+    {
+        start -= 1;
+    }
+
+    text_size(start)
+}
+
+/// Returns the character and the length of the
+/// [code fence](https://spec.commonmark.org/0.31.2/#code-fence) at the start of `text`.
+pub(crate) fn scan_code_fence(text: &str) -> Option<(u8, usize)> {
+    let char = *text.as_bytes().first()?;
+    if !matches!(char, b'`' | b'~') {
+        return None;
+    }
+
+    let length = text.bytes().take_while(|byte| *byte == char).count();
+    // The info string of a backtick fence can't contain backticks
+    let is_valid_info = char == b'~' || !text[length..].contains('`');
+
+    (length >= 3 && is_valid_info).then_some((char, length))
+}
+
+/// Returns `true` if `text` closes a code fence of `length` `char` characters
+fn is_closing_fence(text: &str, char: u8, length: usize) -> bool {
+    let fence = text.bytes().take_while(|byte| *byte == char).count();
+    fence >= length && text[fence..].trim_matches([' ', '\t']).is_empty()
+}
+
+pub(crate) fn at_fenced_code_block(p: &mut MarkdownParser) -> bool {
+    scan_code_fence(cur_line_text(p)).is_some()
+}
+
+pub(crate) fn parse_fenced_code_block(p: &mut MarkdownParser) -> ParsedSyntax {
+    let Some((char, length)) = scan_code_fence(cur_line_text(p)) else {
+        return Absent;
+    };
+    // The indentation of the fence is removed from the lines of the content
+    let column = p.state().content_column + cur_indent(p);
+    let m = p.start();
+
+    p.re_lex(MarkdownReLexContext::CodeFence);
+    p.expect(MD_CODE_FENCE_LITERAL);
+
+    if !p.has_preceding_line_break() && !p.at(T![EOF]) {
+        let info = p.start();
+        p.re_lex(MarkdownReLexContext::FenceInfo);
+        p.bump_remap(MD_TEXTUAL_LITERAL);
+        info.complete(p, MD_TEXTUAL);
+    }
+
+    let content = p.start();
+    let mut is_closed = false;
+    while next_continuation_line(p, |_| true).is_some() {
+        if cur_indent(p) < 4 && is_closing_fence(cur_line_text(p), char, length) {
+            is_closed = true;
+            break;
+        }
+        parse_code_line(p, column);
+    }
+    content.complete(p, MD_INLINE_ITEM_LIST);
+
+    if is_closed {
+        p.re_lex(MarkdownReLexContext::CodeFence);
+        p.expect(MD_CODE_FENCE_LITERAL);
+    }
+
+    Present(m.complete(p, MD_FENCED_CODE_BLOCK))
+}
@@ -0,0 +1,104 @@
+use crate::lexer::{MarkdownLexContext, MarkdownReLexContext};
+use crate::parser::MarkdownParser;
+use crate::syntax::inline::parse_inline_item_list;
+use crate::syntax::{cur_line_text, cur_offset, text_size};
+use biome_markdown_syntax::{T, kind::MarkdownSyntaxKind::*};
+use biome_parser::{
+    Parser,
+    prelude::ParsedSyntax::{self, *},
+};
+use biome_rowan::TextRange;
+
+/// Returns the level of the [ATX heading](https://spec.commonmark.org/0.31.2/#atx-headings) at
+/// the start of `text`: the number of its opening `#` characters.
+pub(crate) fn atx_heading_level(text: &str) -> Option<usize> {
+    let level = text.bytes().take_while(|byte| *byte == b'#').count();
+    let is_followed_by_space = text
+        .as_bytes()
+        .get(level)
+        .is_none_or(|byte| matches!(byte, b' ' | b'\t'));
+
+    ((1..=6).contains(&level) && is_followed_by_space).then_some(level)
+}
+
+/// The parts of an ATX heading, as offsets in its text
+struct AtxHeading {
+    level: usize,
+    content_start: usize,
+    content_end: usize,
+}
+
+fn scan_atx_heading(text: &str) -> Option<AtxHeading> {
+    let level = atx_heading_level(text)?;
+    let trimmed = text.trim_end_matches([' ', '\t']);
+    let rest = &trimmed[level..];
+    let content_start = level + (rest.len() - rest.trim_start_matches([' ', '\t']).len());
+
+    // The optional closing sequence must be preceded by a space or a tab
+    let without_closing = rest.trim_end_matches('#');
+    let content_end = if without_closing.len() < rest.len()
+        && (without_closing.is_empty() || without_closing.ends_with([' ', '\t']))
+    {
+        level + without_closing.trim_end_matches([' ', '\t']).len()
+    } else {
+        trimmed.len()
+    };
+
+    Some(AtxHeading {
+        level,
+        content_start,
+        content_end: content_end.max(content_start),
+    })
+}
+
+/// Returns `true` if `text` is the underline of a
+/// [setext heading](https://spec.commonmark.org/0.31.2/#setext-headings).
+pub(crate) fn is_setext_underline(text: &str) -> bool {
+    let underline = text.trim_end_matches([' ', '\t']);
+    underline.starts_with(['=', '-'])
+        && underline
+            .bytes()
+            .all(|byte| byte == underline.as_bytes()[0])
+}
+
+pub(crate) fn at_header(p: &mut MarkdownParser) -> bool {
+    atx_heading_level(cur_line_text(p)).is_some()
+}
+
+pub(crate) fn parse_header(p: &mut MarkdownParser) -> ParsedSyntax {
+    let Some(heading) = scan_atx_heading(cur_line_text(p)) else {
+        return Absent;
+    };
+    let start = cur_offset(p);
+    let m = p.start();
+
+    let before = p.start();
+    for _ in 0..heading.level {
+        parse_hash(p);
+    }
+    before.complete(p, MD_HASH_LIST);
+
+    let content = TextRange::new(
+        text_size(start + heading.content_start),
+        text_size(start + heading.content_end),
+    );
+    parse_inline_item_list(p, vec![content], MarkdownLexContext::Regular);
+
+    let after = p.start();
+    while !p.at(T![EOF])
+        && !p.has_preceding_line_break()
+        && p.re_lex(MarkdownReLexContext::Hash) == T![#]
+    {
+        parse_hash(p);
+    }
+    after.complete(p, MD_HASH_LIST);
+
+    Present(m.complete(p, MD_HEADER))
+}
+
+fn parse_hash(p: &mut MarkdownParser) {
+    let m = p.start();
+    p.re_lex(MarkdownReLexContext::Hash);
+    p.expect(T![#]);
+    m.complete(p, MD_HASH);
+}
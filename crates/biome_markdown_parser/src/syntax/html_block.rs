@@ -0,0 +1,194 @@
+use crate::parser::MarkdownParser;
+use crate::scanner::scan_inline_html;
+use crate::syntax::code_block::parse_code_line;
+use crate::syntax::line::is_blank;
+use crate::syntax::{cur_line_text, next_continuation_line};
+use biome_markdown_syntax::kind::MarkdownSyntaxKind::*;
+use biome_parser::{
+    Parser,
+    prelude::ParsedSyntax::{self, *},
+};
+use biome_string_case::StrLikeExtension;
+
+/// The tag names that start an HTML block of the sixth kind
+const BLOCK_TAG_NAMES: [&str; 62] = [
+    "address",
+    "article",
+    "aside",
+    "base",
+    "basefont",
+    "blockquote",
+    "body",
+    "caption",
+    "center",
+    "col",
+    "colgroup",
+    "dd",
+    "details",
+    "dialog",
+    "dir",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "frame",
+    "frameset",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "head",
+    "header",
+    "hr",
+    "html",
+    "iframe",
+    "legend",
+    "li",
+    "link",
+    "main",
+    "menu",
+    "menuitem",
+    "nav",
+    "noframes",
+    "ol",
+    "optgroup",
+    "option",
+    "p",
+    "param",
+    "search",
+    "section",
+    "summary",
+    "table",
+    "tbody",
+    "td",
+    "tfoot",
+    "th",
+    "thead",
+    "title",
+    "tr",
+    "track",
+    "ul",
+];
+
+/// The tag names of the HTML blocks of the first kind, which can contain blank lines
+const RAW_TAG_NAMES: [&str; 4] = ["pre", "script", "style", "textarea"];
+
+/// Returns the kind of the [HTML block](https://spec.commonmark.org/0.31.2/#html-blocks) that
+/// starts at the start of `text`, from 1 to 7, following the start conditions of the
+/// specification.
+pub(crate) fn html_block_kind(text: &str) -> Option<u8> {
+    let bytes = text.as_bytes();
+    if bytes.first() != Some(&b'<') {
+        return None;
+    }
+
+    let starts_with = |prefix: &str| {
+        bytes
+            .get(..prefix.len())
+            .is_some_and(|start| start.eq_ignore_ascii_case(prefix.as_bytes()))
+    };
+    let is_tag_name_end = |index: usize| {
+        bytes
+            .get(index)
+            .is_none_or(|byte| matches!(byte, b' ' | b'\t' | b'>'))
+    };
+
+    if RAW_TAG_NAMES
+        .iter()
+        .any(|name| starts_with(&format!("<{name}")) && is_tag_name_end(name.len() + 1))
+    {
+        return Some(1);
+    }
+    if starts_with("<!--") {
+        return Some(2);
+    }
+    if starts_with("<?") {
+        return Some(3);
+    }
+    if starts_with("<![CDATA[") {
+        return Some(5);
+    }
+    if starts_with("<!") && bytes.get(2).is_some_and(u8::is_ascii_alphabetic) {
+        return Some(4);
+    }
+
+    let name_start = if bytes.get(1) == Some(&b'/') { 2 } else { 1 };
+    let name_length = bytes[name_start..]
+        .iter()
+        .take_while(|byte| byte.is_ascii_alphanumeric())
+        .count();
+    let name = &text[name_start..name_start + name_length];
+    let name_end = name_start + name_length;
+    if BLOCK_TAG_NAMES
+        .iter()
+        .any(|block_name| block_name.eq_ignore_ascii_case(name))
+        && (is_tag_name_end(name_end) || text[name_end..].starts_with("/>"))
+    {
+        return Some(6);
+    }
+
+    let is_raw_tag = RAW_TAG_NAMES
+        .iter()
+        .any(|raw_name| raw_name.eq_ignore_ascii_case(name));
+    let is_tag = bytes.get(name_start).is_some_and(u8::is_ascii_alphabetic);
+    match scan_inline_html(text) {
+        Some(length) if is_tag && !is_raw_tag && is_blank(&text[length..]) => Some(7),
+        _ => None,
+    }
+}
+
+/// Returns `true` if `text` contains the end condition of an HTML block of the given `kind`.
+/// The blocks of the sixth and seventh kinds end at a blank line instead.
+fn contains_end_condition(kind: u8, text: &str) -> bool {
+    match kind {
+        1 => {
+            let text = text.to_ascii_lowercase_cow();
+            RAW_TAG_NAMES
+                .iter()
+                .any(|name| text.contains(&format!("</{name}>")))
+        }
+        2 => text.contains("-->"),
+        3 => text.contains("?>"),
+        4 => text.contains('>'),
+        5 => text.contains("]]>"),
+        _ => false,
+    }
+}
+
+pub(crate) fn at_html_block(p: &mut MarkdownParser) -> bool {
+    html_block_kind(cur_line_text(p)).is_some()
+}
+
+pub(crate) fn parse_html_block(p: &mut MarkdownParser) -> ParsedSyntax {
+    let Some(kind) = html_block_kind(cur_line_text(p)) else {
+        return Absent;
+    };
+    let column = p.state().content_column;
+    let m = p.start();
+    let content = p.start();
+
+    // The end condition can be on the first line, after the start condition
+    let mut is_closed = contains_end_condition(kind, &cur_line_text(p)[2..]);
+    parse_code_line(p, column);
+
+    // The blank lines that end the blocks of the sixth and seventh kinds are trivia, so the
+    // blocks end at the first line that follows one
+    while !is_closed
+        && next_continuation_line(p, |prefix| {
+            kind <= 5 || !(prefix.is_blank || prefix.follows_blank_line)
+        })
+        .is_some()
+    {
+        is_closed = contains_end_condition(kind, cur_line_text(p));
+        parse_code_line(p, column);
+    }
+
+    content.complete(p, MD_INLINE_ITEM_LIST);
+    Present(m.complete(p, MD_HTML_BLOCK))
+}
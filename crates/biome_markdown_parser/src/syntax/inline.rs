@@ -0,0 +1,252 @@
+//! Parses the [inlines](https://spec.commonmark.org/0.31.2/#inlines) of a block.
+
+mod analysis;
+
+use analysis::{EmphasisKind, Inline, Inlines, LinkTail, analyze};
+use biome_markdown_syntax::{MarkdownSyntaxKind, T, kind::MarkdownSyntaxKind::*};
+use biome_parser::Parser;
+use biome_parser::token_source::BumpWithContext;
+use biome_rowan::{TextRange, TextSize};
+
+use crate::MarkdownParser;
+use crate::lexer::{MarkdownLexContext, MarkdownReLexContext};
+
+/// Parses the inlines of the text made of `segments`, the ranges of the text of the block on
+/// each of its lines. The tokens between the segments, such as the `>` markers of block quotes,
+/// are skipped as trivia.
+pub(crate) fn parse_inline_item_list(
+    p: &mut MarkdownParser,
+    segments: Vec<TextRange>,
+    context: MarkdownLexContext,
+) {
+    let inlines = analyze(p.source_text(), segments, &p.state().link_labels);
+    let end = inlines.end();
+    parse_inlines(p, &inlines, end, context);
+}
+
+/// Parses the inlines up to `end` into a list
+fn parse_inlines(
+    p: &mut MarkdownParser,
+    inlines: &Inlines,
+    end: TextSize,
+    context: MarkdownLexContext,
+) {
+    let m = p.start();
+
+    loop {
+        skip_between_lines(p, inlines, context);
+        let start = p.cur_range().start();
+        if p.at(T![EOF]) || start >= end {
+            break;
+        }
+
+        match inlines.get(start) {
+            Some(Inline::CodeSpan { closing }) => parse_code_span(p, inlines, closing, context),
+            Some(Inline::Emphasis {
+                kind,
+                length,
+                closing,
+            }) => parse_emphasis(p, inlines, kind, length, closing, context),
+            Some(Inline::Link {
+                image,
+                text_end,
+                tail,
+            }) => parse_link(p, inlines, image, text_end, tail, context),
+            Some(Inline::Autolink) => {
+                parse_single_token(p, MarkdownReLexContext::Autolink, MD_AUTOLINK, context)
+            }
+            Some(Inline::ExtendedAutolink) => parse_single_token(
+                p,
+                MarkdownReLexContext::ExtendedAutolink,
+                MD_AUTOLINK,
+                context,
+            ),
+            Some(Inline::Html) => {
+                parse_single_token(p, MarkdownReLexContext::InlineHtml, MD_INLINE_HTML, context)
+            }
+            None => parse_textual(p, context),
+        }
+    }
+
+    m.complete(p, MD_INLINE_ITEM_LIST);
+}
+
+/// Skips the tokens between two lines of the block as trivia
+fn skip_between_lines(p: &mut MarkdownParser, inlines: &Inlines, context: MarkdownLexContext) {
+    while !p.at(T![EOF]) && inlines.is_between_lines(p.cur_range().start()) {
+        p.source_mut().skip_as_trivia_with_context(context);
+    }
+}
+
+fn parse_textual(p: &mut MarkdownParser, context: MarkdownLexContext) {
+    let m = p.start();
+    if p.at(MD_HARD_LINE_LITERAL) {
+        p.bump_with_context(MD_HARD_LINE_LITERAL, context);
+        m.complete(p, MD_HARD_LINE);
+    } else {
+        p.bump_remap_with_context(MD_TEXTUAL_LITERAL, context);
+        m.complete(p, MD_TEXTUAL);
+    }
+}
+
+fn parse_single_token(
+    p: &mut MarkdownParser,
+    re_lex_context: MarkdownReLexContext,
+    kind: MarkdownSyntaxKind,
+    context: MarkdownLexContext,
+) {
+    let m = p.start();
+    let token = p.re_lex(re_lex_context);
+    p.bump_with_context(token, context);
+    m.complete(p, kind);
+}
+
+fn parse_code_span(
+    p: &mut MarkdownParser,
+    inlines: &Inlines,
+    closing: TextSize,
+    context: MarkdownLexContext,
+) {
+    let m = p.start();
+    p.re_lex(MarkdownReLexContext::BacktickRun);
+    p.expect_with_context(MD_BACKTICK_RUN_LITERAL, MarkdownLexContext::CodeSpan);
+
+    let content = p.start();
+    loop {
+        skip_between_lines(p, inlines, MarkdownLexContext::CodeSpan);
+        if p.at(T![EOF]) || p.cur_range().start() >= closing {
+            break;
+        }
+        let text = p.start();
+        p.bump_remap_with_context(MD_TEXTUAL_LITERAL, MarkdownLexContext::CodeSpan);
+        text.complete(p, MD_TEXTUAL);
+    }
+    content.complete(p, MD_INLINE_ITEM_LIST);
+
+    p.re_lex(MarkdownReLexContext::BacktickRun);
+    p.expect_with_context(MD_BACKTICK_RUN_LITERAL, context);
+    m.complete(p, MD_INLINE_CODE);
+}
+
+fn parse_emphasis(
+    p: &mut MarkdownParser,
+    inlines: &Inlines,
+    kind: EmphasisKind,
+    length: usize,
+    closing: TextSize,
+    context: MarkdownLexContext,
+) {
+    let re_lex_context = if length == 1 {
+        MarkdownReLexContext::Emphasis
+    } else {
+        MarkdownReLexContext::StrongEmphasis
+    };
+
+    let m = p.start();
+    let delimiter = p.re_lex(re_lex_context);
+    p.bump_with_context(delimiter, context);
+
+    parse_inlines(p, inlines, closing, context);
+
+    let delimiter = p.re_lex(re_lex_context);
+    p.bump_with_context(delimiter, context);
+
+    let kind = match kind {
+        EmphasisKind::Italic => MD_INLINE_ITALIC,
+        EmphasisKind::Strong => MD_INLINE_EMPHASIS,
+        EmphasisKind::Strikethrough => MD_INLINE_STRIKETHROUGH,
+    };
+    m.complete(p, kind);
+}
+
+fn parse_link(
+    p: &mut MarkdownParser,
+    inlines: &Inlines,
+    image: bool,
+    text_end: TextSize,
+    tail: LinkTail,
+    context: MarkdownLexContext,
+) {
+    let m = p.start();
+    if image {
+        expect_punctuation(p, T![!], context);
+    }
+    expect_punctuation(p, T!['['], context);
+    parse_inlines(p, inlines, text_end, context);
+    expect_punctuation(p, T![']'], context);
+
+    let kind = match tail {
+        LinkTail::Inline { destination, title } => {
+            expect_punctuation(p, T!['('], context);
+            if destination {
+                skip_between_lines(p, inlines, context);
+                parse_link_part(
+                    p,
+                    MarkdownReLexContext::LinkDestination,
+                    MD_LINK_DESTINATION,
+                    context,
+                );
+            }
+            if title {
+                skip_between_lines(p, inlines, context);
+                parse_link_part(p, MarkdownReLexContext::LinkTitle, MD_LINK_TITLE, context);
+            }
+            skip_between_lines(p, inlines, context);
+            expect_punctuation(p, T![')'], context);
+
+            if image {
+                MD_INLINE_IMAGE
+            } else {
+                MD_INLINE_LINK
+            }
+        }
+        LinkTail::Reference { label } => {
+            let label_marker = p.start();
+            expect_punctuation(p, T!['['], context);
+            if label {
+                skip_between_lines(p, inlines, context);
+                parse_link_part(p, MarkdownReLexContext::LinkLabel, MD_TEXTUAL, context);
+                skip_between_lines(p, inlines, context);
+            }
+            expect_punctuation(p, T![']'], context);
+            label_marker.complete(p, MD_REFERENCE_LINK_LABEL);
+
+            if image {
+                MD_REFERENCE_IMAGE
+            } else {
+                MD_REFERENCE_LINK
+            }
+        }
+        LinkTail::Shortcut => {
+            if image {
+                MD_REFERENCE_IMAGE
+            } else {
+                MD_REFERENCE_LINK
+            }
+        }
+    };
+
+    m.complete(p, kind);
+}
+
+/// Parses a destination, title or label of a link, which is a single textual token
+fn parse_link_part(
+    p: &mut MarkdownParser,
+    re_lex_context: MarkdownReLexContext,
+    kind: MarkdownSyntaxKind,
+    context: MarkdownLexContext,
+) {
+    let m = p.start();
+    p.re_lex(re_lex_context);
+    p.expect_with_context(MD_TEXTUAL_LITERAL, context);
+    m.complete(p, kind);
+}
+
+fn expect_punctuation(
+    p: &mut MarkdownParser,
+    kind: MarkdownSyntaxKind,
+    context: MarkdownLexContext,
+) {
+    p.re_lex(MarkdownReLexContext::Punctuation);
+    p.expect_with_context(kind, context);
+}
@@ -0,0 +1,638 @@
+//! Finds the structure of the inlines of a block before they're parsed.
+//!
+//! The inlines of Markdown can't be parsed with a single token of lookahead: whether a `*` opens
+//! an emphasis depends on the delimiters that follow it, and whether a `[` opens a link depends on
+//! the text after the matching `]`. The analysis implements the
+//! [algorithm of the specification](https://spec.commonmark.org/0.31.2/#phase-2-inline-structure)
+//! on the text of the block and records the inlines that start at each offset, which the parser
+//! then parses in a single pass.
+
+use crate::scanner::{
+    is_escape, normalize_link_label, scan_autolink, scan_extended_autolink, scan_inline_html,
+    scan_link_destination, scan_link_label, scan_link_title,
+};
+use crate::syntax::text_size;
+use biome_rowan::{TextRange, TextSize};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum EmphasisKind {
+    /// `*italic*` or `_italic_`
+    Italic,
+    /// `**strong**` or `__strong__`
+    Strong,
+    /// `~deleted~` or `~~deleted~~`
+    Strikethrough,
+}
+
+/// The part of a link that follows its text
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum LinkTail {
+    /// `(destination "title")`, where the destination and the title are optional
+    Inline { destination: bool, title: bool },
+    /// `[label]`, or `[]` if `label` is `false`
+    Reference { label: bool },
+    /// Nothing, the text of the link is its label
+    Shortcut,
+}
+
+/// An inline that starts at a given offset
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum Inline {
+    /// A code span, closed by the backticks at `closing`
+    CodeSpan { closing: TextSize },
+    /// An emphasis opened by `length` delimiters, and closed by those at `closing`
+    Emphasis {
+        kind: EmphasisKind,
+        length: usize,
+        closing: TextSize,
+    },
+    /// A link or an image whose text is closed by the `]` at `text_end`
+    Link {
+        image: bool,
+        text_end: TextSize,
+        tail: LinkTail,
+    },
+    /// An autolink enclosed in angle brackets
+    Autolink,
+    /// An extended autolink of GitHub Flavored Markdown
+    ExtendedAutolink,
+    /// Raw HTML
+    Html,
+}
+
+/// The inlines of a block
+#[derive(Debug)]
+pub(crate) struct Inlines {
+    /// The ranges of the text of the block on each of its lines, without the container markers
+    /// and the indentation
+    segments: Vec<TextRange>,
+    inlines: FxHashMap<TextSize, Inline>,
+}
+
+impl Inlines {
+    /// Returns the inline that starts at `offset`, if any
+    pub(crate) fn get(&self, offset: TextSize) -> Option<Inline> {
+        self.inlines.get(&offset).copied()
+    }
+
+    /// Returns the end of the text of the block
+    pub(crate) fn end(&self) -> TextSize {
+        self.segments
+            .last()
+            .map_or(TextSize::default(), |segment| segment.end())
+    }
+
+    /// Returns `true` if `offset` is between two lines of the block, in the container markers or
+    /// the indentation of a line.
+    pub(crate) fn is_between_lines(&self, offset: TextSize) -> bool {
+        let index = self
+            .segments
+            .partition_point(|segment| segment.end() <= offset);
+        index > 0
+            && self
+                .segments
+                .get(index)
+                .is_some_and(|segment| offset < segment.start())
+    }
+}
+
+/// Finds the inlines of the text made of `segments`. The `link_labels` are the normalized labels
+/// of the link reference definitions of the document.
+pub(crate) fn analyze(
+    source: &str,
+    segments: Vec<TextRange>,
+    link_labels: &FxHashSet<String>,
+) -> Inlines {
+    let mut analyzer = Analyzer {
+        source,
+        segments: segments
+            .iter()
+            .map(|segment| (usize::from(segment.start()), usize::from(segment.end())))
+            .collect(),
+        link_labels,
+        inlines: FxHashMap::default(),
+        delimiters: Vec::new(),
+        brackets: Vec::new(),
+        next_delimiter_id: 0,
+    };
+    analyzer.run();
+
+    Inlines {
+        segments,
+        inlines: analyzer.inlines,
+    }
+}
+
+/// A run of `*`, `_` or `~` characters that can open or close an emphasis
+#[derive(Debug, Copy, Clone)]
+struct Delimiter {
+    /// Identifies the delimiter, the identifiers increase from the start of the text
+    id: usize,
+    char: u8,
+    /// The offset of the first character of the run that isn't used yet
+    start: usize,
+    /// The number of characters of the run that aren't used yet
+    count: usize,
+    /// The original length of the run
+    length: usize,
+    can_open: bool,
+    can_close: bool,
+}
+
+/// A `[` or a `![` that can open a link or an image
+#[derive(Debug, Copy, Clone)]
+struct Bracket {
+    start: usize,
+    image: bool,
+    /// The number of delimiters before the bracket
+    delimiters: usize,
+    /// Whether the bracket can still open a link. Links can't contain other links.
+    active: bool,
+}
+
+struct Analyzer<'a> {
+    source: &'a str,
+    segments: Vec<(usize, usize)>,
+    link_labels: &'a FxHashSet<String>,
+    inlines: FxHashMap<TextSize, Inline>,
+    delimiters: Vec<Delimiter>,
+    brackets: Vec<Bracket>,
+    next_delimiter_id: usize,
+}
+
+impl Analyzer<'_> {
+    fn run(&mut self) {
+        let mut index = 0;
+        let mut position = self.segments.first().map_or(0, |segment| segment.0);
+
+        while let Some(&(start, end)) = self.segments.get(index) {
+            if position >= end {
+                index += 1;
+                if let Some(&(next_start, _)) = self.segments.get(index) {
+                    position = next_start;
+                }
+                continue;
+            }
+
+            position = self.scan(position.max(start), end);
+            index = self.segment_index(position);
+        }
+
+        self.process_emphasis(0);
+    }
+
+    /// Scans the inline at `position`, in the segment that ends at `end`, and returns the
+    /// offset after it.
+    fn scan(&mut self, position: usize, end: usize) -> usize {
+        let bytes = self.source.as_bytes();
+        match bytes[position] {
+            b'\\' if is_escape(&bytes[position..end]) => position + 2,
+            b'`' => self.scan_code_span(position, end),
+            b'*' | b'_' | b'~' => self.scan_delimiter_run(position, end),
+            b'[' => {
+                self.push_bracket(position, false);
+                position + 1
+            }
+            b'!' if bytes[position + 1..end].first() == Some(&b'[') => {
+                self.push_bracket(position, true);
+                position + 2
+            }
+            b']' => self.scan_closing_bracket(position),
+            b'<' => self.scan_angle_bracket(position, end),
+            b'w' | b'W' | b'h' | b'H' if self.at_word_start(position) => {
+                match scan_extended_autolink(&self.source[position..end]) {
+                    Some(length) => {
+                        self.insert(position, Inline::ExtendedAutolink);
+                        position + length
+                    }
+                    None => position + 1,
+                }
+            }
+            _ => self.next_char(position),
+        }
+    }
+
+    fn scan_code_span(&mut self, position: usize, end: usize) -> usize {
+        let length = self.run_length(position, end);
+        let mut index = self.segment_index(position);
+        let mut cursor = position + length;
+
+        while let Some(&(start, end)) = self.segments.get(index) {
+            cursor = cursor.max(start);
+            while cursor < end {
+                if self.source.as_bytes()[cursor] == b'`' {
+                    let run = self.run_length(cursor, end);
+                    if run == length {
+                        self.insert(
+                            position,
+                            Inline::CodeSpan {
+                                closing: text_size(cursor),
+                            },
+                        );
+                        return cursor + length;
+                    }
+                    cursor += run;
+                } else {
+                    cursor += 1;
+                }
+            }
+            index += 1;
+        }
+
+        position + length
+    }
+
+    fn scan_delimiter_run(&mut self, position: usize, end: usize) -> usize {
+        let char = self.source.as_bytes()[position];
+        let length = self.run_length(position, end);
+        if char == b'~' && length > 2 {
+            return position + length;
+        }
+
+        let before = self.char_before(position);
+        let after = self.char_at(position + length);
+        let left_flanking = !after.is_whitespace()
+            && (!is_punctuation(after) || before.is_whitespace() || is_punctuation(before));
+        let right_flanking = !before.is_whitespace()
+            && (!is_punctuation(before) || after.is_whitespace() || is_punctuation(after));
+
+        let (can_open, can_close) = if char == b'_' {
+            (
+                left_flanking && (!right_flanking || is_punctuation(before)),
+                right_flanking && (!left_flanking || is_punctuation(after)),
+            )
+        } else {
+            (left_flanking, right_flanking)
+        };
+
+        if can_open || can_close {
+            self.delimiters.push(Delimiter {
+                id: self.next_delimiter_id,
+                char,
+                start: position,
+                count: length,
+                length,
+                can_open,
+                can_close,
+            });
+            self.next_delimiter_id += 1;
+        }
+
+        position + length
+    }
+
+    fn push_bracket(&mut self, position: usize, image: bool) {
+        self.brackets.push(Bracket {
+            start: position,
+            image,
+            delimiters: self.delimiters.len(),
+            active: true,
+        });
+    }
+
+    fn scan_closing_bracket(&mut self, position: usize) -> usize {
+        let Some(bracket) = self.brackets.pop() else {
+            return position + 1;
+        };
+        if !bracket.active {
+            return position + 1;
+        }
+
+        let text_start = bracket.start + if bracket.image { 2 } else { 1 };
+        let Some((tail, end)) = self.scan_link_tail(position + 1, text_start, position) else {
+            return position + 1;
+        };
+
+        self.insert(
+            bracket.start,
+            Inline::Link {
+                image: bracket.image,
+                text_end: text_size(position),
+                tail,
+            },
+        );
+        self.process_emphasis(bracket.delimiters);
+
+        if !bracket.image {
+            for bracket in &mut self.brackets {
+                if !bracket.image {
+                    bracket.active = false;
+                }
+            }
+        }
+
+        end
+    }
+
+    /// Scans the part of a link after its text, which ends at `text_end`. Returns the tail and
+    /// the offset after it.
+    fn scan_link_tail(
+        &self,
+        position: usize,
+        text_start: usize,
+        text_end: usize,
+    ) -> Option<(LinkTail, usize)> {
+        match self.byte(position) {
+            Some(b'(') => {
+                if let Some(tail) = self.scan_inline_link_tail(position) {
+                    return Some(tail);
+                }
+            }
+            Some(b'[') => {
+                if self.byte(position + 1) == Some(b']') {
+                    // A collapsed reference: the text of the link is its label
+                    return self
+                        .is_defined(text_start, text_end)
+                        .then_some((LinkTail::Reference { label: false }, position + 2));
+                }
+
+                let text = &self.source[position..self.end()];
+                if let Some(length) = scan_link_label(text) {
+                    let end = position + length;
+                    return (self.is_inside(end) && self.is_defined(position + 1, end - 1))
+                        .then_some((LinkTail::Reference { label: true }, end));
+                }
+            }
+            _ => {}
+        }
+
+        self.is_defined(text_start, text_end)
+            .then_some((LinkTail::Shortcut, position))
+    }
+
+    /// Scans `(destination "title")`, starting at the `(`
+    fn scan_inline_link_tail(&self, position: usize) -> Option<(LinkTail, usize)> {
+        let mut cursor = self.skip_whitespace(position + 1);
+        let mut destination = false;
+        let mut title = false;
+
+        if self.byte(cursor) != Some(b')') {
+            let segment_end = self.segment_end(cursor);
+            if let Some(length) = scan_link_destination(&self.source[cursor..segment_end]) {
+                destination = true;
+                cursor += length;
+            }
+
+            // The title must be separated from the destination by whitespace
+            let after_whitespace = self.skip_whitespace(cursor);
+            if (after_whitespace > cursor || !destination)
+                && matches!(self.byte(after_whitespace), Some(b'"' | b'\'' | b'('))
+            {
+                let length = scan_link_title(&self.source[after_whitespace..self.end()])?;
+                let end = after_whitespace + length;
+                if !self.is_inside(end) {
+                    return None;
+                }
+                title = true;
+                cursor = self.skip_whitespace(end);
+            } else {
+                cursor = after_whitespace;
+            }
+        }
+
+        (self.byte(cursor) == Some(b')'))
+            .then_some((LinkTail::Inline { destination, title }, cursor + 1))
+    }
+
+    fn scan_angle_bracket(&mut self, position: usize, end: usize) -> usize {
+        if let Some(length) = scan_autolink(&self.source[position..end]) {
+            self.insert(position, Inline::Autolink);
+            return position + length;
+        }
+
+        if let Some(length) = scan_inline_html(&self.source[position..self.end()]) {
+            let end = position + length;
+            if self.is_inside(end) {
+                self.insert(position, Inline::Html);
+                return end;
+            }
+        }
+
+        position + 1
+    }
+
+    /// Matches the emphasis delimiters that follow the first `bottom` delimiters, then removes
+    /// them.
+    fn process_emphasis(&mut self, bottom: usize) {
+        // The lowest delimiter that can open an emphasis closed by a given kind of closer, to
+        // avoid searching the same delimiters again
+        let mut openers_bottom: FxHashMap<(u8, usize, bool), usize> = FxHashMap::default();
+        let mut closer_index = bottom;
+
+        while let Some(&closer) = self.delimiters.get(closer_index) {
+            if !closer.can_close {
+                closer_index += 1;
+                continue;
+            }
+
+            let key = (closer.char, closer.length % 3, closer.can_open);
+            let lowest_id = openers_bottom.get(&key).copied();
+            let opener_index = (bottom..closer_index).rev().find(|&index| {
+                let opener = &self.delimiters[index];
+                if lowest_id.is_some_and(|id| opener.id < id)
+                    || opener.char != closer.char
+                    || !opener.can_open
+                {
+                    return false;
+                }
+
+                if closer.char == b'~' {
+                    opener.count == closer.count
+                } else {
+                    // The "rule of 3"
+                    !((opener.can_close || closer.can_open)
+                        && (opener.length + closer.length) % 3 == 0
+                        && !(opener.length % 3 == 0 && closer.length % 3 == 0))
+                }
+            });
+
+            let Some(opener_index) = opener_index else {
+                openers_bottom.insert(key, closer.id);
+                if closer.can_open {
+                    closer_index += 1;
+                } else {
+                    self.delimiters.remove(closer_index);
+                }
+                continue;
+            };
+
+            let opener = &mut self.delimiters[opener_index];
+            let used = if closer.char == b'~' {
+                closer.count
+            } else if opener.count >= 2 && closer.count >= 2 {
+                2
+            } else {
+                1
+            };
+            let kind = match (closer.char, used) {
+                (b'~', _) => EmphasisKind::Strikethrough,
+                (_, 1) => EmphasisKind::Italic,
+                _ => EmphasisKind::Strong,
+            };
+
+            // The opener uses the last characters of its run, and the closer the first ones
+            opener.count -= used;
+            let opener_start = opener.start + opener.count;
+            let is_opener_used = opener.count == 0;
+            self.insert(
+                opener_start,
+                Inline::Emphasis {
+                    kind,
+                    length: used,
+                    closing: text_size(closer.start),
+                },
+            );
+
+            let closer = &mut self.delimiters[closer_index];
+            closer.start += used;
+            closer.count -= used;
+            let is_closer_used = closer.count == 0;
+
+            self.delimiters.drain(opener_index + 1..closer_index);
+            closer_index = opener_index + 1;
+            if is_opener_used {
+                self.delimiters.remove(opener_index);
+                closer_index -= 1;
+            }
+            if is_closer_used {
+                self.delimiters.remove(closer_index);
+            }
+        }
+
+        self.delimiters.truncate(bottom);
+    }
+
+    fn insert(&mut self, position: usize, inline: Inline) {
+        self.inlines.insert(text_size(position), inline);
+    }
+
+    /// Returns `true` if the text between `start` and `end` is the label of a link reference
+    /// definition of the document
+    fn is_defined(&self, start: usize, end: usize) -> bool {
+        let label = self.text(start, end);
+        let bracketed = format!("[{label}]");
+        scan_link_label(&bracketed) == Some(bracketed.len())
+            && self.link_labels.contains(&normalize_link_label(&label))
+    }
+
+    /// Returns the text between `start` and `end`, without the container markers between the
+    /// lines
+    fn text(&self, start: usize, end: usize) -> String {
+        let mut text = String::new();
+        for &(segment_start, segment_end) in &self.segments {
+            let from = segment_start.max(start);
+            let to = segment_end.min(end);
+            if from < to {
+                if !text.is_empty() {
+                    text.push('\n');
+                }
+                text.push_str(&self.source[from..to]);
+            }
+        }
+        text
+    }
+
+    /// Returns the index of the segment that contains `position`, or of the next one if it's
+    /// between two segments
+    fn segment_index(&self, position: usize) -> usize {
+        self.segments.partition_point(|&(_, end)| end < position)
+    }
+
+    /// Returns the end of the segment that contains `position`
+    fn segment_end(&self, position: usize) -> usize {
+        self.segments
+            .get(self.segment_index(position))
+            .map_or(position, |&(_, end)| end)
+    }
+
+    /// Returns the end of the text
+    fn end(&self) -> usize {
+        self.segments.last().map_or(0, |&(_, end)| end)
+    }
+
+    /// Returns `true` if `position` is inside a segment or at its end
+    fn is_inside(&self, position: usize) -> bool {
+        self.segments
+            .get(self.segment_index(position))
+            .is_some_and(|&(start, _)| start <= position)
+    }
+
+    /// Returns the byte at `position`, or `None` if it's at the end of a segment
+    fn byte(&self, position: usize) -> Option<u8> {
+        let (start, end) = *self.segments.get(self.segment_index(position))?;
+        (start <= position && position < end).then(|| self.source.as_bytes()[position])
+    }
+
+    /// Returns the character before `position`. The start of a line is a line ending.
+    fn char_before(&self, position: usize) -> char {
+        let is_segment_start = self
+            .segments
+            .get(self.segment_index(position))
+            .is_some_and(|&(start, _)| start == position);
+        if is_segment_start {
+            '\n'
+        } else {
+            self.source[..position].chars().next_back().unwrap_or('\n')
+        }
+    }
+
+    /// Returns the character at `position`. The end of a line is a line ending.
+    fn char_at(&self, position: usize) -> char {
+        if self.byte(position).is_none() {
+            '\n'
+        } else {
+            self.source[position..].chars().next().unwrap_or('\n')
+        }
+    }
+
+    fn next_char(&self, position: usize) -> usize {
+        position + self.char_at(position).len_utf8()
+    }
+
+    /// Returns the number of times the character at `position` is repeated before `end`
+    fn run_length(&self, position: usize, end: usize) -> usize {
+        let bytes = &self.source.as_bytes()[position..end];
+        bytes
+            .iter()
+            .position(|byte| *byte != bytes[0])
+            .unwrap_or(bytes.len())
+    }
+
+    /// Returns `true` if an extended autolink can start at `position`
+    fn at_word_start(&self, position: usize) -> bool {
+        let before = self.char_before(position);
+        before.is_whitespace() || matches!(before, '*' | '_' | '~' | '(')
+    }
+
+    /// Skips the spaces, tabs, and up to one line ending from `position`
+    fn skip_whitespace(&self, mut position: usize) -> usize {
+        let mut has_line_ending = false;
+        loop {
+            match self.byte(position) {
+                Some(b' ' | b'\t') => position += 1,
+                Some(_) => return position,
+                None => {
+                    let next = self.segment_index(position) + 1;
+                    match self.segments.get(next) {
+                        Some(&(start, _)) if !has_line_ending => {
+                            has_line_ending = true;
+                            position = start;
+                        }
+                        _ => return position,
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns `true` if `char` is a [Unicode punctuation character](https://spec.commonmark.org/0.31.2/#unicode-punctuation-character),
+/// approximated by the characters that aren't alphanumeric, whitespace or control characters.
+fn is_punctuation(char: char) -> bool {
+    if char.is_ascii() {
+        char.is_ascii_punctuation()
+    } else {
+        !char.is_alphanumeric() && !char.is_whitespace() && !char.is_control()
+    }
+}
@@ -0,0 +1,213 @@
+//! Utilities to inspect the lines of the source text and to match them against the open
+//! container blocks.
+//!
+//! Markdown is parsed line by line: each line starts with the markers of the containers that it
+//! continues, such as the `>` of block quotes or the indentation of list items, followed by the
+//! content of the innermost block.
+
+/// A line of the source text
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct Line {
+    /// Offset of the first character of the line
+    pub(crate) start: usize,
+    /// Offset of the line ending, or of the end of the source if it's the last line
+    pub(crate) end: usize,
+    /// Offset of the first character of the next line
+    pub(crate) next: usize,
+}
+
+impl Line {
+    /// Returns the line that contains `offset`
+    pub(crate) fn at(source: &str, offset: usize) -> Self {
+        let start = source[..offset]
+            .rfind(['\n', '\r'])
+            .map_or(0, |index| index + 1);
+        Self::starting_at(source, start)
+    }
+
+    fn starting_at(source: &str, start: usize) -> Self {
+        let end = source[start..]
+            .find(['\n', '\r'])
+            .map_or(source.len(), |index| start + index);
+        let next = if source[end..].starts_with("\r\n") {
+            end + 2
+        } else {
+            (end + 1).min(source.len())
+        };
+
+        Self { start, end, next }
+    }
+
+    /// Returns the line after this one, or `None` if this is the last line
+    pub(crate) fn next(self, source: &str) -> Option<Self> {
+        (self.next < source.len()).then(|| Self::starting_at(source, self.next))
+    }
+
+    /// Returns the line before this one, or `None` if this is the first line
+    pub(crate) fn previous(self, source: &str) -> Option<Self> {
+        (self.start > 0).then(|| {
+            let end = if source[..self.start].ends_with("\r\n") {
+                self.start - 2
+            } else {
+                self.start - 1
+            };
+            Self::at(source, end)
+        })
+    }
+
+    /// Returns the text of the line, without its line ending
+    pub(crate) fn text(self, source: &str) -> &str {
+        &source[self.start..self.end]
+    }
+}
+
+/// Returns `true` if the text contains only spaces and tabs
+pub(crate) fn is_blank(text: &str) -> bool {
+    text.bytes().all(|byte| matches!(byte, b' ' | b'\t'))
+}
+
+/// Returns the column of `offset` on the line that starts at `line_start`.
+///
+/// Tabs aren't expanded in the source, but they behave as if they were replaced by spaces with a
+/// [tab stop of 4 characters](https://spec.commonmark.org/0.31.2/#tabs).
+pub(crate) fn column_at(source: &str, line_start: usize, offset: usize) -> usize {
+    source[line_start..offset].chars().fold(0, |column, char| {
+        if char == '\t' {
+            column + 4 - column % 4
+        } else {
+            column + 1
+        }
+    })
+}
+
+/// Skips the spaces and tabs from `offset`, which is at `column`, up to `end`. Returns the
+/// offset and column of the first other character.
+pub(crate) fn skip_spaces(
+    source: &str,
+    mut offset: usize,
+    mut column: usize,
+    end: usize,
+) -> (usize, usize) {
+    while offset < end {
+        match source.as_bytes()[offset] {
+            b' ' => column += 1,
+            b'\t' => column += 4 - column % 4,
+            _ => break,
+        }
+        offset += 1;
+    }
+    (offset, column)
+}
+
+/// A block that contains other blocks
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum Container {
+    /// A block quote, continued by lines that start with `>`
+    Quote,
+    /// A list item, continued by the lines that are indented up to its content
+    ListItem {
+        /// The column of the content of the item
+        content_column: usize,
+        /// Whether the item doesn't contain any block yet. An empty item is closed by a blank
+        /// line.
+        is_empty: bool,
+    },
+}
+
+/// The result of matching a line against the open containers.
+#[derive(Debug, Clone)]
+pub(crate) struct LinePrefix {
+    pub(crate) line: Line,
+    /// The number of open containers that the line continues, from the outermost one
+    pub(crate) matched: usize,
+    /// The offsets of the `>` markers of the continued block quotes
+    pub(crate) quote_markers: Vec<usize>,
+    /// The offset of the first character of the line that isn't part of the container
+    /// markers or of the indentation
+    pub(crate) content: usize,
+    /// The column at which the content of the continued containers starts
+    pub(crate) content_column: usize,
+    /// The indentation of the content, relative to the content column of the containers
+    pub(crate) indent: usize,
+    /// Whether the line is blank after the container markers
+    pub(crate) is_blank: bool,
+    /// Whether the line follows a blank line, which closes the block quotes and the empty
+    /// list items
+    pub(crate) follows_blank_line: bool,
+}
+
+impl LinePrefix {
+    /// Returns the text of the line after the container markers and the indentation
+    pub(crate) fn text<'a>(&self, source: &'a str) -> &'a str {
+        &source[self.content..self.line.end]
+    }
+}
+
+/// Matches `line` against `containers`.
+pub(crate) fn match_containers(source: &str, line: Line, containers: &[Container]) -> LinePrefix {
+    let bytes = source.as_bytes();
+    let mut offset = line.start;
+    let mut column = 0;
+    let mut content_column = 0;
+    let mut quote_markers = Vec::new();
+    let mut matched = 0;
+
+    for container in containers {
+        let (first, first_column) = skip_spaces(source, offset, column, line.end);
+        let is_blank = first == line.end;
+
+        match container {
+            Container::Quote => {
+                if is_blank
+                    || first_column.saturating_sub(content_column) > 3
+                    || bytes[first] != b'>'
+                {
+                    break;
+                }
+
+                quote_markers.push(first);
+                offset = first + 1;
+                column = first_column + 1;
+                content_column = column;
+
+                // The marker can be followed by an optional space, which can be a part of a tab
+                match bytes.get(offset) {
+                    Some(b' ') => {
+                        offset += 1;
+                        column += 1;
+                        content_column = column;
+                    }
+                    Some(b'\t') => content_column = column + 1,
+                    _ => {}
+                }
+            }
+            Container::ListItem {
+                content_column: item_column,
+                is_empty,
+            } => {
+                if (is_blank && *is_empty) || (!is_blank && first_column < *item_column) {
+                    break;
+                }
+                content_column = *item_column;
+            }
+        }
+
+        matched += 1;
+    }
+
+    let (content, first_column) = skip_spaces(source, offset, column, line.end);
+    let follows_blank_line = line
+        .previous(source)
+        .is_some_and(|previous| is_blank(previous.text(source)));
+
+    LinePrefix {
+        line,
+        matched,
+        quote_markers,
+        content,
+        content_column,
+        indent: first_column.saturating_sub(content_column),
+        is_blank: content == line.end,
+        follows_blank_line,
+    }
+}
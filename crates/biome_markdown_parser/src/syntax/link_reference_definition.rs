@@ -0,0 +1,118 @@
+use crate::lexer::MarkdownReLexContext;
+use crate::parser::MarkdownParser;
+use crate::scanner::{
+    normalize_link_label, scan_link_destination, scan_link_label, scan_link_title,
+};
+use crate::syntax::cur_line_text;
+use crate::syntax::line::{Line, is_blank};
+use crate::syntax::list::scan_list_marker;
+use biome_markdown_syntax::{T, kind::MarkdownSyntaxKind::*};
+use biome_parser::{
+    Parser,
+    prelude::ParsedSyntax::{self, *},
+};
+use rustc_hash::FxHashSet;
+
+/// The parts of a link reference definition, as offsets in its text
+struct LinkReferenceDefinition {
+    /// The end of the label, including its brackets
+    label_end: usize,
+    has_title: bool,
+}
+
+/// Scans the [link reference definition](https://spec.commonmark.org/0.31.2/#link-reference-definitions)
+/// at the start of `text`, such as `[label]: /url "title"`. The definition must fit on a
+/// single line.
+fn scan_link_reference_definition(text: &str) -> Option<LinkReferenceDefinition> {
+    let label_end = scan_link_label(text)?;
+    let rest = text[label_end..].strip_prefix(':')?;
+    let destination_start = rest.trim_start_matches([' ', '\t']);
+    let destination_length = scan_link_destination(destination_start)?;
+    let after_destination = &destination_start[destination_length..];
+
+    let title_start = after_destination.trim_start_matches([' ', '\t']);
+    if title_start.len() < after_destination.len() {
+        if let Some(title_length) = scan_link_title(title_start) {
+            if is_blank(&title_start[title_length..]) {
+                return Some(LinkReferenceDefinition {
+                    label_end,
+                    has_title: true,
+                });
+            }
+        }
+    }
+
+    is_blank(after_destination).then_some(LinkReferenceDefinition {
+        label_end,
+        has_title: false,
+    })
+}
+
+/// Collects the normalized labels of the link reference definitions of `source`, so that the
+/// links can be resolved before the definitions that they reference are parsed.
+pub(crate) fn collect_link_labels(source: &str) -> FxHashSet<String> {
+    let mut labels = FxHashSet::default();
+    let mut line = Some(Line::at(source, 0));
+
+    while let Some(current) = line {
+        let mut text = current.text(source);
+
+        // Strips the markers of the containers
+        loop {
+            text = text.trim_start_matches([' ', '\t']);
+            if let Some(rest) = text.strip_prefix('>') {
+                text = rest;
+            } else if let Some(marker) = scan_list_marker(text) {
+                text = &text[marker.length()..];
+            } else {
+                break;
+            }
+        }
+
+        if let Some(definition) = scan_link_reference_definition(text) {
+            labels.insert(normalize_link_label(&text[1..definition.label_end - 1]));
+        }
+
+        line = current.next(source);
+    }
+
+    labels
+}
+
+pub(crate) fn at_link_reference_definition(p: &mut MarkdownParser) -> bool {
+    scan_link_reference_definition(cur_line_text(p)).is_some()
+}
+
+pub(crate) fn parse_link_reference_definition(p: &mut MarkdownParser) -> ParsedSyntax {
+    let Some(definition) = scan_link_reference_definition(cur_line_text(p)) else {
+        return Absent;
+    };
+    let m = p.start();
+
+    p.re_lex(MarkdownReLexContext::Punctuation);
+    p.expect(T!['[']);
+
+    let label = p.start();
+    p.re_lex(MarkdownReLexContext::LinkLabel);
+    p.expect(MD_TEXTUAL_LITERAL);
+    label.complete(p, MD_TEXTUAL);
+
+    p.re_lex(MarkdownReLexContext::Punctuation);
+    p.expect(T![']']);
+    p.re_lex(MarkdownReLexContext::Punctuation);
+    p.expect(T![:]);
+
+    let destination = p.start();
+    p.re_lex(MarkdownReLexContext::LinkDestination);
+    p.expect(MD_TEXTUAL_LITERAL);
+    destination.complete(p, MD_LINK_DESTINATION);
+
+    if definition.has_title {
+        let title = p.start();
+        p.re_lex(MarkdownReLexContext::LinkTitle);
+        p.expect(MD_TEXTUAL_LITERAL);
+        title.complete(p, MD_LINK_TITLE);
+    }
+
+    Present(m.complete(p, MD_LINK_REFERENCE_DEFINITION))
+}
@@ -0,0 +1,170 @@
+use crate::lexer::MarkdownReLexContext;
+use crate::parser::MarkdownParser;
+use crate::syntax::line::{Container, Line, column_at, is_blank, skip_spaces};
+use crate::syntax::thematic_break_block::is_thematic_break;
+use crate::syntax::{cur_line_text, cur_offset, next_continuation_line, parse_block_list};
+use biome_markdown_syntax::kind::MarkdownSyntaxKind::*;
+use biome_parser::{
+    Parser,
+    prelude::ParsedSyntax::{self, *},
+};
+
+/// A [list marker](https://spec.commonmark.org/0.31.2/#list-marker)
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct ListMarker {
+    /// Whether this is the marker of an ordered list, such as `1.`, rather than a bullet
+    is_ordered: bool,
+    /// The bullet character, or the delimiter of an ordered list marker: `.` or `)`
+    char: u8,
+    /// The number of an ordered list marker
+    number: u32,
+    /// The length of the marker
+    length: usize,
+    /// Whether the marker isn't followed by any content on its line
+    is_empty: bool,
+}
+
+impl ListMarker {
+    /// Returns the length of the marker
+    pub(crate) fn length(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if the marker can interrupt a paragraph. An empty item, or an ordered
+    /// list that doesn't start at 1, can't.
+    pub(crate) fn can_interrupt_paragraph(&self) -> bool {
+        !self.is_empty && (!self.is_ordered || self.number == 1)
+    }
+
+    /// Returns `true` if an item with the `other` marker continues the list of this one
+    fn is_same_list(&self, other: &Self) -> bool {
+        self.is_ordered == other.is_ordered && self.char == other.char
+    }
+}
+
+/// Scans the list marker at the start of `text`
+pub(crate) fn scan_list_marker(text: &str) -> Option<ListMarker> {
+    let bytes = text.as_bytes();
+    let (is_ordered, char, length, number) = match *bytes.first()? {
+        bullet @ (b'-' | b'*' | b'+') => (false, bullet, 1, 0),
+        _ => {
+            let digits = bytes
+                .iter()
+                .take_while(|byte| byte.is_ascii_digit())
+                .count();
+            let delimiter = *bytes.get(digits)?;
+            if !(1..=9).contains(&digits) || !matches!(delimiter, b'.' | b')') {
+                return None;
+            }
+            (true, delimiter, digits + 1, text[..digits].parse().ok()?)
+        }
+    };
+
+    if !bytes
+        .get(length)
+        .is_none_or(|byte| matches!(byte, b' ' | b'\t'))
+    {
+        return None;
+    }
+
+    Some(ListMarker {
+        is_ordered,
+        char,
+        number,
+        length,
+        is_empty: is_blank(&text[length..]),
+    })
+}
+
+pub(crate) fn at_list(p: &mut MarkdownParser) -> bool {
+    scan_list_marker(cur_line_text(p)).is_some()
+}
+
+/// Parses a [list](https://spec.commonmark.org/0.31.2/#lists): a sequence of list items with
+/// the same kind of marker.
+pub(crate) fn parse_list(p: &mut MarkdownParser) -> ParsedSyntax {
+    let Some(marker) = scan_list_marker(cur_line_text(p)) else {
+        return Absent;
+    };
+    let source = p.source_text();
+    let m = p.start();
+    let items = p.start();
+
+    let mut next_marker = marker;
+    loop {
+        parse_list_item(p, next_marker);
+
+        let next_item = next_continuation_line(p, |prefix| {
+            let text = prefix.text(source);
+            prefix.is_blank
+                || (prefix.indent < 4
+                    && !is_thematic_break(text)
+                    && scan_list_marker(text).is_some_and(|next| marker.is_same_list(&next)))
+        });
+        match next_item.and_then(|prefix| scan_list_marker(prefix.text(source))) {
+            Some(next) => next_marker = next,
+            None => break,
+        }
+    }
+
+    if marker.is_ordered {
+        items.complete(p, MD_ORDERED_LIST_ITEM_LIST);
+        Present(m.complete(p, MD_ORDERED_LIST))
+    } else {
+        items.complete(p, MD_BULLET_LIST_ITEM_LIST);
+        Present(m.complete(p, MD_BULLET_LIST))
+    }
+}
+
+fn parse_list_item(p: &mut MarkdownParser, marker: ListMarker) {
+    let source = p.source_text();
+    let offset = cur_offset(p);
+    let line = Line::at(source, offset);
+    let marker_end = offset + marker.length;
+    let marker_end_column = column_at(source, line.start, marker_end);
+    let (content, column) = skip_spaces(source, marker_end, marker_end_column, line.end);
+
+    // The content of the item starts after the spaces that follow the marker, unless there are
+    // more than four of them, in which case the content is an indented code block
+    let content_column = if marker.is_empty || column - marker_end_column > 4 {
+        marker_end_column + 1
+    } else {
+        column
+    };
+
+    let m = p.start();
+    let kind = p.re_lex(MarkdownReLexContext::ListMarker);
+    p.bump(kind);
+
+    let text = &source[content..line.end];
+    let is_task = matches!(
+        text.as_bytes(),
+        [b'[', b' ' | b'x' | b'X', b']', b' ' | b'\t', ..]
+    ) && !is_blank(&text[3..])
+        && p.re_lex(MarkdownReLexContext::TaskMarker) == MD_TASK_MARKER_LITERAL;
+    if is_task {
+        p.bump(MD_TASK_MARKER_LITERAL);
+    }
+
+    // The blocks on the line of a task list item start after the task marker
+    let first_line_column = if is_task {
+        column_at(source, line.start, cur_offset(p))
+    } else {
+        content_column
+    };
+
+    let state = p.state_mut();
+    state.content_column = first_line_column;
+    state.containers.push(Container::ListItem {
+        content_column,
+        is_empty: marker.is_empty,
+    });
+    parse_block_list(p);
+    p.state_mut().containers.pop();
+
+    if marker.is_ordered {
+        m.complete(p, MD_ORDERED_LIST_ITEM);
+    } else {
+        m.complete(p, MD_BULLET_LIST_ITEM);
+    }
+}
@@ -0,0 +1,100 @@
+use crate::lexer::{MarkdownLexContext, MarkdownReLexContext};
+use crate::parser::MarkdownParser;
+use crate::syntax::code_block::scan_code_fence;
+use crate::syntax::header::{atx_heading_level, is_setext_underline};
+use crate::syntax::html_block::html_block_kind;
+use crate::syntax::inline::parse_inline_item_list;
+use crate::syntax::line::{Line, match_containers};
+use crate::syntax::list::scan_list_marker;
+use crate::syntax::table::is_table_start;
+use crate::syntax::thematic_break_block::is_thematic_break;
+use crate::syntax::{consume_line_prefix, cur_offset, line_prefix, text_size};
+use biome_markdown_syntax::kind::MarkdownSyntaxKind::*;
+use biome_parser::{
+    Parser,
+    prelude::ParsedSyntax::{self, *},
+};
+use biome_rowan::TextRange;
+
+/// Returns `true` if `text`, indented by `indent` columns, starts a block that can interrupt a
+/// paragraph.
+pub(crate) fn interrupts_paragraph(text: &str, indent: usize) -> bool {
+    indent < 4
+        && (is_thematic_break(text)
+            || atx_heading_level(text).is_some()
+            || scan_code_fence(text).is_some()
+            || html_block_kind(text).is_some_and(|kind| kind <= 6)
+            || text.starts_with('>')
+            || scan_list_marker(text).is_some_and(|marker| marker.can_interrupt_paragraph()))
+}
+
+/// Returns `true` if `text`, indented by `indent` columns, starts a block on a line that doesn't
+/// continue all the open containers. Such a line is otherwise a
+/// [lazy continuation line](https://spec.commonmark.org/0.31.2/#lazy-continuation-line).
+pub(crate) fn starts_block(text: &str, indent: usize) -> bool {
+    indent < 4
+        && (is_thematic_break(text)
+            || atx_heading_level(text).is_some()
+            || scan_code_fence(text).is_some()
+            || html_block_kind(text).is_some()
+            || text.starts_with('>')
+            || scan_list_marker(text).is_some())
+}
+
+/// Parses a [paragraph](https://spec.commonmark.org/0.31.2/#paragraphs), or a
+/// [setext heading](https://spec.commonmark.org/0.31.2/#setext-headings) if its lines are
+/// followed by an underline.
+pub(crate) fn parse_paragraph(p: &mut MarkdownParser) -> ParsedSyntax {
+    if p.at(EOF) {
+        return Absent;
+    }
+
+    let source = p.source_text();
+    let containers = &p.state().containers;
+    let start = cur_offset(p);
+    let mut line = Line::at(source, start);
+    let mut segments = vec![TextRange::new(text_size(start), text_size(line.end))];
+    let mut is_setext_heading = false;
+
+    while let Some(next) = line.next(source) {
+        let prefix = match_containers(source, next, containers);
+        if prefix.is_blank {
+            break;
+        }
+
+        let text = prefix.text(source);
+        if prefix.matched == containers.len() {
+            if prefix.indent < 4 && is_setext_underline(text) {
+                is_setext_heading = true;
+                break;
+            }
+            if interrupts_paragraph(text, prefix.indent)
+                || is_table_start(source, next, text, containers)
+            {
+                break;
+            }
+        } else if starts_block(text, prefix.indent) {
+            break;
+        }
+
+        segments.push(TextRange::new(
+            text_size(prefix.content),
+            text_size(next.end),
+        ));
+        line = next;
+    }
+
+    let m = p.start();
+    parse_inline_item_list(p, segments, MarkdownLexContext::Inline);
+
+    if is_setext_heading {
+        if let Some(prefix) = line_prefix(p) {
+            consume_line_prefix(p, &prefix);
+        }
+        p.re_lex(MarkdownReLexContext::SetextUnderline);
+        p.expect(MD_SETEXT_UNDERLINE_LITERAL);
+        Present(m.complete(p, MD_SETEXT_HEADER))
+    } else {
+        Present(m.complete(p, MD_PARAGRAPH))
+    }
+}
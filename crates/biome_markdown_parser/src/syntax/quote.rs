@@ -0,0 +1,44 @@
+use crate::lexer::MarkdownReLexContext;
+use crate::parser::MarkdownParser;
+use crate::syntax::line::{Container, Line, column_at};
+use crate::syntax::{cur_line_text, cur_offset, parse_block_list};
+use biome_markdown_syntax::{T, kind::MarkdownSyntaxKind::*};
+use biome_parser::{
+    Parser,
+    prelude::ParsedSyntax::{self, *},
+};
+
+pub(crate) fn at_quote(p: &mut MarkdownParser) -> bool {
+    cur_line_text(p).starts_with('>')
+}
+
+/// Parses a [block quote](https://spec.commonmark.org/0.31.2/#block-quotes). Its content is
+/// continued by the lines that start with `>`, which are consumed with the line prefixes, and
+/// by the lazy continuation lines of its last paragraph.
+pub(crate) fn parse_quote(p: &mut MarkdownParser) -> ParsedSyntax {
+    if !at_quote(p) {
+        return Absent;
+    }
+
+    let source = p.source_text();
+    let offset = cur_offset(p);
+    let line = Line::at(source, offset);
+    let column = column_at(source, line.start, offset) + 1;
+    // The marker can be followed by an optional space, which can be a part of a tab
+    let content_column = match source.as_bytes().get(offset + 1) {
+        Some(b' ' | b'\t') => column + 1,
+        _ => column,
+    };
+
+    let m = p.start();
+    p.re_lex(MarkdownReLexContext::Punctuation);
+    p.expect(T![>]);
+
+    let state = p.state_mut();
+    state.content_column = content_column;
+    state.containers.push(Container::Quote);
+    parse_block_list(p);
+    p.state_mut().containers.pop();
+
+    Present(m.complete(p, MD_QUOTE))
+}
@@ -0,0 +1,207 @@
+use crate::lexer::{MarkdownLexContext, MarkdownReLexContext};
+use crate::parser::MarkdownParser;
+use crate::scanner::is_escape;
+use crate::syntax::inline::parse_inline_item_list;
+use crate::syntax::line::{Container, Line, match_containers};
+use crate::syntax::paragraph::starts_block;
+use crate::syntax::{cur_line_text, cur_offset, next_continuation_line, text_size};
+use biome_markdown_syntax::{T, kind::MarkdownSyntaxKind::*};
+use biome_parser::{
+    Parser,
+    prelude::ParsedSyntax::{self, *},
+};
+use biome_rowan::TextRange;
+
+/// A cell of a table row
+#[derive(Debug, Copy, Clone)]
+struct TableCell {
+    /// The offset of the `|` before the cell, if any
+    pipe: Option<usize>,
+    /// The offset of the start of the content of the cell
+    start: usize,
+    /// The offset of the end of the content of the cell
+    end: usize,
+}
+
+/// A row of a table, as offsets in its text
+#[derive(Debug)]
+struct TableRow {
+    cells: Vec<TableCell>,
+    /// The offset of the `|` after the last cell, if any
+    trailing_pipe: Option<usize>,
+}
+
+impl TableRow {
+    fn has_pipe(&self) -> bool {
+        self.trailing_pipe.is_some() || self.cells.iter().any(|cell| cell.pipe.is_some())
+    }
+}
+
+/// Splits the row of a table into its cells, at the pipes that aren't escaped
+fn split_table_row(text: &str) -> TableRow {
+    let bytes = text.trim_end_matches([' ', '\t']).as_bytes();
+    let mut cells = Vec::new();
+    let mut pipe = None;
+    let mut start = 0;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        if is_escape(&bytes[index..]) {
+            index += 2;
+            continue;
+        }
+        if bytes[index] == b'|' {
+            if index > 0 {
+                cells.push(TableCell {
+                    pipe,
+                    start,
+                    end: index,
+                });
+            }
+            pipe = Some(index);
+            start = index + 1;
+        }
+        index += 1;
+    }
+
+    let trailing_pipe = if start < bytes.len() {
+        cells.push(TableCell {
+            pipe,
+            start,
+            end: bytes.len(),
+        });
+        None
+    } else {
+        pipe
+    };
+
+    TableRow {
+        cells,
+        trailing_pipe,
+    }
+}
+
+/// Returns `true` if `text` is a cell of the delimiter row of a table, such as `:---:`
+fn is_delimiter_cell(text: &str) -> bool {
+    let text = text.trim_matches([' ', '\t']);
+    let text = text.strip_prefix(':').unwrap_or(text);
+    let text = text.strip_suffix(':').unwrap_or(text);
+    !text.is_empty() && text.bytes().all(|byte| byte == b'-')
+}
+
+/// Returns `true` if `text`, the content of `line`, is the header row of a
+/// [table](https://github.github.com/gfm/#tables-extension-): it's followed by a delimiter row
+/// with the same number of cells, in the same `containers`.
+pub(crate) fn is_table_start(
+    source: &str,
+    line: Line,
+    text: &str,
+    containers: &[Container],
+) -> bool {
+    let header = split_table_row(text);
+    if header.cells.is_empty() || !header.has_pipe() {
+        return false;
+    }
+
+    let Some(next) = line.next(source) else {
+        return false;
+    };
+    let prefix = match_containers(source, next, containers);
+    if prefix.matched < containers.len() || prefix.indent >= 4 || prefix.is_blank {
+        return false;
+    }
+
+    let text = prefix.text(source);
+    let delimiter = split_table_row(text);
+    delimiter.has_pipe()
+        && delimiter.cells.len() == header.cells.len()
+        && delimiter
+            .cells
+            .iter()
+            .all(|cell| is_delimiter_cell(&text[cell.start..cell.end]))
+}
+
+pub(crate) fn at_table(p: &mut MarkdownParser) -> bool {
+    let source = p.source_text();
+    let line = Line::at(source, cur_offset(p));
+    is_table_start(source, line, cur_line_text(p), &p.state().containers)
+}
+
+pub(crate) fn parse_table(p: &mut MarkdownParser) -> ParsedSyntax {
+    if !at_table(p) {
+        return Absent;
+    }
+    let source = p.source_text();
+    let m = p.start();
+
+    parse_table_row(p);
+
+    next_continuation_line(p, |_| true);
+    parse_table_delimiter_row(p);
+
+    let rows = p.start();
+    while next_continuation_line(p, |prefix| {
+        !prefix.is_blank
+            && !prefix.follows_blank_line
+            && !starts_block(prefix.text(source), prefix.indent)
+    })
+    .is_some()
+    {
+        parse_table_row(p);
+    }
+    rows.complete(p, MD_TABLE_ROW_LIST);
+
+    Present(m.complete(p, MD_TABLE))
+}
+
+fn parse_table_row(p: &mut MarkdownParser) {
+    let offset = cur_offset(p);
+    let row = split_table_row(cur_line_text(p));
+    let m = p.start();
+
+    let cells = p.start();
+    for cell in row.cells {
+        let m = p.start();
+        if cell.pipe.is_some() {
+            parse_pipe(p);
+        }
+        let content = TextRange::new(text_size(offset + cell.start), text_size(offset + cell.end));
+        parse_inline_item_list(p, vec![content], MarkdownLexContext::Regular);
+        m.complete(p, MD_TABLE_CELL);
+    }
+    cells.complete(p, MD_TABLE_CELL_LIST);
+
+    if row.trailing_pipe.is_some() {
+        parse_pipe(p);
+    }
+
+    m.complete(p, MD_TABLE_ROW);
+}
+
+fn parse_table_delimiter_row(p: &mut MarkdownParser) {
+    let row = split_table_row(cur_line_text(p));
+    let m = p.start();
+
+    let cells = p.start();
+    for cell in row.cells {
+        let m = p.start();
+        if cell.pipe.is_some() {
+            parse_pipe(p);
+        }
+        p.re_lex(MarkdownReLexContext::TableDelimiter);
+        p.expect(MD_TABLE_DELIMITER_LITERAL);
+        m.complete(p, MD_TABLE_DELIMITER_CELL);
+    }
+    cells.complete(p, MD_TABLE_DELIMITER_CELL_LIST);
+
+    if row.trailing_pipe.is_some() {
+        parse_pipe(p);
+    }
+
+    m.complete(p, MD_TABLE_DELIMITER_ROW);
+}
+
+fn parse_pipe(p: &mut MarkdownParser) {
+    p.re_lex(MarkdownReLexContext::Punctuation);
+    p.expect(T![|]);
+}
@@ -1,12 +1,28 @@
+use crate::lexer::MarkdownReLexContext;
 use crate::parser::MarkdownParser;
+use crate::syntax::cur_line_text;
 use biome_markdown_syntax::MarkdownSyntaxKind::*;
 use biome_parser::{
     Parser,
     prelude::ParsedSyntax::{self, *},
 };
 
+/// Returns `true` if `text` is a [thematic break](https://spec.commonmark.org/0.31.2/#thematic-breaks):
+/// three or more `-`, `*` or `_` characters, optionally separated by spaces or tabs.
+pub(crate) fn is_thematic_break(text: &str) -> bool {
+    let mut chars = text.bytes().filter(|byte| !matches!(byte, b' ' | b'\t'));
+    let Some(first) = chars.next() else {
+        return false;
+    };
+
+    matches!(first, b'-' | b'*' | b'_')
+        && chars
+            .try_fold(1, |count, byte| (byte == first).then_some(count + 1))
+            .is_some_and(|count| count >= 3)
+}
+
 pub(crate) fn at_thematic_break_block(p: &mut MarkdownParser) -> bool {
-    p.at(MD_THEMATIC_BREAK_LITERAL)
+    is_thematic_break(cur_line_text(p))
 }
 
 pub(crate) fn parse_thematic_break_block(p: &mut MarkdownParser) -> ParsedSyntax {
@@ -15,6 +31,7 @@ pub(crate) fn parse_thematic_break_block(p: &mut MarkdownParser) -> ParsedSyntax
     }
     let m = p.start();
 
+    p.re_lex(MarkdownReLexContext::ThematicBreak);
     p.expect(MD_THEMATIC_BREAK_LITERAL);
 
     Present(m.complete(p, MD_THEMATIC_BREAK_BLOCK))
@@ -1,11 +1,11 @@
 use crate::lexer::{MarkdownLexContext, MarkdownLexer, MarkdownReLexContext};
 use biome_markdown_syntax::MarkdownSyntaxKind;
 use biome_markdown_syntax::MarkdownSyntaxKind::EOF;
+use biome_parser::diagnostic::ParseDiagnostic;
 use biome_parser::lexer::BufferedLexer;
 use biome_parser::prelude::{BumpWithContext, TokenSource};
 use biome_parser::token_source::{TokenSourceWithBufferedLexer, Trivia};
-use biome_parser::{diagnostic::ParseDiagnostic, token_source::TokenSourceCheckpoint};
-use biome_rowan::{TextRange, TriviaPieceKind};
+use biome_rowan::{TextRange, TextSize, TriviaPieceKind};
 
 pub(crate) struct MarkdownTokenSource<'source> {
     lexer: BufferedLexer<MarkdownSyntaxKind, MarkdownLexer<'source>>,
@@ -14,8 +14,6 @@ pub(crate) struct MarkdownTokenSource<'source> {
     pub(super) trivia_list: Vec<Trivia>,
 }
 
-pub(crate) type MarkdownTokenSourceCheckpoint = TokenSourceCheckpoint<MarkdownSyntaxKind>;
-
 impl<'source> MarkdownTokenSource<'source> {
     /// Creates a new token source.
     pub(crate) fn new(lexer: BufferedLexer<MarkdownSyntaxKind, MarkdownLexer<'source>>) -> Self {
@@ -60,47 +58,43 @@ impl<'source> MarkdownTokenSource<'source> {
         }
     }
 
-    /// Returns the number of whitespace characters before the current token until the first new line.
-    /// tab will be counted as 4 spaces https://spec.commonmark.org/0.31.2/#tabs
-    /// whitespace will be counted as 1 space
-    pub fn before_whitespace_count(&self) -> usize {
-        let last_trivia: Vec<&Trivia> = self
-            .trivia_list
-            .iter()
-            .rev()
-            .take_while(|item| {
-                // get before whitespace and tab collect
-                matches!(
-                    item.kind(),
-                    TriviaPieceKind::Whitespace | TriviaPieceKind::Skipped
-                )
-            })
-            .collect();
-        last_trivia.iter().fold(0, |count, b| match b.kind() {
-            TriviaPieceKind::Skipped => count + 4,
-            TriviaPieceKind::Whitespace => count + u32::from(b.len()) as usize,
-            _ => count,
-        })
-    }
-
-    #[expect(dead_code)]
-    pub fn re_lex(&mut self, mode: MarkdownReLexContext) -> MarkdownSyntaxKind {
-        self.lexer.re_lex(mode)
-    }
-
-    /// Creates a checkpoint to which it can later return using [Self::rewind].
-    pub fn checkpoint(&self) -> MarkdownTokenSourceCheckpoint {
-        MarkdownTokenSourceCheckpoint {
-            trivia_len: self.trivia_list.len() as u32,
-            lexer_checkpoint: self.lexer.checkpoint(),
-        }
+    /// Returns the source text, with the lifetime of the source rather than of the token source.
+    pub fn source_text(&self) -> &'source str {
+        self.lexer.source()
+    }
+
+    /// Re-lexes the current token in the given context.
+    pub fn re_lex(&mut self, context: MarkdownReLexContext) -> MarkdownSyntaxKind {
+        self.lexer.re_lex(context)
     }
 
-    /// Restores the token source to a previous state
-    pub fn rewind(&mut self, checkpoint: MarkdownTokenSourceCheckpoint) {
-        assert!(self.trivia_list.len() >= checkpoint.trivia_len as usize);
-        self.trivia_list.truncate(checkpoint.trivia_len as usize);
-        self.lexer.rewind(checkpoint.lexer_checkpoint);
+    /// Re-lexes the current token in the given context, starting at `start`, which is before the
+    /// current token on the same line. The whitespace between them is removed from the trivia and
+    /// becomes part of the re-lexed token.
+    pub fn re_lex_from(
+        &mut self,
+        start: TextSize,
+        context: MarkdownReLexContext,
+    ) -> MarkdownSyntaxKind {
+        while let Some(trivia) = self.trivia_list.last_mut() {
+            if trivia.end_offset() <= start {
+                break;
+            }
+            if trivia.offset() < start {
+                *trivia = Trivia::new(
+                    trivia.kind(),
+                    TextRange::new(trivia.offset(), start),
+                    trivia.trailing(),
+                );
+                break;
+            }
+            self.trivia_list.pop();
+        }
+
+        let mut checkpoint = self.lexer.checkpoint();
+        checkpoint.current_start = start;
+        self.lexer.rewind(checkpoint);
+        self.lexer.re_lex(context)
     }
 }
 
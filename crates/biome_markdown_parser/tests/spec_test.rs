@@ -6,6 +6,7 @@ use biome_diagnostics::termcolor;
 use biome_markdown_parser::parse_markdown;
 use biome_rowan::SyntaxKind;
 use biome_test_utils::{has_bogus_nodes_or_empty_slots, validate_eof_token};
+use quickcheck_macros::quickcheck;
 use std::fmt::Write;
 use std::fs;
 use std::path::Path;
@@ -146,3 +147,70 @@ your test code
         panic!("modified tree has bogus nodes or empty slots:\n{syntax:#?} \n\n {syntax}")
     }
 }
+
+/// The fragments from which random documents are generated
+const FRAGMENTS: &[&str] = &[
+    "\n",
+    "\r\n",
+    " ",
+    "  ",
+    "    ",
+    "\t",
+    ">",
+    "> ",
+    "- ",
+    "* ",
+    "+ ",
+    "1. ",
+    "2) ",
+    "#",
+    "# ",
+    "`",
+    "```",
+    "~",
+    "~~",
+    "*",
+    "**",
+    "_",
+    "[",
+    "]",
+    "(",
+    ")",
+    "!",
+    "<",
+    "|",
+    ":",
+    "\\",
+    "\"",
+    "'",
+    "=",
+    "---",
+    "[x] ",
+    "a",
+    "b c",
+    "é",
+    "www.a.com",
+    "https://",
+    "a@b.c",
+    "<div>",
+    "<!--",
+    "-->",
+    "<a href=\"b\">",
+    "[a]: /b",
+    "\n\n",
+];
+
+/// Parsing any document must be lossless, and must not emit diagnostics
+#[quickcheck]
+fn parser_is_lossless(indices: Vec<u8>) -> bool {
+    let source: String = indices
+        .iter()
+        .map(|index| FRAGMENTS[usize::from(*index) % FRAGMENTS.len()])
+        .collect();
+
+    let parsed = parse_markdown(&source);
+    let syntax = parsed.syntax();
+    syntax.to_string() == source
+        && parsed.diagnostics().is_empty()
+        && !has_bogus_nodes_or_empty_slots(&syntax)
+}
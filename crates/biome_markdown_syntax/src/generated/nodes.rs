@@ -21,10 +21,10 @@ use std::fmt::{Debug, Formatter};
 #[doc = r" the slots are not statically known."]
 pub(crate) const SLOT_MAP_EMPTY_VALUE: u8 = u8::MAX;
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdBullet {
+pub struct MdAutolink {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdBullet {
+impl MdAutolink {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -34,24 +34,51 @@ impl MdBullet {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdBulletFields {
-        MdBulletFields {
-            bullet: self.bullet(),
-            space_token: self.space_token(),
-            content: self.content(),
+    pub fn as_fields(&self) -> MdAutolinkFields {
+        MdAutolinkFields {
+            value_token: self.value_token(),
         }
     }
-    pub fn bullet(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn space_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 1usize)
+}
+impl Serialize for MdAutolink {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
-    pub fn content(&self) -> MdInlineItemList {
-        support::list(&self.syntax, 2usize)
+}
+#[derive(Serialize)]
+pub struct MdAutolinkFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdBulletList {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdBulletList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdBulletListFields {
+        MdBulletListFields {
+            items: self.items(),
+        }
+    }
+    pub fn items(&self) -> MdBulletListItemList {
+        support::list(&self.syntax, 0usize)
     }
 }
-impl Serialize for MdBullet {
+impl Serialize for MdBulletList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -60,10 +87,8 @@ impl Serialize for MdBullet {
     }
 }
 #[derive(Serialize)]
-pub struct MdBulletFields {
-    pub bullet: SyntaxResult<SyntaxToken>,
-    pub space_token: SyntaxResult<SyntaxToken>,
-    pub content: MdInlineItemList,
+pub struct MdBulletListFields {
+    pub items: MdBulletListItemList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdBulletListItem {
@@ -81,11 +106,19 @@ impl MdBulletListItem {
     }
     pub fn as_fields(&self) -> MdBulletListItemFields {
         MdBulletListItemFields {
-            md_bullet_list: self.md_bullet_list(),
+            bullet: self.bullet(),
+            task_token: self.task_token(),
+            content: self.content(),
         }
     }
-    pub fn md_bullet_list(&self) -> MdBulletList {
-        support::list(&self.syntax, 0usize)
+    pub fn bullet(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn task_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 1usize)
+    }
+    pub fn content(&self) -> MdBlockList {
+        support::list(&self.syntax, 2usize)
     }
 }
 impl Serialize for MdBulletListItem {
@@ -98,7 +131,9 @@ impl Serialize for MdBulletListItem {
 }
 #[derive(Serialize)]
 pub struct MdBulletListItemFields {
-    pub md_bullet_list: MdBulletList,
+    pub bullet: SyntaxResult<SyntaxToken>,
+    pub task_token: Option<SyntaxToken>,
+    pub content: MdBlockList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdDocument {
@@ -162,30 +197,22 @@ impl MdFencedCodeBlock {
     pub fn as_fields(&self) -> MdFencedCodeBlockFields {
         MdFencedCodeBlockFields {
             l_fence_token: self.l_fence_token(),
-            code_list: self.code_list(),
-            l_hard_line: self.l_hard_line(),
+            info: self.info(),
             content: self.content(),
-            r_hard_line: self.r_hard_line(),
             r_fence_token: self.r_fence_token(),
         }
     }
     pub fn l_fence_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn code_list(&self) -> MdCodeNameList {
-        support::list(&self.syntax, 1usize)
-    }
-    pub fn l_hard_line(&self) -> SyntaxResult<MdHardLine> {
-        support::required_node(&self.syntax, 2usize)
-    }
-    pub fn content(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 3usize)
+    pub fn info(&self) -> Option<MdTextual> {
+        support::node(&self.syntax, 1usize)
     }
-    pub fn r_hard_line(&self) -> SyntaxResult<MdHardLine> {
-        support::required_node(&self.syntax, 4usize)
+    pub fn content(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 2usize)
     }
-    pub fn r_fence_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 5usize)
+    pub fn r_fence_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 3usize)
     }
 }
 impl Serialize for MdFencedCodeBlock {
@@ -199,11 +226,9 @@ impl Serialize for MdFencedCodeBlock {
 #[derive(Serialize)]
 pub struct MdFencedCodeBlockFields {
     pub l_fence_token: SyntaxResult<SyntaxToken>,
-    pub code_list: MdCodeNameList,
-    pub l_hard_line: SyntaxResult<MdHardLine>,
-    pub content: SyntaxResult<MdTextual>,
-    pub r_hard_line: SyntaxResult<MdHardLine>,
-    pub r_fence_token: SyntaxResult<SyntaxToken>,
+    pub info: Option<MdTextual>,
+    pub content: MdInlineItemList,
+    pub r_fence_token: Option<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdHardLine {
@@ -299,8 +324,8 @@ impl MdHeader {
     pub fn before(&self) -> MdHashList {
         support::list(&self.syntax, 0usize)
     }
-    pub fn content(&self) -> Option<MdParagraph> {
-        support::node(&self.syntax, 1usize)
+    pub fn content(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 1usize)
     }
     pub fn after(&self) -> MdHashList {
         support::list(&self.syntax, 2usize)
@@ -317,7 +342,7 @@ impl Serialize for MdHeader {
 #[derive(Serialize)]
 pub struct MdHeaderFields {
     pub before: MdHashList,
-    pub content: Option<MdParagraph>,
+    pub content: MdInlineItemList,
     pub after: MdHashList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -336,11 +361,11 @@ impl MdHtmlBlock {
     }
     pub fn as_fields(&self) -> MdHtmlBlockFields {
         MdHtmlBlockFields {
-            md_textual: self.md_textual(),
+            content: self.content(),
         }
     }
-    pub fn md_textual(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn content(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 0usize)
     }
 }
 impl Serialize for MdHtmlBlock {
@@ -353,42 +378,7 @@ impl Serialize for MdHtmlBlock {
 }
 #[derive(Serialize)]
 pub struct MdHtmlBlockFields {
-    pub md_textual: SyntaxResult<MdTextual>,
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdIndent {
-    pub(crate) syntax: SyntaxNode,
-}
-impl MdIndent {
-    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
-    #[doc = r""]
-    #[doc = r" # Safety"]
-    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
-    #[doc = r" or a match on [SyntaxNode::kind]"]
-    #[inline]
-    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
-        Self { syntax }
-    }
-    pub fn as_fields(&self) -> MdIndentFields {
-        MdIndentFields {
-            value_token: self.value_token(),
-        }
-    }
-    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 0usize)
-    }
-}
-impl Serialize for MdIndent {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        self.as_fields().serialize(serializer)
-    }
-}
-#[derive(Serialize)]
-pub struct MdIndentFields {
-    pub value_token: SyntaxResult<SyntaxToken>,
+    pub content: MdInlineItemList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdIndentCodeBlock {
@@ -406,10 +396,10 @@ impl MdIndentCodeBlock {
     }
     pub fn as_fields(&self) -> MdIndentCodeBlockFields {
         MdIndentCodeBlockFields {
-            lines: self.lines(),
+            content: self.content(),
         }
     }
-    pub fn lines(&self) -> MdIndentedCodeLineList {
+    pub fn content(&self) -> MdInlineItemList {
         support::list(&self.syntax, 0usize)
     }
 }
@@ -423,47 +413,7 @@ impl Serialize for MdIndentCodeBlock {
 }
 #[derive(Serialize)]
 pub struct MdIndentCodeBlockFields {
-    pub lines: MdIndentedCodeLineList,
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdIndentedCodeLine {
-    pub(crate) syntax: SyntaxNode,
-}
-impl MdIndentedCodeLine {
-    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
-    #[doc = r""]
-    #[doc = r" # Safety"]
-    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
-    #[doc = r" or a match on [SyntaxNode::kind]"]
-    #[inline]
-    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
-        Self { syntax }
-    }
-    pub fn as_fields(&self) -> MdIndentedCodeLineFields {
-        MdIndentedCodeLineFields {
-            indentation: self.indentation(),
-            content: self.content(),
-        }
-    }
-    pub fn indentation(&self) -> SyntaxResult<MdIndent> {
-        support::required_node(&self.syntax, 0usize)
-    }
-    pub fn content(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 1usize)
-    }
-}
-impl Serialize for MdIndentedCodeLine {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        self.as_fields().serialize(serializer)
-    }
-}
-#[derive(Serialize)]
-pub struct MdIndentedCodeLineFields {
-    pub indentation: SyntaxResult<MdIndent>,
-    pub content: SyntaxResult<MdTextual>,
+    pub content: MdInlineItemList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdInlineCode {
@@ -556,10 +506,10 @@ pub struct MdInlineEmphasisFields {
     pub r_fence: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdInlineImage {
+pub struct MdInlineHtml {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdInlineImage {
+impl MdInlineHtml {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -569,36 +519,16 @@ impl MdInlineImage {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdInlineImageFields {
-        MdInlineImageFields {
-            l_brack_token: self.l_brack_token(),
-            excl_token: self.excl_token(),
-            alt: self.alt(),
-            source: self.source(),
-            r_brack_token: self.r_brack_token(),
-            link: self.link(),
+    pub fn as_fields(&self) -> MdInlineHtmlFields {
+        MdInlineHtmlFields {
+            value_token: self.value_token(),
         }
     }
-    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn excl_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 1usize)
-    }
-    pub fn alt(&self) -> SyntaxResult<MdInlineImageAlt> {
-        support::required_node(&self.syntax, 2usize)
-    }
-    pub fn source(&self) -> SyntaxResult<MdInlineImageSource> {
-        support::required_node(&self.syntax, 3usize)
-    }
-    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 4usize)
-    }
-    pub fn link(&self) -> Option<MdInlineImageLink> {
-        support::node(&self.syntax, 5usize)
-    }
 }
-impl Serialize for MdInlineImage {
+impl Serialize for MdInlineHtml {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -607,19 +537,14 @@ impl Serialize for MdInlineImage {
     }
 }
 #[derive(Serialize)]
-pub struct MdInlineImageFields {
-    pub l_brack_token: SyntaxResult<SyntaxToken>,
-    pub excl_token: SyntaxResult<SyntaxToken>,
-    pub alt: SyntaxResult<MdInlineImageAlt>,
-    pub source: SyntaxResult<MdInlineImageSource>,
-    pub r_brack_token: SyntaxResult<SyntaxToken>,
-    pub link: Option<MdInlineImageLink>,
+pub struct MdInlineHtmlFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdInlineImageAlt {
+pub struct MdInlineImage {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdInlineImageAlt {
+impl MdInlineImage {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -629,24 +554,44 @@ impl MdInlineImageAlt {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdInlineImageAltFields {
-        MdInlineImageAltFields {
+    pub fn as_fields(&self) -> MdInlineImageFields {
+        MdInlineImageFields {
+            excl_token: self.excl_token(),
             l_brack_token: self.l_brack_token(),
-            content: self.content(),
+            alt: self.alt(),
             r_brack_token: self.r_brack_token(),
+            l_paren_token: self.l_paren_token(),
+            destination: self.destination(),
+            title: self.title(),
+            r_paren_token: self.r_paren_token(),
         }
     }
-    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn excl_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn content(&self) -> MdInlineItemList {
-        support::list(&self.syntax, 1usize)
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+    pub fn alt(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 2usize)
     }
     pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 2usize)
+        support::required_token(&self.syntax, 3usize)
+    }
+    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 4usize)
+    }
+    pub fn destination(&self) -> Option<MdLinkDestination> {
+        support::node(&self.syntax, 5usize)
+    }
+    pub fn title(&self) -> Option<MdLinkTitle> {
+        support::node(&self.syntax, 6usize)
+    }
+    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 7usize)
     }
 }
-impl Serialize for MdInlineImageAlt {
+impl Serialize for MdInlineImage {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -655,16 +600,21 @@ impl Serialize for MdInlineImageAlt {
     }
 }
 #[derive(Serialize)]
-pub struct MdInlineImageAltFields {
+pub struct MdInlineImageFields {
+    pub excl_token: SyntaxResult<SyntaxToken>,
     pub l_brack_token: SyntaxResult<SyntaxToken>,
-    pub content: MdInlineItemList,
+    pub alt: MdInlineItemList,
     pub r_brack_token: SyntaxResult<SyntaxToken>,
+    pub l_paren_token: SyntaxResult<SyntaxToken>,
+    pub destination: Option<MdLinkDestination>,
+    pub title: Option<MdLinkTitle>,
+    pub r_paren_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdInlineImageLink {
+pub struct MdInlineItalic {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdInlineImageLink {
+impl MdInlineItalic {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -674,24 +624,24 @@ impl MdInlineImageLink {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdInlineImageLinkFields {
-        MdInlineImageLinkFields {
-            l_paren_token: self.l_paren_token(),
+    pub fn as_fields(&self) -> MdInlineItalicFields {
+        MdInlineItalicFields {
+            l_fence: self.l_fence(),
             content: self.content(),
-            r_paren_token: self.r_paren_token(),
+            r_fence: self.r_fence(),
         }
     }
-    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn l_fence(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
     pub fn content(&self) -> MdInlineItemList {
         support::list(&self.syntax, 1usize)
     }
-    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn r_fence(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 2usize)
     }
 }
-impl Serialize for MdInlineImageLink {
+impl Serialize for MdInlineItalic {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -700,16 +650,16 @@ impl Serialize for MdInlineImageLink {
     }
 }
 #[derive(Serialize)]
-pub struct MdInlineImageLinkFields {
-    pub l_paren_token: SyntaxResult<SyntaxToken>,
+pub struct MdInlineItalicFields {
+    pub l_fence: SyntaxResult<SyntaxToken>,
     pub content: MdInlineItemList,
-    pub r_paren_token: SyntaxResult<SyntaxToken>,
+    pub r_fence: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdInlineImageSource {
+pub struct MdInlineLink {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdInlineImageSource {
+impl MdInlineLink {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -719,24 +669,40 @@ impl MdInlineImageSource {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdInlineImageSourceFields {
-        MdInlineImageSourceFields {
+    pub fn as_fields(&self) -> MdInlineLinkFields {
+        MdInlineLinkFields {
+            l_brack_token: self.l_brack_token(),
+            text: self.text(),
+            r_brack_token: self.r_brack_token(),
             l_paren_token: self.l_paren_token(),
-            content: self.content(),
+            destination: self.destination(),
+            title: self.title(),
             r_paren_token: self.r_paren_token(),
         }
     }
-    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn content(&self) -> MdInlineItemList {
+    pub fn text(&self) -> MdInlineItemList {
         support::list(&self.syntax, 1usize)
     }
-    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 2usize)
     }
+    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 3usize)
+    }
+    pub fn destination(&self) -> Option<MdLinkDestination> {
+        support::node(&self.syntax, 4usize)
+    }
+    pub fn title(&self) -> Option<MdLinkTitle> {
+        support::node(&self.syntax, 5usize)
+    }
+    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 6usize)
+    }
 }
-impl Serialize for MdInlineImageSource {
+impl Serialize for MdInlineLink {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -745,16 +711,20 @@ impl Serialize for MdInlineImageSource {
     }
 }
 #[derive(Serialize)]
-pub struct MdInlineImageSourceFields {
+pub struct MdInlineLinkFields {
+    pub l_brack_token: SyntaxResult<SyntaxToken>,
+    pub text: MdInlineItemList,
+    pub r_brack_token: SyntaxResult<SyntaxToken>,
     pub l_paren_token: SyntaxResult<SyntaxToken>,
-    pub content: MdInlineItemList,
+    pub destination: Option<MdLinkDestination>,
+    pub title: Option<MdLinkTitle>,
     pub r_paren_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdInlineItalic {
+pub struct MdInlineStrikethrough {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdInlineItalic {
+impl MdInlineStrikethrough {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -764,8 +734,8 @@ impl MdInlineItalic {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdInlineItalicFields {
-        MdInlineItalicFields {
+    pub fn as_fields(&self) -> MdInlineStrikethroughFields {
+        MdInlineStrikethroughFields {
             l_fence: self.l_fence(),
             content: self.content(),
             r_fence: self.r_fence(),
@@ -781,7 +751,7 @@ impl MdInlineItalic {
         support::required_token(&self.syntax, 2usize)
     }
 }
-impl Serialize for MdInlineItalic {
+impl Serialize for MdInlineStrikethrough {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -790,16 +760,16 @@ impl Serialize for MdInlineItalic {
     }
 }
 #[derive(Serialize)]
-pub struct MdInlineItalicFields {
+pub struct MdInlineStrikethroughFields {
     pub l_fence: SyntaxResult<SyntaxToken>,
     pub content: MdInlineItemList,
     pub r_fence: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdInlineLink {
+pub struct MdLinkDestination {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdInlineLink {
+impl MdLinkDestination {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -809,36 +779,71 @@ impl MdInlineLink {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdInlineLinkFields {
-        MdInlineLinkFields {
-            l_brack_token: self.l_brack_token(),
-            text: self.text(),
-            r_brack_token: self.r_brack_token(),
-            l_paren_token: self.l_paren_token(),
-            source: self.source(),
-            r_paren_token: self.r_paren_token(),
+    pub fn as_fields(&self) -> MdLinkDestinationFields {
+        MdLinkDestinationFields {
+            value_token: self.value_token(),
         }
     }
-    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn text(&self) -> MdInlineItemList {
-        support::list(&self.syntax, 1usize)
-    }
-    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 2usize)
+}
+impl Serialize for MdLinkDestination {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
-    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+}
+#[derive(Serialize)]
+pub struct MdLinkDestinationFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdLinkReferenceDefinition {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdLinkReferenceDefinition {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdLinkReferenceDefinitionFields {
+        MdLinkReferenceDefinitionFields {
+            l_brack_token: self.l_brack_token(),
+            label: self.label(),
+            r_brack_token: self.r_brack_token(),
+            colon_token: self.colon_token(),
+            destination: self.destination(),
+            title: self.title(),
+        }
+    }
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn label(&self) -> SyntaxResult<MdTextual> {
+        support::required_node(&self.syntax, 1usize)
+    }
+    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+    pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 3usize)
     }
-    pub fn source(&self) -> MdInlineItemList {
-        support::list(&self.syntax, 4usize)
+    pub fn destination(&self) -> SyntaxResult<MdLinkDestination> {
+        support::required_node(&self.syntax, 4usize)
     }
-    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 5usize)
+    pub fn title(&self) -> Option<MdLinkTitle> {
+        support::node(&self.syntax, 5usize)
     }
 }
-impl Serialize for MdInlineLink {
+impl Serialize for MdLinkReferenceDefinition {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -847,19 +852,19 @@ impl Serialize for MdInlineLink {
     }
 }
 #[derive(Serialize)]
-pub struct MdInlineLinkFields {
+pub struct MdLinkReferenceDefinitionFields {
     pub l_brack_token: SyntaxResult<SyntaxToken>,
-    pub text: MdInlineItemList,
+    pub label: SyntaxResult<MdTextual>,
     pub r_brack_token: SyntaxResult<SyntaxToken>,
-    pub l_paren_token: SyntaxResult<SyntaxToken>,
-    pub source: MdInlineItemList,
-    pub r_paren_token: SyntaxResult<SyntaxToken>,
+    pub colon_token: SyntaxResult<SyntaxToken>,
+    pub destination: SyntaxResult<MdLinkDestination>,
+    pub title: Option<MdLinkTitle>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdLinkBlock {
+pub struct MdLinkTitle {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdLinkBlock {
+impl MdLinkTitle {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -869,24 +874,51 @@ impl MdLinkBlock {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdLinkBlockFields {
-        MdLinkBlockFields {
-            label: self.label(),
-            url: self.url(),
-            title: self.title(),
+    pub fn as_fields(&self) -> MdLinkTitleFields {
+        MdLinkTitleFields {
+            value_token: self.value_token(),
         }
     }
-    pub fn label(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-    pub fn url(&self) -> SyntaxResult<MdTextual> {
-        support::required_node(&self.syntax, 1usize)
+}
+impl Serialize for MdLinkTitle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdLinkTitleFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdOrderedList {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdOrderedList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdOrderedListFields {
+        MdOrderedListFields {
+            items: self.items(),
+        }
     }
-    pub fn title(&self) -> Option<MdTextual> {
-        support::node(&self.syntax, 2usize)
+    pub fn items(&self) -> MdOrderedListItemList {
+        support::list(&self.syntax, 0usize)
     }
 }
-impl Serialize for MdLinkBlock {
+impl Serialize for MdOrderedList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -895,16 +927,14 @@ impl Serialize for MdLinkBlock {
     }
 }
 #[derive(Serialize)]
-pub struct MdLinkBlockFields {
-    pub label: SyntaxResult<MdTextual>,
-    pub url: SyntaxResult<MdTextual>,
-    pub title: Option<MdTextual>,
+pub struct MdOrderedListFields {
+    pub items: MdOrderedListItemList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdOrderListItem {
+pub struct MdOrderedListItem {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdOrderListItem {
+impl MdOrderedListItem {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -914,16 +944,24 @@ impl MdOrderListItem {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdOrderListItemFields {
-        MdOrderListItemFields {
-            md_bullet_list: self.md_bullet_list(),
+    pub fn as_fields(&self) -> MdOrderedListItemFields {
+        MdOrderedListItemFields {
+            marker_token: self.marker_token(),
+            task_token: self.task_token(),
+            content: self.content(),
         }
     }
-    pub fn md_bullet_list(&self) -> MdBulletList {
-        support::list(&self.syntax, 0usize)
+    pub fn marker_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn task_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 1usize)
+    }
+    pub fn content(&self) -> MdBlockList {
+        support::list(&self.syntax, 2usize)
     }
 }
-impl Serialize for MdOrderListItem {
+impl Serialize for MdOrderedListItem {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -932,8 +970,10 @@ impl Serialize for MdOrderListItem {
     }
 }
 #[derive(Serialize)]
-pub struct MdOrderListItemFields {
-    pub md_bullet_list: MdBulletList,
+pub struct MdOrderedListItemFields {
+    pub marker_token: SyntaxResult<SyntaxToken>,
+    pub task_token: Option<SyntaxToken>,
+    pub content: MdBlockList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdParagraph {
@@ -951,16 +991,12 @@ impl MdParagraph {
     }
     pub fn as_fields(&self) -> MdParagraphFields {
         MdParagraphFields {
-            list: self.list(),
-            hard_line: self.hard_line(),
+            content: self.content(),
         }
     }
-    pub fn list(&self) -> MdInlineItemList {
+    pub fn content(&self) -> MdInlineItemList {
         support::list(&self.syntax, 0usize)
     }
-    pub fn hard_line(&self) -> SyntaxResult<MdHardLine> {
-        support::required_node(&self.syntax, 1usize)
-    }
 }
 impl Serialize for MdParagraph {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -972,8 +1008,7 @@ impl Serialize for MdParagraph {
 }
 #[derive(Serialize)]
 pub struct MdParagraphFields {
-    pub list: MdInlineItemList,
-    pub hard_line: SyntaxResult<MdHardLine>,
+    pub content: MdInlineItemList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MdQuote {
@@ -991,11 +1026,15 @@ impl MdQuote {
     }
     pub fn as_fields(&self) -> MdQuoteFields {
         MdQuoteFields {
-            any_md_block: self.any_md_block(),
+            marker_token: self.marker_token(),
+            content: self.content(),
         }
     }
-    pub fn any_md_block(&self) -> SyntaxResult<AnyMdBlock> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn marker_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn content(&self) -> MdBlockList {
+        support::list(&self.syntax, 1usize)
     }
 }
 impl Serialize for MdQuote {
@@ -1008,13 +1047,14 @@ impl Serialize for MdQuote {
 }
 #[derive(Serialize)]
 pub struct MdQuoteFields {
-    pub any_md_block: SyntaxResult<AnyMdBlock>,
+    pub marker_token: SyntaxResult<SyntaxToken>,
+    pub content: MdBlockList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdSetextHeader {
+pub struct MdReferenceImage {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdSetextHeader {
+impl MdReferenceImage {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -1024,16 +1064,32 @@ impl MdSetextHeader {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdSetextHeaderFields {
-        MdSetextHeaderFields {
-            md_paragraph: self.md_paragraph(),
+    pub fn as_fields(&self) -> MdReferenceImageFields {
+        MdReferenceImageFields {
+            excl_token: self.excl_token(),
+            l_brack_token: self.l_brack_token(),
+            alt: self.alt(),
+            r_brack_token: self.r_brack_token(),
+            label: self.label(),
         }
     }
-    pub fn md_paragraph(&self) -> SyntaxResult<MdParagraph> {
-        support::required_node(&self.syntax, 0usize)
+    pub fn excl_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+    pub fn alt(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 2usize)
+    }
+    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 3usize)
+    }
+    pub fn label(&self) -> Option<MdReferenceLinkLabel> {
+        support::node(&self.syntax, 4usize)
     }
 }
-impl Serialize for MdSetextHeader {
+impl Serialize for MdReferenceImage {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -1042,14 +1098,18 @@ impl Serialize for MdSetextHeader {
     }
 }
 #[derive(Serialize)]
-pub struct MdSetextHeaderFields {
-    pub md_paragraph: SyntaxResult<MdParagraph>,
+pub struct MdReferenceImageFields {
+    pub excl_token: SyntaxResult<SyntaxToken>,
+    pub l_brack_token: SyntaxResult<SyntaxToken>,
+    pub alt: MdInlineItemList,
+    pub r_brack_token: SyntaxResult<SyntaxToken>,
+    pub label: Option<MdReferenceLinkLabel>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdSoftBreak {
+pub struct MdReferenceLink {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdSoftBreak {
+impl MdReferenceLink {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -1059,16 +1119,28 @@ impl MdSoftBreak {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdSoftBreakFields {
-        MdSoftBreakFields {
-            value_token: self.value_token(),
+    pub fn as_fields(&self) -> MdReferenceLinkFields {
+        MdReferenceLinkFields {
+            l_brack_token: self.l_brack_token(),
+            text: self.text(),
+            r_brack_token: self.r_brack_token(),
+            label: self.label(),
         }
     }
-    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
+    pub fn text(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+    pub fn label(&self) -> Option<MdReferenceLinkLabel> {
+        support::node(&self.syntax, 3usize)
+    }
 }
-impl Serialize for MdSoftBreak {
+impl Serialize for MdReferenceLink {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -1077,14 +1149,17 @@ impl Serialize for MdSoftBreak {
     }
 }
 #[derive(Serialize)]
-pub struct MdSoftBreakFields {
-    pub value_token: SyntaxResult<SyntaxToken>,
+pub struct MdReferenceLinkFields {
+    pub l_brack_token: SyntaxResult<SyntaxToken>,
+    pub text: MdInlineItemList,
+    pub r_brack_token: SyntaxResult<SyntaxToken>,
+    pub label: Option<MdReferenceLinkLabel>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdTextual {
+pub struct MdReferenceLinkLabel {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdTextual {
+impl MdReferenceLinkLabel {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -1094,16 +1169,24 @@ impl MdTextual {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdTextualFields {
-        MdTextualFields {
-            value_token: self.value_token(),
+    pub fn as_fields(&self) -> MdReferenceLinkLabelFields {
+        MdReferenceLinkLabelFields {
+            l_brack_token: self.l_brack_token(),
+            label: self.label(),
+            r_brack_token: self.r_brack_token(),
         }
     }
-    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
+    pub fn label(&self) -> Option<MdTextual> {
+        support::node(&self.syntax, 1usize)
+    }
+    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
 }
-impl Serialize for MdTextual {
+impl Serialize for MdReferenceLinkLabel {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -1112,14 +1195,16 @@ impl Serialize for MdTextual {
     }
 }
 #[derive(Serialize)]
-pub struct MdTextualFields {
-    pub value_token: SyntaxResult<SyntaxToken>,
+pub struct MdReferenceLinkLabelFields {
+    pub l_brack_token: SyntaxResult<SyntaxToken>,
+    pub label: Option<MdTextual>,
+    pub r_brack_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct MdThematicBreakBlock {
+pub struct MdSetextHeader {
     pub(crate) syntax: SyntaxNode,
 }
-impl MdThematicBreakBlock {
+impl MdSetextHeader {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -1129,16 +1214,20 @@ impl MdThematicBreakBlock {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> MdThematicBreakBlockFields {
-        MdThematicBreakBlockFields {
-            value_token: self.value_token(),
+    pub fn as_fields(&self) -> MdSetextHeaderFields {
+        MdSetextHeaderFields {
+            content: self.content(),
+            underline_token: self.underline_token(),
         }
     }
-    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 0usize)
+    pub fn content(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 0usize)
+    }
+    pub fn underline_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
     }
 }
-impl Serialize for MdThematicBreakBlock {
+impl Serialize for MdSetextHeader {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -1147,201 +1236,896 @@ impl Serialize for MdThematicBreakBlock {
     }
 }
 #[derive(Serialize)]
-pub struct MdThematicBreakBlockFields {
-    pub value_token: SyntaxResult<SyntaxToken>,
+pub struct MdSetextHeaderFields {
+    pub content: MdInlineItemList,
+    pub underline_token: SyntaxResult<SyntaxToken>,
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyCodeBlock {
-    MdFencedCodeBlock(MdFencedCodeBlock),
-    MdIndentCodeBlock(MdIndentCodeBlock),
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdTable {
+    pub(crate) syntax: SyntaxNode,
 }
-impl AnyCodeBlock {
-    pub fn as_md_fenced_code_block(&self) -> Option<&MdFencedCodeBlock> {
-        match &self {
-            Self::MdFencedCodeBlock(item) => Some(item),
-            _ => None,
-        }
+impl MdTable {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_md_indent_code_block(&self) -> Option<&MdIndentCodeBlock> {
-        match &self {
-            Self::MdIndentCodeBlock(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> MdTableFields {
+        MdTableFields {
+            header: self.header(),
+            delimiter: self.delimiter(),
+            rows: self.rows(),
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyContainerBlock {
-    MdBulletListItem(MdBulletListItem),
-    MdOrderListItem(MdOrderListItem),
-    MdQuote(MdQuote),
-}
-impl AnyContainerBlock {
-    pub fn as_md_bullet_list_item(&self) -> Option<&MdBulletListItem> {
-        match &self {
-            Self::MdBulletListItem(item) => Some(item),
-            _ => None,
-        }
+    pub fn header(&self) -> SyntaxResult<MdTableRow> {
+        support::required_node(&self.syntax, 0usize)
     }
-    pub fn as_md_order_list_item(&self) -> Option<&MdOrderListItem> {
-        match &self {
-            Self::MdOrderListItem(item) => Some(item),
-            _ => None,
-        }
+    pub fn delimiter(&self) -> SyntaxResult<MdTableDelimiterRow> {
+        support::required_node(&self.syntax, 1usize)
     }
-    pub fn as_md_quote(&self) -> Option<&MdQuote> {
-        match &self {
-            Self::MdQuote(item) => Some(item),
-            _ => None,
-        }
+    pub fn rows(&self) -> MdTableRowList {
+        support::list(&self.syntax, 2usize)
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyLeafBlock {
-    AnyCodeBlock(AnyCodeBlock),
-    MdHeader(MdHeader),
-    MdHtmlBlock(MdHtmlBlock),
-    MdLinkBlock(MdLinkBlock),
-    MdParagraph(MdParagraph),
-    MdSetextHeader(MdSetextHeader),
-    MdThematicBreakBlock(MdThematicBreakBlock),
+impl Serialize for MdTable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
 }
-impl AnyLeafBlock {
-    pub fn as_any_code_block(&self) -> Option<&AnyCodeBlock> {
-        match &self {
-            Self::AnyCodeBlock(item) => Some(item),
-            _ => None,
-        }
+#[derive(Serialize)]
+pub struct MdTableFields {
+    pub header: SyntaxResult<MdTableRow>,
+    pub delimiter: SyntaxResult<MdTableDelimiterRow>,
+    pub rows: MdTableRowList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdTableCell {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdTableCell {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_md_header(&self) -> Option<&MdHeader> {
-        match &self {
-            Self::MdHeader(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> MdTableCellFields {
+        MdTableCellFields {
+            l_pipe_token: self.l_pipe_token(),
+            content: self.content(),
         }
     }
-    pub fn as_md_html_block(&self) -> Option<&MdHtmlBlock> {
-        match &self {
-            Self::MdHtmlBlock(item) => Some(item),
-            _ => None,
+    pub fn l_pipe_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 0usize)
+    }
+    pub fn content(&self) -> MdInlineItemList {
+        support::list(&self.syntax, 1usize)
+    }
+}
+impl Serialize for MdTableCell {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdTableCellFields {
+    pub l_pipe_token: Option<SyntaxToken>,
+    pub content: MdInlineItemList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdTableDelimiterCell {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdTableDelimiterCell {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdTableDelimiterCellFields {
+        MdTableDelimiterCellFields {
+            l_pipe_token: self.l_pipe_token(),
+            value_token: self.value_token(),
         }
     }
-    pub fn as_md_link_block(&self) -> Option<&MdLinkBlock> {
-        match &self {
-            Self::MdLinkBlock(item) => Some(item),
-            _ => None,
+    pub fn l_pipe_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 0usize)
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+}
+impl Serialize for MdTableDelimiterCell {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdTableDelimiterCellFields {
+    pub l_pipe_token: Option<SyntaxToken>,
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdTableDelimiterRow {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdTableDelimiterRow {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdTableDelimiterRowFields {
+        MdTableDelimiterRowFields {
+            cells: self.cells(),
+            r_pipe_token: self.r_pipe_token(),
         }
     }
-    pub fn as_md_paragraph(&self) -> Option<&MdParagraph> {
-        match &self {
-            Self::MdParagraph(item) => Some(item),
-            _ => None,
+    pub fn cells(&self) -> MdTableDelimiterCellList {
+        support::list(&self.syntax, 0usize)
+    }
+    pub fn r_pipe_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 1usize)
+    }
+}
+impl Serialize for MdTableDelimiterRow {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdTableDelimiterRowFields {
+    pub cells: MdTableDelimiterCellList,
+    pub r_pipe_token: Option<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdTableRow {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdTableRow {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdTableRowFields {
+        MdTableRowFields {
+            cells: self.cells(),
+            r_pipe_token: self.r_pipe_token(),
         }
     }
-    pub fn as_md_setext_header(&self) -> Option<&MdSetextHeader> {
+    pub fn cells(&self) -> MdTableCellList {
+        support::list(&self.syntax, 0usize)
+    }
+    pub fn r_pipe_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 1usize)
+    }
+}
+impl Serialize for MdTableRow {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdTableRowFields {
+    pub cells: MdTableCellList,
+    pub r_pipe_token: Option<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdTextual {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdTextual {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdTextualFields {
+        MdTextualFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for MdTextual {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdTextualFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdThematicBreakBlock {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdThematicBreakBlock {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdThematicBreakBlockFields {
+        MdThematicBreakBlockFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+impl Serialize for MdThematicBreakBlock {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[derive(Serialize)]
+pub struct MdThematicBreakBlockFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyMdBlock {
+    AnyMdContainerBlock(AnyMdContainerBlock),
+    AnyMdLeafBlock(AnyMdLeafBlock),
+}
+impl AnyMdBlock {
+    pub fn as_any_md_container_block(&self) -> Option<&AnyMdContainerBlock> {
         match &self {
-            Self::MdSetextHeader(item) => Some(item),
+            Self::AnyMdContainerBlock(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_md_thematic_break_block(&self) -> Option<&MdThematicBreakBlock> {
+    pub fn as_any_md_leaf_block(&self) -> Option<&AnyMdLeafBlock> {
         match &self {
-            Self::MdThematicBreakBlock(item) => Some(item),
+            Self::AnyMdLeafBlock(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
-pub enum AnyMdBlock {
-    AnyContainerBlock(AnyContainerBlock),
-    AnyLeafBlock(AnyLeafBlock),
+pub enum AnyMdContainerBlock {
+    MdBulletList(MdBulletList),
+    MdOrderedList(MdOrderedList),
+    MdQuote(MdQuote),
 }
-impl AnyMdBlock {
-    pub fn as_any_container_block(&self) -> Option<&AnyContainerBlock> {
+impl AnyMdContainerBlock {
+    pub fn as_md_bullet_list(&self) -> Option<&MdBulletList> {
+        match &self {
+            Self::MdBulletList(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_md_ordered_list(&self) -> Option<&MdOrderedList> {
         match &self {
-            Self::AnyContainerBlock(item) => Some(item),
+            Self::MdOrderedList(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_any_leaf_block(&self) -> Option<&AnyLeafBlock> {
+    pub fn as_md_quote(&self) -> Option<&MdQuote> {
         match &self {
-            Self::AnyLeafBlock(item) => Some(item),
+            Self::MdQuote(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash, Serialize)]
 pub enum AnyMdInline {
+    MdAutolink(MdAutolink),
     MdHardLine(MdHardLine),
-    MdHtmlBlock(MdHtmlBlock),
     MdInlineCode(MdInlineCode),
     MdInlineEmphasis(MdInlineEmphasis),
+    MdInlineHtml(MdInlineHtml),
     MdInlineImage(MdInlineImage),
     MdInlineItalic(MdInlineItalic),
     MdInlineLink(MdInlineLink),
-    MdSoftBreak(MdSoftBreak),
+    MdInlineStrikethrough(MdInlineStrikethrough),
+    MdReferenceImage(MdReferenceImage),
+    MdReferenceLink(MdReferenceLink),
     MdTextual(MdTextual),
 }
-impl AnyMdInline {
-    pub fn as_md_hard_line(&self) -> Option<&MdHardLine> {
-        match &self {
-            Self::MdHardLine(item) => Some(item),
-            _ => None,
-        }
+impl AnyMdInline {
+    pub fn as_md_autolink(&self) -> Option<&MdAutolink> {
+        match &self {
+            Self::MdAutolink(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_md_hard_line(&self) -> Option<&MdHardLine> {
+        match &self {
+            Self::MdHardLine(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_md_inline_code(&self) -> Option<&MdInlineCode> {
+        match &self {
+            Self::MdInlineCode(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_md_inline_emphasis(&self) -> Option<&MdInlineEmphasis> {
+        match &self {
+            Self::MdInlineEmphasis(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_md_inline_html(&self) -> Option<&MdInlineHtml> {
+        match &self {
+            Self::MdInlineHtml(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_md_inline_image(&self) -> Option<&MdInlineImage> {
+        match &self {
+            Self::MdInlineImage(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_md_inline_italic(&self) -> Option<&MdInlineItalic> {
+        match &self {
+            Self::MdInlineItalic(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_md_inline_link(&self) -> Option<&MdInlineLink> {
+        match &self {
+            Self::MdInlineLink(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_md_inline_strikethrough(&self) -> Option<&MdInlineStrikethrough> {
+        match &self {
+            Self::MdInlineStrikethrough(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_md_reference_image(&self) -> Option<&MdReferenceImage> {
+        match &self {
+            Self::MdReferenceImage(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_md_reference_link(&self) -> Option<&MdReferenceLink> {
+        match &self {
+            Self::MdReferenceLink(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_md_textual(&self) -> Option<&MdTextual> {
+        match &self {
+            Self::MdTextual(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum AnyMdLeafBlock {
+    MdFencedCodeBlock(MdFencedCodeBlock),
+    MdHeader(MdHeader),
+    MdHtmlBlock(MdHtmlBlock),
+    MdIndentCodeBlock(MdIndentCodeBlock),
+    MdLinkReferenceDefinition(MdLinkReferenceDefinition),
+    MdParagraph(MdParagraph),
+    MdSetextHeader(MdSetextHeader),
+    MdTable(MdTable),
+    MdThematicBreakBlock(MdThematicBreakBlock),
+}
+impl AnyMdLeafBlock {
+    pub fn as_md_fenced_code_block(&self) -> Option<&MdFencedCodeBlock> {
+        match &self {
+            Self::MdFencedCodeBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_md_header(&self) -> Option<&MdHeader> {
+        match &self {
+            Self::MdHeader(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_md_html_block(&self) -> Option<&MdHtmlBlock> {
+        match &self {
+            Self::MdHtmlBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_md_indent_code_block(&self) -> Option<&MdIndentCodeBlock> {
+        match &self {
+            Self::MdIndentCodeBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_md_link_reference_definition(&self) -> Option<&MdLinkReferenceDefinition> {
+        match &self {
+            Self::MdLinkReferenceDefinition(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_md_paragraph(&self) -> Option<&MdParagraph> {
+        match &self {
+            Self::MdParagraph(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_md_setext_header(&self) -> Option<&MdSetextHeader> {
+        match &self {
+            Self::MdSetextHeader(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_md_table(&self) -> Option<&MdTable> {
+        match &self {
+            Self::MdTable(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_md_thematic_break_block(&self) -> Option<&MdThematicBreakBlock> {
+        match &self {
+            Self::MdThematicBreakBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+impl AstNode for MdAutolink {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_AUTOLINK as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_AUTOLINK
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdAutolink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("MdAutolink")
+                .field(
+                    "value_token",
+                    &support::DebugSyntaxResult(self.value_token()),
+                )
+                .finish()
+        } else {
+            f.debug_struct("MdAutolink").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<MdAutolink> for SyntaxNode {
+    fn from(n: MdAutolink) -> Self {
+        n.syntax
+    }
+}
+impl From<MdAutolink> for SyntaxElement {
+    fn from(n: MdAutolink) -> Self {
+        n.syntax.into()
+    }
+}
+impl AstNode for MdBulletList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_BULLET_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_BULLET_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdBulletList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("MdBulletList")
+                .field("items", &self.items())
+                .finish()
+        } else {
+            f.debug_struct("MdBulletList").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<MdBulletList> for SyntaxNode {
+    fn from(n: MdBulletList) -> Self {
+        n.syntax
+    }
+}
+impl From<MdBulletList> for SyntaxElement {
+    fn from(n: MdBulletList) -> Self {
+        n.syntax.into()
+    }
+}
+impl AstNode for MdBulletListItem {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_BULLET_LIST_ITEM as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_BULLET_LIST_ITEM
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdBulletListItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("MdBulletListItem")
+                .field("bullet", &support::DebugSyntaxResult(self.bullet()))
+                .field(
+                    "task_token",
+                    &support::DebugOptionalElement(self.task_token()),
+                )
+                .field("content", &self.content())
+                .finish()
+        } else {
+            f.debug_struct("MdBulletListItem").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<MdBulletListItem> for SyntaxNode {
+    fn from(n: MdBulletListItem) -> Self {
+        n.syntax
+    }
+}
+impl From<MdBulletListItem> for SyntaxElement {
+    fn from(n: MdBulletListItem) -> Self {
+        n.syntax.into()
+    }
+}
+impl AstNode for MdDocument {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_DOCUMENT as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_DOCUMENT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdDocument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("MdDocument")
+                .field(
+                    "bom_token",
+                    &support::DebugOptionalElement(self.bom_token()),
+                )
+                .field("value", &self.value())
+                .field("eof_token", &support::DebugSyntaxResult(self.eof_token()))
+                .finish()
+        } else {
+            f.debug_struct("MdDocument").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<MdDocument> for SyntaxNode {
+    fn from(n: MdDocument) -> Self {
+        n.syntax
+    }
+}
+impl From<MdDocument> for SyntaxElement {
+    fn from(n: MdDocument) -> Self {
+        n.syntax.into()
+    }
+}
+impl AstNode for MdFencedCodeBlock {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_FENCED_CODE_BLOCK as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_FENCED_CODE_BLOCK
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdFencedCodeBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("MdFencedCodeBlock")
+                .field(
+                    "l_fence_token",
+                    &support::DebugSyntaxResult(self.l_fence_token()),
+                )
+                .field("info", &support::DebugOptionalElement(self.info()))
+                .field("content", &self.content())
+                .field(
+                    "r_fence_token",
+                    &support::DebugOptionalElement(self.r_fence_token()),
+                )
+                .finish()
+        } else {
+            f.debug_struct("MdFencedCodeBlock").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<MdFencedCodeBlock> for SyntaxNode {
+    fn from(n: MdFencedCodeBlock) -> Self {
+        n.syntax
+    }
+}
+impl From<MdFencedCodeBlock> for SyntaxElement {
+    fn from(n: MdFencedCodeBlock) -> Self {
+        n.syntax.into()
+    }
+}
+impl AstNode for MdHardLine {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_HARD_LINE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_HARD_LINE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdHardLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("MdHardLine")
+                .field(
+                    "value_token",
+                    &support::DebugSyntaxResult(self.value_token()),
+                )
+                .finish()
+        } else {
+            f.debug_struct("MdHardLine").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<MdHardLine> for SyntaxNode {
+    fn from(n: MdHardLine) -> Self {
+        n.syntax
+    }
+}
+impl From<MdHardLine> for SyntaxElement {
+    fn from(n: MdHardLine) -> Self {
+        n.syntax.into()
+    }
+}
+impl AstNode for MdHash {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_HASH as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_HASH
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdHash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("MdHash")
+                .field("hash_token", &support::DebugSyntaxResult(self.hash_token()))
+                .finish()
+        } else {
+            f.debug_struct("MdHash").finish()
+        };
+        DEPTH.set(current_depth);
+        result
+    }
+}
+impl From<MdHash> for SyntaxNode {
+    fn from(n: MdHash) -> Self {
+        n.syntax
     }
-    pub fn as_md_html_block(&self) -> Option<&MdHtmlBlock> {
-        match &self {
-            Self::MdHtmlBlock(item) => Some(item),
-            _ => None,
-        }
+}
+impl From<MdHash> for SyntaxElement {
+    fn from(n: MdHash) -> Self {
+        n.syntax.into()
     }
-    pub fn as_md_inline_code(&self) -> Option<&MdInlineCode> {
-        match &self {
-            Self::MdInlineCode(item) => Some(item),
-            _ => None,
-        }
+}
+impl AstNode for MdHeader {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_HEADER as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_HEADER
     }
-    pub fn as_md_inline_emphasis(&self) -> Option<&MdInlineEmphasis> {
-        match &self {
-            Self::MdInlineEmphasis(item) => Some(item),
-            _ => None,
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
         }
     }
-    pub fn as_md_inline_image(&self) -> Option<&MdInlineImage> {
-        match &self {
-            Self::MdInlineImage(item) => Some(item),
-            _ => None,
-        }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
     }
-    pub fn as_md_inline_italic(&self) -> Option<&MdInlineItalic> {
-        match &self {
-            Self::MdInlineItalic(item) => Some(item),
-            _ => None,
-        }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
     }
-    pub fn as_md_inline_link(&self) -> Option<&MdInlineLink> {
-        match &self {
-            Self::MdInlineLink(item) => Some(item),
-            _ => None,
-        }
+}
+impl std::fmt::Debug for MdHeader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
+        let current_depth = DEPTH.get();
+        let result = if current_depth < 16 {
+            DEPTH.set(current_depth + 1);
+            f.debug_struct("MdHeader")
+                .field("before", &self.before())
+                .field("content", &self.content())
+                .field("after", &self.after())
+                .finish()
+        } else {
+            f.debug_struct("MdHeader").finish()
+        };
+        DEPTH.set(current_depth);
+        result
     }
-    pub fn as_md_soft_break(&self) -> Option<&MdSoftBreak> {
-        match &self {
-            Self::MdSoftBreak(item) => Some(item),
-            _ => None,
-        }
+}
+impl From<MdHeader> for SyntaxNode {
+    fn from(n: MdHeader) -> Self {
+        n.syntax
     }
-    pub fn as_md_textual(&self) -> Option<&MdTextual> {
-        match &self {
-            Self::MdTextual(item) => Some(item),
-            _ => None,
-        }
+}
+impl From<MdHeader> for SyntaxElement {
+    fn from(n: MdHeader) -> Self {
+        n.syntax.into()
     }
 }
-impl AstNode for MdBullet {
+impl AstNode for MdHtmlBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_BULLET as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_HTML_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_BULLET
+        kind == MD_HTML_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1357,43 +2141,38 @@ impl AstNode for MdBullet {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdBullet {
+impl std::fmt::Debug for MdHtmlBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdBullet")
-                .field("bullet", &support::DebugSyntaxResult(self.bullet()))
-                .field(
-                    "space_token",
-                    &support::DebugSyntaxResult(self.space_token()),
-                )
+            f.debug_struct("MdHtmlBlock")
                 .field("content", &self.content())
                 .finish()
         } else {
-            f.debug_struct("MdBullet").finish()
+            f.debug_struct("MdHtmlBlock").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdBullet> for SyntaxNode {
-    fn from(n: MdBullet) -> Self {
+impl From<MdHtmlBlock> for SyntaxNode {
+    fn from(n: MdHtmlBlock) -> Self {
         n.syntax
     }
 }
-impl From<MdBullet> for SyntaxElement {
-    fn from(n: MdBullet) -> Self {
+impl From<MdHtmlBlock> for SyntaxElement {
+    fn from(n: MdHtmlBlock) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdBulletListItem {
+impl AstNode for MdIndentCodeBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_BULLET_LIST_ITEM as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INDENT_CODE_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_BULLET_LIST_ITEM
+        kind == MD_INDENT_CODE_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1409,38 +2188,38 @@ impl AstNode for MdBulletListItem {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdBulletListItem {
+impl std::fmt::Debug for MdIndentCodeBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdBulletListItem")
-                .field("md_bullet_list", &self.md_bullet_list())
+            f.debug_struct("MdIndentCodeBlock")
+                .field("content", &self.content())
                 .finish()
         } else {
-            f.debug_struct("MdBulletListItem").finish()
+            f.debug_struct("MdIndentCodeBlock").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdBulletListItem> for SyntaxNode {
-    fn from(n: MdBulletListItem) -> Self {
+impl From<MdIndentCodeBlock> for SyntaxNode {
+    fn from(n: MdIndentCodeBlock) -> Self {
         n.syntax
     }
 }
-impl From<MdBulletListItem> for SyntaxElement {
-    fn from(n: MdBulletListItem) -> Self {
+impl From<MdIndentCodeBlock> for SyntaxElement {
+    fn from(n: MdIndentCodeBlock) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdDocument {
+impl AstNode for MdInlineCode {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_DOCUMENT as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_CODE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_DOCUMENT
+        kind == MD_INLINE_CODE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1456,43 +2235,46 @@ impl AstNode for MdDocument {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdDocument {
+impl std::fmt::Debug for MdInlineCode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdDocument")
+            f.debug_struct("MdInlineCode")
                 .field(
-                    "bom_token",
-                    &support::DebugOptionalElement(self.bom_token()),
+                    "l_tick_token",
+                    &support::DebugSyntaxResult(self.l_tick_token()),
+                )
+                .field("content", &self.content())
+                .field(
+                    "r_tick_token",
+                    &support::DebugSyntaxResult(self.r_tick_token()),
                 )
-                .field("value", &self.value())
-                .field("eof_token", &support::DebugSyntaxResult(self.eof_token()))
                 .finish()
         } else {
-            f.debug_struct("MdDocument").finish()
+            f.debug_struct("MdInlineCode").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdDocument> for SyntaxNode {
-    fn from(n: MdDocument) -> Self {
+impl From<MdInlineCode> for SyntaxNode {
+    fn from(n: MdInlineCode) -> Self {
         n.syntax
     }
 }
-impl From<MdDocument> for SyntaxElement {
-    fn from(n: MdDocument) -> Self {
+impl From<MdInlineCode> for SyntaxElement {
+    fn from(n: MdInlineCode) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdFencedCodeBlock {
+impl AstNode for MdInlineEmphasis {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_FENCED_CODE_BLOCK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_EMPHASIS as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_FENCED_CODE_BLOCK
+        kind == MD_INLINE_EMPHASIS
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1508,55 +2290,40 @@ impl AstNode for MdFencedCodeBlock {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdFencedCodeBlock {
+impl std::fmt::Debug for MdInlineEmphasis {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdFencedCodeBlock")
-                .field(
-                    "l_fence_token",
-                    &support::DebugSyntaxResult(self.l_fence_token()),
-                )
-                .field("code_list", &self.code_list())
-                .field(
-                    "l_hard_line",
-                    &support::DebugSyntaxResult(self.l_hard_line()),
-                )
-                .field("content", &support::DebugSyntaxResult(self.content()))
-                .field(
-                    "r_hard_line",
-                    &support::DebugSyntaxResult(self.r_hard_line()),
-                )
-                .field(
-                    "r_fence_token",
-                    &support::DebugSyntaxResult(self.r_fence_token()),
-                )
+            f.debug_struct("MdInlineEmphasis")
+                .field("l_fence", &support::DebugSyntaxResult(self.l_fence()))
+                .field("content", &self.content())
+                .field("r_fence", &support::DebugSyntaxResult(self.r_fence()))
                 .finish()
         } else {
-            f.debug_struct("MdFencedCodeBlock").finish()
+            f.debug_struct("MdInlineEmphasis").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdFencedCodeBlock> for SyntaxNode {
-    fn from(n: MdFencedCodeBlock) -> Self {
+impl From<MdInlineEmphasis> for SyntaxNode {
+    fn from(n: MdInlineEmphasis) -> Self {
         n.syntax
     }
 }
-impl From<MdFencedCodeBlock> for SyntaxElement {
-    fn from(n: MdFencedCodeBlock) -> Self {
+impl From<MdInlineEmphasis> for SyntaxElement {
+    fn from(n: MdInlineEmphasis) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdHardLine {
+impl AstNode for MdInlineHtml {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_HARD_LINE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_HTML as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_HARD_LINE
+        kind == MD_INLINE_HTML
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1572,41 +2339,41 @@ impl AstNode for MdHardLine {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdHardLine {
+impl std::fmt::Debug for MdInlineHtml {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdHardLine")
+            f.debug_struct("MdInlineHtml")
                 .field(
                     "value_token",
                     &support::DebugSyntaxResult(self.value_token()),
                 )
                 .finish()
         } else {
-            f.debug_struct("MdHardLine").finish()
+            f.debug_struct("MdInlineHtml").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdHardLine> for SyntaxNode {
-    fn from(n: MdHardLine) -> Self {
+impl From<MdInlineHtml> for SyntaxNode {
+    fn from(n: MdInlineHtml) -> Self {
         n.syntax
     }
 }
-impl From<MdHardLine> for SyntaxElement {
-    fn from(n: MdHardLine) -> Self {
+impl From<MdInlineHtml> for SyntaxElement {
+    fn from(n: MdInlineHtml) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdHash {
+impl AstNode for MdInlineImage {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_HASH as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_IMAGE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_HASH
+        kind == MD_INLINE_IMAGE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1622,38 +2389,60 @@ impl AstNode for MdHash {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdHash {
+impl std::fmt::Debug for MdInlineImage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdHash")
-                .field("hash_token", &support::DebugSyntaxResult(self.hash_token()))
+            f.debug_struct("MdInlineImage")
+                .field("excl_token", &support::DebugSyntaxResult(self.excl_token()))
+                .field(
+                    "l_brack_token",
+                    &support::DebugSyntaxResult(self.l_brack_token()),
+                )
+                .field("alt", &self.alt())
+                .field(
+                    "r_brack_token",
+                    &support::DebugSyntaxResult(self.r_brack_token()),
+                )
+                .field(
+                    "l_paren_token",
+                    &support::DebugSyntaxResult(self.l_paren_token()),
+                )
+                .field(
+                    "destination",
+                    &support::DebugOptionalElement(self.destination()),
+                )
+                .field("title", &support::DebugOptionalElement(self.title()))
+                .field(
+                    "r_paren_token",
+                    &support::DebugSyntaxResult(self.r_paren_token()),
+                )
                 .finish()
         } else {
-            f.debug_struct("MdHash").finish()
+            f.debug_struct("MdInlineImage").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdHash> for SyntaxNode {
-    fn from(n: MdHash) -> Self {
+impl From<MdInlineImage> for SyntaxNode {
+    fn from(n: MdInlineImage) -> Self {
         n.syntax
     }
 }
-impl From<MdHash> for SyntaxElement {
-    fn from(n: MdHash) -> Self {
+impl From<MdInlineImage> for SyntaxElement {
+    fn from(n: MdInlineImage) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdHeader {
+impl AstNode for MdInlineItalic {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_HEADER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_ITALIC as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_HEADER
+        kind == MD_INLINE_ITALIC
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1669,40 +2458,40 @@ impl AstNode for MdHeader {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdHeader {
+impl std::fmt::Debug for MdInlineItalic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdHeader")
-                .field("before", &self.before())
-                .field("content", &support::DebugOptionalElement(self.content()))
-                .field("after", &self.after())
+            f.debug_struct("MdInlineItalic")
+                .field("l_fence", &support::DebugSyntaxResult(self.l_fence()))
+                .field("content", &self.content())
+                .field("r_fence", &support::DebugSyntaxResult(self.r_fence()))
                 .finish()
         } else {
-            f.debug_struct("MdHeader").finish()
+            f.debug_struct("MdInlineItalic").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdHeader> for SyntaxNode {
-    fn from(n: MdHeader) -> Self {
+impl From<MdInlineItalic> for SyntaxNode {
+    fn from(n: MdInlineItalic) -> Self {
         n.syntax
     }
 }
-impl From<MdHeader> for SyntaxElement {
-    fn from(n: MdHeader) -> Self {
+impl From<MdInlineItalic> for SyntaxElement {
+    fn from(n: MdInlineItalic) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdHtmlBlock {
+impl AstNode for MdInlineLink {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_HTML_BLOCK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_LINK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_HTML_BLOCK
+        kind == MD_INLINE_LINK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1718,38 +2507,59 @@ impl AstNode for MdHtmlBlock {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdHtmlBlock {
+impl std::fmt::Debug for MdInlineLink {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdHtmlBlock")
-                .field("md_textual", &support::DebugSyntaxResult(self.md_textual()))
+            f.debug_struct("MdInlineLink")
+                .field(
+                    "l_brack_token",
+                    &support::DebugSyntaxResult(self.l_brack_token()),
+                )
+                .field("text", &self.text())
+                .field(
+                    "r_brack_token",
+                    &support::DebugSyntaxResult(self.r_brack_token()),
+                )
+                .field(
+                    "l_paren_token",
+                    &support::DebugSyntaxResult(self.l_paren_token()),
+                )
+                .field(
+                    "destination",
+                    &support::DebugOptionalElement(self.destination()),
+                )
+                .field("title", &support::DebugOptionalElement(self.title()))
+                .field(
+                    "r_paren_token",
+                    &support::DebugSyntaxResult(self.r_paren_token()),
+                )
                 .finish()
         } else {
-            f.debug_struct("MdHtmlBlock").finish()
+            f.debug_struct("MdInlineLink").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdHtmlBlock> for SyntaxNode {
-    fn from(n: MdHtmlBlock) -> Self {
+impl From<MdInlineLink> for SyntaxNode {
+    fn from(n: MdInlineLink) -> Self {
         n.syntax
     }
 }
-impl From<MdHtmlBlock> for SyntaxElement {
-    fn from(n: MdHtmlBlock) -> Self {
+impl From<MdInlineLink> for SyntaxElement {
+    fn from(n: MdInlineLink) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdIndent {
+impl AstNode for MdInlineStrikethrough {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INDENT as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_STRIKETHROUGH as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INDENT
+        kind == MD_INLINE_STRIKETHROUGH
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1765,41 +2575,40 @@ impl AstNode for MdIndent {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdIndent {
+impl std::fmt::Debug for MdInlineStrikethrough {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdIndent")
-                .field(
-                    "value_token",
-                    &support::DebugSyntaxResult(self.value_token()),
-                )
+            f.debug_struct("MdInlineStrikethrough")
+                .field("l_fence", &support::DebugSyntaxResult(self.l_fence()))
+                .field("content", &self.content())
+                .field("r_fence", &support::DebugSyntaxResult(self.r_fence()))
                 .finish()
         } else {
-            f.debug_struct("MdIndent").finish()
+            f.debug_struct("MdInlineStrikethrough").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdIndent> for SyntaxNode {
-    fn from(n: MdIndent) -> Self {
+impl From<MdInlineStrikethrough> for SyntaxNode {
+    fn from(n: MdInlineStrikethrough) -> Self {
         n.syntax
     }
 }
-impl From<MdIndent> for SyntaxElement {
-    fn from(n: MdIndent) -> Self {
+impl From<MdInlineStrikethrough> for SyntaxElement {
+    fn from(n: MdInlineStrikethrough) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdIndentCodeBlock {
+impl AstNode for MdLinkDestination {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INDENT_CODE_BLOCK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_LINK_DESTINATION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INDENT_CODE_BLOCK
+        kind == MD_LINK_DESTINATION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1815,38 +2624,41 @@ impl AstNode for MdIndentCodeBlock {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdIndentCodeBlock {
+impl std::fmt::Debug for MdLinkDestination {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdIndentCodeBlock")
-                .field("lines", &self.lines())
+            f.debug_struct("MdLinkDestination")
+                .field(
+                    "value_token",
+                    &support::DebugSyntaxResult(self.value_token()),
+                )
                 .finish()
         } else {
-            f.debug_struct("MdIndentCodeBlock").finish()
+            f.debug_struct("MdLinkDestination").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdIndentCodeBlock> for SyntaxNode {
-    fn from(n: MdIndentCodeBlock) -> Self {
+impl From<MdLinkDestination> for SyntaxNode {
+    fn from(n: MdLinkDestination) -> Self {
         n.syntax
     }
 }
-impl From<MdIndentCodeBlock> for SyntaxElement {
-    fn from(n: MdIndentCodeBlock) -> Self {
+impl From<MdLinkDestination> for SyntaxElement {
+    fn from(n: MdLinkDestination) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdIndentedCodeLine {
+impl AstNode for MdLinkReferenceDefinition {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INDENTED_CODE_LINE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_LINK_REFERENCE_DEFINITION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INDENTED_CODE_LINE
+        kind == MD_LINK_REFERENCE_DEFINITION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1862,42 +2674,55 @@ impl AstNode for MdIndentedCodeLine {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdIndentedCodeLine {
+impl std::fmt::Debug for MdLinkReferenceDefinition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdIndentedCodeLine")
+            f.debug_struct("MdLinkReferenceDefinition")
+                .field(
+                    "l_brack_token",
+                    &support::DebugSyntaxResult(self.l_brack_token()),
+                )
+                .field("label", &support::DebugSyntaxResult(self.label()))
+                .field(
+                    "r_brack_token",
+                    &support::DebugSyntaxResult(self.r_brack_token()),
+                )
+                .field(
+                    "colon_token",
+                    &support::DebugSyntaxResult(self.colon_token()),
+                )
                 .field(
-                    "indentation",
-                    &support::DebugSyntaxResult(self.indentation()),
+                    "destination",
+                    &support::DebugSyntaxResult(self.destination()),
                 )
-                .field("content", &support::DebugSyntaxResult(self.content()))
+                .field("title", &support::DebugOptionalElement(self.title()))
                 .finish()
         } else {
-            f.debug_struct("MdIndentedCodeLine").finish()
+            f.debug_struct("MdLinkReferenceDefinition").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdIndentedCodeLine> for SyntaxNode {
-    fn from(n: MdIndentedCodeLine) -> Self {
+impl From<MdLinkReferenceDefinition> for SyntaxNode {
+    fn from(n: MdLinkReferenceDefinition) -> Self {
         n.syntax
     }
 }
-impl From<MdIndentedCodeLine> for SyntaxElement {
-    fn from(n: MdIndentedCodeLine) -> Self {
+impl From<MdLinkReferenceDefinition> for SyntaxElement {
+    fn from(n: MdLinkReferenceDefinition) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineCode {
+impl AstNode for MdLinkTitle {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_CODE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_LINK_TITLE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_CODE
+        kind == MD_LINK_TITLE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1913,46 +2738,41 @@ impl AstNode for MdInlineCode {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineCode {
+impl std::fmt::Debug for MdLinkTitle {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdInlineCode")
-                .field(
-                    "l_tick_token",
-                    &support::DebugSyntaxResult(self.l_tick_token()),
-                )
-                .field("content", &self.content())
+            f.debug_struct("MdLinkTitle")
                 .field(
-                    "r_tick_token",
-                    &support::DebugSyntaxResult(self.r_tick_token()),
+                    "value_token",
+                    &support::DebugSyntaxResult(self.value_token()),
                 )
                 .finish()
         } else {
-            f.debug_struct("MdInlineCode").finish()
+            f.debug_struct("MdLinkTitle").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdInlineCode> for SyntaxNode {
-    fn from(n: MdInlineCode) -> Self {
+impl From<MdLinkTitle> for SyntaxNode {
+    fn from(n: MdLinkTitle) -> Self {
         n.syntax
     }
 }
-impl From<MdInlineCode> for SyntaxElement {
-    fn from(n: MdInlineCode) -> Self {
+impl From<MdLinkTitle> for SyntaxElement {
+    fn from(n: MdLinkTitle) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineEmphasis {
+impl AstNode for MdOrderedList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_EMPHASIS as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_ORDERED_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_EMPHASIS
+        kind == MD_ORDERED_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1968,40 +2788,38 @@ impl AstNode for MdInlineEmphasis {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineEmphasis {
+impl std::fmt::Debug for MdOrderedList {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdInlineEmphasis")
-                .field("l_fence", &support::DebugSyntaxResult(self.l_fence()))
-                .field("content", &self.content())
-                .field("r_fence", &support::DebugSyntaxResult(self.r_fence()))
+            f.debug_struct("MdOrderedList")
+                .field("items", &self.items())
                 .finish()
         } else {
-            f.debug_struct("MdInlineEmphasis").finish()
+            f.debug_struct("MdOrderedList").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdInlineEmphasis> for SyntaxNode {
-    fn from(n: MdInlineEmphasis) -> Self {
+impl From<MdOrderedList> for SyntaxNode {
+    fn from(n: MdOrderedList) -> Self {
         n.syntax
     }
 }
-impl From<MdInlineEmphasis> for SyntaxElement {
-    fn from(n: MdInlineEmphasis) -> Self {
+impl From<MdOrderedList> for SyntaxElement {
+    fn from(n: MdOrderedList) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineImage {
+impl AstNode for MdOrderedListItem {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_IMAGE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_ORDERED_LIST_ITEM as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_IMAGE
+        kind == MD_ORDERED_LIST_ITEM
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2017,49 +2835,46 @@ impl AstNode for MdInlineImage {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineImage {
+impl std::fmt::Debug for MdOrderedListItem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdInlineImage")
+            f.debug_struct("MdOrderedListItem")
                 .field(
-                    "l_brack_token",
-                    &support::DebugSyntaxResult(self.l_brack_token()),
+                    "marker_token",
+                    &support::DebugSyntaxResult(self.marker_token()),
                 )
-                .field("excl_token", &support::DebugSyntaxResult(self.excl_token()))
-                .field("alt", &support::DebugSyntaxResult(self.alt()))
-                .field("source", &support::DebugSyntaxResult(self.source()))
                 .field(
-                    "r_brack_token",
-                    &support::DebugSyntaxResult(self.r_brack_token()),
+                    "task_token",
+                    &support::DebugOptionalElement(self.task_token()),
                 )
-                .field("link", &support::DebugOptionalElement(self.link()))
+                .field("content", &self.content())
                 .finish()
         } else {
-            f.debug_struct("MdInlineImage").finish()
+            f.debug_struct("MdOrderedListItem").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdInlineImage> for SyntaxNode {
-    fn from(n: MdInlineImage) -> Self {
+impl From<MdOrderedListItem> for SyntaxNode {
+    fn from(n: MdOrderedListItem) -> Self {
         n.syntax
     }
 }
-impl From<MdInlineImage> for SyntaxElement {
-    fn from(n: MdInlineImage) -> Self {
+impl From<MdOrderedListItem> for SyntaxElement {
+    fn from(n: MdOrderedListItem) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineImageAlt {
+impl AstNode for MdParagraph {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_IMAGE_ALT as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_PARAGRAPH as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_IMAGE_ALT
+        kind == MD_PARAGRAPH
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2075,46 +2890,38 @@ impl AstNode for MdInlineImageAlt {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineImageAlt {
+impl std::fmt::Debug for MdParagraph {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdInlineImageAlt")
-                .field(
-                    "l_brack_token",
-                    &support::DebugSyntaxResult(self.l_brack_token()),
-                )
+            f.debug_struct("MdParagraph")
                 .field("content", &self.content())
-                .field(
-                    "r_brack_token",
-                    &support::DebugSyntaxResult(self.r_brack_token()),
-                )
                 .finish()
         } else {
-            f.debug_struct("MdInlineImageAlt").finish()
+            f.debug_struct("MdParagraph").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdInlineImageAlt> for SyntaxNode {
-    fn from(n: MdInlineImageAlt) -> Self {
+impl From<MdParagraph> for SyntaxNode {
+    fn from(n: MdParagraph) -> Self {
         n.syntax
     }
 }
-impl From<MdInlineImageAlt> for SyntaxElement {
-    fn from(n: MdInlineImageAlt) -> Self {
+impl From<MdParagraph> for SyntaxElement {
+    fn from(n: MdParagraph) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineImageLink {
+impl AstNode for MdQuote {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_IMAGE_LINK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_QUOTE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_IMAGE_LINK
+        kind == MD_QUOTE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2130,46 +2937,42 @@ impl AstNode for MdInlineImageLink {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineImageLink {
+impl std::fmt::Debug for MdQuote {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdInlineImageLink")
+            f.debug_struct("MdQuote")
                 .field(
-                    "l_paren_token",
-                    &support::DebugSyntaxResult(self.l_paren_token()),
+                    "marker_token",
+                    &support::DebugSyntaxResult(self.marker_token()),
                 )
                 .field("content", &self.content())
-                .field(
-                    "r_paren_token",
-                    &support::DebugSyntaxResult(self.r_paren_token()),
-                )
                 .finish()
         } else {
-            f.debug_struct("MdInlineImageLink").finish()
+            f.debug_struct("MdQuote").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdInlineImageLink> for SyntaxNode {
-    fn from(n: MdInlineImageLink) -> Self {
+impl From<MdQuote> for SyntaxNode {
+    fn from(n: MdQuote) -> Self {
         n.syntax
     }
 }
-impl From<MdInlineImageLink> for SyntaxElement {
-    fn from(n: MdInlineImageLink) -> Self {
+impl From<MdQuote> for SyntaxElement {
+    fn from(n: MdQuote) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineImageSource {
+impl AstNode for MdReferenceImage {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_IMAGE_SOURCE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_REFERENCE_IMAGE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_IMAGE_SOURCE
+        kind == MD_REFERENCE_IMAGE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2185,46 +2988,48 @@ impl AstNode for MdInlineImageSource {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineImageSource {
+impl std::fmt::Debug for MdReferenceImage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdInlineImageSource")
+            f.debug_struct("MdReferenceImage")
+                .field("excl_token", &support::DebugSyntaxResult(self.excl_token()))
                 .field(
-                    "l_paren_token",
-                    &support::DebugSyntaxResult(self.l_paren_token()),
+                    "l_brack_token",
+                    &support::DebugSyntaxResult(self.l_brack_token()),
                 )
-                .field("content", &self.content())
+                .field("alt", &self.alt())
                 .field(
-                    "r_paren_token",
-                    &support::DebugSyntaxResult(self.r_paren_token()),
+                    "r_brack_token",
+                    &support::DebugSyntaxResult(self.r_brack_token()),
                 )
+                .field("label", &support::DebugOptionalElement(self.label()))
                 .finish()
         } else {
-            f.debug_struct("MdInlineImageSource").finish()
+            f.debug_struct("MdReferenceImage").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdInlineImageSource> for SyntaxNode {
-    fn from(n: MdInlineImageSource) -> Self {
+impl From<MdReferenceImage> for SyntaxNode {
+    fn from(n: MdReferenceImage) -> Self {
         n.syntax
     }
 }
-impl From<MdInlineImageSource> for SyntaxElement {
-    fn from(n: MdInlineImageSource) -> Self {
+impl From<MdReferenceImage> for SyntaxElement {
+    fn from(n: MdReferenceImage) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineItalic {
+impl AstNode for MdReferenceLink {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_ITALIC as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_REFERENCE_LINK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_ITALIC
+        kind == MD_REFERENCE_LINK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2240,40 +3045,47 @@ impl AstNode for MdInlineItalic {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineItalic {
+impl std::fmt::Debug for MdReferenceLink {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdInlineItalic")
-                .field("l_fence", &support::DebugSyntaxResult(self.l_fence()))
-                .field("content", &self.content())
-                .field("r_fence", &support::DebugSyntaxResult(self.r_fence()))
+            f.debug_struct("MdReferenceLink")
+                .field(
+                    "l_brack_token",
+                    &support::DebugSyntaxResult(self.l_brack_token()),
+                )
+                .field("text", &self.text())
+                .field(
+                    "r_brack_token",
+                    &support::DebugSyntaxResult(self.r_brack_token()),
+                )
+                .field("label", &support::DebugOptionalElement(self.label()))
                 .finish()
         } else {
-            f.debug_struct("MdInlineItalic").finish()
+            f.debug_struct("MdReferenceLink").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdInlineItalic> for SyntaxNode {
-    fn from(n: MdInlineItalic) -> Self {
+impl From<MdReferenceLink> for SyntaxNode {
+    fn from(n: MdReferenceLink) -> Self {
         n.syntax
     }
 }
-impl From<MdInlineItalic> for SyntaxElement {
-    fn from(n: MdInlineItalic) -> Self {
+impl From<MdReferenceLink> for SyntaxElement {
+    fn from(n: MdReferenceLink) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdInlineLink {
+impl AstNode for MdReferenceLinkLabel {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_LINK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_REFERENCE_LINK_LABEL as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_LINK
+        kind == MD_REFERENCE_LINK_LABEL
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2289,55 +3101,46 @@ impl AstNode for MdInlineLink {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdInlineLink {
+impl std::fmt::Debug for MdReferenceLinkLabel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdInlineLink")
+            f.debug_struct("MdReferenceLinkLabel")
                 .field(
                     "l_brack_token",
                     &support::DebugSyntaxResult(self.l_brack_token()),
                 )
-                .field("text", &self.text())
+                .field("label", &support::DebugOptionalElement(self.label()))
                 .field(
                     "r_brack_token",
                     &support::DebugSyntaxResult(self.r_brack_token()),
                 )
-                .field(
-                    "l_paren_token",
-                    &support::DebugSyntaxResult(self.l_paren_token()),
-                )
-                .field("source", &self.source())
-                .field(
-                    "r_paren_token",
-                    &support::DebugSyntaxResult(self.r_paren_token()),
-                )
                 .finish()
         } else {
-            f.debug_struct("MdInlineLink").finish()
+            f.debug_struct("MdReferenceLinkLabel").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdInlineLink> for SyntaxNode {
-    fn from(n: MdInlineLink) -> Self {
+impl From<MdReferenceLinkLabel> for SyntaxNode {
+    fn from(n: MdReferenceLinkLabel) -> Self {
         n.syntax
     }
 }
-impl From<MdInlineLink> for SyntaxElement {
-    fn from(n: MdInlineLink) -> Self {
+impl From<MdReferenceLinkLabel> for SyntaxElement {
+    fn from(n: MdReferenceLinkLabel) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdLinkBlock {
+impl AstNode for MdSetextHeader {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_LINK_BLOCK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_SETEXT_HEADER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_LINK_BLOCK
+        kind == MD_SETEXT_HEADER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2353,40 +3156,42 @@ impl AstNode for MdLinkBlock {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdLinkBlock {
+impl std::fmt::Debug for MdSetextHeader {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdLinkBlock")
-                .field("label", &support::DebugSyntaxResult(self.label()))
-                .field("url", &support::DebugSyntaxResult(self.url()))
-                .field("title", &support::DebugOptionalElement(self.title()))
+            f.debug_struct("MdSetextHeader")
+                .field("content", &self.content())
+                .field(
+                    "underline_token",
+                    &support::DebugSyntaxResult(self.underline_token()),
+                )
                 .finish()
         } else {
-            f.debug_struct("MdLinkBlock").finish()
+            f.debug_struct("MdSetextHeader").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdLinkBlock> for SyntaxNode {
-    fn from(n: MdLinkBlock) -> Self {
+impl From<MdSetextHeader> for SyntaxNode {
+    fn from(n: MdSetextHeader) -> Self {
         n.syntax
     }
 }
-impl From<MdLinkBlock> for SyntaxElement {
-    fn from(n: MdLinkBlock) -> Self {
+impl From<MdSetextHeader> for SyntaxElement {
+    fn from(n: MdSetextHeader) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdOrderListItem {
+impl AstNode for MdTable {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_ORDER_LIST_ITEM as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_ORDER_LIST_ITEM
+        kind == MD_TABLE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2402,38 +3207,40 @@ impl AstNode for MdOrderListItem {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdOrderListItem {
+impl std::fmt::Debug for MdTable {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdOrderListItem")
-                .field("md_bullet_list", &self.md_bullet_list())
+            f.debug_struct("MdTable")
+                .field("header", &support::DebugSyntaxResult(self.header()))
+                .field("delimiter", &support::DebugSyntaxResult(self.delimiter()))
+                .field("rows", &self.rows())
                 .finish()
         } else {
-            f.debug_struct("MdOrderListItem").finish()
+            f.debug_struct("MdTable").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdOrderListItem> for SyntaxNode {
-    fn from(n: MdOrderListItem) -> Self {
+impl From<MdTable> for SyntaxNode {
+    fn from(n: MdTable) -> Self {
         n.syntax
     }
 }
-impl From<MdOrderListItem> for SyntaxElement {
-    fn from(n: MdOrderListItem) -> Self {
+impl From<MdTable> for SyntaxElement {
+    fn from(n: MdTable) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdParagraph {
+impl AstNode for MdTableCell {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_PARAGRAPH as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE_CELL as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_PARAGRAPH
+        kind == MD_TABLE_CELL
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2449,39 +3256,42 @@ impl AstNode for MdParagraph {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdParagraph {
+impl std::fmt::Debug for MdTableCell {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdParagraph")
-                .field("list", &self.list())
-                .field("hard_line", &support::DebugSyntaxResult(self.hard_line()))
+            f.debug_struct("MdTableCell")
+                .field(
+                    "l_pipe_token",
+                    &support::DebugOptionalElement(self.l_pipe_token()),
+                )
+                .field("content", &self.content())
                 .finish()
         } else {
-            f.debug_struct("MdParagraph").finish()
+            f.debug_struct("MdTableCell").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdParagraph> for SyntaxNode {
-    fn from(n: MdParagraph) -> Self {
+impl From<MdTableCell> for SyntaxNode {
+    fn from(n: MdTableCell) -> Self {
         n.syntax
     }
 }
-impl From<MdParagraph> for SyntaxElement {
-    fn from(n: MdParagraph) -> Self {
+impl From<MdTableCell> for SyntaxElement {
+    fn from(n: MdTableCell) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdQuote {
+impl AstNode for MdTableDelimiterCell {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_QUOTE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE_DELIMITER_CELL as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_QUOTE
+        kind == MD_TABLE_DELIMITER_CELL
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2497,41 +3307,45 @@ impl AstNode for MdQuote {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdQuote {
+impl std::fmt::Debug for MdTableDelimiterCell {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdQuote")
+            f.debug_struct("MdTableDelimiterCell")
+                .field(
+                    "l_pipe_token",
+                    &support::DebugOptionalElement(self.l_pipe_token()),
+                )
                 .field(
-                    "any_md_block",
-                    &support::DebugSyntaxResult(self.any_md_block()),
+                    "value_token",
+                    &support::DebugSyntaxResult(self.value_token()),
                 )
                 .finish()
         } else {
-            f.debug_struct("MdQuote").finish()
+            f.debug_struct("MdTableDelimiterCell").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdQuote> for SyntaxNode {
-    fn from(n: MdQuote) -> Self {
+impl From<MdTableDelimiterCell> for SyntaxNode {
+    fn from(n: MdTableDelimiterCell) -> Self {
         n.syntax
     }
 }
-impl From<MdQuote> for SyntaxElement {
-    fn from(n: MdQuote) -> Self {
+impl From<MdTableDelimiterCell> for SyntaxElement {
+    fn from(n: MdTableDelimiterCell) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdSetextHeader {
+impl AstNode for MdTableDelimiterRow {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_SETEXT_HEADER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE_DELIMITER_ROW as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_SETEXT_HEADER
+        kind == MD_TABLE_DELIMITER_ROW
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2547,41 +3361,42 @@ impl AstNode for MdSetextHeader {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdSetextHeader {
+impl std::fmt::Debug for MdTableDelimiterRow {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdSetextHeader")
+            f.debug_struct("MdTableDelimiterRow")
+                .field("cells", &self.cells())
                 .field(
-                    "md_paragraph",
-                    &support::DebugSyntaxResult(self.md_paragraph()),
+                    "r_pipe_token",
+                    &support::DebugOptionalElement(self.r_pipe_token()),
                 )
                 .finish()
         } else {
-            f.debug_struct("MdSetextHeader").finish()
+            f.debug_struct("MdTableDelimiterRow").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdSetextHeader> for SyntaxNode {
-    fn from(n: MdSetextHeader) -> Self {
+impl From<MdTableDelimiterRow> for SyntaxNode {
+    fn from(n: MdTableDelimiterRow) -> Self {
         n.syntax
     }
 }
-impl From<MdSetextHeader> for SyntaxElement {
-    fn from(n: MdSetextHeader) -> Self {
+impl From<MdTableDelimiterRow> for SyntaxElement {
+    fn from(n: MdTableDelimiterRow) -> Self {
         n.syntax.into()
     }
 }
-impl AstNode for MdSoftBreak {
+impl AstNode for MdTableRow {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_SOFT_BREAK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE_ROW as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_SOFT_BREAK
+        kind == MD_TABLE_ROW
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -2597,32 +3412,33 @@ impl AstNode for MdSoftBreak {
         self.syntax
     }
 }
-impl std::fmt::Debug for MdSoftBreak {
+impl std::fmt::Debug for MdTableRow {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         thread_local! { static DEPTH : std :: cell :: Cell < u8 > = const { std :: cell :: Cell :: new (0) } };
         let current_depth = DEPTH.get();
         let result = if current_depth < 16 {
             DEPTH.set(current_depth + 1);
-            f.debug_struct("MdSoftBreak")
+            f.debug_struct("MdTableRow")
+                .field("cells", &self.cells())
                 .field(
-                    "value_token",
-                    &support::DebugSyntaxResult(self.value_token()),
+                    "r_pipe_token",
+                    &support::DebugOptionalElement(self.r_pipe_token()),
                 )
                 .finish()
         } else {
-            f.debug_struct("MdSoftBreak").finish()
+            f.debug_struct("MdTableRow").finish()
         };
         DEPTH.set(current_depth);
         result
     }
 }
-impl From<MdSoftBreak> for SyntaxNode {
-    fn from(n: MdSoftBreak) -> Self {
+impl From<MdTableRow> for SyntaxNode {
+    fn from(n: MdTableRow) -> Self {
         n.syntax
     }
 }
-impl From<MdSoftBreak> for SyntaxElement {
-    fn from(n: MdSoftBreak) -> Self {
+impl From<MdTableRow> for SyntaxElement {
+    fn from(n: MdTableRow) -> Self {
         n.syntax.into()
     }
 }
@@ -2726,93 +3542,91 @@ impl From<MdThematicBreakBlock> for SyntaxElement {
         n.syntax.into()
     }
 }
-impl From<MdFencedCodeBlock> for AnyCodeBlock {
-    fn from(node: MdFencedCodeBlock) -> Self {
-        Self::MdFencedCodeBlock(node)
-    }
-}
-impl From<MdIndentCodeBlock> for AnyCodeBlock {
-    fn from(node: MdIndentCodeBlock) -> Self {
-        Self::MdIndentCodeBlock(node)
-    }
-}
-impl AstNode for AnyCodeBlock {
+impl AstNode for AnyMdBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        MdFencedCodeBlock::KIND_SET.union(MdIndentCodeBlock::KIND_SET);
+        AnyMdContainerBlock::KIND_SET.union(AnyMdLeafBlock::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, MD_FENCED_CODE_BLOCK | MD_INDENT_CODE_BLOCK)
+        match kind {
+            k if AnyMdContainerBlock::can_cast(k) => true,
+            k if AnyMdLeafBlock::can_cast(k) => true,
+            _ => false,
+        }
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
-        let res = match syntax.kind() {
-            MD_FENCED_CODE_BLOCK => Self::MdFencedCodeBlock(MdFencedCodeBlock { syntax }),
-            MD_INDENT_CODE_BLOCK => Self::MdIndentCodeBlock(MdIndentCodeBlock { syntax }),
-            _ => return None,
+        let syntax = match AnyMdContainerBlock::try_cast(syntax) {
+            Ok(any_md_container_block) => {
+                return Some(Self::AnyMdContainerBlock(any_md_container_block));
+            }
+            Err(syntax) => syntax,
         };
-        Some(res)
+        if let Some(any_md_leaf_block) = AnyMdLeafBlock::cast(syntax) {
+            return Some(Self::AnyMdLeafBlock(any_md_leaf_block));
+        }
+        None
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
-            Self::MdFencedCodeBlock(it) => &it.syntax,
-            Self::MdIndentCodeBlock(it) => &it.syntax,
+            Self::AnyMdContainerBlock(it) => it.syntax(),
+            Self::AnyMdLeafBlock(it) => it.syntax(),
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
-            Self::MdFencedCodeBlock(it) => it.syntax,
-            Self::MdIndentCodeBlock(it) => it.syntax,
+            Self::AnyMdContainerBlock(it) => it.into_syntax(),
+            Self::AnyMdLeafBlock(it) => it.into_syntax(),
         }
     }
 }
-impl std::fmt::Debug for AnyCodeBlock {
+impl std::fmt::Debug for AnyMdBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::MdFencedCodeBlock(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdIndentCodeBlock(it) => std::fmt::Debug::fmt(it, f),
+            Self::AnyMdContainerBlock(it) => std::fmt::Debug::fmt(it, f),
+            Self::AnyMdLeafBlock(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
-impl From<AnyCodeBlock> for SyntaxNode {
-    fn from(n: AnyCodeBlock) -> Self {
+impl From<AnyMdBlock> for SyntaxNode {
+    fn from(n: AnyMdBlock) -> Self {
         match n {
-            AnyCodeBlock::MdFencedCodeBlock(it) => it.into(),
-            AnyCodeBlock::MdIndentCodeBlock(it) => it.into(),
+            AnyMdBlock::AnyMdContainerBlock(it) => it.into(),
+            AnyMdBlock::AnyMdLeafBlock(it) => it.into(),
         }
     }
 }
-impl From<AnyCodeBlock> for SyntaxElement {
-    fn from(n: AnyCodeBlock) -> Self {
+impl From<AnyMdBlock> for SyntaxElement {
+    fn from(n: AnyMdBlock) -> Self {
         let node: SyntaxNode = n.into();
         node.into()
     }
 }
-impl From<MdBulletListItem> for AnyContainerBlock {
-    fn from(node: MdBulletListItem) -> Self {
-        Self::MdBulletListItem(node)
+impl From<MdBulletList> for AnyMdContainerBlock {
+    fn from(node: MdBulletList) -> Self {
+        Self::MdBulletList(node)
     }
 }
-impl From<MdOrderListItem> for AnyContainerBlock {
-    fn from(node: MdOrderListItem) -> Self {
-        Self::MdOrderListItem(node)
+impl From<MdOrderedList> for AnyMdContainerBlock {
+    fn from(node: MdOrderedList) -> Self {
+        Self::MdOrderedList(node)
     }
 }
-impl From<MdQuote> for AnyContainerBlock {
+impl From<MdQuote> for AnyMdContainerBlock {
     fn from(node: MdQuote) -> Self {
         Self::MdQuote(node)
     }
 }
-impl AstNode for AnyContainerBlock {
+impl AstNode for AnyMdContainerBlock {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = MdBulletListItem::KIND_SET
-        .union(MdOrderListItem::KIND_SET)
+    const KIND_SET: SyntaxKindSet<Language> = MdBulletList::KIND_SET
+        .union(MdOrderedList::KIND_SET)
         .union(MdQuote::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, MD_BULLET_LIST_ITEM | MD_ORDER_LIST_ITEM | MD_QUOTE)
+        matches!(kind, MD_BULLET_LIST | MD_ORDERED_LIST | MD_QUOTE)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
-            MD_BULLET_LIST_ITEM => Self::MdBulletListItem(MdBulletListItem { syntax }),
-            MD_ORDER_LIST_ITEM => Self::MdOrderListItem(MdOrderListItem { syntax }),
+            MD_BULLET_LIST => Self::MdBulletList(MdBulletList { syntax }),
+            MD_ORDERED_LIST => Self::MdOrderedList(MdOrderedList { syntax }),
             MD_QUOTE => Self::MdQuote(MdQuote { syntax }),
             _ => return None,
         };
@@ -2820,398 +3634,405 @@ impl AstNode for AnyContainerBlock {
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
-            Self::MdBulletListItem(it) => &it.syntax,
-            Self::MdOrderListItem(it) => &it.syntax,
+            Self::MdBulletList(it) => &it.syntax,
+            Self::MdOrderedList(it) => &it.syntax,
             Self::MdQuote(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
-            Self::MdBulletListItem(it) => it.syntax,
-            Self::MdOrderListItem(it) => it.syntax,
+            Self::MdBulletList(it) => it.syntax,
+            Self::MdOrderedList(it) => it.syntax,
             Self::MdQuote(it) => it.syntax,
         }
     }
 }
-impl std::fmt::Debug for AnyContainerBlock {
+impl std::fmt::Debug for AnyMdContainerBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::MdBulletListItem(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdOrderListItem(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdBulletList(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdOrderedList(it) => std::fmt::Debug::fmt(it, f),
             Self::MdQuote(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
-impl From<AnyContainerBlock> for SyntaxNode {
-    fn from(n: AnyContainerBlock) -> Self {
+impl From<AnyMdContainerBlock> for SyntaxNode {
+    fn from(n: AnyMdContainerBlock) -> Self {
         match n {
-            AnyContainerBlock::MdBulletListItem(it) => it.into(),
-            AnyContainerBlock::MdOrderListItem(it) => it.into(),
-            AnyContainerBlock::MdQuote(it) => it.into(),
+            AnyMdContainerBlock::MdBulletList(it) => it.into(),
+            AnyMdContainerBlock::MdOrderedList(it) => it.into(),
+            AnyMdContainerBlock::MdQuote(it) => it.into(),
         }
     }
 }
-impl From<AnyContainerBlock> for SyntaxElement {
-    fn from(n: AnyContainerBlock) -> Self {
+impl From<AnyMdContainerBlock> for SyntaxElement {
+    fn from(n: AnyMdContainerBlock) -> Self {
         let node: SyntaxNode = n.into();
         node.into()
     }
 }
-impl From<MdHeader> for AnyLeafBlock {
-    fn from(node: MdHeader) -> Self {
-        Self::MdHeader(node)
+impl From<MdAutolink> for AnyMdInline {
+    fn from(node: MdAutolink) -> Self {
+        Self::MdAutolink(node)
     }
 }
-impl From<MdHtmlBlock> for AnyLeafBlock {
-    fn from(node: MdHtmlBlock) -> Self {
-        Self::MdHtmlBlock(node)
+impl From<MdHardLine> for AnyMdInline {
+    fn from(node: MdHardLine) -> Self {
+        Self::MdHardLine(node)
     }
 }
-impl From<MdLinkBlock> for AnyLeafBlock {
-    fn from(node: MdLinkBlock) -> Self {
-        Self::MdLinkBlock(node)
+impl From<MdInlineCode> for AnyMdInline {
+    fn from(node: MdInlineCode) -> Self {
+        Self::MdInlineCode(node)
     }
 }
-impl From<MdParagraph> for AnyLeafBlock {
-    fn from(node: MdParagraph) -> Self {
-        Self::MdParagraph(node)
+impl From<MdInlineEmphasis> for AnyMdInline {
+    fn from(node: MdInlineEmphasis) -> Self {
+        Self::MdInlineEmphasis(node)
     }
 }
-impl From<MdSetextHeader> for AnyLeafBlock {
-    fn from(node: MdSetextHeader) -> Self {
-        Self::MdSetextHeader(node)
+impl From<MdInlineHtml> for AnyMdInline {
+    fn from(node: MdInlineHtml) -> Self {
+        Self::MdInlineHtml(node)
     }
 }
-impl From<MdThematicBreakBlock> for AnyLeafBlock {
-    fn from(node: MdThematicBreakBlock) -> Self {
-        Self::MdThematicBreakBlock(node)
+impl From<MdInlineImage> for AnyMdInline {
+    fn from(node: MdInlineImage) -> Self {
+        Self::MdInlineImage(node)
     }
 }
-impl AstNode for AnyLeafBlock {
-    type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = AnyCodeBlock::KIND_SET
-        .union(MdHeader::KIND_SET)
-        .union(MdHtmlBlock::KIND_SET)
-        .union(MdLinkBlock::KIND_SET)
-        .union(MdParagraph::KIND_SET)
-        .union(MdSetextHeader::KIND_SET)
-        .union(MdThematicBreakBlock::KIND_SET);
-    fn can_cast(kind: SyntaxKind) -> bool {
-        match kind {
-            MD_HEADER
-            | MD_HTML_BLOCK
-            | MD_LINK_BLOCK
-            | MD_PARAGRAPH
-            | MD_SETEXT_HEADER
-            | MD_THEMATIC_BREAK_BLOCK => true,
-            k if AnyCodeBlock::can_cast(k) => true,
-            _ => false,
-        }
-    }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        let res = match syntax.kind() {
-            MD_HEADER => Self::MdHeader(MdHeader { syntax }),
-            MD_HTML_BLOCK => Self::MdHtmlBlock(MdHtmlBlock { syntax }),
-            MD_LINK_BLOCK => Self::MdLinkBlock(MdLinkBlock { syntax }),
-            MD_PARAGRAPH => Self::MdParagraph(MdParagraph { syntax }),
-            MD_SETEXT_HEADER => Self::MdSetextHeader(MdSetextHeader { syntax }),
-            MD_THEMATIC_BREAK_BLOCK => Self::MdThematicBreakBlock(MdThematicBreakBlock { syntax }),
-            _ => {
-                if let Some(any_code_block) = AnyCodeBlock::cast(syntax) {
-                    return Some(Self::AnyCodeBlock(any_code_block));
-                }
-                return None;
-            }
-        };
-        Some(res)
+impl From<MdInlineItalic> for AnyMdInline {
+    fn from(node: MdInlineItalic) -> Self {
+        Self::MdInlineItalic(node)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        match self {
-            Self::MdHeader(it) => &it.syntax,
-            Self::MdHtmlBlock(it) => &it.syntax,
-            Self::MdLinkBlock(it) => &it.syntax,
-            Self::MdParagraph(it) => &it.syntax,
-            Self::MdSetextHeader(it) => &it.syntax,
-            Self::MdThematicBreakBlock(it) => &it.syntax,
-            Self::AnyCodeBlock(it) => it.syntax(),
-        }
+}
+impl From<MdInlineLink> for AnyMdInline {
+    fn from(node: MdInlineLink) -> Self {
+        Self::MdInlineLink(node)
     }
-    fn into_syntax(self) -> SyntaxNode {
-        match self {
-            Self::MdHeader(it) => it.syntax,
-            Self::MdHtmlBlock(it) => it.syntax,
-            Self::MdLinkBlock(it) => it.syntax,
-            Self::MdParagraph(it) => it.syntax,
-            Self::MdSetextHeader(it) => it.syntax,
-            Self::MdThematicBreakBlock(it) => it.syntax,
-            Self::AnyCodeBlock(it) => it.into_syntax(),
-        }
+}
+impl From<MdInlineStrikethrough> for AnyMdInline {
+    fn from(node: MdInlineStrikethrough) -> Self {
+        Self::MdInlineStrikethrough(node)
     }
 }
-impl std::fmt::Debug for AnyLeafBlock {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::AnyCodeBlock(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdHeader(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdHtmlBlock(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdLinkBlock(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdParagraph(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdSetextHeader(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdThematicBreakBlock(it) => std::fmt::Debug::fmt(it, f),
-        }
+impl From<MdReferenceImage> for AnyMdInline {
+    fn from(node: MdReferenceImage) -> Self {
+        Self::MdReferenceImage(node)
     }
 }
-impl From<AnyLeafBlock> for SyntaxNode {
-    fn from(n: AnyLeafBlock) -> Self {
-        match n {
-            AnyLeafBlock::AnyCodeBlock(it) => it.into(),
-            AnyLeafBlock::MdHeader(it) => it.into(),
-            AnyLeafBlock::MdHtmlBlock(it) => it.into(),
-            AnyLeafBlock::MdLinkBlock(it) => it.into(),
-            AnyLeafBlock::MdParagraph(it) => it.into(),
-            AnyLeafBlock::MdSetextHeader(it) => it.into(),
-            AnyLeafBlock::MdThematicBreakBlock(it) => it.into(),
-        }
+impl From<MdReferenceLink> for AnyMdInline {
+    fn from(node: MdReferenceLink) -> Self {
+        Self::MdReferenceLink(node)
     }
 }
-impl From<AnyLeafBlock> for SyntaxElement {
-    fn from(n: AnyLeafBlock) -> Self {
-        let node: SyntaxNode = n.into();
-        node.into()
+impl From<MdTextual> for AnyMdInline {
+    fn from(node: MdTextual) -> Self {
+        Self::MdTextual(node)
     }
 }
-impl AstNode for AnyMdBlock {
+impl AstNode for AnyMdInline {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        AnyContainerBlock::KIND_SET.union(AnyLeafBlock::KIND_SET);
+    const KIND_SET: SyntaxKindSet<Language> = MdAutolink::KIND_SET
+        .union(MdHardLine::KIND_SET)
+        .union(MdInlineCode::KIND_SET)
+        .union(MdInlineEmphasis::KIND_SET)
+        .union(MdInlineHtml::KIND_SET)
+        .union(MdInlineImage::KIND_SET)
+        .union(MdInlineItalic::KIND_SET)
+        .union(MdInlineLink::KIND_SET)
+        .union(MdInlineStrikethrough::KIND_SET)
+        .union(MdReferenceImage::KIND_SET)
+        .union(MdReferenceLink::KIND_SET)
+        .union(MdTextual::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
-        match kind {
-            k if AnyContainerBlock::can_cast(k) => true,
-            k if AnyLeafBlock::can_cast(k) => true,
-            _ => false,
-        }
+        matches!(
+            kind,
+            MD_AUTOLINK
+                | MD_HARD_LINE
+                | MD_INLINE_CODE
+                | MD_INLINE_EMPHASIS
+                | MD_INLINE_HTML
+                | MD_INLINE_IMAGE
+                | MD_INLINE_ITALIC
+                | MD_INLINE_LINK
+                | MD_INLINE_STRIKETHROUGH
+                | MD_REFERENCE_IMAGE
+                | MD_REFERENCE_LINK
+                | MD_TEXTUAL
+        )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
-        let syntax = match AnyContainerBlock::try_cast(syntax) {
-            Ok(any_container_block) => {
-                return Some(Self::AnyContainerBlock(any_container_block));
+        let res = match syntax.kind() {
+            MD_AUTOLINK => Self::MdAutolink(MdAutolink { syntax }),
+            MD_HARD_LINE => Self::MdHardLine(MdHardLine { syntax }),
+            MD_INLINE_CODE => Self::MdInlineCode(MdInlineCode { syntax }),
+            MD_INLINE_EMPHASIS => Self::MdInlineEmphasis(MdInlineEmphasis { syntax }),
+            MD_INLINE_HTML => Self::MdInlineHtml(MdInlineHtml { syntax }),
+            MD_INLINE_IMAGE => Self::MdInlineImage(MdInlineImage { syntax }),
+            MD_INLINE_ITALIC => Self::MdInlineItalic(MdInlineItalic { syntax }),
+            MD_INLINE_LINK => Self::MdInlineLink(MdInlineLink { syntax }),
+            MD_INLINE_STRIKETHROUGH => {
+                Self::MdInlineStrikethrough(MdInlineStrikethrough { syntax })
             }
-            Err(syntax) => syntax,
+            MD_REFERENCE_IMAGE => Self::MdReferenceImage(MdReferenceImage { syntax }),
+            MD_REFERENCE_LINK => Self::MdReferenceLink(MdReferenceLink { syntax }),
+            MD_TEXTUAL => Self::MdTextual(MdTextual { syntax }),
+            _ => return None,
         };
-        if let Some(any_leaf_block) = AnyLeafBlock::cast(syntax) {
-            return Some(Self::AnyLeafBlock(any_leaf_block));
-        }
-        None
+        Some(res)
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
-            Self::AnyContainerBlock(it) => it.syntax(),
-            Self::AnyLeafBlock(it) => it.syntax(),
+            Self::MdAutolink(it) => &it.syntax,
+            Self::MdHardLine(it) => &it.syntax,
+            Self::MdInlineCode(it) => &it.syntax,
+            Self::MdInlineEmphasis(it) => &it.syntax,
+            Self::MdInlineHtml(it) => &it.syntax,
+            Self::MdInlineImage(it) => &it.syntax,
+            Self::MdInlineItalic(it) => &it.syntax,
+            Self::MdInlineLink(it) => &it.syntax,
+            Self::MdInlineStrikethrough(it) => &it.syntax,
+            Self::MdReferenceImage(it) => &it.syntax,
+            Self::MdReferenceLink(it) => &it.syntax,
+            Self::MdTextual(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
-            Self::AnyContainerBlock(it) => it.into_syntax(),
-            Self::AnyLeafBlock(it) => it.into_syntax(),
+            Self::MdAutolink(it) => it.syntax,
+            Self::MdHardLine(it) => it.syntax,
+            Self::MdInlineCode(it) => it.syntax,
+            Self::MdInlineEmphasis(it) => it.syntax,
+            Self::MdInlineHtml(it) => it.syntax,
+            Self::MdInlineImage(it) => it.syntax,
+            Self::MdInlineItalic(it) => it.syntax,
+            Self::MdInlineLink(it) => it.syntax,
+            Self::MdInlineStrikethrough(it) => it.syntax,
+            Self::MdReferenceImage(it) => it.syntax,
+            Self::MdReferenceLink(it) => it.syntax,
+            Self::MdTextual(it) => it.syntax,
         }
     }
 }
-impl std::fmt::Debug for AnyMdBlock {
+impl std::fmt::Debug for AnyMdInline {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::AnyContainerBlock(it) => std::fmt::Debug::fmt(it, f),
-            Self::AnyLeafBlock(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdAutolink(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdHardLine(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdInlineCode(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdInlineEmphasis(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdInlineHtml(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdInlineImage(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdInlineItalic(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdInlineLink(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdInlineStrikethrough(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdReferenceImage(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdReferenceLink(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdTextual(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
-impl From<AnyMdBlock> for SyntaxNode {
-    fn from(n: AnyMdBlock) -> Self {
+impl From<AnyMdInline> for SyntaxNode {
+    fn from(n: AnyMdInline) -> Self {
         match n {
-            AnyMdBlock::AnyContainerBlock(it) => it.into(),
-            AnyMdBlock::AnyLeafBlock(it) => it.into(),
+            AnyMdInline::MdAutolink(it) => it.into(),
+            AnyMdInline::MdHardLine(it) => it.into(),
+            AnyMdInline::MdInlineCode(it) => it.into(),
+            AnyMdInline::MdInlineEmphasis(it) => it.into(),
+            AnyMdInline::MdInlineHtml(it) => it.into(),
+            AnyMdInline::MdInlineImage(it) => it.into(),
+            AnyMdInline::MdInlineItalic(it) => it.into(),
+            AnyMdInline::MdInlineLink(it) => it.into(),
+            AnyMdInline::MdInlineStrikethrough(it) => it.into(),
+            AnyMdInline::MdReferenceImage(it) => it.into(),
+            AnyMdInline::MdReferenceLink(it) => it.into(),
+            AnyMdInline::MdTextual(it) => it.into(),
         }
     }
 }
-impl From<AnyMdBlock> for SyntaxElement {
-    fn from(n: AnyMdBlock) -> Self {
+impl From<AnyMdInline> for SyntaxElement {
+    fn from(n: AnyMdInline) -> Self {
         let node: SyntaxNode = n.into();
         node.into()
     }
 }
-impl From<MdHardLine> for AnyMdInline {
-    fn from(node: MdHardLine) -> Self {
-        Self::MdHardLine(node)
+impl From<MdFencedCodeBlock> for AnyMdLeafBlock {
+    fn from(node: MdFencedCodeBlock) -> Self {
+        Self::MdFencedCodeBlock(node)
     }
 }
-impl From<MdHtmlBlock> for AnyMdInline {
-    fn from(node: MdHtmlBlock) -> Self {
-        Self::MdHtmlBlock(node)
+impl From<MdHeader> for AnyMdLeafBlock {
+    fn from(node: MdHeader) -> Self {
+        Self::MdHeader(node)
     }
 }
-impl From<MdInlineCode> for AnyMdInline {
-    fn from(node: MdInlineCode) -> Self {
-        Self::MdInlineCode(node)
+impl From<MdHtmlBlock> for AnyMdLeafBlock {
+    fn from(node: MdHtmlBlock) -> Self {
+        Self::MdHtmlBlock(node)
     }
 }
-impl From<MdInlineEmphasis> for AnyMdInline {
-    fn from(node: MdInlineEmphasis) -> Self {
-        Self::MdInlineEmphasis(node)
+impl From<MdIndentCodeBlock> for AnyMdLeafBlock {
+    fn from(node: MdIndentCodeBlock) -> Self {
+        Self::MdIndentCodeBlock(node)
     }
 }
-impl From<MdInlineImage> for AnyMdInline {
-    fn from(node: MdInlineImage) -> Self {
-        Self::MdInlineImage(node)
+impl From<MdLinkReferenceDefinition> for AnyMdLeafBlock {
+    fn from(node: MdLinkReferenceDefinition) -> Self {
+        Self::MdLinkReferenceDefinition(node)
     }
 }
-impl From<MdInlineItalic> for AnyMdInline {
-    fn from(node: MdInlineItalic) -> Self {
-        Self::MdInlineItalic(node)
+impl From<MdParagraph> for AnyMdLeafBlock {
+    fn from(node: MdParagraph) -> Self {
+        Self::MdParagraph(node)
     }
 }
-impl From<MdInlineLink> for AnyMdInline {
-    fn from(node: MdInlineLink) -> Self {
-        Self::MdInlineLink(node)
+impl From<MdSetextHeader> for AnyMdLeafBlock {
+    fn from(node: MdSetextHeader) -> Self {
+        Self::MdSetextHeader(node)
     }
 }
-impl From<MdSoftBreak> for AnyMdInline {
-    fn from(node: MdSoftBreak) -> Self {
-        Self::MdSoftBreak(node)
+impl From<MdTable> for AnyMdLeafBlock {
+    fn from(node: MdTable) -> Self {
+        Self::MdTable(node)
     }
 }
-impl From<MdTextual> for AnyMdInline {
-    fn from(node: MdTextual) -> Self {
-        Self::MdTextual(node)
+impl From<MdThematicBreakBlock> for AnyMdLeafBlock {
+    fn from(node: MdThematicBreakBlock) -> Self {
+        Self::MdThematicBreakBlock(node)
     }
 }
-impl AstNode for AnyMdInline {
+impl AstNode for AnyMdLeafBlock {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = MdHardLine::KIND_SET
-        .union(MdHtmlBlock::KIND_SET)
-        .union(MdInlineCode::KIND_SET)
-        .union(MdInlineEmphasis::KIND_SET)
-        .union(MdInlineImage::KIND_SET)
-        .union(MdInlineItalic::KIND_SET)
-        .union(MdInlineLink::KIND_SET)
-        .union(MdSoftBreak::KIND_SET)
-        .union(MdTextual::KIND_SET);
+    const KIND_SET: SyntaxKindSet<Language> = MdFencedCodeBlock::KIND_SET
+        .union(MdHeader::KIND_SET)
+        .union(MdHtmlBlock::KIND_SET)
+        .union(MdIndentCodeBlock::KIND_SET)
+        .union(MdLinkReferenceDefinition::KIND_SET)
+        .union(MdParagraph::KIND_SET)
+        .union(MdSetextHeader::KIND_SET)
+        .union(MdTable::KIND_SET)
+        .union(MdThematicBreakBlock::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         matches!(
             kind,
-            MD_HARD_LINE
+            MD_FENCED_CODE_BLOCK
+                | MD_HEADER
                 | MD_HTML_BLOCK
-                | MD_INLINE_CODE
-                | MD_INLINE_EMPHASIS
-                | MD_INLINE_IMAGE
-                | MD_INLINE_ITALIC
-                | MD_INLINE_LINK
-                | MD_SOFT_BREAK
-                | MD_TEXTUAL
+                | MD_INDENT_CODE_BLOCK
+                | MD_LINK_REFERENCE_DEFINITION
+                | MD_PARAGRAPH
+                | MD_SETEXT_HEADER
+                | MD_TABLE
+                | MD_THEMATIC_BREAK_BLOCK
         )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
-            MD_HARD_LINE => Self::MdHardLine(MdHardLine { syntax }),
+            MD_FENCED_CODE_BLOCK => Self::MdFencedCodeBlock(MdFencedCodeBlock { syntax }),
+            MD_HEADER => Self::MdHeader(MdHeader { syntax }),
             MD_HTML_BLOCK => Self::MdHtmlBlock(MdHtmlBlock { syntax }),
-            MD_INLINE_CODE => Self::MdInlineCode(MdInlineCode { syntax }),
-            MD_INLINE_EMPHASIS => Self::MdInlineEmphasis(MdInlineEmphasis { syntax }),
-            MD_INLINE_IMAGE => Self::MdInlineImage(MdInlineImage { syntax }),
-            MD_INLINE_ITALIC => Self::MdInlineItalic(MdInlineItalic { syntax }),
-            MD_INLINE_LINK => Self::MdInlineLink(MdInlineLink { syntax }),
-            MD_SOFT_BREAK => Self::MdSoftBreak(MdSoftBreak { syntax }),
-            MD_TEXTUAL => Self::MdTextual(MdTextual { syntax }),
+            MD_INDENT_CODE_BLOCK => Self::MdIndentCodeBlock(MdIndentCodeBlock { syntax }),
+            MD_LINK_REFERENCE_DEFINITION => {
+                Self::MdLinkReferenceDefinition(MdLinkReferenceDefinition { syntax })
+            }
+            MD_PARAGRAPH => Self::MdParagraph(MdParagraph { syntax }),
+            MD_SETEXT_HEADER => Self::MdSetextHeader(MdSetextHeader { syntax }),
+            MD_TABLE => Self::MdTable(MdTable { syntax }),
+            MD_THEMATIC_BREAK_BLOCK => Self::MdThematicBreakBlock(MdThematicBreakBlock { syntax }),
             _ => return None,
         };
         Some(res)
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
-            Self::MdHardLine(it) => &it.syntax,
+            Self::MdFencedCodeBlock(it) => &it.syntax,
+            Self::MdHeader(it) => &it.syntax,
             Self::MdHtmlBlock(it) => &it.syntax,
-            Self::MdInlineCode(it) => &it.syntax,
-            Self::MdInlineEmphasis(it) => &it.syntax,
-            Self::MdInlineImage(it) => &it.syntax,
-            Self::MdInlineItalic(it) => &it.syntax,
-            Self::MdInlineLink(it) => &it.syntax,
-            Self::MdSoftBreak(it) => &it.syntax,
-            Self::MdTextual(it) => &it.syntax,
+            Self::MdIndentCodeBlock(it) => &it.syntax,
+            Self::MdLinkReferenceDefinition(it) => &it.syntax,
+            Self::MdParagraph(it) => &it.syntax,
+            Self::MdSetextHeader(it) => &it.syntax,
+            Self::MdTable(it) => &it.syntax,
+            Self::MdThematicBreakBlock(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
-            Self::MdHardLine(it) => it.syntax,
+            Self::MdFencedCodeBlock(it) => it.syntax,
+            Self::MdHeader(it) => it.syntax,
             Self::MdHtmlBlock(it) => it.syntax,
-            Self::MdInlineCode(it) => it.syntax,
-            Self::MdInlineEmphasis(it) => it.syntax,
-            Self::MdInlineImage(it) => it.syntax,
-            Self::MdInlineItalic(it) => it.syntax,
-            Self::MdInlineLink(it) => it.syntax,
-            Self::MdSoftBreak(it) => it.syntax,
-            Self::MdTextual(it) => it.syntax,
+            Self::MdIndentCodeBlock(it) => it.syntax,
+            Self::MdLinkReferenceDefinition(it) => it.syntax,
+            Self::MdParagraph(it) => it.syntax,
+            Self::MdSetextHeader(it) => it.syntax,
+            Self::MdTable(it) => it.syntax,
+            Self::MdThematicBreakBlock(it) => it.syntax,
         }
     }
 }
-impl std::fmt::Debug for AnyMdInline {
+impl std::fmt::Debug for AnyMdLeafBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::MdHardLine(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdFencedCodeBlock(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdHeader(it) => std::fmt::Debug::fmt(it, f),
             Self::MdHtmlBlock(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdInlineCode(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdInlineEmphasis(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdInlineImage(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdInlineItalic(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdInlineLink(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdSoftBreak(it) => std::fmt::Debug::fmt(it, f),
-            Self::MdTextual(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdIndentCodeBlock(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdLinkReferenceDefinition(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdParagraph(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdSetextHeader(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdTable(it) => std::fmt::Debug::fmt(it, f),
+            Self::MdThematicBreakBlock(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
-impl From<AnyMdInline> for SyntaxNode {
-    fn from(n: AnyMdInline) -> Self {
+impl From<AnyMdLeafBlock> for SyntaxNode {
+    fn from(n: AnyMdLeafBlock) -> Self {
         match n {
-            AnyMdInline::MdHardLine(it) => it.into(),
-            AnyMdInline::MdHtmlBlock(it) => it.into(),
-            AnyMdInline::MdInlineCode(it) => it.into(),
-            AnyMdInline::MdInlineEmphasis(it) => it.into(),
-            AnyMdInline::MdInlineImage(it) => it.into(),
-            AnyMdInline::MdInlineItalic(it) => it.into(),
-            AnyMdInline::MdInlineLink(it) => it.into(),
-            AnyMdInline::MdSoftBreak(it) => it.into(),
-            AnyMdInline::MdTextual(it) => it.into(),
+            AnyMdLeafBlock::MdFencedCodeBlock(it) => it.into(),
+            AnyMdLeafBlock::MdHeader(it) => it.into(),
+            AnyMdLeafBlock::MdHtmlBlock(it) => it.into(),
+            AnyMdLeafBlock::MdIndentCodeBlock(it) => it.into(),
+            AnyMdLeafBlock::MdLinkReferenceDefinition(it) => it.into(),
+            AnyMdLeafBlock::MdParagraph(it) => it.into(),
+            AnyMdLeafBlock::MdSetextHeader(it) => it.into(),
+            AnyMdLeafBlock::MdTable(it) => it.into(),
+            AnyMdLeafBlock::MdThematicBreakBlock(it) => it.into(),
         }
     }
 }
-impl From<AnyMdInline> for SyntaxElement {
-    fn from(n: AnyMdInline) -> Self {
+impl From<AnyMdLeafBlock> for SyntaxElement {
+    fn from(n: AnyMdLeafBlock) -> Self {
         let node: SyntaxNode = n.into();
         node.into()
     }
 }
-impl std::fmt::Display for AnyCodeBlock {
+impl std::fmt::Display for AnyMdBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for AnyContainerBlock {
+impl std::fmt::Display for AnyMdContainerBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for AnyLeafBlock {
+impl std::fmt::Display for AnyMdInline {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for AnyMdBlock {
+impl std::fmt::Display for AnyMdLeafBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for AnyMdInline {
+impl std::fmt::Display for MdAutolink {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdBullet {
+impl std::fmt::Display for MdBulletList {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -3251,67 +4072,67 @@ impl std::fmt::Display for MdHtmlBlock {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdIndent {
+impl std::fmt::Display for MdIndentCodeBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdIndentCodeBlock {
+impl std::fmt::Display for MdInlineCode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdIndentedCodeLine {
+impl std::fmt::Display for MdInlineEmphasis {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineCode {
+impl std::fmt::Display for MdInlineHtml {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineEmphasis {
+impl std::fmt::Display for MdInlineImage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineImage {
+impl std::fmt::Display for MdInlineItalic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineImageAlt {
+impl std::fmt::Display for MdInlineLink {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineImageLink {
+impl std::fmt::Display for MdInlineStrikethrough {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineImageSource {
+impl std::fmt::Display for MdLinkDestination {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineItalic {
+impl std::fmt::Display for MdLinkReferenceDefinition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdInlineLink {
+impl std::fmt::Display for MdLinkTitle {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdLinkBlock {
+impl std::fmt::Display for MdOrderedList {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdOrderListItem {
+impl std::fmt::Display for MdOrderedListItem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -3326,12 +4147,47 @@ impl std::fmt::Display for MdQuote {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for MdReferenceImage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MdReferenceLink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MdReferenceLinkLabel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for MdSetextHeader {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for MdSoftBreak {
+impl std::fmt::Display for MdTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MdTableCell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MdTableDelimiterCell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MdTableDelimiterRow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MdTableRow {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -3486,10 +4342,10 @@ impl IntoIterator for MdBlockList {
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct MdBulletList {
+pub struct MdBulletListItemList {
     syntax_list: SyntaxList,
 }
-impl MdBulletList {
+impl MdBulletListItemList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -3502,12 +4358,12 @@ impl MdBulletList {
         }
     }
 }
-impl AstNode for MdBulletList {
+impl AstNode for MdBulletListItemList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_BULLET_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_BULLET_LIST_ITEM_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_BULLET_LIST
+        kind == MD_BULLET_LIST_ITEM_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -3525,7 +4381,89 @@ impl AstNode for MdBulletList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for MdBulletList {
+impl Serialize for MdBulletListItemList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstNodeList for MdBulletListItemList {
+    type Language = Language;
+    type Node = MdBulletListItem;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for MdBulletListItemList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MdBulletListItemList ")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl IntoIterator for &MdBulletListItemList {
+    type Item = MdBulletListItem;
+    type IntoIter = AstNodeListIterator<Language, MdBulletListItem>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for MdBulletListItemList {
+    type Item = MdBulletListItem;
+    type IntoIter = AstNodeListIterator<Language, MdBulletListItem>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct MdHashList {
+    syntax_list: SyntaxList,
+}
+impl MdHashList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for MdHashList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_HASH_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MD_HASH_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+impl Serialize for MdHashList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -3537,9 +4475,9 @@ impl Serialize for MdBulletList {
         seq.end()
     }
 }
-impl AstNodeList for MdBulletList {
+impl AstNodeList for MdHashList {
     type Language = Language;
-    type Node = MdBullet;
+    type Node = MdHash;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -3547,31 +4485,31 @@ impl AstNodeList for MdBulletList {
         self.syntax_list
     }
 }
-impl Debug for MdBulletList {
+impl Debug for MdHashList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("MdBulletList ")?;
+        f.write_str("MdHashList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &MdBulletList {
-    type Item = MdBullet;
-    type IntoIter = AstNodeListIterator<Language, MdBullet>;
+impl IntoIterator for &MdHashList {
+    type Item = MdHash;
+    type IntoIter = AstNodeListIterator<Language, MdHash>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for MdBulletList {
-    type Item = MdBullet;
-    type IntoIter = AstNodeListIterator<Language, MdBullet>;
+impl IntoIterator for MdHashList {
+    type Item = MdHash;
+    type IntoIter = AstNodeListIterator<Language, MdHash>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct MdCodeNameList {
+pub struct MdInlineItemList {
     syntax_list: SyntaxList,
 }
-impl MdCodeNameList {
+impl MdInlineItemList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -3584,12 +4522,12 @@ impl MdCodeNameList {
         }
     }
 }
-impl AstNode for MdCodeNameList {
+impl AstNode for MdInlineItemList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_CODE_NAME_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_ITEM_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_CODE_NAME_LIST
+        kind == MD_INLINE_ITEM_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -3607,7 +4545,7 @@ impl AstNode for MdCodeNameList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for MdCodeNameList {
+impl Serialize for MdInlineItemList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -3619,9 +4557,9 @@ impl Serialize for MdCodeNameList {
         seq.end()
     }
 }
-impl AstSeparatedList for MdCodeNameList {
+impl AstNodeList for MdInlineItemList {
     type Language = Language;
-    type Node = MdTextual;
+    type Node = AnyMdInline;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -3629,31 +4567,31 @@ impl AstSeparatedList for MdCodeNameList {
         self.syntax_list
     }
 }
-impl Debug for MdCodeNameList {
+impl Debug for MdInlineItemList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("MdCodeNameList ")?;
-        f.debug_list().entries(self.elements()).finish()
+        f.write_str("MdInlineItemList ")?;
+        f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for MdCodeNameList {
-    type Item = SyntaxResult<MdTextual>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, MdTextual>;
+impl IntoIterator for &MdInlineItemList {
+    type Item = AnyMdInline;
+    type IntoIter = AstNodeListIterator<Language, AnyMdInline>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for &MdCodeNameList {
-    type Item = SyntaxResult<MdTextual>;
-    type IntoIter = AstSeparatedListNodesIterator<Language, MdTextual>;
+impl IntoIterator for MdInlineItemList {
+    type Item = AnyMdInline;
+    type IntoIter = AstNodeListIterator<Language, AnyMdInline>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct MdHashList {
+pub struct MdOrderedListItemList {
     syntax_list: SyntaxList,
 }
-impl MdHashList {
+impl MdOrderedListItemList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -3666,12 +4604,12 @@ impl MdHashList {
         }
     }
 }
-impl AstNode for MdHashList {
+impl AstNode for MdOrderedListItemList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_HASH_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_ORDERED_LIST_ITEM_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_HASH_LIST
+        kind == MD_ORDERED_LIST_ITEM_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -3689,7 +4627,7 @@ impl AstNode for MdHashList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for MdHashList {
+impl Serialize for MdOrderedListItemList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -3701,9 +4639,9 @@ impl Serialize for MdHashList {
         seq.end()
     }
 }
-impl AstNodeList for MdHashList {
+impl AstNodeList for MdOrderedListItemList {
     type Language = Language;
-    type Node = MdHash;
+    type Node = MdOrderedListItem;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -3711,31 +4649,31 @@ impl AstNodeList for MdHashList {
         self.syntax_list
     }
 }
-impl Debug for MdHashList {
+impl Debug for MdOrderedListItemList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("MdHashList ")?;
+        f.write_str("MdOrderedListItemList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &MdHashList {
-    type Item = MdHash;
-    type IntoIter = AstNodeListIterator<Language, MdHash>;
+impl IntoIterator for &MdOrderedListItemList {
+    type Item = MdOrderedListItem;
+    type IntoIter = AstNodeListIterator<Language, MdOrderedListItem>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for MdHashList {
-    type Item = MdHash;
-    type IntoIter = AstNodeListIterator<Language, MdHash>;
+impl IntoIterator for MdOrderedListItemList {
+    type Item = MdOrderedListItem;
+    type IntoIter = AstNodeListIterator<Language, MdOrderedListItem>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct MdIndentedCodeLineList {
+pub struct MdTableCellList {
     syntax_list: SyntaxList,
 }
-impl MdIndentedCodeLineList {
+impl MdTableCellList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -3748,12 +4686,12 @@ impl MdIndentedCodeLineList {
         }
     }
 }
-impl AstNode for MdIndentedCodeLineList {
+impl AstNode for MdTableCellList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INDENTED_CODE_LINE_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE_CELL_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INDENTED_CODE_LINE_LIST
+        kind == MD_TABLE_CELL_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -3771,7 +4709,7 @@ impl AstNode for MdIndentedCodeLineList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for MdIndentedCodeLineList {
+impl Serialize for MdTableCellList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -3783,9 +4721,9 @@ impl Serialize for MdIndentedCodeLineList {
         seq.end()
     }
 }
-impl AstNodeList for MdIndentedCodeLineList {
+impl AstNodeList for MdTableCellList {
     type Language = Language;
-    type Node = MdIndentedCodeLine;
+    type Node = MdTableCell;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -3793,31 +4731,31 @@ impl AstNodeList for MdIndentedCodeLineList {
         self.syntax_list
     }
 }
-impl Debug for MdIndentedCodeLineList {
+impl Debug for MdTableCellList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("MdIndentedCodeLineList ")?;
+        f.write_str("MdTableCellList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &MdIndentedCodeLineList {
-    type Item = MdIndentedCodeLine;
-    type IntoIter = AstNodeListIterator<Language, MdIndentedCodeLine>;
+impl IntoIterator for &MdTableCellList {
+    type Item = MdTableCell;
+    type IntoIter = AstNodeListIterator<Language, MdTableCell>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for MdIndentedCodeLineList {
-    type Item = MdIndentedCodeLine;
-    type IntoIter = AstNodeListIterator<Language, MdIndentedCodeLine>;
+impl IntoIterator for MdTableCellList {
+    type Item = MdTableCell;
+    type IntoIter = AstNodeListIterator<Language, MdTableCell>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct MdInlineItemList {
+pub struct MdTableDelimiterCellList {
     syntax_list: SyntaxList,
 }
-impl MdInlineItemList {
+impl MdTableDelimiterCellList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -3830,12 +4768,12 @@ impl MdInlineItemList {
         }
     }
 }
-impl AstNode for MdInlineItemList {
+impl AstNode for MdTableDelimiterCellList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_INLINE_ITEM_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE_DELIMITER_CELL_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_INLINE_ITEM_LIST
+        kind == MD_TABLE_DELIMITER_CELL_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -3853,7 +4791,7 @@ impl AstNode for MdInlineItemList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for MdInlineItemList {
+impl Serialize for MdTableDelimiterCellList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -3865,9 +4803,9 @@ impl Serialize for MdInlineItemList {
         seq.end()
     }
 }
-impl AstNodeList for MdInlineItemList {
+impl AstNodeList for MdTableDelimiterCellList {
     type Language = Language;
-    type Node = AnyMdInline;
+    type Node = MdTableDelimiterCell;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -3875,31 +4813,31 @@ impl AstNodeList for MdInlineItemList {
         self.syntax_list
     }
 }
-impl Debug for MdInlineItemList {
+impl Debug for MdTableDelimiterCellList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("MdInlineItemList ")?;
+        f.write_str("MdTableDelimiterCellList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &MdInlineItemList {
-    type Item = AnyMdInline;
-    type IntoIter = AstNodeListIterator<Language, AnyMdInline>;
+impl IntoIterator for &MdTableDelimiterCellList {
+    type Item = MdTableDelimiterCell;
+    type IntoIter = AstNodeListIterator<Language, MdTableDelimiterCell>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for MdInlineItemList {
-    type Item = AnyMdInline;
-    type IntoIter = AstNodeListIterator<Language, AnyMdInline>;
+impl IntoIterator for MdTableDelimiterCellList {
+    type Item = MdTableDelimiterCell;
+    type IntoIter = AstNodeListIterator<Language, MdTableDelimiterCell>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct MdOrderList {
+pub struct MdTableRowList {
     syntax_list: SyntaxList,
 }
-impl MdOrderList {
+impl MdTableRowList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -3912,12 +4850,12 @@ impl MdOrderList {
         }
     }
 }
-impl AstNode for MdOrderList {
+impl AstNode for MdTableRowList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(MD_ORDER_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(MD_TABLE_ROW_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == MD_ORDER_LIST
+        kind == MD_TABLE_ROW_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -3935,7 +4873,7 @@ impl AstNode for MdOrderList {
         self.syntax_list.into_node()
     }
 }
-impl Serialize for MdOrderList {
+impl Serialize for MdTableRowList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -3947,9 +4885,9 @@ impl Serialize for MdOrderList {
         seq.end()
     }
 }
-impl AstNodeList for MdOrderList {
+impl AstNodeList for MdTableRowList {
     type Language = Language;
-    type Node = AnyCodeBlock;
+    type Node = MdTableRow;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -3957,22 +4895,22 @@ impl AstNodeList for MdOrderList {
         self.syntax_list
     }
 }
-impl Debug for MdOrderList {
+impl Debug for MdTableRowList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("MdOrderList ")?;
+        f.write_str("MdTableRowList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &MdOrderList {
-    type Item = AnyCodeBlock;
-    type IntoIter = AstNodeListIterator<Language, AnyCodeBlock>;
+impl IntoIterator for &MdTableRowList {
+    type Item = MdTableRow;
+    type IntoIter = AstNodeListIterator<Language, MdTableRow>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for MdOrderList {
-    type Item = AnyCodeBlock;
-    type IntoIter = AstNodeListIterator<Language, AnyCodeBlock>;
+impl IntoIterator for MdTableRowList {
+    type Item = MdTableRow;
+    type IntoIter = AstNodeListIterator<Language, MdTableRow>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
@@ -2,10 +2,13 @@
 
 #[macro_use]
 mod generated;
+mod file_source;
 mod syntax_node;
 
 pub use self::generated::*;
-use biome_rowan::{RawSyntaxKind, SyntaxKind, TriviaPieceKind};
+use biome_rowan::{RawSyntaxKind, SyntaxKind};
+pub use biome_rowan::{TextLen, TextRange, TextSize, TokenAtOffset, TriviaPieceKind, WalkEvent};
+pub use file_source::MarkdownFileSource;
 pub use syntax_node::*;
 
 impl From<u16> for MarkdownSyntaxKind {
@@ -37,7 +40,7 @@ impl SyntaxKind for MarkdownSyntaxKind {
     }
 
     fn is_root(&self) -> bool {
-        todo!()
+        matches!(self, Self::MD_DOCUMENT)
     }
 
     fn is_list(&self) -> bool {
@@ -16,7 +16,7 @@ use rustc_hash::FxHashMap;
 
 use crate::{JsExport, JsOwnExport, ModuleGraph, js_module_info::JsModuleInfoInner};
 
-use super::JsModuleInfo;
+use super::{JsModuleInfo, scope_id_for_range};
 
 const MAX_IMPORT_DEPTH: usize = 10; // Arbitrary depth, may require tweaking.
 
@@ -86,6 +86,18 @@ impl ScopedResolver {
         Type::from_id(self.clone(), type_id)
     }
 
+    /// Returns the resolved type of the binding with the given `name`, as seen
+    /// from the scope that contains `range`.
+    ///
+    /// Assumes that the resolver's inference has run.
+    pub fn resolved_type_for_binding(self: &Arc<Self>, name: &Text, range: TextRange) -> Type {
+        let scope_id = scope_id_for_range(&self.modules[0].scope_by_range, range);
+        let type_id = self
+            .resolve_type_of(name, scope_id)
+            .unwrap_or(GLOBAL_UNKNOWN_ID);
+        Type::from_id(self.clone(), type_id)
+    }
+
     fn find_module(&self, path: &ResolvedPath) -> Option<ModuleId> {
         self.modules_by_path.get(path).copied()
     }
@@ -7,6 +7,7 @@
 //! The module graph is instantiated and updated inside the Workspace Server.
 
 mod fs_proxy;
+mod navigation;
 
 use std::collections::BTreeSet;
 
@@ -20,9 +21,10 @@ use camino::{Utf8Path, Utf8PathBuf};
 use papaya::{HashMap, HashMapRef, LocalGuard};
 use rustc_hash::FxBuildHasher;
 
-use crate::{JsExport, JsModuleInfo, JsOwnExport, js_module_info::JsModuleVisitor};
+use crate::{JsExport, JsImport, JsModuleInfo, JsOwnExport, js_module_info::JsModuleVisitor};
 
 pub(crate) use fs_proxy::ModuleGraphFsProxy;
+pub use navigation::{SymbolImport, SymbolLocation};
 
 pub const SUPPORTED_EXTENSIONS: &[&str] = &[
     "js", "jsx", "mjs", "cjs", "ts", "tsx", "mts", "cts", "json", "node",
@@ -113,6 +115,25 @@ impl ModuleGraph {
         }
     }
 
+    /// Collects the module info for the given `root` without registering it in
+    /// the graph.
+    ///
+    /// This is useful for documents that were not indexed by the scanner, but
+    /// for which we still want to resolve types, such as documents opened in
+    /// an editor. Imports are resolved against the modules that _are_ part of
+    /// the graph.
+    pub fn module_info_for_js_root(
+        &self,
+        fs: &dyn FsWithResolverProxy,
+        project_layout: &ProjectLayout,
+        path: &BiomePath,
+        root: AnyJsRoot,
+    ) -> JsModuleInfo {
+        let fs_proxy = ModuleGraphFsProxy::new(fs, self, project_layout);
+        let directory = path.parent().unwrap_or(path);
+        JsModuleVisitor::new(root, directory, &fs_proxy).collect_info()
+    }
+
     pub fn get_or_insert_path_info(
         &self,
         path: &Utf8Path,
@@ -124,6 +145,32 @@ impl ModuleGraph {
             .clone()
     }
 
+    /// Returns the paths of the modules that `path` imports, directly or
+    /// through other modules, sorted by path.
+    ///
+    /// Only imports that could be resolved are followed. The returned set
+    /// doesn't include `path` itself, unless it's part of an import cycle.
+    pub fn transitive_dependencies(&self, path: &Utf8Path) -> BTreeSet<Utf8PathBuf> {
+        let data = self.data.pin();
+        let mut dependencies = BTreeSet::new();
+        let mut queue = vec![path.to_path_buf()];
+        while let Some(path) = queue.pop() {
+            let Some(module) = data.get(&path) else {
+                continue;
+            };
+
+            for resolved_path in module.all_import_paths() {
+                if let Some(dependency) = resolved_path.as_path() {
+                    if dependencies.insert(dependency.to_path_buf()) {
+                        queue.push(dependency.to_path_buf());
+                    }
+                }
+            }
+        }
+
+        dependencies
+    }
+
     /// Finds an exported symbol by `symbol_name` as exported by `module`.
     ///
     /// Follows re-exports if necessary.
@@ -136,7 +183,7 @@ impl ModuleGraph {
         let mut seen_paths = BTreeSet::new();
 
         find_exported_symbol_with_seen_paths(&data, module, symbol_name, &mut seen_paths)
-            .map(|(_, export)| export.clone())
+            .map(|(_, _, export)| export.clone())
     }
 
     /// Finds an exported symbol by `symbol_name` as exported by `module`.
@@ -151,7 +198,7 @@ impl ModuleGraph {
         let mut seen_paths = BTreeSet::new();
 
         find_exported_symbol_with_seen_paths(&data, module, symbol_name, &mut seen_paths).and_then(
-            |(module, export)| match export {
+            |(_, module, export)| match export {
                 JsOwnExport::Binding(binding_id) => {
                     module.bindings[binding_id.index()].jsdoc.clone()
                 }
@@ -161,36 +208,46 @@ impl ModuleGraph {
     }
 }
 
+/// Finds an exported symbol by `symbol_name` as exported by `module`.
+///
+/// Returns the module that defines the symbol, together with its path if it
+/// differs from `module`.
 fn find_exported_symbol_with_seen_paths<'a>(
     data: &'a HashMapRef<Utf8PathBuf, JsModuleInfo, FxBuildHasher, LocalGuard>,
     module: &'a JsModuleInfo,
     symbol_name: &str,
     seen_paths: &mut BTreeSet<&'a Utf8Path>,
-) -> Option<(&'a JsModuleInfo, &'a JsOwnExport)> {
+) -> Option<(Option<&'a Utf8Path>, &'a JsModuleInfo, &'a JsOwnExport)> {
     match module.exports.get(symbol_name) {
         Some(JsExport::Own(own_export) | JsExport::OwnType(own_export)) => {
-            Some((module, own_export))
+            Some((None, module, own_export))
         }
         Some(JsExport::Reexport(reexport) | JsExport::ReexportType(reexport)) => {
-            if reexport.import.symbol == ImportSymbol::All {
+            let imported_name = match &reexport.import.symbol {
+                ImportSymbol::Default => "default",
+                ImportSymbol::Named(name) => name.text(),
                 // TODO: Follow namespace exports.
-                None
-            } else {
-                match reexport.import.resolved_path.as_deref() {
-                    Ok(path) if seen_paths.insert(path) => data.get(path).and_then(|module| {
-                        find_exported_symbol_with_seen_paths(data, module, symbol_name, seen_paths)
-                    }),
-                    _ => None,
-                }
-            }
+                ImportSymbol::All => return None,
+            };
+            find_reexported_symbol(data, &reexport.import, imported_name, seen_paths)
         }
         None => module.blanket_reexports.iter().find_map(|reexport| {
-            match reexport.import.resolved_path.as_deref() {
-                Ok(path) if seen_paths.insert(path) => data.get(path).and_then(|module| {
-                    find_exported_symbol_with_seen_paths(data, module, symbol_name, seen_paths)
-                }),
-                _ => None,
-            }
+            find_reexported_symbol(data, &reexport.import, symbol_name, seen_paths)
+        }),
+    }
+}
+
+fn find_reexported_symbol<'a>(
+    data: &'a HashMapRef<Utf8PathBuf, JsModuleInfo, FxBuildHasher, LocalGuard>,
+    import: &'a JsImport,
+    symbol_name: &str,
+    seen_paths: &mut BTreeSet<&'a Utf8Path>,
+) -> Option<(Option<&'a Utf8Path>, &'a JsModuleInfo, &'a JsOwnExport)> {
+    match import.resolved_path.as_deref() {
+        Ok(path) if seen_paths.insert(path) => data.get(path).and_then(|module| {
+            find_exported_symbol_with_seen_paths(data, module, symbol_name, seen_paths)
+                .map(|(found_path, module, export)| (found_path.or(Some(path)), module, export))
         }),
+        _ => None,
     }
 }
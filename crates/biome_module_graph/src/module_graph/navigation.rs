@@ -0,0 +1,389 @@
+//! Navigation across modules, such as finding the definition of a symbol or
+//! all the references to it.
+
+use std::collections::BTreeSet;
+
+use biome_js_type_info::{BindingId, ImportSymbol, ScopeId};
+use biome_rowan::{Text, TextRange, TextSize};
+use camino::{Utf8Path, Utf8PathBuf};
+use papaya::{HashMapRef, LocalGuard};
+use rustc_hash::FxBuildHasher;
+
+use crate::{JsExport, JsImport, JsModuleInfo, JsOwnExport, js_module_info::JsBindingData};
+
+use super::{ModuleGraph, find_exported_symbol_with_seen_paths};
+
+/// Location of a symbol in a module.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct SymbolLocation {
+    /// Path of the module that contains the symbol.
+    pub path: Utf8PathBuf,
+
+    /// Range of the symbol inside the module.
+    pub range: TextRange,
+}
+
+/// Import or re-export of a symbol by another module.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct SymbolImport {
+    /// Path of the importing module.
+    pub path: Utf8PathBuf,
+
+    /// Specifier of the imported module, as it appears in the source text.
+    pub specifier: Text,
+
+    /// Name under which the symbol is exported by the imported module.
+    pub imported_name: Text,
+
+    /// Whether the symbol is re-exported, rather than imported as a binding.
+    pub is_reexport: bool,
+}
+
+/// Pinned reference to the modules of the [ModuleGraph].
+type ModulesRef<'a> = HashMapRef<'a, Utf8PathBuf, JsModuleInfo, FxBuildHasher, LocalGuard<'a>>;
+
+impl ModuleGraph {
+    /// Finds the definition of the symbol found at the given `offset` inside
+    /// `module`, which is the module info for `path`.
+    ///
+    /// Imported symbols are followed to the module that defines them, including
+    /// through re-exports. If the defining module isn't part of the graph, the
+    /// returned location points to the start of the imported module.
+    pub fn find_definition(
+        &self,
+        path: &Utf8Path,
+        module: &JsModuleInfo,
+        offset: TextSize,
+    ) -> Option<SymbolLocation> {
+        let binding_id = binding_at_offset(module, offset)?;
+        let binding = module.binding(binding_id);
+        if binding.declaration_kind.is_import_declaration() {
+            if let Some(import) = module.static_imports.get(&binding.name) {
+                return self.find_imported_definition(import);
+            }
+        }
+
+        Some(SymbolLocation {
+            path: path.to_path_buf(),
+            range: binding.range,
+        })
+    }
+
+    /// Finds all the references to the symbol found at the given `offset`
+    /// inside `module`, which is the module info for `path`.
+    ///
+    /// If the symbol is exported, references inside every module that imports
+    /// it are returned too, including those that import it through a
+    /// re-export. The declaration of the symbol is only included if
+    /// `include_declaration` is `true`.
+    ///
+    /// Locations are sorted by path and range.
+    pub fn find_references(
+        &self,
+        path: &Utf8Path,
+        module: &JsModuleInfo,
+        offset: TextSize,
+        include_declaration: bool,
+    ) -> Vec<SymbolLocation> {
+        let Some(binding_id) = binding_at_offset(module, offset) else {
+            return Vec::new();
+        };
+
+        let data = self.data.pin();
+        let lookup = |module_path: &Utf8Path| {
+            if module_path == path {
+                Some(module.clone())
+            } else {
+                data.get(module_path).cloned()
+            }
+        };
+
+        // If the symbol is imported, we start looking from its definition.
+        let binding = module.binding(binding_id);
+        let definition = if binding.declaration_kind.is_import_declaration() {
+            module
+                .static_imports
+                .get(&binding.name)
+                .and_then(|import| self.find_imported_binding(import))
+        } else {
+            None
+        };
+        let (definition_path, definition_module, definition_id) = match definition {
+            Some((definition_path, definition_id)) => match lookup(&definition_path) {
+                Some(definition_module) => (definition_path, definition_module, definition_id),
+                None => (path.to_path_buf(), module.clone(), binding_id),
+            },
+            None => (path.to_path_buf(), module.clone(), binding_id),
+        };
+
+        let mut locations = Vec::new();
+        let definition = definition_module.binding(definition_id);
+        if include_declaration {
+            locations.push(SymbolLocation {
+                path: definition_path.clone(),
+                range: definition.range,
+            });
+        }
+        push_references(&mut locations, &definition_path, definition);
+
+        let mut queue: Vec<(Utf8PathBuf, Text)> = export_names(&definition_module, definition_id)
+            .map(|name| (definition_path.clone(), name))
+            .collect();
+        let mut seen_exports = BTreeSet::new();
+        while let Some((exporting_path, export_name)) = queue.pop() {
+            if !seen_exports.insert((exporting_path.clone(), export_name.clone())) {
+                continue;
+            }
+
+            for (importing_path, importing_module) in modules_with(&data, path, module) {
+                for (local_name, import) in importing_module.static_imports.iter() {
+                    if !imports_symbol(import, &exporting_path, &export_name) {
+                        continue;
+                    }
+
+                    let Some(import_id) = global_import_binding(importing_module, local_name)
+                    else {
+                        continue;
+                    };
+
+                    let binding = importing_module.binding(import_id);
+                    locations.push(SymbolLocation {
+                        path: importing_path.to_path_buf(),
+                        range: binding.range,
+                    });
+                    push_references(&mut locations, importing_path, binding);
+
+                    // The imported binding may be exported again.
+                    queue.extend(
+                        export_names(importing_module, import_id)
+                            .map(|name| (importing_path.to_path_buf(), name)),
+                    );
+                }
+
+                for (reexport_name, export) in importing_module.exports.iter() {
+                    if let JsExport::Reexport(reexport) | JsExport::ReexportType(reexport) = export
+                    {
+                        if imports_symbol(&reexport.import, &exporting_path, &export_name) {
+                            queue.push((importing_path.to_path_buf(), reexport_name.clone()));
+                        }
+                    }
+                }
+
+                if !importing_module.exports.contains_key(&export_name)
+                    && importing_module.blanket_reexports.iter().any(|reexport| {
+                        reexport.import.resolved_path.as_path() == Some(&exporting_path)
+                    })
+                {
+                    queue.push((importing_path.to_path_buf(), export_name.clone()));
+                }
+            }
+        }
+
+        locations.sort();
+        locations.dedup();
+        locations
+    }
+
+    /// Finds the imports and re-exports of the symbol declared at the given
+    /// `offset` inside `module`, which is the module info for `path`.
+    ///
+    /// Only the exports and re-exports that use the name of the symbol are
+    /// followed, since these are the ones that are affected when the symbol
+    /// is renamed. Imports that alias the symbol are returned, but the modules
+    /// that import the alias aren't.
+    pub fn find_symbol_imports(
+        &self,
+        path: &Utf8Path,
+        module: &JsModuleInfo,
+        offset: TextSize,
+    ) -> Vec<SymbolImport> {
+        let Some(binding_id) = binding_at_offset(module, offset) else {
+            return Vec::new();
+        };
+
+        let name = &module.binding(binding_id).name;
+        let mut queue: Vec<(Utf8PathBuf, Text)> = export_names(module, binding_id)
+            .filter(|export_name| export_name == name)
+            .map(|export_name| (path.to_path_buf(), export_name))
+            .collect();
+
+        let data = self.data.pin();
+        let mut imports = Vec::new();
+        let mut seen_exports = BTreeSet::new();
+        while let Some((exporting_path, export_name)) = queue.pop() {
+            if !seen_exports.insert((exporting_path.clone(), export_name.clone())) {
+                continue;
+            }
+
+            for (importing_path, importing_module) in modules_with(&data, path, module) {
+                for (local_name, import) in importing_module.static_imports.iter() {
+                    if !imports_symbol(import, &exporting_path, &export_name) {
+                        continue;
+                    }
+
+                    imports.push(SymbolImport {
+                        path: importing_path.to_path_buf(),
+                        specifier: import.specifier.clone(),
+                        imported_name: export_name.clone(),
+                        is_reexport: false,
+                    });
+
+                    // Renaming the import renames the local binding too, which
+                    // may be exported again.
+                    if local_name == &export_name {
+                        if let Some(import_id) = global_import_binding(importing_module, local_name)
+                        {
+                            queue.extend(
+                                export_names(importing_module, import_id)
+                                    .filter(|name| name == &export_name)
+                                    .map(|name| (importing_path.to_path_buf(), name)),
+                            );
+                        }
+                    }
+                }
+
+                for (reexport_name, export) in importing_module.exports.iter() {
+                    if let JsExport::Reexport(reexport) | JsExport::ReexportType(reexport) = export
+                    {
+                        if imports_symbol(&reexport.import, &exporting_path, &export_name) {
+                            imports.push(SymbolImport {
+                                path: importing_path.to_path_buf(),
+                                specifier: reexport.import.specifier.clone(),
+                                imported_name: export_name.clone(),
+                                is_reexport: true,
+                            });
+
+                            if reexport_name == &export_name {
+                                queue.push((importing_path.to_path_buf(), reexport_name.clone()));
+                            }
+                        }
+                    }
+                }
+
+                if !importing_module.exports.contains_key(&export_name)
+                    && importing_module.blanket_reexports.iter().any(|reexport| {
+                        reexport.import.resolved_path.as_path() == Some(&exporting_path)
+                    })
+                {
+                    queue.push((importing_path.to_path_buf(), export_name.clone()));
+                }
+            }
+        }
+
+        imports.sort();
+        imports.dedup();
+        imports
+    }
+
+    /// Returns the location of the definition of an imported symbol.
+    fn find_imported_definition(&self, import: &JsImport) -> Option<SymbolLocation> {
+        let import_path = import.resolved_path.as_path()?;
+        if let Some((path, binding_id)) = self.find_imported_binding(import) {
+            let module = self.module_info_for_path(&path)?;
+            return Some(SymbolLocation {
+                path,
+                range: module.binding(binding_id).range,
+            });
+        }
+
+        Some(SymbolLocation {
+            path: import_path.to_path_buf(),
+            range: TextRange::default(),
+        })
+    }
+
+    /// Returns the path of the module that defines the binding for an imported
+    /// symbol, together with the ID of the binding inside that module.
+    fn find_imported_binding(&self, import: &JsImport) -> Option<(Utf8PathBuf, BindingId)> {
+        let import_path = import.resolved_path.as_path()?;
+        let name = match &import.symbol {
+            ImportSymbol::Default => "default",
+            ImportSymbol::Named(name) => name.text(),
+            ImportSymbol::All => return None,
+        };
+
+        let data = self.data.pin();
+        let module = data.get(import_path)?;
+        let mut seen_paths = BTreeSet::from([import_path]);
+        match find_exported_symbol_with_seen_paths(&data, module, name, &mut seen_paths)? {
+            (path, _, JsOwnExport::Binding(binding_id)) => {
+                Some((path.unwrap_or(import_path).to_path_buf(), *binding_id))
+            }
+            (_, _, JsOwnExport::Type(_)) => None,
+        }
+    }
+}
+
+/// Returns all the modules of the graph, where the module for `path` is
+/// substituted by `module`.
+///
+/// `module` is included even if it's not part of the graph (yet).
+fn modules_with<'a>(
+    data: &'a ModulesRef<'_>,
+    path: &'a Utf8Path,
+    module: &'a JsModuleInfo,
+) -> impl Iterator<Item = (&'a Utf8Path, &'a JsModuleInfo)> {
+    data.iter()
+        .filter(move |(module_path, _)| module_path.as_path() != path)
+        .map(|(module_path, module)| (module_path.as_path(), module))
+        .chain(std::iter::once((path, module)))
+}
+
+/// Returns the binding that is declared or referenced at the given `offset`.
+fn binding_at_offset(module: &JsModuleInfo, offset: TextSize) -> Option<BindingId> {
+    module
+        .bindings
+        .iter()
+        .position(|binding| {
+            binding.range.contains_inclusive(offset)
+                || binding.references.iter().any(|reference| {
+                    TextRange::at(reference.range_start, TextSize::of(binding.name.text()))
+                        .contains_inclusive(offset)
+                })
+        })
+        .map(BindingId::new)
+}
+
+/// Returns the imported binding with the given `name` from the global scope
+/// of `module`.
+fn global_import_binding(module: &JsModuleInfo, name: &Text) -> Option<BindingId> {
+    module
+        .bindings
+        .iter()
+        .position(|binding| {
+            binding.scope_id == ScopeId::GLOBAL
+                && binding.declaration_kind.is_import_declaration()
+                && &binding.name == name
+        })
+        .map(BindingId::new)
+}
+
+/// Returns the names under which `module` exports the given binding.
+fn export_names(module: &JsModuleInfo, binding_id: BindingId) -> impl Iterator<Item = Text> + '_ {
+    module
+        .exports
+        .iter()
+        .filter_map(move |(name, export)| match export.as_own_export() {
+            Some(JsOwnExport::Binding(id)) if *id == binding_id => Some(name.clone()),
+            _ => None,
+        })
+}
+
+/// Returns whether `import` imports the symbol exported as `export_name` by
+/// the module at `path`.
+fn imports_symbol(import: &JsImport, path: &Utf8Path, export_name: &Text) -> bool {
+    import.resolved_path.as_path() == Some(path)
+        && match &import.symbol {
+            ImportSymbol::Default => export_name.text() == "default",
+            ImportSymbol::Named(name) => name == export_name,
+            ImportSymbol::All => false,
+        }
+}
+
+fn push_references(locations: &mut Vec<SymbolLocation>, path: &Utf8Path, binding: &JsBindingData) {
+    let len = TextSize::of(binding.name.text());
+    locations.extend(binding.references.iter().map(|reference| SymbolLocation {
+        path: path.to_path_buf(),
+        range: TextRange::at(reference.range_start, len),
+    }));
+}
@@ -14,11 +14,12 @@ use biome_json_parser::{JsonParserOptions, parse_json};
 use biome_json_value::{JsonObject, JsonString};
 use biome_module_graph::JsExport;
 use biome_module_graph::{
-    ImportSymbol, JsImport, JsReexport, ModuleGraph, ResolvedPath, ScopedResolver,
+    ImportSymbol, JsImport, JsReexport, ModuleGraph, ResolvedPath, ScopedResolver, SymbolImport,
+    SymbolLocation,
 };
 use biome_package::{Dependencies, PackageJson};
 use biome_project_layout::ProjectLayout;
-use biome_rowan::Text;
+use biome_rowan::{Text, TextRange, TextSize};
 use biome_test_utils::get_added_paths;
 use camino::{Utf8Path, Utf8PathBuf};
 
@@ -1308,3 +1309,200 @@ fn test_resolve_promise_from_imported_function_returning_reexported_promise_type
         "test_resolve_promise_from_imported_function_returning_reexported_promise_type",
     );
 }
+
+#[test]
+fn test_find_definition_through_reexport() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert("/src/foo.ts".into(), "export function foo() {}\n");
+    fs.insert(
+        "/src/reexport.ts".into(),
+        "export { foo as bar } from \"./foo.ts\";\n",
+    );
+    fs.insert(
+        "/src/index.ts".into(),
+        "import { bar } from \"./reexport.ts\";\n\nbar();\n",
+    );
+
+    let added_paths = [
+        BiomePath::new("/src/foo.ts"),
+        BiomePath::new("/src/index.ts"),
+        BiomePath::new("/src/reexport.ts"),
+    ];
+    let added_paths = get_added_paths(&fs, &added_paths);
+
+    let module_graph = ModuleGraph::default();
+    module_graph.update_graph_for_js_paths(&fs, &ProjectLayout::default(), &added_paths, &[]);
+
+    let index_path = Utf8Path::new("/src/index.ts");
+    let index_module = module_graph
+        .module_info_for_path(index_path)
+        .expect("module must exist");
+
+    // Offset of `bar` in `bar();`.
+    let definition = module_graph
+        .find_definition(index_path, &index_module, TextSize::from(38))
+        .expect("definition must be found");
+    assert_eq!(
+        definition,
+        SymbolLocation {
+            path: Utf8PathBuf::from("/src/foo.ts"),
+            range: TextRange::new(TextSize::from(16), TextSize::from(19)),
+        }
+    );
+}
+
+#[test]
+fn test_find_references_across_importers() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert("/src/foo.ts".into(), "export const foo = 1;\nfoo;\n");
+    fs.insert("/src/reexport.ts".into(), "export * from \"./foo.ts\";\n");
+    fs.insert(
+        "/src/a.ts".into(),
+        "import { foo as renamed } from \"./foo.ts\";\nrenamed;\n",
+    );
+    fs.insert(
+        "/src/b.ts".into(),
+        "import { foo } from \"./reexport.ts\";\nfoo;\n",
+    );
+
+    let added_paths = [
+        BiomePath::new("/src/a.ts"),
+        BiomePath::new("/src/b.ts"),
+        BiomePath::new("/src/foo.ts"),
+        BiomePath::new("/src/reexport.ts"),
+    ];
+    let added_paths = get_added_paths(&fs, &added_paths);
+
+    let module_graph = ModuleGraph::default();
+    module_graph.update_graph_for_js_paths(&fs, &ProjectLayout::default(), &added_paths, &[]);
+
+    let b_path = Utf8Path::new("/src/b.ts");
+    let b_module = module_graph
+        .module_info_for_path(b_path)
+        .expect("module must exist");
+
+    // Offset of `foo` in the last line of `b.ts`.
+    let references = module_graph.find_references(b_path, &b_module, TextSize::from(37), true);
+    let location = |path: &str, start: u32, end: u32| SymbolLocation {
+        path: Utf8PathBuf::from(path),
+        range: TextRange::new(TextSize::from(start), TextSize::from(end)),
+    };
+    assert_eq!(
+        references,
+        vec![
+            location("/src/a.ts", 16, 23),
+            location("/src/a.ts", 43, 50),
+            location("/src/b.ts", 9, 12),
+            location("/src/b.ts", 37, 40),
+            location("/src/foo.ts", 13, 16),
+            location("/src/foo.ts", 22, 25),
+        ]
+    );
+}
+
+#[test]
+fn test_find_symbol_imports_through_reexports() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert("/src/foo.ts".into(), "export const foo = 1;\n");
+    fs.insert(
+        "/src/reexport.ts".into(),
+        "export { foo } from \"./foo.ts\";\nexport { foo as bar } from \"./foo.ts\";\n",
+    );
+    fs.insert(
+        "/src/a.ts".into(),
+        "import { foo as renamed } from \"./foo.ts\";\nrenamed;\n",
+    );
+    fs.insert(
+        "/src/b.ts".into(),
+        "import { foo } from \"./reexport.ts\";\nexport { foo };\n",
+    );
+    fs.insert(
+        "/src/c.ts".into(),
+        "import { foo } from \"./b.ts\";\nimport { bar } from \"./reexport.ts\";\n",
+    );
+
+    let added_paths = [
+        BiomePath::new("/src/a.ts"),
+        BiomePath::new("/src/b.ts"),
+        BiomePath::new("/src/c.ts"),
+        BiomePath::new("/src/foo.ts"),
+        BiomePath::new("/src/reexport.ts"),
+    ];
+    let added_paths = get_added_paths(&fs, &added_paths);
+
+    let module_graph = ModuleGraph::default();
+    module_graph.update_graph_for_js_paths(&fs, &ProjectLayout::default(), &added_paths, &[]);
+
+    let foo_path = Utf8Path::new("/src/foo.ts");
+    let foo_module = module_graph
+        .module_info_for_path(foo_path)
+        .expect("module must exist");
+
+    // Offset of `foo` in its declaration.
+    let imports = module_graph.find_symbol_imports(foo_path, &foo_module, TextSize::from(13));
+    let import = |path: &str, specifier: &'static str, is_reexport: bool| SymbolImport {
+        path: Utf8PathBuf::from(path),
+        specifier: specifier.into(),
+        imported_name: "foo".into(),
+        is_reexport,
+    };
+    assert_eq!(
+        imports,
+        vec![
+            import("/src/a.ts", "./foo.ts", false),
+            import("/src/b.ts", "./reexport.ts", false),
+            import("/src/c.ts", "./b.ts", false),
+            import("/src/reexport.ts", "./foo.ts", true),
+        ]
+    );
+}
+
+#[test]
+fn test_transitive_dependencies() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert("/src/foo.ts".into(), "export const foo = 1;\n");
+    fs.insert("/src/reexport.ts".into(), "export * from \"./foo.ts\";\n");
+    fs.insert(
+        "/src/index.ts".into(),
+        "import { foo } from \"./reexport.ts\";\nimport \"./missing.ts\";\n",
+    );
+    fs.insert(
+        "/src/cycle.ts".into(),
+        "import \"./index.ts\";\nexport * from \"./cycle.ts\";\n",
+    );
+
+    let added_paths = [
+        BiomePath::new("/src/cycle.ts"),
+        BiomePath::new("/src/foo.ts"),
+        BiomePath::new("/src/index.ts"),
+        BiomePath::new("/src/reexport.ts"),
+    ];
+    let added_paths = get_added_paths(&fs, &added_paths);
+
+    let module_graph = ModuleGraph::default();
+    module_graph.update_graph_for_js_paths(&fs, &ProjectLayout::default(), &added_paths, &[]);
+
+    assert_eq!(
+        module_graph.transitive_dependencies(Utf8Path::new("/src/index.ts")),
+        [
+            Utf8PathBuf::from("/src/foo.ts"),
+            Utf8PathBuf::from("/src/reexport.ts")
+        ]
+        .into()
+    );
+    assert_eq!(
+        module_graph.transitive_dependencies(Utf8Path::new("/src/cycle.ts")),
+        [
+            Utf8PathBuf::from("/src/cycle.ts"),
+            Utf8PathBuf::from("/src/foo.ts"),
+            Utf8PathBuf::from("/src/index.ts"),
+            Utf8PathBuf::from("/src/reexport.ts")
+        ]
+        .into()
+    );
+    assert!(
+        module_graph
+            .transitive_dependencies(Utf8Path::new("/src/foo.ts"))
+            .is_empty()
+    );
+}
@@ -42,8 +42,12 @@ impl AnalyzerGritPlugin {
 }
 
 impl AnalyzerPlugin for AnalyzerGritPlugin {
+    fn name(&self) -> &str {
+        self.grit_query.name.as_deref().unwrap_or("anonymous")
+    }
+
     fn evaluate(&self, root: AnyParse, path: Arc<Utf8PathBuf>) -> Vec<RuleDiagnostic> {
-        let name: &str = self.grit_query.name.as_deref().unwrap_or("anonymous");
+        let name = self.name();
 
         let file = GritTargetFile { parse: root, path };
         match self.grit_query.execute(file) {
@@ -2,7 +2,8 @@ use crate::WorkspaceError;
 use crate::settings::Settings;
 use crate::workspace::ScanKind;
 use biome_analyze::{
-    AnalyzerRules, Queryable, RegistryVisitor, Rule, RuleDomain, RuleFilter, RuleGroup,
+    AnalyzerRules, GroupCategory, Queryable, RegistryVisitor, Rule, RuleCategory, RuleDomain,
+    RuleFilter, RuleGroup,
 };
 use biome_configuration::analyzer::{RuleDomainValue, RuleSelector};
 use biome_configuration::diagnostics::{
@@ -30,6 +31,8 @@ use biome_json_parser::{JsonParserOptions, parse_json};
 use biome_json_syntax::JsonLanguage;
 use biome_resolver::{FsWithResolverProxy, ResolveOptions, resolve};
 use biome_rowan::Language;
+use biome_yaml_analyze::METADATA as yaml_lint_metadata;
+use biome_yaml_syntax::YamlLanguage;
 use camino::{Utf8Path, Utf8PathBuf};
 use rustc_hash::FxHashSet;
 use std::fmt::Debug;
@@ -481,12 +484,14 @@ pub fn to_analyzer_rules(settings: &Settings, path: &Utf8Path) -> AnalyzerRules
         push_to_analyzer_rules(rules, css_lint_metadata.deref(), &mut analyzer_rules);
         push_to_analyzer_rules(rules, json_lint_metadata.deref(), &mut analyzer_rules);
         push_to_analyzer_rules(rules, graphql_lint_metadata.deref(), &mut analyzer_rules);
+        push_to_analyzer_rules(rules, yaml_lint_metadata.deref(), &mut analyzer_rules);
     }
     if let Some(rules) = settings.assist.actions.as_ref() {
         push_to_analyzer_assist(rules, js_lint_metadata.deref(), &mut analyzer_rules);
         push_to_analyzer_assist(rules, css_lint_metadata.deref(), &mut analyzer_rules);
         push_to_analyzer_assist(rules, json_lint_metadata.deref(), &mut analyzer_rules);
         push_to_analyzer_assist(rules, graphql_lint_metadata.deref(), &mut analyzer_rules);
+        push_to_analyzer_assist(rules, yaml_lint_metadata.deref(), &mut analyzer_rules);
     }
     let overrides = &settings.override_settings;
     overrides.override_analyzer_rules(path, analyzer_rules)
@@ -506,7 +511,7 @@ pub trait ConfigurationExt {
         fs: &dyn FsWithResolverProxy,
         relative_resolution_base_path: &Utf8Path,
         external_resolution_base_path: &Utf8Path,
-    ) -> Result<Vec<Deserialized<Configuration>>, WorkspaceError>;
+    ) -> Result<Vec<(Utf8PathBuf, Deserialized<Configuration>)>, WorkspaceError>;
 
     fn migrate_deprecated_fields(&mut self);
 }
@@ -530,8 +535,10 @@ impl ConfigurationExt for Configuration {
             file_path.parent().expect("file path should have a parent"),
             external_resolution_base_path,
         )?;
-        let (configurations, errors): (Vec<_>, Vec<_>) =
-            deserialized.into_iter().map(Deserialized::consume).unzip();
+        let (configurations, errors): (Vec<_>, Vec<_>) = deserialized
+            .into_iter()
+            .map(|(_, deserialized)| deserialized.consume())
+            .unzip();
 
         let extended_configuration = configurations.into_iter().flatten().reduce(
             |mut previous_configuration, current_configuration| {
@@ -561,13 +568,13 @@ impl ConfigurationExt for Configuration {
     }
 
     /// Deserializes all the configuration files that were specified in the
-    /// `extends` field.
+    /// `extends` field, along with their paths.
     fn deserialize_extends(
         &mut self,
         fs: &dyn FsWithResolverProxy,
         relative_resolution_base_path: &Utf8Path,
         external_resolution_base_path: &Utf8Path,
-    ) -> Result<Vec<Deserialized<Configuration>>, WorkspaceError> {
+    ) -> Result<Vec<(Utf8PathBuf, Deserialized<Configuration>)>, WorkspaceError> {
         let Some(extends) = &self.extends else {
             return Ok(Vec::new());
         };
@@ -643,7 +650,7 @@ impl ConfigurationExt for Configuration {
                     },
                     "",
                 );
-                deserialized_configurations.push(deserialized)
+                deserialized_configurations.push((extend_configuration_file_path, deserialized))
             }
         }
         Ok(deserialized_configurations)
@@ -769,6 +776,7 @@ impl<'a> ProjectScanComputer<'a> {
         }
 
         biome_graphql_analyze::visit_registry(&mut self);
+        biome_yaml_analyze::visit_registry(&mut self);
         biome_css_analyze::visit_registry(&mut self);
         biome_json_analyze::visit_registry(&mut self);
         biome_js_analyze::visit_registry(&mut self);
@@ -843,6 +851,132 @@ impl RegistryVisitor<GraphqlLanguage> for ProjectScanComputer<'_> {
     }
 }
 
+impl RegistryVisitor<YamlLanguage> for ProjectScanComputer<'_> {
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = YamlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.check_rule::<R, YamlLanguage>();
+    }
+}
+
+/// Collects the selectors of all the lint rules that don't belong to the
+/// [RuleDomain::Project] domain.
+///
+/// They can be passed as `skip` to run only the rules that need the project
+/// scan, while still respecting the configuration.
+#[derive(Debug, Default)]
+pub struct NonProjectRulesCollector {
+    selectors: Vec<RuleSelector>,
+}
+
+impl NonProjectRulesCollector {
+    /// Returns the selectors of the lint rules that aren't part of the
+    /// [RuleDomain::Project] domain
+    pub fn collect() -> Vec<RuleSelector> {
+        let mut collector = Self::default();
+        biome_graphql_analyze::visit_registry(&mut collector);
+        biome_yaml_analyze::visit_registry(&mut collector);
+        biome_css_analyze::visit_registry(&mut collector);
+        biome_json_analyze::visit_registry(&mut collector);
+        biome_js_analyze::visit_registry(&mut collector);
+        collector.selectors
+    }
+
+    fn check_rule<R, L>(&mut self)
+    where
+        L: Language,
+        R: Rule<Options: Default, Query: Queryable<Language = L, Output: Clone>> + 'static,
+    {
+        if !R::METADATA.domains.contains(&RuleDomain::Project) {
+            self.selectors.push(RuleSelector::Rule(
+                <R::Group as RuleGroup>::NAME,
+                R::METADATA.name,
+            ));
+        }
+    }
+}
+
+impl RegistryVisitor<JsLanguage> for NonProjectRulesCollector {
+    fn record_category<C: GroupCategory<Language = JsLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Lint {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = JsLanguage, Output: Clone>> + 'static,
+    {
+        self.check_rule::<R, JsLanguage>();
+    }
+}
+
+impl RegistryVisitor<JsonLanguage> for NonProjectRulesCollector {
+    fn record_category<C: GroupCategory<Language = JsonLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Lint {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = JsonLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.check_rule::<R, JsonLanguage>();
+    }
+}
+
+impl RegistryVisitor<CssLanguage> for NonProjectRulesCollector {
+    fn record_category<C: GroupCategory<Language = CssLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Lint {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = CssLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.check_rule::<R, CssLanguage>();
+    }
+}
+
+impl RegistryVisitor<GraphqlLanguage> for NonProjectRulesCollector {
+    fn record_category<C: GroupCategory<Language = GraphqlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Lint {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = GraphqlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.check_rule::<R, GraphqlLanguage>();
+    }
+}
+
+impl RegistryVisitor<YamlLanguage> for NonProjectRulesCollector {
+    fn record_category<C: GroupCategory<Language = YamlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Lint {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = YamlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.check_rule::<R, YamlLanguage>();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -9,6 +9,7 @@ use biome_graphql_syntax::GraphqlLanguage;
 use biome_js_syntax::JsLanguage;
 use biome_json_syntax::JsonLanguage;
 use biome_rowan::Language;
+use biome_yaml_syntax::YamlLanguage;
 use std::{collections::BTreeMap, str::FromStr};
 
 #[derive(Debug, Clone)]
@@ -42,6 +43,28 @@ pub struct ExplainRule {
     category: &'static str,
 }
 
+impl ExplainRule {
+    /// Returns the metadata of the rule, such as its name and documentation.
+    pub fn metadata(&self) -> &RuleMetadata {
+        &self.metadata
+    }
+
+    /// Returns the name of the group of the rule.
+    pub fn group(&self) -> &'static str {
+        self.group
+    }
+
+    /// Returns the category of the rule: `lint` or `assist`.
+    pub fn category(&self) -> &'static str {
+        self.category
+    }
+}
+
+/// Returns the metadata of the lint rules and assist actions, by name.
+pub fn rules_metadata() -> BTreeMap<&'static str, ExplainRule> {
+    RulesVisitor::new().rules_metadata
+}
+
 struct RulesVisitor {
     rules_metadata: BTreeMap<&'static str, ExplainRule>,
 }
@@ -53,6 +76,7 @@ impl RulesVisitor {
         };
 
         biome_graphql_analyze::visit_registry(&mut visitor);
+        biome_yaml_analyze::visit_registry(&mut visitor);
         biome_css_analyze::visit_registry(&mut visitor);
         biome_json_analyze::visit_registry(&mut visitor);
         biome_js_analyze::visit_registry(&mut visitor);
@@ -121,6 +145,16 @@ impl RegistryVisitor<GraphqlLanguage> for RulesVisitor {
     }
 }
 
+impl RegistryVisitor<YamlLanguage> for RulesVisitor {
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = YamlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.store_rule::<R, YamlLanguage>();
+    }
+}
+
 impl biome_console::fmt::Display for ExplainRule {
     fn fmt(&self, fmt: &mut Formatter) -> std::io::Result<()> {
         let metadata = &self.metadata;
@@ -1,8 +1,8 @@
 use crate::WorkspaceError;
 use crate::file_handlers::{
-    AnalyzerCapabilities, Capabilities, CodeActionsParams, DebugCapabilities, EnabledForPath,
-    ExtensionHandler, FixAllParams, FormatterCapabilities, LintParams, LintResults, ParseResult,
-    ParserCapabilities, javascript,
+    AnalyzerCapabilities, Capabilities, CodeActionsParams, DebugCapabilities, EditorCapabilities,
+    EnabledForPath, ExtensionHandler, FixAllParams, FormatterCapabilities, LintParams, LintResults,
+    ParseResult, ParserCapabilities, javascript,
 };
 use crate::settings::WorkspaceSettingsHandle;
 use crate::workspace::{DocumentFileSource, FixFileResult, PullActionsResult};
@@ -88,6 +88,7 @@ impl ExtensionHandler for AstroFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                prepare_rename: None,
                 fix_all: Some(fix_all),
             },
             formatter: FormatterCapabilities {
@@ -97,6 +98,18 @@ impl ExtensionHandler for AstroFileHandler {
             },
             // TODO: We should be able to search JS portions already
             search: SearchCapabilities { search: None },
+            editor: EditorCapabilities {
+                hover: None,
+                goto_definition: None,
+                find_references: None,
+                document_symbols: None,
+                semantic_tokens: None,
+                folding_ranges: None,
+                selection_range: None,
+                inlay_hints: None,
+                completions: None,
+                linked_editing_ranges: None,
+            },
         }
     }
 }
@@ -1,7 +1,7 @@
 use super::{
-    AnalyzerVisitorBuilder, CodeActionsParams, EnabledForPath, ExtensionHandler, FixAllParams,
-    LintParams, LintResults, ParseResult, ProcessLint, SearchCapabilities, is_diagnostic_error,
-    search,
+    AnalyzerVisitorBuilder, CodeActionsParams, EditorCapabilities, EditorParams, EnabledForPath,
+    ExtensionHandler, FixAllParams, LintParams, LintResults, ParseResult, ProcessLint,
+    SearchCapabilities, is_diagnostic_error, search, syntax_folding_ranges, syntax_selection_range,
 };
 use crate::WorkspaceError;
 use crate::configuration::to_analyzer_rules;
@@ -14,8 +14,8 @@ use crate::settings::{
     Settings, WorkspaceSettingsHandle, check_feature_activity, check_override_feature_activity,
 };
 use crate::workspace::{
-    CodeAction, DocumentFileSource, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult,
-    PullActionsResult,
+    CodeAction, DocumentFileSource, DocumentSymbol, FixAction, FixFileMode, FixFileResult,
+    FoldingRange, GetSyntaxTreeResult, PullActionsResult, SymbolKind,
 };
 use biome_analyze::options::PreferredQuote;
 use biome_analyze::{
@@ -30,7 +30,9 @@ use biome_css_analyze::analyze;
 use biome_css_formatter::context::CssFormatOptions;
 use biome_css_formatter::format_node;
 use biome_css_parser::CssParserOptions;
-use biome_css_syntax::{CssLanguage, CssRoot, CssSyntaxNode};
+use biome_css_semantic::model::{Rule, RuleNode, SemanticModel};
+use biome_css_semantic::semantic_model;
+use biome_css_syntax::{CssLanguage, CssRoot, CssSyntaxKind, CssSyntaxNode};
 use biome_diagnostics::Applicability;
 use biome_formatter::{
     FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed, QuoteStyle,
@@ -342,6 +344,7 @@ impl ExtensionHandler for CssFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                prepare_rename: None,
                 fix_all: Some(fix_all),
             },
             formatter: FormatterCapabilities {
@@ -352,6 +355,18 @@ impl ExtensionHandler for CssFileHandler {
             search: SearchCapabilities {
                 search: Some(search),
             },
+            editor: EditorCapabilities {
+                hover: None,
+                goto_definition: None,
+                find_references: None,
+                document_symbols: Some(document_symbols),
+                semantic_tokens: None,
+                folding_ranges: Some(folding_ranges),
+                selection_range: Some(syntax_selection_range::<CssLanguage>),
+                inlay_hints: None,
+                completions: None,
+                linked_editing_ranges: None,
+            },
             enabled_for_path: EnabledForPath {
                 formatter: Some(formatter_enabled),
                 linter: Some(linter_enabled),
@@ -526,7 +541,7 @@ fn lint(params: LintParams) -> LintResults {
         categories: params.categories,
         enabled_rules: Some(enabled_rules.as_slice()),
         disabled_rules: &disabled_rules,
-        range: None,
+        range: params.range,
     };
 
     let mut process_lint = ProcessLint::new(&params);
@@ -746,6 +761,76 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
     }
 }
 
+fn document_symbols(params: EditorParams) -> Vec<DocumentSymbol> {
+    let root: CssRoot = params.parse.tree();
+    let model = semantic_model(&root);
+    model
+        .rules()
+        .iter()
+        .filter_map(|rule| rule_symbol(&model, rule))
+        .collect()
+}
+
+/// Returns the symbol for a rule, whose children are the nested rules.
+fn rule_symbol(model: &SemanticModel, rule: &Rule) -> Option<DocumentSymbol> {
+    let (name, range, selection_range) = match rule.node() {
+        RuleNode::CssQualifiedRule(_) | RuleNode::CssNestedQualifiedRule(_) => {
+            let selectors = rule.selectors();
+            let name = selectors
+                .iter()
+                .map(|selector| selector.text().to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let selection_range = selectors.first()?.range().cover(selectors.last()?.range());
+            (name, rule.range(), selection_range)
+        }
+        RuleNode::CssMediaAtRule(node) => {
+            let selection_range = node
+                .media_token()
+                .ok()?
+                .text_trimmed_range()
+                .cover(node.queries().range());
+            let name = format!("@media {}", node.queries().syntax().text_trimmed());
+            (name, at_rule_range(node.syntax()), selection_range)
+        }
+        RuleNode::CssSupportsAtRule(node) => {
+            let condition = node.condition().ok()?;
+            let selection_range = node
+                .supports_token()
+                .ok()?
+                .text_trimmed_range()
+                .cover(condition.range());
+            let name = format!("@supports {}", condition.syntax().text_trimmed());
+            (name, at_rule_range(node.syntax()), selection_range)
+        }
+    };
+
+    Some(DocumentSymbol {
+        name,
+        kind: SymbolKind::Class,
+        range,
+        selection_range,
+        children: rule
+            .child_ids()
+            .iter()
+            .filter_map(|id| model.get_rule_by_id(*id))
+            .filter_map(|child| rule_symbol(model, child))
+            .collect(),
+    })
+}
+
+/// Returns the range of an at-rule, including its `@` token.
+fn at_rule_range(node: &CssSyntaxNode) -> TextRange {
+    node.parent()
+        .filter(|parent| parent.kind() == CssSyntaxKind::CSS_AT_RULE)
+        .unwrap_or_else(|| node.clone())
+        .text_trimmed_range()
+}
+
+fn folding_ranges(params: EditorParams) -> Vec<FoldingRange> {
+    syntax_folding_ranges(&params.parse.syntax::<CssLanguage>(), |_| false)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
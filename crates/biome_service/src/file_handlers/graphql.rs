@@ -1,7 +1,8 @@
 use super::{
-    AnalyzerVisitorBuilder, CodeActionsParams, DocumentFileSource, EnabledForPath,
-    ExtensionHandler, FixAllParams, LintParams, LintResults, ParseResult, ProcessLint,
-    SearchCapabilities, is_diagnostic_error,
+    AnalyzerVisitorBuilder, CodeActionsParams, DocumentFileSource, EditorCapabilities,
+    EditorParams, EnabledForPath, ExtensionHandler, FixAllParams, LintParams, LintResults,
+    ParseResult, ProcessLint, SearchCapabilities, is_diagnostic_error, nest_document_symbols,
+    syntax_folding_ranges, syntax_selection_range,
 };
 use crate::WorkspaceError;
 use crate::file_handlers::DebugCapabilities;
@@ -13,7 +14,9 @@ use crate::settings::{
     Settings, WorkspaceSettingsHandle, check_feature_activity, check_override_feature_activity,
 };
 use crate::workspace::{
-    CodeAction, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult, PullActionsResult,
+    CodeAction, DocumentSymbol, FixAction, FixFileMode, FixFileResult, FoldingRange,
+    GetSyntaxTreeResult, PullActionsResult, SemanticToken, SemanticTokenKind,
+    SemanticTokenModifier, SymbolKind,
 };
 use biome_analyze::{AnalysisFilter, AnalyzerOptions, ControlFlow, Never, RuleError};
 use biome_configuration::graphql::{
@@ -30,7 +33,11 @@ use biome_graphql_analyze::analyze;
 use biome_graphql_formatter::context::GraphqlFormatOptions;
 use biome_graphql_formatter::format_node;
 use biome_graphql_parser::parse_graphql_with_cache;
-use biome_graphql_syntax::{GraphqlLanguage, GraphqlRoot, GraphqlSyntaxNode, TextRange, TextSize};
+use biome_graphql_semantic::semantic_model;
+use biome_graphql_syntax::{
+    GraphqlEnumValueDefinition, GraphqlFieldDefinition, GraphqlInputValueDefinition,
+    GraphqlLanguage, GraphqlRoot, GraphqlSyntaxKind, GraphqlSyntaxNode, TextRange, TextSize,
+};
 use biome_parser::AnyParse;
 use biome_rowan::{AstNode, NodeCache, TokenAtOffset};
 use camino::Utf8Path;
@@ -281,6 +288,7 @@ impl ExtensionHandler for GraphqlFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                prepare_rename: None,
                 fix_all: Some(fix_all),
             },
             formatter: FormatterCapabilities {
@@ -289,6 +297,18 @@ impl ExtensionHandler for GraphqlFileHandler {
                 format_on_type: Some(format_on_type),
             },
             search: SearchCapabilities { search: None },
+            editor: EditorCapabilities {
+                hover: None,
+                goto_definition: None,
+                find_references: None,
+                document_symbols: Some(document_symbols),
+                semantic_tokens: Some(semantic_tokens),
+                folding_ranges: Some(folding_ranges),
+                selection_range: Some(syntax_selection_range::<GraphqlLanguage>),
+                inlay_hints: None,
+                completions: None,
+                linked_editing_ranges: None,
+            },
         }
     }
 }
@@ -441,7 +461,7 @@ fn lint(params: LintParams) -> LintResults {
         categories: params.categories,
         enabled_rules: Some(enabled_rules.as_slice()),
         disabled_rules: &disabled_rules,
-        range: None,
+        range: params.range,
     };
 
     let mut process_lint = ProcessLint::new(&params);
@@ -653,3 +673,168 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
         }
     }
 }
+
+fn document_symbols(params: EditorParams) -> Vec<DocumentSymbol> {
+    let root: GraphqlRoot = params.parse.tree();
+    let model = semantic_model(&root);
+    let mut symbols = Vec::new();
+    for binding in model.all_bindings() {
+        let name = binding.tree();
+        let Some(definition) = name.syntax().parent() else {
+            continue;
+        };
+        let kind = match definition.kind() {
+            GraphqlSyntaxKind::GRAPHQL_OBJECT_TYPE_DEFINITION => SymbolKind::Class,
+            GraphqlSyntaxKind::GRAPHQL_INTERFACE_TYPE_DEFINITION => SymbolKind::Interface,
+            GraphqlSyntaxKind::GRAPHQL_ENUM_TYPE_DEFINITION => SymbolKind::Enum,
+            GraphqlSyntaxKind::GRAPHQL_INPUT_OBJECT_TYPE_DEFINITION => SymbolKind::Struct,
+            GraphqlSyntaxKind::GRAPHQL_SCALAR_TYPE_DEFINITION
+            | GraphqlSyntaxKind::GRAPHQL_UNION_TYPE_DEFINITION => SymbolKind::TypeParameter,
+            GraphqlSyntaxKind::GRAPHQL_DIRECTIVE_DEFINITION
+            | GraphqlSyntaxKind::GRAPHQL_OPERATION_DEFINITION => SymbolKind::Function,
+            GraphqlSyntaxKind::GRAPHQL_FRAGMENT_DEFINITION => SymbolKind::Object,
+            _ => continue,
+        };
+
+        symbols.push(DocumentSymbol {
+            name: name.syntax().text_trimmed().to_string(),
+            kind,
+            range: definition.text_trimmed_range(),
+            selection_range: name.range(),
+            children: Vec::new(),
+        });
+        symbols.extend(
+            definition
+                .descendants()
+                .filter_map(|node| member_symbol(&node)),
+        );
+    }
+
+    nest_document_symbols(symbols)
+}
+
+/// Returns the symbol for a field, an input field, or an enum value.
+fn member_symbol(node: &GraphqlSyntaxNode) -> Option<DocumentSymbol> {
+    let (name, kind) = match node.kind() {
+        GraphqlSyntaxKind::GRAPHQL_FIELD_DEFINITION => (
+            GraphqlFieldDefinition::cast_ref(node)?.name().ok()?,
+            SymbolKind::Field,
+        ),
+        GraphqlSyntaxKind::GRAPHQL_INPUT_VALUE_DEFINITION
+            if node.parent().is_some_and(|parent| {
+                parent.kind() == GraphqlSyntaxKind::GRAPHQL_INPUT_FIELD_LIST
+            }) =>
+        {
+            (
+                GraphqlInputValueDefinition::cast_ref(node)?.name().ok()?,
+                SymbolKind::Field,
+            )
+        }
+        GraphqlSyntaxKind::GRAPHQL_ENUM_VALUE_DEFINITION => (
+            GraphqlEnumValueDefinition::cast_ref(node)?.value().ok()?,
+            SymbolKind::EnumMember,
+        ),
+        _ => return None,
+    };
+
+    Some(DocumentSymbol {
+        name: name.syntax().text_trimmed().to_string(),
+        kind,
+        range: node.text_trimmed_range(),
+        selection_range: name.range(),
+        children: Vec::new(),
+    })
+}
+
+fn semantic_tokens(params: EditorParams, range: Option<TextRange>) -> Vec<SemanticToken> {
+    let root: GraphqlRoot = params.parse.tree();
+    let model = semantic_model(&root);
+    let in_range =
+        |token_range: TextRange| range.is_none_or(|range| range.intersect(token_range).is_some());
+
+    let mut tokens = Vec::new();
+    for binding in model.all_bindings() {
+        let Some(kind) = binding
+            .syntax()
+            .parent()
+            .and_then(|definition| definition_token_kind(definition.kind()))
+        else {
+            continue;
+        };
+
+        let binding_range = binding.syntax().text_trimmed_range();
+        if in_range(binding_range) {
+            tokens.push(SemanticToken {
+                range: binding_range,
+                kind,
+                modifiers: vec![SemanticTokenModifier::Declaration],
+            });
+        }
+
+        for reference in binding.all_references() {
+            if in_range(*reference.range()) {
+                tokens.push(SemanticToken {
+                    range: *reference.range(),
+                    kind,
+                    modifiers: Vec::new(),
+                });
+            }
+        }
+    }
+
+    for reference in model.all_unresolved_references() {
+        if !in_range(*reference.range()) {
+            continue;
+        }
+
+        let name = reference.tree();
+        let kind = match name.syntax().parent().map(|parent| parent.kind()) {
+            Some(GraphqlSyntaxKind::GRAPHQL_FRAGMENT_SPREAD) => SemanticTokenKind::Fragment,
+            Some(GraphqlSyntaxKind::GRAPHQL_DIRECTIVE) => SemanticTokenKind::Directive,
+            _ => SemanticTokenKind::Type,
+        };
+        let is_built_in = name.value_token().is_ok_and(|token| match kind {
+            SemanticTokenKind::Directive => BUILT_IN_DIRECTIVES.contains(&token.text_trimmed()),
+            SemanticTokenKind::Type => BUILT_IN_SCALARS.contains(&token.text_trimmed()),
+            _ => false,
+        });
+        tokens.push(SemanticToken {
+            range: *reference.range(),
+            kind,
+            modifiers: vec![if is_built_in {
+                SemanticTokenModifier::Global
+            } else {
+                SemanticTokenModifier::Unresolved
+            }],
+        });
+    }
+
+    tokens.sort_by_key(|token| token.range.start());
+    tokens
+}
+
+/// Scalars that every GraphQL schema provides.
+const BUILT_IN_SCALARS: &[&str] = &["Boolean", "Float", "ID", "Int", "String"];
+
+/// Directives that every GraphQL schema provides.
+const BUILT_IN_DIRECTIVES: &[&str] = &["deprecated", "include", "oneOf", "skip", "specifiedBy"];
+
+/// Returns the kind of the token that names a definition of the given kind.
+fn definition_token_kind(kind: GraphqlSyntaxKind) -> Option<SemanticTokenKind> {
+    match kind {
+        GraphqlSyntaxKind::GRAPHQL_OBJECT_TYPE_DEFINITION
+        | GraphqlSyntaxKind::GRAPHQL_INPUT_OBJECT_TYPE_DEFINITION
+        | GraphqlSyntaxKind::GRAPHQL_SCALAR_TYPE_DEFINITION
+        | GraphqlSyntaxKind::GRAPHQL_UNION_TYPE_DEFINITION => Some(SemanticTokenKind::Type),
+        GraphqlSyntaxKind::GRAPHQL_INTERFACE_TYPE_DEFINITION => Some(SemanticTokenKind::Interface),
+        GraphqlSyntaxKind::GRAPHQL_ENUM_TYPE_DEFINITION => Some(SemanticTokenKind::Enum),
+        GraphqlSyntaxKind::GRAPHQL_DIRECTIVE_DEFINITION => Some(SemanticTokenKind::Directive),
+        GraphqlSyntaxKind::GRAPHQL_FRAGMENT_DEFINITION => Some(SemanticTokenKind::Fragment),
+        GraphqlSyntaxKind::GRAPHQL_OPERATION_DEFINITION => Some(SemanticTokenKind::Function),
+        _ => None,
+    }
+}
+
+fn folding_ranges(params: EditorParams) -> Vec<FoldingRange> {
+    syntax_folding_ranges(&params.parse.syntax::<GraphqlLanguage>(), |_| false)
+}
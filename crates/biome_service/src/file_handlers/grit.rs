@@ -1,10 +1,11 @@
 use super::{
-    AnalyzerCapabilities, Capabilities, DebugCapabilities, DocumentFileSource, EnabledForPath,
-    ExtensionHandler, FixAllParams, FormatterCapabilities, LintParams, LintResults, ParseResult,
-    ParserCapabilities, SearchCapabilities,
+    AnalyzerCapabilities, Capabilities, DebugCapabilities, DocumentFileSource, EditorCapabilities,
+    EditorParams, EnabledForPath, ExtensionHandler, FixAllParams, FormatterCapabilities,
+    LintParams, LintResults, ParseResult, ParserCapabilities, SearchCapabilities,
+    syntax_folding_ranges, syntax_selection_range,
 };
 use crate::settings::{check_feature_activity, check_override_feature_activity};
-use crate::workspace::{FixFileResult, GetSyntaxTreeResult};
+use crate::workspace::{FixFileResult, FoldingRange, GetSyntaxTreeResult};
 use crate::{
     WorkspaceError,
     settings::{ServiceLanguage, Settings, WorkspaceSettingsHandle},
@@ -255,6 +256,7 @@ impl ExtensionHandler for GritFileHandler {
                 lint: Some(lint),
                 code_actions: None,
                 rename: None,
+                prepare_rename: None,
                 fix_all: Some(fix_all),
             },
             formatter: FormatterCapabilities {
@@ -263,6 +265,18 @@ impl ExtensionHandler for GritFileHandler {
                 format_on_type: Some(format_on_type),
             },
             search: SearchCapabilities { search: None },
+            editor: EditorCapabilities {
+                hover: None,
+                goto_definition: None,
+                find_references: None,
+                document_symbols: None,
+                semantic_tokens: None,
+                folding_ranges: Some(folding_ranges),
+                selection_range: Some(syntax_selection_range::<GritLanguage>),
+                inlay_hints: None,
+                completions: None,
+                linked_editing_ranges: None,
+            },
         }
     }
 }
@@ -436,3 +450,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
         errors: 0,
     })
 }
+
+fn folding_ranges(params: EditorParams) -> Vec<FoldingRange> {
+    syntax_folding_ranges(&params.parse.syntax::<GritLanguage>(), |_| false)
+}
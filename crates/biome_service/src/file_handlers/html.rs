@@ -1,10 +1,11 @@
 use super::{
-    AnalyzerCapabilities, Capabilities, DebugCapabilities, DocumentFileSource, EnabledForPath,
-    ExtensionHandler, FixAllParams, FormatterCapabilities, LintParams, LintResults, ParseResult,
-    ParserCapabilities, SearchCapabilities,
+    AnalyzerCapabilities, Capabilities, DebugCapabilities, DocumentFileSource, EditorCapabilities,
+    EditorParams, EnabledForPath, ExtensionHandler, FixAllParams, FormatterCapabilities,
+    LintParams, LintResults, ParseResult, ParserCapabilities, SearchCapabilities,
+    syntax_folding_ranges, syntax_selection_range,
 };
 use crate::settings::{check_feature_activity, check_override_feature_activity};
-use crate::workspace::FixFileResult;
+use crate::workspace::{FixFileResult, FoldingRange};
 use crate::{
     WorkspaceError,
     settings::{ServiceLanguage, Settings, WorkspaceSettingsHandle},
@@ -24,9 +25,9 @@ use biome_html_formatter::{
     format_node,
 };
 use biome_html_parser::parse_html_with_cache;
-use biome_html_syntax::{HtmlLanguage, HtmlRoot, HtmlSyntaxNode};
+use biome_html_syntax::{HtmlElement, HtmlLanguage, HtmlRoot, HtmlSyntaxKind, HtmlSyntaxNode};
 use biome_parser::AnyParse;
-use biome_rowan::{AstNode, NodeCache};
+use biome_rowan::{AstNode, NodeCache, TextRange, TextSize};
 use camino::Utf8Path;
 use tracing::debug_span;
 
@@ -214,6 +215,7 @@ impl ExtensionHandler for HtmlFileHandler {
                 lint: Some(lint),
                 code_actions: None,
                 rename: None,
+                prepare_rename: None,
                 fix_all: Some(fix_all),
             },
             formatter: FormatterCapabilities {
@@ -222,6 +224,18 @@ impl ExtensionHandler for HtmlFileHandler {
                 format_on_type: None,
             },
             search: SearchCapabilities { search: None },
+            editor: EditorCapabilities {
+                hover: None,
+                goto_definition: None,
+                find_references: None,
+                document_symbols: None,
+                semantic_tokens: None,
+                folding_ranges: Some(folding_ranges),
+                selection_range: Some(syntax_selection_range::<HtmlLanguage>),
+                inlay_hints: None,
+                completions: None,
+                linked_editing_ranges: Some(linked_editing_ranges),
+            },
         }
     }
 }
@@ -342,3 +356,27 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
         errors: 0,
     })
 }
+
+fn folding_ranges(params: EditorParams) -> Vec<FoldingRange> {
+    syntax_folding_ranges(&params.parse.syntax::<HtmlLanguage>(), |kind| {
+        kind == HtmlSyntaxKind::HTML_ELEMENT
+    })
+}
+
+/// Returns the names of the opening and closing tags of the element, if the
+/// offset is inside one of them.
+fn linked_editing_ranges(params: EditorParams, offset: TextSize) -> Vec<TextRange> {
+    let root: HtmlRoot = params.parse.tree();
+    root.syntax()
+        .token_at_offset(offset)
+        .find_map(|token| {
+            let element = token.parent()?.ancestors().find_map(HtmlElement::cast)?;
+            let opening_name = element.opening_element().ok()?.name().ok()?;
+            let closing_name = element.closing_element().ok()?.name().ok()?;
+            let ranges = vec![opening_name.range(), closing_name.range()];
+            let is_linked = ranges.iter().any(|range| range.contains_inclusive(offset))
+                && opening_name.syntax().text_trimmed() == closing_name.syntax().text_trimmed();
+            is_linked.then_some(ranges)
+        })
+        .unwrap_or_default()
+}
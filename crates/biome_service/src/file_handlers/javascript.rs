@@ -1,7 +1,9 @@
 use super::{
     AnalyzerCapabilities, AnalyzerVisitorBuilder, CodeActionsParams, DebugCapabilities,
-    EnabledForPath, ExtensionHandler, FormatterCapabilities, LintParams, LintResults, ParseResult,
-    ParserCapabilities, ProcessLint, SearchCapabilities, search,
+    EditorCapabilities, EditorParams, EnabledForPath, ExtensionHandler, FormatterCapabilities,
+    LintParams, LintResults, ParseForPath, ParseResult, ParserCapabilities, ProcessLint,
+    SearchCapabilities, nest_document_symbols, search, syntax_folding_ranges,
+    syntax_selection_range,
 };
 use crate::configuration::to_analyzer_rules;
 use crate::diagnostics::extension_error;
@@ -17,8 +19,10 @@ use crate::{
         WorkspaceSettingsHandle,
     },
     workspace::{
-        CodeAction, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult, PullActionsResult,
-        RenameResult,
+        CodeAction, DocumentSymbol, FixAction, FixFileMode, FixFileResult, FoldingRange,
+        GetSyntaxTreeResult, HoverResult, InlayHint, InlayHintKind, PullActionsResult,
+        RenameFileEdits, RenameResult, SemanticToken, SemanticTokenKind, SemanticTokenModifier,
+        SymbolKind, SymbolLocation,
     },
 };
 use biome_analyze::options::PreferredQuote;
@@ -37,7 +41,10 @@ use biome_formatter::{
     IndentWidth, LineEnding, LineWidth, Printed, QuoteStyle,
 };
 use biome_fs::BiomePath;
-use biome_js_analyze::utils::rename::{RenameError, RenameSymbolExtensions};
+use biome_js_analyze::globals::{is_js_global, is_ts_global};
+use biome_js_analyze::utils::rename::{
+    AnyJsRenamableDeclaration, RenamableNode, RenameError, RenameSymbolExtensions,
+};
 use biome_js_analyze::{
     ControlFlowGraph, JsAnalyzerServices, analyze, analyze_with_inspect_matcher,
 };
@@ -45,19 +52,29 @@ use biome_js_formatter::context::trailing_commas::TrailingCommas;
 use biome_js_formatter::context::{ArrowParentheses, JsFormatOptions, QuoteProperties, Semicolons};
 use biome_js_formatter::format_node;
 use biome_js_parser::JsParserOptions;
-use biome_js_semantic::{SemanticModelOptions, semantic_model};
+use biome_js_semantic::{Binding, SemanticModel, SemanticModelOptions, semantic_model};
+use biome_js_syntax::binding_ext::{AnyJsBindingDeclaration, AnyJsIdentifierBinding};
 use biome_js_syntax::{
-    AnyJsRoot, JsClassDeclaration, JsClassExpression, JsFileSource, JsFunctionDeclaration,
-    JsLanguage, JsSyntaxNode, JsVariableDeclarator, LanguageVariant, TextRange, TextSize,
-    TokenAtOffset,
+    AnyJsArrowFunctionParameters, AnyJsCallArgument, AnyJsClass, AnyJsClassMember, AnyJsExpression,
+    AnyJsFunction, AnyJsFunctionBody, AnyJsNamedImportSpecifier, AnyJsObjectMember, AnyJsRoot,
+    JsCallExpression, JsClassDeclaration, JsClassExpression, JsExportNamedFromClause,
+    JsExportNamedFromSpecifier, JsFileSource, JsFunctionDeclaration, JsLanguage, JsReturnStatement,
+    JsShorthandNamedImportSpecifier, JsSyntaxKind, JsSyntaxNode, JsSyntaxToken,
+    JsVariableDeclarator, JsxElement, LanguageVariant, TextRange, TextSize, TokenAtOffset,
+    inner_string_text,
 };
-use biome_js_type_info::{GlobalsResolver, ScopeId, TypeData, TypeResolver};
-use biome_module_graph::ModuleGraph;
+use biome_js_type_info::{GlobalsResolver, Literal, ScopeId, Type, TypeData, TypeResolver};
+use biome_module_graph::{JsModuleInfo, ModuleGraph, ScopedResolver, SymbolImport};
 use biome_parser::AnyParse;
-use biome_rowan::{AstNode, BatchMutationExt, Direction, NodeCache, WalkEvent};
+use biome_rowan::{
+    AstNode, AstSeparatedList, BatchMutation, BatchMutationExt, Direction, NodeCache, WalkEvent,
+};
+use biome_text_edit::TextEdit;
 use camino::Utf8Path;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
 use std::fmt::Debug;
 use std::sync::Arc;
 use tracing::{debug, debug_span, error, trace_span};
@@ -494,6 +511,7 @@ impl ExtensionHandler for JsFileHandler {
                 code_actions: Some(code_actions),
                 fix_all: Some(fix_all),
                 rename: Some(rename),
+                prepare_rename: Some(prepare_rename),
             },
             formatter: FormatterCapabilities {
                 format: Some(format),
@@ -503,6 +521,18 @@ impl ExtensionHandler for JsFileHandler {
             search: SearchCapabilities {
                 search: Some(search),
             },
+            editor: EditorCapabilities {
+                hover: Some(hover),
+                goto_definition: Some(goto_definition),
+                find_references: Some(find_references),
+                document_symbols: Some(document_symbols),
+                semantic_tokens: Some(semantic_tokens),
+                folding_ranges: Some(folding_ranges),
+                selection_range: Some(syntax_selection_range::<JsLanguage>),
+                inlay_hints: Some(inlay_hints),
+                completions: None,
+                linked_editing_ranges: Some(linked_editing_ranges),
+            },
         }
     }
 }
@@ -773,7 +803,7 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
         categories: params.categories,
         enabled_rules: Some(enabled_rules.as_slice()),
         disabled_rules: &disabled_rules,
-        range: None,
+        range: params.range,
     };
 
     let mut process_lint = ProcessLint::new(&params);
@@ -1093,45 +1123,994 @@ pub(crate) fn format_on_type(
     Ok(printed)
 }
 
+fn document_symbols(params: EditorParams) -> Vec<DocumentSymbol> {
+    let root: AnyJsRoot = params.parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    let mut symbols: Vec<_> = model
+        .all_bindings()
+        .filter_map(|binding| binding_symbol(&binding.tree()))
+        .collect();
+    symbols.extend(
+        root.syntax()
+            .descendants()
+            .filter_map(AnyJsClassMember::cast)
+            .filter_map(|member| class_member_symbol(&member)),
+    );
+
+    nest_document_symbols(symbols)
+}
+
+/// Returns the symbol for a binding, unless it's a parameter, an import, or
+/// another kind of binding that doesn't declare a symbol of its own.
+fn binding_symbol(binding: &AnyJsIdentifierBinding) -> Option<DocumentSymbol> {
+    let name = binding.name_token().ok()?;
+    let declaration = binding.declaration()?;
+    let (kind, range) = match &declaration {
+        AnyJsBindingDeclaration::JsVariableDeclarator(declarator) => {
+            (variable_kind(declarator), declarator.range())
+        }
+        AnyJsBindingDeclaration::JsArrayBindingPatternElement(_)
+        | AnyJsBindingDeclaration::JsArrayBindingPatternRestElement(_)
+        | AnyJsBindingDeclaration::JsObjectBindingPatternProperty(_)
+        | AnyJsBindingDeclaration::JsObjectBindingPatternRest(_)
+        | AnyJsBindingDeclaration::JsObjectBindingPatternShorthandProperty(_) => {
+            match declaration.parent_binding_pattern_declaration()? {
+                AnyJsBindingDeclaration::JsVariableDeclarator(declarator) => {
+                    (variable_kind(&declarator), binding.range())
+                }
+                _ => return None,
+            }
+        }
+        AnyJsBindingDeclaration::JsFunctionDeclaration(_)
+        | AnyJsBindingDeclaration::JsFunctionExportDefaultDeclaration(_)
+        | AnyJsBindingDeclaration::TsDeclareFunctionDeclaration(_)
+        | AnyJsBindingDeclaration::TsDeclareFunctionExportDefaultDeclaration(_) => {
+            (SymbolKind::Function, declaration.range())
+        }
+        AnyJsBindingDeclaration::JsClassDeclaration(_)
+        | AnyJsBindingDeclaration::JsClassExportDefaultDeclaration(_) => {
+            (SymbolKind::Class, declaration.range())
+        }
+        AnyJsBindingDeclaration::TsInterfaceDeclaration(_) => {
+            (SymbolKind::Interface, declaration.range())
+        }
+        AnyJsBindingDeclaration::TsTypeAliasDeclaration(_) => {
+            (SymbolKind::TypeParameter, declaration.range())
+        }
+        AnyJsBindingDeclaration::TsEnumDeclaration(_) => (SymbolKind::Enum, declaration.range()),
+        AnyJsBindingDeclaration::TsEnumMember(_) => (SymbolKind::EnumMember, declaration.range()),
+        AnyJsBindingDeclaration::TsModuleDeclaration(module) => {
+            let kind = if module.is_namespace().unwrap_or_default() {
+                SymbolKind::Namespace
+            } else {
+                SymbolKind::Module
+            };
+            (kind, declaration.range())
+        }
+        AnyJsBindingDeclaration::TsExternalModuleDeclaration(_) => {
+            (SymbolKind::Module, declaration.range())
+        }
+        _ => return None,
+    };
+
+    Some(DocumentSymbol {
+        name: name.text_trimmed().to_string(),
+        kind,
+        range,
+        selection_range: name.text_trimmed_range(),
+        children: Vec::new(),
+    })
+}
+
+fn variable_kind(declarator: &JsVariableDeclarator) -> SymbolKind {
+    if declarator
+        .declaration()
+        .is_some_and(|declaration| declaration.is_const())
+    {
+        SymbolKind::Constant
+    } else {
+        SymbolKind::Variable
+    }
+}
+
+fn class_member_symbol(member: &AnyJsClassMember) -> Option<DocumentSymbol> {
+    let kind = match member {
+        AnyJsClassMember::JsConstructorClassMember(_) => SymbolKind::Constructor,
+        AnyJsClassMember::JsMethodClassMember(_)
+        | AnyJsClassMember::TsMethodSignatureClassMember(_) => SymbolKind::Method,
+        AnyJsClassMember::JsGetterClassMember(_)
+        | AnyJsClassMember::JsSetterClassMember(_)
+        | AnyJsClassMember::JsPropertyClassMember(_)
+        | AnyJsClassMember::TsGetterSignatureClassMember(_)
+        | AnyJsClassMember::TsSetterSignatureClassMember(_)
+        | AnyJsClassMember::TsPropertySignatureClassMember(_) => SymbolKind::Property,
+        _ => return None,
+    };
+    let name = member.name().ok()??;
+
+    Some(DocumentSymbol {
+        name: name.syntax().text_trimmed().to_string(),
+        kind,
+        range: member.range(),
+        selection_range: name.range(),
+        children: Vec::new(),
+    })
+}
+
+fn semantic_tokens(params: EditorParams, range: Option<TextRange>) -> Vec<SemanticToken> {
+    let root: AnyJsRoot = params.parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    let configured_globals = params
+        .settings
+        .settings()
+        .map(|settings| {
+            settings
+                .override_settings
+                .override_js_globals(params.path, &settings.languages.javascript.globals)
+        })
+        .unwrap_or_default();
+    let in_range =
+        |token_range: TextRange| range.is_none_or(|range| range.intersect(token_range).is_some());
+
+    let mut tokens = Vec::new();
+    for binding in model.all_bindings() {
+        let (kind, modifiers) = binding_token_classification(&binding);
+
+        let binding_range = binding.syntax().text_trimmed_range();
+        if in_range(binding_range) {
+            let mut modifiers = modifiers.clone();
+            modifiers.insert(0, SemanticTokenModifier::Declaration);
+            tokens.push(SemanticToken {
+                range: binding_range,
+                kind,
+                modifiers,
+            });
+        }
+
+        for reference in binding.all_references() {
+            let reference_range = reference.syntax().text_trimmed_range();
+            if in_range(reference_range) {
+                tokens.push(SemanticToken {
+                    range: reference_range,
+                    kind,
+                    modifiers: modifiers.clone(),
+                });
+            }
+        }
+    }
+
+    for reference in model.all_unresolved_references() {
+        let reference_range = reference.range();
+        if !in_range(reference_range) {
+            continue;
+        }
+
+        let Ok(name) = reference.tree().value_token() else {
+            continue;
+        };
+        let name = name.text_trimmed();
+        let modifier =
+            if is_js_global(name) || is_ts_global(name) || configured_globals.contains(name) {
+                SemanticTokenModifier::Global
+            } else {
+                SemanticTokenModifier::Unresolved
+            };
+        tokens.push(SemanticToken {
+            range: reference_range,
+            kind: SemanticTokenKind::Variable,
+            modifiers: vec![modifier],
+        });
+    }
+
+    tokens.sort_by_key(|token| token.range.start());
+    tokens
+}
+
+/// Returns the kind and the modifiers shared by a binding and its references.
+fn binding_token_classification(
+    binding: &Binding,
+) -> (SemanticTokenKind, Vec<SemanticTokenModifier>) {
+    let identifier = binding.tree();
+    let mut modifiers = Vec::new();
+
+    let declaration = identifier.declaration();
+    // Bindings declared by patterns take their kind from the declaration of
+    // the pattern, such as a parameter or a variable declarator.
+    let declaration = match &declaration {
+        Some(
+            AnyJsBindingDeclaration::JsArrayBindingPatternElement(_)
+            | AnyJsBindingDeclaration::JsArrayBindingPatternRestElement(_)
+            | AnyJsBindingDeclaration::JsObjectBindingPatternProperty(_)
+            | AnyJsBindingDeclaration::JsObjectBindingPatternRest(_)
+            | AnyJsBindingDeclaration::JsObjectBindingPatternShorthandProperty(_),
+        ) => declaration.and_then(|declaration| declaration.parent_binding_pattern_declaration()),
+        _ => declaration,
+    };
+
+    let kind = match declaration {
+        Some(declaration) if declaration.is_parameter_like() => SemanticTokenKind::Parameter,
+        Some(AnyJsBindingDeclaration::JsVariableDeclarator(declarator)) => {
+            if declarator
+                .declaration()
+                .is_some_and(|declaration| declaration.is_const())
+            {
+                modifiers.push(SemanticTokenModifier::Readonly);
+            }
+            SemanticTokenKind::Variable
+        }
+        Some(
+            AnyJsBindingDeclaration::TsInferType(_)
+            | AnyJsBindingDeclaration::TsMappedType(_)
+            | AnyJsBindingDeclaration::TsTypeParameter(_),
+        ) => SemanticTokenKind::TypeParameter,
+        Some(
+            AnyJsBindingDeclaration::JsFunctionDeclaration(_)
+            | AnyJsBindingDeclaration::JsFunctionExpression(_)
+            | AnyJsBindingDeclaration::JsFunctionExportDefaultDeclaration(_)
+            | AnyJsBindingDeclaration::TsDeclareFunctionDeclaration(_)
+            | AnyJsBindingDeclaration::TsDeclareFunctionExportDefaultDeclaration(_),
+        ) => SemanticTokenKind::Function,
+        Some(
+            AnyJsBindingDeclaration::JsClassDeclaration(_)
+            | AnyJsBindingDeclaration::JsClassExpression(_)
+            | AnyJsBindingDeclaration::JsClassExportDefaultDeclaration(_),
+        ) => SemanticTokenKind::Class,
+        Some(AnyJsBindingDeclaration::TsInterfaceDeclaration(_)) => SemanticTokenKind::Interface,
+        Some(AnyJsBindingDeclaration::TsTypeAliasDeclaration(_)) => SemanticTokenKind::Type,
+        Some(AnyJsBindingDeclaration::TsEnumDeclaration(_)) => SemanticTokenKind::Enum,
+        Some(AnyJsBindingDeclaration::TsEnumMember(_)) => {
+            modifiers.push(SemanticTokenModifier::Readonly);
+            SemanticTokenKind::EnumMember
+        }
+        Some(
+            AnyJsBindingDeclaration::TsExternalModuleDeclaration(_)
+            | AnyJsBindingDeclaration::TsModuleDeclaration(_),
+        ) => SemanticTokenKind::Namespace,
+        _ => SemanticTokenKind::Variable,
+    };
+
+    // Imported bindings can't be reassigned.
+    if binding.is_imported() {
+        modifiers.push(SemanticTokenModifier::Imported);
+        modifiers.push(SemanticTokenModifier::Readonly);
+    }
+
+    // The scope that contains the name of a binding isn't necessarily the one
+    // that declares it, such as for the name of a function, so we look for
+    // the declaring scope first.
+    let is_shadowing = identifier.name_token().is_ok_and(|name| {
+        let name = name.text_trimmed();
+        binding
+            .scope()
+            .ancestors()
+            .skip_while(|scope| {
+                scope
+                    .get_binding(name)
+                    .is_none_or(|declared| declared.syntax() != binding.syntax())
+            })
+            .skip(1)
+            .any(|scope| scope.get_binding(name).is_some())
+    });
+    if is_shadowing {
+        modifiers.push(SemanticTokenModifier::Shadowing);
+    }
+
+    (kind, modifiers)
+}
+
 fn rename(
-    _rome_path: &BiomePath,
-    parse: AnyParse,
+    params: EditorParams,
+    parse_for_path: ParseForPath,
     symbol_at: TextSize,
     new_name: String,
 ) -> Result<RenameResult, WorkspaceError> {
-    let root = parse.tree();
-    let model = semantic_model(&root, SemanticModelOptions::default());
-
-    if let Some(node) = parse
+    let root: AnyJsRoot = params.parse.tree();
+    let Some(node) = root
         .syntax()
         .descendants_tokens(Direction::Next)
         .find(|token| token.text_range().contains(symbol_at))
         .and_then(|token| token.parent())
-    {
-        let original_name = node.text_trimmed();
-        let range = node.text_range_with_trivia();
-        match node.try_into() {
-            Ok(node) => {
-                let mut batch = root.begin();
-                let result = batch.rename_any_renamable_node(&model, &node, &new_name);
-                if !result {
-                    Err(WorkspaceError::RenameError(RenameError::CannotBeRenamed {
-                        original_name: original_name.to_string(),
-                        original_range: range,
-                        new_name,
-                    }))
-                } else {
-                    let (range, indels) = batch.to_text_range_and_edit().unwrap_or_default();
-                    Ok(RenameResult { range, indels })
+    else {
+        return Err(WorkspaceError::RenameError(
+            RenameError::CannotFindDeclaration(new_name),
+        ));
+    };
+
+    let original_name = node.text_trimmed().to_string();
+    let original_range = node.text_range_with_trivia();
+    let node: AnyJsRenamableDeclaration = node.try_into().map_err(WorkspaceError::RenameError)?;
+    let cannot_be_renamed = |new_name: String| {
+        WorkspaceError::RenameError(RenameError::CannotBeRenamed {
+            original_name,
+            original_range,
+            new_name,
+        })
+    };
+
+    let mut current = ModuleRename::new(root.clone());
+    let Some(binding) = node
+        .binding(&current.model)
+        .and_then(AnyJsIdentifierBinding::cast)
+    else {
+        return Err(cannot_be_renamed(new_name));
+    };
+
+    let module_info = module_info(&params, &root);
+    let mut modules = BTreeMap::new();
+
+    // Symbols imported without an alias are renamed where they're defined, so
+    // that all the modules importing them are updated consistently.
+    let definition = binding
+        .parent::<JsShorthandNamedImportSpecifier>()
+        .and_then(|_| {
+            params
+                .module_graph
+                .find_definition(params.path, &module_info, binding.range().start())
+        })
+        .filter(|definition| definition.path != params.path.as_path())
+        .and_then(|definition| {
+            let definition_root: AnyJsRoot = parse_for_path(&definition.path)?.tree();
+            let definition_module = params.module_graph.module_info_for_path(&definition.path)?;
+            let definition_binding = definition_root
+                .syntax()
+                .token_at_offset(definition.range.start())
+                .find_map(|token| token.parent().and_then(AnyJsIdentifierBinding::cast))?;
+            Some((
+                definition.path,
+                definition_root,
+                definition_module,
+                definition_binding,
+            ))
+        });
+
+    let imports = match definition {
+        Some((definition_path, definition_root, definition_module, definition_binding)) => {
+            let mut definition = ModuleRename::new(definition_root);
+            if !definition.rename_binding(&definition_binding, &new_name) {
+                return Err(cannot_be_renamed(new_name));
+            }
+            modules.insert(definition_path.clone(), definition);
+
+            // The current module may not be part of the module graph, in which
+            // case it wouldn't be found among the importers.
+            if !current.rename_binding(&binding, &new_name) {
+                return Err(cannot_be_renamed(new_name));
+            }
+            modules.insert(params.path.to_path_buf(), current);
+
+            params.module_graph.find_symbol_imports(
+                &definition_path,
+                &definition_module,
+                definition_binding.range().start(),
+            )
+        }
+        None => {
+            if !current.rename_binding(&binding, &new_name) {
+                return Err(cannot_be_renamed(new_name));
+            }
+            modules.insert(params.path.to_path_buf(), current);
+
+            params.module_graph.find_symbol_imports(
+                params.path,
+                &module_info,
+                binding.range().start(),
+            )
+        }
+    };
+
+    for import in imports {
+        let module = match modules.entry(import.path.clone()) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let Some(parse) = parse_for_path(&import.path) else {
+                    continue;
+                };
+                entry.insert(ModuleRename::new(parse.tree()))
+            }
+        };
+
+        let renamed = if import.is_reexport {
+            module.rename_reexport(&import, &new_name);
+            true
+        } else {
+            module.rename_import(&import, &new_name)
+        };
+        if !renamed {
+            return Err(cannot_be_renamed(new_name));
+        }
+    }
+
+    let mut result = RenameResult {
+        range: TextRange::default(),
+        indels: TextEdit::default(),
+        other_files: Vec::new(),
+    };
+    for (path, module) in modules {
+        let Some((range, indels)) = module.batch.to_text_range_and_edit() else {
+            continue;
+        };
+        if path == params.path.as_path() {
+            result.range = range;
+            result.indels = indels;
+        } else {
+            result.other_files.push(RenameFileEdits {
+                path: path.into(),
+                range,
+                indels,
+            });
+        }
+    }
+
+    Ok(result)
+}
+
+fn prepare_rename(parse: AnyParse, symbol_at: TextSize) -> Option<TextRange> {
+    let root: AnyJsRoot = parse.tree();
+    let token = root
+        .syntax()
+        .token_at_offset(symbol_at)
+        .find(|token| token.kind() == JsSyntaxKind::IDENT)?;
+    let node = AnyJsRenamableDeclaration::try_from(token.parent()?).ok()?;
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    node.binding(&model)?;
+
+    Some(token.text_trimmed_range())
+}
+
+/// Pending changes to a module affected by a rename operation.
+struct ModuleRename {
+    root: AnyJsRoot,
+    model: SemanticModel,
+    batch: BatchMutation<JsLanguage>,
+    /// Ranges of the bindings that have been renamed already.
+    renamed_bindings: Vec<TextRange>,
+}
+
+impl ModuleRename {
+    fn new(root: AnyJsRoot) -> Self {
+        let model = semantic_model(&root, SemanticModelOptions::default());
+        let batch = root.clone().begin();
+        Self {
+            root,
+            model,
+            batch,
+            renamed_bindings: Vec::new(),
+        }
+    }
+
+    /// Renames `binding` and all its references, unless it was renamed
+    /// already.
+    fn rename_binding(&mut self, binding: &AnyJsIdentifierBinding, new_name: &str) -> bool {
+        let range = binding.range();
+        if self.renamed_bindings.contains(&range) {
+            return true;
+        }
+
+        self.renamed_bindings.push(range);
+        self.batch
+            .rename_node_declaration(&self.model, binding, new_name)
+    }
+
+    /// Updates the import specifiers that match `import`.
+    ///
+    /// Specifiers without an alias are renamed together with their local
+    /// binding, while for the other ones only the imported name is updated.
+    fn rename_import(&mut self, import: &SymbolImport, new_name: &str) -> bool {
+        let specifiers: Vec<_> = self
+            .root
+            .syntax()
+            .descendants()
+            .filter_map(AnyJsNamedImportSpecifier::cast)
+            .filter(|specifier| {
+                specifier
+                    .import_clause()
+                    .and_then(|clause| clause.source().ok())
+                    .and_then(|source| source.inner_string_text().ok())
+                    .is_some_and(|source| source.text() == import.specifier.text())
+            })
+            .collect();
+
+        for specifier in specifiers {
+            let Some(name_token) = specifier.imported_name() else {
+                continue;
+            };
+            if inner_string_text(&name_token).text() != import.imported_name.text() {
+                continue;
+            }
+
+            match specifier {
+                AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(specifier) => {
+                    let Some(binding) = specifier
+                        .local_name()
+                        .ok()
+                        .and_then(|binding| AnyJsIdentifierBinding::cast(binding.into_syntax()))
+                    else {
+                        continue;
+                    };
+                    if !self.rename_binding(&binding, new_name) {
+                        return false;
+                    }
+                }
+                AnyJsNamedImportSpecifier::JsNamedImportSpecifier(_) => {
+                    self.batch.replace_token(
+                        name_token.clone(),
+                        export_name_token(&name_token, new_name),
+                    );
                 }
+                AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => {}
             }
-            Err(err) => Err(WorkspaceError::RenameError(err)),
         }
+
+        true
+    }
+
+    /// Updates the names of the re-export specifiers that match `import`.
+    fn rename_reexport(&mut self, import: &SymbolImport, new_name: &str) {
+        let name_tokens: Vec<_> = self
+            .root
+            .syntax()
+            .descendants()
+            .filter_map(JsExportNamedFromSpecifier::cast)
+            .filter(|specifier| {
+                specifier
+                    .syntax()
+                    .ancestors()
+                    .find_map(JsExportNamedFromClause::cast)
+                    .and_then(|clause| clause.source().ok())
+                    .and_then(|source| source.as_js_module_source()?.inner_string_text().ok())
+                    .is_some_and(|source| source.text() == import.specifier.text())
+            })
+            .filter_map(|specifier| specifier.source_name().ok()?.value().ok())
+            .filter(|name_token| {
+                inner_string_text(name_token).text() == import.imported_name.text()
+            })
+            .collect();
+
+        for name_token in name_tokens {
+            self.batch
+                .replace_token(name_token.clone(), export_name_token(&name_token, new_name));
+        }
+    }
+}
+
+/// Creates the token for an export name, which may be either an identifier or
+/// a string literal, such as in `import { "foo" as foo } from "./foo.js"`.
+fn export_name_token(token: &JsSyntaxToken, new_name: &str) -> JsSyntaxToken {
+    if token.kind() == JsSyntaxKind::JS_STRING_LITERAL {
+        JsSyntaxToken::new_detached(
+            JsSyntaxKind::JS_STRING_LITERAL,
+            &format!("\"{new_name}\""),
+            [],
+            [],
+        )
     } else {
-        Err(WorkspaceError::RenameError(
-            RenameError::CannotFindDeclaration(new_name),
-        ))
+        JsSyntaxToken::new_detached(JsSyntaxKind::IDENT, new_name, [], [])
+    }
+}
+
+/// Returns the module info for the document, using the one from the module
+/// graph when available.
+fn module_info(params: &EditorParams, root: &AnyJsRoot) -> JsModuleInfo {
+    params
+        .module_graph
+        .module_info_for_path(params.path)
+        .unwrap_or_else(|| {
+            params.module_graph.module_info_for_js_root(
+                params.fs,
+                &params.project_layout,
+                params.path,
+                root.clone(),
+            )
+        })
+}
+
+/// Builds a [ScopedResolver] for the document.
+fn scoped_resolver(params: &EditorParams, root: &AnyJsRoot) -> Arc<ScopedResolver> {
+    let mut resolver =
+        ScopedResolver::from_global_scope(module_info(params, root), params.module_graph.clone());
+    resolver.run_inference();
+    Arc::new(resolver)
+}
+
+fn hover(params: EditorParams, offset: TextSize) -> HoverResult {
+    let root: AnyJsRoot = params.parse.tree();
+    let Some(token) = root
+        .syntax()
+        .token_at_offset(offset)
+        .max_by_key(|token| token.kind() == JsSyntaxKind::IDENT)
+    else {
+        return HoverResult::default();
+    };
+
+    let Some(node) = token.parent() else {
+        return HoverResult::default();
+    };
+
+    if let Some(binding) = AnyJsIdentifierBinding::cast_ref(&node) {
+        let Ok(name) = binding.name_token() else {
+            return HoverResult::default();
+        };
+        let resolver = scoped_resolver(&params, &root);
+        let range = binding.range();
+        let ty = resolver.resolved_type_for_binding(&name.token_text_trimmed().into(), range);
+        if ty.is_inferred() {
+            return HoverResult {
+                range: Some(range),
+                contents: vec![format!(
+                    "{}: {}",
+                    code_span(name.text_trimmed()),
+                    code_span(&ty.to_string())
+                )],
+            };
+        }
+    } else if let Some(expression) = node.ancestors().find_map(AnyJsExpression::cast) {
+        let resolver = scoped_resolver(&params, &root);
+        let ty = resolver.resolved_type_for_expression(&expression);
+        if ty.is_inferred() {
+            return HoverResult {
+                range: Some(expression.range()),
+                contents: vec![code_span(&ty.to_string())],
+            };
+        }
+    }
+
+    HoverResult::default()
+}
+
+/// Wraps `text` in a Markdown code span, using a backtick fence longer than
+/// any run of backticks inside `text`.
+fn code_span(text: &str) -> String {
+    let longest_run = text
+        .split(|c| c != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or_default();
+    let fence = "`".repeat(longest_run + 1);
+    if text.starts_with('`') || text.ends_with('`') {
+        format!("{fence} {text} {fence}")
+    } else {
+        format!("{fence}{text}{fence}")
+    }
+}
+
+fn goto_definition(params: EditorParams, offset: TextSize) -> Vec<SymbolLocation> {
+    let root: AnyJsRoot = params.parse.tree();
+    let module_info = module_info(&params, &root);
+    params
+        .module_graph
+        .find_definition(params.path, &module_info, offset)
+        .map(|location| SymbolLocation {
+            path: location.path.into(),
+            range: location.range,
+        })
+        .into_iter()
+        .collect()
+}
+
+fn find_references(
+    params: EditorParams,
+    offset: TextSize,
+    include_declaration: bool,
+) -> Vec<SymbolLocation> {
+    let root: AnyJsRoot = params.parse.tree();
+    let module_info = module_info(&params, &root);
+    params
+        .module_graph
+        .find_references(params.path, &module_info, offset, include_declaration)
+        .into_iter()
+        .map(|location| SymbolLocation {
+            path: location.path.into(),
+            range: location.range,
+        })
+        .collect()
+}
+
+fn folding_ranges(params: EditorParams) -> Vec<FoldingRange> {
+    syntax_folding_ranges(&params.parse.syntax::<JsLanguage>(), |kind| {
+        kind == JsSyntaxKind::JSX_ELEMENT
+    })
+}
+
+/// Returns the names of the opening and closing elements of a JSX element, if
+/// the offset is inside one of them.
+fn linked_editing_ranges(params: EditorParams, offset: TextSize) -> Vec<TextRange> {
+    let root: AnyJsRoot = params.parse.tree();
+    root.syntax()
+        .token_at_offset(offset)
+        .find_map(|token| {
+            let element = token.parent()?.ancestors().find_map(JsxElement::cast)?;
+            let opening_name = element.opening_element().ok()?.name().ok()?;
+            let closing_name = element.closing_element().ok()?.name().ok()?;
+            let ranges = vec![opening_name.range(), closing_name.range()];
+            let is_linked = ranges.iter().any(|range| range.contains_inclusive(offset))
+                && opening_name.syntax().text_trimmed() == closing_name.syntax().text_trimmed();
+            is_linked.then_some(ranges)
+        })
+        .unwrap_or_default()
+}
+
+/// The maximum nesting of the types rendered by inlay hints. Following a type
+/// reference counts as a level of nesting, which bounds cyclic references.
+const MAX_INLAY_HINT_DEPTH: usize = 3;
+
+fn inlay_hints(params: EditorParams, range: Option<TextRange>) -> Vec<InlayHint> {
+    let root: AnyJsRoot = params.parse.tree();
+    let resolver = scoped_resolver(&params, &root);
+
+    let mut hints = Vec::new();
+    for node in root.syntax().descendants() {
+        if range.is_some_and(|range| range.intersect(node.text_trimmed_range()).is_none()) {
+            continue;
+        }
+
+        if let Some(function) = AnyJsFunction::cast_ref(&node) {
+            hints.extend(return_type_hint(&resolver, &function));
+        } else if let Some(declarator) = JsVariableDeclarator::cast_ref(&node) {
+            hints.extend(const_type_hint(&resolver, &declarator));
+        } else if let Some(call) = JsCallExpression::cast_ref(&node) {
+            parameter_name_hints(&resolver, &call, &mut hints);
+        }
+    }
+
+    if let Some(range) = range {
+        hints.retain(|hint| range.contains_inclusive(hint.offset));
     }
+    hints.sort_by_key(|hint| hint.offset);
+    hints
+}
+
+/// Returns a hint with the inferred return type of a function that doesn't
+/// have a return type annotation.
+///
+/// The return type is inferred from the types of the returned expressions.
+fn return_type_hint(resolver: &Arc<ScopedResolver>, function: &AnyJsFunction) -> Option<InlayHint> {
+    if function.return_type_annotation().is_some() || function.is_generator() {
+        return None;
+    }
+
+    // An arrow function with a single parameter needs parentheses around the
+    // parameter before it can have a return type.
+    let parameters = function.parameters().ok()?;
+    if matches!(parameters, AnyJsArrowFunctionParameters::AnyJsBinding(_)) {
+        return None;
+    }
+
+    let mut labels: Vec<String> = Vec::new();
+    match function.body().ok()? {
+        AnyJsFunctionBody::AnyJsExpression(expression) => {
+            labels.push(type_label(
+                &resolver.resolved_type_for_expression(&expression),
+                0,
+            )?);
+        }
+        AnyJsFunctionBody::JsFunctionBody(body) => {
+            let mut preorder = body.syntax().preorder();
+            while let Some(event) = preorder.next() {
+                let WalkEvent::Enter(node) = event else {
+                    continue;
+                };
+
+                // Nested functions have their own return statements.
+                if AnyJsFunction::can_cast(node.kind())
+                    || AnyJsClass::can_cast(node.kind())
+                    || AnyJsObjectMember::can_cast(node.kind())
+                {
+                    preorder.skip_subtree();
+                    continue;
+                }
+
+                if let Some(statement) = JsReturnStatement::cast(node) {
+                    let label = match statement.argument() {
+                        Some(argument) => {
+                            type_label(&resolver.resolved_type_for_expression(&argument), 0)?
+                        }
+                        None => "undefined".to_string(),
+                    };
+                    if !labels.contains(&label) {
+                        labels.push(label);
+                    }
+                }
+            }
+        }
+    }
+
+    let label = if labels.is_empty() {
+        "void".to_string()
+    } else {
+        labels.join(" | ")
+    };
+    let label = if function.async_token().is_some() {
+        format!("Promise<{label}>")
+    } else {
+        label
+    };
+
+    Some(InlayHint {
+        offset: parameters.range().end(),
+        label: format!(": {label}"),
+        kind: InlayHintKind::Type,
+    })
+}
+
+/// Returns a hint with the inferred type of a `const` binding that doesn't
+/// have a type annotation.
+fn const_type_hint(
+    resolver: &Arc<ScopedResolver>,
+    declarator: &JsVariableDeclarator,
+) -> Option<InlayHint> {
+    if declarator.variable_annotation().is_some() || !declarator.declaration()?.is_const() {
+        return None;
+    }
+
+    // The type of literals and functions is already obvious from the source.
+    let initializer = declarator.initializer()?.expression().ok()?;
+    if matches!(
+        initializer,
+        AnyJsExpression::AnyJsLiteralExpression(_)
+            | AnyJsExpression::JsArrowFunctionExpression(_)
+            | AnyJsExpression::JsClassExpression(_)
+            | AnyJsExpression::JsFunctionExpression(_)
+    ) {
+        return None;
+    }
+
+    let id = declarator.id().ok()?;
+    let binding = id.as_any_js_binding()?.as_js_identifier_binding()?;
+    let name = binding.name_token().ok()?;
+    let ty = resolver.resolved_type_for_binding(&name.token_text_trimmed().into(), binding.range());
+    let label = type_label(&ty, 0)?;
+
+    Some(InlayHint {
+        offset: binding.range().end(),
+        label: format!(": {label}"),
+        kind: InlayHintKind::Type,
+    })
+}
+
+/// Pushes hints with the names of the parameters in front of the arguments of
+/// the given call.
+///
+/// Arguments that are named like the parameter they're passed to don't get a
+/// hint.
+fn parameter_name_hints(
+    resolver: &Arc<ScopedResolver>,
+    call: &JsCallExpression,
+    hints: &mut Vec<InlayHint>,
+) {
+    let (Ok(callee), Ok(arguments)) = (call.callee(), call.arguments()) else {
+        return;
+    };
+    let ty = resolver.resolved_type_for_expression(&callee);
+    let TypeData::Function(function) = &*ty else {
+        return;
+    };
+
+    for (argument, parameter) in arguments.args().iter().zip(function.parameters.iter()) {
+        // Parameters can't be matched to the arguments after a spread.
+        let Ok(AnyJsCallArgument::AnyJsExpression(argument)) = argument else {
+            break;
+        };
+
+        let name = parameter.name.as_ref();
+        if let Some(name) = name.filter(|name| !is_named_like(&argument, name.text())) {
+            hints.push(InlayHint {
+                offset: argument.range().start(),
+                label: if parameter.is_rest {
+                    format!("...{name}:")
+                } else {
+                    format!("{name}:")
+                },
+                kind: InlayHintKind::Parameter,
+            });
+        }
+
+        if parameter.is_rest {
+            break;
+        }
+    }
+}
+
+/// Returns whether the expression is an identifier or a member named `name`.
+fn is_named_like(expression: &AnyJsExpression, name: &str) -> bool {
+    let token = match expression.clone().omit_parentheses() {
+        AnyJsExpression::JsIdentifierExpression(identifier) => {
+            identifier.name().and_then(|name| name.value_token())
+        }
+        AnyJsExpression::JsStaticMemberExpression(member) => {
+            member.member().and_then(|member| member.value_token())
+        }
+        _ => return false,
+    };
+
+    token.is_ok_and(|token| token.text_trimmed() == name)
+}
+
+/// Renders a type the way it would be written in a TypeScript annotation.
+///
+/// Returns `None` for types that can't be rendered in a concise way, such as
+/// object types, or that weren't inferred.
+fn type_label(ty: &Type, depth: usize) -> Option<String> {
+    if depth > MAX_INLAY_HINT_DEPTH {
+        return None;
+    }
+
+    let label = match &**ty {
+        TypeData::BigInt => "bigint".to_string(),
+        TypeData::Boolean => "boolean".to_string(),
+        TypeData::Null => "null".to_string(),
+        TypeData::Number => "number".to_string(),
+        TypeData::String => "string".to_string(),
+        TypeData::Symbol => "symbol".to_string(),
+        TypeData::Undefined => "undefined".to_string(),
+        TypeData::AnyKeyword => "any".to_string(),
+        TypeData::NeverKeyword => "never".to_string(),
+        TypeData::ObjectKeyword => "object".to_string(),
+        TypeData::UnknownKeyword => "unknown".to_string(),
+        TypeData::VoidKeyword => "void".to_string(),
+        TypeData::Literal(literal) => match literal.as_ref() {
+            Literal::BigInt(value) => value.to_string(),
+            Literal::Boolean(value) => value.as_bool().to_string(),
+            Literal::Null => "null".to_string(),
+            Literal::Number(value) => value.as_str().to_string(),
+            Literal::RegExp(_) => "RegExp".to_string(),
+            Literal::String(value) => format!("\"{}\"", value.as_str()),
+            Literal::Template(_) => "string".to_string(),
+            Literal::Object(_) => return None,
+        },
+        TypeData::Generic(generic) => generic.name.to_string(),
+        TypeData::Reference(reference) => return type_label(&ty.resolve(reference)?, depth + 1),
+        TypeData::Union(union) => union
+            .types()
+            .iter()
+            .map(|variant| type_label(&ty.resolve(variant)?, depth + 1))
+            .collect::<Option<Vec<_>>>()?
+            .join(" | "),
+        TypeData::InstanceOf(instance) => {
+            let class = ty.resolve(&instance.ty)?;
+            let TypeData::Class(class) = &*class else {
+                return None;
+            };
+            let name = class.name.as_ref()?;
+            if instance.type_parameters.len() != class.type_parameters.len() {
+                return None;
+            }
+            let type_parameters = instance
+                .type_parameters
+                .iter()
+                .map(|parameter| type_label(&ty.resolve(parameter)?, depth + 1))
+                .collect::<Option<Vec<_>>>()?;
+            match type_parameters.as_slice() {
+                [] => name.to_string(),
+                [element] if name.text() == "Array" => {
+                    if element.contains(' ') {
+                        format!("({element})[]")
+                    } else {
+                        format!("{element}[]")
+                    }
+                }
+                _ => format!("{name}<{}>", type_parameters.join(", ")),
+            }
+        }
+        TypeData::Function(function) => {
+            let parameters = function
+                .parameters
+                .iter()
+                .map(|parameter| {
+                    let name = parameter.name.as_ref()?;
+                    let ty = type_label(&ty.resolve(&parameter.ty)?, depth + 1)?;
+                    let rest = if parameter.is_rest { "..." } else { "" };
+                    let optional = if parameter.is_optional { "?" } else { "" };
+                    Some(format!("{rest}{name}{optional}: {ty}"))
+                })
+                .collect::<Option<Vec<_>>>()?;
+            let return_type = ty.resolve(function.return_type.as_type()?)?;
+            let return_type = type_label(&return_type, depth + 1)?;
+            format!("({}) => {return_type}", parameters.join(", "))
+        }
+        _ => return None,
+    };
+
+    Some(label)
 }
 
 #[cfg(test)]
@@ -1,6 +1,7 @@
 use super::{
-    AnalyzerVisitorBuilder, CodeActionsParams, DocumentFileSource, EnabledForPath,
-    ExtensionHandler, ParseResult, ProcessLint, SearchCapabilities, is_diagnostic_error,
+    AnalyzerVisitorBuilder, CodeActionsParams, DocumentFileSource, EditorCapabilities,
+    EditorParams, EnabledForPath, ExtensionHandler, ParseResult, ProcessLint, SearchCapabilities,
+    is_diagnostic_error, syntax_folding_ranges, syntax_selection_range,
 };
 use crate::configuration::to_analyzer_rules;
 use crate::file_handlers::DebugCapabilities;
@@ -13,7 +14,8 @@ use crate::settings::{
     Settings, WorkspaceSettingsHandle, check_feature_activity, check_override_feature_activity,
 };
 use crate::workspace::{
-    CodeAction, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult, PullActionsResult,
+    CodeAction, DocumentSymbol, FixAction, FixFileMode, FixFileResult, FoldingRange,
+    GetSyntaxTreeResult, PullActionsResult, SymbolKind,
 };
 use crate::{WorkspaceError, extension_error};
 use biome_analyze::options::PreferredQuote;
@@ -36,14 +38,16 @@ use biome_json_analyze::analyze;
 use biome_json_formatter::context::{JsonFormatOptions, TrailingCommas};
 use biome_json_formatter::format_node;
 use biome_json_parser::JsonParserOptions;
-use biome_json_syntax::{JsonFileSource, JsonLanguage, JsonRoot, JsonSyntaxNode};
+use biome_json_syntax::{AnyJsonValue, JsonFileSource, JsonLanguage, JsonRoot, JsonSyntaxNode};
 use biome_parser::AnyParse;
-use biome_rowan::{AstNode, NodeCache};
+use biome_rowan::{AstNode, AstSeparatedList, NodeCache};
 use biome_rowan::{TextRange, TextSize, TokenAtOffset};
 use camino::Utf8Path;
 use std::borrow::Cow;
 use tracing::{debug_span, error, instrument};
 
+mod configuration;
+
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct JsonFormatterSettings {
@@ -334,6 +338,7 @@ impl ExtensionHandler for JsonFileHandler {
                 lint: Some(lint),
                 code_actions: Some(code_actions),
                 rename: None,
+                prepare_rename: None,
                 fix_all: Some(fix_all),
             },
             formatter: FormatterCapabilities {
@@ -342,6 +347,18 @@ impl ExtensionHandler for JsonFileHandler {
                 format_on_type: Some(format_on_type),
             },
             search: SearchCapabilities { search: None },
+            editor: EditorCapabilities {
+                hover: Some(configuration::hover),
+                goto_definition: None,
+                find_references: None,
+                document_symbols: Some(document_symbols),
+                semantic_tokens: None,
+                folding_ranges: Some(folding_ranges),
+                selection_range: Some(syntax_selection_range::<JsonLanguage>),
+                inlay_hints: None,
+                completions: Some(configuration::completions),
+                linked_editing_ranges: None,
+            },
         }
     }
 }
@@ -531,7 +548,7 @@ fn lint(params: LintParams) -> LintResults {
         categories: params.categories,
         enabled_rules: Some(enabled_rules.as_slice()),
         disabled_rules: &disabled_rules,
-        range: None,
+        range: params.range,
     };
 
     let mut process_lint = ProcessLint::new(&params);
@@ -763,3 +780,75 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
         }
     }
 }
+
+fn document_symbols(params: EditorParams) -> Vec<DocumentSymbol> {
+    let root: JsonRoot = params.parse.tree();
+    root.value()
+        .map(|value| value_children(&value))
+        .unwrap_or_default()
+}
+
+/// Returns the symbols of the members of an object, or of the elements of an
+/// array, which are named after their index.
+fn value_children(value: &AnyJsonValue) -> Vec<DocumentSymbol> {
+    match value {
+        AnyJsonValue::JsonObjectValue(object) => object
+            .json_member_list()
+            .iter()
+            .flatten()
+            .filter_map(|member| {
+                let name = member.name().ok()?;
+                let value = member.value().ok()?;
+                Some(value_symbol(
+                    name.inner_string_text().ok()?.to_string(),
+                    member.range(),
+                    name.range(),
+                    &value,
+                ))
+            })
+            .collect(),
+        AnyJsonValue::JsonArrayValue(array) => array
+            .elements()
+            .iter()
+            .flatten()
+            .enumerate()
+            .map(|(index, element)| {
+                value_symbol(
+                    index.to_string(),
+                    element.range(),
+                    element.range(),
+                    &element,
+                )
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn value_symbol(
+    name: String,
+    range: TextRange,
+    selection_range: TextRange,
+    value: &AnyJsonValue,
+) -> DocumentSymbol {
+    let kind = match value {
+        AnyJsonValue::JsonArrayValue(_) => SymbolKind::Array,
+        AnyJsonValue::JsonBooleanValue(_) => SymbolKind::Boolean,
+        AnyJsonValue::JsonNullValue(_) => SymbolKind::Null,
+        AnyJsonValue::JsonNumberValue(_) => SymbolKind::Number,
+        AnyJsonValue::JsonObjectValue(_) | AnyJsonValue::JsonBogusValue(_) => SymbolKind::Object,
+        AnyJsonValue::JsonStringValue(_) => SymbolKind::String,
+    };
+
+    DocumentSymbol {
+        name,
+        kind,
+        range,
+        selection_range,
+        children: value_children(value),
+    }
+}
+
+fn folding_ranges(params: EditorParams) -> Vec<FoldingRange> {
+    syntax_folding_ranges(&params.parse.syntax::<JsonLanguage>(), |_| false)
+}
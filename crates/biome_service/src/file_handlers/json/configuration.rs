@@ -0,0 +1,279 @@
+//! Editor features for the Biome configuration files.
+//!
+//! The keys and values accepted by the configuration are found by
+//! deserializing the document with a placeholder at the position of the
+//! cursor: the diagnostic emitted for the unknown placeholder lists the keys
+//! or the values that are accepted in its place. This way, completions always
+//! match the version of Biome that is running.
+
+use crate::documentation::rules_metadata;
+use crate::file_handlers::EditorParams;
+use crate::workspace::{CompletionItem, CompletionItemKind, HoverResult};
+use biome_analyze::RuleMetadata;
+use biome_configuration::Configuration;
+use biome_deserialize::{Deserializable, DeserializationContext, DeserializationDiagnostic};
+use biome_fs::ConfigName;
+use biome_json_parser::{JsonParserOptions, parse_json};
+use biome_json_syntax::{
+    JsonMember, JsonMemberName, JsonObjectValue, JsonRoot, JsonStringValue, JsonSyntaxKind, T,
+};
+use biome_rowan::{AstNode, AstSeparatedList, TextRange, TextSize};
+
+/// Key or value inserted in place of the one being completed.
+const PLACEHOLDER: &str = "\"biomeCompletionPlaceholder\"";
+
+fn is_configuration_file(params: &EditorParams) -> bool {
+    params
+        .path
+        .file_name()
+        .is_some_and(|name| ConfigName::file_names().contains(&name))
+}
+
+pub(super) fn completions(params: EditorParams, offset: TextSize) -> Vec<CompletionItem> {
+    if !is_configuration_file(&params) {
+        return Vec::new();
+    }
+
+    let root: JsonRoot = params.parse.tree();
+    let Some(target) = CompletionTarget::at_offset(&root, offset) else {
+        return Vec::new();
+    };
+
+    let names = accepted_names(&root.syntax().to_string(), &target);
+    if names.is_empty() {
+        return Vec::new();
+    }
+
+    let (existing_keys, rules) = match &target.object {
+        Some(object) => (
+            existing_keys(object, target.range),
+            is_rule_group(object).then(rules_metadata),
+        ),
+        None => (Vec::new(), None),
+    };
+
+    names
+        .into_iter()
+        .filter(|name| !existing_keys.contains(name))
+        .map(|name| CompletionItem {
+            documentation: rules
+                .as_ref()
+                .and_then(|rules| rules.get(name.as_str()))
+                .map(|rule| rule_summary(rule.metadata())),
+            insert_text: format!("\"{name}\""),
+            label: name,
+            kind: target.kind,
+            range: target.range,
+        })
+        .collect()
+}
+
+pub(super) fn hover(params: EditorParams, offset: TextSize) -> HoverResult {
+    if !is_configuration_file(&params) {
+        return HoverResult::default();
+    }
+
+    let root: JsonRoot = params.parse.tree();
+    let Some(name) = root
+        .syntax()
+        .token_at_offset(offset)
+        .find_map(|token| JsonMemberName::cast(token.parent()?))
+    else {
+        return HoverResult::default();
+    };
+
+    let is_rule = name
+        .syntax()
+        .ancestors()
+        .find_map(JsonObjectValue::cast)
+        .is_some_and(|object| is_rule_group(&object));
+    let Some(rule) = is_rule
+        .then(|| name.inner_string_text().ok())
+        .flatten()
+        .and_then(|rule_name| rules_metadata().remove(rule_name.text()))
+    else {
+        return HoverResult::default();
+    };
+
+    HoverResult {
+        range: Some(name.range()),
+        contents: vec![rule_documentation(rule.metadata())],
+    }
+}
+
+/// The key or value being completed.
+struct CompletionTarget {
+    kind: CompletionItemKind,
+    /// Range of the text replaced by the completion.
+    range: TextRange,
+    /// Text inserted after the placeholder, so the document stays valid.
+    suffix: &'static str,
+    /// The object that contains the key being completed.
+    object: Option<JsonObjectValue>,
+}
+
+impl CompletionTarget {
+    fn at_offset(root: &JsonRoot, offset: TextSize) -> Option<Self> {
+        let token = root.syntax().token_at_offset(offset).left_biased()?;
+        let range = token.text_trimmed_range();
+
+        if range.start() < offset && offset < range.end() {
+            if token.kind() != JsonSyntaxKind::JSON_STRING_LITERAL {
+                return None;
+            }
+
+            let parent = token.parent()?;
+            return if JsonMemberName::can_cast(parent.kind()) {
+                Some(Self {
+                    kind: CompletionItemKind::Property,
+                    range,
+                    suffix: "",
+                    object: parent.ancestors().find_map(JsonObjectValue::cast),
+                })
+            } else if JsonStringValue::can_cast(parent.kind()) {
+                Some(Self {
+                    kind: CompletionItemKind::Value,
+                    range,
+                    suffix: "",
+                    object: None,
+                })
+            } else {
+                None
+            };
+        }
+
+        // Otherwise, a new key can be inserted after the start of an object
+        // or after the comma that separates two members.
+        let previous = if range.end() <= offset {
+            token
+        } else {
+            token.prev_token()?
+        };
+        let parent = previous.parent()?;
+        let object = match previous.kind() {
+            T!['{'] => JsonObjectValue::cast(parent)?,
+            T![,] if parent.kind() == JsonSyntaxKind::JSON_MEMBER_LIST => {
+                JsonObjectValue::cast(parent.parent()?)?
+            }
+            _ => return None,
+        };
+        let suffix = match previous.next_token() {
+            Some(next) if next.kind() == T!['}'] => ": null",
+            _ => ": null,",
+        };
+
+        Some(Self {
+            kind: CompletionItemKind::Property,
+            range: TextRange::empty(offset),
+            suffix,
+            object: Some(object),
+        })
+    }
+}
+
+/// Returns the keys or values accepted in place of the target.
+fn accepted_names(text: &str, target: &CompletionTarget) -> Vec<String> {
+    let start = usize::from(target.range.start());
+    let end = usize::from(target.range.end());
+    let source = format!(
+        "{}{PLACEHOLDER}{}{}",
+        &text[..start],
+        target.suffix,
+        &text[end..]
+    );
+
+    let parse = parse_json(
+        &source,
+        JsonParserOptions::default()
+            .with_allow_comments()
+            .with_allow_trailing_commas(),
+    );
+    let Ok(value) = parse.tree().value() else {
+        return Vec::new();
+    };
+
+    let mut ctx = PlaceholderContext {
+        placeholder_range: TextRange::at(target.range.start(), TextSize::of(PLACEHOLDER)),
+        accepted_names: Vec::new(),
+    };
+    Configuration::deserialize(&mut ctx, &value, "");
+    ctx.accepted_names
+}
+
+/// Deserialization context that keeps the names accepted in place of the
+/// placeholder.
+struct PlaceholderContext {
+    placeholder_range: TextRange,
+    accepted_names: Vec<String>,
+}
+
+impl DeserializationContext for PlaceholderContext {
+    fn id(&self) -> Option<&str> {
+        None
+    }
+
+    fn report(&mut self, diagnostic: DeserializationDiagnostic) {
+        if diagnostic.range() == Some(self.placeholder_range) {
+            self.accepted_names = diagnostic.expected_names();
+        }
+    }
+}
+
+/// Returns the keys of the object, except the one being completed.
+fn existing_keys(object: &JsonObjectValue, completed_range: TextRange) -> Vec<String> {
+    object
+        .json_member_list()
+        .iter()
+        .flatten()
+        .filter_map(|member| member.name().ok())
+        .filter(|name| name.range() != completed_range)
+        .filter_map(|name| Some(name.inner_string_text().ok()?.to_string()))
+        .collect()
+}
+
+/// Returns whether the object configures the rules of a group, such as
+/// `linter.rules.style` or `assist.actions.source`.
+fn is_rule_group(object: &JsonObjectValue) -> bool {
+    let mut keys = object.syntax().ancestors().filter_map(|node| {
+        let member = JsonMember::cast(node)?;
+        member.name().ok()?.inner_string_text().ok()
+    });
+
+    keys.next().is_some()
+        && keys
+            .next()
+            .is_some_and(|key| matches!(key.text(), "rules" | "actions"))
+}
+
+/// Returns the first paragraph of the documentation of a rule.
+fn rule_summary(metadata: &RuleMetadata) -> String {
+    metadata
+        .docs
+        .trim_start()
+        .lines()
+        .map(str::trim)
+        .take_while(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns the documentation of a rule as Markdown.
+///
+/// The attributes of the code blocks, such as `expect_diagnostic`, are only
+/// meaningful to the website, so they're removed.
+fn rule_documentation(metadata: &RuleMetadata) -> String {
+    let mut documentation = format!("## {}\n\n", metadata.name);
+    for line in metadata.docs.lines() {
+        let line = line.strip_prefix(' ').unwrap_or(line);
+        match line.strip_prefix("```") {
+            Some(info) => {
+                documentation.push_str("```");
+                documentation.push_str(info.split(',').next().unwrap_or_default());
+            }
+            None => documentation.push_str(line),
+        }
+        documentation.push('\n');
+    }
+
+    documentation
+}
@@ -0,0 +1,457 @@
+use super::{
+    AnalyzerCapabilities, Capabilities, DebugCapabilities, DocumentFileSource, EditorCapabilities,
+    EnabledForPath, ExtensionHandler, Features, FixAllParams, FormatterCapabilities, LintParams,
+    LintResults, ParseResult, ParserCapabilities, SearchCapabilities, syntax_selection_range,
+};
+use crate::settings::{check_feature_activity, check_override_feature_activity};
+use crate::workspace::FixFileResult;
+use crate::{
+    WorkspaceError,
+    settings::{ServiceLanguage, Settings, WorkspaceSettingsHandle},
+    workspace::GetSyntaxTreeResult,
+};
+use biome_analyze::AnalyzerOptions;
+use biome_configuration::markdown::{MarkdownFormatterConfiguration, MarkdownFormatterEnabled};
+use biome_css_syntax::CssLanguage;
+use biome_diagnostics::{Diagnostic, Severity};
+use biome_formatter::{
+    FormatContext, FormatOptions, FormatResult, Formatted, IndentStyle, IndentWidth, LineEnding,
+    LineWidth, Printed,
+};
+use biome_fs::BiomePath;
+use biome_graphql_syntax::GraphqlLanguage;
+use biome_js_syntax::{EmbeddingKind, JsLanguage};
+use biome_json_syntax::JsonLanguage;
+use biome_markdown_formatter::context::{
+    EmbeddedFormatter, MarkdownFormatContext, MarkdownFormatOptions, ProseWrap,
+};
+use biome_markdown_formatter::format_node_with_embedded_formatter;
+use biome_markdown_parser::parse_markdown_with_cache;
+use biome_markdown_syntax::{MarkdownLanguage, MarkdownSyntaxNode, MdDocument};
+use biome_parser::AnyParse;
+use biome_rowan::{AstNode, NodeCache};
+use biome_string_case::StrLikeExtension;
+use camino::Utf8Path;
+use std::rc::Rc;
+use tracing::debug_span;
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MarkdownFormatterSettings {
+    pub enabled: Option<MarkdownFormatterEnabled>,
+    pub line_ending: Option<LineEnding>,
+    pub line_width: Option<LineWidth>,
+    pub indent_width: Option<IndentWidth>,
+    pub indent_style: Option<IndentStyle>,
+    pub prose_wrap: Option<ProseWrap>,
+}
+
+impl From<MarkdownFormatterConfiguration> for MarkdownFormatterSettings {
+    fn from(config: MarkdownFormatterConfiguration) -> Self {
+        Self {
+            enabled: config.enabled,
+            line_ending: config.line_ending,
+            line_width: config.line_width,
+            indent_width: config.indent_width,
+            indent_style: config.indent_style,
+            prose_wrap: config.prose_wrap,
+        }
+    }
+}
+
+impl ServiceLanguage for MarkdownLanguage {
+    type FormatterSettings = MarkdownFormatterSettings;
+    type LinterSettings = ();
+    type FormatOptions = MarkdownFormatOptions;
+    type ParserSettings = ();
+    type EnvironmentSettings = ();
+    type AssistSettings = ();
+
+    fn lookup_settings(
+        languages: &crate::settings::LanguageListSettings,
+    ) -> &crate::settings::LanguageSettings<Self> {
+        &languages.markdown
+    }
+
+    fn resolve_format_options(
+        global: Option<&crate::settings::FormatSettings>,
+        overrides: Option<&crate::settings::OverrideSettings>,
+        language: Option<&Self::FormatterSettings>,
+        path: &biome_fs::BiomePath,
+        file_source: &super::DocumentFileSource,
+    ) -> Self::FormatOptions {
+        let indent_style = language
+            .and_then(|l| l.indent_style)
+            .or(global.and_then(|g| g.indent_style))
+            .unwrap_or_default();
+        let line_width = language
+            .and_then(|l| l.line_width)
+            .or(global.and_then(|g| g.line_width))
+            .unwrap_or_default();
+        let indent_width = language
+            .and_then(|l| l.indent_width)
+            .or(global.and_then(|g| g.indent_width))
+            .unwrap_or_default();
+        let line_ending = language
+            .and_then(|l| l.line_ending)
+            .or(global.and_then(|g| g.line_ending))
+            .unwrap_or_default();
+        let prose_wrap = language.and_then(|l| l.prose_wrap).unwrap_or_default();
+
+        let options =
+            MarkdownFormatOptions::new(file_source.to_markdown_file_source().unwrap_or_default())
+                .with_indent_style(indent_style)
+                .with_indent_width(indent_width)
+                .with_line_width(line_width)
+                .with_line_ending(line_ending)
+                .with_prose_wrap(prose_wrap);
+        if let Some(overrides) = overrides {
+            overrides.to_override_markdown_format_options(path, options)
+        } else {
+            options
+        }
+    }
+
+    fn resolve_analyzer_options(
+        _global: Option<&Settings>,
+        _language: Option<&Self::LinterSettings>,
+        _environment: Option<&Self::EnvironmentSettings>,
+        path: &biome_fs::BiomePath,
+        _file_source: &super::DocumentFileSource,
+        suppression_reason: Option<&str>,
+    ) -> AnalyzerOptions {
+        AnalyzerOptions::default()
+            .with_file_path(path.as_path())
+            .with_suppression_reason(suppression_reason)
+    }
+
+    fn formatter_enabled_for_file_path(settings: Option<&Settings>, path: &Utf8Path) -> bool {
+        settings
+            .and_then(|settings| {
+                let overrides_activity =
+                    settings
+                        .override_settings
+                        .patterns
+                        .iter()
+                        .rev()
+                        .find_map(|pattern| {
+                            check_override_feature_activity(
+                                pattern.languages.markdown.formatter.enabled,
+                                pattern.formatter.enabled,
+                            )
+                            .filter(|_| {
+                                // Then check whether the path satisfies
+                                pattern.is_file_included(path)
+                            })
+                        });
+
+                overrides_activity.or(check_feature_activity(
+                    settings.languages.markdown.formatter.enabled,
+                    settings.formatter.enabled,
+                ))
+            })
+            .unwrap_or_default()
+            .into()
+    }
+
+    fn assist_enabled_for_file_path(_settings: Option<&Settings>, _path: &Utf8Path) -> bool {
+        false
+    }
+
+    fn linter_enabled_for_file_path(_settings: Option<&Settings>, _path: &Utf8Path) -> bool {
+        false
+    }
+
+    fn resolve_environment(_settings: Option<&Settings>) -> Option<&Self::EnvironmentSettings> {
+        None
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct MarkdownFileHandler;
+
+impl ExtensionHandler for MarkdownFileHandler {
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            enabled_for_path: EnabledForPath {
+                formatter: Some(formatter_enabled),
+                linter: Some(linter_enabled),
+                assist: Some(assist_enabled),
+                search: Some(search_enabled),
+            },
+            parser: ParserCapabilities { parse: Some(parse) },
+            debug: DebugCapabilities {
+                debug_syntax_tree: Some(debug_syntax_tree),
+                debug_control_flow: None,
+                debug_formatter_ir: Some(debug_formatter_ir),
+                debug_type_info: None,
+                debug_registered_types: None,
+                debug_semantic_model: None,
+            },
+            analyzer: AnalyzerCapabilities {
+                lint: Some(lint),
+                code_actions: None,
+                rename: None,
+                prepare_rename: None,
+                fix_all: Some(fix_all),
+            },
+            formatter: FormatterCapabilities {
+                format: Some(format),
+                format_range: None,
+                format_on_type: None,
+            },
+            search: SearchCapabilities { search: None },
+            editor: EditorCapabilities {
+                hover: None,
+                goto_definition: None,
+                find_references: None,
+                document_symbols: None,
+                semantic_tokens: None,
+                folding_ranges: None,
+                selection_range: Some(syntax_selection_range::<MarkdownLanguage>),
+                inlay_hints: None,
+                completions: None,
+                linked_editing_ranges: None,
+            },
+        }
+    }
+}
+
+fn formatter_enabled(path: &Utf8Path, handle: &WorkspaceSettingsHandle) -> bool {
+    handle.formatter_enabled_for_file_path::<MarkdownLanguage>(path)
+}
+
+fn linter_enabled(path: &Utf8Path, handle: &WorkspaceSettingsHandle) -> bool {
+    handle.linter_enabled_for_file_path::<MarkdownLanguage>(path)
+}
+
+fn assist_enabled(path: &Utf8Path, handle: &WorkspaceSettingsHandle) -> bool {
+    handle.assist_enabled_for_file_path::<MarkdownLanguage>(path)
+}
+
+fn search_enabled(_path: &Utf8Path, _handle: &WorkspaceSettingsHandle) -> bool {
+    false
+}
+
+fn parse(
+    _biome_path: &BiomePath,
+    file_source: DocumentFileSource,
+    text: &str,
+    _handle: WorkspaceSettingsHandle,
+    cache: &mut NodeCache,
+) -> ParseResult {
+    let parse = parse_markdown_with_cache(text, cache);
+
+    ParseResult {
+        any_parse: parse.into(),
+        language: Some(file_source),
+    }
+}
+
+fn debug_syntax_tree(_biome_path: &BiomePath, parse: AnyParse) -> GetSyntaxTreeResult {
+    let syntax: MarkdownSyntaxNode = parse.syntax();
+    let tree: MdDocument = parse.tree();
+    GetSyntaxTreeResult {
+        cst: format!("{syntax:#?}"),
+        ast: format!("{tree:#?}"),
+    }
+}
+
+fn debug_formatter_ir(
+    path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<String, WorkspaceError> {
+    let tree = parse.syntax();
+    let formatted = format_markdown(path, document_file_source, settings, &tree)?;
+
+    let root_element = formatted.into_document();
+    Ok(root_element.to_string())
+}
+
+#[tracing::instrument(level = "debug", skip(parse, settings))]
+fn format(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<Printed, WorkspaceError> {
+    let tree = parse.syntax();
+    let formatted = format_markdown(biome_path, document_file_source, settings, &tree)?;
+
+    match formatted.print() {
+        Ok(printed) => Ok(printed),
+        Err(error) => Err(WorkspaceError::FormatError(error.into())),
+    }
+}
+
+/// Formats a Markdown document, and the code of its fenced code blocks with the settings of
+/// their language.
+fn format_markdown(
+    biome_path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    settings: WorkspaceSettingsHandle,
+    root: &MarkdownSyntaxNode,
+) -> FormatResult<Formatted<MarkdownFormatContext>> {
+    let options = settings.format_options::<MarkdownLanguage>(biome_path, document_file_source);
+    let embedded_formatter = EmbeddedCodeFormatter {
+        path: biome_path.clone(),
+        settings,
+    };
+    format_node_with_embedded_formatter(options, Rc::new(embedded_formatter), root)
+}
+
+/// Formats the code of the fenced code blocks with the file handler of their language, using
+/// the settings that apply to the Markdown document.
+#[derive(Debug)]
+struct EmbeddedCodeFormatter {
+    path: BiomePath,
+    settings: WorkspaceSettingsHandle,
+}
+
+impl EmbeddedFormatter for EmbeddedCodeFormatter {
+    fn format(&self, info: &str, code: &str, prefix_width: usize) -> Option<String> {
+        let file_source = embedded_file_source(info)?;
+        let capabilities = Features::new().get_capabilities(file_source);
+        let formatter_enabled = capabilities.enabled_for_path.formatter?;
+        if !formatter_enabled(&self.path, &self.settings) {
+            return None;
+        }
+
+        let parse = capabilities.parser.parse?(
+            &self.path,
+            file_source,
+            code,
+            self.settings.settings().cloned().into(),
+            &mut NodeCache::default(),
+        )
+        .any_parse;
+        if parse.has_errors() {
+            return None;
+        }
+
+        match file_source {
+            DocumentFileSource::Js(_) => {
+                let options = self
+                    .settings
+                    .format_options::<JsLanguage>(&self.path, &file_source);
+                let line_width = embedded_line_width(options.line_width(), prefix_width);
+                print(biome_js_formatter::format_node(
+                    options.with_line_width(line_width),
+                    &parse.syntax(),
+                ))
+            }
+            DocumentFileSource::Json(_) => {
+                let options = self
+                    .settings
+                    .format_options::<JsonLanguage>(&self.path, &file_source);
+                let line_width = embedded_line_width(options.line_width(), prefix_width);
+                print(biome_json_formatter::format_node(
+                    options.with_line_width(line_width),
+                    &parse.syntax(),
+                ))
+            }
+            DocumentFileSource::Css(_) => {
+                let options = self
+                    .settings
+                    .format_options::<CssLanguage>(&self.path, &file_source);
+                let line_width = embedded_line_width(options.line_width(), prefix_width);
+                print(biome_css_formatter::format_node(
+                    options.with_line_width(line_width),
+                    &parse.syntax(),
+                ))
+            }
+            DocumentFileSource::Graphql(_) => {
+                let options = self
+                    .settings
+                    .format_options::<GraphqlLanguage>(&self.path, &file_source);
+                let line_width = embedded_line_width(options.line_width(), prefix_width);
+                print(biome_graphql_formatter::format_node(
+                    options.with_line_width(line_width),
+                    &parse.syntax(),
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Returns the file source of the language named by the first word of the info string of a
+/// fenced code block, if its code can be formatted.
+fn embedded_file_source(info: &str) -> Option<DocumentFileSource> {
+    let name = info.split_whitespace().next()?.to_ascii_lowercase_cow();
+    let extension = match name.as_ref() {
+        "javascript" => "js",
+        "typescript" => "ts",
+        "gql" => "graphql",
+        name => name,
+    };
+
+    match DocumentFileSource::from_extension(extension) {
+        DocumentFileSource::Js(file_source)
+            if matches!(file_source.as_embedding_kind(), EmbeddingKind::None) =>
+        {
+            Some(file_source.into())
+        }
+        file_source @ (DocumentFileSource::Json(_)
+        | DocumentFileSource::Css(_)
+        | DocumentFileSource::Graphql(_)) => Some(file_source),
+        _ => None,
+    }
+}
+
+/// Returns the line width left to the code of a block whose lines start with `prefix_width`
+/// columns of container markers.
+fn embedded_line_width(line_width: LineWidth, prefix_width: usize) -> LineWidth {
+    let width = usize::from(line_width.value()).saturating_sub(prefix_width);
+    LineWidth::try_from(width.max(1) as u16).unwrap_or_default()
+}
+
+fn print<Context: FormatContext>(formatted: FormatResult<Formatted<Context>>) -> Option<String> {
+    Some(formatted.ok()?.print().ok()?.into_code())
+}
+
+#[tracing::instrument(level = "debug", skip(params))]
+fn lint(params: LintParams) -> LintResults {
+    let _ = debug_span!("Linting Markdown file", path =? params.path, language =? params.language)
+        .entered();
+    let diagnostics = params.parse.into_diagnostics();
+
+    let diagnostic_count = diagnostics.len() as u32;
+    let skipped_diagnostics = diagnostic_count.saturating_sub(diagnostics.len() as u32);
+    let errors = diagnostics
+        .iter()
+        .filter(|diag| diag.severity() <= Severity::Error)
+        .count();
+
+    LintResults {
+        diagnostics,
+        errors,
+        skipped_diagnostics,
+    }
+}
+
+#[tracing::instrument(level = "debug", skip(params))]
+pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
+    // We don't have analyzer rules yet
+    let tree: MdDocument = params.parse.tree();
+    let code = if params.should_format {
+        format_markdown(
+            params.biome_path,
+            &params.document_file_source,
+            params.workspace,
+            tree.syntax(),
+        )?
+        .print()?
+        .into_code()
+    } else {
+        tree.syntax().to_string()
+    };
+    Ok(FixFileResult {
+        code,
+        skipped_suggested_fixes: 0,
+        actions: vec![],
+        errors: 0,
+    })
+}
@@ -10,7 +10,9 @@ pub use crate::file_handlers::svelte::{SVELTE_FENCE, SvelteFileHandler};
 pub use crate::file_handlers::vue::{VUE_FENCE, VueFileHandler};
 use crate::settings::{Settings, WorkspaceSettingsHandle};
 use crate::workspace::{
-    FixFileMode, FixFileResult, GetSyntaxTreeResult, PullActionsResult, RenameResult,
+    CompletionItem, DocumentSymbol, FixFileMode, FixFileResult, FoldingRange, FoldingRangeKind,
+    GetSyntaxTreeResult, HoverResult, InlayHint, PullActionsResult, RenameResult, SearchRewrite,
+    SemanticToken, SymbolLocation,
 };
 use biome_analyze::{
     AnalyzerDiagnostic, AnalyzerOptions, AnalyzerPluginVec, AnalyzerSignal, ControlFlow,
@@ -38,11 +40,18 @@ use biome_js_syntax::{
 };
 use biome_json_analyze::METADATA as json_metadata;
 use biome_json_syntax::{JsonFileSource, JsonLanguage};
+use biome_markdown_syntax::MarkdownFileSource;
 use biome_module_graph::ModuleGraph;
 use biome_parser::AnyParse;
 use biome_project_layout::ProjectLayout;
-use biome_rowan::{FileSourceError, NodeCache};
+use biome_resolver::FsWithResolverProxy;
+use biome_rowan::{
+    Direction, FileSourceError, NodeCache, SyntaxNode, SyntaxToken, TokenAtOffset,
+    syntax::SyntaxTrivia,
+};
 use biome_string_case::StrLikeExtension;
+use biome_yaml_analyze::METADATA as yaml_metadata;
+use biome_yaml_syntax::{YamlFileSource, YamlLanguage};
 
 use crate::file_handlers::ignore::IgnoreFileHandler;
 use biome_configuration::vcs::{GIT_IGNORE_FILE_NAME, IGNORE_FILE_NAME};
@@ -51,10 +60,12 @@ use camino::Utf8Path;
 use grit::GritFileHandler;
 use html::HtmlFileHandler;
 pub use javascript::JsFormatterSettings;
+use markdown::MarkdownFileHandler;
 use rustc_hash::FxHashSet;
 use std::borrow::Cow;
 use std::sync::Arc;
 use tracing::instrument;
+use yaml::YamlFileHandler;
 
 mod astro;
 pub(crate) mod css;
@@ -64,9 +75,11 @@ pub(crate) mod html;
 mod ignore;
 pub(crate) mod javascript;
 pub(crate) mod json;
+pub(crate) mod markdown;
 mod svelte;
 mod unknown;
 mod vue;
+pub(crate) mod yaml;
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(
@@ -79,6 +92,8 @@ pub enum DocumentFileSource {
     Graphql(GraphqlFileSource),
     Html(HtmlFileSource),
     Grit(GritFileSource),
+    Markdown(MarkdownFileSource),
+    Yaml(YamlFileSource),
     // Ignore files
     Ignore,
     #[default]
@@ -121,6 +136,18 @@ impl From<GritFileSource> for DocumentFileSource {
     }
 }
 
+impl From<MarkdownFileSource> for DocumentFileSource {
+    fn from(value: MarkdownFileSource) -> Self {
+        Self::Markdown(value)
+    }
+}
+
+impl From<YamlFileSource> for DocumentFileSource {
+    fn from(value: YamlFileSource) -> Self {
+        Self::Yaml(value)
+    }
+}
+
 impl From<&Utf8Path> for DocumentFileSource {
     fn from(path: &Utf8Path) -> Self {
         Self::from_path(path)
@@ -141,6 +168,9 @@ impl DocumentFileSource {
         if let Ok(file_source) = GraphqlFileSource::try_from_well_known(path) {
             return Ok(file_source.into());
         }
+        if let Ok(file_source) = YamlFileSource::try_from_well_known(path) {
+            return Ok(file_source.into());
+        }
 
         Err(FileSourceError::UnknownFileName)
     }
@@ -169,6 +199,12 @@ impl DocumentFileSource {
         if let Ok(file_source) = GritFileSource::try_from_extension(extension) {
             return Ok(file_source.into());
         }
+        if let Ok(file_source) = MarkdownFileSource::try_from_extension(extension) {
+            return Ok(file_source.into());
+        }
+        if let Ok(file_source) = YamlFileSource::try_from_extension(extension) {
+            return Ok(file_source.into());
+        }
         Err(FileSourceError::UnknownExtension)
     }
 
@@ -197,6 +233,12 @@ impl DocumentFileSource {
         if let Ok(file_source) = GritFileSource::try_from_language_id(language_id) {
             return Ok(file_source.into());
         }
+        if let Ok(file_source) = MarkdownFileSource::try_from_language_id(language_id) {
+            return Ok(file_source.into());
+        }
+        if let Ok(file_source) = YamlFileSource::try_from_language_id(language_id) {
+            return Ok(file_source.into());
+        }
         Err(FileSourceError::UnknownLanguageId)
     }
 
@@ -332,12 +374,32 @@ impl DocumentFileSource {
         }
     }
 
+    pub fn to_markdown_file_source(&self) -> Option<MarkdownFileSource> {
+        match self {
+            Self::Markdown(markdown) => Some(*markdown),
+            _ => None,
+        }
+    }
+
+    pub fn to_yaml_file_source(&self) -> Option<YamlFileSource> {
+        match self {
+            Self::Yaml(yaml) => Some(*yaml),
+            _ => None,
+        }
+    }
+
     /// The file can be parsed
     pub fn can_parse(path: &Utf8Path) -> bool {
         let file_source = Self::from(path);
         match file_source {
             Self::Js(_) => true,
-            Self::Css(_) | Self::Graphql(_) | Self::Json(_) | Self::Html(_) | Self::Grit(_) => true,
+            Self::Css(_)
+            | Self::Graphql(_)
+            | Self::Json(_)
+            | Self::Html(_)
+            | Self::Grit(_)
+            | Self::Markdown(_)
+            | Self::Yaml(_) => true,
             Self::Ignore => false,
             Self::Unknown => false,
         }
@@ -352,7 +414,9 @@ impl DocumentFileSource {
             | Self::Graphql(_)
             | Self::Json(_)
             | Self::Html(_)
-            | Self::Grit(_) => true,
+            | Self::Grit(_)
+            | Self::Markdown(_)
+            | Self::Yaml(_) => true,
             Self::Ignore => true,
             Self::Unknown => false,
         }
@@ -387,6 +451,8 @@ impl biome_console::fmt::Display for DocumentFileSource {
             Self::Graphql(_) => fmt.write_markup(markup! { "GraphQL" }),
             Self::Html(_) => fmt.write_markup(markup! { "HTML" }),
             Self::Grit(_) => fmt.write_markup(markup! { "Grit" }),
+            Self::Markdown(_) => fmt.write_markup(markup! { "Markdown" }),
+            Self::Yaml(_) => fmt.write_markup(markup! { "YAML" }),
             Self::Ignore => fmt.write_markup(markup! { "Ignore" }),
             Self::Unknown => fmt.write_markup(markup! { "Unknown" }),
         }
@@ -419,6 +485,7 @@ pub struct Capabilities {
     pub(crate) analyzer: AnalyzerCapabilities,
     pub(crate) formatter: FormatterCapabilities,
     pub(crate) search: SearchCapabilities,
+    pub(crate) editor: EditorCapabilities,
     pub(crate) enabled_for_path: EnabledForPath,
 }
 
@@ -486,6 +553,8 @@ pub(crate) struct LintParams<'a> {
     pub(crate) enabled_rules: Vec<RuleSelector>,
     pub(crate) plugins: AnalyzerPluginVec,
     pub(crate) pull_code_actions: bool,
+    /// When set, only the signals overlapping this range are emitted
+    pub(crate) range: Option<TextRange>,
 }
 
 pub(crate) struct LintResults {
@@ -620,7 +689,9 @@ pub(crate) struct CodeActionsParams<'a> {
 type Lint = fn(LintParams) -> LintResults;
 type CodeActions = fn(CodeActionsParams) -> PullActionsResult;
 type FixAll = fn(FixAllParams) -> Result<FixFileResult, WorkspaceError>;
-type Rename = fn(&BiomePath, AnyParse, TextSize, String) -> Result<RenameResult, WorkspaceError>;
+type Rename =
+    fn(EditorParams, ParseForPath, TextSize, String) -> Result<RenameResult, WorkspaceError>;
+type PrepareRename = fn(AnyParse, TextSize) -> Option<TextRange>;
 
 #[derive(Default)]
 pub struct AnalyzerCapabilities {
@@ -630,8 +701,11 @@ pub struct AnalyzerCapabilities {
     pub(crate) code_actions: Option<CodeActions>,
     /// Applies fixes to a file
     pub(crate) fix_all: Option<FixAll>,
-    /// It renames a binding inside a file
+    /// It renames a binding, as well as its imports in other files
     pub(crate) rename: Option<Rename>,
+    /// It returns the range of the binding that would be renamed at a given
+    /// offset
+    pub(crate) prepare_rename: Option<PrepareRename>,
 }
 
 type Format = fn(
@@ -665,6 +739,288 @@ pub(crate) struct FormatterCapabilities {
     pub(crate) format_on_type: Option<FormatOnType>,
 }
 
+/// Parameters shared by the capabilities that serve editor features, such as
+/// hover information.
+pub(crate) struct EditorParams<'a> {
+    pub(crate) parse: AnyParse,
+    pub(crate) path: &'a BiomePath,
+    pub(crate) module_graph: Arc<ModuleGraph>,
+    pub(crate) project_layout: Arc<ProjectLayout>,
+    pub(crate) fs: &'a dyn FsWithResolverProxy,
+    pub(crate) settings: WorkspaceSettingsHandle,
+}
+
+/// Returns the parse of another file of the project, if it's loaded in the
+/// workspace.
+pub(crate) type ParseForPath<'a> = &'a dyn Fn(&Utf8Path) -> Option<AnyParse>;
+
+type Hover = fn(EditorParams, TextSize) -> HoverResult;
+type GotoDefinition = fn(EditorParams, TextSize) -> Vec<SymbolLocation>;
+type FindReferences = fn(EditorParams, TextSize, bool) -> Vec<SymbolLocation>;
+type DocumentSymbols = fn(EditorParams) -> Vec<DocumentSymbol>;
+type SemanticTokens = fn(EditorParams, Option<TextRange>) -> Vec<SemanticToken>;
+type FoldingRanges = fn(EditorParams) -> Vec<FoldingRange>;
+type SelectionRange = fn(EditorParams, TextSize) -> Vec<TextRange>;
+type InlayHints = fn(EditorParams, Option<TextRange>) -> Vec<InlayHint>;
+type Completions = fn(EditorParams, TextSize) -> Vec<CompletionItem>;
+type LinkedEditingRanges = fn(EditorParams, TextSize) -> Vec<TextRange>;
+
+#[derive(Default)]
+pub(crate) struct EditorCapabilities {
+    /// It returns information about the node at a given offset
+    pub(crate) hover: Option<Hover>,
+    /// It returns the definitions of the symbol at a given offset
+    pub(crate) goto_definition: Option<GotoDefinition>,
+    /// It returns the references to the symbol at a given offset
+    pub(crate) find_references: Option<FindReferences>,
+    /// It returns the symbols declared in a file, as a hierarchy
+    pub(crate) document_symbols: Option<DocumentSymbols>,
+    /// It returns the semantic tokens of a file, optionally limited to a range
+    pub(crate) semantic_tokens: Option<SemanticTokens>,
+    /// It returns the ranges of a file that can be folded
+    pub(crate) folding_ranges: Option<FoldingRanges>,
+    /// It returns the ranges that contain a given offset, from the innermost
+    pub(crate) selection_range: Option<SelectionRange>,
+    /// It returns the inlay hints of a file, optionally limited to a range
+    pub(crate) inlay_hints: Option<InlayHints>,
+    /// It returns the completions at a given offset
+    pub(crate) completions: Option<Completions>,
+    /// It returns the ranges that are edited together with the one at a given offset
+    pub(crate) linked_editing_ranges: Option<LinkedEditingRanges>,
+}
+
+/// Turns a flat list of symbols into a hierarchy, where each symbol becomes a
+/// child of the innermost symbol whose range contains it.
+pub(crate) fn nest_document_symbols(mut symbols: Vec<DocumentSymbol>) -> Vec<DocumentSymbol> {
+    // Outer symbols come first when symbols start at the same offset.
+    symbols.sort_by(|a, b| {
+        a.range
+            .start()
+            .cmp(&b.range.start())
+            .then_with(|| b.range.end().cmp(&a.range.end()))
+    });
+
+    fn collect_children(
+        symbols: &mut std::iter::Peekable<std::vec::IntoIter<DocumentSymbol>>,
+        parent_range: Option<TextRange>,
+    ) -> Vec<DocumentSymbol> {
+        let mut children = Vec::new();
+        while let Some(mut symbol) = symbols.next_if(|symbol| {
+            parent_range.is_none_or(|parent_range| parent_range.contains_range(symbol.range))
+        }) {
+            symbol
+                .children
+                .extend(collect_children(symbols, Some(symbol.range)));
+            children.push(symbol);
+        }
+        children
+    }
+
+    collect_children(&mut symbols.into_iter().peekable(), None)
+}
+
+/// Returns the ranges of a syntax tree that can be folded.
+///
+/// These are the contents between matching brackets, the children of the
+/// nodes for which `is_element` returns `true`, block comments, consecutive
+/// line comments, and the contents between `#region` and `#endregion`
+/// comments.
+pub(crate) fn syntax_folding_ranges<L: biome_rowan::Language>(
+    root: &SyntaxNode<L>,
+    is_element: impl Fn(L::Kind) -> bool,
+) -> Vec<FoldingRange> {
+    let mut ranges = Vec::new();
+    let mut push_range = |start: TextSize, end: TextSize, kind: Option<FoldingRangeKind>| {
+        if start < end {
+            ranges.push(FoldingRange {
+                range: TextRange::new(start, end),
+                kind,
+            });
+        }
+    };
+
+    for node in root.descendants() {
+        if is_element(node.kind()) {
+            let closing_token = node.last_child().and_then(|child| child.first_token());
+            if let (Some(first_token), Some(closing_token)) = (node.first_token(), closing_token) {
+                push_range(
+                    first_token.text_trimmed_range().start(),
+                    content_end(&closing_token),
+                    None,
+                );
+            }
+            continue;
+        }
+
+        let mut open_brackets: Vec<SyntaxToken<L>> = Vec::new();
+        for token in node
+            .children_with_tokens()
+            .filter_map(|element| element.into_token())
+        {
+            match token.text_trimmed() {
+                "{" | "[" | "(" => open_brackets.push(token),
+                close @ ("}" | "]" | ")") => {
+                    let is_matching = open_brackets.last().is_some_and(|open| {
+                        matches!(
+                            (open.text_trimmed(), close),
+                            ("{", "}") | ("[", "]") | ("(", ")")
+                        )
+                    });
+                    if let Some(open) = open_brackets.pop().filter(|_| is_matching) {
+                        push_range(open.text_trimmed_range().start(), content_end(&token), None);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut regions = Vec::new();
+    // Range of the current group of line comments, and its number of comments
+    let mut comment_group: Option<(TextRange, usize)> = None;
+    let mut newlines = 0;
+    for token in root.descendants_tokens(Direction::Next) {
+        let leading = token.leading_trivia().pieces().map(|piece| (piece, true));
+        let trailing = token.trailing_trivia().pieces().map(|piece| (piece, false));
+        for (piece, is_leading) in leading.chain(trailing) {
+            if piece.is_newline() {
+                newlines += 1;
+                continue;
+            }
+            if piece.is_whitespace() {
+                continue;
+            }
+
+            let range = piece.text_range();
+            let is_line_comment = is_leading
+                && piece.kind().is_single_line_comment()
+                && region_marker(piece.text()).is_none();
+            if is_line_comment && newlines <= 1 {
+                if let Some((group_range, count)) = comment_group.as_mut() {
+                    *group_range = group_range.cover(range);
+                    *count += 1;
+                    newlines = 0;
+                    continue;
+                }
+            }
+            if let Some((group_range, count)) = comment_group.take() {
+                if count > 1 {
+                    push_range(
+                        group_range.start(),
+                        group_range.end(),
+                        Some(FoldingRangeKind::Comment),
+                    );
+                }
+            }
+            newlines = 0;
+
+            match region_marker(piece.text()) {
+                Some(RegionMarker::Start) => regions.push(range.start()),
+                Some(RegionMarker::End) => {
+                    if let Some(start) = regions.pop() {
+                        push_range(start, range.end(), Some(FoldingRangeKind::Region));
+                    }
+                }
+                None if is_line_comment => comment_group = Some((range, 1)),
+                None if piece.kind().is_multiline_comment() => {
+                    push_range(range.start(), range.end(), Some(FoldingRangeKind::Comment));
+                }
+                None => {}
+            }
+        }
+
+        if let Some((group_range, count)) = comment_group.take() {
+            if count > 1 {
+                push_range(
+                    group_range.start(),
+                    group_range.end(),
+                    Some(FoldingRangeKind::Comment),
+                );
+            }
+        }
+        newlines = 0;
+    }
+
+    let text = root.text_with_trivia();
+    ranges.retain(|folding_range| text.slice(folding_range.range).contains_char('\n'));
+    ranges.sort_by_key(|folding_range| folding_range.range.start());
+    ranges
+}
+
+/// Returns the end of the content that precedes `token`, including its
+/// comments, so that the line of `token` stays visible once folded.
+fn content_end<L: biome_rowan::Language>(token: &SyntaxToken<L>) -> TextSize {
+    let last_comment_end = |trivia: SyntaxTrivia<L>| {
+        trivia
+            .pieces()
+            .rev()
+            .find(|piece| piece.is_comments())
+            .map(|piece| piece.text_range().end())
+    };
+
+    last_comment_end(token.leading_trivia())
+        .or_else(|| {
+            let previous = token.prev_token()?;
+            last_comment_end(previous.trailing_trivia())
+                .or(Some(previous.text_trimmed_range().end()))
+        })
+        .unwrap_or_else(|| token.text_trimmed_range().start())
+}
+
+enum RegionMarker {
+    Start,
+    End,
+}
+
+/// Recognizes the comments that start or end a region, such as
+/// `// #region` or `/* #endregion */`.
+fn region_marker(comment: &str) -> Option<RegionMarker> {
+    let text = comment
+        .trim_start_matches(['/', '*', '<', '!', '-'])
+        .trim_start();
+    if text.starts_with("#region") {
+        Some(RegionMarker::Start)
+    } else if text.starts_with("#endregion") {
+        Some(RegionMarker::End)
+    } else {
+        None
+    }
+}
+
+/// Returns the ranges of the token at `offset` and of its ancestors, from the
+/// innermost to the outermost.
+pub(crate) fn syntax_selection_range<L: biome_rowan::Language + 'static>(
+    params: EditorParams,
+    offset: TextSize,
+) -> Vec<TextRange> {
+    let root = params.parse.syntax::<L>();
+    let is_word = |token: &SyntaxToken<L>| {
+        token
+            .text_trimmed()
+            .starts_with(|c: char| c.is_alphanumeric() || c == '_' || c == '$')
+    };
+    let token = match root.token_at_offset(offset) {
+        TokenAtOffset::None => return Vec::new(),
+        TokenAtOffset::Single(token) => token,
+        TokenAtOffset::Between(left, right) => {
+            if is_word(&right) || !is_word(&left) {
+                right
+            } else {
+                left
+            }
+        }
+    };
+
+    let mut ranges: Vec<TextRange> = Vec::new();
+    let ancestors = token.ancestors().map(|node| node.text_trimmed_range());
+    for range in std::iter::once(token.text_trimmed_range()).chain(ancestors) {
+        if range.contains_inclusive(offset) && ranges.last() != Some(&range) {
+            ranges.push(range);
+        }
+    }
+    ranges
+}
+
 type Enabled = fn(&Utf8Path, &WorkspaceSettingsHandle) -> bool;
 
 type Search = fn(
@@ -673,7 +1029,15 @@ type Search = fn(
     AnyParse,
     &GritQuery,
     WorkspaceSettingsHandle,
-) -> Result<Vec<TextRange>, WorkspaceError>;
+) -> Result<SearchOutput, WorkspaceError>;
+
+/// The result of a search in a file
+#[derive(Debug, Default)]
+pub(crate) struct SearchOutput {
+    pub(crate) matches: Vec<TextRange>,
+    pub(crate) rewrite: Option<SearchRewrite>,
+    pub(crate) conflicts: Vec<TextRange>,
+}
 
 #[derive(Default)]
 pub(crate) struct SearchCapabilities {
@@ -709,6 +1073,8 @@ pub(crate) struct Features {
     graphql: GraphqlFileHandler,
     html: HtmlFileHandler,
     grit: GritFileHandler,
+    markdown: MarkdownFileHandler,
+    yaml: YamlFileHandler,
     ignore: IgnoreFileHandler,
 }
 
@@ -724,6 +1090,8 @@ impl Features {
             graphql: GraphqlFileHandler {},
             html: HtmlFileHandler {},
             grit: GritFileHandler {},
+            markdown: MarkdownFileHandler {},
+            yaml: YamlFileHandler {},
             ignore: IgnoreFileHandler {},
             unknown: UnknownFileHandler::default(),
         }
@@ -743,6 +1111,8 @@ impl Features {
             DocumentFileSource::Graphql(_) => self.graphql.capabilities(),
             DocumentFileSource::Html(_) => self.html.capabilities(),
             DocumentFileSource::Grit(_) => self.grit.capabilities(),
+            DocumentFileSource::Markdown(_) => self.markdown.capabilities(),
+            DocumentFileSource::Yaml(_) => self.yaml.capabilities(),
             DocumentFileSource::Ignore => self.ignore.capabilities(),
             DocumentFileSource::Unknown => self.unknown.capabilities(),
         }
@@ -832,24 +1202,56 @@ pub(crate) fn search(
     parse: AnyParse,
     query: &GritQuery,
     _settings: WorkspaceSettingsHandle,
-) -> Result<Vec<TextRange>, WorkspaceError> {
+) -> Result<SearchOutput, WorkspaceError> {
     let result = query
         .execute(GritTargetFile::new(path.as_path(), parse))
         .map_err(|err| {
             WorkspaceError::SearchError(SearchError::QueryError(QueryDiagnostic(err.to_string())))
         })?;
 
-    let matches = result
-        .effects
-        .into_iter()
-        .flat_map(|result| match result {
+    let mut output = SearchOutput {
+        conflicts: result
+            .conflicts
+            .into_iter()
+            .map(|conflict| {
+                let start = conflict.range.start.min(conflict.conflicting_range.start);
+                let end = conflict.range.end.max(conflict.conflicting_range.end);
+                TextRange::new(TextSize::from(start as u32), TextSize::from(end as u32))
+            })
+            .collect(),
+        ..Default::default()
+    };
+    for effect in result.effects {
+        let ranges = match effect {
             GritQueryEffect::Match(m) => m.ranges,
-            _ => Vec::new(),
-        })
-        .map(|range| TextRange::new(range.start_byte.into(), range.end_byte.into()))
-        .collect();
+            GritQueryEffect::Rewrite(rewrite) => {
+                output.rewrite = Some(SearchRewrite {
+                    ranges: rewrite
+                        .rewritten
+                        .byte_ranges
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(|range| {
+                            TextRange::new(
+                                TextSize::from(range.start as u32),
+                                TextSize::from(range.end as u32),
+                            )
+                        })
+                        .collect(),
+                    content: rewrite.rewritten.content,
+                });
+                rewrite.original.ranges
+            }
+            GritQueryEffect::CreateFile(_) => Vec::new(),
+        };
+        output.matches.extend(
+            ranges
+                .into_iter()
+                .map(|range| TextRange::new(range.start_byte.into(), range.end_byte.into())),
+        );
+    }
 
-    Ok(matches)
+    Ok(output)
 }
 
 #[test]
@@ -966,6 +1368,25 @@ impl RegistryVisitor<GraphqlLanguage> for SyntaxVisitor<'_> {
     }
 }
 
+impl RegistryVisitor<YamlLanguage> for SyntaxVisitor<'_> {
+    fn record_category<C: GroupCategory<Language = YamlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Syntax {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = YamlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.enabled_rules.push(RuleFilter::Rule(
+            <R::Group as RuleGroup>::NAME,
+            R::METADATA.name,
+        ))
+    }
+}
+
 /// Type meant to register all the lint rules for each language supported by Biome
 ///
 #[derive(Debug)]
@@ -1271,6 +1692,30 @@ impl RegistryVisitor<GraphqlLanguage> for LintVisitor<'_, '_> {
     }
 }
 
+impl RegistryVisitor<YamlLanguage> for LintVisitor<'_, '_> {
+    fn record_category<C: GroupCategory<Language = YamlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Lint {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_group<G: RuleGroup<Language = YamlLanguage>>(&mut self) {
+        G::record_rules(self)
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = YamlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.push_rule::<R, <R::Query as Queryable>::Language>(
+            yaml_metadata
+                .find_rule(R::Group::NAME, R::METADATA.name)
+                .map(RuleFilter::from),
+        )
+    }
+}
+
 struct AssistsVisitor<'a, 'b> {
     settings: Option<&'b Settings>,
     enabled_rules: Vec<RuleFilter<'a>>,
@@ -1405,6 +1850,22 @@ impl RegistryVisitor<GraphqlLanguage> for AssistsVisitor<'_, '_> {
     }
 }
 
+impl RegistryVisitor<YamlLanguage> for AssistsVisitor<'_, '_> {
+    fn record_category<C: GroupCategory<Language = YamlLanguage>>(&mut self) {
+        if C::CATEGORY == RuleCategory::Action {
+            C::record_groups(self)
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule<Options: Default, Query: Queryable<Language = YamlLanguage, Output: Clone>>
+            + 'static,
+    {
+        self.push_rule::<R, <R::Query as Queryable>::Language>();
+    }
+}
+
 pub(crate) struct AnalyzerVisitorBuilder<'a> {
     settings: Option<&'a Settings>,
     only: Option<&'a [RuleSelector]>,
@@ -1477,6 +1938,7 @@ impl<'b> AnalyzerVisitorBuilder<'b> {
         biome_css_analyze::visit_registry(&mut syntax);
         biome_json_analyze::visit_registry(&mut syntax);
         biome_graphql_analyze::visit_registry(&mut syntax);
+        biome_yaml_analyze::visit_registry(&mut syntax);
         enabled_rules.extend(syntax.enabled_rules);
 
         let package_json = self
@@ -1497,6 +1959,7 @@ impl<'b> AnalyzerVisitorBuilder<'b> {
         biome_css_analyze::visit_registry(&mut lint);
         biome_json_analyze::visit_registry(&mut lint);
         biome_graphql_analyze::visit_registry(&mut lint);
+        biome_yaml_analyze::visit_registry(&mut lint);
         let (linter_enabled_rules, linter_disabled_rules) = lint.finish();
         enabled_rules.extend(linter_enabled_rules);
         disabled_rules.extend(linter_disabled_rules);
@@ -1507,6 +1970,7 @@ impl<'b> AnalyzerVisitorBuilder<'b> {
         biome_css_analyze::visit_registry(&mut assist);
         biome_json_analyze::visit_registry(&mut assist);
         biome_graphql_analyze::visit_registry(&mut assist);
+        biome_yaml_analyze::visit_registry(&mut assist);
         let (assists_enabled_rules, assists_disabled_rules) = assist.finish();
         enabled_rules.extend(assists_enabled_rules);
         disabled_rules.extend(assists_disabled_rules);
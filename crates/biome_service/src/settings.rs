@@ -8,9 +8,11 @@ use biome_configuration::diagnostics::InvalidIgnorePattern;
 use biome_configuration::formatter::{FormatWithErrorsEnabled, FormatterEnabled};
 use biome_configuration::html::HtmlConfiguration;
 use biome_configuration::javascript::JsxRuntime;
+use biome_configuration::markdown::MarkdownConfiguration;
 use biome_configuration::max_size::MaxSize;
 use biome_configuration::plugins::Plugins;
 use biome_configuration::vcs::{VcsClientKind, VcsConfiguration, VcsEnabled, VcsUseIgnoreFile};
+use biome_configuration::yaml::YamlConfiguration;
 use biome_configuration::{
     BiomeDiagnostic, Configuration, CssConfiguration, FilesConfiguration,
     FilesIgnoreUnknownEnabled, FormatterConfiguration, GraphqlConfiguration, GritConfiguration,
@@ -39,6 +41,10 @@ use biome_js_syntax::JsLanguage;
 use biome_json_formatter::context::JsonFormatOptions;
 use biome_json_parser::JsonParserOptions;
 use biome_json_syntax::JsonLanguage;
+use biome_markdown_formatter::context::MarkdownFormatOptions;
+use biome_markdown_syntax::MarkdownLanguage;
+use biome_yaml_formatter::context::YamlFormatOptions;
+use biome_yaml_syntax::YamlLanguage;
 use camino::{Utf8Path, Utf8PathBuf};
 use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use std::borrow::Cow;
@@ -159,6 +165,14 @@ impl Settings {
         if let Some(html) = configuration.html {
             self.languages.html = html.into()
         }
+        // markdown settings
+        if let Some(markdown) = configuration.markdown {
+            self.languages.markdown = markdown.into()
+        }
+        // yaml settings
+        if let Some(yaml) = configuration.yaml {
+            self.languages.yaml = yaml.into()
+        }
 
         // plugin settings
         if let Some(plugins) = configuration.plugins {
@@ -458,6 +472,8 @@ pub struct LanguageListSettings {
     pub graphql: LanguageSettings<GraphqlLanguage>,
     pub html: LanguageSettings<HtmlLanguage>,
     pub grit: LanguageSettings<GritLanguage>,
+    pub markdown: LanguageSettings<MarkdownLanguage>,
+    pub yaml: LanguageSettings<YamlLanguage>,
 }
 
 impl From<JsConfiguration> for LanguageSettings<JsLanguage> {
@@ -598,6 +614,32 @@ impl From<HtmlConfiguration> for LanguageSettings<HtmlLanguage> {
     }
 }
 
+impl From<MarkdownConfiguration> for LanguageSettings<MarkdownLanguage> {
+    fn from(markdown: MarkdownConfiguration) -> Self {
+        let mut language_setting: Self = Self::default();
+        if let Some(formatter) = markdown.formatter {
+            language_setting.formatter = formatter.into();
+        }
+
+        language_setting
+    }
+}
+
+impl From<YamlConfiguration> for LanguageSettings<YamlLanguage> {
+    fn from(yaml: YamlConfiguration) -> Self {
+        let mut language_setting: Self = Self::default();
+        if let Some(formatter) = yaml.formatter {
+            language_setting.formatter = formatter.into();
+        }
+
+        if let Some(linter) = yaml.linter {
+            language_setting.linter = linter.into();
+        }
+
+        language_setting
+    }
+}
+
 pub trait ServiceLanguage: biome_rowan::Language {
     /// Formatter settings type for this language
     type FormatterSettings: Default;
@@ -1153,6 +1195,32 @@ impl OverrideSettings {
         options
     }
 
+    pub fn to_override_markdown_format_options(
+        &self,
+        path: &Utf8Path,
+        mut options: MarkdownFormatOptions,
+    ) -> MarkdownFormatOptions {
+        for pattern in self.patterns.iter() {
+            if pattern.is_file_included(path) {
+                pattern.apply_overrides_to_markdown_format_options(&mut options);
+            }
+        }
+        options
+    }
+
+    pub fn to_override_yaml_format_options(
+        &self,
+        path: &Utf8Path,
+        mut options: YamlFormatOptions,
+    ) -> YamlFormatOptions {
+        for pattern in self.patterns.iter() {
+            if pattern.is_file_included(path) {
+                pattern.apply_overrides_to_yaml_format_options(&mut options);
+            }
+        }
+        options
+    }
+
     pub fn to_override_js_parser_options(
         &self,
         path: &Utf8Path,
@@ -1285,6 +1353,11 @@ impl OverrideSettings {
                         biome_graphql_analyze::METADATA.deref(),
                         &mut analyzer_rules,
                     );
+                    push_to_analyzer_rules(
+                        rules,
+                        biome_yaml_analyze::METADATA.deref(),
+                        &mut analyzer_rules,
+                    );
                 }
 
                 if let Some(actions) = pattern.assist.actions.as_ref() {
@@ -1308,6 +1381,11 @@ impl OverrideSettings {
                         biome_graphql_analyze::METADATA.deref(),
                         &mut analyzer_rules,
                     );
+                    push_to_analyzer_assist(
+                        actions,
+                        biome_yaml_analyze::METADATA.deref(),
+                        &mut analyzer_rules,
+                    );
                 }
             }
         }
@@ -1529,6 +1607,49 @@ impl OverrideSettingPattern {
         // #endregion
     }
 
+    fn apply_overrides_to_markdown_format_options(&self, options: &mut MarkdownFormatOptions) {
+        let markdown_formatter = &self.languages.markdown.formatter;
+        let formatter = &self.formatter;
+
+        if let Some(indent_style) = markdown_formatter.indent_style.or(formatter.indent_style) {
+            options.set_indent_style(indent_style);
+        }
+        if let Some(indent_width) = markdown_formatter.indent_width.or(formatter.indent_width) {
+            options.set_indent_width(indent_width)
+        }
+        if let Some(line_ending) = markdown_formatter.line_ending.or(formatter.line_ending) {
+            options.set_line_ending(line_ending);
+        }
+        if let Some(line_width) = markdown_formatter.line_width.or(formatter.line_width) {
+            options.set_line_width(line_width);
+        }
+        if let Some(prose_wrap) = markdown_formatter.prose_wrap {
+            options.set_prose_wrap(prose_wrap);
+        }
+    }
+
+    fn apply_overrides_to_yaml_format_options(&self, options: &mut YamlFormatOptions) {
+        let yaml_formatter = &self.languages.yaml.formatter;
+        let formatter = &self.formatter;
+
+        if let Some(indent_width) = yaml_formatter.indent_width.or(formatter.indent_width) {
+            options.set_indent_width(indent_width)
+        }
+        if let Some(line_ending) = yaml_formatter.line_ending.or(formatter.line_ending) {
+            options.set_line_ending(line_ending);
+        }
+        if let Some(line_width) = yaml_formatter.line_width.or(formatter.line_width) {
+            options.set_line_width(line_width);
+        }
+        if let Some(quote_style) = yaml_formatter.quote_style {
+            options.set_quote_style(quote_style);
+        }
+        if let Some(bracket_spacing) = yaml_formatter.bracket_spacing.or(formatter.bracket_spacing)
+        {
+            options.set_bracket_spacing(bracket_spacing);
+        }
+    }
+
     fn apply_overrides_to_js_parser_options(&self, options: &mut JsParserOptions) {
         let js_parser = &self.languages.javascript.parser;
 
@@ -1622,6 +1743,8 @@ pub fn to_override_settings(
         let graphql = pattern.graphql.take().unwrap_or_default();
         let grit = pattern.grit.take().unwrap_or_default();
         let html = pattern.html.take().unwrap_or_default();
+        let markdown = pattern.markdown.take().unwrap_or_default();
+        let yaml = pattern.yaml.take().unwrap_or_default();
 
         languages.javascript =
             to_javascript_language_settings(javascript, &current_settings.languages.javascript);
@@ -1632,6 +1755,9 @@ pub fn to_override_settings(
             to_graphql_language_settings(graphql, &current_settings.languages.graphql);
         languages.grit = to_grit_language_settings(grit, &current_settings.languages.grit);
         languages.html = to_html_language_settings(html, &current_settings.languages.html);
+        languages.markdown =
+            to_markdown_language_settings(markdown, &current_settings.languages.markdown);
+        languages.yaml = to_yaml_language_settings(yaml, &current_settings.languages.yaml);
 
         let pattern_setting = OverrideSettingPattern {
             includes: OverrideIncludes::new(working_directory.clone(), pattern.includes),
@@ -1773,6 +1899,33 @@ fn to_html_language_settings(
     language_setting
 }
 
+fn to_markdown_language_settings(
+    mut conf: MarkdownConfiguration,
+    _parent_settings: &LanguageSettings<MarkdownLanguage>,
+) -> LanguageSettings<MarkdownLanguage> {
+    let mut language_setting: LanguageSettings<MarkdownLanguage> = LanguageSettings::default();
+    let formatter = conf.formatter.take().unwrap_or_default();
+
+    language_setting.formatter = formatter.into();
+
+    language_setting
+}
+
+fn to_yaml_language_settings(
+    mut conf: YamlConfiguration,
+    _parent_settings: &LanguageSettings<YamlLanguage>,
+) -> LanguageSettings<YamlLanguage> {
+    let mut language_setting: LanguageSettings<YamlLanguage> = LanguageSettings::default();
+    let formatter = conf.formatter.take().unwrap_or_default();
+
+    language_setting.formatter = formatter.into();
+
+    let linter = conf.linter.take().unwrap_or_default();
+    language_setting.linter.enabled = linter.enabled;
+
+    language_setting
+}
+
 pub fn to_format_settings(
     working_directory: Option<Utf8PathBuf>,
     conf: FormatterConfiguration,
@@ -709,6 +709,45 @@ pub struct GetFileContentParams {
     pub path: BiomePath,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetModuleDependenciesParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetModuleDependenciesResult {
+    /// The paths of the modules imported by the file, directly or through
+    /// other modules, sorted by path
+    pub dependencies: Vec<BiomePath>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetFileConfigurationParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetFileConfigurationResult {
+    /// The configuration that applies to the file, with the configurations
+    /// that it extends merged into it
+    pub configuration: Configuration,
+    /// The folder of the configuration file that applies to the file, if any
+    pub directory: Option<BiomePath>,
+    /// The indexes of the `overrides` of the configuration that match the
+    /// file, in the order in which they apply
+    pub overrides: Vec<usize>,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
@@ -765,6 +804,9 @@ pub struct PullDiagnosticsParams {
     pub enabled_rules: Vec<RuleSelector>,
     /// When `false` the diagnostics, don't have code frames of the code actions (fixes, suppressions, etc.)
     pub pull_code_actions: bool,
+    /// When set, only the diagnostics overlapping this range are computed
+    #[serde(default)]
+    pub range: Option<TextRange>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -776,6 +818,30 @@ pub struct PullDiagnosticsResult {
     pub skipped_diagnostics: u64,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct PullProjectDiagnosticsParams {
+    pub project_key: ProjectKey,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct PullProjectDiagnosticsResult {
+    /// The diagnostics of every file of the project that can be linted,
+    /// including the files without diagnostics.
+    pub files: Vec<ProjectFileDiagnostics>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectFileDiagnostics {
+    pub path: BiomePath,
+    pub diagnostics: Vec<biome_diagnostics::serde::Diagnostic>,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
@@ -912,6 +978,422 @@ pub struct RenameResult {
     pub range: TextRange,
     /// List of text edit operations to apply on the source code
     pub indels: TextEdit,
+    /// Text edit operations to apply on other files, such as the modules that
+    /// import the renamed symbol
+    pub other_files: Vec<RenameFileEdits>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct RenameFileEdits {
+    /// Path of the file to modify
+    pub path: BiomePath,
+    /// Range of source code modified by this rename operation
+    pub range: TextRange,
+    /// List of text edit operations to apply on the source code
+    pub indels: TextEdit,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct PrepareRenameParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+    pub symbol_at: TextSize,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct PrepareRenameResult {
+    /// Range of the symbol that would be renamed, if the symbol at the given
+    /// position can be renamed
+    pub range: Option<TextRange>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct HoverParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+    pub offset: TextSize,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct HoverResult {
+    /// Range of the node the information refers to
+    pub range: Option<TextRange>,
+    /// List of Markdown snippets describing the node
+    pub contents: Vec<String>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GotoDefinitionParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+    pub offset: TextSize,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GotoDefinitionResult {
+    /// Locations where the symbol is defined
+    pub definitions: Vec<SymbolLocation>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct FindReferencesParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+    pub offset: TextSize,
+    /// Whether the declaration of the symbol should be part of the result
+    pub include_declaration: bool,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct FindReferencesResult {
+    /// Locations where the symbol is referenced, sorted by path and range
+    pub references: Vec<SymbolLocation>,
+}
+
+#[derive(Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolLocation {
+    /// Path of the file that contains the symbol
+    pub path: BiomePath,
+    /// Range of the symbol inside the file
+    pub range: TextRange,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentSymbolsParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentSymbolsResult {
+    /// Symbols declared at the top level of the document
+    pub symbols: Vec<DocumentSymbol>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentSymbol {
+    /// Name of the symbol
+    pub name: String,
+    /// Kind of the symbol
+    pub kind: SymbolKind,
+    /// Range of the whole declaration of the symbol
+    pub range: TextRange,
+    /// Range of the name of the symbol
+    pub selection_range: TextRange,
+    /// Symbols declared inside this symbol
+    pub children: Vec<DocumentSymbol>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum SymbolKind {
+    Array,
+    Boolean,
+    Class,
+    Constant,
+    Constructor,
+    Enum,
+    EnumMember,
+    Field,
+    Function,
+    Interface,
+    Method,
+    Module,
+    Namespace,
+    Null,
+    Number,
+    Object,
+    Property,
+    String,
+    Struct,
+    TypeParameter,
+    Variable,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceSymbolsParams {
+    pub project_key: ProjectKey,
+    /// Text used to filter the symbols by name. An empty query matches all
+    /// the symbols.
+    pub query: String,
+    /// Maximum number of symbols to return
+    pub limit: usize,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceSymbolsResult {
+    /// Symbols matching the query, sorted by path and range. The number of
+    /// symbols is capped by the limit, so large projects may have more matches.
+    pub symbols: Vec<WorkspaceSymbol>,
+}
+
+#[derive(Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceSymbol {
+    /// Name of the symbol
+    pub name: String,
+    /// Kind of the symbol
+    pub kind: SymbolKind,
+    /// Path of the file that contains the symbol
+    pub path: BiomePath,
+    /// Range of the name of the symbol inside the file
+    pub range: TextRange,
+    /// Name of the symbol that contains this symbol, if any
+    pub container_name: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticTokensParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+    /// If specified, only the tokens that intersect this range are returned
+    pub range: Option<TextRange>,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticTokensResult {
+    /// Tokens of the document, sorted by range
+    pub tokens: Vec<SemanticToken>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticToken {
+    /// Range of the token
+    pub range: TextRange,
+    /// Kind of the symbol the token refers to
+    pub kind: SemanticTokenKind,
+    /// Additional information about the token
+    pub modifiers: Vec<SemanticTokenModifier>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum SemanticTokenKind {
+    Class,
+    Directive,
+    Enum,
+    EnumMember,
+    Fragment,
+    Function,
+    Interface,
+    Namespace,
+    Parameter,
+    Type,
+    TypeParameter,
+    Variable,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum SemanticTokenModifier {
+    /// The token declares the symbol
+    Declaration,
+    /// The token refers to a global that isn't declared in the file, such as
+    /// a built-in or a global from the configuration
+    Global,
+    /// The symbol is imported from another module
+    Imported,
+    /// The symbol can't be reassigned
+    Readonly,
+    /// The symbol has the same name as a symbol of an enclosing scope
+    Shadowing,
+    /// The token refers to a symbol that isn't declared anywhere
+    Unresolved,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct FoldingRangesParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct FoldingRangesResult {
+    /// Ranges that can be folded, sorted by their start
+    pub ranges: Vec<FoldingRange>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct FoldingRange {
+    /// Range of the folded content. It always spans multiple lines, and it
+    /// ends before the line of the closing delimiter, if there's any.
+    pub range: TextRange,
+    pub kind: Option<FoldingRangeKind>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum FoldingRangeKind {
+    /// A block comment, or consecutive line comments
+    Comment,
+    /// The content between `#region` and `#endregion` comments
+    Region,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SelectionRangeParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+    pub offset: TextSize,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SelectionRangeResult {
+    /// Ranges that contain the offset, from the innermost to the outermost
+    pub ranges: Vec<TextRange>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct CompletionsParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+    pub offset: TextSize,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct CompletionsResult {
+    pub items: Vec<CompletionItem>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct CompletionItem {
+    /// Text shown in the list of completions
+    pub label: String,
+    pub kind: CompletionItemKind,
+    /// Text inserted in place of `range` when the completion is accepted
+    pub insert_text: String,
+    /// Range of the text replaced by the completion, such as a partially
+    /// typed key. It's empty when the completion is inserted at the offset.
+    pub range: TextRange,
+    /// Markdown documentation of the item
+    pub documentation: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum CompletionItemKind {
+    /// The key of an object
+    Property,
+    /// A value, such as a variant of an enumeration
+    Value,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct LinkedEditingRangesParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+    pub offset: TextSize,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct LinkedEditingRangesResult {
+    /// Ranges that have the same content and are edited together, such as
+    /// the names of an opening and a closing tag. It's empty if the offset
+    /// isn't inside any of these ranges.
+    pub ranges: Vec<TextRange>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct InlayHintsParams {
+    pub project_key: ProjectKey,
+    pub path: BiomePath,
+    /// If specified, only the hints within this range are returned
+    pub range: Option<TextRange>,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct InlayHintsResult {
+    /// Hints of the document, sorted by offset
+    pub hints: Vec<InlayHint>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct InlayHint {
+    /// Offset where the hint is displayed
+    pub offset: TextSize,
+    /// Text of the hint, such as `: number` for types or `name:` for
+    /// parameters
+    pub label: String,
+    pub kind: InlayHintKind,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum InlayHintKind {
+    /// The inferred type of a binding, or the inferred return type of a
+    /// function
+    Type,
+    /// The name of the parameter that receives an argument
+    Parameter,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -1012,6 +1494,22 @@ pub struct SearchPatternParams {
 pub struct SearchResults {
     pub path: BiomePath,
     pub matches: Vec<TextRange>,
+    /// The file after applying the rewrites of the pattern, if it has any
+    /// and they change the file.
+    pub rewrite: Option<SearchRewrite>,
+    /// The ranges of the code targeted by conflicting rewrites, which
+    /// prevent the file from being rewritten
+    pub conflicts: Vec<TextRange>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SearchRewrite {
+    /// The new content of the file
+    pub content: String,
+    /// The ranges of the rewritten code in the new content
+    pub ranges: Vec<TextRange>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -1275,6 +1773,23 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// Returns the content of a given file.
     fn get_file_content(&self, params: GetFileContentParams) -> Result<String, WorkspaceError>;
 
+    /// Returns the modules that a file depends on, according to the module
+    /// graph built by the scanner.
+    ///
+    /// The result is empty if the project wasn't scanned for the module graph.
+    fn get_module_dependencies(
+        &self,
+        params: GetModuleDependenciesParams,
+    ) -> Result<GetModuleDependenciesResult, WorkspaceError>;
+
+    /// Returns the configuration that applies to a file, which is the root
+    /// configuration of the project or the nested configuration of the folder
+    /// of the file, and the overrides that match the file.
+    fn get_file_configuration(
+        &self,
+        params: GetFileConfigurationParams,
+    ) -> Result<GetFileConfigurationResult, WorkspaceError>;
+
     /// Returns the size of a given file, as well as the allowed maximum file
     /// size for that file.
     fn check_file_size(
@@ -1312,6 +1827,92 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// Returns the content of the file after renaming a symbol.
     fn rename(&self, params: RenameParams) -> Result<RenameResult, WorkspaceError>;
 
+    /// Checks whether the symbol at a given position can be renamed, and
+    /// returns its range.
+    fn prepare_rename(
+        &self,
+        params: PrepareRenameParams,
+    ) -> Result<PrepareRenameResult, WorkspaceError>;
+
+    /// Returns information about the symbol or expression at a given
+    /// position, such as its inferred type.
+    fn hover(&self, params: HoverParams) -> Result<HoverResult, WorkspaceError>;
+
+    /// Returns the locations where the symbol at a given position is defined,
+    /// following imports and re-exports across files.
+    fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<GotoDefinitionResult, WorkspaceError>;
+
+    /// Returns all the locations where the symbol at a given position is
+    /// referenced, including the modules that import it.
+    fn find_references(
+        &self,
+        params: FindReferencesParams,
+    ) -> Result<FindReferencesResult, WorkspaceError>;
+
+    /// Returns the symbols declared in a document, such as functions, classes
+    /// or CSS rules, as a hierarchy.
+    fn document_symbols(
+        &self,
+        params: DocumentSymbolsParams,
+    ) -> Result<DocumentSymbolsResult, WorkspaceError>;
+
+    /// Returns the symbols declared across all the documents of the project
+    /// whose name matches a query.
+    fn workspace_symbols(
+        &self,
+        params: WorkspaceSymbolsParams,
+    ) -> Result<WorkspaceSymbolsResult, WorkspaceError>;
+
+    /// Returns the semantic tokens of a document, classified using the
+    /// semantic model of its language.
+    fn semantic_tokens(
+        &self,
+        params: SemanticTokensParams,
+    ) -> Result<SemanticTokensResult, WorkspaceError>;
+
+    /// Retrieves the diagnostics of the rules that belong to the `project`
+    /// domain, for all the files of a project indexed by the scanner.
+    ///
+    /// Unlike [Workspace::pull_diagnostics], the files don't need to be
+    /// opened by the client.
+    fn pull_project_diagnostics(
+        &self,
+        params: PullProjectDiagnosticsParams,
+    ) -> Result<PullProjectDiagnosticsResult, WorkspaceError>;
+
+    /// Returns the ranges of a document that can be folded, such as blocks,
+    /// comments and regions.
+    fn folding_ranges(
+        &self,
+        params: FoldingRangesParams,
+    ) -> Result<FoldingRangesResult, WorkspaceError>;
+
+    /// Returns the ranges of the syntax nodes that contain an offset, which
+    /// are used to expand the selection in editors.
+    fn selection_range(
+        &self,
+        params: SelectionRangeParams,
+    ) -> Result<SelectionRangeResult, WorkspaceError>;
+
+    /// Returns the inlay hints of a document, such as the inferred types of
+    /// the bindings and the names of the parameters at call sites.
+    fn inlay_hints(&self, params: InlayHintsParams) -> Result<InlayHintsResult, WorkspaceError>;
+
+    /// Returns the completions at an offset, such as the keys and values
+    /// accepted by the Biome configuration files.
+    fn completions(&self, params: CompletionsParams) -> Result<CompletionsResult, WorkspaceError>;
+
+    /// Returns the ranges that are edited together with the one at an offset,
+    /// such as the name of the closing tag of an element when its opening
+    /// tag is renamed.
+    fn linked_editing_ranges(
+        &self,
+        params: LinkedEditingRangesParams,
+    ) -> Result<LinkedEditingRangesResult, WorkspaceError>;
+
     /// Closes a file that is opened in the workspace.
     ///
     /// This only unloads the document from the workspace if the file is NOT
@@ -1470,6 +2071,7 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
             skip,
             enabled_rules: vec![],
             pull_code_actions,
+            range: None,
         })
     }
 
@@ -9,7 +9,7 @@ use biome_configuration::{
 };
 use biome_diagnostics::Diagnostic;
 use biome_fs::{BiomePath, MemoryFileSystem};
-use biome_js_syntax::{JsFileSource, TextSize};
+use biome_js_syntax::{JsFileSource, TextRange, TextSize};
 use camino::Utf8PathBuf;
 use insta::{assert_debug_snapshot, assert_snapshot};
 
@@ -21,7 +21,7 @@ use super::{
     CloseFileParams, CloseProjectParams, FileContent, FileFeaturesResult, FileGuard,
     GetFileContentParams, GetSyntaxTreeParams, OpenFileParams, OpenProjectParams,
     OpenProjectResult, PullDiagnosticsParams, ScanKind, ScanProjectFolderParams,
-    UpdateSettingsParams, server,
+    UpdateSettingsParams, WorkspaceSymbolsParams, server,
 };
 
 fn create_server() -> (Box<dyn Workspace>, ProjectKey) {
@@ -285,6 +285,103 @@ fn correctly_pulls_lint_diagnostics() {
     assert_eq!(diagnostics.len(), 1)
 }
 
+#[test]
+fn pulls_only_lint_diagnostics_in_range() {
+    let (workspace, project_key) = create_server();
+
+    let js_file = FileGuard::open(
+        workspace.as_ref(),
+        OpenFileParams {
+            project_key,
+            path: BiomePath::new("file.js"),
+            content: FileContent::from_client("debugger;\nconst a = 1;\ndebugger;\n"),
+            document_file_source: None,
+            persist_node_cache: false,
+        },
+    )
+    .unwrap();
+
+    let pull_diagnostics = |range| {
+        workspace
+            .pull_diagnostics(PullDiagnosticsParams {
+                project_key,
+                path: BiomePath::new("file.js"),
+                categories: RuleCategories::all(),
+                only: vec![RuleSelector::Rule(
+                    RuleGroup::Suspicious.as_str(),
+                    "noDebugger",
+                )],
+                skip: Vec::new(),
+                enabled_rules: Vec::new(),
+                pull_code_actions: false,
+                range,
+            })
+            .unwrap()
+            .diagnostics
+    };
+
+    assert_eq!(pull_diagnostics(None).len(), 2);
+
+    let diagnostics =
+        pull_diagnostics(Some(TextRange::new(TextSize::from(24), TextSize::from(26))));
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].location().span,
+        Some(TextRange::new(TextSize::from(23), TextSize::from(32)))
+    );
+
+    let diagnostics =
+        pull_diagnostics(Some(TextRange::new(TextSize::from(12), TextSize::from(14))));
+    assert!(diagnostics.is_empty());
+
+    drop(js_file);
+}
+
+#[test]
+fn workspace_symbols_are_limited() {
+    let mut fs = MemoryFileSystem::default();
+    let content: String = (0..300).map(|i| format!("const a{i} = {i};\n")).collect();
+    fs.insert(Utf8PathBuf::from("/project/a.js"), content.as_bytes());
+    fs.insert(Utf8PathBuf::from("/project/b.js"), b"const a = 1;\n");
+
+    let workspace = server(Box::new(fs), None);
+    let OpenProjectResult { project_key, .. } = workspace
+        .open_project(OpenProjectParams {
+            path: Utf8PathBuf::from("/project").into(),
+            open_uninitialized: true,
+            only_rules: None,
+            skip_rules: None,
+        })
+        .unwrap();
+
+    workspace
+        .scan_project_folder(ScanProjectFolderParams {
+            project_key,
+            path: None,
+            watch: false,
+            force: false,
+            scan_kind: ScanKind::Project,
+        })
+        .unwrap();
+
+    let result = workspace
+        .workspace_symbols(WorkspaceSymbolsParams {
+            project_key,
+            query: "a".to_string(),
+            limit: 100,
+        })
+        .unwrap();
+    assert_eq!(result.symbols.len(), 100);
+    assert!(
+        result
+            .symbols
+            .iter()
+            .all(|symbol| symbol.path.as_str() == "/project/a.js")
+    );
+    assert_eq!(result.symbols[0].name, "a0");
+    assert_eq!(result.symbols[99].name, "a99");
+}
+
 #[test]
 fn pull_grit_debug_info() {
     let (workspace, project_key) = create_server();
@@ -501,6 +598,7 @@ fn plugins_are_loaded_and_used_during_analysis() {
             skip: Vec::new(),
             enabled_rules: Vec::new(),
             pull_code_actions: true,
+            range: None,
         })
         .unwrap();
     assert_debug_snapshot!(result.diagnostics);
@@ -570,6 +668,7 @@ language css;
             skip: Vec::new(),
             enabled_rules: Vec::new(),
             pull_code_actions: true,
+            range: None,
         })
         .unwrap();
     assert_debug_snapshot!(result.diagnostics);
@@ -635,6 +734,7 @@ fn plugins_may_use_invalid_span() {
             skip: Vec::new(),
             enabled_rules: Vec::new(),
             pull_code_actions: true,
+            range: None,
         })
         .unwrap();
     assert_debug_snapshot!(result.diagnostics);
@@ -755,6 +855,7 @@ const hasOwn = Object.hasOwn({ foo: 'bar' }, 'foo');"#,
                 skip: Vec::new(),
                 enabled_rules: Vec::new(),
                 pull_code_actions: true,
+                range: None,
             })
             .unwrap();
         // Filter only diagnostics with category name "plugin"
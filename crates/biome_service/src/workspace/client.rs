@@ -1,16 +1,24 @@
 use super::{
-    ChangeFileParams, CloseFileParams, FileExitsParams, FixFileParams, FixFileResult,
-    FormatFileParams, FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams,
-    GetFormatterIRParams, GetSemanticModelParams, GetSyntaxTreeParams, GetSyntaxTreeResult,
-    OpenFileParams, PullActionsParams, PullActionsResult, PullDiagnosticsParams,
-    PullDiagnosticsResult, RenameParams, RenameResult, ScanProjectFolderParams,
-    ScanProjectFolderResult, SearchPatternParams, SearchResults, SupportsFeatureParams,
-    UpdateSettingsParams, UpdateSettingsResult,
+    ChangeFileParams, CloseFileParams, CompletionsParams, CompletionsResult, DocumentSymbolsParams,
+    DocumentSymbolsResult, FileExitsParams, FindReferencesParams, FindReferencesResult,
+    FixFileParams, FixFileResult, FoldingRangesParams, FoldingRangesResult, FormatFileParams,
+    FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams,
+    GetSemanticModelParams, GetSyntaxTreeParams, GetSyntaxTreeResult, GotoDefinitionParams,
+    GotoDefinitionResult, HoverParams, HoverResult, InlayHintsParams, InlayHintsResult,
+    LinkedEditingRangesParams, LinkedEditingRangesResult, OpenFileParams, PrepareRenameParams,
+    PrepareRenameResult, PullActionsParams, PullActionsResult, PullDiagnosticsParams,
+    PullDiagnosticsResult, PullProjectDiagnosticsParams, PullProjectDiagnosticsResult,
+    RenameParams, RenameResult, ScanProjectFolderParams, ScanProjectFolderResult,
+    SearchPatternParams, SearchResults, SelectionRangeParams, SelectionRangeResult,
+    SemanticTokensParams, SemanticTokensResult, SupportsFeatureParams, UpdateSettingsParams,
+    UpdateSettingsResult, WorkspaceSymbolsParams, WorkspaceSymbolsResult,
 };
 use crate::workspace::{
     CheckFileSizeParams, CheckFileSizeResult, CloseProjectParams, FileFeaturesResult,
-    GetFileContentParams, GetRegisteredTypesParams, GetTypeInfoParams, IsPathIgnoredParams,
-    OpenProjectParams, OpenProjectResult, RageParams, RageResult, ServerInfo,
+    GetFileConfigurationParams, GetFileConfigurationResult, GetFileContentParams,
+    GetModuleDependenciesParams, GetModuleDependenciesResult, GetRegisteredTypesParams,
+    GetTypeInfoParams, IsPathIgnoredParams, OpenProjectParams, OpenProjectResult, RageParams,
+    RageResult, ServerInfo,
 };
 use crate::{TransportError, Workspace, WorkspaceError};
 use biome_formatter::Printed;
@@ -185,6 +193,20 @@ where
         self.request("biome/get_file_content", params)
     }
 
+    fn get_module_dependencies(
+        &self,
+        params: GetModuleDependenciesParams,
+    ) -> Result<GetModuleDependenciesResult, WorkspaceError> {
+        self.request("biome/get_module_dependencies", params)
+    }
+
+    fn get_file_configuration(
+        &self,
+        params: GetFileConfigurationParams,
+    ) -> Result<GetFileConfigurationResult, WorkspaceError> {
+        self.request("biome/get_file_configuration", params)
+    }
+
     fn check_file_size(
         &self,
         params: CheckFileSizeParams,
@@ -227,6 +249,88 @@ where
         self.request("biome/rename", params)
     }
 
+    fn prepare_rename(
+        &self,
+        params: PrepareRenameParams,
+    ) -> Result<PrepareRenameResult, WorkspaceError> {
+        self.request("biome/prepare_rename", params)
+    }
+
+    fn hover(&self, params: HoverParams) -> Result<HoverResult, WorkspaceError> {
+        self.request("biome/hover", params)
+    }
+
+    fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<GotoDefinitionResult, WorkspaceError> {
+        self.request("biome/goto_definition", params)
+    }
+
+    fn find_references(
+        &self,
+        params: FindReferencesParams,
+    ) -> Result<FindReferencesResult, WorkspaceError> {
+        self.request("biome/find_references", params)
+    }
+
+    fn document_symbols(
+        &self,
+        params: DocumentSymbolsParams,
+    ) -> Result<DocumentSymbolsResult, WorkspaceError> {
+        self.request("biome/document_symbols", params)
+    }
+
+    fn workspace_symbols(
+        &self,
+        params: WorkspaceSymbolsParams,
+    ) -> Result<WorkspaceSymbolsResult, WorkspaceError> {
+        self.request("biome/workspace_symbols", params)
+    }
+
+    fn semantic_tokens(
+        &self,
+        params: SemanticTokensParams,
+    ) -> Result<SemanticTokensResult, WorkspaceError> {
+        self.request("biome/semantic_tokens", params)
+    }
+
+    fn pull_project_diagnostics(
+        &self,
+        params: PullProjectDiagnosticsParams,
+    ) -> Result<PullProjectDiagnosticsResult, WorkspaceError> {
+        self.request("biome/pull_project_diagnostics", params)
+    }
+
+    fn folding_ranges(
+        &self,
+        params: FoldingRangesParams,
+    ) -> Result<FoldingRangesResult, WorkspaceError> {
+        self.request("biome/folding_ranges", params)
+    }
+
+    fn selection_range(
+        &self,
+        params: SelectionRangeParams,
+    ) -> Result<SelectionRangeResult, WorkspaceError> {
+        self.request("biome/selection_range", params)
+    }
+
+    fn inlay_hints(&self, params: InlayHintsParams) -> Result<InlayHintsResult, WorkspaceError> {
+        self.request("biome/inlay_hints", params)
+    }
+
+    fn completions(&self, params: CompletionsParams) -> Result<CompletionsResult, WorkspaceError> {
+        self.request("biome/completions", params)
+    }
+
+    fn linked_editing_ranges(
+        &self,
+        params: LinkedEditingRangesParams,
+    ) -> Result<LinkedEditingRangesResult, WorkspaceError> {
+        self.request("biome/linked_editing_ranges", params)
+    }
+
     fn close_file(&self, params: CloseFileParams) -> Result<(), WorkspaceError> {
         self.request("biome/close_file", params)
     }
@@ -14,6 +14,7 @@ use crate::diagnostics::Panic;
 use crate::projects::ProjectKey;
 use crate::workspace::DocumentFileSource;
 use crate::{Workspace, WorkspaceError};
+use biome_analyze::profiling::Timer;
 use biome_diagnostics::serde::Diagnostic;
 use biome_diagnostics::{Diagnostic as _, Error, Severity};
 use biome_fs::{BiomePath, PathInterner, PathKind, TraversalContext, TraversalScope};
@@ -92,6 +93,7 @@ impl WorkspaceServer {
 #[instrument(level = "debug", skip(ctx))]
 fn scan_folder(folder: &Utf8Path, ctx: ScanContext) -> (Duration, Vec<BiomePath>) {
     let start = Instant::now();
+    let timer = Timer::start();
     let fs = ctx.workspace.fs();
     let ctx_ref = &ctx;
     fs.traversal(Box::new(move |scope: &dyn TraversalScope| {
@@ -158,6 +160,7 @@ fn scan_folder(folder: &Utf8Path, ctx: ScanContext) -> (Duration, Vec<BiomePath>
         }
     }));
 
+    timer.record_scan();
     (start.elapsed(), configs)
 }
 
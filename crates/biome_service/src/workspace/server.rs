@@ -1,32 +1,43 @@
 use super::document::Document;
 use super::{
     ChangeFileParams, CheckFileSizeParams, CheckFileSizeResult, CloseFileParams,
-    CloseProjectParams, FeatureName, FileContent, FileExitsParams, FixFileParams, FixFileResult,
+    CloseProjectParams, CompletionsParams, CompletionsResult, DocumentSymbolsParams,
+    DocumentSymbolsResult, FeatureName, FileContent, FileExitsParams, FindReferencesParams,
+    FindReferencesResult, FixFileParams, FixFileResult, FoldingRangesParams, FoldingRangesResult,
     FormatFileParams, FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams,
     GetFormatterIRParams, GetSemanticModelParams, GetSyntaxTreeParams, GetSyntaxTreeResult,
-    OpenFileParams, OpenProjectParams, ParsePatternParams, ParsePatternResult, PatternId,
-    ProjectKey, PullActionsParams, PullActionsResult, PullDiagnosticsParams, PullDiagnosticsResult,
-    RenameResult, ScanProjectFolderParams, ScanProjectFolderResult, SearchPatternParams,
-    SearchResults, ServiceDataNotification, SupportsFeatureParams, UpdateSettingsParams,
-    UpdateSettingsResult,
+    GotoDefinitionParams, GotoDefinitionResult, HoverParams, HoverResult, InlayHintsParams,
+    InlayHintsResult, LinkedEditingRangesParams, LinkedEditingRangesResult, OpenFileParams,
+    OpenProjectParams, ParsePatternParams, ParsePatternResult, PatternId, PrepareRenameParams,
+    PrepareRenameResult, ProjectFileDiagnostics, ProjectKey, PullActionsParams, PullActionsResult,
+    PullDiagnosticsParams, PullDiagnosticsResult, PullProjectDiagnosticsParams,
+    PullProjectDiagnosticsResult, RenameResult, ScanProjectFolderParams, ScanProjectFolderResult,
+    SearchPatternParams, SearchResults, SelectionRangeParams, SelectionRangeResult,
+    SemanticTokensParams, SemanticTokensResult, ServiceDataNotification, SupportsFeatureParams,
+    UpdateSettingsParams, UpdateSettingsResult, WorkspaceSymbol, WorkspaceSymbolsParams,
+    WorkspaceSymbolsResult,
+};
+use crate::configuration::{
+    LoadedConfiguration, NonProjectRulesCollector, ProjectScanComputer, read_config,
 };
-use crate::configuration::{LoadedConfiguration, ProjectScanComputer, read_config};
 use crate::diagnostics::FileTooLarge;
 use crate::file_handlers::{
-    Capabilities, CodeActionsParams, DocumentFileSource, Features, FixAllParams, LintParams,
-    ParseResult,
+    Capabilities, CodeActionsParams, DocumentFileSource, EditorParams, Features, FixAllParams,
+    LintParams, ParseResult, SearchOutput,
 };
 use crate::projects::Projects;
 use crate::settings::WorkspaceSettingsHandle;
 use crate::workspace::{
-    FileFeaturesResult, GetFileContentParams, GetRegisteredTypesParams, GetTypeInfoParams,
-    IsPathIgnoredParams, OpenProjectResult, RageEntry, RageParams, RageResult, ScanKind,
-    ServerInfo,
+    FeaturesBuilder, FileFeaturesResult, GetFileConfigurationParams, GetFileConfigurationResult,
+    GetFileContentParams, GetModuleDependenciesParams, GetModuleDependenciesResult,
+    GetRegisteredTypesParams, GetTypeInfoParams, IsPathIgnoredParams, OpenProjectResult, RageEntry,
+    RageParams, RageResult, ScanKind, ServerInfo,
 };
 use crate::workspace_watcher::{OpenFileReason, WatcherSignalKind};
 use crate::{WatcherInstruction, Workspace, WorkspaceError};
 use append_only_vec::AppendOnlyVec;
-use biome_analyze::{AnalyzerPluginVec, RuleCategory};
+use biome_analyze::profiling::{FilePhase, Timer};
+use biome_analyze::{AnalyzerPluginVec, RuleCategoriesBuilder, RuleCategory};
 use biome_configuration::analyzer::RuleSelector;
 use biome_configuration::bool::Bool;
 use biome_configuration::plugins::{PluginConfiguration, Plugins};
@@ -50,6 +61,7 @@ use biome_plugin_loader::{BiomePlugin, PluginCache, PluginDiagnostic};
 use biome_project_layout::ProjectLayout;
 use biome_resolver::FsWithResolverProxy;
 use biome_rowan::{AstNode, NodeCache, SendNode};
+use biome_string_case::StrOnlyExtension;
 use camino::{Utf8Path, Utf8PathBuf};
 use crossbeam::channel::Sender;
 use papaya::{Compute, HashMap, HashSet, Operation};
@@ -243,6 +255,26 @@ impl WorkspaceServer {
             .unwrap_or(DocumentFileSource::from_path(path))
     }
 
+    /// Returns the parameters for the capabilities that serve editor features.
+    fn editor_params<'a>(
+        &'a self,
+        project_key: ProjectKey,
+        parse: AnyParse,
+        path: &'a BiomePath,
+    ) -> EditorParams<'a> {
+        EditorParams {
+            parse,
+            path,
+            module_graph: self.module_graph.clone(),
+            project_layout: self.project_layout.clone(),
+            fs: self.fs.as_ref(),
+            settings: self
+                .projects
+                .get_settings_based_on_path(project_key, path)
+                .into(),
+        }
+    }
+
     /// Returns an error factory function for unsupported features at a given
     /// path.
     fn build_capability_error<'a>(
@@ -527,6 +559,7 @@ impl WorkspaceServer {
             .projects
             .get_settings_based_on_path(project_key, path)
             .ok_or_else(WorkspaceError::no_project)?;
+        let timer = Timer::start();
         let parsed = parse(
             &BiomePath::new(path),
             file_source,
@@ -534,6 +567,7 @@ impl WorkspaceServer {
             settings.into(),
             node_cache,
         );
+        timer.record_phase(path, FilePhase::Parse);
         Ok(parsed)
     }
 
@@ -808,12 +842,14 @@ impl WorkspaceServer {
             WatcherSignalKind::Removed => (&[] as &[_], &[path] as &[_]),
         };
 
+        let timer = Timer::start();
         self.module_graph.update_graph_for_js_paths(
             self.fs.as_ref(),
             &self.project_layout,
             added_or_changed_paths,
             removed_paths,
         );
+        timer.record_module_graph();
     }
 
     /// Updates the state of any services relevant to the given `path`.
@@ -1201,6 +1237,45 @@ impl Workspace for WorkspaceServer {
             .ok_or_else(WorkspaceError::not_found)
     }
 
+    fn get_module_dependencies(
+        &self,
+        params: GetModuleDependenciesParams,
+    ) -> Result<GetModuleDependenciesResult, WorkspaceError> {
+        let dependencies = self
+            .module_graph
+            .transitive_dependencies(params.path.as_path())
+            .into_iter()
+            .map(BiomePath::from)
+            .collect();
+
+        Ok(GetModuleDependenciesResult { dependencies })
+    }
+
+    fn get_file_configuration(
+        &self,
+        params: GetFileConfigurationParams,
+    ) -> Result<GetFileConfigurationResult, WorkspaceError> {
+        let settings = self
+            .projects
+            .get_settings_based_on_path(params.project_key, &params.path)
+            .ok_or_else(WorkspaceError::no_project)?;
+
+        let overrides = settings
+            .override_settings
+            .patterns
+            .iter()
+            .enumerate()
+            .filter(|(_, pattern)| pattern.is_file_included(&params.path))
+            .map(|(index, _)| index)
+            .collect();
+
+        Ok(GetFileConfigurationResult {
+            configuration: settings.source().unwrap_or_default(),
+            directory: settings.source_path().map(BiomePath::from),
+            overrides,
+        })
+    }
+
     fn check_file_size(
         &self,
         params: CheckFileSizeParams,
@@ -1313,6 +1388,7 @@ impl Workspace for WorkspaceServer {
             skip,
             enabled_rules,
             pull_code_actions,
+            range,
         } = params;
         let parse = self.get_parse(&path)?;
         let language = self.get_file_source(&path);
@@ -1330,6 +1406,7 @@ impl Workspace for WorkspaceServer {
                         &settings.get_plugins_for_path(&path),
                     )
                     .map_err(WorkspaceError::plugin_errors)?;
+                let timer = Timer::start();
                 let results = lint(LintParams {
                     parse,
                     workspace: &settings.into(),
@@ -1343,12 +1420,14 @@ impl Workspace for WorkspaceServer {
                     suppression_reason: None,
                     enabled_rules,
                     pull_code_actions,
+                    range,
                     plugins: if categories.contains(RuleCategory::Lint) {
                         plugins
                     } else {
                         Vec::new()
                     },
                 });
+                timer.record_phase(&path, FilePhase::Analysis);
 
                 (
                     results.diagnostics,
@@ -1464,7 +1543,10 @@ impl Workspace for WorkspaceServer {
             return Err(WorkspaceError::format_with_errors_disabled());
         }
         let document_file_source = self.get_file_source(&params.path);
-        format(&params.path, &document_file_source, parse, handle)
+        let timer = Timer::start();
+        let result = format(&params.path, &document_file_source, parse, handle);
+        timer.record_phase(&params.path, FilePhase::Format);
+        result
     }
 
     #[instrument(level = "debug", skip(self, params))]
@@ -1565,7 +1647,8 @@ impl Workspace for WorkspaceServer {
             )
             .map_err(WorkspaceError::plugin_errors)?;
         let language = self.get_file_source(&path);
-        fix_all(FixAllParams {
+        let timer = Timer::start();
+        let result = fix_all(FixAllParams {
             parse,
             fix_file_mode,
             workspace: settings.into(),
@@ -1584,7 +1667,9 @@ impl Workspace for WorkspaceServer {
             } else {
                 Vec::new()
             },
-        })
+        });
+        timer.record_phase(&path, FilePhase::Analysis);
+        result
     }
 
     fn rename(&self, params: super::RenameParams) -> Result<RenameResult, WorkspaceError> {
@@ -1595,11 +1680,346 @@ impl Workspace for WorkspaceServer {
             .ok_or_else(self.build_capability_error(&params.path))?;
 
         let parse = self.get_parse(&params.path)?;
-        let result = rename(&params.path, parse, params.symbol_at, params.new_name)?;
+        let parse_for_path = |path: &Utf8Path| {
+            matches!(self.get_file_source(path), DocumentFileSource::Js(_))
+                .then(|| self.get_parse(path).ok())
+                .flatten()
+        };
+        let result = rename(
+            self.editor_params(params.project_key, parse, &params.path),
+            &parse_for_path,
+            params.symbol_at,
+            params.new_name,
+        )?;
 
         Ok(result)
     }
 
+    fn prepare_rename(
+        &self,
+        params: PrepareRenameParams,
+    ) -> Result<PrepareRenameResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let prepare_rename = capabilities
+            .analyzer
+            .prepare_rename
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(&params.path)?;
+        let range = prepare_rename(parse, params.symbol_at);
+
+        Ok(PrepareRenameResult { range })
+    }
+
+    fn hover(&self, params: HoverParams) -> Result<HoverResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let hover = capabilities
+            .editor
+            .hover
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(&params.path)?;
+        let result = hover(
+            self.editor_params(params.project_key, parse, &params.path),
+            params.offset,
+        );
+
+        Ok(result)
+    }
+
+    fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<GotoDefinitionResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let goto_definition = capabilities
+            .editor
+            .goto_definition
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(&params.path)?;
+        let definitions = goto_definition(
+            self.editor_params(params.project_key, parse, &params.path),
+            params.offset,
+        );
+
+        Ok(GotoDefinitionResult { definitions })
+    }
+
+    fn find_references(
+        &self,
+        params: FindReferencesParams,
+    ) -> Result<FindReferencesResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let find_references = capabilities
+            .editor
+            .find_references
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(&params.path)?;
+        let references = find_references(
+            self.editor_params(params.project_key, parse, &params.path),
+            params.offset,
+            params.include_declaration,
+        );
+
+        Ok(FindReferencesResult { references })
+    }
+
+    fn document_symbols(
+        &self,
+        params: DocumentSymbolsParams,
+    ) -> Result<DocumentSymbolsResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let document_symbols = capabilities
+            .editor
+            .document_symbols
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(&params.path)?;
+        let symbols = document_symbols(self.editor_params(params.project_key, parse, &params.path));
+
+        Ok(DocumentSymbolsResult { symbols })
+    }
+
+    /// Searches the symbols of all the documents of the project.
+    ///
+    /// Only documents that are known to the workspace are searched, which
+    /// means the project needs to be scanned for symbols of files that aren't
+    /// opened to be found. At most `limit` symbols are returned, so the search
+    /// stops as soon as enough symbols match.
+    fn workspace_symbols(
+        &self,
+        params: WorkspaceSymbolsParams,
+    ) -> Result<WorkspaceSymbolsResult, WorkspaceError> {
+        let Some(project_path) = self.projects.get_project_path(params.project_key) else {
+            return Ok(WorkspaceSymbolsResult::default());
+        };
+
+        let mut documents: Vec<_> = self
+            .documents
+            .pin()
+            .iter()
+            .filter(|(path, _)| path.starts_with(&project_path))
+            .filter_map(|(path, document)| match &document.syntax {
+                Some(Ok(parse)) => Some((BiomePath::new(path), parse.clone())),
+                _ => None,
+            })
+            .collect();
+        documents.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let query = params.query.to_lowercase_cow();
+        let mut symbols = Vec::new();
+        for (path, parse) in documents {
+            if symbols.len() >= params.limit {
+                break;
+            }
+
+            let capabilities = self.get_file_capabilities(&path);
+            let Some(document_symbols) = capabilities.editor.document_symbols else {
+                continue;
+            };
+
+            let mut document_matches = Vec::new();
+            let mut stack: Vec<_> =
+                document_symbols(self.editor_params(params.project_key, parse, &path))
+                    .into_iter()
+                    .map(|symbol| (symbol, None))
+                    .collect();
+            while let Some((symbol, container_name)) = stack.pop() {
+                stack.extend(
+                    symbol
+                        .children
+                        .into_iter()
+                        .map(|child| (child, Some(symbol.name.clone()))),
+                );
+                if matches_symbol_query(&symbol.name, query.as_ref()) {
+                    document_matches.push(WorkspaceSymbol {
+                        name: symbol.name,
+                        kind: symbol.kind,
+                        path: path.clone(),
+                        range: symbol.selection_range,
+                        container_name,
+                    });
+                }
+            }
+
+            document_matches.sort_by_key(|symbol| symbol.range.start());
+            document_matches.truncate(params.limit - symbols.len());
+            symbols.extend(document_matches);
+        }
+
+        Ok(WorkspaceSymbolsResult { symbols })
+    }
+
+    fn semantic_tokens(
+        &self,
+        params: SemanticTokensParams,
+    ) -> Result<SemanticTokensResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let semantic_tokens = capabilities
+            .editor
+            .semantic_tokens
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(&params.path)?;
+        let tokens = semantic_tokens(
+            self.editor_params(params.project_key, parse, &params.path),
+            params.range,
+        );
+
+        Ok(SemanticTokensResult { tokens })
+    }
+
+    fn pull_project_diagnostics(
+        &self,
+        params: PullProjectDiagnosticsParams,
+    ) -> Result<PullProjectDiagnosticsResult, WorkspaceError> {
+        let Some(project_path) = self.projects.get_project_path(params.project_key) else {
+            return Ok(PullProjectDiagnosticsResult::default());
+        };
+
+        let paths: Vec<_> = self
+            .documents
+            .pin()
+            .iter()
+            .filter(|(path, document)| {
+                document.opened_by_scanner && path.starts_with(&project_path)
+            })
+            .map(|(path, _)| BiomePath::new(path))
+            .collect();
+
+        // Only the rules of the project domain are run, because the other
+        // ones don't depend on the rest of the project.
+        let skip = NonProjectRulesCollector::collect();
+        let mut files = Vec::new();
+        for path in paths {
+            let features = self.file_features(SupportsFeatureParams {
+                project_key: params.project_key,
+                path: path.clone(),
+                features: FeaturesBuilder::new().with_linter().build(),
+            })?;
+            if !features.supports_lint() {
+                continue;
+            }
+
+            let result = self.pull_diagnostics(PullDiagnosticsParams {
+                project_key: params.project_key,
+                path: path.clone(),
+                categories: RuleCategoriesBuilder::default().with_lint().build(),
+                only: Vec::new(),
+                skip: skip.clone(),
+                enabled_rules: Vec::new(),
+                pull_code_actions: false,
+                range: None,
+            })?;
+
+            // Parse errors and plugin diagnostics are reported once the
+            // file is opened.
+            let diagnostics = result
+                .diagnostics
+                .into_iter()
+                .filter(|diagnostic| {
+                    diagnostic
+                        .category()
+                        .is_some_and(|category| category.name().starts_with("lint/"))
+                })
+                .collect();
+
+            files.push(ProjectFileDiagnostics { path, diagnostics });
+        }
+
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+
+        Ok(PullProjectDiagnosticsResult { files })
+    }
+
+    fn folding_ranges(
+        &self,
+        params: FoldingRangesParams,
+    ) -> Result<FoldingRangesResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let folding_ranges = capabilities
+            .editor
+            .folding_ranges
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(&params.path)?;
+        let ranges = folding_ranges(self.editor_params(params.project_key, parse, &params.path));
+
+        Ok(FoldingRangesResult { ranges })
+    }
+
+    fn selection_range(
+        &self,
+        params: SelectionRangeParams,
+    ) -> Result<SelectionRangeResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let selection_range = capabilities
+            .editor
+            .selection_range
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(&params.path)?;
+        let ranges = selection_range(
+            self.editor_params(params.project_key, parse, &params.path),
+            params.offset,
+        );
+
+        Ok(SelectionRangeResult { ranges })
+    }
+
+    fn inlay_hints(&self, params: InlayHintsParams) -> Result<InlayHintsResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let inlay_hints = capabilities
+            .editor
+            .inlay_hints
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(&params.path)?;
+        let hints = inlay_hints(
+            self.editor_params(params.project_key, parse, &params.path),
+            params.range,
+        );
+
+        Ok(InlayHintsResult { hints })
+    }
+
+    fn completions(&self, params: CompletionsParams) -> Result<CompletionsResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let completions = capabilities
+            .editor
+            .completions
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(&params.path)?;
+        let items = completions(
+            self.editor_params(params.project_key, parse, &params.path),
+            params.offset,
+        );
+
+        Ok(CompletionsResult { items })
+    }
+
+    fn linked_editing_ranges(
+        &self,
+        params: LinkedEditingRangesParams,
+    ) -> Result<LinkedEditingRangesResult, WorkspaceError> {
+        let capabilities = self.get_file_capabilities(&params.path);
+        let linked_editing_ranges = capabilities
+            .editor
+            .linked_editing_ranges
+            .ok_or_else(self.build_capability_error(&params.path))?;
+
+        let parse = self.get_parse(&params.path)?;
+        let ranges = linked_editing_ranges(
+            self.editor_params(params.project_key, parse, &params.path),
+            params.offset,
+        );
+
+        Ok(LinkedEditingRangesResult { ranges })
+    }
+
     /// Closes a file that is opened in the workspace.
     ///
     /// This only unloads the document from the workspace if the file is NOT
@@ -1688,9 +2108,18 @@ impl Workspace for WorkspaceServer {
         let parse = self.get_parse(&path)?;
 
         let document_file_source = self.get_file_source(&path);
-        let matches = search(&path, &document_file_source, parse, query, settings.into())?;
+        let SearchOutput {
+            matches,
+            rewrite,
+            conflicts,
+        } = search(&path, &document_file_source, parse, query, settings.into())?;
 
-        Ok(SearchResults { path, matches })
+        Ok(SearchResults {
+            path,
+            matches,
+            rewrite,
+            conflicts,
+        })
     }
 
     fn drop_pattern(&self, params: super::DropPatternParams) -> Result<(), WorkspaceError> {
@@ -1739,6 +2168,13 @@ fn make_search_pattern_id() -> PatternId {
     format!("p{counter}").into()
 }
 
+/// Returns whether the characters of `query`, which must be lowercase, appear
+/// in `name` in the same order, regardless of their case.
+fn matches_symbol_query(name: &str, query: &str) -> bool {
+    let mut name = name.chars().flat_map(char::to_lowercase);
+    query.chars().all(|c| name.any(|n| n == c))
+}
+
 #[cfg(test)]
 #[path = "server.tests.rs"]
 mod tests;
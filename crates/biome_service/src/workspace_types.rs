@@ -587,8 +587,8 @@ macro_rules! workspace_method {
 }
 
 /// Returns a list of signature for all the methods in the [Workspace] trait
-pub fn methods() -> [WorkspaceMethod; 25] {
-    [
+pub fn methods() -> Vec<WorkspaceMethod> {
+    vec![
         workspace_method!(file_features),
         workspace_method!(update_settings),
         workspace_method!(open_project),
@@ -599,6 +599,8 @@ pub fn methods() -> [WorkspaceMethod; 25] {
         workspace_method!(file_exists),
         workspace_method!(check_file_size),
         workspace_method!(get_file_content),
+        workspace_method!(get_module_dependencies),
+        workspace_method!(get_file_configuration),
         workspace_method!(get_control_flow_graph),
         workspace_method!(get_formatter_ir),
         workspace_method!(get_type_info),
@@ -614,5 +616,18 @@ pub fn methods() -> [WorkspaceMethod; 25] {
         workspace_method!(parse_pattern),
         workspace_method!(search_pattern),
         workspace_method!(drop_pattern),
+        workspace_method!(hover),
+        workspace_method!(goto_definition),
+        workspace_method!(find_references),
+        workspace_method!(prepare_rename),
+        workspace_method!(document_symbols),
+        workspace_method!(workspace_symbols),
+        workspace_method!(semantic_tokens),
+        workspace_method!(pull_project_diagnostics),
+        workspace_method!(folding_ranges),
+        workspace_method!(selection_range),
+        workspace_method!(inlay_hints),
+        workspace_method!(completions),
+        workspace_method!(linked_editing_ranges),
     ]
 }
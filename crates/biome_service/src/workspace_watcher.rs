@@ -92,6 +92,10 @@ pub struct WorkspaceWatcher {
 
     /// Channel receiver for watch instructions.
     instruction_rx: Receiver<WatcherInstruction>,
+
+    /// Channel sender for the paths that changed, if a client subscribed to
+    /// them through [Self::subscribe_to_changes()].
+    changes_tx: Option<Sender<Vec<Utf8PathBuf>>>,
 }
 
 impl WorkspaceWatcher {
@@ -120,11 +124,23 @@ impl WorkspaceWatcher {
             watcher: Box::new(watcher),
             notify_rx: rx,
             instruction_rx,
+            changes_tx: None,
         };
 
         Ok((watcher, instruction_channel))
     }
 
+    /// Returns a channel receiver for the paths of the files and folders that
+    /// are created, modified or removed.
+    ///
+    /// The paths are sent after the workspace has processed the change, so
+    /// the module graph is already up-to-date when they're received.
+    pub fn subscribe_to_changes(&mut self) -> Receiver<Vec<Utf8PathBuf>> {
+        let (changes_tx, changes_rx) = unbounded();
+        self.changes_tx = Some(changes_tx);
+        changes_rx
+    }
+
     /// Runs the watcher.
     ///
     /// This function is expected to run continuously until either the workspace
@@ -147,6 +163,12 @@ impl WorkspaceWatcher {
                             continue;
                         };
 
+                        let changed_paths = self
+                            .changes_tx
+                            .as_ref()
+                            .filter(|_| is_change(&event.kind))
+                            .map(|changes_tx| (changes_tx, paths.clone()));
+
                         let result = match event.kind {
                             EventKind::Access(_) => Ok(()),
                             EventKind::Create(create_kind) => match create_kind {
@@ -185,6 +207,10 @@ impl WorkspaceWatcher {
                             // TODO: Improve error propagation.
                             warn!("Error processing watch event: {error}");
                         }
+
+                        if let Some((changes_tx, paths)) = changed_paths {
+                            let _ = changes_tx.send(paths);
+                        }
                     },
                     Ok(Err(error)) => {
                         // TODO: Improve error propagation.
@@ -231,6 +257,19 @@ impl WorkspaceWatcher {
     }
 }
 
+/// Returns whether the event is about a path that was created, removed, or
+/// whose content or name changed.
+fn is_change(kind: &EventKind) -> bool {
+    match kind {
+        EventKind::Create(_) | EventKind::Remove(_) => true,
+        EventKind::Modify(modify_kind) => matches!(
+            modify_kind,
+            ModifyKind::Data(_) | ModifyKind::Name(_) | ModifyKind::Any
+        ),
+        EventKind::Access(_) | EventKind::Any | EventKind::Other => false,
+    }
+}
+
 impl From<NotifyError> for WorkspaceError {
     fn from(error: NotifyError) -> Self {
         Self::WatchError(WatchError {
@@ -0,0 +1,10 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use biome_analyze::declare_lint_group;
+pub mod no_ambiguous_booleans;
+pub mod no_duplicate_keys;
+pub mod no_undeclared_aliases;
+pub mod use_consistent_indentation;
+declare_lint_group! { pub Nursery { name : "nursery" , rules : [self :: no_ambiguous_booleans :: NoAmbiguousBooleans , self :: no_duplicate_keys :: NoDuplicateKeys , self :: no_undeclared_aliases :: NoUndeclaredAliases , self :: use_consistent_indentation :: UseConsistentIndentation ,] } }
@@ -0,0 +1,148 @@
+use crate::YamlRuleAction;
+use crate::utils::{has_tag, is_mapping_key};
+use biome_analyze::{
+    Ast, FixKind, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_rowan::{AstNode, BatchMutationExt};
+use biome_yaml_factory::make;
+use biome_yaml_syntax::{
+    AnyYamlFlowNode, AnyYamlJsonContent, YamlFlowYamlNode, YamlPlainScalar, YamlSyntaxKind,
+    YamlSyntaxToken,
+};
+
+declare_lint_rule! {
+    /// Disallow plain values that YAML 1.1 reads as booleans, such as `yes` and `on`.
+    ///
+    /// YAML 1.1 reads `y`, `n`, `yes`, `no`, `on` and `off` as booleans, while YAML 1.2
+    /// reads them as strings. Tools use parsers that implement either version, so the same
+    /// file can be read differently. For example, Kubernetes reads `on` as `true`, while a
+    /// YAML 1.2 parser reads it as the string `"on"`.
+    ///
+    /// Use `true` and `false` for booleans, and quote the values that are strings.
+    /// Keys aren't checked, because `on` is the name of a key of GitHub Actions workflows.
+    /// Values with a tag, such as `!!str yes`, aren't checked either.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```yaml,expect_diagnostic
+    /// enabled: yes
+    /// ```
+    ///
+    /// ```yaml,expect_diagnostic
+    /// switches: [on, true]
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```yaml
+    /// enabled: true
+    /// country: "no"
+    /// on: push
+    /// ```
+    pub NoAmbiguousBooleans {
+        version: "next",
+        name: "noAmbiguousBooleans",
+        language: "yaml",
+        sources: &[RuleSource::Yamllint("truthy").inspired()],
+        recommended: false,
+        severity: Severity::Warning,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for NoAmbiguousBooleans {
+    type Query = Ast<YamlPlainScalar>;
+    type State = YamlFlowYamlNode;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let scalar = ctx.query();
+        let token = scalar.value_token().ok()?;
+        if !is_yaml_1_1_boolean(token.text_trimmed()) {
+            return None;
+        }
+
+        let node = scalar.parent::<YamlFlowYamlNode>()?;
+        if has_tag(node.properties()) || is_mapping_key(node.syntax()) {
+            return None;
+        }
+        Some(node)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<RuleDiagnostic> {
+        let scalar = ctx.query();
+        let token = scalar.value_token().ok()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                scalar.range(),
+                markup! {
+                    "The value "<Emphasis>{token.text_trimmed()}</Emphasis>" is a boolean in YAML 1.1, but a string in YAML 1.2."
+                },
+            )
+            .note(markup! {
+                "Parsers that implement different versions of YAML read this value differently."
+            })
+            .note(markup! {
+                "Use "<Emphasis>"true"</Emphasis>" or "<Emphasis>"false"</Emphasis>" for a boolean, or quote the value for a string."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, node: &Self::State) -> Option<YamlRuleAction> {
+        let scalar = ctx.query();
+        let token = scalar.value_token().ok()?;
+        let text = token.text_trimmed();
+        let quoted = make::yaml_double_quoted_scalar(YamlSyntaxToken::new_detached(
+            YamlSyntaxKind::DOUBLE_QUOTED_LITERAL,
+            &format!("\"{text}\""),
+            [],
+            [],
+        ));
+        let mut new_node =
+            make::yaml_flow_json_node().with_content(AnyYamlJsonContent::from(quoted));
+        if let Some(properties) = node.properties() {
+            new_node = new_node.with_properties(properties);
+        }
+
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(
+            AnyYamlFlowNode::from(node.clone()),
+            AnyYamlFlowNode::from(new_node.build()),
+        );
+
+        Some(YamlRuleAction::new(
+            ctx.metadata().action_category(ctx.category(), ctx.group()),
+            ctx.metadata().applicability(),
+            markup! { "Quote the value to keep it a string." },
+            mutation,
+        ))
+    }
+}
+
+/// Returns `true` if the plain scalar `text` is a boolean in YAML 1.1, but a string in YAML 1.2.
+fn is_yaml_1_1_boolean(text: &str) -> bool {
+    matches!(
+        text,
+        "y" | "Y"
+            | "yes"
+            | "Yes"
+            | "YES"
+            | "n"
+            | "N"
+            | "no"
+            | "No"
+            | "NO"
+            | "on"
+            | "On"
+            | "ON"
+            | "off"
+            | "Off"
+            | "OFF"
+    )
+}
@@ -0,0 +1,281 @@
+use biome_analyze::{
+    Ast, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList, TextRange, declare_node_union};
+use biome_yaml_syntax::{
+    AnyYamlBlockIndented, AnyYamlBlockMapEntry, AnyYamlBlockNode, AnyYamlFlowMapEntry,
+    AnyYamlJsonContent, AnyYamlPropertiesCombination, YamlAliasNode, YamlBlockMapEntryList,
+    YamlBlockMapping, YamlCompactMapping, YamlFlowJsonNode, YamlFlowMapping, YamlFlowYamlNode,
+};
+use rustc_hash::FxHashMap;
+
+declare_lint_rule! {
+    /// Disallow two keys with the same value inside mappings.
+    ///
+    /// The YAML specification requires the keys of a mapping to be unique. Depending on the
+    /// parser, a duplicated key is either rejected, or it silently overrides the value of the
+    /// previous key.
+    ///
+    /// Keys are compared by their value, so `key`, `'key'`, `"key"` and `!!str key` are the
+    /// same key. Keys with another tag, such as `!!int 1`, aren't checked. The merge key `<<`
+    /// is ignored, because some tools allow it to be repeated.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```yaml,expect_diagnostic
+    /// name: app
+    /// name: service
+    /// ```
+    ///
+    /// ```yaml,expect_diagnostic
+    /// ports: { http: 80, "http": 8080 }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```yaml
+    /// name: app
+    /// version: 1.0.0
+    /// ```
+    ///
+    /// ```yaml
+    /// 1: one # a number
+    /// "1": one # a string
+    /// ```
+    pub NoDuplicateKeys {
+        version: "next",
+        name: "noDuplicateKeys",
+        language: "yaml",
+        sources: &[RuleSource::Yamllint("key-duplicates").same()],
+        recommended: true,
+        severity: Severity::Error,
+    }
+}
+
+declare_node_union! {
+    pub AnyYamlMapping = YamlBlockMapping | YamlCompactMapping | YamlFlowMapping
+}
+
+declare_node_union! {
+    pub AnyYamlMappingKey = YamlFlowYamlNode | YamlFlowJsonNode | YamlAliasNode
+}
+
+impl Rule for NoDuplicateKeys {
+    type Query = Ast<AnyYamlMapping>;
+    type State = (AnyYamlMappingKey, Box<[TextRange]>);
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let mapping = ctx.query();
+        let mut duplicated_keys: Vec<(AnyYamlMappingKey, Vec<TextRange>)> = Vec::new();
+        let mut keys_found = FxHashMap::<KeyValue, usize>::default();
+        let mut first_keys = Vec::new();
+
+        for key in mapping_keys(mapping) {
+            let Some(value) = KeyValue::from_key(&key) else {
+                continue;
+            };
+            if let Some(&index) = keys_found.get(&value) {
+                let original: &AnyYamlMappingKey = &first_keys[index];
+                match duplicated_keys
+                    .iter_mut()
+                    .find(|(duplicated, _)| duplicated == original)
+                {
+                    Some((_, ranges)) => ranges.push(key.range()),
+                    None => duplicated_keys.push((original.clone(), vec![key.range()])),
+                }
+            } else {
+                keys_found.insert(value, first_keys.len());
+                first_keys.push(key);
+            }
+        }
+
+        duplicated_keys
+            .into_iter()
+            .map(|(key, ranges)| (key, ranges.into_boxed_slice()))
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let (original, ranges) = state;
+        let name = original.syntax().text_trimmed();
+        let mut diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            original.range(),
+            markup! {
+                "The key "<Emphasis>{name.to_string()}</Emphasis>" was already declared."
+            },
+        );
+        for range in ranges {
+            diagnostic = diagnostic.detail(
+                range,
+                markup! {
+                    "This is where a duplicated key was declared again."
+                },
+            );
+        }
+        Some(diagnostic.note(
+            markup! {
+                "The keys of a mapping must be unique. Depending on the parser, a duplicated key is either rejected, or it overrides the value of the previous key."
+            },
+        ))
+    }
+}
+
+/// Returns the keys of the entries of `mapping`, in source order.
+fn mapping_keys(mapping: &AnyYamlMapping) -> Vec<AnyYamlMappingKey> {
+    match mapping {
+        AnyYamlMapping::YamlBlockMapping(mapping) => block_mapping_keys(mapping.entries()),
+        AnyYamlMapping::YamlCompactMapping(mapping) => block_mapping_keys(mapping.entries()),
+        AnyYamlMapping::YamlFlowMapping(mapping) => mapping
+            .entries()
+            .iter()
+            .flatten()
+            .filter_map(|entry| match entry {
+                AnyYamlFlowMapEntry::YamlFlowMapImplicitEntry(entry) => entry.key(),
+                AnyYamlFlowMapEntry::YamlFlowMapExplicitEntry(entry) => entry.entry()?.key(),
+                AnyYamlFlowMapEntry::YamlBogus(_) => None,
+            })
+            .filter_map(|key| AnyYamlMappingKey::cast(key.into_syntax()))
+            .collect(),
+    }
+}
+
+fn block_mapping_keys(entries: YamlBlockMapEntryList) -> Vec<AnyYamlMappingKey> {
+    entries
+        .iter()
+        .filter_map(|entry| match entry {
+            AnyYamlBlockMapEntry::YamlBlockMapImplicitEntry(entry) => {
+                AnyYamlMappingKey::cast(entry.key()?.into_syntax())
+            }
+            AnyYamlBlockMapEntry::YamlBlockMapExplicitEntry(entry) => {
+                match entry.key().ok()?.key()? {
+                    AnyYamlBlockIndented::AnyYamlBlockNode(
+                        AnyYamlBlockNode::YamlFlowInBlockNode(node),
+                    ) => AnyYamlMappingKey::cast(node.flow().ok()?.into_syntax()),
+                    _ => None,
+                }
+            }
+            AnyYamlBlockMapEntry::YamlBogusBlockMapEntry(_) => None,
+        })
+        .collect()
+}
+
+/// The value of a key, used to compare keys written in different styles.
+#[derive(Debug, Eq, PartialEq, Hash)]
+enum KeyValue {
+    /// A string, e.g. `key`, `'key'` or `"key"`
+    String(String),
+    /// A plain scalar resolved to a null, a boolean or a number, e.g. `1`
+    Scalar(String),
+    /// An alias, e.g. `*key`
+    Alias(String),
+}
+
+impl KeyValue {
+    /// Returns the value of `key`, or `None` if it can't be compared with the other keys.
+    fn from_key(key: &AnyYamlMappingKey) -> Option<Self> {
+        match key {
+            AnyYamlMappingKey::YamlFlowYamlNode(node) => {
+                let is_string = has_str_tag(node.properties())?;
+                let token = node.content()?.value_token().ok()?;
+                let text = token.text_trimmed();
+                if text.contains('\n') || (!is_string && text == "<<") {
+                    None
+                } else if !is_string && is_resolved_as_non_string(text) {
+                    Some(Self::Scalar(text.to_string()))
+                } else {
+                    Some(Self::String(text.to_string()))
+                }
+            }
+            AnyYamlMappingKey::YamlFlowJsonNode(node) => {
+                has_str_tag(node.properties())?;
+                match node.content()? {
+                    AnyYamlJsonContent::YamlSingleQuotedScalar(scalar) => {
+                        let token = scalar.value_token().ok()?;
+                        let inner = unquote(token.text_trimmed())?;
+                        Some(Self::String(inner.replace("''", "'")))
+                    }
+                    AnyYamlJsonContent::YamlDoubleQuotedScalar(scalar) => {
+                        let token = scalar.value_token().ok()?;
+                        let inner = unquote(token.text_trimmed())?;
+                        // Escape sequences aren't decoded
+                        (!inner.contains('\\')).then(|| Self::String(inner.to_string()))
+                    }
+                    AnyYamlJsonContent::YamlFlowMapping(_)
+                    | AnyYamlJsonContent::YamlFlowSequence(_) => None,
+                }
+            }
+            AnyYamlMappingKey::YamlAliasNode(node) => {
+                let token = node.value_token().ok()?;
+                Some(Self::Alias(token.text_trimmed().to_string()))
+            }
+        }
+    }
+}
+
+/// Returns whether the properties of a key have the `!!str` tag, which makes the key a string.
+///
+/// Returns `None` for the other tags, because they change the value of the key.
+fn has_str_tag(properties: Option<AnyYamlPropertiesCombination>) -> Option<bool> {
+    let tag = match properties {
+        Some(AnyYamlPropertiesCombination::YamlPropertiesTagFirst(properties)) => {
+            properties.tag().ok()?
+        }
+        Some(AnyYamlPropertiesCombination::YamlPropertiesAnchorFirst(properties)) => {
+            match properties.tag() {
+                Some(tag) => tag,
+                None => return Some(false),
+            }
+        }
+        None => return Some(false),
+    };
+    let token = tag.value_token().ok()?;
+    matches!(token.text_trimmed(), "!!str" | "!<tag:yaml.org,2002:str>").then_some(true)
+}
+
+/// Returns the text between the quotes of a single line quoted scalar.
+fn unquote(text: &str) -> Option<&str> {
+    let inner = text.get(1..text.len().checked_sub(1)?)?;
+    (!inner.contains('\n')).then_some(inner)
+}
+
+/// Returns `true` if the plain scalar `text` is resolved as a null, a boolean or a number by
+/// the core schema of YAML.
+fn is_resolved_as_non_string(text: &str) -> bool {
+    if matches!(
+        text,
+        "~" | "null"
+            | "Null"
+            | "NULL"
+            | "true"
+            | "True"
+            | "TRUE"
+            | "false"
+            | "False"
+            | "FALSE"
+            | ".nan"
+            | ".NaN"
+            | ".NAN"
+    ) {
+        return true;
+    }
+
+    let unsigned = text.trim_start_matches(['-', '+']);
+    if matches!(unsigned, ".inf" | ".Inf" | ".INF") {
+        return true;
+    }
+    if let Some(digits) = text.strip_prefix("0x") {
+        return !digits.is_empty() && digits.bytes().all(|byte| byte.is_ascii_hexdigit());
+    }
+    if let Some(digits) = text.strip_prefix("0o") {
+        return !digits.is_empty() && digits.bytes().all(|byte| matches!(byte, b'0'..=b'7'));
+    }
+
+    unsigned.starts_with(|c: char| c.is_ascii_digit() || c == '.') && text.parse::<f64>().is_ok()
+}
@@ -0,0 +1,155 @@
+use biome_analyze::{
+    Ast, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_rowan::{AstNode, TextRange, WalkEvent};
+use biome_yaml_syntax::{YamlAliasNode, YamlAnchorProperty, YamlDocument};
+use rustc_hash::FxHashMap;
+
+declare_lint_rule! {
+    /// Disallow aliases that don't refer to an anchor declared before them.
+    ///
+    /// An alias, such as `*base`, repeats the node marked with the anchor of the same name, such
+    /// as `&base`. The anchor must be declared before the alias, in the same document, and the
+    /// alias can't be inside the node that the anchor marks. Otherwise, the document is invalid
+    /// and parsers reject it.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```yaml,expect_diagnostic
+    /// defaults: &defaults
+    ///   adapter: postgres
+    /// development: *default
+    /// ```
+    ///
+    /// ```yaml,expect_diagnostic
+    /// development: *defaults
+    /// defaults: &defaults
+    ///   adapter: postgres
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```yaml
+    /// defaults: &defaults
+    ///   adapter: postgres
+    /// development: *defaults
+    /// ```
+    pub NoUndeclaredAliases {
+        version: "next",
+        name: "noUndeclaredAliases",
+        language: "yaml",
+        sources: &[RuleSource::Yamllint("anchors").inspired()],
+        recommended: true,
+        severity: Severity::Error,
+    }
+}
+
+pub struct UndeclaredAlias {
+    alias: YamlAliasNode,
+    /// The range of the anchor with the same name, when it's declared after the alias or when
+    /// it marks a node that contains the alias
+    anchor_range: Option<TextRange>,
+}
+
+impl Rule for NoUndeclaredAliases {
+    type Query = Ast<YamlDocument>;
+    type State = UndeclaredAlias;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let document = ctx.query();
+
+        // The nodes are visited in source order, so an anchor is in the map only if it's declared
+        // before the aliases that are visited after it. The anchor is added once the node that
+        // it marks is complete, because that node can't contain an alias to itself.
+        let mut anchors = FxHashMap::<String, TextRange>::default();
+        let mut pending_anchors = Vec::new();
+        let mut undeclared_aliases = Vec::new();
+        for event in document.syntax().preorder() {
+            match event {
+                WalkEvent::Enter(node) => {
+                    if let Some(anchor) = YamlAnchorProperty::cast_ref(&node) {
+                        let name = anchor.value_token().ok().and_then(|token| {
+                            token
+                                .text_trimmed()
+                                .strip_prefix('&')
+                                .map(|name| name.to_string())
+                        });
+                        // The anchor is in the properties of the node that it marks
+                        if let Some((name, marked_node)) = name.zip(node.grand_parent()) {
+                            pending_anchors.push((marked_node, name, anchor.range()));
+                        }
+                    } else if let Some(alias) = YamlAliasNode::cast(node) {
+                        let Some(token) = alias.value_token().ok() else {
+                            continue;
+                        };
+                        let Some(name) = token.text_trimmed().strip_prefix('*') else {
+                            continue;
+                        };
+                        if !anchors.contains_key(name) {
+                            undeclared_aliases.push((alias, name.to_string()));
+                        }
+                    }
+                }
+                WalkEvent::Leave(node) => {
+                    while pending_anchors
+                        .last()
+                        .is_some_and(|(marked_node, _, _)| *marked_node == node)
+                    {
+                        if let Some((_, name, range)) = pending_anchors.pop() {
+                            anchors.entry(name).or_insert(range);
+                        }
+                    }
+                }
+            }
+        }
+
+        undeclared_aliases
+            .into_iter()
+            .map(|(alias, name)| UndeclaredAlias {
+                anchor_range: anchors.get(&name).copied(),
+                alias,
+            })
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let UndeclaredAlias {
+            alias,
+            anchor_range,
+        } = state;
+        let name = alias.value_token().ok()?;
+        let name = name.text_trimmed();
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            alias.range(),
+            markup! {
+                "The alias "<Emphasis>{name}</Emphasis>" refers to an anchor that isn't declared before it."
+            },
+        );
+        let diagnostic = match anchor_range {
+            Some(anchor_range) if anchor_range.start() < alias.range().start() => diagnostic
+                .detail(
+                    anchor_range,
+                    markup! {
+                        "The anchor is declared here, on a node that contains the alias."
+                    },
+                ),
+            Some(anchor_range) => diagnostic.detail(
+                anchor_range,
+                markup! {
+                    "The anchor is declared here, after the alias."
+                },
+            ),
+            None => diagnostic,
+        };
+        Some(diagnostic.note(markup! {
+            "An alias can only refer to an anchor declared before it, in the same document. Declare the anchor before the alias, or fix the name of the alias."
+        }))
+    }
+}
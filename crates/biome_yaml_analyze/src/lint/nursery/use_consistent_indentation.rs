@@ -0,0 +1,247 @@
+use crate::utils::source_column;
+use biome_analyze::{
+    Ast, Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule,
+};
+use biome_console::markup;
+use biome_diagnostics::Severity;
+use biome_rowan::{AstNode, TextRange};
+use biome_yaml_syntax::{
+    AnyYamlBlockCollectionContent, YamlBlockCollection, YamlRoot, YamlSyntaxKind,
+};
+
+declare_lint_rule! {
+    /// Enforce the same indentation width for all the block collections of a file.
+    ///
+    /// YAML lets every nested mapping and sequence choose its own indentation, so a file can mix
+    /// different widths. This rule reports the nested collections that don't use the width of
+    /// the first nested collection of the file.
+    ///
+    /// The entries of a sequence that is the value of a mapping entry can also be written at the
+    /// same column as the key. This is allowed, as long as all such sequences of the file do the
+    /// same.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```yaml,expect_diagnostic
+    /// server:
+    ///   host: localhost
+    /// database:
+    ///     name: app
+    /// ```
+    ///
+    /// ```yaml,expect_diagnostic
+    /// dependencies:
+    ///   - serde
+    /// features:
+    /// - std
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```yaml
+    /// server:
+    ///   host: localhost
+    /// database:
+    ///   name: app
+    /// ```
+    ///
+    /// ```yaml
+    /// dependencies:
+    /// - serde
+    /// features:
+    /// - std
+    /// ```
+    pub UseConsistentIndentation {
+        version: "next",
+        name: "useConsistentIndentation",
+        language: "yaml",
+        sources: &[RuleSource::Yamllint("indentation").inspired()],
+        recommended: false,
+        severity: Severity::Warning,
+    }
+}
+
+pub enum InconsistentIndentation {
+    /// The collection doesn't use the indentation width of the file
+    Width {
+        range: TextRange,
+        width: usize,
+        expected_width: usize,
+        expected_range: TextRange,
+    },
+    /// The entries of the sequence are indented under their key, or they aren't, unlike the
+    /// first sequence of the file that is the value of a mapping entry
+    Sequence {
+        range: TextRange,
+        indented: bool,
+        expected_range: TextRange,
+    },
+}
+
+impl Rule for UseConsistentIndentation {
+    type Query = Ast<YamlRoot>;
+    type State = InconsistentIndentation;
+    type Signals = Box<[Self::State]>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let root = ctx.query();
+        let mut expected_width: Option<(usize, TextRange)> = None;
+        let mut expected_sequence_indented: Option<(bool, TextRange)> = None;
+        let mut signals = Vec::new();
+
+        for collection in root
+            .syntax()
+            .descendants()
+            .filter_map(AnyYamlBlockCollectionContent::cast)
+        {
+            let Some(parent) = ParentIndicator::of(&collection) else {
+                continue;
+            };
+            let Some(first_token) = collection.syntax().first_token() else {
+                continue;
+            };
+            // The collection starts on the same line of its parent, e.g. `- - a`
+            if !first_token.has_leading_newline() {
+                continue;
+            }
+
+            let range = first_token.text_trimmed_range();
+            let width = source_column(&first_token).saturating_sub(parent.column);
+
+            if matches!(
+                collection,
+                AnyYamlBlockCollectionContent::YamlBlockSequence(_)
+            ) && parent.is_mapping_entry
+            {
+                let indented = width > 0;
+                match expected_sequence_indented {
+                    None => expected_sequence_indented = Some((indented, range)),
+                    Some((expected_indented, expected_range)) if expected_indented != indented => {
+                        signals.push(InconsistentIndentation::Sequence {
+                            range,
+                            indented,
+                            expected_range,
+                        });
+                        continue;
+                    }
+                    Some(_) => {}
+                }
+            }
+
+            if width == 0 {
+                continue;
+            }
+            match expected_width {
+                None => expected_width = Some((width, range)),
+                Some((expected_width, expected_range)) if expected_width != width => {
+                    signals.push(InconsistentIndentation::Width {
+                        range,
+                        width,
+                        expected_width,
+                        expected_range,
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+
+        signals.into_boxed_slice()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            InconsistentIndentation::Width {
+                range,
+                width,
+                expected_width,
+                expected_range,
+            } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This collection is indented by "{width}" spaces, instead of "{expected_width}" spaces."
+                },
+            )
+            .detail(
+                expected_range,
+                markup! {
+                    "The first nested collection of the file is indented by "{expected_width}" spaces."
+                },
+            ),
+            InconsistentIndentation::Sequence {
+                range,
+                indented: true,
+                expected_range,
+            } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The entries of this sequence are indented under their key."
+                },
+            )
+            .detail(
+                expected_range,
+                markup! {
+                    "The entries of the first sequence of the file are written at the column of their key."
+                },
+            ),
+            InconsistentIndentation::Sequence {
+                range,
+                indented: false,
+                expected_range,
+            } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The entries of this sequence are written at the column of their key."
+                },
+            )
+            .detail(
+                expected_range,
+                markup! {
+                    "The entries of the first sequence of the file are indented under their key."
+                },
+            ),
+        };
+        Some(diagnostic.note(markup! {
+            "Use the same indentation in the whole file to make it easier to read."
+        }))
+    }
+}
+
+/// The indicator of the entry that contains a nested block collection.
+struct ParentIndicator {
+    /// The column of the entry
+    column: usize,
+    /// Whether the collection is the key or the value of a mapping entry
+    is_mapping_entry: bool,
+}
+
+impl ParentIndicator {
+    /// Returns the indicator of the entry that contains `collection`, or `None` if `collection`
+    /// isn't nested in another collection.
+    fn of(collection: &AnyYamlBlockCollectionContent) -> Option<Self> {
+        let parent = collection
+            .parent::<YamlBlockCollection>()?
+            .into_syntax()
+            .parent()?;
+        let (indicator, is_mapping_entry) = match parent.kind() {
+            // The column of the key, e.g. `key:`
+            YamlSyntaxKind::YAML_BLOCK_MAP_IMPLICIT_VALUE => (parent.parent()?, true),
+            // The column of `?` or `:`
+            YamlSyntaxKind::YAML_BLOCK_MAP_EXPLICIT_KEY
+            | YamlSyntaxKind::YAML_BLOCK_MAP_EXPLICIT_VALUE => (parent, true),
+            // The column of `-`
+            YamlSyntaxKind::YAML_BLOCK_SEQUENCE_ENTRY => (parent, false),
+            _ => return None,
+        };
+
+        Some(Self {
+            column: source_column(&indicator.first_token()?),
+            is_mapping_entry,
+        })
+    }
+}
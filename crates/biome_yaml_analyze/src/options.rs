@@ -0,0 +1,12 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::lint;
+pub type NoAmbiguousBooleans =
+    <lint::nursery::no_ambiguous_booleans::NoAmbiguousBooleans as biome_analyze::Rule>::Options;
+pub type NoDuplicateKeys =
+    <lint::nursery::no_duplicate_keys::NoDuplicateKeys as biome_analyze::Rule>::Options;
+pub type NoUndeclaredAliases =
+    <lint::nursery::no_undeclared_aliases::NoUndeclaredAliases as biome_analyze::Rule>::Options;
+pub type UseConsistentIndentation = < lint :: nursery :: use_consistent_indentation :: UseConsistentIndentation as biome_analyze :: Rule > :: Options ;
@@ -0,0 +1,57 @@
+use biome_rowan::AstNode;
+use biome_yaml_syntax::{
+    AnyYamlPropertiesCombination, YamlBlockMapExplicitKey, YamlBlockMapImplicitEntry,
+    YamlFlowInBlockNode, YamlFlowMapImplicitEntry, YamlSyntaxNode, YamlSyntaxToken,
+};
+
+/// Returns the column of the first character of `token` in the source document.
+pub(crate) fn source_column(token: &YamlSyntaxToken) -> usize {
+    let leading_trivia = token.leading_trivia();
+    let leading_text = leading_trivia.text();
+    if let Some(index) = leading_text.rfind('\n') {
+        return leading_text[index + 1..].chars().count();
+    }
+
+    let mut column = leading_text.chars().count();
+    let mut current = token.prev_token();
+    while let Some(previous) = current {
+        let text = previous.text();
+        if let Some(index) = text.rfind('\n') {
+            return column + text[index + 1..].chars().count();
+        }
+        column += text.chars().count();
+        current = previous.prev_token();
+    }
+    column
+}
+
+/// Returns `true` if the properties of a node contain a tag, e.g. `!!str`.
+pub(crate) fn has_tag(properties: Option<AnyYamlPropertiesCombination>) -> bool {
+    match properties {
+        Some(AnyYamlPropertiesCombination::YamlPropertiesTagFirst(_)) => true,
+        Some(AnyYamlPropertiesCombination::YamlPropertiesAnchorFirst(properties)) => {
+            properties.tag().is_some()
+        }
+        None => false,
+    }
+}
+
+/// Returns `true` if `node` is the key of a mapping entry, e.g. `key` in `key: value`.
+pub(crate) fn is_mapping_key(node: &YamlSyntaxNode) -> bool {
+    let Some(parent) = node.parent() else {
+        return false;
+    };
+
+    if let Some(entry) = YamlBlockMapImplicitEntry::cast_ref(&parent) {
+        entry.key().is_some_and(|key| key.syntax() == node)
+    } else if let Some(entry) = YamlFlowMapImplicitEntry::cast_ref(&parent) {
+        entry.key().is_some_and(|key| key.syntax() == node)
+    } else if YamlFlowInBlockNode::can_cast(parent.kind()) {
+        // The key of an explicit entry, e.g. `? key`
+        parent
+            .parent()
+            .is_some_and(|grand_parent| YamlBlockMapExplicitKey::can_cast(grand_parent.kind()))
+    } else {
+        false
+    }
+}
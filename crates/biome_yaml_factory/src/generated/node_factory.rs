@@ -152,23 +152,17 @@ pub fn yaml_block_map_implicit_value(colon_token: SyntaxToken) -> YamlBlockMapIm
     YamlBlockMapImplicitValueBuilder {
         colon_token,
         value: None,
-        newline_token: None,
     }
 }
 pub struct YamlBlockMapImplicitValueBuilder {
     colon_token: SyntaxToken,
     value: Option<AnyYamlBlockNode>,
-    newline_token: Option<SyntaxToken>,
 }
 impl YamlBlockMapImplicitValueBuilder {
     pub fn with_value(mut self, value: AnyYamlBlockNode) -> Self {
         self.value = Some(value);
         self
     }
-    pub fn with_newline_token(mut self, newline_token: SyntaxToken) -> Self {
-        self.newline_token = Some(newline_token);
-        self
-    }
     pub fn build(self) -> YamlBlockMapImplicitValue {
         YamlBlockMapImplicitValue::unwrap_cast(SyntaxNode::new_detached(
             YamlSyntaxKind::YAML_BLOCK_MAP_IMPLICIT_VALUE,
@@ -176,42 +170,15 @@ impl YamlBlockMapImplicitValueBuilder {
                 Some(SyntaxElement::Token(self.colon_token)),
                 self.value
                     .map(|token| SyntaxElement::Node(token.into_syntax())),
-                self.newline_token.map(|token| SyntaxElement::Token(token)),
             ],
         ))
     }
 }
-pub fn yaml_block_mapping(entries: YamlBlockMapEntryList) -> YamlBlockMappingBuilder {
-    YamlBlockMappingBuilder {
-        entries,
-        indent_token: None,
-        dedent_token: None,
-    }
-}
-pub struct YamlBlockMappingBuilder {
-    entries: YamlBlockMapEntryList,
-    indent_token: Option<SyntaxToken>,
-    dedent_token: Option<SyntaxToken>,
-}
-impl YamlBlockMappingBuilder {
-    pub fn with_indent_token(mut self, indent_token: SyntaxToken) -> Self {
-        self.indent_token = Some(indent_token);
-        self
-    }
-    pub fn with_dedent_token(mut self, dedent_token: SyntaxToken) -> Self {
-        self.dedent_token = Some(dedent_token);
-        self
-    }
-    pub fn build(self) -> YamlBlockMapping {
-        YamlBlockMapping::unwrap_cast(SyntaxNode::new_detached(
-            YamlSyntaxKind::YAML_BLOCK_MAPPING,
-            [
-                self.indent_token.map(|token| SyntaxElement::Token(token)),
-                Some(SyntaxElement::Node(self.entries.into_syntax())),
-                self.dedent_token.map(|token| SyntaxElement::Token(token)),
-            ],
-        ))
-    }
+pub fn yaml_block_mapping(entries: YamlBlockMapEntryList) -> YamlBlockMapping {
+    YamlBlockMapping::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_BLOCK_MAPPING,
+        [Some(SyntaxElement::Node(entries.into_syntax()))],
+    ))
 }
 pub fn yaml_block_scalar(content: AnyYamlBlockScalarContent) -> YamlBlockScalarBuilder {
     YamlBlockScalarBuilder {
@@ -239,37 +206,11 @@ impl YamlBlockScalarBuilder {
         ))
     }
 }
-pub fn yaml_block_sequence(entries: YamlBlockSequenceEntryList) -> YamlBlockSequenceBuilder {
-    YamlBlockSequenceBuilder {
-        entries,
-        indent_token: None,
-        dedent_token: None,
-    }
-}
-pub struct YamlBlockSequenceBuilder {
-    entries: YamlBlockSequenceEntryList,
-    indent_token: Option<SyntaxToken>,
-    dedent_token: Option<SyntaxToken>,
-}
-impl YamlBlockSequenceBuilder {
-    pub fn with_indent_token(mut self, indent_token: SyntaxToken) -> Self {
-        self.indent_token = Some(indent_token);
-        self
-    }
-    pub fn with_dedent_token(mut self, dedent_token: SyntaxToken) -> Self {
-        self.dedent_token = Some(dedent_token);
-        self
-    }
-    pub fn build(self) -> YamlBlockSequence {
-        YamlBlockSequence::unwrap_cast(SyntaxNode::new_detached(
-            YamlSyntaxKind::YAML_BLOCK_SEQUENCE,
-            [
-                self.indent_token.map(|token| SyntaxElement::Token(token)),
-                Some(SyntaxElement::Node(self.entries.into_syntax())),
-                self.dedent_token.map(|token| SyntaxElement::Token(token)),
-            ],
-        ))
-    }
+pub fn yaml_block_sequence(entries: YamlBlockSequenceEntryList) -> YamlBlockSequence {
+    YamlBlockSequence::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_BLOCK_SEQUENCE,
+        [Some(SyntaxElement::Node(entries.into_syntax()))],
+    ))
 }
 pub fn yaml_block_sequence_entry(minus_token: SyntaxToken) -> YamlBlockSequenceEntryBuilder {
     YamlBlockSequenceEntryBuilder {
@@ -297,7 +238,7 @@ impl YamlBlockSequenceEntryBuilder {
         ))
     }
 }
-pub fn yaml_compact_mapping(entries: YamlBlockSequenceEntryList) -> YamlCompactMapping {
+pub fn yaml_compact_mapping(entries: YamlBlockMapEntryList) -> YamlCompactMapping {
     YamlCompactMapping::unwrap_cast(SyntaxNode::new_detached(
         YamlSyntaxKind::YAML_COMPACT_MAPPING,
         [Some(SyntaxElement::Node(entries.into_syntax()))],
@@ -370,30 +311,11 @@ pub fn yaml_double_quoted_scalar(value_token: SyntaxToken) -> YamlDoubleQuotedSc
         [Some(SyntaxElement::Token(value_token))],
     ))
 }
-pub fn yaml_flow_in_block_node(flow: AnyYamlFlowNode) -> YamlFlowInBlockNodeBuilder {
-    YamlFlowInBlockNodeBuilder {
-        flow,
-        newline_token: None,
-    }
-}
-pub struct YamlFlowInBlockNodeBuilder {
-    flow: AnyYamlFlowNode,
-    newline_token: Option<SyntaxToken>,
-}
-impl YamlFlowInBlockNodeBuilder {
-    pub fn with_newline_token(mut self, newline_token: SyntaxToken) -> Self {
-        self.newline_token = Some(newline_token);
-        self
-    }
-    pub fn build(self) -> YamlFlowInBlockNode {
-        YamlFlowInBlockNode::unwrap_cast(SyntaxNode::new_detached(
-            YamlSyntaxKind::YAML_FLOW_IN_BLOCK_NODE,
-            [
-                Some(SyntaxElement::Node(self.flow.into_syntax())),
-                self.newline_token.map(|token| SyntaxElement::Token(token)),
-            ],
-        ))
-    }
+pub fn yaml_flow_in_block_node(flow: AnyYamlFlowNode) -> YamlFlowInBlockNode {
+    YamlFlowInBlockNode::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_FLOW_IN_BLOCK_NODE,
+        [Some(SyntaxElement::Node(flow.into_syntax()))],
+    ))
 }
 pub fn yaml_flow_json_node() -> YamlFlowJsonNodeBuilder {
     YamlFlowJsonNodeBuilder {
@@ -454,34 +376,40 @@ impl YamlFlowMapExplicitEntryBuilder {
         ))
     }
 }
-pub fn yaml_flow_map_implicit_entry(
-    colon_token: SyntaxToken,
-    value: AnyYamlFlowNode,
-) -> YamlFlowMapImplicitEntryBuilder {
+pub fn yaml_flow_map_implicit_entry() -> YamlFlowMapImplicitEntryBuilder {
     YamlFlowMapImplicitEntryBuilder {
-        colon_token,
-        value,
         key: None,
+        colon_token: None,
+        value: None,
     }
 }
 pub struct YamlFlowMapImplicitEntryBuilder {
-    colon_token: SyntaxToken,
-    value: AnyYamlFlowNode,
     key: Option<AnyYamlFlowMapImplicitKey>,
+    colon_token: Option<SyntaxToken>,
+    value: Option<AnyYamlFlowNode>,
 }
 impl YamlFlowMapImplicitEntryBuilder {
     pub fn with_key(mut self, key: AnyYamlFlowMapImplicitKey) -> Self {
         self.key = Some(key);
         self
     }
+    pub fn with_colon_token(mut self, colon_token: SyntaxToken) -> Self {
+        self.colon_token = Some(colon_token);
+        self
+    }
+    pub fn with_value(mut self, value: AnyYamlFlowNode) -> Self {
+        self.value = Some(value);
+        self
+    }
     pub fn build(self) -> YamlFlowMapImplicitEntry {
         YamlFlowMapImplicitEntry::unwrap_cast(SyntaxNode::new_detached(
             YamlSyntaxKind::YAML_FLOW_MAP_IMPLICIT_ENTRY,
             [
                 self.key
                     .map(|token| SyntaxElement::Node(token.into_syntax())),
-                Some(SyntaxElement::Token(self.colon_token)),
-                Some(SyntaxElement::Node(self.value.into_syntax())),
+                self.colon_token.map(|token| SyntaxElement::Token(token)),
+                self.value
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
             ],
         ))
     }
@@ -646,7 +574,7 @@ where
 }
 pub fn yaml_block_sequence_entry_list<I>(items: I) -> YamlBlockSequenceEntryList
 where
-    I: IntoIterator<Item = YamlBlockSequenceEntry>,
+    I: IntoIterator<Item = AnyYamlBlockSequenceEntry>,
     I::IntoIter: ExactSizeIterator,
 {
     YamlBlockSequenceEntryList::unwrap_cast(SyntaxNode::new_detached(
@@ -749,3 +677,13 @@ where
         slots,
     ))
 }
+pub fn yaml_bogus_flow_node<I>(slots: I) -> YamlBogusFlowNode
+where
+    I: IntoIterator<Item = Option<SyntaxElement>>,
+    I::IntoIter: ExactSizeIterator,
+{
+    YamlBogusFlowNode::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_BOGUS_FLOW_NODE,
+        slots,
+    ))
+}
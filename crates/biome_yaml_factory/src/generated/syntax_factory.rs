@@ -14,9 +14,10 @@ impl SyntaxFactory for YamlSyntaxFactory {
         children: ParsedChildren<Self::Kind>,
     ) -> RawSyntaxNode<Self::Kind> {
         match kind {
-            YAML_BOGUS | YAML_BOGUS_BLOCK_MAP_ENTRY | YAML_BOGUS_BLOCK_NODE => {
-                RawSyntaxNode::new(kind, children.into_iter().map(Some))
-            }
+            YAML_BOGUS
+            | YAML_BOGUS_BLOCK_MAP_ENTRY
+            | YAML_BOGUS_BLOCK_NODE
+            | YAML_BOGUS_FLOW_NODE => RawSyntaxNode::new(kind, children.into_iter().map(Some)),
             YAML_ALIAS_NODE => {
                 let mut elements = (&children).into_iter();
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
@@ -187,7 +188,7 @@ impl SyntaxFactory for YamlSyntaxFactory {
             }
             YAML_BLOCK_MAP_IMPLICIT_VALUE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
                     if element.kind() == T ! [:] {
@@ -203,13 +204,6 @@ impl SyntaxFactory for YamlSyntaxFactory {
                     }
                 }
                 slots.next_slot();
-                if let Some(element) = &current_element {
-                    if element.kind() == NEWLINE {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
                         YAML_BLOCK_MAP_IMPLICIT_VALUE.to_bogus(),
@@ -220,15 +214,8 @@ impl SyntaxFactory for YamlSyntaxFactory {
             }
             YAML_BLOCK_MAPPING => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
-                if let Some(element) = &current_element {
-                    if element.kind() == INDENT {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
                 if let Some(element) = &current_element {
                     if YamlBlockMapEntryList::can_cast(element.kind()) {
                         slots.mark_present();
@@ -236,13 +223,6 @@ impl SyntaxFactory for YamlSyntaxFactory {
                     }
                 }
                 slots.next_slot();
-                if let Some(element) = &current_element {
-                    if element.kind() == DEDENT {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
                         YAML_BLOCK_MAPPING.to_bogus(),
@@ -279,15 +259,8 @@ impl SyntaxFactory for YamlSyntaxFactory {
             }
             YAML_BLOCK_SEQUENCE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<3usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
-                if let Some(element) = &current_element {
-                    if element.kind() == INDENT {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
                 if let Some(element) = &current_element {
                     if YamlBlockSequenceEntryList::can_cast(element.kind()) {
                         slots.mark_present();
@@ -295,13 +268,6 @@ impl SyntaxFactory for YamlSyntaxFactory {
                     }
                 }
                 slots.next_slot();
-                if let Some(element) = &current_element {
-                    if element.kind() == DEDENT {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
                         YAML_BLOCK_SEQUENCE.to_bogus(),
@@ -341,7 +307,7 @@ impl SyntaxFactory for YamlSyntaxFactory {
                 let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
-                    if YamlBlockSequenceEntryList::can_cast(element.kind()) {
+                    if YamlBlockMapEntryList::can_cast(element.kind()) {
                         slots.mark_present();
                         current_element = elements.next();
                     }
@@ -461,7 +427,7 @@ impl SyntaxFactory for YamlSyntaxFactory {
             }
             YAML_FLOW_IN_BLOCK_NODE => {
                 let mut elements = (&children).into_iter();
-                let mut slots: RawNodeSlots<2usize> = RawNodeSlots::default();
+                let mut slots: RawNodeSlots<1usize> = RawNodeSlots::default();
                 let mut current_element = elements.next();
                 if let Some(element) = &current_element {
                     if AnyYamlFlowNode::can_cast(element.kind()) {
@@ -470,13 +436,6 @@ impl SyntaxFactory for YamlSyntaxFactory {
                     }
                 }
                 slots.next_slot();
-                if let Some(element) = &current_element {
-                    if element.kind() == NEWLINE {
-                        slots.mark_present();
-                        current_element = elements.next();
-                    }
-                }
-                slots.next_slot();
                 if current_element.is_some() {
                     return RawSyntaxNode::new(
                         YAML_FLOW_IN_BLOCK_NODE.to_bogus(),
@@ -839,7 +798,7 @@ impl SyntaxFactory for YamlSyntaxFactory {
                 Self::make_node_list_syntax(kind, children, AnyYamlBlockMapEntry::can_cast)
             }
             YAML_BLOCK_SEQUENCE_ENTRY_LIST => {
-                Self::make_node_list_syntax(kind, children, YamlBlockSequenceEntry::can_cast)
+                Self::make_node_list_syntax(kind, children, AnyYamlBlockSequenceEntry::can_cast)
             }
             YAML_DIRECTIVE_LIST => {
                 Self::make_node_list_syntax(kind, children, YamlDirective::can_cast)
@@ -852,14 +811,14 @@ impl SyntaxFactory for YamlSyntaxFactory {
                 children,
                 AnyYamlFlowMapEntry::can_cast,
                 T ! [,],
-                false,
+                true,
             ),
             YAML_FLOW_SEQUENCE_ENTRY_LIST => Self::make_separated_list_syntax(
                 kind,
                 children,
                 AnyYamlFlowSequenceEntry::can_cast,
                 T ! [,],
-                false,
+                true,
             ),
             _ => unreachable!("Is {:?} a token?", kind),
         }
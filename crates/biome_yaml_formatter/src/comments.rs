@@ -0,0 +1,138 @@
+use crate::prelude::*;
+use biome_diagnostics::category;
+use biome_formatter::comments::{
+    CommentKind, CommentPlacement, CommentStyle, CommentTextPosition, Comments, DecoratedComment,
+    SourceComment,
+};
+use biome_formatter::formatter::Formatter;
+use biome_formatter::{CstFormatContext, FormatRefWithRule, FormatResult, FormatRule, write};
+use biome_rowan::SyntaxTriviaPieceComments;
+use biome_suppression::parse_suppression_comment;
+use biome_yaml_syntax::{YamlLanguage, YamlSyntaxKind, YamlSyntaxNode};
+
+pub type YamlComments = Comments<YamlLanguage>;
+
+#[derive(Default)]
+pub struct FormatYamlLeadingComment;
+
+impl FormatRule<SourceComment<YamlLanguage>> for FormatYamlLeadingComment {
+    type Context = YamlFormatContext;
+
+    fn fmt(
+        &self,
+        comment: &SourceComment<YamlLanguage>,
+        f: &mut Formatter<Self::Context>,
+    ) -> FormatResult<()> {
+        write!(f, [comment.piece().as_piece()])
+    }
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub struct YamlCommentStyle;
+
+impl CommentStyle for YamlCommentStyle {
+    type Language = YamlLanguage;
+
+    fn is_suppression(text: &str) -> bool {
+        parse_suppression_comment(text)
+            .filter_map(Result::ok)
+            .flat_map(|suppression| suppression.categories)
+            .any(|(key, ..)| key == category!("format"))
+    }
+
+    fn get_comment_kind(_comment: &SyntaxTriviaPieceComments<Self::Language>) -> CommentKind {
+        CommentKind::Line
+    }
+
+    fn place_comment(
+        &self,
+        comment: DecoratedComment<Self::Language>,
+    ) -> CommentPlacement<Self::Language> {
+        handle_indicator_comment(comment)
+    }
+}
+
+/// Keeps the comments that directly follow an indicator on the line of the indicator:
+///
+/// ```yaml
+/// key: # comment
+///   nested: value
+/// - # comment
+///   item
+/// --- # comment
+/// ```
+///
+/// These comments become dangling comments of the node that owns the indicator, which prints
+/// them with [FormatYamlIndicatorComments]. By default, they would be leading comments of the
+/// value and would move to the next line.
+fn handle_indicator_comment(
+    comment: DecoratedComment<YamlLanguage>,
+) -> CommentPlacement<YamlLanguage> {
+    if comment.text_position() != CommentTextPosition::EndOfLine {
+        return CommentPlacement::Default(comment);
+    }
+
+    let token_kind = comment.piece().as_piece().token().kind();
+    let is_after_indicator = match comment.enclosing_node().kind() {
+        YamlSyntaxKind::YAML_DOCUMENT => matches!(
+            token_kind,
+            YamlSyntaxKind::DIRECTIVE_END | YamlSyntaxKind::DOC_END
+        ),
+        YamlSyntaxKind::YAML_BLOCK_MAP_IMPLICIT_VALUE
+        | YamlSyntaxKind::YAML_BLOCK_MAP_EXPLICIT_KEY
+        | YamlSyntaxKind::YAML_BLOCK_MAP_EXPLICIT_VALUE
+        | YamlSyntaxKind::YAML_BLOCK_SEQUENCE_ENTRY => comment.preceding_node().is_none(),
+        YamlSyntaxKind::YAML_DIRECTIVE => true,
+        _ => false,
+    };
+
+    if is_after_indicator {
+        CommentPlacement::dangling(comment.enclosing_node().clone(), comment)
+    } else {
+        CommentPlacement::Default(comment)
+    }
+}
+
+/// Formats the dangling comments of a node that follow one of its indicators on the same line,
+/// see [handle_indicator_comment].
+///
+/// Only the comments that follow a token of kind `after` are formatted, which allows the
+/// document to format the comments of the `---` and `...` markers separately.
+pub(crate) struct FormatYamlIndicatorComments<'a> {
+    node: &'a YamlSyntaxNode,
+    after: Option<YamlSyntaxKind>,
+}
+
+impl<'a> FormatYamlIndicatorComments<'a> {
+    pub(crate) fn new(node: &'a YamlSyntaxNode) -> Self {
+        Self { node, after: None }
+    }
+
+    pub(crate) fn after(mut self, kind: YamlSyntaxKind) -> Self {
+        self.after = Some(kind);
+        self
+    }
+}
+
+impl Format<YamlFormatContext> for FormatYamlIndicatorComments<'_> {
+    fn fmt(&self, f: &mut YamlFormatter) -> FormatResult<()> {
+        let comments = f.context().comments().clone();
+        for comment in comments.dangling_comments(self.node) {
+            if self
+                .after
+                .is_some_and(|kind| comment.piece().as_piece().token().kind() != kind)
+            {
+                continue;
+            }
+            let format_comment = FormatRefWithRule::new(comment, FormatYamlLeadingComment);
+            write!(f, [space(), format_comment])?;
+            comment.mark_formatted();
+        }
+        Ok(())
+    }
+}
+
+/// Returns `true` if `node` has dangling comments following one of its indicators.
+pub(crate) fn has_indicator_comments(node: &YamlSyntaxNode, f: &YamlFormatter) -> bool {
+    f.context().comments().has_dangling_comments(node)
+}
@@ -0,0 +1,162 @@
+use crate::comments::{FormatYamlLeadingComment, YamlCommentStyle, YamlComments};
+use biome_formatter::printer::PrinterOptions;
+use biome_formatter::{
+    BracketSpacing, CstFormatContext, FormatContext, FormatOptions, IndentStyle, IndentWidth,
+    LineEnding, LineWidth, QuoteStyle, TransformSourceMap,
+};
+use biome_yaml_syntax::{YamlFileSource, YamlLanguage};
+use std::fmt;
+use std::rc::Rc;
+
+#[derive(Debug)]
+pub struct YamlFormatContext {
+    options: YamlFormatOptions,
+    /// The comments of the nodes and tokens in the document.
+    comments: Rc<YamlComments>,
+    source_map: Option<TransformSourceMap>,
+}
+
+impl YamlFormatContext {
+    pub fn new(options: YamlFormatOptions, comments: YamlComments) -> Self {
+        Self {
+            options,
+            comments: Rc::new(comments),
+            source_map: None,
+        }
+    }
+
+    pub fn with_source_map(mut self, source_map: Option<TransformSourceMap>) -> Self {
+        self.source_map = source_map;
+        self
+    }
+}
+
+impl FormatContext for YamlFormatContext {
+    type Options = YamlFormatOptions;
+
+    fn options(&self) -> &Self::Options {
+        &self.options
+    }
+
+    fn source_map(&self) -> Option<&TransformSourceMap> {
+        self.source_map.as_ref()
+    }
+}
+
+impl CstFormatContext for YamlFormatContext {
+    type Language = YamlLanguage;
+    type Style = YamlCommentStyle;
+    type CommentRule = FormatYamlLeadingComment;
+
+    fn comments(&self) -> &YamlComments {
+        &self.comments
+    }
+}
+
+/// The options of the YAML formatter.
+///
+/// There's no indent style option: YAML doesn't allow tabs in indentation, so the formatter
+/// always indents with spaces.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct YamlFormatOptions {
+    indent_width: IndentWidth,
+    line_ending: LineEnding,
+    line_width: LineWidth,
+    quote_style: QuoteStyle,
+    bracket_spacing: BracketSpacing,
+    _file_source: YamlFileSource,
+}
+
+impl YamlFormatOptions {
+    pub fn new(file_source: YamlFileSource) -> Self {
+        Self {
+            _file_source: file_source,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_indent_width(mut self, indent_width: IndentWidth) -> Self {
+        self.indent_width = indent_width;
+        self
+    }
+
+    pub fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    pub fn with_line_width(mut self, line_width: LineWidth) -> Self {
+        self.line_width = line_width;
+        self
+    }
+
+    pub fn with_quote_style(mut self, quote_style: QuoteStyle) -> Self {
+        self.quote_style = quote_style;
+        self
+    }
+
+    pub fn with_bracket_spacing(mut self, bracket_spacing: BracketSpacing) -> Self {
+        self.bracket_spacing = bracket_spacing;
+        self
+    }
+
+    pub fn set_indent_width(&mut self, indent_width: IndentWidth) {
+        self.indent_width = indent_width;
+    }
+
+    pub fn set_line_ending(&mut self, line_ending: LineEnding) {
+        self.line_ending = line_ending;
+    }
+
+    pub fn set_line_width(&mut self, line_width: LineWidth) {
+        self.line_width = line_width;
+    }
+
+    pub fn set_quote_style(&mut self, quote_style: QuoteStyle) {
+        self.quote_style = quote_style;
+    }
+
+    pub fn set_bracket_spacing(&mut self, bracket_spacing: BracketSpacing) {
+        self.bracket_spacing = bracket_spacing;
+    }
+
+    pub fn quote_style(&self) -> QuoteStyle {
+        self.quote_style
+    }
+
+    pub fn bracket_spacing(&self) -> BracketSpacing {
+        self.bracket_spacing
+    }
+}
+
+impl FormatOptions for YamlFormatOptions {
+    fn indent_style(&self) -> IndentStyle {
+        IndentStyle::Space
+    }
+
+    fn indent_width(&self) -> IndentWidth {
+        self.indent_width
+    }
+
+    fn line_width(&self) -> LineWidth {
+        self.line_width
+    }
+
+    fn line_ending(&self) -> LineEnding {
+        self.line_ending
+    }
+
+    fn as_print_options(&self) -> PrinterOptions {
+        PrinterOptions::from(self)
+    }
+}
+
+impl fmt::Display for YamlFormatOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Indent width: {}", self.indent_width.value())?;
+        writeln!(f, "Line ending: {}", self.line_ending)?;
+        writeln!(f, "Line width: {}", self.line_width.value())?;
+        writeln!(f, "Quote style: {}", self.quote_style)?;
+        writeln!(f, "Bracket spacing: {}", self.bracket_spacing.value())
+    }
+}
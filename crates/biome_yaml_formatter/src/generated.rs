@@ -0,0 +1,1808 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+#![allow(clippy::use_self)]
+#![expect(clippy::default_constructed_unit_structs)]
+use crate::{
+    AsFormat, FormatBogusNodeRule, FormatNodeRule, IntoFormat, YamlFormatContext, YamlFormatter,
+};
+use biome_formatter::{FormatOwnedWithRule, FormatRefWithRule, FormatResult, FormatRule};
+impl FormatRule<biome_yaml_syntax::YamlAliasNode>
+    for crate::yaml::auxiliary::alias_node::FormatYamlAliasNode
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlAliasNode,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlAliasNode>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlAliasNode {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlAliasNode,
+        crate::yaml::auxiliary::alias_node::FormatYamlAliasNode,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::alias_node::FormatYamlAliasNode::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlAliasNode {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlAliasNode,
+        crate::yaml::auxiliary::alias_node::FormatYamlAliasNode,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::alias_node::FormatYamlAliasNode::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlAnchorProperty>
+    for crate::yaml::auxiliary::anchor_property::FormatYamlAnchorProperty
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlAnchorProperty,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlAnchorProperty>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlAnchorProperty {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlAnchorProperty,
+        crate::yaml::auxiliary::anchor_property::FormatYamlAnchorProperty,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::anchor_property::FormatYamlAnchorProperty::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlAnchorProperty {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlAnchorProperty,
+        crate::yaml::auxiliary::anchor_property::FormatYamlAnchorProperty,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::anchor_property::FormatYamlAnchorProperty::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlBlockCollection>
+    for crate::yaml::auxiliary::block_collection::FormatYamlBlockCollection
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlBlockCollection,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlBlockCollection>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockCollection {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBlockCollection,
+        crate::yaml::auxiliary::block_collection::FormatYamlBlockCollection,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::block_collection::FormatYamlBlockCollection::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockCollection {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBlockCollection,
+        crate::yaml::auxiliary::block_collection::FormatYamlBlockCollection,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::block_collection::FormatYamlBlockCollection::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlBlockMapExplicitEntry>
+    for crate::yaml::auxiliary::block_map_explicit_entry::FormatYamlBlockMapExplicitEntry
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlBlockMapExplicitEntry,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlBlockMapExplicitEntry>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockMapExplicitEntry {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBlockMapExplicitEntry,
+        crate::yaml::auxiliary::block_map_explicit_entry::FormatYamlBlockMapExplicitEntry,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: yaml :: auxiliary :: block_map_explicit_entry :: FormatYamlBlockMapExplicitEntry :: default ())
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockMapExplicitEntry {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBlockMapExplicitEntry,
+        crate::yaml::auxiliary::block_map_explicit_entry::FormatYamlBlockMapExplicitEntry,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: yaml :: auxiliary :: block_map_explicit_entry :: FormatYamlBlockMapExplicitEntry :: default ())
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlBlockMapExplicitKey>
+    for crate::yaml::auxiliary::block_map_explicit_key::FormatYamlBlockMapExplicitKey
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlBlockMapExplicitKey,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlBlockMapExplicitKey>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockMapExplicitKey {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBlockMapExplicitKey,
+        crate::yaml::auxiliary::block_map_explicit_key::FormatYamlBlockMapExplicitKey,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::block_map_explicit_key::FormatYamlBlockMapExplicitKey::default(
+            ),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockMapExplicitKey {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBlockMapExplicitKey,
+        crate::yaml::auxiliary::block_map_explicit_key::FormatYamlBlockMapExplicitKey,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::block_map_explicit_key::FormatYamlBlockMapExplicitKey::default(
+            ),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlBlockMapExplicitValue>
+    for crate::yaml::auxiliary::block_map_explicit_value::FormatYamlBlockMapExplicitValue
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlBlockMapExplicitValue,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlBlockMapExplicitValue>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockMapExplicitValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBlockMapExplicitValue,
+        crate::yaml::auxiliary::block_map_explicit_value::FormatYamlBlockMapExplicitValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: yaml :: auxiliary :: block_map_explicit_value :: FormatYamlBlockMapExplicitValue :: default ())
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockMapExplicitValue {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBlockMapExplicitValue,
+        crate::yaml::auxiliary::block_map_explicit_value::FormatYamlBlockMapExplicitValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: yaml :: auxiliary :: block_map_explicit_value :: FormatYamlBlockMapExplicitValue :: default ())
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlBlockMapImplicitEntry>
+    for crate::yaml::auxiliary::block_map_implicit_entry::FormatYamlBlockMapImplicitEntry
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlBlockMapImplicitEntry,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlBlockMapImplicitEntry>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockMapImplicitEntry {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBlockMapImplicitEntry,
+        crate::yaml::auxiliary::block_map_implicit_entry::FormatYamlBlockMapImplicitEntry,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: yaml :: auxiliary :: block_map_implicit_entry :: FormatYamlBlockMapImplicitEntry :: default ())
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockMapImplicitEntry {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBlockMapImplicitEntry,
+        crate::yaml::auxiliary::block_map_implicit_entry::FormatYamlBlockMapImplicitEntry,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: yaml :: auxiliary :: block_map_implicit_entry :: FormatYamlBlockMapImplicitEntry :: default ())
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlBlockMapImplicitValue>
+    for crate::yaml::auxiliary::block_map_implicit_value::FormatYamlBlockMapImplicitValue
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlBlockMapImplicitValue,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlBlockMapImplicitValue>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockMapImplicitValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBlockMapImplicitValue,
+        crate::yaml::auxiliary::block_map_implicit_value::FormatYamlBlockMapImplicitValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: yaml :: auxiliary :: block_map_implicit_value :: FormatYamlBlockMapImplicitValue :: default ())
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockMapImplicitValue {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBlockMapImplicitValue,
+        crate::yaml::auxiliary::block_map_implicit_value::FormatYamlBlockMapImplicitValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: yaml :: auxiliary :: block_map_implicit_value :: FormatYamlBlockMapImplicitValue :: default ())
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlBlockMapping>
+    for crate::yaml::auxiliary::block_mapping::FormatYamlBlockMapping
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlBlockMapping,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlBlockMapping>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockMapping {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBlockMapping,
+        crate::yaml::auxiliary::block_mapping::FormatYamlBlockMapping,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::block_mapping::FormatYamlBlockMapping::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockMapping {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBlockMapping,
+        crate::yaml::auxiliary::block_mapping::FormatYamlBlockMapping,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::block_mapping::FormatYamlBlockMapping::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlBlockScalar>
+    for crate::yaml::auxiliary::block_scalar::FormatYamlBlockScalar
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlBlockScalar,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlBlockScalar>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockScalar {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBlockScalar,
+        crate::yaml::auxiliary::block_scalar::FormatYamlBlockScalar,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::block_scalar::FormatYamlBlockScalar::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockScalar {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBlockScalar,
+        crate::yaml::auxiliary::block_scalar::FormatYamlBlockScalar,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::block_scalar::FormatYamlBlockScalar::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlBlockSequence>
+    for crate::yaml::auxiliary::block_sequence::FormatYamlBlockSequence
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlBlockSequence,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlBlockSequence>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockSequence {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBlockSequence,
+        crate::yaml::auxiliary::block_sequence::FormatYamlBlockSequence,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::block_sequence::FormatYamlBlockSequence::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockSequence {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBlockSequence,
+        crate::yaml::auxiliary::block_sequence::FormatYamlBlockSequence,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::block_sequence::FormatYamlBlockSequence::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlBlockSequenceEntry>
+    for crate::yaml::auxiliary::block_sequence_entry::FormatYamlBlockSequenceEntry
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlBlockSequenceEntry,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlBlockSequenceEntry>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockSequenceEntry {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBlockSequenceEntry,
+        crate::yaml::auxiliary::block_sequence_entry::FormatYamlBlockSequenceEntry,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::block_sequence_entry::FormatYamlBlockSequenceEntry::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockSequenceEntry {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBlockSequenceEntry,
+        crate::yaml::auxiliary::block_sequence_entry::FormatYamlBlockSequenceEntry,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::block_sequence_entry::FormatYamlBlockSequenceEntry::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlCompactMapping>
+    for crate::yaml::auxiliary::compact_mapping::FormatYamlCompactMapping
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlCompactMapping,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlCompactMapping>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlCompactMapping {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlCompactMapping,
+        crate::yaml::auxiliary::compact_mapping::FormatYamlCompactMapping,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::compact_mapping::FormatYamlCompactMapping::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlCompactMapping {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlCompactMapping,
+        crate::yaml::auxiliary::compact_mapping::FormatYamlCompactMapping,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::compact_mapping::FormatYamlCompactMapping::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlCompactSequence>
+    for crate::yaml::auxiliary::compact_sequence::FormatYamlCompactSequence
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlCompactSequence,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlCompactSequence>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlCompactSequence {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlCompactSequence,
+        crate::yaml::auxiliary::compact_sequence::FormatYamlCompactSequence,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::compact_sequence::FormatYamlCompactSequence::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlCompactSequence {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlCompactSequence,
+        crate::yaml::auxiliary::compact_sequence::FormatYamlCompactSequence,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::compact_sequence::FormatYamlCompactSequence::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlDirective>
+    for crate::yaml::auxiliary::directive::FormatYamlDirective
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlDirective,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlDirective>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlDirective {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlDirective,
+        crate::yaml::auxiliary::directive::FormatYamlDirective,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::directive::FormatYamlDirective::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlDirective {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlDirective,
+        crate::yaml::auxiliary::directive::FormatYamlDirective,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::directive::FormatYamlDirective::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlDocument>
+    for crate::yaml::auxiliary::document::FormatYamlDocument
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlDocument,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlDocument>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlDocument {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlDocument,
+        crate::yaml::auxiliary::document::FormatYamlDocument,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::document::FormatYamlDocument::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlDocument {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlDocument,
+        crate::yaml::auxiliary::document::FormatYamlDocument,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::document::FormatYamlDocument::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlDoubleQuotedScalar>
+    for crate::yaml::auxiliary::double_quoted_scalar::FormatYamlDoubleQuotedScalar
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlDoubleQuotedScalar,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlDoubleQuotedScalar>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlDoubleQuotedScalar {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlDoubleQuotedScalar,
+        crate::yaml::auxiliary::double_quoted_scalar::FormatYamlDoubleQuotedScalar,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::double_quoted_scalar::FormatYamlDoubleQuotedScalar::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlDoubleQuotedScalar {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlDoubleQuotedScalar,
+        crate::yaml::auxiliary::double_quoted_scalar::FormatYamlDoubleQuotedScalar,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::double_quoted_scalar::FormatYamlDoubleQuotedScalar::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlFlowInBlockNode>
+    for crate::yaml::auxiliary::flow_in_block_node::FormatYamlFlowInBlockNode
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlFlowInBlockNode,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlFlowInBlockNode>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowInBlockNode {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlFlowInBlockNode,
+        crate::yaml::auxiliary::flow_in_block_node::FormatYamlFlowInBlockNode,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::flow_in_block_node::FormatYamlFlowInBlockNode::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowInBlockNode {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlFlowInBlockNode,
+        crate::yaml::auxiliary::flow_in_block_node::FormatYamlFlowInBlockNode,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::flow_in_block_node::FormatYamlFlowInBlockNode::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlFlowJsonNode>
+    for crate::yaml::auxiliary::flow_json_node::FormatYamlFlowJsonNode
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlFlowJsonNode,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlFlowJsonNode>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowJsonNode {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlFlowJsonNode,
+        crate::yaml::auxiliary::flow_json_node::FormatYamlFlowJsonNode,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::flow_json_node::FormatYamlFlowJsonNode::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowJsonNode {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlFlowJsonNode,
+        crate::yaml::auxiliary::flow_json_node::FormatYamlFlowJsonNode,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::flow_json_node::FormatYamlFlowJsonNode::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlFlowMapExplicitEntry>
+    for crate::yaml::auxiliary::flow_map_explicit_entry::FormatYamlFlowMapExplicitEntry
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlFlowMapExplicitEntry,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlFlowMapExplicitEntry>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowMapExplicitEntry {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlFlowMapExplicitEntry,
+        crate::yaml::auxiliary::flow_map_explicit_entry::FormatYamlFlowMapExplicitEntry,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: yaml :: auxiliary :: flow_map_explicit_entry :: FormatYamlFlowMapExplicitEntry :: default ())
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowMapExplicitEntry {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlFlowMapExplicitEntry,
+        crate::yaml::auxiliary::flow_map_explicit_entry::FormatYamlFlowMapExplicitEntry,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: yaml :: auxiliary :: flow_map_explicit_entry :: FormatYamlFlowMapExplicitEntry :: default ())
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlFlowMapImplicitEntry>
+    for crate::yaml::auxiliary::flow_map_implicit_entry::FormatYamlFlowMapImplicitEntry
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlFlowMapImplicitEntry,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlFlowMapImplicitEntry>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowMapImplicitEntry {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlFlowMapImplicitEntry,
+        crate::yaml::auxiliary::flow_map_implicit_entry::FormatYamlFlowMapImplicitEntry,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: yaml :: auxiliary :: flow_map_implicit_entry :: FormatYamlFlowMapImplicitEntry :: default ())
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowMapImplicitEntry {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlFlowMapImplicitEntry,
+        crate::yaml::auxiliary::flow_map_implicit_entry::FormatYamlFlowMapImplicitEntry,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: yaml :: auxiliary :: flow_map_implicit_entry :: FormatYamlFlowMapImplicitEntry :: default ())
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlFlowMapping>
+    for crate::yaml::auxiliary::flow_mapping::FormatYamlFlowMapping
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlFlowMapping,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlFlowMapping>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowMapping {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlFlowMapping,
+        crate::yaml::auxiliary::flow_mapping::FormatYamlFlowMapping,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::flow_mapping::FormatYamlFlowMapping::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowMapping {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlFlowMapping,
+        crate::yaml::auxiliary::flow_mapping::FormatYamlFlowMapping,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::flow_mapping::FormatYamlFlowMapping::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlFlowSequence>
+    for crate::yaml::auxiliary::flow_sequence::FormatYamlFlowSequence
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlFlowSequence,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlFlowSequence>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowSequence {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlFlowSequence,
+        crate::yaml::auxiliary::flow_sequence::FormatYamlFlowSequence,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::flow_sequence::FormatYamlFlowSequence::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowSequence {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlFlowSequence,
+        crate::yaml::auxiliary::flow_sequence::FormatYamlFlowSequence,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::flow_sequence::FormatYamlFlowSequence::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlFlowYamlNode>
+    for crate::yaml::auxiliary::flow_yaml_node::FormatYamlFlowYamlNode
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlFlowYamlNode,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlFlowYamlNode>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowYamlNode {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlFlowYamlNode,
+        crate::yaml::auxiliary::flow_yaml_node::FormatYamlFlowYamlNode,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::flow_yaml_node::FormatYamlFlowYamlNode::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowYamlNode {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlFlowYamlNode,
+        crate::yaml::auxiliary::flow_yaml_node::FormatYamlFlowYamlNode,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::flow_yaml_node::FormatYamlFlowYamlNode::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlFoldedScalar>
+    for crate::yaml::auxiliary::folded_scalar::FormatYamlFoldedScalar
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlFoldedScalar,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlFoldedScalar>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlFoldedScalar {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlFoldedScalar,
+        crate::yaml::auxiliary::folded_scalar::FormatYamlFoldedScalar,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::folded_scalar::FormatYamlFoldedScalar::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlFoldedScalar {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlFoldedScalar,
+        crate::yaml::auxiliary::folded_scalar::FormatYamlFoldedScalar,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::folded_scalar::FormatYamlFoldedScalar::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlLiteralScalar>
+    for crate::yaml::auxiliary::literal_scalar::FormatYamlLiteralScalar
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlLiteralScalar,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlLiteralScalar>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlLiteralScalar {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlLiteralScalar,
+        crate::yaml::auxiliary::literal_scalar::FormatYamlLiteralScalar,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::literal_scalar::FormatYamlLiteralScalar::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlLiteralScalar {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlLiteralScalar,
+        crate::yaml::auxiliary::literal_scalar::FormatYamlLiteralScalar,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::literal_scalar::FormatYamlLiteralScalar::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlPlainScalar>
+    for crate::yaml::auxiliary::plain_scalar::FormatYamlPlainScalar
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlPlainScalar,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlPlainScalar>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlPlainScalar {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlPlainScalar,
+        crate::yaml::auxiliary::plain_scalar::FormatYamlPlainScalar,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::plain_scalar::FormatYamlPlainScalar::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlPlainScalar {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlPlainScalar,
+        crate::yaml::auxiliary::plain_scalar::FormatYamlPlainScalar,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::plain_scalar::FormatYamlPlainScalar::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlPropertiesAnchorFirst>
+    for crate::yaml::auxiliary::properties_anchor_first::FormatYamlPropertiesAnchorFirst
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlPropertiesAnchorFirst,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlPropertiesAnchorFirst>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlPropertiesAnchorFirst {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlPropertiesAnchorFirst,
+        crate::yaml::auxiliary::properties_anchor_first::FormatYamlPropertiesAnchorFirst,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: yaml :: auxiliary :: properties_anchor_first :: FormatYamlPropertiesAnchorFirst :: default ())
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlPropertiesAnchorFirst {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlPropertiesAnchorFirst,
+        crate::yaml::auxiliary::properties_anchor_first::FormatYamlPropertiesAnchorFirst,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: yaml :: auxiliary :: properties_anchor_first :: FormatYamlPropertiesAnchorFirst :: default ())
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlPropertiesTagFirst>
+    for crate::yaml::auxiliary::properties_tag_first::FormatYamlPropertiesTagFirst
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlPropertiesTagFirst,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlPropertiesTagFirst>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlPropertiesTagFirst {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlPropertiesTagFirst,
+        crate::yaml::auxiliary::properties_tag_first::FormatYamlPropertiesTagFirst,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::properties_tag_first::FormatYamlPropertiesTagFirst::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlPropertiesTagFirst {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlPropertiesTagFirst,
+        crate::yaml::auxiliary::properties_tag_first::FormatYamlPropertiesTagFirst,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::properties_tag_first::FormatYamlPropertiesTagFirst::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlRoot> for crate::yaml::auxiliary::root::FormatYamlRoot {
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_yaml_syntax::YamlRoot, f: &mut YamlFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlRoot>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlRoot {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlRoot,
+        crate::yaml::auxiliary::root::FormatYamlRoot,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::root::FormatYamlRoot::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlRoot {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlRoot,
+        crate::yaml::auxiliary::root::FormatYamlRoot,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::root::FormatYamlRoot::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlSingleQuotedScalar>
+    for crate::yaml::auxiliary::single_quoted_scalar::FormatYamlSingleQuotedScalar
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlSingleQuotedScalar,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlSingleQuotedScalar>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlSingleQuotedScalar {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlSingleQuotedScalar,
+        crate::yaml::auxiliary::single_quoted_scalar::FormatYamlSingleQuotedScalar,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::single_quoted_scalar::FormatYamlSingleQuotedScalar::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlSingleQuotedScalar {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlSingleQuotedScalar,
+        crate::yaml::auxiliary::single_quoted_scalar::FormatYamlSingleQuotedScalar,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::single_quoted_scalar::FormatYamlSingleQuotedScalar::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlTagProperty>
+    for crate::yaml::auxiliary::tag_property::FormatYamlTagProperty
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlTagProperty,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlTagProperty>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlTagProperty {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlTagProperty,
+        crate::yaml::auxiliary::tag_property::FormatYamlTagProperty,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::tag_property::FormatYamlTagProperty::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlTagProperty {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlTagProperty,
+        crate::yaml::auxiliary::tag_property::FormatYamlTagProperty,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::tag_property::FormatYamlTagProperty::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockMapEntryList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBlockMapEntryList,
+        crate::yaml::lists::block_map_entry_list::FormatYamlBlockMapEntryList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::lists::block_map_entry_list::FormatYamlBlockMapEntryList::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockMapEntryList {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBlockMapEntryList,
+        crate::yaml::lists::block_map_entry_list::FormatYamlBlockMapEntryList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::lists::block_map_entry_list::FormatYamlBlockMapEntryList::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockSequenceEntryList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBlockSequenceEntryList,
+        crate::yaml::lists::block_sequence_entry_list::FormatYamlBlockSequenceEntryList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: yaml :: lists :: block_sequence_entry_list :: FormatYamlBlockSequenceEntryList :: default ())
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockSequenceEntryList {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBlockSequenceEntryList,
+        crate::yaml::lists::block_sequence_entry_list::FormatYamlBlockSequenceEntryList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: yaml :: lists :: block_sequence_entry_list :: FormatYamlBlockSequenceEntryList :: default ())
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlDirectiveList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlDirectiveList,
+        crate::yaml::lists::directive_list::FormatYamlDirectiveList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::lists::directive_list::FormatYamlDirectiveList::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlDirectiveList {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlDirectiveList,
+        crate::yaml::lists::directive_list::FormatYamlDirectiveList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::lists::directive_list::FormatYamlDirectiveList::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlDocumentList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlDocumentList,
+        crate::yaml::lists::document_list::FormatYamlDocumentList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::lists::document_list::FormatYamlDocumentList::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlDocumentList {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlDocumentList,
+        crate::yaml::lists::document_list::FormatYamlDocumentList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::lists::document_list::FormatYamlDocumentList::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowMapEntryList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlFlowMapEntryList,
+        crate::yaml::lists::flow_map_entry_list::FormatYamlFlowMapEntryList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::lists::flow_map_entry_list::FormatYamlFlowMapEntryList::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowMapEntryList {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlFlowMapEntryList,
+        crate::yaml::lists::flow_map_entry_list::FormatYamlFlowMapEntryList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::lists::flow_map_entry_list::FormatYamlFlowMapEntryList::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowSequenceEntryList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlFlowSequenceEntryList,
+        crate::yaml::lists::flow_sequence_entry_list::FormatYamlFlowSequenceEntryList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::lists::flow_sequence_entry_list::FormatYamlFlowSequenceEntryList::default(
+            ),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowSequenceEntryList {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlFlowSequenceEntryList,
+        crate::yaml::lists::flow_sequence_entry_list::FormatYamlFlowSequenceEntryList,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::lists::flow_sequence_entry_list::FormatYamlFlowSequenceEntryList::default(
+            ),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlBogus> for crate::yaml::bogus::bogus::FormatYamlBogus {
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_yaml_syntax::YamlBogus, f: &mut YamlFormatter) -> FormatResult<()> {
+        FormatBogusNodeRule::<biome_yaml_syntax::YamlBogus>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBogus {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBogus,
+        crate::yaml::bogus::bogus::FormatYamlBogus,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(self, crate::yaml::bogus::bogus::FormatYamlBogus::default())
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBogus {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBogus,
+        crate::yaml::bogus::bogus::FormatYamlBogus,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(self, crate::yaml::bogus::bogus::FormatYamlBogus::default())
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlBogusBlockMapEntry>
+    for crate::yaml::bogus::bogus_block_map_entry::FormatYamlBogusBlockMapEntry
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlBogusBlockMapEntry,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatBogusNodeRule::<biome_yaml_syntax::YamlBogusBlockMapEntry>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBogusBlockMapEntry {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBogusBlockMapEntry,
+        crate::yaml::bogus::bogus_block_map_entry::FormatYamlBogusBlockMapEntry,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::bogus::bogus_block_map_entry::FormatYamlBogusBlockMapEntry::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBogusBlockMapEntry {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBogusBlockMapEntry,
+        crate::yaml::bogus::bogus_block_map_entry::FormatYamlBogusBlockMapEntry,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::bogus::bogus_block_map_entry::FormatYamlBogusBlockMapEntry::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlBogusBlockNode>
+    for crate::yaml::bogus::bogus_block_node::FormatYamlBogusBlockNode
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlBogusBlockNode,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatBogusNodeRule::<biome_yaml_syntax::YamlBogusBlockNode>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBogusBlockNode {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBogusBlockNode,
+        crate::yaml::bogus::bogus_block_node::FormatYamlBogusBlockNode,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::bogus::bogus_block_node::FormatYamlBogusBlockNode::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBogusBlockNode {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBogusBlockNode,
+        crate::yaml::bogus::bogus_block_node::FormatYamlBogusBlockNode,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::bogus::bogus_block_node::FormatYamlBogusBlockNode::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlBogusFlowNode>
+    for crate::yaml::bogus::bogus_flow_node::FormatYamlBogusFlowNode
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlBogusFlowNode,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatBogusNodeRule::<biome_yaml_syntax::YamlBogusFlowNode>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBogusFlowNode {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBogusFlowNode,
+        crate::yaml::bogus::bogus_flow_node::FormatYamlBogusFlowNode,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::bogus::bogus_flow_node::FormatYamlBogusFlowNode::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBogusFlowNode {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBogusFlowNode,
+        crate::yaml::bogus::bogus_flow_node::FormatYamlBogusFlowNode,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::bogus::bogus_flow_node::FormatYamlBogusFlowNode::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlBlockCollectionContent {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::AnyYamlBlockCollectionContent,
+        crate::yaml::any::block_collection_content::FormatAnyYamlBlockCollectionContent,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule :: new (self , crate :: yaml :: any :: block_collection_content :: FormatAnyYamlBlockCollectionContent :: default ())
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlBlockCollectionContent {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::AnyYamlBlockCollectionContent,
+        crate::yaml::any::block_collection_content::FormatAnyYamlBlockCollectionContent,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule :: new (self , crate :: yaml :: any :: block_collection_content :: FormatAnyYamlBlockCollectionContent :: default ())
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlBlockInBlockNode {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::AnyYamlBlockInBlockNode,
+        crate::yaml::any::block_in_block_node::FormatAnyYamlBlockInBlockNode,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::any::block_in_block_node::FormatAnyYamlBlockInBlockNode::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlBlockInBlockNode {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::AnyYamlBlockInBlockNode,
+        crate::yaml::any::block_in_block_node::FormatAnyYamlBlockInBlockNode,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::any::block_in_block_node::FormatAnyYamlBlockInBlockNode::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlBlockIndented {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::AnyYamlBlockIndented,
+        crate::yaml::any::block_indented::FormatAnyYamlBlockIndented,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::any::block_indented::FormatAnyYamlBlockIndented::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlBlockIndented {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::AnyYamlBlockIndented,
+        crate::yaml::any::block_indented::FormatAnyYamlBlockIndented,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::any::block_indented::FormatAnyYamlBlockIndented::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlBlockMapEntry {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::AnyYamlBlockMapEntry,
+        crate::yaml::any::block_map_entry::FormatAnyYamlBlockMapEntry,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::any::block_map_entry::FormatAnyYamlBlockMapEntry::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlBlockMapEntry {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::AnyYamlBlockMapEntry,
+        crate::yaml::any::block_map_entry::FormatAnyYamlBlockMapEntry,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::any::block_map_entry::FormatAnyYamlBlockMapEntry::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlBlockMapImplicitKey {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::AnyYamlBlockMapImplicitKey,
+        crate::yaml::any::block_map_implicit_key::FormatAnyYamlBlockMapImplicitKey,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::any::block_map_implicit_key::FormatAnyYamlBlockMapImplicitKey::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlBlockMapImplicitKey {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::AnyYamlBlockMapImplicitKey,
+        crate::yaml::any::block_map_implicit_key::FormatAnyYamlBlockMapImplicitKey,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::any::block_map_implicit_key::FormatAnyYamlBlockMapImplicitKey::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlBlockNode {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::AnyYamlBlockNode,
+        crate::yaml::any::block_node::FormatAnyYamlBlockNode,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::any::block_node::FormatAnyYamlBlockNode::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlBlockNode {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::AnyYamlBlockNode,
+        crate::yaml::any::block_node::FormatAnyYamlBlockNode,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::any::block_node::FormatAnyYamlBlockNode::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlBlockScalarContent {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::AnyYamlBlockScalarContent,
+        crate::yaml::any::block_scalar_content::FormatAnyYamlBlockScalarContent,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::any::block_scalar_content::FormatAnyYamlBlockScalarContent::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlBlockScalarContent {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::AnyYamlBlockScalarContent,
+        crate::yaml::any::block_scalar_content::FormatAnyYamlBlockScalarContent,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::any::block_scalar_content::FormatAnyYamlBlockScalarContent::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlBlockSequenceEntry {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::AnyYamlBlockSequenceEntry,
+        crate::yaml::any::block_sequence_entry::FormatAnyYamlBlockSequenceEntry,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::any::block_sequence_entry::FormatAnyYamlBlockSequenceEntry::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlBlockSequenceEntry {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::AnyYamlBlockSequenceEntry,
+        crate::yaml::any::block_sequence_entry::FormatAnyYamlBlockSequenceEntry,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::any::block_sequence_entry::FormatAnyYamlBlockSequenceEntry::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlFlowMapEntry {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::AnyYamlFlowMapEntry,
+        crate::yaml::any::flow_map_entry::FormatAnyYamlFlowMapEntry,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::any::flow_map_entry::FormatAnyYamlFlowMapEntry::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlFlowMapEntry {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::AnyYamlFlowMapEntry,
+        crate::yaml::any::flow_map_entry::FormatAnyYamlFlowMapEntry,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::any::flow_map_entry::FormatAnyYamlFlowMapEntry::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlFlowMapImplicitKey {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::AnyYamlFlowMapImplicitKey,
+        crate::yaml::any::flow_map_implicit_key::FormatAnyYamlFlowMapImplicitKey,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::any::flow_map_implicit_key::FormatAnyYamlFlowMapImplicitKey::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlFlowMapImplicitKey {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::AnyYamlFlowMapImplicitKey,
+        crate::yaml::any::flow_map_implicit_key::FormatAnyYamlFlowMapImplicitKey,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::any::flow_map_implicit_key::FormatAnyYamlFlowMapImplicitKey::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlFlowNode {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::AnyYamlFlowNode,
+        crate::yaml::any::flow_node::FormatAnyYamlFlowNode,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::any::flow_node::FormatAnyYamlFlowNode::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlFlowNode {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::AnyYamlFlowNode,
+        crate::yaml::any::flow_node::FormatAnyYamlFlowNode,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::any::flow_node::FormatAnyYamlFlowNode::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlFlowSequenceEntry {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::AnyYamlFlowSequenceEntry,
+        crate::yaml::any::flow_sequence_entry::FormatAnyYamlFlowSequenceEntry,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::any::flow_sequence_entry::FormatAnyYamlFlowSequenceEntry::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlFlowSequenceEntry {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::AnyYamlFlowSequenceEntry,
+        crate::yaml::any::flow_sequence_entry::FormatAnyYamlFlowSequenceEntry,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::any::flow_sequence_entry::FormatAnyYamlFlowSequenceEntry::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlJsonContent {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::AnyYamlJsonContent,
+        crate::yaml::any::json_content::FormatAnyYamlJsonContent,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::any::json_content::FormatAnyYamlJsonContent::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlJsonContent {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::AnyYamlJsonContent,
+        crate::yaml::any::json_content::FormatAnyYamlJsonContent,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::any::json_content::FormatAnyYamlJsonContent::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlPropertiesCombination {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::AnyYamlPropertiesCombination,
+        crate::yaml::any::properties_combination::FormatAnyYamlPropertiesCombination,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::any::properties_combination::FormatAnyYamlPropertiesCombination::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlPropertiesCombination {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::AnyYamlPropertiesCombination,
+        crate::yaml::any::properties_combination::FormatAnyYamlPropertiesCombination,
+    >;
+    fn into_format(self) -> Self::Format {
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::any::properties_combination::FormatAnyYamlPropertiesCombination::default(),
+        )
+    }
+}
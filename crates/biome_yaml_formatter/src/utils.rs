@@ -0,0 +1,352 @@
+use crate::comments::has_indicator_comments;
+use crate::prelude::*;
+use biome_formatter::{QuoteStyle, format_args, write};
+use biome_rowan::TextSize;
+use biome_yaml_syntax::{
+    AnyYamlBlockInBlockNode, AnyYamlBlockIndented, AnyYamlBlockNode, YamlSyntaxKind,
+    YamlSyntaxNode, YamlSyntaxToken,
+};
+use std::borrow::Cow;
+
+/// Returns the column of the first character of `token` in the source document.
+pub(crate) fn source_column(token: &YamlSyntaxToken) -> usize {
+    let leading_trivia = token.leading_trivia();
+    let leading_text = leading_trivia.text();
+    if let Some(index) = leading_text.rfind('\n') {
+        return leading_text[index + 1..].chars().count();
+    }
+
+    let mut column = leading_text.chars().count();
+    let mut current = token.prev_token();
+    while let Some(previous) = current {
+        let text = previous.text();
+        if let Some(index) = text.rfind('\n') {
+            return column + text[index + 1..].chars().count();
+        }
+        column += text.chars().count();
+        current = previous.prev_token();
+    }
+    column
+}
+
+/// Returns `true` if `token` is a block scalar with the keep chomping indicator (`+`)
+fn is_kept_block_scalar(token: &YamlSyntaxToken) -> bool {
+    matches!(
+        token.kind(),
+        YamlSyntaxKind::LITERAL_BLOCK_LITERAL | YamlSyntaxKind::FOLDED_BLOCK_LITERAL
+    ) && block_scalar_header(token.text_trimmed()).contains('+')
+}
+
+/// Returns the header of a block scalar without the comment that may follow its indicators.
+fn block_scalar_header(text: &str) -> &str {
+    let header = text.split('\n').next().unwrap_or_default();
+    // Skip the comment of the header
+    header.split(" #").next().unwrap_or(header)
+}
+
+/// Separates two consecutive entries of a block collection, or two documents.
+///
+/// A single empty line between the entries is preserved. The trailing empty lines of a block
+/// scalar with the keep chomping indicator (`+`) are part of its value, the block scalar prints
+/// them itself.
+pub(crate) struct FormatEntrySeparator<'a> {
+    previous: &'a YamlSyntaxNode,
+    next: &'a YamlSyntaxNode,
+}
+
+impl<'a> FormatEntrySeparator<'a> {
+    pub(crate) fn new(previous: &'a YamlSyntaxNode, next: &'a YamlSyntaxNode) -> Self {
+        Self { previous, next }
+    }
+}
+
+impl Format<YamlFormatContext> for FormatEntrySeparator<'_> {
+    fn fmt(&self, f: &mut YamlFormatter) -> FormatResult<()> {
+        let previous_is_kept = self
+            .previous
+            .last_token()
+            .is_some_and(|token| is_kept_block_scalar(&token));
+
+        if !previous_is_kept && get_lines_before(self.next) > 1 {
+            write!(f, [empty_line()])
+        } else {
+            write!(f, [hard_line_break()])
+        }
+    }
+}
+
+/// Formats the value that follows the `-` of a sequence entry, or the `?` and `:` of an explicit
+/// mapping entry.
+///
+/// The value starts on the line of the indicator and its following lines are aligned with its
+/// first line, which is the form of compact collections:
+///
+/// ```yaml
+/// - - nested
+///   - sequence
+/// - key: value
+///   other: value
+/// ```
+pub(crate) struct FormatBlockIndentedValue<'a> {
+    /// The node that owns the indicator
+    node: &'a YamlSyntaxNode,
+    value: Option<AnyYamlBlockIndented>,
+}
+
+impl<'a> FormatBlockIndentedValue<'a> {
+    pub(crate) fn new(node: &'a YamlSyntaxNode, value: Option<AnyYamlBlockIndented>) -> Self {
+        Self { node, value }
+    }
+}
+
+impl Format<YamlFormatContext> for FormatBlockIndentedValue<'_> {
+    fn fmt(&self, f: &mut YamlFormatter) -> FormatResult<()> {
+        let Some(value) = &self.value else {
+            return Ok(());
+        };
+
+        // A comment after the indicator moves the value to the next line
+        if has_indicator_comments(self.node, f) {
+            return write!(
+                f,
+                [align(2, &format_args![hard_line_break(), value.format()])]
+            );
+        }
+
+        // The content of a block scalar is aligned by `FormatBlockScalarToken`
+        if matches!(
+            value,
+            AnyYamlBlockIndented::AnyYamlBlockNode(AnyYamlBlockNode::AnyYamlBlockInBlockNode(
+                AnyYamlBlockInBlockNode::YamlBlockScalar(_)
+            ))
+        ) {
+            return write!(f, [space(), value.format()]);
+        }
+
+        write!(f, [space(), align(2, &value.format())])
+    }
+}
+
+/// Formats the token of a literal (`|`) or folded (`>`) block scalar.
+///
+/// The content of a block scalar is kept byte for byte: every line keeps its indentation relative
+/// to the entry that owns the block scalar, which is the indentation that the content is parsed
+/// against. Only the lines that are empty are printed without their trailing spaces.
+pub(crate) struct FormatBlockScalarToken<'a> {
+    token: &'a YamlSyntaxToken,
+}
+
+impl<'a> FormatBlockScalarToken<'a> {
+    pub(crate) fn new(token: &'a YamlSyntaxToken) -> Self {
+        Self { token }
+    }
+}
+
+impl Format<YamlFormatContext> for FormatBlockScalarToken<'_> {
+    fn fmt(&self, f: &mut YamlFormatter) -> FormatResult<()> {
+        let source = self.token.text_trimmed();
+        let start = self.token.text_trimmed_range().start();
+
+        let mut lines = source.split('\n');
+        let header = lines.next().unwrap_or_default();
+        let content_lines: Vec<_> = lines
+            .map(|line| line.strip_suffix('\r').unwrap_or(line))
+            .collect();
+
+        let parent_column = block_scalar_parent_column(self.token);
+        let indentation_indicator = header
+            .bytes()
+            .skip(1)
+            .take_while(|byte| matches!(byte, b'1'..=b'9' | b'+' | b'-'))
+            .find(u8::is_ascii_digit)
+            .map(|digit| usize::from(digit - b'0'));
+        let content_indent = match indentation_indicator {
+            Some(indicator) => parent_column + indicator,
+            // The indentation of the content is the indentation of its first non-empty line
+            None => content_lines
+                .iter()
+                .find(|line| !line.trim().is_empty())
+                .map_or(0, |line| line.len() - line.trim_start_matches(' ').len()),
+        };
+
+        let format_content = format_with(|f| {
+            let mut offset = start + TextSize::of(header) + TextSize::from(1);
+            let mut empty_lines = 0;
+            for line in &content_lines {
+                let indent = line.len() - line.trim_start_matches(' ').len();
+                if line.len() <= content_indent && indent == line.len() {
+                    empty_lines += 1;
+                } else {
+                    write!(f, [FormatEmptyLines(empty_lines), hard_line_break()])?;
+                    empty_lines = 0;
+
+                    let content = &line[indent.min(content_indent)..];
+                    let content_offset = offset + TextSize::from(indent.min(content_indent) as u32);
+                    write!(f, [dynamic_text(content, content_offset)])?;
+                }
+                offset += TextSize::of(*line) + TextSize::from(1);
+            }
+
+            if is_kept_block_scalar(self.token) {
+                // The trailing empty lines are part of the value of the scalar, and they're in the
+                // leading trivia of the next token
+                let line_breaks = self
+                    .token
+                    .next_token()
+                    .map_or(0, |next| get_lines_before_token(&next));
+                write!(
+                    f,
+                    [FormatEmptyLines(
+                        empty_lines + line_breaks.saturating_sub(1)
+                    )]
+                )?;
+            }
+
+            Ok(())
+        });
+
+        write!(f, [dynamic_text(header.trim_end(), start)])?;
+
+        // The content is indented by the same number of spaces relative to its parent entry as in
+        // the source document
+        match u8::try_from(content_indent.saturating_sub(parent_column))
+            .ok()
+            .filter(|offset| *offset > 0)
+        {
+            Some(offset) => write!(f, [align(offset, &format_content)]),
+            None => write!(f, [format_content]),
+        }
+    }
+}
+
+/// Returns the column of the entry that owns the block scalar `token` in the source document,
+/// which is the indentation that the content of the block scalar is relative to.
+fn block_scalar_parent_column(token: &YamlSyntaxToken) -> usize {
+    token
+        .parent()
+        .into_iter()
+        .flat_map(|parent| parent.ancestors())
+        .find(|ancestor| {
+            matches!(
+                ancestor.kind(),
+                YamlSyntaxKind::YAML_BLOCK_SEQUENCE_ENTRY
+                    | YamlSyntaxKind::YAML_BLOCK_MAP_IMPLICIT_ENTRY
+                    | YamlSyntaxKind::YAML_BLOCK_MAP_EXPLICIT_ENTRY
+            )
+        })
+        .and_then(|entry| entry.first_token())
+        .map_or(0, |token| source_column(&token))
+}
+
+/// Formats a flow scalar (plain, single-quoted or double-quoted) that spans multiple lines.
+///
+/// The line breaks of a flow scalar are folded and the indentation of its continuation lines
+/// isn't part of its value, so the continuation lines are re-indented at the current
+/// indentation level.
+pub(crate) struct FormatMultilineFlowScalar<'a> {
+    text: &'a str,
+    start: TextSize,
+    is_plain: bool,
+}
+
+impl<'a> FormatMultilineFlowScalar<'a> {
+    pub(crate) fn new(token: &'a YamlSyntaxToken) -> Self {
+        Self {
+            text: token.text_trimmed(),
+            start: token.text_trimmed_range().start(),
+            is_plain: token.kind() == YamlSyntaxKind::PLAIN_LITERAL,
+        }
+    }
+}
+
+impl Format<YamlFormatContext> for FormatMultilineFlowScalar<'_> {
+    fn fmt(&self, f: &mut YamlFormatter) -> FormatResult<()> {
+        let mut offset = self.start;
+        let mut empty_lines = 0;
+        for (index, line) in self.text.split('\n').enumerate() {
+            let line_offset = offset;
+            offset += TextSize::of(line) + TextSize::from(1);
+
+            let mut content = line.strip_suffix('\r').unwrap_or(line);
+            // The trailing white space of the lines of a quoted scalar can be escaped
+            if self.is_plain {
+                content = content.trim_end();
+            }
+            if index == 0 {
+                write!(f, [dynamic_text(content, line_offset)])?;
+                continue;
+            }
+
+            let trimmed = content.trim_start();
+            if trimmed.is_empty() {
+                empty_lines += 1;
+            } else {
+                let trimmed_offset = line_offset + TextSize::of(content) - TextSize::of(trimmed);
+                write!(
+                    f,
+                    [
+                        FormatEmptyLines(empty_lines),
+                        hard_line_break(),
+                        dynamic_text(trimmed, trimmed_offset)
+                    ]
+                )?;
+                empty_lines = 0;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Prints the given number of empty lines at the end of the current line.
+///
+/// The empty lines of a scalar are part of its value: unlike [empty_line], they aren't collapsed
+/// and they aren't indented. The line break that follows them is printed by the next
+/// [hard_line_break], which only sets the indentation of the next line because the current line
+/// is empty.
+struct FormatEmptyLines(usize);
+
+impl Format<YamlFormatContext> for FormatEmptyLines {
+    fn fmt(&self, f: &mut YamlFormatter) -> FormatResult<()> {
+        if self.0 > 0 {
+            // Terminates the current line
+            write!(f, [text("\n")])?;
+        }
+        for _ in 0..self.0 {
+            write!(f, [text("\n")])?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the text of a single-line quoted scalar written with the preferred quotes, or the
+/// text as it is if changing the quotes would require escaping some characters.
+///
+/// Double-quoted scalars support escape sequences and single-quoted scalars escape `'` as `''`.
+/// The quotes are changed only when the value stays the same without any new escape.
+pub(crate) fn normalize_quotes(text: &str, preferred: QuoteStyle) -> Cow<str> {
+    let Some(quote) = text.bytes().next().and_then(QuoteStyle::from_byte) else {
+        return Cow::Borrowed(text);
+    };
+    if quote == preferred || text.len() < 2 || text.contains('\n') {
+        return Cow::Borrowed(text);
+    }
+
+    let content = &text[1..text.len() - 1];
+    match preferred {
+        QuoteStyle::Double => {
+            let unescaped = content.replace("''", "'");
+            if unescaped.contains(['"', '\\']) {
+                Cow::Borrowed(text)
+            } else {
+                Cow::Owned(std::format!("\"{unescaped}\""))
+            }
+        }
+        QuoteStyle::Single => {
+            if content.contains(['\'', '\\']) {
+                Cow::Borrowed(text)
+            } else {
+                Cow::Owned(std::format!("'{content}'"))
+            }
+        }
+    }
+}
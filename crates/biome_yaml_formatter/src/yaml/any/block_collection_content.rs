@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_yaml_syntax::AnyYamlBlockCollectionContent;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyYamlBlockCollectionContent;
+impl FormatRule<AnyYamlBlockCollectionContent> for FormatAnyYamlBlockCollectionContent {
+    type Context = YamlFormatContext;
+    fn fmt(&self, node: &AnyYamlBlockCollectionContent, f: &mut YamlFormatter) -> FormatResult<()> {
+        match node {
+            AnyYamlBlockCollectionContent::YamlBlockMapping(node) => node.format().fmt(f),
+            AnyYamlBlockCollectionContent::YamlBlockSequence(node) => node.format().fmt(f),
+        }
+    }
+}
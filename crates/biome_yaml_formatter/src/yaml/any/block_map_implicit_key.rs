@@ -0,0 +1,16 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_yaml_syntax::AnyYamlBlockMapImplicitKey;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyYamlBlockMapImplicitKey;
+impl FormatRule<AnyYamlBlockMapImplicitKey> for FormatAnyYamlBlockMapImplicitKey {
+    type Context = YamlFormatContext;
+    fn fmt(&self, node: &AnyYamlBlockMapImplicitKey, f: &mut YamlFormatter) -> FormatResult<()> {
+        match node {
+            AnyYamlBlockMapImplicitKey::YamlAliasNode(node) => node.format().fmt(f),
+            AnyYamlBlockMapImplicitKey::YamlFlowJsonNode(node) => node.format().fmt(f),
+            AnyYamlBlockMapImplicitKey::YamlFlowYamlNode(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_yaml_syntax::AnyYamlBlockScalarContent;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyYamlBlockScalarContent;
+impl FormatRule<AnyYamlBlockScalarContent> for FormatAnyYamlBlockScalarContent {
+    type Context = YamlFormatContext;
+    fn fmt(&self, node: &AnyYamlBlockScalarContent, f: &mut YamlFormatter) -> FormatResult<()> {
+        match node {
+            AnyYamlBlockScalarContent::YamlFoldedScalar(node) => node.format().fmt(f),
+            AnyYamlBlockScalarContent::YamlLiteralScalar(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlBlockCollection, YamlBlockCollectionFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlBlockCollection;
+impl FormatNodeRule<YamlBlockCollection> for FormatYamlBlockCollection {
+    fn fmt_fields(&self, node: &YamlBlockCollection, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlBlockCollectionFields {
+            properties,
+            content,
+        } = node.as_fields();
+
+        // The properties are on the line of the parent, and the content starts on the next line:
+        //
+        // ```yaml
+        // key: &anchor
+        //   nested: value
+        // ```
+        if let Some(properties) = properties {
+            write!(f, [properties.format(), hard_line_break()])?;
+        }
+        write!(f, [content.format()])
+    }
+}
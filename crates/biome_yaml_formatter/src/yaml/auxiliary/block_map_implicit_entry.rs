@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{
+    AnyYamlBlockMapImplicitKey, YamlBlockMapImplicitEntry, YamlBlockMapImplicitEntryFields,
+};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlBlockMapImplicitEntry;
+impl FormatNodeRule<YamlBlockMapImplicitEntry> for FormatYamlBlockMapImplicitEntry {
+    fn fmt_fields(
+        &self,
+        node: &YamlBlockMapImplicitEntry,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        let YamlBlockMapImplicitEntryFields { key, value } = node.as_fields();
+
+        write!(f, [key.format()])?;
+        // `:` is a valid character of an alias name, `*alias:` is the alias of the anchor `alias:`
+        if matches!(key, Some(AnyYamlBlockMapImplicitKey::YamlAliasNode(_))) {
+            write!(f, [space()])?;
+        }
+        write!(f, [value.format()])
+    }
+}
@@ -0,0 +1,102 @@
+use crate::comments::{FormatYamlIndicatorComments, has_indicator_comments};
+use crate::prelude::*;
+use biome_formatter::{format_args, write};
+use biome_rowan::{AstNode, SyntaxResult};
+use biome_yaml_syntax::{
+    AnyYamlBlockInBlockNode, AnyYamlBlockNode, AnyYamlFlowNode, AnyYamlJsonContent,
+    YamlBlockMapImplicitValue, YamlBlockMapImplicitValueFields,
+};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlBlockMapImplicitValue;
+impl FormatNodeRule<YamlBlockMapImplicitValue> for FormatYamlBlockMapImplicitValue {
+    fn fmt_fields(
+        &self,
+        node: &YamlBlockMapImplicitValue,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        let YamlBlockMapImplicitValueFields { colon_token, value } = node.as_fields();
+
+        write!(
+            f,
+            [
+                colon_token.format(),
+                FormatYamlIndicatorComments::new(node.syntax())
+            ]
+        )?;
+
+        let Some(value) = value else {
+            return Ok(());
+        };
+
+        // A comment after the `:` moves the value to the next line
+        if has_indicator_comments(node.syntax(), f) {
+            return write!(
+                f,
+                [indent(&format_args![hard_line_break(), value.format()])]
+            );
+        }
+
+        match &value {
+            AnyYamlBlockNode::AnyYamlBlockInBlockNode(
+                AnyYamlBlockInBlockNode::YamlBlockCollection(collection),
+            ) => {
+                if collection.properties().is_some() {
+                    // ```yaml
+                    // key: &anchor
+                    //   nested: value
+                    // ```
+                    write!(f, [space(), indent(&value.format())])
+                } else {
+                    write!(
+                        f,
+                        [indent(&format_args![hard_line_break(), value.format()])]
+                    )
+                }
+            }
+            // The content of a block scalar is aligned by `FormatBlockScalarToken`
+            AnyYamlBlockNode::AnyYamlBlockInBlockNode(
+                AnyYamlBlockInBlockNode::YamlBlockScalar(_),
+            ) => write!(f, [space(), value.format()]),
+            AnyYamlBlockNode::YamlFlowInBlockNode(node) if is_flow_collection(node.flow()) => {
+                // A flow collection that doesn't fit on the line of its key moves to the next
+                // line, before breaking its entries:
+                //
+                // ```yaml
+                // key:
+                //   [first, second, third]
+                // ```
+                write!(
+                    f,
+                    [group(&indent(&format_args![
+                        soft_line_break_or_space(),
+                        value.format()
+                    ]))]
+                )
+            }
+            _ => write!(f, [space(), indent(&value.format())]),
+        }
+    }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &YamlBlockMapImplicitValue,
+        _: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
+    }
+}
+
+/// Returns `true` if `node` is a flow sequence or a flow mapping without properties.
+fn is_flow_collection(node: SyntaxResult<AnyYamlFlowNode>) -> bool {
+    matches!(
+        node,
+        Ok(AnyYamlFlowNode::YamlFlowJsonNode(node))
+            if node.properties().is_none()
+                && matches!(
+                    node.content(),
+                    Some(AnyYamlJsonContent::YamlFlowSequence(_) | AnyYamlJsonContent::YamlFlowMapping(_))
+                )
+    )
+}
@@ -0,0 +1,12 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlCompactSequence, YamlCompactSequenceFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlCompactSequence;
+impl FormatNodeRule<YamlCompactSequence> for FormatYamlCompactSequence {
+    fn fmt_fields(&self, node: &YamlCompactSequence, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlCompactSequenceFields { entries } = node.as_fields();
+        write!(f, [entries.format()])
+    }
+}
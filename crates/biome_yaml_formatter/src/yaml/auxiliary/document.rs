@@ -0,0 +1,82 @@
+use crate::comments::FormatYamlIndicatorComments;
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{
+    AnyYamlBlockInBlockNode, AnyYamlBlockNode, YamlDocument, YamlDocumentFields, YamlSyntaxKind,
+};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlDocument;
+impl FormatNodeRule<YamlDocument> for FormatYamlDocument {
+    fn fmt_fields(&self, node: &YamlDocument, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlDocumentFields {
+            bom_token,
+            directives,
+            dashdashdash_token,
+            node: content,
+            dotdotdot_token,
+        } = node.as_fields();
+
+        write!(f, [bom_token.format(), directives.format()])?;
+
+        if let Some(dashdashdash_token) = &dashdashdash_token {
+            write!(
+                f,
+                [
+                    dashdashdash_token.format(),
+                    FormatYamlIndicatorComments::new(node.syntax())
+                        .after(YamlSyntaxKind::DIRECTIVE_END)
+                ]
+            )?;
+
+            if let Some(content) = &content {
+                let comments = f.comments();
+                let has_marker_comment =
+                    comments
+                        .dangling_comments(node.syntax())
+                        .iter()
+                        .any(|comment| {
+                            comment.piece().as_piece().token().kind()
+                                == YamlSyntaxKind::DIRECTIVE_END
+                        });
+                // A block collection must start on its own line, unless it starts with properties
+                let starts_on_next_line = has_marker_comment
+                    || comments.has_leading_comments(content.syntax())
+                    || matches!(
+                        content,
+                        AnyYamlBlockNode::AnyYamlBlockInBlockNode(
+                            AnyYamlBlockInBlockNode::YamlBlockCollection(collection)
+                        ) if collection.properties().is_none()
+                    );
+
+                if starts_on_next_line {
+                    write!(f, [hard_line_break()])?;
+                } else {
+                    write!(f, [space()])?;
+                }
+            }
+        }
+
+        write!(f, [content.format()])?;
+
+        if let Some(dotdotdot_token) = &dotdotdot_token {
+            if dashdashdash_token.is_some() || content.is_some() || !directives.is_empty() {
+                write!(f, [hard_line_break()])?;
+            }
+            write!(
+                f,
+                [
+                    dotdotdot_token.format(),
+                    FormatYamlIndicatorComments::new(node.syntax()).after(YamlSyntaxKind::DOC_END)
+                ]
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn fmt_dangling_comments(&self, _: &YamlDocument, _: &mut YamlFormatter) -> FormatResult<()> {
+        // Formatted inside of `fmt_fields`
+        Ok(())
+    }
+}
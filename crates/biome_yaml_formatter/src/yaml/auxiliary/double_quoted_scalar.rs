@@ -0,0 +1,36 @@
+use crate::prelude::*;
+use crate::utils::{FormatMultilineFlowScalar, normalize_quotes};
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlDoubleQuotedScalar, YamlDoubleQuotedScalarFields};
+use std::borrow::Cow;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlDoubleQuotedScalar;
+impl FormatNodeRule<YamlDoubleQuotedScalar> for FormatYamlDoubleQuotedScalar {
+    fn fmt_fields(&self, node: &YamlDoubleQuotedScalar, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlDoubleQuotedScalarFields { value_token } = node.as_fields();
+        let value_token = value_token?;
+        let text = value_token.text_trimmed();
+
+        if text.contains('\n') {
+            return write!(
+                f,
+                [format_replaced(
+                    &value_token,
+                    &FormatMultilineFlowScalar::new(&value_token)
+                )]
+            );
+        }
+
+        match normalize_quotes(text, f.options().quote_style()) {
+            Cow::Borrowed(_) => write!(f, [value_token.format()]),
+            Cow::Owned(normalized) => write!(
+                f,
+                [format_replaced(
+                    &value_token,
+                    &dynamic_text(&normalized, value_token.text_trimmed_range().start())
+                )]
+            ),
+        }
+    }
+}
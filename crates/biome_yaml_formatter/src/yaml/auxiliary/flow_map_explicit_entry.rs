@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlFlowMapExplicitEntry, YamlFlowMapExplicitEntryFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlFlowMapExplicitEntry;
+impl FormatNodeRule<YamlFlowMapExplicitEntry> for FormatYamlFlowMapExplicitEntry {
+    fn fmt_fields(
+        &self,
+        node: &YamlFlowMapExplicitEntry,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        let YamlFlowMapExplicitEntryFields {
+            question_mark_token,
+            entry,
+        } = node.as_fields();
+
+        write!(f, [question_mark_token.format()])?;
+        if let Some(entry) = entry {
+            write!(f, [space(), entry.format()])?;
+        }
+        Ok(())
+    }
+}
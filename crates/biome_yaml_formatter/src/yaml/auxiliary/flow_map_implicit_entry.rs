@@ -0,0 +1,36 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{
+    AnyYamlFlowMapImplicitKey, YamlFlowMapImplicitEntry, YamlFlowMapImplicitEntryFields,
+};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlFlowMapImplicitEntry;
+impl FormatNodeRule<YamlFlowMapImplicitEntry> for FormatYamlFlowMapImplicitEntry {
+    fn fmt_fields(
+        &self,
+        node: &YamlFlowMapImplicitEntry,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        let YamlFlowMapImplicitEntryFields {
+            key,
+            colon_token,
+            value,
+        } = node.as_fields();
+
+        write!(f, [key.format()])?;
+        // `:` is a valid character of an alias name, `*alias:` is the alias of the anchor `alias:`
+        if colon_token.is_some() && matches!(key, Some(AnyYamlFlowMapImplicitKey::YamlAliasNode(_)))
+        {
+            write!(f, [space()])?;
+        }
+        write!(f, [colon_token.format()])?;
+        if let Some(value) = value {
+            if colon_token.is_some() {
+                write!(f, [space()])?;
+            }
+            write!(f, [value.format()])?;
+        }
+        Ok(())
+    }
+}
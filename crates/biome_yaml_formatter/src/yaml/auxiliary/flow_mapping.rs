@@ -0,0 +1,43 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_rowan::{AstNode, AstSeparatedList};
+use biome_yaml_syntax::{YamlFlowMapping, YamlFlowMappingFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlFlowMapping;
+impl FormatNodeRule<YamlFlowMapping> for FormatYamlFlowMapping {
+    fn fmt_fields(&self, node: &YamlFlowMapping, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlFlowMappingFields {
+            l_curly_token,
+            entries,
+            r_curly_token,
+        } = node.as_fields();
+
+        write!(f, [l_curly_token.format()])?;
+        if entries.is_empty() {
+            write!(
+                f,
+                [format_dangling_comments(node.syntax()).with_block_indent()]
+            )?;
+        } else {
+            let bracket_spacing = f.options().bracket_spacing().value();
+            write!(
+                f,
+                [group(&soft_block_indent_with_maybe_space(
+                    &entries.format(),
+                    bracket_spacing
+                ))]
+            )?;
+        }
+        write!(f, [r_curly_token.format()])
+    }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &YamlFlowMapping,
+        _: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
+    }
+}
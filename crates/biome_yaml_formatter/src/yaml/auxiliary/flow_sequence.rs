@@ -0,0 +1,36 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_rowan::{AstNode, AstSeparatedList};
+use biome_yaml_syntax::{YamlFlowSequence, YamlFlowSequenceFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlFlowSequence;
+impl FormatNodeRule<YamlFlowSequence> for FormatYamlFlowSequence {
+    fn fmt_fields(&self, node: &YamlFlowSequence, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlFlowSequenceFields {
+            l_brack_token,
+            entries,
+            r_brack_token,
+        } = node.as_fields();
+
+        write!(f, [l_brack_token.format()])?;
+        if entries.is_empty() {
+            write!(
+                f,
+                [format_dangling_comments(node.syntax()).with_block_indent()]
+            )?;
+        } else {
+            write!(f, [group(&soft_block_indent(&entries.format()))])?;
+        }
+        write!(f, [r_brack_token.format()])
+    }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &YamlFlowSequence,
+        _: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+use crate::prelude::*;
+use crate::utils::FormatBlockScalarToken;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlLiteralScalar, YamlLiteralScalarFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlLiteralScalar;
+impl FormatNodeRule<YamlLiteralScalar> for FormatYamlLiteralScalar {
+    fn fmt_fields(&self, node: &YamlLiteralScalar, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlLiteralScalarFields { value_token } = node.as_fields();
+        let value_token = value_token?;
+
+        write!(
+            f,
+            [format_replaced(
+                &value_token,
+                &FormatBlockScalarToken::new(&value_token)
+            )]
+        )
+    }
+}
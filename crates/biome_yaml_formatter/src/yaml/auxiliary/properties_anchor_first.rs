@@ -0,0 +1,21 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlPropertiesAnchorFirst, YamlPropertiesAnchorFirstFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlPropertiesAnchorFirst;
+impl FormatNodeRule<YamlPropertiesAnchorFirst> for FormatYamlPropertiesAnchorFirst {
+    fn fmt_fields(
+        &self,
+        node: &YamlPropertiesAnchorFirst,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        let YamlPropertiesAnchorFirstFields { anchor, tag } = node.as_fields();
+
+        write!(f, [anchor.format()])?;
+        if let Some(tag) = tag {
+            write!(f, [space(), tag.format()])?;
+        }
+        Ok(())
+    }
+}
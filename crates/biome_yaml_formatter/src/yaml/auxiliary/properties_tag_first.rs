@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlPropertiesTagFirst, YamlPropertiesTagFirstFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlPropertiesTagFirst;
+impl FormatNodeRule<YamlPropertiesTagFirst> for FormatYamlPropertiesTagFirst {
+    fn fmt_fields(&self, node: &YamlPropertiesTagFirst, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlPropertiesTagFirstFields { tag, anchor } = node.as_fields();
+
+        write!(f, [tag.format()])?;
+        if let Some(anchor) = anchor {
+            write!(f, [space(), anchor.format()])?;
+        }
+        Ok(())
+    }
+}
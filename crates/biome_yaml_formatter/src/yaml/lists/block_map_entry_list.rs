@@ -0,0 +1,21 @@
+use crate::prelude::*;
+use crate::utils::FormatEntrySeparator;
+use biome_formatter::write;
+use biome_yaml_syntax::YamlBlockMapEntryList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlBlockMapEntryList;
+impl FormatRule<YamlBlockMapEntryList> for FormatYamlBlockMapEntryList {
+    type Context = YamlFormatContext;
+    fn fmt(&self, node: &YamlBlockMapEntryList, f: &mut YamlFormatter) -> FormatResult<()> {
+        let mut previous = None;
+        for entry in node {
+            if let Some(previous) = &previous {
+                write!(f, [FormatEntrySeparator::new(previous, entry.syntax())])?;
+            }
+            write!(f, [entry.format()])?;
+            previous = Some(entry.into_syntax());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,16 @@
+use crate::prelude::*;
+use biome_yaml_syntax::YamlDirectiveList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlDirectiveList;
+impl FormatRule<YamlDirectiveList> for FormatYamlDirectiveList {
+    type Context = YamlFormatContext;
+    fn fmt(&self, node: &YamlDirectiveList, f: &mut YamlFormatter) -> FormatResult<()> {
+        // Every directive is on its own line, followed by the `---` marker
+        for directive in node.iter().formatted() {
+            directive.fmt(f)?;
+            hard_line_break().fmt(f)?;
+        }
+        Ok(())
+    }
+}
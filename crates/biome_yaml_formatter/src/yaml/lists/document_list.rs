@@ -0,0 +1,21 @@
+use crate::prelude::*;
+use crate::utils::FormatEntrySeparator;
+use biome_formatter::write;
+use biome_yaml_syntax::YamlDocumentList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlDocumentList;
+impl FormatRule<YamlDocumentList> for FormatYamlDocumentList {
+    type Context = YamlFormatContext;
+    fn fmt(&self, node: &YamlDocumentList, f: &mut YamlFormatter) -> FormatResult<()> {
+        let mut previous = None;
+        for document in node {
+            if let Some(previous) = &previous {
+                write!(f, [FormatEntrySeparator::new(previous, document.syntax())])?;
+            }
+            write!(f, [document.format()])?;
+            previous = Some(document.into_syntax());
+        }
+        Ok(())
+    }
+}
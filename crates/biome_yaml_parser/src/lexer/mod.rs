@@ -32,6 +32,10 @@ pub(crate) struct YamlLexer<'src> {
 
     /// diagnostics emitted during the parsing phase
     diagnostics: Vec<ParseDiagnostic>,
+
+    /// The number of diagnostics before lexing the current token. The diagnostics of the current
+    /// token are dropped when it's re-lexed.
+    current_diagnostics_start: usize,
 }
 
 impl<'src> YamlLexer<'src> {
@@ -46,24 +50,52 @@ impl<'src> YamlLexer<'src> {
             current_start: TextSize::from(0),
             current_flags: TokenFlags::empty(),
             diagnostics: vec![],
+            current_diagnostics_start: 0,
         }
     }
 
+    /// Lexes the next token, or the Unicode BOM if the source starts with one
+    fn consume_token_or_bom(&mut self, current: u8, context: YamlLexContext) -> YamlSyntaxKind {
+        if self.position == 0 {
+            if let Some((kind, length)) = self.consume_potential_bom(UNICODE_BOM) {
+                self.unicode_bom_length = length;
+                return kind;
+            }
+        }
+
+        self.consume_token(current, context)
+    }
+
     /// Consume a token in the given context
     fn consume_token(&mut self, current: u8, context: YamlLexContext) -> YamlSyntaxKind {
         let start = self.text_position();
 
         let kind = match current {
+            b'-' | b'.' if self.is_at_document_marker(self.position) => {
+                self.advance(3);
+                if current == b'-' { T![---] } else { T![...] }
+            }
+            b'%' if self.is_at_line_start(self.position) => self.consume_directive(),
+            b':' if context.is_flow() && self.follows_json_node() => self.consume_byte(T![:]),
+            b'-' | b'?' | b':' if self.is_first_plain_char(current, context) => {
+                self.consume_plain_literal(context)
+            }
             b':' => self.consume_byte(T![:]),
             b',' => self.consume_byte(T![,]),
             b'[' => self.consume_byte(T!['[']),
             b']' => self.consume_byte(T![']']),
+            b'{' => self.consume_byte(T!['{']),
+            b'}' => self.consume_byte(T!['}']),
             b'?' => self.consume_byte(T![?]),
             b'-' => self.consume_byte(T![-]),
+            b'&' => self.consume_node_name(ANCHOR_PROPERTY_LITERAL),
+            b'*' => self.consume_node_name(ALIAS_LITERAL),
+            b'!' => self.consume_tag(),
+            b'|' | b'>' if !context.is_flow() => self.consume_block_scalar(current, context),
             b'#' => self.consume_comment(),
             b'\'' => self.consume_single_quoted_literal(),
             b'"' => self.consume_double_quoted_literal(),
-            b' ' | b'\n' => self.consume_newline_or_whitespaces(),
+            b' ' | b'\t' | b'\n' | b'\r' => self.consume_newline_or_whitespaces(),
             _ if self.is_first_plain_char(current, context) => self.consume_plain_literal(context),
             _ => self.consume_unexpected_token(),
         };
@@ -82,7 +114,7 @@ impl<'src> YamlLexer<'src> {
     fn consume_comment(&mut self) -> YamlSyntaxKind {
         self.assert_byte(b'#');
         while let Some(c) = self.current_byte() {
-            if c == b'\n' {
+            if is_break(c) {
                 break;
             }
             self.advance(1);
@@ -90,8 +122,91 @@ impl<'src> YamlLexer<'src> {
         COMMENT
     }
 
+    /// Consumes a directive, without its trailing comment. For example:
+    /// %YAML 1.2
+    /// %TAG ! tag:example.com,2000:
+    /// https://yaml.org/spec/1.2.2/#68-directives
+    fn consume_directive(&mut self) -> YamlSyntaxKind {
+        self.assert_byte(b'%');
+        self.advance(1);
+        let mut end = self.position;
+        while let Some(c) = self.current_byte() {
+            if is_break(c) || (c == b'#' && self.is_after_whitespace()) {
+                break;
+            }
+            self.advance(1);
+            if !is_space(c) {
+                end = self.position;
+            }
+        }
+        self.position = end;
+        DIRECTIVE_LITERAL
+    }
+
+    /// Consumes an anchor (`&anchor`) or an alias (`*anchor`)
+    /// https://yaml.org/spec/1.2.2/#rule-ns-anchor-name
+    fn consume_node_name(&mut self, kind: YamlSyntaxKind) -> YamlSyntaxKind {
+        let start = self.text_position();
+        self.advance(1);
+        while let Some(c) = self.current_byte() {
+            if !is_non_space_char(c) || is_flow_indicator(c) {
+                break;
+            }
+            self.advance_byte_or_char(c);
+        }
+
+        if self.text_position() - start == TextSize::from(1) {
+            let name = if kind == ALIAS_LITERAL {
+                "alias"
+            } else {
+                "anchor"
+            };
+            self.diagnostics.push(ParseDiagnostic::new(
+                format!("Expected the name of the {name}."),
+                start..self.text_position(),
+            ));
+            return ERROR_TOKEN;
+        }
+
+        kind
+    }
+
+    /// Consumes a tag, for example `!local`, `!!str` or `!<tag:yaml.org,2002:str>`
+    /// https://yaml.org/spec/1.2.2/#rule-c-ns-tag-property
+    fn consume_tag(&mut self) -> YamlSyntaxKind {
+        self.assert_byte(b'!');
+        let start = self.text_position();
+        self.advance(1);
+
+        // Verbatim tag
+        if self.current_byte() == Some(b'<') {
+            while let Some(c) = self.current_byte() {
+                if !is_non_space_char(c) {
+                    break;
+                }
+                self.advance_byte_or_char(c);
+                if c == b'>' {
+                    return TAG_PROPERTY_LITERAL;
+                }
+            }
+
+            self.diagnostics.push(ParseDiagnostic::new(
+                "Missing closing `>` of the verbatim tag.",
+                start..self.text_position(),
+            ));
+            return ERROR_TOKEN;
+        }
+
+        while let Some(c) = self.current_byte() {
+            if !is_non_space_char(c) || is_flow_indicator(c) {
+                break;
+            }
+            self.advance_byte_or_char(c);
+        }
+        TAG_PROPERTY_LITERAL
+    }
+
     // https://yaml.org/spec/1.2.2/#rule-ns-plain
-    // TODO: parse multiline plain scalar at current indentation level
     fn consume_plain_literal(&mut self, context: YamlLexContext) -> YamlSyntaxKind {
         self.assert_current_char_boundary();
         debug_assert!(
@@ -99,30 +214,86 @@ impl<'src> YamlLexer<'src> {
                 .is_some_and(|c| self.is_first_plain_char(c, context))
         );
         self.advance_char_unchecked();
+
+        // Trailing whitespace isn't part of the scalar
+        let mut end = self.position;
         while let Some(c) = self.current_byte() {
-            // https://yaml.org/spec/1.2.2/#rule-ns-plain-char
-            if is_plain_safe(c, context) && c != b':' && c != b'#' {
-                self.advance_char_unchecked();
-            } else if is_non_space_char(c) && self.peek_byte().is_some_and(|c| c == b'#') {
-                self.advance_char_unchecked();
-                self.advance(1); // '#'
-            } else if c == b':' && self.peek_byte().is_some_and(|c| is_plain_safe(c, context)) {
-                self.advance(1); // ':'
-                self.advance_char_unchecked();
-            }
-            // Yes plain token can contain spaces
-            // For example:
-            // a bc: x yz
-            // Is a valid mapping
-            else if is_space(c) {
+            if is_space(c) {
                 self.advance(1);
-            } else {
+            } else if is_break(c) {
+                match self.plain_continuation_line(context) {
+                    Some(position) => self.position = position,
+                    None => break,
+                }
+            } else if
+            // https://yaml.org/spec/1.2.2/#rule-ns-plain-char
+            (c == b'#' && self.is_after_whitespace())
+                || (c == b':' && !self.peek_byte().is_some_and(|c| is_plain_safe(c, context)))
+                || !is_plain_safe(c, context)
+            {
                 break;
+            } else {
+                self.advance_byte_or_char(c);
+                end = self.position;
             }
         }
+
+        self.position = end;
         PLAIN_LITERAL
     }
 
+    /// Returns the position of the first character of the next line if a multiline plain scalar
+    /// continues on it. Must be called at a line break.
+    ///
+    /// Empty lines are folded into the scalar, the continuation line must be indented at least as
+    /// much as the node. Implicit keys can't span multiple lines.
+    /// https://yaml.org/spec/1.2.2/#rule-s-ns-plain-next-line
+    fn plain_continuation_line(&self, context: YamlLexContext) -> Option<usize> {
+        let min_indent = match context {
+            YamlLexContext::FlowOut { indent } => indent,
+            YamlLexContext::FlowIn => 0,
+            _ => return None,
+        };
+
+        let bytes = self.source.as_bytes();
+        let mut position = self.position;
+        loop {
+            // Skip the line break
+            if bytes.get(position) == Some(&b'\r') {
+                position += 1;
+            }
+            if bytes.get(position) == Some(&b'\n') {
+                position += 1;
+            }
+
+            let line_start = position;
+            while bytes.get(position) == Some(&b' ') {
+                position += 1;
+            }
+            let indent = position - line_start;
+            while bytes.get(position).is_some_and(|c| is_space(*c)) {
+                position += 1;
+            }
+
+            match bytes.get(position).copied() {
+                None => return None,
+                // Empty lines are part of the scalar
+                Some(c) if is_break(c) => {}
+                Some(c) => {
+                    let is_continuation = indent >= min_indent
+                        && !(indent == 0 && self.is_at_document_marker(line_start))
+                        && c != b'#'
+                        && is_plain_safe(c, context)
+                        && (c != b':'
+                            || bytes
+                                .get(position + 1)
+                                .is_some_and(|c| is_plain_safe(*c, context)));
+                    return is_continuation.then_some(position);
+                }
+            }
+        }
+    }
+
     // https://yaml.org/spec/1.2.2/#rule-ns-plain-first
     fn is_first_plain_char(&self, c: u8, context: YamlLexContext) -> bool {
         (is_non_space_char(c) && !is_indicator(c))
@@ -130,18 +301,126 @@ impl<'src> YamlLexer<'src> {
                 && self.peek_byte().is_some_and(|c| is_plain_safe(c, context)))
     }
 
+    /// Consumes a literal (`|`) or a folded (`>`) block scalar: its header, the comment of the
+    /// header and all its content lines. The trailing empty lines aren't part of the token.
+    ///
+    /// The content lines must be more indented than the parent node, which is why the lexer
+    /// must be in the [YamlLexContext::FlowOut] context of the node to lex block scalars correctly.
+    /// https://yaml.org/spec/1.2.2/#81-block-scalar-styles
+    fn consume_block_scalar(&mut self, current: u8, context: YamlLexContext) -> YamlSyntaxKind {
+        let start = self.text_position();
+        let kind = if current == b'|' {
+            LITERAL_BLOCK_LITERAL
+        } else {
+            FOLDED_BLOCK_LITERAL
+        };
+        self.advance(1);
+
+        // https://yaml.org/spec/1.2.2/#rule-c-b-block-header
+        let mut indentation_indicator = None;
+        let mut chomping_indicator = false;
+        while let Some(c) = self.current_byte() {
+            match c {
+                b'1'..=b'9' if indentation_indicator.is_none() => {
+                    indentation_indicator = Some(usize::from(c - b'0'));
+                }
+                b'+' | b'-' if !chomping_indicator => chomping_indicator = true,
+                _ => break,
+            }
+            self.advance(1);
+        }
+
+        while self.current_byte().is_some_and(is_space) {
+            self.advance(1);
+        }
+        match self.current_byte() {
+            None => {}
+            Some(c) if is_break(c) => {}
+            Some(b'#') if self.is_after_whitespace() => {
+                self.consume_comment();
+            }
+            Some(_) => {
+                let invalid_start = self.text_position();
+                while self.current_byte().is_some_and(|c| !is_break(c)) {
+                    self.advance(1);
+                }
+                self.diagnostics.push(
+                    ParseDiagnostic::new(
+                        "Invalid block scalar header.",
+                        invalid_start..self.text_position(),
+                    )
+                    .with_detail(
+                        start..invalid_start,
+                        "The header of a block scalar can only contain an indentation indicator, a chomping indicator and a comment.",
+                    ),
+                );
+            }
+        }
+
+        let parent_indent = match context {
+            YamlLexContext::FlowOut { indent } => indent,
+            _ => 0,
+        };
+        // https://yaml.org/spec/1.2.2/#8111-block-indentation-indicator
+        let mut content_indent =
+            indentation_indicator.map(|indicator| parent_indent.saturating_sub(1) + indicator);
+
+        let bytes = self.source.as_bytes();
+        let mut end = self.position;
+        let mut position = self.position;
+        while position < bytes.len() {
+            // Skip the line break
+            if bytes[position] == b'\r' {
+                position += 1;
+            }
+            if bytes.get(position) == Some(&b'\n') {
+                position += 1;
+            }
+
+            let line_start = position;
+            while bytes.get(position) == Some(&b' ') {
+                position += 1;
+            }
+            let indent = position - line_start;
+
+            match bytes.get(position) {
+                None => break,
+                // An empty line
+                Some(c) if is_break(*c) => {}
+                Some(_) => {
+                    let content_indent = *content_indent.get_or_insert(indent);
+                    if indent < content_indent
+                        || indent < parent_indent
+                        || (indent == 0 && self.is_at_document_marker(line_start))
+                    {
+                        break;
+                    }
+
+                    while bytes.get(position).is_some_and(|c| !is_break(*c)) {
+                        position += 1;
+                    }
+                    end = position;
+                }
+            }
+        }
+
+        self.position = end;
+        kind
+    }
+
     // https://yaml.org/spec/1.2.2/#731-double-quoted-style
     fn consume_double_quoted_literal(&mut self) -> YamlSyntaxKind {
         self.assert_byte(b'"');
+        let start = self.text_position();
         self.advance(1);
 
         loop {
             match self.current_byte() {
                 Some(b'\\') => {
-                    if matches!(self.peek_byte(), Some(b'"')) {
-                        self.advance(2)
-                    } else {
-                        self.advance(1)
+                    // Skip the escaped character
+                    self.advance(1);
+                    if self.current_byte().is_some() {
+                        self.advance(1);
                     }
                 }
                 Some(b'"') => {
@@ -149,7 +428,10 @@ impl<'src> YamlLexer<'src> {
                     break DOUBLE_QUOTED_LITERAL;
                 }
                 Some(_) => self.advance(1),
-                None => break ERROR_TOKEN,
+                None => {
+                    self.push_unterminated_string_diagnostic(start);
+                    break ERROR_TOKEN;
+                }
             }
         }
     }
@@ -157,6 +439,7 @@ impl<'src> YamlLexer<'src> {
     // https://yaml.org/spec/1.2.2/#732-single-quoted-style
     fn consume_single_quoted_literal(&mut self) -> YamlSyntaxKind {
         self.assert_byte(b'\'');
+        let start = self.text_position();
         self.advance(1);
 
         loop {
@@ -170,11 +453,20 @@ impl<'src> YamlLexer<'src> {
                     }
                 }
                 Some(_) => self.advance(1),
-                None => break ERROR_TOKEN,
+                None => {
+                    self.push_unterminated_string_diagnostic(start);
+                    break ERROR_TOKEN;
+                }
             }
         }
     }
 
+    fn push_unterminated_string_diagnostic(&mut self, start: TextSize) {
+        let diagnostic = ParseDiagnostic::new("Missing closing quote", start..self.text_position())
+            .with_detail(start..start + TextSize::from(1), "The string starts here");
+        self.diagnostics.push(diagnostic);
+    }
+
     fn consume_unexpected_token(&mut self) -> YamlSyntaxKind {
         self.assert_current_char_boundary();
 
@@ -188,6 +480,44 @@ impl<'src> YamlLexer<'src> {
 
         ERROR_TOKEN
     }
+
+    /// Returns `true` if `position` is the first column of a line
+    fn is_at_line_start(&self, position: usize) -> bool {
+        position == self.unicode_bom_length
+            || position
+                .checked_sub(1)
+                .and_then(|previous| self.source.as_bytes().get(previous))
+                .is_some_and(|c| is_break(*c))
+    }
+
+    /// Returns `true` if there's a document marker, `---` or `...`, at `position`.
+    /// https://yaml.org/spec/1.2.2/#rule-c-forbidden
+    fn is_at_document_marker(&self, position: usize) -> bool {
+        let bytes = self.source.as_bytes();
+        self.is_at_line_start(position)
+            && matches!(bytes.get(position..position + 3), Some(b"---" | b"..."))
+            && bytes
+                .get(position + 3)
+                .is_none_or(|c| is_space(*c) || is_break(*c))
+    }
+
+    /// Returns `true` if the character before the current position is a whitespace or a line break
+    fn is_after_whitespace(&self) -> bool {
+        self.position
+            .checked_sub(1)
+            .and_then(|previous| self.source.as_bytes().get(previous))
+            .is_none_or(|c| is_space(*c) || is_break(*c))
+    }
+
+    /// Returns `true` if the current position directly follows a JSON-like node. A `:` following
+    /// a JSON-like key doesn't need to be followed by a space in flow collections, e.g. `{"a":b}`.
+    /// https://yaml.org/spec/1.2.2/#rule-c-ns-flow-map-adjacent-value
+    fn follows_json_node(&self) -> bool {
+        self.position
+            .checked_sub(1)
+            .and_then(|previous| self.source.as_bytes().get(previous))
+            .is_some_and(|c| matches!(c, b'"' | b'\'' | b']' | b'}'))
+    }
 }
 
 impl<'src> Lexer<'src> for YamlLexer<'src> {
@@ -224,8 +554,9 @@ impl<'src> Lexer<'src> for YamlLexer<'src> {
     fn next_token(&mut self, context: Self::LexContext) -> Self::Kind {
         self.current_start = TextSize::from(self.position as u32);
         self.current_flags = TokenFlags::empty();
+        self.current_diagnostics_start = self.diagnostics.len();
         let kind = match self.current_byte() {
-            Some(current) => self.consume_token(current, context),
+            Some(current) => self.consume_token_or_bom(current, context),
             None => EOF,
         };
         self.current_kind = kind;
@@ -259,6 +590,7 @@ impl<'src> Lexer<'src> for YamlLexer<'src> {
         self.current_flags = current_flags;
         self.unicode_bom_length = unicode_bom_length;
         self.diagnostics.truncate(diagnostics_pos as usize);
+        self.current_diagnostics_start = self.current_diagnostics_start.min(self.diagnostics.len());
     }
 
     fn finish(self) -> Vec<ParseDiagnostic> {
@@ -299,9 +631,10 @@ impl<'src> Lexer<'src> for YamlLexer<'src> {
 impl<'src> ReLexer<'src> for YamlLexer<'src> {
     fn re_lex(&mut self, context: Self::ReLexContext) -> Self::Kind {
         self.position = u32::from(self.current_start) as usize;
+        self.diagnostics.truncate(self.current_diagnostics_start);
 
         let kind = match self.current_byte() {
-            Some(current) => self.consume_token(current, context),
+            Some(current) => self.consume_token_or_bom(current, context),
             None => EOF,
         };
         self.current_kind = kind;
@@ -325,7 +658,7 @@ impl<'src> LexerWithCheckpoint<'src> for YamlLexer<'src> {
 
 /// Lex context as specified in
 /// https://yaml.org/spec/1.2.2/#42-production-parameters
-#[derive(Default, Clone, Copy)]
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
 pub enum YamlLexContext {
     /// Before getting into the document body, for example:
     /// %YAML 1.2
@@ -341,7 +674,11 @@ pub enum YamlLexContext {
     /// Inside block value, but outside flow context, for example:
     /// abc: [1, 2, 3]
     ///      ^^^^^^^^^
-    FlowOut,
+    ///
+    /// `indent` is the minimal indentation of the lines of the node, which is one more than the
+    /// indentation of its parent collection. The continuation lines of a multiline plain scalar
+    /// and the content of a block scalar must be indented by at least `indent` spaces.
+    FlowOut { indent: usize },
     /// Inside flow context, for example:
     /// abc: [1, 2, [4, 5]]
     ///       ^  ^  ^^^^^^
@@ -352,6 +689,13 @@ pub enum YamlLexContext {
     FlowKey,
 }
 
+impl YamlLexContext {
+    /// Returns `true` if the context is inside a flow collection
+    pub(crate) fn is_flow(&self) -> bool {
+        matches!(self, Self::FlowIn | Self::FlowKey)
+    }
+}
+
 impl LexContext for YamlLexContext {
     fn is_regular(&self) -> bool {
         matches!(self, Self::Regular)
@@ -362,7 +706,7 @@ impl LexContext for YamlLexContext {
 fn is_plain_safe(c: u8, context: YamlLexContext) -> bool {
     use YamlLexContext::*;
     match context {
-        FlowOut | BlockKey => is_non_space_char(c),
+        FlowOut { .. } | BlockKey => is_non_space_char(c),
         FlowIn | FlowKey => is_non_space_char(c) && !is_flow_indicator(c),
         // Can happen when a YAML document starts with a plain token. This token will then be
         // used by the parser to determine whether the parser is already inside the document
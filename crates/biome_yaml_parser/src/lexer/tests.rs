@@ -168,3 +168,92 @@ fn lex_incorrect_flow_key() {
         PLAIN_LITERAL:3,
     );
 }
+
+#[test]
+fn lex_document_markers() {
+    assert_lex!(
+        YamlLexContext::Regular,
+        "---\na\n...",
+        DIRECTIVE_END:3,
+        NEWLINE:1,
+        PLAIN_LITERAL:1,
+        NEWLINE:1,
+        DOC_END:3,
+    );
+}
+
+#[test]
+fn lex_directive() {
+    assert_lex!(
+        YamlLexContext::Regular,
+        "%YAML 1.2 # version",
+        DIRECTIVE_LITERAL:9,
+        WHITESPACE:1,
+        COMMENT:9,
+    );
+}
+
+#[test]
+fn lex_anchor_and_alias() {
+    assert_lex!(
+        YamlLexContext::Regular,
+        "&anchor *alias",
+        ANCHOR_PROPERTY_LITERAL:7,
+        WHITESPACE:1,
+        ALIAS_LITERAL:6,
+    );
+}
+
+#[test]
+fn lex_tags() {
+    assert_lex!(
+        YamlLexContext::Regular,
+        "!!str !local !<tag:yaml.org,2002:str>",
+        TAG_PROPERTY_LITERAL:5,
+        WHITESPACE:1,
+        TAG_PROPERTY_LITERAL:6,
+        WHITESPACE:1,
+        TAG_PROPERTY_LITERAL:24,
+    );
+}
+
+#[test]
+fn lex_block_scalar_with_indicators() {
+    assert_lex!(
+        YamlLexContext::FlowOut { indent: 0 },
+        "|2- # comment\n   text\n  more\nnext",
+        LITERAL_BLOCK_LITERAL:28,
+        NEWLINE:1,
+        PLAIN_LITERAL:4,
+    );
+}
+
+#[test]
+fn lex_multiline_plain() {
+    assert_lex!(
+        YamlLexContext::FlowOut { indent: 1 },
+        "a b\n  c\nd",
+        PLAIN_LITERAL:7,
+        NEWLINE:1,
+        PLAIN_LITERAL:1,
+    );
+}
+
+#[test]
+fn lex_flow_collection() {
+    assert_lex!(
+        YamlLexContext::FlowIn,
+        "[a, {b: c}]",
+        L_BRACK:1,
+        PLAIN_LITERAL:1,
+        COMMA:1,
+        WHITESPACE:1,
+        L_CURLY:1,
+        PLAIN_LITERAL:1,
+        COLON:1,
+        WHITESPACE:1,
+        PLAIN_LITERAL:1,
+        R_CURLY:1,
+        R_BRACK:1,
+    );
+}
@@ -1,9 +1,10 @@
 use biome_parser::{
     CompletedMarker, Marker, Parser,
     parse_lists::ParseNodeList,
-    parse_recovery::ParseRecovery,
+    parse_recovery::{ParseRecovery, RecoveryResult},
     prelude::ParsedSyntax::{self, *},
 };
+use biome_rowan::{TextRange, TextSize};
 use biome_yaml_syntax::{
     T,
     YamlSyntaxKind::{self, *},
@@ -13,111 +14,426 @@ use crate::lexer::YamlLexContext;
 
 use super::{
     YamlParser,
-    flow::{is_at_flow_yaml_node, parse_flow_yaml_node},
-    parse_error::expected_block_mapping,
+    flow::{
+        flow_node_kind, is_at_any_flow_node, is_at_flow_content, parse_any_flow_node,
+        parse_flow_content,
+    },
+    parse_error::{
+        duplicate_properties, expected_block_mapping_entry, expected_block_sequence_entry,
+        mapping_in_inline_value, multiline_implicit_key, sequence_in_inline_value,
+    },
+    property::{is_at_properties, parse_any_properties},
 };
 
-pub(crate) fn parse_any_block_node(p: &mut YamlParser) -> ParsedSyntax {
-    if maybe_at_implicit_mapping_key(p) {
-        Present(parse_implicit_entry_or_flow_node(p))
-    } else if is_at_explicit_mapping_key(p) {
-        Present(parse_block_collection(p))
-    } else if is_at_block_scalar(p) {
-        Present(parse_block_scalar(p))
-    } else {
-        Absent
+/// The minimal indentation of the lines of a block node
+#[derive(Debug, Copy, Clone)]
+struct BlockIndent {
+    /// The minimal indentation of the node, which is one more than the indentation of its parent
+    /// collection
+    node: usize,
+    /// The minimal indentation of a block sequence. A sequence that's the key or the value of a
+    /// mapping entry can be at the same indentation as the mapping.
+    /// https://yaml.org/spec/1.2.2/#rule-s-l+block-collection
+    sequence: usize,
+}
+
+impl BlockIndent {
+    /// The indentation of the root node of a document
+    const DOCUMENT: Self = Self {
+        node: 0,
+        sequence: 0,
+    };
+
+    /// The indentation of an entry of a sequence at `column`
+    fn block_in(column: usize) -> Self {
+        Self {
+            node: column + 1,
+            sequence: column + 1,
+        }
+    }
+
+    /// The indentation of the key or the value of an entry of a mapping at `column`
+    fn block_out(column: usize) -> Self {
+        Self {
+            node: column + 1,
+            sequence: column,
+        }
+    }
+
+    fn lex_context(&self) -> YamlLexContext {
+        YamlLexContext::FlowOut { indent: self.node }
     }
 }
 
-fn parse_block_scalar(p: &mut YamlParser) -> CompletedMarker {
-    let m = p.start();
+/// Where a block node starts, relative to the indicator of its parent entry
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum NodeStart {
+    /// The node starts on a new line, like the root node of a document, or a value on the lines
+    /// following its key.
+    NewLine,
+    /// The node follows a `-`, `?` or explicit `:` indicator on the same line. The node can be a
+    /// compact sequence or a compact mapping.
+    /// https://yaml.org/spec/1.2.2/#rule-s-l+block-indented
+    Compact,
+    /// The node follows the `:` of an implicit entry on the same line, it can't be a collection.
+    Inline,
+}
+
+impl NodeStart {
+    fn sequence_kind(&self) -> YamlSyntaxKind {
+        if *self == Self::Compact {
+            YAML_COMPACT_SEQUENCE
+        } else {
+            YAML_BLOCK_SEQUENCE
+        }
+    }
+
+    fn mapping_kind(&self) -> YamlSyntaxKind {
+        if *self == Self::Compact {
+            YAML_COMPACT_MAPPING
+        } else {
+            YAML_BLOCK_MAPPING
+        }
+    }
+
+    /// Wraps the content of a collection in a block collection, unless it's a compact collection
+    fn complete_collection(&self, p: &mut YamlParser, content: CompletedMarker) -> CompletedMarker {
+        if *self == Self::Compact {
+            content
+        } else {
+            content.precede(p).complete(p, YAML_BLOCK_COLLECTION)
+        }
+    }
+}
+
+/// Parses the root node of a document
+pub(crate) fn parse_document_node(p: &mut YamlParser) -> ParsedSyntax {
+    parse_any_block_node(p, BlockIndent::DOCUMENT, NodeStart::NewLine)
+}
+
+// https://yaml.org/spec/1.2.2/#rule-s-l+block-node
+fn parse_any_block_node(p: &mut YamlParser, indent: BlockIndent, start: NodeStart) -> ParsedSyntax {
+    p.re_lex(indent.lex_context());
+    if p.at_document_end() {
+        return Absent;
+    }
+    if start == NodeStart::NewLine {
+        let min_column = if p.at(T![-]) {
+            indent.sequence
+        } else {
+            indent.node
+        };
+        if p.cur_column() < min_column {
+            return Absent;
+        }
+    }
+
+    if is_at_properties(p) {
+        return Present(parse_block_node_with_properties(p, indent, start));
+    }
+
     match p.cur() {
-        LITERAL_BLOCK_LITERAL => {
-            parse_literal_scalar(p);
+        T![-] if start != NodeStart::Inline => {
+            let sequence = parse_block_sequence(p, start.sequence_kind());
+            Present(start.complete_collection(p, sequence))
+        }
+        T![?] if start != NodeStart::Inline => {
+            let mapping = parse_block_mapping(p, start.mapping_kind());
+            Present(start.complete_collection(p, mapping))
+        }
+        T![-] | T![?] => {
+            // For example `a: - b`
+            let m = p.start();
+            let range = bump_rest_of_line(p);
+            let diagnostic = if p.text(range).starts_with('-') {
+                sequence_in_inline_value(p, range)
+            } else {
+                mapping_in_inline_value(p, range)
+            };
+            p.error(diagnostic);
+            Present(m.complete(p, YAML_BOGUS_BLOCK_NODE))
         }
-        FOLDED_BLOCK_LITERAL => {
-            parse_folded_scalar(p);
+        LITERAL_BLOCK_LITERAL | FOLDED_BLOCK_LITERAL => {
+            let m = p.start();
+            parse_block_scalar_content(p);
+            Present(m.complete(p, YAML_BLOCK_SCALAR))
         }
-        _ => {}
+        _ => parse_any_flow_node(p, indent.lex_context())
+            .map(|node| parse_flow_in_block_or_mapping(p, node, start)),
     }
-    m.complete(p, YAML_BLOCK_SCALAR)
 }
 
-fn parse_literal_scalar(p: &mut YamlParser) -> CompletedMarker {
+/// Parses a node starting with properties. The properties belong to the node following them on
+/// the same line, otherwise, they belong to the block node on the next lines.
+fn parse_block_node_with_properties(
+    p: &mut YamlParser,
+    indent: BlockIndent,
+    start: NodeStart,
+) -> CompletedMarker {
     let m = p.start();
-    p.bump(LITERAL_BLOCK_LITERAL);
-    m.complete(p, YAML_LITERAL_SCALAR)
+    parse_any_properties(p).ok();
+    p.re_lex(indent.lex_context());
+
+    if !p.at_line_start() && !p.at_document_end() {
+        return match p.cur() {
+            LITERAL_BLOCK_LITERAL | FOLDED_BLOCK_LITERAL => {
+                parse_block_scalar_content(p);
+                m.complete(p, YAML_BLOCK_SCALAR)
+            }
+            _ if is_at_flow_content(p) => {
+                let content = parse_flow_content(p, indent.lex_context());
+                let node = m.complete(p, flow_node_kind(content.kind(p)));
+                parse_flow_in_block_or_mapping(p, node, start)
+            }
+            _ => complete_empty_node(p, m),
+        };
+    }
+
+    let min_column = if p.at(T![-]) {
+        indent.sequence
+    } else {
+        indent.node
+    };
+    if p.at_document_end() || p.cur_column() < min_column {
+        return complete_empty_node(p, m);
+    }
+
+    match p.cur() {
+        T![-] => {
+            parse_block_sequence(p, YAML_BLOCK_SEQUENCE);
+            m.complete(p, YAML_BLOCK_COLLECTION)
+        }
+        T![?] => {
+            parse_block_mapping(p, YAML_BLOCK_MAPPING);
+            m.complete(p, YAML_BLOCK_COLLECTION)
+        }
+        LITERAL_BLOCK_LITERAL | FOLDED_BLOCK_LITERAL => {
+            parse_block_scalar_content(p);
+            m.complete(p, YAML_BLOCK_SCALAR)
+        }
+        _ if is_at_flow_content(p) => {
+            let content = parse_flow_content(p, indent.lex_context());
+            let kind = flow_node_kind(content.kind(p));
+            if is_at_implicit_value(p) {
+                // The properties belong to the mapping, the content is its first key
+                let key = content.precede(p).complete(p, kind);
+                parse_block_mapping_from_implicit_key(p, key, YAML_BLOCK_MAPPING);
+                m.complete(p, YAML_BLOCK_COLLECTION)
+            } else {
+                let node = m.complete(p, kind);
+                node.precede(p).complete(p, YAML_FLOW_IN_BLOCK_NODE)
+            }
+        }
+        _ if is_at_any_flow_node(p) => {
+            let node_start = p.cur_range().start();
+            if let Present(key) = parse_any_flow_node(p, indent.lex_context()) {
+                if is_at_implicit_value(p) {
+                    parse_block_mapping_from_implicit_key(p, key, YAML_BLOCK_MAPPING);
+                    return m.complete(p, YAML_BLOCK_COLLECTION);
+                }
+            }
+            let range = TextRange::new(node_start, p.last_end().unwrap_or(node_start));
+            p.error(duplicate_properties(p, range));
+            m.complete(p, YAML_BOGUS_BLOCK_NODE)
+        }
+        _ => complete_empty_node(p, m),
+    }
 }
 
-fn parse_folded_scalar(p: &mut YamlParser) -> CompletedMarker {
-    let m = p.start();
-    p.bump(FOLDED_BLOCK_LITERAL);
-    m.complete(p, YAML_FOLDED_SCALAR)
+/// Completes a node that only has properties
+fn complete_empty_node(p: &mut YamlParser, m: Marker) -> CompletedMarker {
+    let node = m.complete(p, YAML_FLOW_YAML_NODE);
+    node.precede(p).complete(p, YAML_FLOW_IN_BLOCK_NODE)
+}
+
+/// Returns `true` if the parser is at the `:` following an implicit key on the same line
+fn is_at_implicit_value(p: &YamlParser) -> bool {
+    p.at(T![:]) && !p.at_line_start()
+}
+
+/// Wraps a flow node in a block node. If it's followed by `:`, it's the first key of a mapping.
+fn parse_flow_in_block_or_mapping(
+    p: &mut YamlParser,
+    node: CompletedMarker,
+    start: NodeStart,
+) -> CompletedMarker {
+    if !is_at_implicit_value(p) {
+        return node.precede(p).complete(p, YAML_FLOW_IN_BLOCK_NODE);
+    }
+
+    if start == NodeStart::Inline {
+        // For example `a: b: c`
+        let m = node.precede(p);
+        let range = bump_rest_of_line(p);
+        p.error(mapping_in_inline_value(p, range));
+        return m.complete(p, YAML_BOGUS_BLOCK_NODE);
+    }
+
+    let mapping = parse_block_mapping_from_implicit_key(p, node, start.mapping_kind());
+    start.complete_collection(p, mapping)
+}
+
+/// Bumps the tokens until the end of the line, and returns their range
+fn bump_rest_of_line(p: &mut YamlParser) -> TextRange {
+    let start = p.cur_range().start();
+    while !p.at_line_start() && !p.at_document_end() {
+        p.bump_any();
+    }
+    TextRange::new(start, p.last_end().unwrap_or(start))
 }
 
-fn parse_block_collection(p: &mut YamlParser) -> CompletedMarker {
+// https://yaml.org/spec/1.2.2/#81-block-scalar-styles
+fn parse_block_scalar_content(p: &mut YamlParser) -> CompletedMarker {
     let m = p.start();
-    parse_block_mapping(p);
-    m.complete(p, YAML_BLOCK_COLLECTION)
+    let kind = if p.at(LITERAL_BLOCK_LITERAL) {
+        YAML_LITERAL_SCALAR
+    } else {
+        YAML_FOLDED_SCALAR
+    };
+    p.bump_any();
+    m.complete(p, kind)
+}
+
+/// Parses the node following a `-`, `?` or explicit `:` indicator
+/// https://yaml.org/spec/1.2.2/#rule-s-l+block-indented
+fn parse_block_indented(p: &mut YamlParser, indent: BlockIndent) -> ParsedSyntax {
+    let start = if p.at_line_start() {
+        NodeStart::NewLine
+    } else {
+        NodeStart::Compact
+    };
+    parse_any_block_node(p, indent, start)
+}
+
+/// Returns `true` if the parser is at the start of a line, at the indentation of a collection.
+/// Block collections are delimited by the indentation of their entries.
+fn is_at_entry_start(p: &YamlParser, column: usize) -> bool {
+    p.at_line_start() && p.cur_column() == column
+}
+
+/// Returns `true` if the recovery of a collection at `column` that started at `start` can stop:
+/// at a line that's not more indented than the collection.
+fn is_at_block_recovery_point(p: &YamlParser, column: usize, start: TextSize) -> bool {
+    p.at_document_end()
+        || (p.cur_range().start() > start && p.at_line_start() && p.cur_column() <= column)
 }
 
-/// Since the block key context's only difference with flow context is that it disallows multiline
-/// flow node, we can just parse a flow node and emit a diagnostic when we encounter a key that
-/// violate the restriction.lexer
-fn parse_implicit_entry_or_flow_node(p: &mut YamlParser) -> CompletedMarker {
-    let implicit_key_marker = parse_block_map_implicit_key(p);
-    // It's indeed an implicit key
-    if p.at(T![:]) {
-        let implicit_entry_marker = implicit_key_marker.precede(p);
-        parse_block_map_implicit_value(p);
-        let implicit_entry_marker =
-            implicit_entry_marker.complete(p, YAML_BLOCK_MAP_IMPLICIT_ENTRY);
-        parse_block_collection_starting_with_implicit_entry(p, implicit_entry_marker)
+// https://yaml.org/spec/1.2.2/#rule-l+block-sequence
+fn parse_block_sequence(p: &mut YamlParser, kind: YamlSyntaxKind) -> CompletedMarker {
+    let column = p.cur_column();
+    let entry = parse_block_sequence_entry(p, column);
+    let entries = BlockSequenceEntryList::new(entry.precede(p), column).parse_list(p);
+    entries.precede(p).complete(p, kind)
+}
+
+struct BlockSequenceEntryList {
+    start_marker: Option<Marker>,
+    /// The column of the `-` of the entries
+    column: usize,
+}
+
+impl BlockSequenceEntryList {
+    fn new(start_marker: Marker, column: usize) -> Self {
+        Self {
+            start_marker: Some(start_marker),
+            column,
+        }
+    }
+}
+
+impl ParseNodeList for BlockSequenceEntryList {
+    type Kind = YamlSyntaxKind;
+    type Parser<'source> = YamlParser<'source>;
+
+    const LIST_KIND: Self::Kind = YAML_BLOCK_SEQUENCE_ENTRY_LIST;
+
+    fn start_list(&mut self, p: &mut Self::Parser<'_>) -> Marker {
+        self.start_marker.take().unwrap_or_else(|| p.start())
+    }
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        if !is_at_entry_start(p, self.column) || !p.at(T![-]) {
+            return Absent;
+        }
+        Present(parse_block_sequence_entry(p, self.column))
     }
-    // It's just a flow node
-    else {
-        let block_node_marker = implicit_key_marker.precede(p);
-        p.eat(NEWLINE);
-        block_node_marker.complete(p, YAML_FLOW_IN_BLOCK_NODE)
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at_document_end()
+            || (p.at_line_start()
+                && (p.cur_column() < self.column
+                    || (p.cur_column() == self.column && !p.at(T![-]))))
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        let recovery = BlockCollectionParseRecovery {
+            column: self.column,
+            start: p.cur_range().start(),
+        };
+        parsed_element.or_recover(p, &recovery, expected_block_sequence_entry)
     }
 }
 
-fn parse_block_collection_starting_with_implicit_entry(
+// https://yaml.org/spec/1.2.2/#rule-c-l-block-seq-entry
+fn parse_block_sequence_entry(p: &mut YamlParser, column: usize) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T![-]);
+    parse_block_indented(p, BlockIndent::block_in(column)).ok();
+    m.complete(p, YAML_BLOCK_SEQUENCE_ENTRY)
+}
+
+/// Parses a mapping starting with an explicit entry
+// https://yaml.org/spec/1.2.2/#rule-l+block-mapping
+fn parse_block_mapping(p: &mut YamlParser, kind: YamlSyntaxKind) -> CompletedMarker {
+    let column = p.cur_column();
+    let entry = parse_block_map_explicit_entry(p, column);
+    parse_block_map_entries(p, entry, column, kind)
+}
+
+/// Parses a mapping whose first implicit key is `key`
+fn parse_block_mapping_from_implicit_key(
     p: &mut YamlParser,
-    implicit_entry_marker: CompletedMarker,
+    key: CompletedMarker,
+    kind: YamlSyntaxKind,
 ) -> CompletedMarker {
-    let block_mapping_marker =
-        parse_block_mapping_starting_with_implicit_entry(p, implicit_entry_marker);
-    let block_collection_marker = block_mapping_marker.precede(p);
-    block_collection_marker.complete(p, YAML_BLOCK_COLLECTION)
-}
+    let key_range = key.range(p);
+    let column = p.column_at(key_range.start());
+    if p.text(key_range).contains(['\n', '\r']) {
+        p.error(multiline_implicit_key(p, key_range));
+    }
 
-fn parse_block_mapping(p: &mut YamlParser) -> CompletedMarker {
-    let m = p.start();
-    BlockMapEntryList::default().parse_list(p);
-    m.complete(p, YAML_BLOCK_MAPPING)
+    let entry = key.precede(p);
+    parse_block_map_implicit_value(p, column);
+    let entry = entry.complete(p, YAML_BLOCK_MAP_IMPLICIT_ENTRY);
+    parse_block_map_entries(p, entry, column, kind)
 }
 
-fn parse_block_mapping_starting_with_implicit_entry(
+/// Parses the entries of a mapping at `column` following its first entry
+fn parse_block_map_entries(
     p: &mut YamlParser,
-    implicit_entry_marker: CompletedMarker,
+    first_entry: CompletedMarker,
+    column: usize,
+    kind: YamlSyntaxKind,
 ) -> CompletedMarker {
-    let block_map_entry_list_marker = implicit_entry_marker.precede(p);
-    let block_map_entry_list_marker =
-        BlockMapEntryList::with(block_map_entry_list_marker).parse_list(p);
-    let block_map_marker = block_map_entry_list_marker.precede(p);
-    block_map_marker.complete(p, YAML_BLOCK_MAPPING)
+    let entries = BlockMapEntryList::new(first_entry.precede(p), column).parse_list(p);
+    entries.precede(p).complete(p, kind)
 }
 
-#[derive(Default)]
 pub(crate) struct BlockMapEntryList {
     start_marker: Option<Marker>,
+    /// The column of the keys of the entries
+    column: usize,
 }
 
 impl BlockMapEntryList {
-    fn with(start_marker: Marker) -> Self {
+    fn new(start_marker: Marker, column: usize) -> Self {
         Self {
             start_marker: Some(start_marker),
+            column,
         }
     }
 }
@@ -133,109 +449,112 @@ impl ParseNodeList for BlockMapEntryList {
     }
 
     fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
-        parse_block_map_entry(p)
+        if !is_at_entry_start(p, self.column) {
+            return Absent;
+        }
+        parse_block_map_entry(p, self.column)
     }
 
     fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
-        p.at(DEDENT)
-        // If this is the top level block mapping
-        || p.at(DOC_END)
+        p.at_document_end() || (p.at_line_start() && p.cur_column() < self.column)
     }
 
     fn recover(
         &mut self,
         p: &mut Self::Parser<'_>,
         parsed_element: ParsedSyntax,
-    ) -> biome_parser::parse_recovery::RecoveryResult {
-        parsed_element.or_recover(p, &BlockMapEntryListParseRecovery, expected_block_mapping)
+    ) -> RecoveryResult {
+        let recovery = BlockMapEntryListParseRecovery {
+            column: self.column,
+            start: p.cur_range().start(),
+        };
+        parsed_element.or_recover(p, &recovery, expected_block_mapping_entry)
     }
 }
 
-struct BlockMapEntryListParseRecovery;
+/// Skips the tokens that can't start an entry until a line that's not more indented than the
+/// collection
+struct BlockCollectionParseRecovery {
+    column: usize,
+    start: TextSize,
+}
 
-impl ParseRecovery for BlockMapEntryListParseRecovery {
+impl ParseRecovery for BlockCollectionParseRecovery {
     type Kind = YamlSyntaxKind;
     type Parser<'source> = YamlParser<'source>;
-    const RECOVERED_KIND: Self::Kind = YAML_BOGUS_BLOCK_MAP_ENTRY;
+    const RECOVERED_KIND: Self::Kind = YAML_BOGUS;
 
     fn is_at_recovered(&self, p: &mut Self::Parser<'_>) -> bool {
-        p.at(DEDENT)
+        is_at_block_recovery_point(p, self.column, self.start)
     }
 }
 
-fn parse_block_map_entry(p: &mut YamlParser) -> ParsedSyntax {
-    if is_at_explicit_mapping_key(p) {
-        parse_block_map_explicit_entry(p)
-    } else {
-        parse_block_map_implicit_entry(p)
-    }
+struct BlockMapEntryListParseRecovery {
+    column: usize,
+    start: TextSize,
 }
 
-fn parse_block_map_explicit_entry(p: &mut YamlParser) -> ParsedSyntax {
-    if !is_at_explicit_mapping_key(p) {
-        return Absent;
+impl ParseRecovery for BlockMapEntryListParseRecovery {
+    type Kind = YamlSyntaxKind;
+    type Parser<'source> = YamlParser<'source>;
+    const RECOVERED_KIND: Self::Kind = YAML_BOGUS_BLOCK_MAP_ENTRY;
+
+    fn is_at_recovered(&self, p: &mut Self::Parser<'_>) -> bool {
+        is_at_block_recovery_point(p, self.column, self.start)
     }
-    let m = p.start();
-    parse_block_map_explicit_key(p);
-    // Value can be omitted in an explicit entry
-    parse_block_map_explicit_value(p).ok();
-    Present(m.complete(p, YAML_BLOCK_MAP_EXPLICIT_ENTRY))
 }
 
-fn parse_block_map_explicit_key(p: &mut YamlParser) -> CompletedMarker {
-    let m = p.start();
-    p.bump(T![?]);
-    parse_block_indented(p).ok();
-    m.complete(p, YAML_BLOCK_MAP_EXPLICIT_KEY)
+// https://yaml.org/spec/1.2.2/#rule-ns-l-block-map-entry
+fn parse_block_map_entry(p: &mut YamlParser, column: usize) -> ParsedSyntax {
+    // Implicit keys are restricted to a single line
+    p.re_lex(YamlLexContext::BlockKey);
+    match p.cur() {
+        T![?] => Present(parse_block_map_explicit_entry(p, column)),
+        T![:] => {
+            // An implicit entry with an empty key
+            let m = p.start();
+            parse_block_map_implicit_value(p, column);
+            Present(m.complete(p, YAML_BLOCK_MAP_IMPLICIT_ENTRY))
+        }
+        _ => parse_any_flow_node(p, YamlLexContext::BlockKey).map(|key| {
+            let m = key.precede(p);
+            parse_block_map_implicit_value(p, column);
+            m.complete(p, YAML_BLOCK_MAP_IMPLICIT_ENTRY)
+        }),
+    }
 }
 
-fn parse_block_map_explicit_value(p: &mut YamlParser) -> ParsedSyntax {
-    if !p.at(T![:]) {
-        return Absent;
-    }
+// https://yaml.org/spec/1.2.2/#rule-c-l-block-map-explicit-entry
+fn parse_block_map_explicit_entry(p: &mut YamlParser, column: usize) -> CompletedMarker {
     let m = p.start();
-    p.bump(T![:]);
-    parse_block_indented(p).ok();
-    Present(m.complete(p, YAML_BLOCK_MAP_EXPLICIT_VALUE))
-}
 
-fn parse_block_indented(p: &mut YamlParser) -> ParsedSyntax {
-    parse_any_block_node(p)
-}
+    let key = p.start();
+    p.bump(T![?]);
+    parse_block_indented(p, BlockIndent::block_out(column)).ok();
+    key.complete(p, YAML_BLOCK_MAP_EXPLICIT_KEY);
 
-fn parse_block_map_implicit_entry(p: &mut YamlParser) -> ParsedSyntax {
-    if !is_at_flow_yaml_node(p) {
-        return Absent;
+    // Value can be omitted in an explicit entry
+    if p.at(T![:]) && (!p.at_line_start() || p.cur_column() == column) {
+        let value = p.start();
+        p.bump(T![:]);
+        parse_block_indented(p, BlockIndent::block_out(column)).ok();
+        value.complete(p, YAML_BLOCK_MAP_EXPLICIT_VALUE);
     }
-    let m = p.start();
-    parse_block_map_implicit_key(p);
-    parse_block_map_implicit_value(p);
-    Present(m.complete(p, YAML_BLOCK_MAP_IMPLICIT_ENTRY))
-}
 
-fn parse_block_map_implicit_key(p: &mut YamlParser) -> CompletedMarker {
-    parse_flow_yaml_node(p, YamlLexContext::BlockKey)
+    m.complete(p, YAML_BLOCK_MAP_EXPLICIT_ENTRY)
 }
 
-fn parse_block_map_implicit_value(p: &mut YamlParser) -> CompletedMarker {
+// https://yaml.org/spec/1.2.2/#rule-c-l-block-map-implicit-value
+fn parse_block_map_implicit_value(p: &mut YamlParser, column: usize) -> CompletedMarker {
     let m = p.start();
-    p.bump(COLON);
-    // Value can be completely empty according to the spec
-    let value = parse_any_block_node(p);
-    if value.is_absent() {
-        p.eat(NEWLINE);
+    if p.expect(T![:]) {
+        // Value can be completely empty according to the spec
+        let start = if p.at_line_start() {
+            NodeStart::NewLine
+        } else {
+            NodeStart::Inline
+        };
+        parse_any_block_node(p, BlockIndent::block_out(column), start).ok();
     }
     m.complete(p, YAML_BLOCK_MAP_IMPLICIT_VALUE)
 }
-
-fn is_at_explicit_mapping_key(p: &mut YamlParser) -> bool {
-    p.at(T![?])
-}
-
-fn maybe_at_implicit_mapping_key(p: &YamlParser) -> bool {
-    is_at_flow_yaml_node(p)
-}
-
-fn is_at_block_scalar(p: &YamlParser) -> bool {
-    p.at(LITERAL_BLOCK_LITERAL) || p.at(FOLDED_BLOCK_LITERAL)
-}
@@ -5,12 +5,17 @@ use biome_parser::{
     prelude::ParsedSyntax::{self, *},
     token_set,
 };
+use biome_rowan::TextRange;
 use biome_yaml_syntax::{
     T,
     YamlSyntaxKind::{self, *},
 };
 
-use super::{YamlParser, block::parse_any_block_node, parse_error::expected_directive};
+use super::{
+    YamlParser,
+    block::parse_document_node,
+    parse_error::{expected_directive, expected_document_end},
+};
 
 #[derive(Default)]
 pub(crate) struct DocumentList;
@@ -38,13 +43,42 @@ impl ParseNodeList for DocumentList {
     }
 }
 
+// https://yaml.org/spec/1.2.2/#91-documents
 fn parse_document(p: &mut YamlParser) -> CompletedMarker {
     let m = p.start();
     p.eat(UNICODE_BOM);
-    p.eat(NEWLINE);
+
+    let has_directives = p.at(DIRECTIVE_LITERAL);
     DirectiveList.parse_list(p);
-    p.eat(T![---]);
-    parse_any_block_node(p).ok();
+    if has_directives {
+        // Directives must be followed by an explicit document start
+        p.expect(T![---]);
+    } else {
+        p.eat(T![---]);
+    }
+
+    let node = if p.at_document_end() {
+        Absent
+    } else {
+        parse_document_node(p)
+    };
+
+    // Process the document to its end, e.g. in cases where the root node is followed by
+    // a less indented node
+    if !p.at_document_end() {
+        let rest = match node {
+            Present(node) => node.precede(p),
+            Absent => p.start(),
+        };
+        let start = p.cur_range().start();
+        while !p.at_document_end() {
+            p.bump_any();
+        }
+        let range = TextRange::new(start, p.last_end().unwrap_or(start));
+        p.error(expected_document_end(p, range));
+        rest.complete(p, YAML_BOGUS_BLOCK_NODE);
+    }
+
     p.eat(T![...]);
     m.complete(p, YAML_DOCUMENT)
 }
@@ -1,15 +1,95 @@
-use biome_parser::{CompletedMarker, Parser};
-use biome_yaml_syntax::YamlSyntaxKind::*;
+use biome_parser::{
+    CompletedMarker, Parser,
+    parse_lists::ParseSeparatedList,
+    parse_recovery::{ParseRecoveryTokenSet, RecoveryResult},
+    prelude::ParsedSyntax::{self, *},
+    token_set,
+};
+use biome_yaml_syntax::{
+    T,
+    YamlSyntaxKind::{self, *},
+};
 
 use crate::lexer::YamlLexContext;
 
-use super::YamlParser;
+use super::{
+    YamlParser,
+    parse_error::{expected_flow_mapping_entry, expected_flow_sequence_entry},
+    property::{is_at_properties, parse_any_properties},
+};
+
+/// Parses a flow node: an alias, or a scalar or a flow collection with optional properties.
+/// https://yaml.org/spec/1.2.2/#rule-ns-flow-node
+pub(crate) fn parse_any_flow_node(p: &mut YamlParser, context: YamlLexContext) -> ParsedSyntax {
+    p.re_lex(context);
+    if p.at(ALIAS_LITERAL) {
+        return Present(parse_alias_node(p));
+    }
+    if !is_at_properties(p) && !is_at_flow_content(p) {
+        return Absent;
+    }
 
-// TODO: parse node properties
-pub(crate) fn parse_flow_yaml_node(p: &mut YamlParser, context: YamlLexContext) -> CompletedMarker {
     let m = p.start();
-    parse_plain_scalar(p, context);
-    m.complete(p, YAML_FLOW_YAML_NODE)
+    parse_any_properties(p).ok();
+    p.re_lex(context);
+    let kind = if is_at_flow_content(p) {
+        let content = parse_flow_content(p, context);
+        flow_node_kind(content.kind(p))
+    } else {
+        // A node with only properties has an empty content
+        YAML_FLOW_YAML_NODE
+    };
+    Present(m.complete(p, kind))
+}
+
+/// Parses the content of a flow node: a scalar or a flow collection.
+pub(crate) fn parse_flow_content(p: &mut YamlParser, context: YamlLexContext) -> CompletedMarker {
+    match p.cur() {
+        PLAIN_LITERAL => parse_plain_scalar(p, context),
+        DOUBLE_QUOTED_LITERAL => {
+            let m = p.start();
+            p.bump(DOUBLE_QUOTED_LITERAL);
+            m.complete(p, YAML_DOUBLE_QUOTED_SCALAR)
+        }
+        SINGLE_QUOTED_LITERAL => {
+            let m = p.start();
+            p.bump(SINGLE_QUOTED_LITERAL);
+            m.complete(p, YAML_SINGLE_QUOTED_SCALAR)
+        }
+        T!['['] => parse_flow_sequence(p),
+        T!['{'] => parse_flow_mapping(p),
+        _ => unreachable!("expected the content of a flow node"),
+    }
+}
+
+/// Returns the kind of the flow node with the content of kind `content`:
+/// [YAML_FLOW_YAML_NODE] for plain scalars, [YAML_FLOW_JSON_NODE] for quoted scalars and flow
+/// collections.
+pub(crate) fn flow_node_kind(content: YamlSyntaxKind) -> YamlSyntaxKind {
+    if content == YAML_PLAIN_SCALAR {
+        YAML_FLOW_YAML_NODE
+    } else {
+        YAML_FLOW_JSON_NODE
+    }
+}
+
+/// Returns `true` if the parser is at a scalar or at a flow collection
+pub(crate) fn is_at_flow_content(p: &YamlParser) -> bool {
+    matches!(
+        p.cur(),
+        PLAIN_LITERAL | DOUBLE_QUOTED_LITERAL | SINGLE_QUOTED_LITERAL | T!['['] | T!['{']
+    )
+}
+
+/// Returns `true` if the parser is at the start of a flow node
+pub(crate) fn is_at_any_flow_node(p: &YamlParser) -> bool {
+    p.at(ALIAS_LITERAL) || is_at_properties(p) || is_at_flow_content(p)
+}
+
+fn parse_alias_node(p: &mut YamlParser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(ALIAS_LITERAL);
+    m.complete(p, YAML_ALIAS_NODE)
 }
 
 fn parse_plain_scalar(p: &mut YamlParser, context: YamlLexContext) -> CompletedMarker {
@@ -21,10 +101,170 @@ fn parse_plain_scalar(p: &mut YamlParser, context: YamlLexContext) -> CompletedM
     m.complete(p, YAML_PLAIN_SCALAR)
 }
 
-pub(crate) fn is_at_flow_yaml_node(p: &YamlParser) -> bool {
-    is_at_plain_scalar(p)
+// https://yaml.org/spec/1.2.2/#rule-c-flow-sequence
+fn parse_flow_sequence(p: &mut YamlParser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T!['[']);
+    FlowSequenceEntryList.parse_list(p);
+    p.expect(T![']']);
+    m.complete(p, YAML_FLOW_SEQUENCE)
+}
+
+struct FlowSequenceEntryList;
+
+impl ParseSeparatedList for FlowSequenceEntryList {
+    type Kind = YamlSyntaxKind;
+    type Parser<'source> = YamlParser<'source>;
+
+    const LIST_KIND: Self::Kind = YAML_FLOW_SEQUENCE_ENTRY_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        if p.at(T![,]) {
+            // A missing entry, for example `[a, , b]`
+            p.error(expected_flow_sequence_entry(p, p.cur_range()));
+            return Absent;
+        }
+        parse_flow_sequence_entry(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![']']) || p.at_document_end()
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(
+                YAML_BOGUS_FLOW_NODE,
+                token_set![T![,], T![']'], T![---], T![...]],
+            ),
+            expected_flow_sequence_entry,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+/// Parses an entry of a flow sequence: a flow node, or a single pair mapping like `[a: b]`.
+/// https://yaml.org/spec/1.2.2/#rule-ns-flow-seq-entry
+fn parse_flow_sequence_entry(p: &mut YamlParser) -> ParsedSyntax {
+    p.re_lex(YamlLexContext::FlowIn);
+    match p.cur() {
+        T![?] => Present(parse_flow_map_explicit_entry(p)),
+        T![:] => Present(parse_flow_map_implicit_entry(p)),
+        _ => {
+            let node = parse_any_flow_node(p, YamlLexContext::FlowIn);
+            // A `:` directly following a JSON-like key is only lexed in the flow context
+            p.re_lex(YamlLexContext::FlowIn);
+            if p.at(T![:]) {
+                node.map(|node| {
+                    let m = node.precede(p);
+                    parse_flow_map_implicit_value(p);
+                    m.complete(p, YAML_FLOW_MAP_IMPLICIT_ENTRY)
+                })
+            } else {
+                node
+            }
+        }
+    }
+}
+
+// https://yaml.org/spec/1.2.2/#rule-c-flow-mapping
+fn parse_flow_mapping(p: &mut YamlParser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T!['{']);
+    FlowMapEntryList.parse_list(p);
+    p.expect(T!['}']);
+    m.complete(p, YAML_FLOW_MAPPING)
+}
+
+struct FlowMapEntryList;
+
+impl ParseSeparatedList for FlowMapEntryList {
+    type Kind = YamlSyntaxKind;
+    type Parser<'source> = YamlParser<'source>;
+
+    const LIST_KIND: Self::Kind = YAML_FLOW_MAP_ENTRY_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        if p.at(T![,]) {
+            // A missing entry, for example `{a, , b}`
+            p.error(expected_flow_mapping_entry(p, p.cur_range()));
+            return Absent;
+        }
+        parse_flow_map_entry(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T!['}']) || p.at_document_end()
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(YAML_BOGUS, token_set![T![,], T!['}'], T![---], T![...]]),
+            expected_flow_mapping_entry,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+// https://yaml.org/spec/1.2.2/#rule-ns-flow-map-entry
+fn parse_flow_map_entry(p: &mut YamlParser) -> ParsedSyntax {
+    p.re_lex(YamlLexContext::FlowIn);
+    match p.cur() {
+        T![?] => Present(parse_flow_map_explicit_entry(p)),
+        T![:] => Present(parse_flow_map_implicit_entry(p)),
+        _ if is_at_any_flow_node(p) => Present(parse_flow_map_implicit_entry(p)),
+        _ => Absent,
+    }
+}
+
+// https://yaml.org/spec/1.2.2/#rule-ns-flow-map-explicit-entry
+fn parse_flow_map_explicit_entry(p: &mut YamlParser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T![?]);
+    p.re_lex(YamlLexContext::FlowIn);
+    if p.at(T![:]) || is_at_any_flow_node(p) {
+        parse_flow_map_implicit_entry(p);
+    }
+    m.complete(p, YAML_FLOW_MAP_EXPLICIT_ENTRY)
+}
+
+/// Parses an entry whose key, `:` and value are all optional, e.g. `{a: b, c, : d}`
+/// https://yaml.org/spec/1.2.2/#rule-ns-flow-map-implicit-entry
+fn parse_flow_map_implicit_entry(p: &mut YamlParser) -> CompletedMarker {
+    let m = p.start();
+    parse_any_flow_node(p, YamlLexContext::FlowIn).ok();
+    p.re_lex(YamlLexContext::FlowIn);
+    if p.at(T![:]) {
+        parse_flow_map_implicit_value(p);
+    }
+    m.complete(p, YAML_FLOW_MAP_IMPLICIT_ENTRY)
 }
 
-fn is_at_plain_scalar(p: &YamlParser) -> bool {
-    p.at(PLAIN_LITERAL)
+fn parse_flow_map_implicit_value(p: &mut YamlParser) {
+    p.bump(T![:]);
+    parse_any_flow_node(p, YamlLexContext::FlowIn).ok();
 }
@@ -5,7 +5,11 @@ use biome_parser::{
     parse_lists::ParseNodeList,
     prelude::{ParseDiagnostic, TokenSource, Trivia},
 };
-use biome_yaml_syntax::YamlSyntaxKind::{self, *};
+use biome_rowan::TextSize;
+use biome_yaml_syntax::{
+    T,
+    YamlSyntaxKind::{self, *},
+};
 use document::DocumentList;
 
 use crate::{
@@ -17,6 +21,7 @@ mod block;
 mod document;
 mod flow;
 mod parse_error;
+mod property;
 
 pub(crate) struct YamlParser<'source> {
     context: ParserContext<YamlSyntaxKind>,
@@ -37,6 +42,43 @@ impl<'source> YamlParser<'source> {
         self.source_mut().re_lex(context)
     }
 
+    /// Returns the column of the current token
+    pub fn cur_column(&self) -> usize {
+        self.column_at(self.cur_range().start())
+    }
+
+    /// Returns the column of `offset` in the source text
+    pub fn column_at(&self, offset: TextSize) -> usize {
+        self.line_prefix(offset)
+            .chars()
+            .filter(|c| *c != '\u{feff}')
+            .count()
+    }
+
+    /// Returns `true` if the current token is the first token of its line. YAML relies on the
+    /// indentation of the lines to delimit block collections.
+    pub fn at_line_start(&self) -> bool {
+        self.line_prefix(self.cur_range().start())
+            .chars()
+            .all(|c| matches!(c, ' ' | '\t' | '\u{feff}'))
+    }
+
+    /// Returns the text between the start of the line and `offset`
+    fn line_prefix(&self, offset: TextSize) -> &str {
+        let text = self.source().text();
+        let offset = usize::from(offset);
+        let line_start = text[..offset]
+            .rfind(['\n', '\r'])
+            .map_or(0, |index| index + 1);
+        &text[line_start..offset]
+    }
+
+    /// Returns `true` if the parser is at the end of the current document: at a document marker,
+    /// at the directives of the next document, or at the end of the file.
+    pub fn at_document_end(&self) -> bool {
+        matches!(self.cur(), EOF | T![---] | T![...] | DIRECTIVE_LITERAL)
+    }
+
     pub fn finish(
         self,
     ) -> (
@@ -1,11 +1,58 @@
 use crate::parser::YamlParser;
 use biome_parser::diagnostic::{ParseDiagnostic, expected_node};
+use biome_parser::prelude::*;
 use biome_rowan::TextRange;
 
-pub(crate) fn expected_block_mapping(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
-    expected_node("mapping", range, p)
+pub(crate) fn expected_block_mapping_entry(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("mapping entry", range, p)
+}
+
+pub(crate) fn expected_block_sequence_entry(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("sequence entry", range, p)
+}
+
+pub(crate) fn expected_flow_sequence_entry(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("flow sequence entry", range, p)
+}
+
+pub(crate) fn expected_flow_mapping_entry(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("flow mapping entry", range, p)
 }
 
 pub(crate) fn expected_directive(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
     expected_node("directive", range, p)
 }
+
+pub(crate) fn expected_document_end(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    p.err_builder(
+        "Expected the end of the document, but found more content.",
+        range,
+    )
+}
+
+pub(crate) fn multiline_implicit_key(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    p.err_builder("An implicit key can't span multiple lines.", range)
+        .with_hint("Use an explicit key starting with `? ` instead.")
+}
+
+pub(crate) fn mapping_in_inline_value(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    p.err_builder(
+        "A mapping can't start on the same line as the key of its parent entry.",
+        range,
+    )
+    .with_hint("Move the nested mapping to the next line and indent it, or quote the value if it contains `: `.")
+}
+
+pub(crate) fn sequence_in_inline_value(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    p.err_builder(
+        "A sequence can't start on the same line as the key of its parent entry.",
+        range,
+    )
+    .with_hint(
+        "Move the nested sequence to the next line, or quote the value if it starts with `- `.",
+    )
+}
+
+pub(crate) fn duplicate_properties(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    p.err_builder("A node can only have one anchor and one tag.", range)
+}
@@ -0,0 +1,52 @@
+use biome_parser::{
+    Parser,
+    prelude::ParsedSyntax::{self, *},
+};
+use biome_yaml_syntax::YamlSyntaxKind::*;
+
+use super::YamlParser;
+
+/// Parses the properties of a node: an anchor, a tag, or both in any order.
+/// https://yaml.org/spec/1.2.2/#rule-c-ns-properties
+pub(crate) fn parse_any_properties(p: &mut YamlParser) -> ParsedSyntax {
+    let m = p.start();
+    let kind = match p.cur() {
+        TAG_PROPERTY_LITERAL => {
+            parse_tag_property(p).ok();
+            parse_anchor_property(p).ok();
+            YAML_PROPERTIES_TAG_FIRST
+        }
+        ANCHOR_PROPERTY_LITERAL => {
+            parse_anchor_property(p).ok();
+            parse_tag_property(p).ok();
+            YAML_PROPERTIES_ANCHOR_FIRST
+        }
+        _ => {
+            m.abandon(p);
+            return Absent;
+        }
+    };
+    Present(m.complete(p, kind))
+}
+
+pub(crate) fn is_at_properties(p: &YamlParser) -> bool {
+    p.at(TAG_PROPERTY_LITERAL) || p.at(ANCHOR_PROPERTY_LITERAL)
+}
+
+fn parse_tag_property(p: &mut YamlParser) -> ParsedSyntax {
+    if !p.at(TAG_PROPERTY_LITERAL) {
+        return Absent;
+    }
+    let m = p.start();
+    p.bump(TAG_PROPERTY_LITERAL);
+    Present(m.complete(p, YAML_TAG_PROPERTY))
+}
+
+fn parse_anchor_property(p: &mut YamlParser) -> ParsedSyntax {
+    if !p.at(ANCHOR_PROPERTY_LITERAL) {
+        return Absent;
+    }
+    let m = p.start();
+    p.bump(ANCHOR_PROPERTY_LITERAL);
+    Present(m.complete(p, YAML_ANCHOR_PROPERTY))
+}
@@ -36,8 +36,6 @@ pub enum YamlSyntaxKind {
     ERROR_TOKEN,
     NEWLINE,
     WHITESPACE,
-    INDENT,
-    DEDENT,
     COMMENT,
     YAML_ROOT,
     YAML_DOCUMENT_LIST,
@@ -81,6 +79,7 @@ pub enum YamlSyntaxKind {
     YAML_BOGUS,
     YAML_BOGUS_BLOCK_NODE,
     YAML_BOGUS_BLOCK_MAP_ENTRY,
+    YAML_BOGUS_FLOW_NODE,
     #[doc(hidden)]
     __LAST,
 }
@@ -161,6 +161,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::YamlBogusBlockNode::new_unchecked(node) };
                     $body
                 }
+                $crate::YamlSyntaxKind::YAML_BOGUS_FLOW_NODE => {
+                    let $pattern = unsafe { $crate::YamlBogusFlowNode::new_unchecked(node) };
+                    $body
+                }
                 $crate::YamlSyntaxKind::YAML_BLOCK_MAP_ENTRY_LIST => {
                     let $pattern = unsafe { $crate::YamlBlockMapEntryList::new_unchecked(node) };
                     $body
@@ -102,30 +102,12 @@ impl YamlBlockMapImplicitValue {
             once(element.map(|element| element.into_syntax().into())),
         ))
     }
-    pub fn with_newline_token(self, element: Option<SyntaxToken>) -> Self {
-        Self::unwrap_cast(
-            self.syntax
-                .splice_slots(2usize..=2usize, once(element.map(|element| element.into()))),
-        )
-    }
 }
 impl YamlBlockMapping {
-    pub fn with_indent_token(self, element: Option<SyntaxToken>) -> Self {
-        Self::unwrap_cast(
-            self.syntax
-                .splice_slots(0usize..=0usize, once(element.map(|element| element.into()))),
-        )
-    }
     pub fn with_entries(self, element: YamlBlockMapEntryList) -> Self {
         Self::unwrap_cast(
             self.syntax
-                .splice_slots(1usize..=1usize, once(Some(element.into_syntax().into()))),
-        )
-    }
-    pub fn with_dedent_token(self, element: Option<SyntaxToken>) -> Self {
-        Self::unwrap_cast(
-            self.syntax
-                .splice_slots(2usize..=2usize, once(element.map(|element| element.into()))),
+                .splice_slots(0usize..=0usize, once(Some(element.into_syntax().into()))),
         )
     }
 }
@@ -144,22 +126,10 @@ impl YamlBlockScalar {
     }
 }
 impl YamlBlockSequence {
-    pub fn with_indent_token(self, element: Option<SyntaxToken>) -> Self {
-        Self::unwrap_cast(
-            self.syntax
-                .splice_slots(0usize..=0usize, once(element.map(|element| element.into()))),
-        )
-    }
     pub fn with_entries(self, element: YamlBlockSequenceEntryList) -> Self {
         Self::unwrap_cast(
             self.syntax
-                .splice_slots(1usize..=1usize, once(Some(element.into_syntax().into()))),
-        )
-    }
-    pub fn with_dedent_token(self, element: Option<SyntaxToken>) -> Self {
-        Self::unwrap_cast(
-            self.syntax
-                .splice_slots(2usize..=2usize, once(element.map(|element| element.into()))),
+                .splice_slots(0usize..=0usize, once(Some(element.into_syntax().into()))),
         )
     }
 }
@@ -178,7 +148,7 @@ impl YamlBlockSequenceEntry {
     }
 }
 impl YamlCompactMapping {
-    pub fn with_entries(self, element: YamlBlockSequenceEntryList) -> Self {
+    pub fn with_entries(self, element: YamlBlockMapEntryList) -> Self {
         Self::unwrap_cast(
             self.syntax
                 .splice_slots(0usize..=0usize, once(Some(element.into_syntax().into()))),
@@ -248,12 +218,6 @@ impl YamlFlowInBlockNode {
                 .splice_slots(0usize..=0usize, once(Some(element.into_syntax().into()))),
         )
     }
-    pub fn with_newline_token(self, element: Option<SyntaxToken>) -> Self {
-        Self::unwrap_cast(
-            self.syntax
-                .splice_slots(1usize..=1usize, once(element.map(|element| element.into()))),
-        )
-    }
 }
 impl YamlFlowJsonNode {
     pub fn with_properties(self, element: Option<AnyYamlPropertiesCombination>) -> Self {
@@ -290,17 +254,17 @@ impl YamlFlowMapImplicitEntry {
             once(element.map(|element| element.into_syntax().into())),
         ))
     }
-    pub fn with_colon_token(self, element: SyntaxToken) -> Self {
+    pub fn with_colon_token(self, element: Option<SyntaxToken>) -> Self {
         Self::unwrap_cast(
             self.syntax
-                .splice_slots(1usize..=1usize, once(Some(element.into()))),
+                .splice_slots(1usize..=1usize, once(element.map(|element| element.into()))),
         )
     }
-    pub fn with_value(self, element: AnyYamlFlowNode) -> Self {
-        Self::unwrap_cast(
-            self.syntax
-                .splice_slots(2usize..=2usize, once(Some(element.into_syntax().into()))),
-        )
+    pub fn with_value(self, element: Option<AnyYamlFlowNode>) -> Self {
+        Self::unwrap_cast(self.syntax.splice_slots(
+            2usize..=2usize,
+            once(element.map(|element| element.into_syntax().into())),
+        ))
     }
 }
 impl YamlFlowMapping {
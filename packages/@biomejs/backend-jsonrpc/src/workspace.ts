@@ -75,6 +75,10 @@ export interface Configuration {
 	 * The configuration for the linter
 	 */
 	linter?: LinterConfiguration;
+	/**
+	 * Specific configuration for the Markdown language
+	 */
+	markdown?: MarkdownConfiguration;
 	/**
 	 * A list of granular patterns that should be applied only to a sub set of files
 	 */
@@ -91,6 +95,10 @@ export interface Configuration {
 	 * The configuration of the VCS integration
 	 */
 	vcs?: VcsConfiguration;
+	/**
+	 * Specific configuration for the YAML language
+	 */
+	yaml?: YamlConfiguration;
 }
 export type Schema = string;
 export interface AssistConfiguration {
@@ -336,6 +344,15 @@ export interface LinterConfiguration {
 	 */
 	rules?: Rules;
 }
+/**
+ * Options applied to Markdown files
+ */
+export interface MarkdownConfiguration {
+	/**
+	 * Markdown formatter options
+	 */
+	formatter?: MarkdownFormatterConfiguration;
+}
 export type Overrides = OverridePattern[];
 export type Plugins = PluginConfiguration[];
 export type Bool = boolean;
@@ -366,6 +383,19 @@ If Biome can't find the configuration, it will attempt to use the current workin
 	 */
 	useIgnoreFile?: Bool;
 }
+/**
+ * Options applied to YAML files
+ */
+export interface YamlConfiguration {
+	/**
+	 * YAML formatter options
+	 */
+	formatter?: YamlFormatterConfiguration;
+	/**
+	 * YAML linter options
+	 */
+	linter?: YamlLinterConfiguration;
+}
 export interface Actions {
 	/**
 	 * It enables the assist actions recommended by Biome. `true` by default.
@@ -776,6 +806,35 @@ export interface Rules {
 	style?: SeverityOrGroup_for_Style;
 	suspicious?: SeverityOrGroup_for_Suspicious;
 }
+/**
+ * Options that changes how the Markdown formatter behaves
+ */
+export interface MarkdownFormatterConfiguration {
+	/**
+	 * Control the formatter for Markdown files.
+	 */
+	enabled?: Bool;
+	/**
+	 * The indent style applied to the code blocks of Markdown files.
+	 */
+	indentStyle?: IndentStyle;
+	/**
+	 * The size of the indentation applied to the code blocks of Markdown files. Default to 2.
+	 */
+	indentWidth?: IndentWidth;
+	/**
+	 * The type of line ending applied to Markdown files.
+	 */
+	lineEnding?: LineEnding;
+	/**
+	 * What's the max width of a line applied to Markdown files. Defaults to 80.
+	 */
+	lineWidth?: LineWidth;
+	/**
+	 * How the prose of Markdown files is wrapped: `always` wraps it at the line width, `never` joins each paragraph on a single line, and `preserve` keeps the line breaks as they are. Defaults to preserve.
+	 */
+	proseWrap?: ProseWrap;
+}
 export interface OverridePattern {
 	/**
 	 * Specific configuration for the Json language
@@ -821,13 +880,59 @@ export interface OverridePattern {
 	 * Specific configuration for the Json language
 	 */
 	linter?: OverrideLinterConfiguration;
+	/**
+	 * Specific configuration for the Markdown language
+	 */
+	markdown?: MarkdownConfiguration;
 	/**
 	 * Specific configuration for additional plugins
 	 */
 	plugins?: Plugins;
+	/**
+	 * Specific configuration for the YAML language
+	 */
+	yaml?: YamlConfiguration;
 }
 export type PluginConfiguration = string;
 export type VcsClientKind = "git";
+/**
+ * Options that changes how the YAML formatter behaves
+ */
+export interface YamlFormatterConfiguration {
+	/**
+	 * Whether to insert spaces around the braces of flow mappings. Defaults to true.
+	 */
+	bracketSpacing?: BracketSpacing;
+	/**
+	 * Control the formatter for YAML files.
+	 */
+	enabled?: Bool;
+	/**
+	 * The size of the indentation applied to YAML files. Default to 2. YAML files are always indented with spaces.
+	 */
+	indentWidth?: IndentWidth;
+	/**
+	 * The type of line ending applied to YAML files.
+	 */
+	lineEnding?: LineEnding;
+	/**
+	 * What's the max width of a line applied to YAML files. Defaults to 80.
+	 */
+	lineWidth?: LineWidth;
+	/**
+	 * The type of quotes used in YAML files. Defaults to double. The quotes of a scalar are changed only when its value doesn't need any new escape.
+	 */
+	quoteStyle?: QuoteStyle;
+}
+/**
+ * Options that change how the YAML linter behaves.
+ */
+export interface YamlLinterConfiguration {
+	/**
+	 * Control the linter for YAML files.
+	 */
+	enabled?: Bool;
+}
 /**
  * A list of rules that belong to this group
  */
@@ -914,6 +1019,10 @@ export type SeverityOrGroup_for_Style = GroupPlainConfiguration | Style;
 export type SeverityOrGroup_for_Suspicious =
 	| GroupPlainConfiguration
 	| Suspicious;
+/**
+ * Controls how the text of paragraphs is wrapped
+ */
+export type ProseWrap = "always" | "never" | "preserve";
 export interface OverrideAssistConfiguration {
 	/**
 	 * List of actions
@@ -1542,6 +1651,10 @@ export interface Correctness {
  * A list of rules that belong to this group
  */
 export interface Nursery {
+	/**
+	 * Disallow plain values that YAML 1.1 reads as booleans, such as yes and on.
+	 */
+	noAmbiguousBooleans?: RuleFixConfiguration_for_Null;
 	/**
 	 * Disallow await inside loops.
 	 */
@@ -1558,6 +1671,10 @@ export interface Nursery {
 	 * Disallow destructuring props inside JSX components in Solid projects.
 	 */
 	noDestructuredProps?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow two keys with the same value inside mappings.
+	 */
+	noDuplicateKeys?: RuleConfiguration_for_Null;
 	/**
 	 * Restrict the number of lines of code in a function.
 	 */
@@ -1622,6 +1739,10 @@ export interface Nursery {
 	 * Disallow let or var variables that are read but never assigned.
 	 */
 	noUnassignedVariables?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow aliases that don't refer to an anchor declared before them.
+	 */
+	noUndeclaredAliases?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow unknown at-rules.
 	 */
@@ -1654,6 +1775,10 @@ export interface Nursery {
 	 * Enforce that getters and setters for the same property are adjacent in class and object definitions.
 	 */
 	useAdjacentGetterSetter?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce the same indentation width for all the block collections of a file.
+	 */
+	useConsistentIndentation?: RuleConfiguration_for_Null;
 	/**
 	 * Require the consistent declaration of object literals. Defaults to explicit definitions.
 	 */
@@ -3614,6 +3739,7 @@ export type Category =
 	| "lint/correctness/useValidTypeof"
 	| "lint/correctness/useYield"
 	| "lint/nursery/colorNoInvalidHex"
+	| "lint/nursery/noAmbiguousBooleans"
 	| "lint/nursery/noAwaitInLoop"
 	| "lint/nursery/noBitwiseOperators"
 	| "lint/nursery/noColorInvalidHex"
@@ -3622,6 +3748,7 @@ export type Category =
 	| "lint/nursery/noDestructuredProps"
 	| "lint/nursery/noDoneCallback"
 	| "lint/nursery/noDuplicateAtImportRules"
+	| "lint/nursery/noDuplicateKeys"
 	| "lint/nursery/noExcessiveLinesPerFunction"
 	| "lint/nursery/noFloatingPromises"
 	| "lint/nursery/noGlobalDirnameFilename"
@@ -3645,6 +3772,7 @@ export type Category =
 	| "lint/nursery/noShorthandPropertyOverrides"
 	| "lint/nursery/noTsIgnore"
 	| "lint/nursery/noUnassignedVariables"
+	| "lint/nursery/noUndeclaredAliases"
 	| "lint/nursery/noUndeclaredDependencies"
 	| "lint/nursery/noUnknownAtRule"
 	| "lint/nursery/noUnknownFunction"
@@ -3661,6 +3789,7 @@ export type Category =
 	| "lint/nursery/noUselessUndefined"
 	| "lint/nursery/useAdjacentGetterSetter"
 	| "lint/nursery/useBiomeSuppressionComment"
+	| "lint/nursery/useConsistentIndentation"
 	| "lint/nursery/useConsistentObjectDefinition"
 	| "lint/nursery/useConsistentResponse"
 	| "lint/nursery/useExhaustiveSwitchCases"
@@ -3886,6 +4015,8 @@ export type Category =
 	| "suppressions/unknownAction"
 	| "suppressions/unused"
 	| "suppressions/incorrect"
+	| "baseline/parse"
+	| "baseline/stale"
 	| "args/fileNotFound"
 	| "flags/invalid"
 	| "semanticTests";
@@ -4033,7 +4164,9 @@ export type DocumentFileSource =
 	| { Css: CssFileSource }
 	| { Graphql: GraphqlFileSource }
 	| { Html: HtmlFileSource }
-	| { Grit: GritFileSource };
+	| { Grit: GritFileSource }
+	| { Markdown: MarkdownFileSource }
+	| { Yaml: YamlFileSource };
 export interface JsFileSource {
 	/**
 	 * Used to mark if the source is being used for an Astro, Svelte or Vue file
@@ -4061,6 +4194,10 @@ export interface HtmlFileSource {
 export interface GritFileSource {
 	variant: GritVariant;
 }
+export interface MarkdownFileSource {
+	variant: MarkdownVariant;
+}
+export interface YamlFileSource {}
 export type EmbeddingKind = "Astro" | "Vue" | "Svelte" | "None";
 export type Language =
 	| "javaScript"
@@ -4092,6 +4229,10 @@ export type CssVariant = "standard";
 export type GraphqlVariant = "standard";
 export type HtmlVariant = "Standard" | "Astro";
 export type GritVariant = "Standard";
+/**
+ * The flavor of Markdown contained in the file.
+ */
+export type MarkdownVariant = "gfm";
 export interface ChangeFileParams {
 	content: string;
 	path: BiomePath;
@@ -4125,6 +4266,34 @@ export interface GetFileContentParams {
 	path: BiomePath;
 	projectKey: ProjectKey;
 }
+export interface GetModuleDependenciesParams {
+	path: BiomePath;
+	projectKey: ProjectKey;
+}
+export interface GetModuleDependenciesResult {
+	/**
+	 * The paths of the modules imported by the file, directly or through other modules, sorted by path
+	 */
+	dependencies: BiomePath[];
+}
+export interface GetFileConfigurationParams {
+	path: BiomePath;
+	projectKey: ProjectKey;
+}
+export interface GetFileConfigurationResult {
+	/**
+	 * The configuration that applies to the file, with the configurations that it extends merged into it
+	 */
+	configuration: Configuration;
+	/**
+	 * The folder of the configuration file that applies to the file, if any
+	 */
+	directory?: BiomePath;
+	/**
+	 * The indexes of the `overrides` of the configuration that match the file, in the order in which they apply
+	 */
+	overrides: number[];
+}
 export interface GetControlFlowGraphParams {
 	cursor: TextSize;
 	path: BiomePath;
@@ -4159,6 +4328,10 @@ export interface PullDiagnosticsParams {
 	 * When `false` the diagnostics, don't have code frames of the code actions (fixes, suppressions, etc.)
 	 */
 	pullCodeActions: boolean;
+	/**
+	 * When set, only the diagnostics overlapping this range are computed
+	 */
+	range?: TextRange;
 	skip?: RuleCode[];
 }
 export type RuleCategories = RuleCategory[];
@@ -4326,6 +4499,24 @@ export interface RenameResult {
 	 * List of text edit operations to apply on the source code
 	 */
 	indels: TextEdit;
+	/**
+	 * Text edit operations to apply on other files, such as the modules that import the renamed symbol
+	 */
+	otherFiles: RenameFileEdits[];
+	/**
+	 * Range of source code modified by this rename operation
+	 */
+	range: TextRange;
+}
+export interface RenameFileEdits {
+	/**
+	 * List of text edit operations to apply on the source code
+	 */
+	indels: TextEdit;
+	/**
+	 * Path of the file to modify
+	 */
+	path: BiomePath;
 	/**
 	 * Range of source code modified by this rename operation
 	 */
@@ -4346,12 +4537,340 @@ export interface SearchPatternParams {
 	projectKey: ProjectKey;
 }
 export interface SearchResults {
+	/**
+	 * The ranges of the code targeted by conflicting rewrites, which prevent the file from being rewritten
+	 */
+	conflicts: TextRange[];
 	matches: TextRange[];
 	path: BiomePath;
+	/**
+	 * The file after applying the rewrites of the pattern, if it has any and they change the file.
+	 */
+	rewrite?: SearchRewrite;
+}
+export interface SearchRewrite {
+	/**
+	 * The new content of the file
+	 */
+	content: string;
+	/**
+	 * The ranges of the rewritten code in the new content
+	 */
+	ranges: TextRange[];
 }
 export interface DropPatternParams {
 	pattern: PatternId;
 }
+export interface HoverParams {
+	offset: TextSize;
+	path: BiomePath;
+	projectKey: ProjectKey;
+}
+export interface HoverResult {
+	/**
+	 * List of Markdown snippets describing the node
+	 */
+	contents: string[];
+	/**
+	 * Range of the node the information refers to
+	 */
+	range?: TextRange;
+}
+export interface GotoDefinitionParams {
+	offset: TextSize;
+	path: BiomePath;
+	projectKey: ProjectKey;
+}
+export interface GotoDefinitionResult {
+	/**
+	 * Locations where the symbol is defined
+	 */
+	definitions: SymbolLocation[];
+}
+export interface SymbolLocation {
+	/**
+	 * Path of the file that contains the symbol
+	 */
+	path: BiomePath;
+	/**
+	 * Range of the symbol inside the file
+	 */
+	range: TextRange;
+}
+export interface FindReferencesParams {
+	/**
+	 * Whether the declaration of the symbol should be part of the result
+	 */
+	includeDeclaration: boolean;
+	offset: TextSize;
+	path: BiomePath;
+	projectKey: ProjectKey;
+}
+export interface FindReferencesResult {
+	/**
+	 * Locations where the symbol is referenced, sorted by path and range
+	 */
+	references: SymbolLocation[];
+}
+export interface PrepareRenameParams {
+	path: BiomePath;
+	projectKey: ProjectKey;
+	symbolAt: TextSize;
+}
+export interface PrepareRenameResult {
+	/**
+	 * Range of the symbol that would be renamed, if the symbol at the given position can be renamed
+	 */
+	range?: TextRange;
+}
+export interface DocumentSymbolsParams {
+	path: BiomePath;
+	projectKey: ProjectKey;
+}
+export interface DocumentSymbolsResult {
+	/**
+	 * Symbols declared at the top level of the document
+	 */
+	symbols: DocumentSymbol[];
+}
+export interface DocumentSymbol {
+	/**
+	 * Symbols declared inside this symbol
+	 */
+	children: DocumentSymbol[];
+	/**
+	 * Kind of the symbol
+	 */
+	kind: SymbolKind;
+	/**
+	 * Name of the symbol
+	 */
+	name: string;
+	/**
+	 * Range of the whole declaration of the symbol
+	 */
+	range: TextRange;
+	/**
+	 * Range of the name of the symbol
+	 */
+	selectionRange: TextRange;
+}
+export type SymbolKind =
+	| "array"
+	| "boolean"
+	| "class"
+	| "constant"
+	| "constructor"
+	| "enum"
+	| "enumMember"
+	| "field"
+	| "function"
+	| "interface"
+	| "method"
+	| "module"
+	| "namespace"
+	| "null"
+	| "number"
+	| "object"
+	| "property"
+	| "string"
+	| "struct"
+	| "typeParameter"
+	| "variable";
+export interface WorkspaceSymbolsParams {
+	/**
+	 * Maximum number of symbols to return
+	 */
+	limit: number;
+	projectKey: ProjectKey;
+	/**
+	 * Text used to filter the symbols by name. An empty query matches all the symbols.
+	 */
+	query: string;
+}
+export interface WorkspaceSymbolsResult {
+	/**
+	 * Symbols matching the query, sorted by path and range. The number of symbols is capped by the limit, so large projects may have more matches.
+	 */
+	symbols: WorkspaceSymbol[];
+}
+export interface WorkspaceSymbol {
+	/**
+	 * Name of the symbol that contains this symbol, if any
+	 */
+	containerName?: string;
+	/**
+	 * Kind of the symbol
+	 */
+	kind: SymbolKind;
+	/**
+	 * Name of the symbol
+	 */
+	name: string;
+	/**
+	 * Path of the file that contains the symbol
+	 */
+	path: BiomePath;
+	/**
+	 * Range of the name of the symbol inside the file
+	 */
+	range: TextRange;
+}
+export interface SemanticTokensParams {
+	path: BiomePath;
+	projectKey: ProjectKey;
+	/**
+	 * If specified, only the tokens that intersect this range are returned
+	 */
+	range?: TextRange;
+}
+export interface SemanticTokensResult {
+	/**
+	 * Tokens of the document, sorted by range
+	 */
+	tokens: SemanticToken[];
+}
+export interface SemanticToken {
+	/**
+	 * Kind of the symbol the token refers to
+	 */
+	kind: SemanticTokenKind;
+	/**
+	 * Additional information about the token
+	 */
+	modifiers: SemanticTokenModifier[];
+	/**
+	 * Range of the token
+	 */
+	range: TextRange;
+}
+export type SemanticTokenKind =
+	| "class"
+	| "directive"
+	| "enum"
+	| "enumMember"
+	| "fragment"
+	| "function"
+	| "interface"
+	| "namespace"
+	| "parameter"
+	| "type"
+	| "typeParameter"
+	| "variable";
+export type SemanticTokenModifier =
+	| "declaration"
+	| "global"
+	| "imported"
+	| "readonly"
+	| "shadowing"
+	| "unresolved";
+export interface PullProjectDiagnosticsParams {
+	projectKey: ProjectKey;
+}
+export interface PullProjectDiagnosticsResult {
+	/**
+	 * The diagnostics of every file of the project that can be linted, including the files without diagnostics.
+	 */
+	files: ProjectFileDiagnostics[];
+}
+export interface ProjectFileDiagnostics {
+	diagnostics: Diagnostic[];
+	path: BiomePath;
+}
+export interface FoldingRangesParams {
+	path: BiomePath;
+	projectKey: ProjectKey;
+}
+export interface FoldingRangesResult {
+	/**
+	 * Ranges that can be folded, sorted by their start
+	 */
+	ranges: FoldingRange[];
+}
+export interface FoldingRange {
+	kind?: FoldingRangeKind;
+	/**
+	 * Range of the folded content. It always spans multiple lines, and it ends before the line of the closing delimiter, if there's any.
+	 */
+	range: TextRange;
+}
+export type FoldingRangeKind = "comment" | "region";
+export interface SelectionRangeParams {
+	offset: TextSize;
+	path: BiomePath;
+	projectKey: ProjectKey;
+}
+export interface SelectionRangeResult {
+	/**
+	 * Ranges that contain the offset, from the innermost to the outermost
+	 */
+	ranges: TextRange[];
+}
+export interface InlayHintsParams {
+	path: BiomePath;
+	projectKey: ProjectKey;
+	/**
+	 * If specified, only the hints within this range are returned
+	 */
+	range?: TextRange;
+}
+export interface InlayHintsResult {
+	/**
+	 * Hints of the document, sorted by offset
+	 */
+	hints: InlayHint[];
+}
+export interface InlayHint {
+	kind: InlayHintKind;
+	/**
+	 * Text of the hint, such as `: number` for types or `name:` for parameters
+	 */
+	label: string;
+	/**
+	 * Offset where the hint is displayed
+	 */
+	offset: TextSize;
+}
+export type InlayHintKind = "type" | "parameter";
+export interface CompletionsParams {
+	offset: TextSize;
+	path: BiomePath;
+	projectKey: ProjectKey;
+}
+export interface CompletionsResult {
+	items: CompletionItem[];
+}
+export interface CompletionItem {
+	/**
+	 * Markdown documentation of the item
+	 */
+	documentation?: string;
+	/**
+	 * Text inserted in place of `range` when the completion is accepted
+	 */
+	insertText: string;
+	kind: CompletionItemKind;
+	/**
+	 * Text shown in the list of completions
+	 */
+	label: string;
+	/**
+	 * Range of the text replaced by the completion, such as a partially typed key. It's empty when the completion is inserted at the offset.
+	 */
+	range: TextRange;
+}
+export type CompletionItemKind = "property" | "value";
+export interface LinkedEditingRangesParams {
+	offset: TextSize;
+	path: BiomePath;
+	projectKey: ProjectKey;
+}
+export interface LinkedEditingRangesResult {
+	/**
+	 * Ranges that have the same content and are edited together, such as the names of an opening and a closing tag. It's empty if the offset isn't inside any of these ranges.
+	 */
+	ranges: TextRange[];
+}
 export interface Workspace {
 	fileFeatures(params: SupportsFeatureParams): Promise<FileFeaturesResult>;
 	updateSettings(params: UpdateSettingsParams): Promise<UpdateSettingsResult>;
@@ -4363,6 +4882,12 @@ export interface Workspace {
 	fileExists(params: FileExitsParams): Promise<boolean>;
 	checkFileSize(params: CheckFileSizeParams): Promise<CheckFileSizeResult>;
 	getFileContent(params: GetFileContentParams): Promise<string>;
+	getModuleDependencies(
+		params: GetModuleDependenciesParams,
+	): Promise<GetModuleDependenciesResult>;
+	getFileConfiguration(
+		params: GetFileConfigurationParams,
+	): Promise<GetFileConfigurationResult>;
 	getControlFlowGraph(params: GetControlFlowGraphParams): Promise<string>;
 	getFormatterIr(params: GetFormatterIRParams): Promise<string>;
 	getTypeInfo(params: GetTypeInfoParams): Promise<string>;
@@ -4380,6 +4905,27 @@ export interface Workspace {
 	parsePattern(params: ParsePatternParams): Promise<ParsePatternResult>;
 	searchPattern(params: SearchPatternParams): Promise<SearchResults>;
 	dropPattern(params: DropPatternParams): Promise<void>;
+	hover(params: HoverParams): Promise<HoverResult>;
+	gotoDefinition(params: GotoDefinitionParams): Promise<GotoDefinitionResult>;
+	findReferences(params: FindReferencesParams): Promise<FindReferencesResult>;
+	prepareRename(params: PrepareRenameParams): Promise<PrepareRenameResult>;
+	documentSymbols(
+		params: DocumentSymbolsParams,
+	): Promise<DocumentSymbolsResult>;
+	workspaceSymbols(
+		params: WorkspaceSymbolsParams,
+	): Promise<WorkspaceSymbolsResult>;
+	semanticTokens(params: SemanticTokensParams): Promise<SemanticTokensResult>;
+	pullProjectDiagnostics(
+		params: PullProjectDiagnosticsParams,
+	): Promise<PullProjectDiagnosticsResult>;
+	foldingRanges(params: FoldingRangesParams): Promise<FoldingRangesResult>;
+	selectionRange(params: SelectionRangeParams): Promise<SelectionRangeResult>;
+	inlayHints(params: InlayHintsParams): Promise<InlayHintsResult>;
+	completions(params: CompletionsParams): Promise<CompletionsResult>;
+	linkedEditingRanges(
+		params: LinkedEditingRangesParams,
+	): Promise<LinkedEditingRangesResult>;
 	destroy(): void;
 }
 export function createWorkspace(transport: Transport): Workspace {
@@ -4414,6 +4960,12 @@ export function createWorkspace(transport: Transport): Workspace {
 		getFileContent(params) {
 			return transport.request("biome/get_file_content", params);
 		},
+		getModuleDependencies(params) {
+			return transport.request("biome/get_module_dependencies", params);
+		},
+		getFileConfiguration(params) {
+			return transport.request("biome/get_file_configuration", params);
+		},
 		getControlFlowGraph(params) {
 			return transport.request("biome/get_control_flow_graph", params);
 		},
@@ -4459,6 +5011,45 @@ export function createWorkspace(transport: Transport): Workspace {
 		dropPattern(params) {
 			return transport.request("biome/drop_pattern", params);
 		},
+		hover(params) {
+			return transport.request("biome/hover", params);
+		},
+		gotoDefinition(params) {
+			return transport.request("biome/goto_definition", params);
+		},
+		findReferences(params) {
+			return transport.request("biome/find_references", params);
+		},
+		prepareRename(params) {
+			return transport.request("biome/prepare_rename", params);
+		},
+		documentSymbols(params) {
+			return transport.request("biome/document_symbols", params);
+		},
+		workspaceSymbols(params) {
+			return transport.request("biome/workspace_symbols", params);
+		},
+		semanticTokens(params) {
+			return transport.request("biome/semantic_tokens", params);
+		},
+		pullProjectDiagnostics(params) {
+			return transport.request("biome/pull_project_diagnostics", params);
+		},
+		foldingRanges(params) {
+			return transport.request("biome/folding_ranges", params);
+		},
+		selectionRange(params) {
+			return transport.request("biome/selection_range", params);
+		},
+		inlayHints(params) {
+			return transport.request("biome/inlay_hints", params);
+		},
+		completions(params) {
+			return transport.request("biome/completions", params);
+		},
+		linkedEditingRanges(params) {
+			return transport.request("biome/linked_editing_ranges", params);
+		},
 		destroy() {
 			transport.destroy();
 		},
@@ -564,6 +564,8 @@ enum NodeDialect {
     Grit,
     Graphql,
     Html,
+    Markdown,
+    Yaml,
 }
 
 impl NodeDialect {
@@ -577,6 +579,8 @@ impl NodeDialect {
             Self::Grit,
             Self::Graphql,
             Self::Html,
+            Self::Markdown,
+            Self::Yaml,
         ]
     }
 
@@ -594,6 +598,8 @@ impl NodeDialect {
             Self::Grit => "grit",
             Self::Graphql => "graphql",
             Self::Html => "html",
+            Self::Markdown => "markdown",
+            Self::Yaml => "yaml",
         }
     }
 
@@ -607,6 +613,8 @@ impl NodeDialect {
             "Grit" => Self::Grit,
             "Graphql" => Self::Graphql,
             "Html" => Self::Html,
+            "Md" => Self::Markdown,
+            "Yaml" => Self::Yaml,
             _ => {
                 eprintln!("missing prefix {name}");
                 Self::Js
@@ -896,7 +904,7 @@ impl LanguageKind {
             Self::Grit => "GritFormatter",
             Self::Html => "HtmlFormatter",
             Self::Yaml => "YamlFormatter",
-            Self::Markdown => "DemoFormatter",
+            Self::Markdown => "MarkdownFormatter",
         };
 
         Ident::new(name, Span::call_site())
@@ -911,7 +919,7 @@ impl LanguageKind {
             Self::Grit => "GritFormatContext",
             Self::Html => "HtmlFormatContext",
             Self::Yaml => "YamlFormatContext",
-            Self::Markdown => "DemoFormatterContext",
+            Self::Markdown => "MarkdownFormatContext",
         };
 
         Ident::new(name, Span::call_site())
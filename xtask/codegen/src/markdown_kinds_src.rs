@@ -8,30 +8,32 @@ pub const MARKDOWN_KINDS_SRC: KindsSrc = KindsSrc {
         (")", "R_PAREN"),
         ("[", "L_BRACK"),
         ("]", "R_BRACK"),
-        ("/", "SLASH"),
-        ("=", "EQ"),
         ("!", "BANG"),
         ("-", "MINUS"),
+        ("+", "PLUS"),
         ("*", "STAR"),
         ("**", "DOUBLE_STAR"),
-        ("`", "BACKTICK"),
-        ("```", "TRIPLE_BACKTICK"),
-        ("~", "TILDE"),
-        ("   ", "WHITESPACE3"),
         ("_", "UNDERSCORE"),
         ("__", "DOUBLE_UNDERSCORE"),
+        ("~", "TILDE"),
+        ("~~", "DOUBLE_TILDE"),
         ("#", "HASH"),
-        (",", "COMMA"),
+        ("|", "PIPE"),
+        (":", "COLON"),
     ],
     keywords: &["null"],
     literals: &[
         "MD_HARD_LINE_LITERAL",
-        "MD_SOFT_BREAK_LITERAL",
         "MD_TEXTUAL_LITERAL",
-        "MD_STRING_LITERAL",
-        "MD_INDENT_CHUNK_LITERAL",
         "MD_THEMATIC_BREAK_LITERAL",
-        "MD_ERROR_LITERAL",
+        "MD_SETEXT_UNDERLINE_LITERAL",
+        "MD_CODE_FENCE_LITERAL",
+        "MD_BACKTICK_RUN_LITERAL",
+        "MD_ORDERED_LIST_MARKER_LITERAL",
+        "MD_TASK_MARKER_LITERAL",
+        "MD_TABLE_DELIMITER_LITERAL",
+        "MD_AUTOLINK_LITERAL",
+        "MD_HTML_LITERAL",
     ],
     tokens: &["ERROR_TOKEN", "NEWLINE", "WHITESPACE", "TAB"],
     nodes: &[
@@ -41,38 +43,46 @@ pub const MARKDOWN_KINDS_SRC: KindsSrc = KindsSrc {
         // node
         "MD_DOCUMENT",
         "MD_BLOCK_LIST",
+        "MD_THEMATIC_BREAK_BLOCK",
+        "MD_HEADER",
         "MD_HASH_LIST",
         "MD_HASH",
-        "MD_HEADER",
+        "MD_SETEXT_HEADER",
         "MD_INDENT_CODE_BLOCK",
         "MD_FENCED_CODE_BLOCK",
-        "MD_CODE_NAME_LIST",
         "MD_HTML_BLOCK",
-        "MD_LINK_BLOCK",
+        "MD_LINK_REFERENCE_DEFINITION",
+        "MD_TABLE",
+        "MD_TABLE_ROW_LIST",
+        "MD_TABLE_ROW",
+        "MD_TABLE_CELL_LIST",
+        "MD_TABLE_CELL",
+        "MD_TABLE_DELIMITER_ROW",
+        "MD_TABLE_DELIMITER_CELL_LIST",
+        "MD_TABLE_DELIMITER_CELL",
+        "MD_PARAGRAPH",
         "MD_QUOTE",
-        "MD_ORDER_LIST_ITEM",
-        "MD_BULLET_LIST_ITEM",
         "MD_BULLET_LIST",
-        "MD_ORDER_LIST",
-        "MD_PARAGRAPH",
+        "MD_BULLET_LIST_ITEM_LIST",
+        "MD_BULLET_LIST_ITEM",
+        "MD_ORDERED_LIST",
+        "MD_ORDERED_LIST_ITEM_LIST",
+        "MD_ORDERED_LIST_ITEM",
         "MD_INLINE_ITEM_LIST",
-        "MD_INLINE_EMPHASIS",
         "MD_INLINE_ITALIC",
+        "MD_INLINE_EMPHASIS",
+        "MD_INLINE_STRIKETHROUGH",
         "MD_INLINE_CODE",
-        "MD_BULLET",
         "MD_INLINE_LINK",
+        "MD_REFERENCE_LINK",
+        "MD_REFERENCE_LINK_LABEL",
         "MD_INLINE_IMAGE",
-        "MD_INLINE_IMAGE_ALT",
-        "MD_INDENTED_CODE_LINE",
-        "MD_INLINE_IMAGE_LINK",
-        "MD_INLINE_IMAGE_SOURCE",
-        "MD_INDENTED_CODE_LINE_LIST",
+        "MD_REFERENCE_IMAGE",
+        "MD_LINK_DESTINATION",
+        "MD_LINK_TITLE",
+        "MD_AUTOLINK",
+        "MD_INLINE_HTML",
         "MD_HARD_LINE",
-        "MD_SOFT_BREAK",
         "MD_TEXTUAL",
-        "MD_SETEXT_HEADER",
-        "MD_STRING",
-        "MD_INDENT",
-        "MD_THEMATIC_BREAK_BLOCK",
     ],
 };
@@ -28,14 +28,7 @@ pub const YAML_KINDS_SRC: KindsSrc = KindsSrc {
         "LITERAL_BLOCK_LITERAL",
         "FOLDED_BLOCK_LITERAL",
     ],
-    tokens: &[
-        "ERROR_TOKEN",
-        "NEWLINE",
-        "WHITESPACE",
-        "INDENT",
-        "DEDENT",
-        "COMMENT",
-    ],
+    tokens: &["ERROR_TOKEN", "NEWLINE", "WHITESPACE", "COMMENT"],
     nodes: &[
         "YAML_ROOT",
         "YAML_DOCUMENT_LIST",
@@ -80,5 +73,6 @@ pub const YAML_KINDS_SRC: KindsSrc = KindsSrc {
         "YAML_BOGUS",
         "YAML_BOGUS_BLOCK_NODE",
         "YAML_BOGUS_BLOCK_MAP_ENTRY",
+        "YAML_BOGUS_FLOW_NODE",
     ],
 };